@@ -13,9 +13,9 @@ DECLARE
 BEGIN
     IF (a_agreed_tos = FALSE OR a_agreed_privacy = FALSE) THEN
         RAISE SQLSTATE 'R000X'; -- ConsentMissing
-    ELSEIF ((SELECT pkey_id
-             FROM tbl.user
-             WHERE address = a_address) IS NOT NULL) THEN
+    ELSEIF EXISTS(SELECT 1
+                  FROM tbl.user_login_wallet
+                  WHERE address = a_address) THEN
         RAISE SQLSTATE 'R000Z'; -- UsernameAlreadyRegistered
     END IF;
     INSERT INTO tbl.user (address,
@@ -51,6 +51,8 @@ BEGIN
             a_ens_avatar
         )
     RETURNING pkey_id INTO STRICT id_;
+    INSERT INTO tbl.user_login_wallet(fkey_user_id, address, is_primary, created_at)
+    VALUES (id_, a_address, TRUE, extract(Epoch FROM (NOW()))::bigint);
     INSERT INTO tbl.user_whitelisted_wallet(fkey_user_id,
                                            blockchain,
                                            address,
@@ -91,11 +93,12 @@ BEGIN
     ASSERT (a_ip_address NOTNULL AND a_device_id NOTNULL AND a_device_os NOTNULL AND
             a_address NOTNULL AND a_service_code NOTNULL);
 
-    -- Looking up the user.
-    SELECT pkey_id, is_blocked, u.role, u.public_id
+    -- Looking up the user of any of its login wallets.
+    SELECT u.pkey_id, is_blocked, u.role, u.public_id
     INTO _user_id, is_blocked_, _role, _public_user_id
     FROM tbl.user u
-    WHERE address = a_address;
+    JOIN tbl.user_login_wallet w ON w.fkey_user_id = u.pkey_id
+    WHERE w.address = a_address;
 
     -- Log the login attempt. 
     INSERT INTO tbl.login_attempt(fkey_user, address, ip_address, is_password_ok, moment)
@@ -128,23 +131,25 @@ BEGIN
     END IF;
     RETURN QUERY SELECT u.pkey_id, u.public_id, u.role, u.ens_name, u.ens_avatar
     FROM tbl.user u
-    WHERE address = a_address;
+    WHERE u.pkey_id = _user_id;
 END
         
 $$;
         
 
-CREATE OR REPLACE FUNCTION api.fun_auth_set_token(a_user_id bigint, a_user_token uuid, a_admin_token uuid, a_service_code int)
-RETURNS void
+CREATE OR REPLACE FUNCTION api.fun_auth_create_session(a_user_id bigint, a_service enum_service, a_device_id varchar, a_device_os varchar, a_ip_address inet, a_access_token uuid, a_access_expires_at bigint, a_refresh_token uuid, a_refresh_expires_at bigint)
+RETURNS table (
+    "session_id" bigint
+)
 LANGUAGE plpgsql
 AS $$
     
 DECLARE
-  rc_         integer;
   is_blocked_ boolean;
+  _session_id bigint;
 BEGIN
-  ASSERT (a_user_id NOTNULL AND a_service_code NOTNULL AND a_user_token NOTNULL AND
-          a_admin_token NOTNULL);
+  ASSERT (a_user_id NOTNULL AND a_service NOTNULL AND a_device_id NOTNULL AND
+          a_access_token NOTNULL AND a_refresh_token NOTNULL);
   -- Looking up the user.
   SELECT is_blocked INTO is_blocked_ FROM tbl.user WHERE pkey_id = a_user_id;
   IF (is_blocked_ ISNULL) THEN
@@ -153,105 +158,187 @@ BEGIN
     RAISE SQLSTATE 'R0008'; -- BlockedUser
   END IF;
 
-  -- Setting up the token.
-  IF a_service_code = (SELECT code FROM api.USER_SERVICE()) THEN
-    UPDATE tbl.user
-    SET user_token = a_user_token
-    WHERE pkey_id = a_user_id;
-  END IF;
-  IF a_service_code = (SELECT code FROM api.ADMIN_SERVICE())  THEN
-    UPDATE tbl.user
-    SET admin_token = a_admin_token
-    WHERE pkey_id = a_user_id;
-  END IF;
-
-  GET DIAGNOSTICS rc_ := ROW_COUNT;
-  ASSERT (rc_ = 1);
+  -- A new login replaces the session of the same device only.
+  UPDATE tbl.user_session
+  SET revoked_at = EXTRACT(EPOCH FROM NOW())::bigint
+  WHERE fkey_user_id = a_user_id
+    AND service = a_service
+    AND device_id = a_device_id
+    AND revoked_at ISNULL;
+
+  INSERT INTO tbl.user_session (fkey_user_id, service, device_id, device_os, ip_address,
+                                access_token, access_expires_at, refresh_token,
+                                refresh_expires_at, created_at, last_seen_at)
+  VALUES (a_user_id, a_service, a_device_id, a_device_os, a_ip_address,
+          a_access_token, a_access_expires_at, a_refresh_token,
+          a_refresh_expires_at, EXTRACT(EPOCH FROM NOW())::bigint,
+          EXTRACT(EPOCH FROM NOW())::bigint)
+  RETURNING pkey_id INTO STRICT _session_id;
+  RETURN QUERY SELECT _session_id;
 END
             
 $$;
         
 
-CREATE OR REPLACE FUNCTION api.fun_auth_remove_token(a_user_id bigint)
-RETURNS void
+CREATE OR REPLACE FUNCTION api.fun_auth_refresh_session(a_address varchar, a_refresh_token uuid, a_service enum_service, a_device_id varchar, a_ip_address inet, a_new_access_token uuid, a_new_access_expires_at bigint, a_new_refresh_token uuid, a_new_refresh_expires_at bigint)
+RETURNS table (
+    "session_id" bigint,
+    "user_id" bigint,
+    "is_reused" boolean
+)
 LANGUAGE plpgsql
 AS $$
     
+DECLARE
+  _session_id         bigint;
+  _user_id            bigint;
+  _device_id          varchar;
+  _refresh_expires_at bigint;
+  _revoked_at         bigint;
 BEGIN
-  ASSERT (a_user_id NOTNULL);
+  SELECT s.pkey_id, s.fkey_user_id, s.device_id, s.refresh_expires_at, s.revoked_at
+  INTO _session_id, _user_id, _device_id, _refresh_expires_at, _revoked_at
+  FROM tbl.user_session s
+  JOIN tbl.user_login_wallet w ON w.fkey_user_id = s.fkey_user_id
+  WHERE s.refresh_token = a_refresh_token
+    AND s.service = a_service
+    AND w.address = a_address
+  FOR UPDATE OF s;
+
+  IF (_session_id ISNULL) THEN
+    -- A refresh token that was already rotated has leaked, the whole session goes.
+    SELECT r.fkey_session_id, s.fkey_user_id
+    INTO _session_id, _user_id
+    FROM tbl.user_session_rotated_token r
+    JOIN tbl.user_session s ON s.pkey_id = r.fkey_session_id
+    JOIN tbl.user_login_wallet w ON w.fkey_user_id = s.fkey_user_id
+    WHERE r.refresh_token = a_refresh_token
+      AND w.address = a_address;
+    IF (_session_id ISNULL) THEN
+      RAISE SQLSTATE 'R000A'; -- InvalidToken
+    END IF;
+    UPDATE tbl.user_session
+    SET revoked_at = EXTRACT(EPOCH FROM NOW())::bigint
+    WHERE pkey_id = _session_id
+      AND revoked_at ISNULL;
+    RETURN QUERY SELECT _session_id, _user_id, TRUE;
+    RETURN;
+  END IF;
 
-  -- Setting up the token.
-  UPDATE tbl.user
-  SET user_token = NULL, admin_token = NULL
-  WHERE pkey_id = a_user_id;
+  IF (_revoked_at NOTNULL OR _device_id <> a_device_id) THEN
+    RAISE SQLSTATE 'R000A'; -- InvalidToken
+  ELSIF (_refresh_expires_at < EXTRACT(EPOCH FROM NOW())::bigint) THEN
+    RAISE SQLSTATE 'R000L'; -- TokenExpired
+  END IF;
 
+  INSERT INTO tbl.user_session_rotated_token (refresh_token, fkey_session_id, rotated_at)
+  VALUES (a_refresh_token, _session_id, EXTRACT(EPOCH FROM NOW())::bigint);
+  UPDATE tbl.user_session
+  SET access_token       = a_new_access_token,
+      access_expires_at  = a_new_access_expires_at,
+      refresh_token      = a_new_refresh_token,
+      refresh_expires_at = a_new_refresh_expires_at,
+      ip_address         = a_ip_address,
+      last_seen_at       = EXTRACT(EPOCH FROM NOW())::bigint
+  WHERE pkey_id = _session_id;
+  RETURN QUERY SELECT _session_id, _user_id, FALSE;
 END
             
 $$;
         
 
-CREATE OR REPLACE FUNCTION api.fun_auth_authorize(a_address varchar, a_token uuid, a_service enum_service, a_device_id varchar, a_device_os varchar, a_ip_address inet)
+CREATE OR REPLACE FUNCTION api.fun_auth_remove_token(a_address varchar, a_token uuid, a_service enum_service, a_device_id varchar)
 RETURNS table (
     "user_id" bigint,
-    "role" enum_role
+    "session_id" bigint
 )
 LANGUAGE plpgsql
 AS $$
     
 DECLARE
-    rc_          integer;
-    is_token_ok_ boolean;
-    user_id_     bigint;
-    role_        enum_role;
+  _user_id    bigint;
+  _session_id bigint;
+BEGIN
+  -- Only the session of the device logging out, the other devices stay logged in.
+  UPDATE tbl.user_session AS s
+  SET revoked_at = EXTRACT(EPOCH FROM NOW())::bigint
+  FROM tbl.user_login_wallet AS w
+  WHERE w.fkey_user_id = s.fkey_user_id
+    AND w.address = a_address
+    AND s.access_token = a_token
+    AND s.service = a_service
+    AND s.device_id = a_device_id
+    AND s.revoked_at ISNULL
+  RETURNING s.fkey_user_id, s.pkey_id INTO _user_id, _session_id;
+  IF (_session_id ISNULL) THEN
+    RAISE SQLSTATE 'R000A'; -- InvalidToken
+  END IF;
+  RETURN QUERY SELECT _user_id, _session_id;
+END
+
+$$;
+        
 
+CREATE OR REPLACE FUNCTION api.fun_auth_authorize(a_address varchar, a_token uuid, a_service enum_service, a_device_id varchar, a_device_os varchar, a_ip_address inet)
+RETURNS table (
+    "user_id" bigint,
+    "role" enum_role,
+    "session_id" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+DECLARE
+    rc_                integer;
+    user_id_           bigint;
+    role_              enum_role;
+    session_id_        bigint;
+    access_expires_at_ bigint;
 BEGIN
     ASSERT (a_address NOTNULL AND a_token NOTNULL AND a_service NOTNULL AND
             a_device_id NOTNULL AND a_device_os NOTNULL);
 
-    -- Looking up the user
-    CASE a_service
-        WHEN 'user'::enum_service
-            THEN SELECT pkey_id, u.role, (user_token = a_token)
-                 INTO user_id_, role_, is_token_ok_
-                 FROM tbl.user AS u
-                 WHERE address = a_address;
-        WHEN 'admin'::enum_service
-            THEN SELECT pkey_id, u.role, (admin_token = a_token)
-                 INTO user_id_, role_, is_token_ok_
-                 FROM tbl.user AS u
-                 WHERE address = a_address;
-        ELSE RAISE SQLSTATE 'R0001'; -- InvalidArgument
-        END CASE;
+    -- Looking up the user of any of its login wallets
+    SELECT u.pkey_id, u.role
+    INTO user_id_, role_
+    FROM tbl.user AS u
+    JOIN tbl.user_login_wallet AS w ON w.fkey_user_id = u.pkey_id
+    WHERE w.address = a_address;
     GET DIAGNOSTICS rc_ := ROW_COUNT;
     IF (rc_ <> 1) THEN
         RAISE SQLSTATE 'R0007'; -- UnknownUser
     END IF;
 
+    -- Looking up the session of the device
+    SELECT pkey_id, access_expires_at
+    INTO session_id_, access_expires_at_
+    FROM tbl.user_session
+    WHERE fkey_user_id = user_id_
+      AND service = a_service
+      AND device_id = a_device_id
+      AND access_token = a_token
+      AND revoked_at ISNULL;
+
     -- Log the authorization attempt
     INSERT INTO tbl.authorization_attempt(fkey_user, ip_address, is_token_ok, moment)
-    VALUES (user_id_, a_ip_address, is_token_ok_ NOTNULL AND is_token_ok_, extract(Epoch FROM (NOW()))::bigint);
+    VALUES (user_id_, a_ip_address,
+            session_id_ NOTNULL AND access_expires_at_ >= extract(Epoch FROM (NOW()))::bigint,
+            extract(Epoch FROM (NOW()))::bigint);
     -- COMMIT;
     -- Validating the token
-    IF NOT is_token_ok_ OR is_token_ok_ IS NULL THEN
+    IF session_id_ ISNULL THEN
         RAISE SQLSTATE 'R000A'; -- InvalidToken
+    ELSIF access_expires_at_ < extract(Epoch FROM (NOW()))::bigint THEN
+        RAISE SQLSTATE 'R000L'; -- TokenExpired
     END IF;
 
     -- Updating the device info
-    CASE a_service
-        WHEN 'user'::enum_service
-            THEN UPDATE tbl.user
-                 SET user_device_id = a_device_id
-                 WHERE pkey_id = user_id_
-                   AND user_token = a_token;
-        WHEN 'admin'::enum_service
-            THEN UPDATE tbl.user
-                 SET admin_device_id = a_device_id
-                 WHERE pkey_id = user_id_
-                   AND admin_token = a_token;
-        END CASE;
-    GET DIAGNOSTICS rc_ := ROW_COUNT;
-    ASSERT (rc_ = 1);
-    RETURN QUERY SELECT user_id_, role_;
+    UPDATE tbl.user_session
+    SET device_os    = a_device_os,
+        ip_address   = a_ip_address,
+        last_seen_at = extract(Epoch FROM (NOW()))::bigint
+    WHERE pkey_id = session_id_;
+    RETURN QUERY SELECT user_id_, role_, session_id_;
 END
             
 $$;
@@ -275,10 +362,11 @@ LANGUAGE plpgsql
 AS $$
     
 BEGIN
+    UPDATE tbl.user_login_wallet SET address = a_new_wallet_address
+     WHERE address = a_old_wallet_address;
     UPDATE tbl.user SET address = a_new_wallet_address,
                 updated_at = EXTRACT(EPOCH FROM NOW())::bigint
      WHERE address = a_old_wallet_address;
-    
 END
             
 $$;
@@ -303,6 +391,214 @@ END
 $$;
         
 
+CREATE OR REPLACE FUNCTION api.fun_auth_issue_login_nonce(a_address varchar, a_ip_address inet, a_nonce varchar, a_expires_at bigint)
+RETURNS void
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    -- nonces expired for a day are of no use even for auditing
+    DELETE FROM tbl.login_nonce
+    WHERE expires_at < EXTRACT(EPOCH FROM NOW())::bigint - 86400;
+    INSERT INTO tbl.login_nonce (nonce, address, ip_address, created_at, expires_at)
+    VALUES (a_nonce, a_address, a_ip_address, EXTRACT(EPOCH FROM NOW())::bigint, a_expires_at);
+END
+            
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_auth_consume_login_nonce(a_nonce varchar, a_address varchar, a_ip_address inet)
+RETURNS void
+LANGUAGE plpgsql
+AS $$
+    
+DECLARE
+    _nonce_id   bigint;
+    _expires_at bigint;
+    _used_at    bigint;
+BEGIN
+    SELECT pkey_id, expires_at, used_at
+    INTO _nonce_id, _expires_at, _used_at
+    FROM tbl.login_nonce
+    WHERE nonce = a_nonce
+      AND address = a_address
+      AND ip_address = a_ip_address
+    FOR UPDATE;
+    IF (_nonce_id ISNULL) THEN
+        RAISE SQLSTATE 'R000I'; -- UnknownLoginNonce
+    ELSEIF (_used_at NOTNULL) THEN
+        RAISE SQLSTATE 'R000K'; -- UsedLoginNonce
+    ELSEIF (_expires_at < EXTRACT(EPOCH FROM NOW())::bigint) THEN
+        RAISE SQLSTATE 'R000J'; -- ExpiredLoginNonce
+    END IF;
+    UPDATE tbl.login_nonce
+    SET used_at = EXTRACT(EPOCH FROM NOW())::bigint
+    WHERE pkey_id = _nonce_id;
+END
+            
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_auth_add_failed_login_attempt(a_address varchar, a_ip_address inet, a_device_id varchar DEFAULT NULL, a_device_os varchar DEFAULT NULL)
+RETURNS void
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    INSERT INTO tbl.login_attempt(fkey_user, address, ip_address, device_id, device_os, is_password_ok, moment)
+    VALUES ((SELECT fkey_user_id FROM tbl.user_login_wallet WHERE address = a_address),
+            a_address,
+            a_ip_address,
+            a_device_id,
+            a_device_os,
+            FALSE,
+            EXTRACT(EPOCH FROM NOW())::bigint);
+END
+            
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_auth_authorize_api_key(a_api_key varchar, a_ip_address inet)
+RETURNS table (
+    "user_id" bigint,
+    "role" enum_role,
+    "api_key_id" bigint,
+    "api_secret" varchar,
+    "scopes" enum_api_key_scope[]
+)
+LANGUAGE plpgsql
+AS $$
+    
+DECLARE
+    _api_key_id   bigint;
+    _user_id      bigint;
+    _api_secret   varchar;
+    _scopes       enum_api_key_scope[];
+    _ip_allowlist inet[];
+    _expires_at   bigint;
+    _role         enum_role;
+    _is_blocked   boolean;
+BEGIN
+    SELECT k.pkey_id, k.fkey_user_id, k.api_secret, k.scopes, k.ip_allowlist, k.expires_at, u.role, u.is_blocked
+    INTO _api_key_id, _user_id, _api_secret, _scopes, _ip_allowlist, _expires_at, _role, _is_blocked
+    FROM tbl.user_api_key AS k
+    JOIN tbl.user AS u ON u.pkey_id = k.fkey_user_id
+    WHERE k.api_key = a_api_key
+      AND k.revoked_at ISNULL;
+    IF (_api_key_id ISNULL) THEN
+        RAISE SQLSTATE 'R000A'; -- InvalidToken
+    ELSEIF (_expires_at < EXTRACT(EPOCH FROM NOW())::bigint) THEN
+        RAISE SQLSTATE 'R000L'; -- TokenExpired
+    ELSEIF (_ip_allowlist NOTNULL AND a_ip_address <> ALL (_ip_allowlist)) THEN
+        RAISE SQLSTATE 'R0013'; -- IpNotAllowed
+    ELSEIF (_is_blocked) THEN
+        RAISE SQLSTATE 'R0008'; -- BlockedUser
+    END IF;
+    RETURN QUERY SELECT _user_id, _role, _api_key_id, _api_secret, _scopes;
+END
+            
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_auth_validate_user_session(a_user_id bigint, a_session_id bigint)
+RETURNS table (
+    "role" enum_role
+)
+LANGUAGE plpgsql
+AS $$
+    
+DECLARE
+    _access_expires_at bigint;
+    _role              enum_role;
+BEGIN
+    SELECT s.access_expires_at, u.role
+    INTO _access_expires_at, _role
+    FROM tbl.user_session AS s
+    JOIN tbl.user AS u ON u.pkey_id = s.fkey_user_id
+    WHERE s.pkey_id = a_session_id
+      AND s.fkey_user_id = a_user_id
+      AND s.revoked_at ISNULL;
+    IF (_role ISNULL) THEN
+        RAISE SQLSTATE 'R000A'; -- InvalidToken
+    ELSEIF (_access_expires_at < EXTRACT(EPOCH FROM NOW())::bigint) THEN
+        RAISE SQLSTATE 'R000L'; -- TokenExpired
+    END IF;
+    RETURN QUERY SELECT _role;
+END
+            
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_auth_validate_api_key(a_user_id bigint, a_api_key_id bigint, a_ip_address inet)
+RETURNS table (
+    "role" enum_role,
+    "scopes" enum_api_key_scope[]
+)
+LANGUAGE plpgsql
+AS $$
+    
+DECLARE
+    _scopes       enum_api_key_scope[];
+    _ip_allowlist inet[];
+    _expires_at   bigint;
+    _role         enum_role;
+    _is_blocked   boolean;
+BEGIN
+    SELECT k.scopes, k.ip_allowlist, k.expires_at, u.role, u.is_blocked
+    INTO _scopes, _ip_allowlist, _expires_at, _role, _is_blocked
+    FROM tbl.user_api_key AS k
+    JOIN tbl.user AS u ON u.pkey_id = k.fkey_user_id
+    WHERE k.pkey_id = a_api_key_id
+      AND k.fkey_user_id = a_user_id
+      AND k.revoked_at ISNULL;
+    IF (_role ISNULL) THEN
+        RAISE SQLSTATE 'R000A'; -- InvalidToken
+    ELSEIF (_expires_at < EXTRACT(EPOCH FROM NOW())::bigint) THEN
+        RAISE SQLSTATE 'R000L'; -- TokenExpired
+    ELSEIF (_ip_allowlist NOTNULL AND a_ip_address <> ALL (_ip_allowlist)) THEN
+        RAISE SQLSTATE 'R0013'; -- IpNotAllowed
+    ELSEIF (_is_blocked) THEN
+        RAISE SQLSTATE 'R0008'; -- BlockedUser
+    END IF;
+    RETURN QUERY SELECT _role, _scopes;
+END
+            
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_auth_touch_api_key(a_api_key_id bigint)
+RETURNS void
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    UPDATE tbl.user_api_key
+    SET last_used_at = EXTRACT(EPOCH FROM NOW())::bigint
+    WHERE pkey_id = a_api_key_id;
+END
+            
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_auth_use_api_key_nonce(a_api_key_id bigint, a_nonce varchar, a_timestamp bigint, a_expired_before bigint)
+RETURNS table (
+    "accepted" boolean
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    DELETE FROM tbl.user_api_key_nonce
+    WHERE timestamp < a_expired_before;
+    INSERT INTO tbl.user_api_key_nonce (fkey_api_key_id, nonce, timestamp)
+    VALUES (a_api_key_id, a_nonce, a_timestamp)
+    ON CONFLICT (fkey_api_key_id, nonce) DO NOTHING;
+    RETURN QUERY SELECT FOUND;
+END
+            
+$$;
+        
+
 CREATE OR REPLACE FUNCTION api.fun_user_follow_strategy(a_user_id bigint, a_strategy_id bigint)
 RETURNS table (
     "success" boolean
@@ -602,7 +898,7 @@ END
 $$;
         
 
-CREATE OR REPLACE FUNCTION api.fun_user_back_strategy(a_user_id bigint, a_strategy_id bigint, a_quantity decimal(56, 18), a_new_total_backed_quantity decimal(56, 18), a_old_total_backed_quantity decimal(56, 18), a_new_current_quantity decimal(56, 18), a_old_current_quantity decimal(56, 18), a_blockchain enum_block_chain, a_transaction_hash varchar, a_earn_sp_tokens decimal(56, 18))
+CREATE OR REPLACE FUNCTION api.fun_user_back_strategy(a_user_id bigint, a_strategy_id bigint, a_quantity decimal(56, 18), a_new_total_backed_quantity decimal(56, 18), a_old_total_backed_quantity decimal(56, 18), a_new_current_quantity decimal(56, 18), a_old_current_quantity decimal(56, 18), a_blockchain enum_block_chain, a_transaction_hash varchar, a_earn_sp_tokens decimal(56, 18), a_fees decimal(56, 18))
 RETURNS table (
     "success" boolean
 )
@@ -633,7 +929,8 @@ BEGIN
 			quantity_sp_tokens,
 			transaction_hash,
 			happened_at,
-			is_back
+			is_back,
+			fees
 		) VALUES (
 			a_user_id,
 			a_strategy_id,
@@ -642,7 +939,8 @@ BEGIN
 			a_earn_sp_tokens,
 			a_transaction_hash,
 			extract(epoch from now())::bigint,
-			TRUE);
+			TRUE,
+			a_fees);
     RETURN QUERY SELECT TRUE;
 END
             
@@ -1083,7 +1381,7 @@ DECLARE
 BEGIN
 		SELECT etca.pkey_id INTO _token_id
 		FROM tbl.escrow_token_contract_address AS etca
-		WHERE etca.address = a_token_address AND etca.blockchain = a_blockchain;
+		WHERE lower(etca.address) = lower(a_token_address) AND etca.blockchain = a_blockchain;
 
 		ASSERT _token_id IS NOT NULL;
 
@@ -1144,7 +1442,7 @@ BEGIN
 
 		SELECT etca.pkey_id INTO _token_id
 		FROM tbl.escrow_token_contract_address AS etca
-		WHERE etca.address = a_token_address AND etca.blockchain = a_blockchain;
+		WHERE lower(etca.address) = lower(a_token_address) AND etca.blockchain = a_blockchain;
 
 		ASSERT _token_id IS NOT NULL;
 
@@ -1223,7 +1521,7 @@ AS $$
 BEGIN
     IF a_expert_id ISNULL THEN
         SELECT e.pkey_id INTO STRICT a_expert_id FROM tbl.expert_profile AS e
-         JOIN tbl.user AS u ON e.pkey_id = e.fkey_user_id
+         JOIN tbl.user AS u ON u.pkey_id = e.fkey_user_id
          WHERE u.public_id = a_expert_public_id;
     END IF;
     INSERT INTO tbl.user_follow_expert (fkey_user_id, fkey_expert_id, updated_at, created_at)
@@ -1266,6 +1564,7 @@ RETURNS table (
     "strategy_count" bigint,
     "description" varchar,
     "social_media" varchar,
+    "consistent_score" double precision,
     "risk_score" double precision,
     "reputation_score" double precision,
     "aum" double precision,
@@ -1294,6 +1593,7 @@ AS $$
         (SELECT COUNT(DISTINCT d.fkey_user_id) FROM tbl.strategy AS d WHERE d.fkey_user_id = u.pkey_id) AS strategy_count,
         e.description                                             AS description,
         e.social_media                                            AS social_media,
+        e.consistent_score                                        AS consistent_score,
         e.risk_score                                              AS risk_score,
         e.reputation_score                                        AS reputation_score,
         e.aum                                                     AS aum,
@@ -1319,7 +1619,7 @@ AS $$
 $$;
         
 
-CREATE OR REPLACE FUNCTION api.fun_user_list_experts(a_limit bigint, a_offset bigint, a_user_id bigint, a_sort_by_followers boolean, a_expert_id bigint DEFAULT NULL, a_expert_user_id bigint DEFAULT NULL, a_expert_user_public_id bigint DEFAULT NULL, a_username varchar DEFAULT NULL, a_family_name varchar DEFAULT NULL, a_given_name varchar DEFAULT NULL, a_description varchar DEFAULT NULL, a_social_media varchar DEFAULT NULL)
+CREATE OR REPLACE FUNCTION api.fun_user_list_experts(a_limit bigint, a_offset bigint, a_user_id bigint, a_sort_by enum_expert_sort_by, a_expert_id bigint DEFAULT NULL, a_expert_user_id bigint DEFAULT NULL, a_expert_user_public_id bigint DEFAULT NULL, a_username varchar DEFAULT NULL, a_family_name varchar DEFAULT NULL, a_given_name varchar DEFAULT NULL, a_description varchar DEFAULT NULL, a_social_media varchar DEFAULT NULL)
 RETURNS table (
     "total" bigint,
     "expert_id" bigint,
@@ -1334,6 +1634,7 @@ RETURNS table (
     "strategy_count" bigint,
     "description" varchar,
     "social_media" varchar,
+    "consistent_score" double precision,
     "risk_score" double precision,
     "reputation_score" double precision,
     "aum" double precision,
@@ -1362,6 +1663,7 @@ BEGIN
         (SELECT COUNT(DISTINCT d.fkey_user_id) FROM tbl.strategy AS d WHERE d.fkey_user_id = u.pkey_id) AS strategy_count,
         e.description                                             AS description,
         e.social_media                                            AS social_media,
+        e.consistent_score                                        AS consistent_score,
         e.risk_score                                              AS risk_score,
         e.reputation_score                                        AS reputation_score,
         e.aum                                                     AS aum,
@@ -1383,10 +1685,13 @@ BEGIN
                         AND (a_given_name ISNULL OR u.given_name ILIKE a_given_name || '%')
                         AND (a_description ISNULL OR e.description ILIKE a_description || '%')
                         AND (a_social_media ISNULL OR e.social_media ILIKE a_social_media || '%')
-                 ORDER BY CASE 
-                  WHEN a_sort_by_followers = TRUE THEN follower_count 
-                  ELSE e.pkey_id
-                 END DESC
+                 ORDER BY
+                    (CASE WHEN a_sort_by = 'Followers' THEN
+                        (SELECT COUNT(DISTINCT d.fkey_user_id) FROM tbl.user_follow_expert AS d WHERE d.fkey_expert_id = e.pkey_id AND d.unfollowed = FALSE)
+                    END) DESC NULLS LAST,
+                    (CASE WHEN a_sort_by = 'Reputation' THEN e.reputation_score END) DESC NULLS LAST,
+                    (CASE WHEN a_sort_by = 'Consistency' THEN e.consistent_score END) DESC NULLS LAST,
+                    e.pkey_id DESC
                  OFFSET a_offset
                  LIMIT a_limit
                  ;
@@ -1410,6 +1715,7 @@ RETURNS table (
     "strategy_count" bigint,
     "description" varchar,
     "social_media" varchar,
+    "consistent_score" double precision,
     "risk_score" double precision,
     "reputation_score" double precision,
     "aum" double precision,
@@ -1439,6 +1745,7 @@ BEGIN
         (SELECT COUNT(DISTINCT d.fkey_user_id) FROM tbl.strategy AS d WHERE d.fkey_user_id = u.pkey_id) AS strategy_count,
         e.description                                             AS description,
         e.social_media                                            AS social_media,
+        e.consistent_score                                        AS consistent_score,
         e.risk_score                                              AS risk_score,
         e.reputation_score                                        AS reputation_score,
         e.aum                                                     AS aum,
@@ -1542,7 +1849,28 @@ AS $$
     
 DECLARE
     _expert_id bigint;
+    _application_id bigint;
+    _status enum_expert_application_status;
 BEGIN
+    SELECT pkey_id, status INTO _application_id, _status
+    FROM tbl.expert_application
+    WHERE fkey_user_id = a_user_id
+    FOR UPDATE;
+    IF _application_id IS NULL THEN
+        INSERT INTO tbl.expert_application(fkey_user_id, status, questionnaire, updated_at, created_at)
+        VALUES (a_user_id, 'Draft', '{}', extract(epoch from now())::bigint, extract(epoch from now())::bigint)
+        RETURNING pkey_id INTO _application_id;
+    ELSIF _status NOT IN ('Draft', 'InfoRequested', 'Rejected') THEN
+        RAISE SQLSTATE 'R001A'; -- InvalidExpertApplicationState
+    END IF;
+    UPDATE tbl.expert_application
+    SET status = 'Submitted',
+        submitted_at = extract(epoch from now())::bigint,
+        updated_at = extract(epoch from now())::bigint
+    WHERE pkey_id = _application_id;
+    INSERT INTO tbl.expert_application_note(fkey_application_id, fkey_author_user_id, status, created_at)
+    VALUES (_application_id, a_user_id, 'Submitted', extract(epoch from now())::bigint);
+
     IF NOT EXISTS(SELECT * FROM tbl.expert_profile WHERE fkey_user_id = a_user_id) THEN
         INSERT INTO tbl.expert_profile(fkey_user_id, pending_expert, requested_at, updated_at, created_at)
         VALUES(a_user_id, TRUE, extract(epoch from now())::bigint, extract(epoch from now())::bigint, extract(epoch from now())::bigint)
@@ -1561,6 +1889,172 @@ END
 $$;
         
 
+CREATE OR REPLACE FUNCTION api.fun_user_save_expert_application(a_user_id bigint, a_questionnaire jsonb)
+RETURNS table (
+    "application_id" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+DECLARE
+    _application_id bigint;
+    _status enum_expert_application_status;
+BEGIN
+    SELECT pkey_id, status INTO _application_id, _status
+    FROM tbl.expert_application
+    WHERE fkey_user_id = a_user_id
+    FOR UPDATE;
+    IF _application_id IS NULL THEN
+        INSERT INTO tbl.expert_application(fkey_user_id, status, questionnaire, updated_at, created_at)
+        VALUES (a_user_id, 'Draft', a_questionnaire, extract(epoch from now())::bigint, extract(epoch from now())::bigint)
+        RETURNING pkey_id INTO _application_id;
+    ELSIF _status NOT IN ('Draft', 'InfoRequested', 'Rejected') THEN
+        RAISE SQLSTATE 'R001A'; -- InvalidExpertApplicationState
+    ELSE
+        -- editing a rejected application starts a new draft for resubmission
+        UPDATE tbl.expert_application
+        SET questionnaire = a_questionnaire,
+            status = CASE WHEN _status = 'Rejected' THEN 'Draft' ELSE _status END,
+            updated_at = extract(epoch from now())::bigint
+        WHERE pkey_id = _application_id;
+    END IF;
+    RETURN QUERY SELECT _application_id;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_user_add_expert_application_document(a_user_id bigint, a_name varchar, a_content_type varchar, a_size bigint, a_blob_key varchar)
+RETURNS table (
+    "document_id" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+DECLARE
+    _application_id bigint;
+BEGIN
+    SELECT pkey_id INTO _application_id
+    FROM tbl.expert_application
+    WHERE fkey_user_id = a_user_id
+      AND status IN ('Draft', 'InfoRequested', 'Rejected')
+    FOR UPDATE;
+    IF _application_id IS NULL THEN
+        RAISE SQLSTATE 'R001A'; -- InvalidExpertApplicationState
+    END IF;
+    RETURN QUERY INSERT INTO tbl.expert_application_document(fkey_application_id, name, content_type, size, blob_key, created_at)
+    VALUES (_application_id, a_name, a_content_type, a_size, a_blob_key, extract(epoch from now())::bigint)
+    RETURNING pkey_id;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_user_delete_expert_application_document(a_user_id bigint, a_document_id bigint)
+RETURNS table (
+    "blob_key" varchar
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    IF NOT EXISTS(SELECT * FROM tbl.expert_application
+                  WHERE fkey_user_id = a_user_id
+                    AND status IN ('Draft', 'InfoRequested', 'Rejected')) THEN
+        RAISE SQLSTATE 'R001A'; -- InvalidExpertApplicationState
+    END IF;
+    RETURN QUERY DELETE FROM tbl.expert_application_document d
+    USING tbl.expert_application a
+    WHERE d.pkey_id = a_document_id
+      AND d.fkey_application_id = a.pkey_id
+      AND a.fkey_user_id = a_user_id
+    RETURNING d.blob_key;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_user_get_expert_application(a_user_id bigint DEFAULT NULL, a_user_public_id bigint DEFAULT NULL)
+RETURNS table (
+    "application_id" bigint,
+    "user_public_id" bigint,
+    "status" enum_expert_application_status,
+    "questionnaire" jsonb,
+    "submitted_at" bigint,
+    "reviewed_at" bigint,
+    "updated_at" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT a.pkey_id,
+                        u.public_id,
+                        a.status,
+                        a.questionnaire,
+                        a.submitted_at,
+                        a.reviewed_at,
+                        a.updated_at
+                 FROM tbl.expert_application AS a
+                   JOIN tbl.user AS u ON u.pkey_id = a.fkey_user_id
+                 WHERE (a_user_id ISNULL OR u.pkey_id = a_user_id)
+                   AND (a_user_public_id ISNULL OR u.public_id = a_user_public_id);
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_user_list_expert_application_documents(a_application_id bigint)
+RETURNS table (
+    "document_id" bigint,
+    "name" varchar,
+    "content_type" varchar,
+    "size" bigint,
+    "blob_key" varchar,
+    "created_at" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT d.pkey_id,
+                        d.name,
+                        d.content_type,
+                        d.size,
+                        d.blob_key,
+                        d.created_at
+                 FROM tbl.expert_application_document AS d
+                 WHERE d.fkey_application_id = a_application_id
+                 ORDER BY d.pkey_id;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_user_list_expert_application_notes(a_application_id bigint)
+RETURNS table (
+    "status" enum_expert_application_status,
+    "note" varchar,
+    "from_reviewer" boolean,
+    "created_at" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT n.status,
+                        n.note,
+                        n.fkey_author_user_id <> a.fkey_user_id,
+                        n.created_at
+                 FROM tbl.expert_application_note AS n
+                   JOIN tbl.expert_application AS a ON a.pkey_id = n.fkey_application_id
+                 WHERE n.fkey_application_id = a_application_id
+                 ORDER BY n.pkey_id;
+END
+
+$$;
+        
+
 CREATE OR REPLACE FUNCTION api.fun_user_create_strategy(a_user_id bigint, a_name varchar, a_description varchar, a_strategy_thesis_url varchar, a_minimum_backing_amount_usd double precision, a_swap_fee double precision, a_expert_fee double precision, a_agreed_tos boolean, a_wallet_address varchar, a_blockchain enum_block_chain)
 RETURNS table (
     "success" boolean,
@@ -1654,7 +2148,7 @@ END
 $$;
         
 
-CREATE OR REPLACE FUNCTION api.fun_user_update_strategy(a_user_id bigint, a_strategy_id bigint, a_name varchar DEFAULT NULL, a_description varchar DEFAULT NULL, a_social_media varchar DEFAULT NULL)
+CREATE OR REPLACE FUNCTION api.fun_user_update_strategy(a_user_id bigint, a_strategy_id bigint, a_name varchar DEFAULT NULL, a_description varchar DEFAULT NULL, a_social_media varchar DEFAULT NULL, a_rebalance_interval_seconds bigint DEFAULT NULL, a_rebalance_drift_threshold double precision DEFAULT NULL, a_copy_trade_min_usd double precision DEFAULT NULL, a_copy_trade_max_aum_ratio double precision DEFAULT NULL, a_copy_trade_cooldown_seconds bigint DEFAULT NULL, a_copy_trade_batch_window_seconds bigint DEFAULT NULL, a_expert_fee double precision DEFAULT NULL)
 RETURNS table (
     "success" boolean
 )
@@ -1666,7 +2160,14 @@ BEGIN
     UPDATE tbl.strategy
     SET name = COALESCE(a_name, name),
         description = COALESCE(a_description, description),
-        social_media = COALESCE(a_social_media, social_media)
+        social_media = COALESCE(a_social_media, social_media),
+        rebalance_interval_seconds = NULLIF(COALESCE(a_rebalance_interval_seconds, rebalance_interval_seconds), 0),
+        rebalance_drift_threshold = COALESCE(a_rebalance_drift_threshold, rebalance_drift_threshold),
+        copy_trade_min_usd = NULLIF(COALESCE(a_copy_trade_min_usd, copy_trade_min_usd), 0),
+        copy_trade_max_aum_ratio = NULLIF(COALESCE(a_copy_trade_max_aum_ratio, copy_trade_max_aum_ratio), 0),
+        copy_trade_cooldown_seconds = NULLIF(COALESCE(a_copy_trade_cooldown_seconds, copy_trade_cooldown_seconds), 0),
+        copy_trade_batch_window_seconds = NULLIF(COALESCE(a_copy_trade_batch_window_seconds, copy_trade_batch_window_seconds), 0),
+        expert_fee = COALESCE(a_expert_fee, expert_fee)
     WHERE pkey_id = a_strategy_id
       AND fkey_user_id = a_user_id;
     RETURN QUERY SELECT TRUE;
@@ -1890,7 +2391,7 @@ END
 $$;
         
 
-CREATE OR REPLACE FUNCTION api.fun_user_add_user_deposit_withdraw_ledger_entry(a_user_id bigint, a_token_address varchar, a_blockchain enum_block_chain, a_user_address varchar, a_escrow_contract_address varchar, a_receiver_address varchar, a_quantity decimal(56, 18), a_transaction_hash varchar, a_is_deposit boolean, a_is_back boolean, a_is_withdraw boolean)
+CREATE OR REPLACE FUNCTION api.fun_user_add_user_deposit_withdraw_ledger_entry(a_user_id bigint, a_token_address varchar, a_blockchain enum_block_chain, a_user_address varchar, a_escrow_contract_address varchar, a_receiver_address varchar, a_quantity decimal(56, 18), a_transaction_hash varchar, a_is_deposit boolean, a_is_back boolean, a_is_withdraw boolean, a_is_fee boolean)
 RETURNS table (
     "ledger_entry_id" bigint
 )
@@ -1912,10 +2413,10 @@ BEGIN
 	END IF;
 
 	SELECT etca.pkey_id INTO _token_id FROM tbl.escrow_token_contract_address AS etca
-		WHERE etca.address = a_token_address AND etca.blockchain = a_blockchain;
+		WHERE lower(etca.address) = lower(a_token_address) AND etca.blockchain = a_blockchain;
 
 	SELECT eca.pkey_id INTO _escrow_contract_id FROM tbl.escrow_contract_address AS eca
-		WHERE eca.address = a_escrow_contract_address AND eca.blockchain = a_blockchain;
+		WHERE lower(eca.address) = lower(a_escrow_contract_address) AND eca.blockchain = a_blockchain;
 
 	ASSERT _token_id IS NOT NULL AND _escrow_contract_id IS NOT NULL;
 
@@ -1932,6 +2433,7 @@ BEGIN
 		is_deposit,
 		is_back,
 		is_withdraw,
+		is_fee,
 		happened_at
 		) VALUES (a_user_id,
 							_token_id,
@@ -1945,6 +2447,7 @@ BEGIN
 							a_is_deposit,
 							a_is_back,
 							a_is_withdraw,
+							a_is_fee,
 							EXTRACT(EPOCH FROM NOW())::bigint
 		) RETURNING pkey_id;
 
@@ -2243,8 +2746,8 @@ AS $$
 DECLARE
 		_user_id BIGINT;
 BEGIN
-		-- search for user id in user table by address
-		SELECT pkey_id INTO _user_id FROM tbl.user AS a WHERE a.address = a_address;
+		-- search for user id by any of its login wallets
+		SELECT fkey_user_id INTO _user_id FROM tbl.user_login_wallet AS a WHERE a.address = a_address;
 
 		-- if address is not registered in user table, search for a whitelisted wallet
 		IF _user_id IS NULL THEN
@@ -2273,6 +2776,22 @@ END
 $$;
         
 
+CREATE OR REPLACE FUNCTION api.fun_user_get_user_by_login_wallet(a_address varchar)
+RETURNS table (
+    "user_id" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT a.fkey_user_id
+    FROM tbl.user_login_wallet AS a
+    WHERE a.address = a_address;
+END
+            
+$$;
+        
+
 CREATE OR REPLACE FUNCTION api.fun_user_add_strategy_wallet(a_user_id bigint, a_blockchain enum_block_chain, a_address varchar, a_is_platform_managed boolean)
 RETURNS void
 LANGUAGE plpgsql
@@ -2524,29 +3043,99 @@ END
 $$;
         
 
-CREATE OR REPLACE FUNCTION api.fun_user_add_strategy_pool_contract(a_strategy_id bigint, a_blockchain enum_block_chain, a_address varchar)
+CREATE OR REPLACE FUNCTION api.fun_user_debit_user_deposit_withdraw_balance(a_deposit_withdraw_balance_id bigint, a_quantity decimal(56, 18))
 RETURNS table (
-    "strategy_pool_contract_id" bigint
+    "balance" decimal(56, 18)
 )
 LANGUAGE plpgsql
 AS $$
     
 BEGIN
-    UPDATE tbl.strategy
-    SET strategy_pool_address = a_address
-    WHERE pkey_id = a_strategy_id
-        AND strategy_pool_address ISNULL;
-    RETURN QUERY INSERT INTO tbl.strategy_pool_contract (fkey_strategy_id, blockchain, address, created_at)
-    VALUES (a_strategy_id, a_blockchain, a_address, EXTRACT(EPOCH FROM NOW()))
-    RETURNING pkey_id;
+    RETURN QUERY UPDATE tbl.user_deposit_withdraw_balance AS b
+                 SET balance = b.balance - a_quantity
+                 WHERE b.pkey_id = a_deposit_withdraw_balance_id
+                    AND b.balance >= a_quantity
+                 RETURNING b.balance;
 END
-        
+
 $$;
         
 
-CREATE OR REPLACE FUNCTION api.fun_user_list_escrow_token_contract_address(a_limit bigint, a_offset bigint, a_token_id bigint DEFAULT NULL, a_blockchain enum_block_chain DEFAULT NULL, a_address varchar DEFAULT NULL, a_symbol varchar DEFAULT NULL, a_is_stablecoin boolean DEFAULT NULL)
-RETURNS table (
-    "total" bigint,
+CREATE OR REPLACE FUNCTION api.fun_user_credit_user_deposit_withdraw_balance(a_deposit_withdraw_balance_id bigint, a_quantity decimal(56, 18))
+RETURNS void
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    UPDATE tbl.user_deposit_withdraw_balance
+    SET balance = balance + a_quantity
+    WHERE pkey_id = a_deposit_withdraw_balance_id;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_user_add_pending_withdraw(a_user_id bigint, a_deposit_withdraw_balance_id bigint, a_blockchain enum_block_chain, a_token_address varchar, a_user_address varchar, a_escrow_contract_address varchar, a_receiver_address varchar, a_quantity decimal(56, 18), a_transaction_hash varchar, a_is_fee boolean)
+RETURNS table (
+    "pending_withdraw_id" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY INSERT INTO tbl.user_pending_withdraw (
+        fkey_user_id,
+        fkey_deposit_withdraw_balance_id,
+        blockchain,
+        token_address,
+        user_address,
+        escrow_contract_address,
+        receiver_address,
+        quantity,
+        transaction_hash,
+        is_fee,
+        created_at
+    ) VALUES (
+        a_user_id,
+        a_deposit_withdraw_balance_id,
+        a_blockchain,
+        a_token_address,
+        a_user_address,
+        a_escrow_contract_address,
+        a_receiver_address,
+        a_quantity,
+        a_transaction_hash,
+        a_is_fee,
+        EXTRACT(EPOCH FROM NOW())::bigint
+    ) RETURNING pkey_id;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_user_add_strategy_pool_contract(a_strategy_id bigint, a_blockchain enum_block_chain, a_address varchar)
+RETURNS table (
+    "strategy_pool_contract_id" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    UPDATE tbl.strategy
+    SET strategy_pool_address = a_address
+    WHERE pkey_id = a_strategy_id
+        AND strategy_pool_address ISNULL;
+    RETURN QUERY INSERT INTO tbl.strategy_pool_contract (fkey_strategy_id, blockchain, address, created_at)
+    VALUES (a_strategy_id, a_blockchain, a_address, EXTRACT(EPOCH FROM NOW()))
+    RETURNING pkey_id;
+END
+        
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_user_list_escrow_token_contract_address(a_limit bigint, a_offset bigint, a_token_id bigint DEFAULT NULL, a_blockchain enum_block_chain DEFAULT NULL, a_address varchar DEFAULT NULL, a_symbol varchar DEFAULT NULL, a_is_stablecoin boolean DEFAULT NULL)
+RETURNS table (
+    "total" bigint,
     "token_id" bigint,
     "blockchain" enum_block_chain,
     "address" varchar,
@@ -2716,6 +3305,795 @@ END
 $$;
         
 
+CREATE OR REPLACE FUNCTION api.fun_user_list_user_activity_report(a_user_id bigint, a_start_time bigint, a_end_time bigint, a_limit bigint, a_offset bigint, a_blockchain enum_block_chain DEFAULT NULL)
+RETURNS table (
+    "total" bigint,
+    "event_type" enum_user_activity_event_type,
+    "blockchain" enum_block_chain,
+    "strategy_id" bigint,
+    "transaction_hash" varchar,
+    "token_symbol" varchar,
+    "token_address" varchar,
+    "quantity" decimal(56, 18),
+    "usd_price" double precision,
+    "usd_value" double precision,
+    "fees" decimal(56, 18),
+    "happened_at" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT
+        count(*) OVER() AS total,
+        e.event_type,
+        e.blockchain,
+        e.strategy_id,
+        e.transaction_hash,
+        e.token_symbol,
+        e.token_address,
+        e.quantity,
+        p.price,
+        (e.quantity::double precision * p.price)::double precision,
+        e.fees,
+        e.happened_at
+    FROM (
+        -- deposits, withdrawals and their gas fees, refunds and escrow spent on backing
+        SELECT
+            (CASE WHEN a.is_deposit THEN 'Deposit'
+                  WHEN a.is_back THEN 'EscrowSpent'
+                  WHEN a.is_withdraw AND a.is_fee THEN 'WithdrawFee'
+                  WHEN a.is_withdraw THEN 'Withdraw'
+                  ELSE 'Refund' END)::enum_user_activity_event_type AS event_type,
+            a.blockchain,
+            NULL::bigint AS strategy_id,
+            NULLIF(a.transaction_hash, '')::varchar AS transaction_hash,
+            t.symbol::varchar AS token_symbol,
+            t.address::varchar AS token_address,
+            t.is_stablecoin,
+            a.quantity,
+            0::decimal(56, 18) AS fees,
+            a.happened_at,
+            a.pkey_id AS event_id
+        FROM tbl.user_deposit_withdraw_ledger AS a
+        JOIN tbl.escrow_token_contract_address AS t ON t.pkey_id = a.fkey_token_id
+        WHERE a.fkey_user_id = a_user_id
+        UNION ALL
+        -- backing and exiting strategies, always denominated in USDC
+        SELECT
+            (CASE WHEN a.is_back THEN 'BackStrategy'
+                  ELSE 'ExitStrategy' END)::enum_user_activity_event_type,
+            a.blockchain,
+            a.fkey_strategy_id,
+            a.transaction_hash::varchar,
+            'USDC'::varchar,
+            t.address::varchar,
+            TRUE,
+            a.quantity_of_usdc,
+            -- the fees charged when the strategy was backed
+            a.fees,
+            a.happened_at,
+            a.pkey_id
+        FROM tbl.user_back_exit_strategy_ledger AS a
+        LEFT JOIN tbl.escrow_token_contract_address AS t ON t.blockchain = a.blockchain AND t.symbol = 'USDC'
+        WHERE a.fkey_user_id = a_user_id
+        UNION ALL
+        -- assets moved in and out of strategy pools on behalf of the user's strategy wallet
+        SELECT
+            (CASE WHEN a.is_add THEN 'PoolAssetAdd'
+                  ELSE 'PoolAssetRemove' END)::enum_user_activity_event_type,
+            w.blockchain,
+            spc.fkey_strategy_id,
+            NULL::varchar,
+            t.symbol::varchar,
+            t.address::varchar,
+            t.is_stablecoin,
+            a.amount,
+            0::decimal(56, 18),
+            a.happened_at,
+            a.pkey_id
+        FROM tbl.user_strategy_pool_contract_asset_ledger AS a
+        JOIN tbl.user_strategy_wallet AS w ON w.pkey_id = a.fkey_strategy_wallet_id
+        JOIN tbl.strategy_pool_contract AS spc ON spc.pkey_id = a.fkey_strategy_pool_contract_id
+        JOIN tbl.escrow_token_contract_address AS t ON t.pkey_id = a.fkey_token_id
+        WHERE w.fkey_user_id = a_user_id
+    ) AS e
+    -- price of the token at the time of the event, stablecoins fall back to 1 USD
+    LEFT JOIN LATERAL (
+        SELECT COALESCE(
+            (SELECT tp.price FROM tbl.token_price AS tp
+                WHERE tp.symbol = e.token_symbol AND tp.created_at <= e.happened_at
+                ORDER BY tp.created_at DESC
+                LIMIT 1),
+            CASE WHEN e.is_stablecoin THEN 1.0::double precision END
+        ) AS price
+    ) AS p ON TRUE
+    WHERE e.happened_at >= a_start_time
+        AND e.happened_at < a_end_time
+        AND (a_blockchain ISNULL OR e.blockchain = a_blockchain)
+    -- every event type comes from a single ledger, so its id makes the order total
+    ORDER BY e.happened_at, e.event_type, e.event_id
+    OFFSET a_offset
+    LIMIT a_limit;
+END
+            
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_expert_add_strategy_guard_rule(a_strategy_id bigint, a_rule_type enum_strategy_guard_rule_type, a_threshold double precision, a_token_id bigint DEFAULT NULL, a_exit_token_id bigint DEFAULT NULL)
+RETURNS table (
+    "rule_id" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY INSERT INTO tbl.strategy_guard_rule (
+        fkey_strategy_id,
+        rule_type,
+        fkey_token_id,
+        threshold,
+        fkey_exit_token_id,
+        created_at
+    ) VALUES (
+        a_strategy_id,
+        a_rule_type,
+        a_token_id,
+        a_threshold,
+        a_exit_token_id,
+        EXTRACT(EPOCH FROM NOW())::bigint
+    ) RETURNING pkey_id;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_expert_remove_strategy_guard_rule(a_strategy_id bigint, a_rule_id bigint)
+RETURNS table (
+    "success" boolean
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    DELETE FROM tbl.strategy_guard_rule_reference AS r
+    USING tbl.strategy_guard_rule AS g
+    WHERE r.fkey_strategy_guard_rule_id = g.pkey_id
+        AND g.pkey_id = a_rule_id AND g.fkey_strategy_id = a_strategy_id;
+    DELETE FROM tbl.strategy_guard_rule
+    WHERE pkey_id = a_rule_id AND fkey_strategy_id = a_strategy_id;
+    RETURN QUERY SELECT FOUND;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_user_list_strategy_guard_rules(a_strategy_id bigint)
+RETURNS table (
+    "total" bigint,
+    "rule_id" bigint,
+    "rule_type" enum_strategy_guard_rule_type,
+    "token_id" bigint,
+    "token_symbol" varchar,
+    "threshold" double precision,
+    "reference_value" double precision,
+    "exit_token_id" bigint,
+    "exit_token_symbol" varchar,
+    "triggered_at" bigint,
+    "created_at" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT
+        COUNT(*) OVER() AS total,
+        a.pkey_id,
+        a.rule_type,
+        a.fkey_token_id,
+        t.symbol,
+        a.threshold,
+        -- share value the rule is measured from, on the strategy's first pool
+        (SELECT r.reference_value
+         FROM tbl.strategy_guard_rule_reference AS r
+         WHERE r.fkey_strategy_guard_rule_id = a.pkey_id
+         ORDER BY r.fkey_strategy_pool_contract_id
+         LIMIT 1),
+        a.fkey_exit_token_id,
+        et.symbol,
+        a.triggered_at,
+        a.created_at
+    FROM tbl.strategy_guard_rule AS a
+    LEFT JOIN tbl.escrow_token_contract_address AS t ON t.pkey_id = a.fkey_token_id
+    LEFT JOIN tbl.escrow_token_contract_address AS et ON et.pkey_id = a.fkey_exit_token_id
+    WHERE a.fkey_strategy_id = a_strategy_id
+    ORDER BY a.pkey_id;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_expert_resume_strategy_copy_trading(a_strategy_id bigint)
+RETURNS void
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    UPDATE tbl.strategy
+    SET copy_trading_paused = FALSE
+    WHERE pkey_id = a_strategy_id;
+    -- triggered rules are re-armed against fresh reference values
+    DELETE FROM tbl.strategy_guard_rule_reference AS r
+    USING tbl.strategy_guard_rule AS g
+    WHERE r.fkey_strategy_guard_rule_id = g.pkey_id
+        AND g.fkey_strategy_id = a_strategy_id AND g.triggered_at IS NOT NULL;
+    UPDATE tbl.strategy_guard_rule
+    SET triggered_at = NULL
+    WHERE fkey_strategy_id = a_strategy_id AND triggered_at IS NOT NULL;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_user_list_strategy_guard_notification_recipients(a_strategy_id bigint)
+RETURNS table (
+    "user_id" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT s.fkey_user_id
+    FROM tbl.strategy AS s
+    WHERE s.pkey_id = a_strategy_id AND s.fkey_user_id IS NOT NULL
+    UNION
+    SELECT usw.fkey_user_id
+    FROM tbl.user_strategy_balance AS usb
+    JOIN tbl.strategy_pool_contract AS spc ON spc.pkey_id = usb.fkey_strategy_pool_contract_id
+    JOIN tbl.user_strategy_wallet AS usw ON usw.pkey_id = usb.fkey_user_strategy_wallet_id
+    WHERE spc.fkey_strategy_id = a_strategy_id AND usb.balance > 0;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_user_add_notifications(a_user_ids bigint[], a_kind enum_user_notification_kind, a_message varchar, a_strategy_id bigint DEFAULT NULL, a_event_key varchar DEFAULT NULL)
+RETURNS table (
+    "notification_id" bigint,
+    "user_id" bigint,
+    "created_at" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    IF a_event_key ISNULL THEN
+        RETURN QUERY INSERT INTO tbl.user_notification AS n (
+            fkey_user_id,
+            kind,
+            fkey_strategy_id,
+            message,
+            created_at
+        ) SELECT
+            u.user_id,
+            a_kind,
+            a_strategy_id,
+            a_message,
+            EXTRACT(EPOCH FROM NOW())::bigint
+        FROM UNNEST(a_user_ids) AS u(user_id)
+        RETURNING n.pkey_id, n.fkey_user_id, n.created_at;
+        RETURN;
+    END IF;
+    -- the first caller stores the notifications of the event, the others get the stored rows
+    INSERT INTO tbl.user_notification (
+        fkey_user_id,
+        kind,
+        fkey_strategy_id,
+        message,
+        created_at,
+        event_key
+    ) SELECT
+        u.user_id,
+        a_kind,
+        a_strategy_id,
+        a_message,
+        EXTRACT(EPOCH FROM NOW())::bigint,
+        a_event_key
+    FROM UNNEST(a_user_ids) AS u(user_id)
+    ON CONFLICT (fkey_user_id, event_key) DO NOTHING;
+    RETURN QUERY SELECT n.pkey_id, n.fkey_user_id, n.created_at
+    FROM tbl.user_notification AS n
+    WHERE n.event_key = a_event_key AND n.fkey_user_id = ANY(a_user_ids);
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_user_list_notifications(a_user_id bigint, a_unread_only boolean, a_limit bigint, a_offset bigint)
+RETURNS table (
+    "total" bigint,
+    "unread_total" bigint,
+    "notification_id" bigint,
+    "kind" enum_user_notification_kind,
+    "strategy_id" bigint,
+    "message" varchar,
+    "read_at" bigint,
+    "created_at" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT
+        COUNT(*) OVER() AS total,
+        COUNT(*) FILTER (WHERE a.read_at ISNULL) OVER() AS unread_total,
+        a.pkey_id,
+        a.kind,
+        a.fkey_strategy_id,
+        a.message,
+        a.read_at,
+        a.created_at
+    FROM tbl.user_notification AS a
+    WHERE a.fkey_user_id = a_user_id
+        AND (NOT a_unread_only OR a.read_at ISNULL)
+    ORDER BY a.pkey_id DESC
+    LIMIT a_limit
+    OFFSET a_offset;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_user_mark_notifications_read(a_user_id bigint, a_notification_ids bigint[] DEFAULT NULL)
+RETURNS table (
+    "marked" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+DECLARE
+    _marked bigint;
+BEGIN
+    UPDATE tbl.user_notification
+    SET read_at = EXTRACT(EPOCH FROM NOW())::bigint
+    WHERE fkey_user_id = a_user_id
+        AND read_at ISNULL
+        AND (a_notification_ids ISNULL OR pkey_id = ANY(a_notification_ids));
+    GET DIAGNOSTICS _marked = ROW_COUNT;
+    RETURN QUERY SELECT _marked;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_user_list_expert_follower_ids(a_expert_user_id bigint)
+RETURNS table (
+    "user_id" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT DISTINCT ufe.fkey_user_id
+    FROM tbl.user_follow_expert AS ufe
+    JOIN tbl.expert_profile AS ep ON ep.pkey_id = ufe.fkey_expert_id
+    WHERE ep.fkey_user_id = a_expert_user_id AND NOT ufe.unfollowed;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_user_list_sessions(a_user_id bigint)
+RETURNS table (
+    "session_id" bigint,
+    "service" enum_service,
+    "device_id" varchar,
+    "device_os" varchar,
+    "ip_address" inet,
+    "created_at" bigint,
+    "last_seen_at" bigint,
+    "expires_at" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT
+        a.pkey_id,
+        a.service,
+        a.device_id,
+        a.device_os,
+        a.ip_address,
+        a.created_at,
+        a.last_seen_at,
+        a.refresh_expires_at
+    FROM tbl.user_session AS a
+    WHERE a.fkey_user_id = a_user_id
+        AND a.revoked_at ISNULL
+        AND a.refresh_expires_at >= EXTRACT(EPOCH FROM NOW())::bigint
+    ORDER BY a.last_seen_at DESC;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_user_revoke_session(a_user_id bigint, a_session_id bigint)
+RETURNS table (
+    "revoked" boolean
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    UPDATE tbl.user_session
+    SET revoked_at = EXTRACT(EPOCH FROM NOW())::bigint
+    WHERE pkey_id = a_session_id
+        AND fkey_user_id = a_user_id
+        AND revoked_at ISNULL;
+    RETURN QUERY SELECT FOUND;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_user_create_api_key(a_user_id bigint, a_name varchar, a_api_key varchar, a_api_secret varchar, a_scopes enum_api_key_scope[], a_ip_allowlist inet[] DEFAULT NULL, a_expires_at bigint DEFAULT NULL)
+RETURNS table (
+    "api_key_id" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY INSERT INTO tbl.user_api_key (
+        fkey_user_id,
+        name,
+        api_key,
+        api_secret,
+        scopes,
+        ip_allowlist,
+        expires_at,
+        created_at
+    ) VALUES (
+        a_user_id,
+        a_name,
+        a_api_key,
+        a_api_secret,
+        a_scopes,
+        a_ip_allowlist,
+        a_expires_at,
+        EXTRACT(EPOCH FROM NOW())::bigint
+    ) RETURNING pkey_id;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_user_list_api_keys(a_user_id bigint)
+RETURNS table (
+    "api_key_id" bigint,
+    "name" varchar,
+    "api_key" varchar,
+    "scopes" enum_api_key_scope[],
+    "ip_allowlist" inet[],
+    "expires_at" bigint,
+    "created_at" bigint,
+    "last_used_at" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT
+        a.pkey_id,
+        a.name,
+        a.api_key,
+        a.scopes,
+        a.ip_allowlist,
+        a.expires_at,
+        a.created_at,
+        a.last_used_at
+    FROM tbl.user_api_key AS a
+    WHERE a.fkey_user_id = a_user_id
+        AND a.revoked_at ISNULL
+    ORDER BY a.pkey_id DESC;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_user_revoke_api_key(a_user_id bigint, a_api_key_id bigint)
+RETURNS table (
+    "revoked" boolean
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    UPDATE tbl.user_api_key
+    SET revoked_at = EXTRACT(EPOCH FROM NOW())::bigint
+    WHERE pkey_id = a_api_key_id
+        AND fkey_user_id = a_user_id
+        AND revoked_at ISNULL;
+    RETURN QUERY SELECT FOUND;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_user_list_login_wallets(a_user_id bigint)
+RETURNS table (
+    "address" varchar,
+    "is_primary" boolean,
+    "created_at" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT a.address, a.is_primary, a.created_at
+    FROM tbl.user_login_wallet AS a
+    WHERE a.fkey_user_id = a_user_id
+    ORDER BY a.is_primary DESC, a.pkey_id;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_user_link_login_wallet(a_user_id bigint, a_address varchar)
+RETURNS void
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    IF EXISTS(SELECT 1 FROM tbl.user_login_wallet WHERE address = a_address) THEN
+        RAISE SQLSTATE 'R000Z'; -- UsernameAlreadyRegistered
+    END IF;
+    INSERT INTO tbl.user_login_wallet (fkey_user_id, address, is_primary, created_at)
+    VALUES (a_user_id, a_address, FALSE, EXTRACT(EPOCH FROM NOW())::bigint);
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_user_unlink_login_wallet(a_user_id bigint, a_address varchar)
+RETURNS table (
+    "unlinked" boolean
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    DELETE FROM tbl.user_login_wallet
+    WHERE fkey_user_id = a_user_id
+        AND address = a_address
+        AND NOT is_primary;
+    RETURN QUERY SELECT FOUND;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_user_set_primary_login_wallet(a_user_id bigint, a_address varchar)
+RETURNS table (
+    "updated" boolean
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    IF NOT EXISTS(SELECT 1
+                  FROM tbl.user_login_wallet
+                  WHERE fkey_user_id = a_user_id
+                    AND address = a_address) THEN
+        RETURN QUERY SELECT FALSE;
+        RETURN;
+    END IF;
+    UPDATE tbl.user_login_wallet
+    SET is_primary = FALSE
+    WHERE fkey_user_id = a_user_id
+        AND is_primary;
+    UPDATE tbl.user_login_wallet
+    SET is_primary = TRUE
+    WHERE fkey_user_id = a_user_id
+        AND address = a_address;
+    UPDATE tbl.user
+    SET address = a_address,
+        updated_at = EXTRACT(EPOCH FROM NOW())::bigint
+    WHERE pkey_id = a_user_id;
+    RETURN QUERY SELECT TRUE;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_user_get_strategy_snapshot(a_strategy_id bigint)
+RETURNS table (
+    "creator_id" bigint,
+    "snapshot" jsonb
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT s.fkey_user_id, jsonb_build_object(
+        'name', s.name,
+        'description', s.description,
+        'social_media', s.social_media,
+        'expert_fee', s.expert_fee,
+        'swap_fee', s.swap_fee,
+        'minimum_backing_amount_usd', s.minimum_backing_amount_usd,
+        'rebalance_interval_seconds', s.rebalance_interval_seconds,
+        'rebalance_drift_threshold', s.rebalance_drift_threshold,
+        'copy_trade_min_usd', s.copy_trade_min_usd,
+        'copy_trade_max_aum_ratio', s.copy_trade_max_aum_ratio,
+        'copy_trade_cooldown_seconds', s.copy_trade_cooldown_seconds,
+        'copy_trade_batch_window_seconds', s.copy_trade_batch_window_seconds,
+        'watching_wallets', COALESCE((SELECT jsonb_agg(jsonb_build_object(
+                                          'wallet_id', sw.pkey_id,
+                                          'blockchain', ew.blockchain,
+                                          'address', ew.address,
+                                          'ratio', sw.ratio_distribution
+                                      ) ORDER BY sw.pkey_id)
+                                      FROM tbl.strategy_watched_wallet AS sw
+                                        JOIN tbl.expert_watched_wallet AS ew
+                                          ON ew.pkey_id = sw.fkey_expert_watched_wallet_id
+                                      WHERE sw.fkey_strategy_id = s.pkey_id), '[]'::jsonb),
+        'initial_token_ratios', COALESCE((SELECT jsonb_agg(jsonb_build_object(
+                                              'token_id', r.token_id,
+                                              'quantity', r.quantity
+                                          ) ORDER BY r.token_id)
+                                          FROM tbl.strategy_initial_token_ratio AS r
+                                          WHERE r.fkey_strategy_id = s.pkey_id), '[]'::jsonb),
+        'audit_rules', COALESCE((SELECT jsonb_agg(ar.fkey_audit_rule_id ORDER BY ar.fkey_audit_rule_id)
+                                 FROM tbl.strategy_audit_rule AS ar
+                                 WHERE ar.fkey_strategy_id = s.pkey_id), '[]'::jsonb),
+        'guard_rules', COALESCE((SELECT jsonb_agg(jsonb_build_object(
+                                     'rule_id', g.pkey_id,
+                                     'rule_type', g.rule_type,
+                                     'token_id', g.fkey_token_id,
+                                     'threshold', g.threshold,
+                                     'exit_token_id', g.fkey_exit_token_id
+                                 ) ORDER BY g.pkey_id)
+                                 FROM tbl.strategy_guard_rule AS g
+                                 WHERE g.fkey_strategy_id = s.pkey_id), '[]'::jsonb)
+    )
+    FROM tbl.strategy AS s
+    WHERE s.pkey_id = a_strategy_id;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_user_list_strategy_current_backers(a_strategy_id bigint)
+RETURNS table (
+    "user_id" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT DISTINCT usw.fkey_user_id
+    FROM tbl.user_strategy_balance AS usb
+    JOIN tbl.strategy_pool_contract AS spc ON spc.pkey_id = usb.fkey_strategy_pool_contract_id
+    JOIN tbl.user_strategy_wallet AS usw ON usw.pkey_id = usb.fkey_user_strategy_wallet_id
+    WHERE spc.fkey_strategy_id = a_strategy_id AND usb.balance > 0;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_user_add_strategy_version(a_strategy_id bigint, a_user_id bigint, a_method_id int, a_method_name varchar, a_params jsonb, a_is_material boolean, a_status enum_strategy_version_status, a_effective_at bigint, a_diff jsonb DEFAULT NULL)
+RETURNS table (
+    "version_id" bigint,
+    "version" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+DECLARE
+    _version bigint;
+    _now bigint := EXTRACT(EPOCH FROM NOW())::bigint;
+BEGIN
+    -- serializes version numbers of the same strategy
+    PERFORM 1 FROM tbl.strategy WHERE pkey_id = a_strategy_id FOR UPDATE;
+    SELECT COALESCE(MAX(v.version), 0) + 1 INTO _version
+    FROM tbl.strategy_version AS v
+    WHERE v.fkey_strategy_id = a_strategy_id;
+    RETURN QUERY INSERT INTO tbl.strategy_version AS v (fkey_strategy_id, version, fkey_changed_by_user_id,
+                                                        method_id, method_name, params, diff, is_material,
+                                                        status, effective_at, applied_at, created_at)
+    VALUES (a_strategy_id, _version, a_user_id, a_method_id, a_method_name, a_params, a_diff, a_is_material,
+            a_status, a_effective_at, CASE WHEN a_status = 'Applied' THEN _now END, _now)
+    RETURNING v.pkey_id, v.version;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_user_set_strategy_version_status(a_version_id bigint, a_status enum_strategy_version_status, a_diff jsonb DEFAULT NULL)
+RETURNS void
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    UPDATE tbl.strategy_version
+    SET status = a_status,
+        diff = COALESCE(a_diff, diff),
+        applied_at = CASE WHEN a_status = 'Applied' THEN EXTRACT(EPOCH FROM NOW())::bigint END
+    WHERE pkey_id = a_version_id;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_user_claim_due_strategy_versions()
+RETURNS table (
+    "version_id" bigint,
+    "strategy_id" bigint,
+    "user_id" bigint,
+    "method_id" int,
+    "params" jsonb
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    -- every user service looks for due versions, each one is replayed by the service that claims it
+    RETURN QUERY WITH claimed AS (
+        UPDATE tbl.strategy_version AS v
+        SET status = 'Applying'
+        WHERE v.pkey_id IN (SELECT d.pkey_id
+                            FROM tbl.strategy_version AS d
+                            WHERE d.status = 'Scheduled'
+                              AND d.effective_at <= EXTRACT(EPOCH FROM NOW())::bigint
+                            FOR UPDATE SKIP LOCKED)
+        RETURNING v.pkey_id, v.fkey_strategy_id, v.fkey_changed_by_user_id, v.method_id, v.params
+    )
+    SELECT c.pkey_id, c.fkey_strategy_id, c.fkey_changed_by_user_id, c.method_id, c.params
+    FROM claimed AS c
+    ORDER BY c.pkey_id;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_user_list_strategy_versions(a_strategy_id bigint, a_limit bigint, a_offset bigint)
+RETURNS table (
+    "total" bigint,
+    "version" bigint,
+    "method_name" varchar,
+    "params" jsonb,
+    "diff" jsonb,
+    "is_material" boolean,
+    "status" enum_strategy_version_status,
+    "effective_at" bigint,
+    "applied_at" bigint,
+    "created_at" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT count(*) OVER() AS total,
+                        v.version,
+                        v.method_name,
+                        v.params,
+                        v.diff,
+                        v.is_material,
+                        v.status,
+                        v.effective_at,
+                        v.applied_at,
+                        v.created_at
+                 FROM tbl.strategy_version AS v
+                 WHERE v.fkey_strategy_id = a_strategy_id
+                 ORDER BY v.version DESC
+                 OFFSET a_offset
+                 LIMIT a_limit;
+END
+
+$$;
+        
+
 CREATE OR REPLACE FUNCTION api.fun_admin_list_users(a_limit bigint, a_offset bigint, a_user_id bigint DEFAULT NULL, a_address varchar DEFAULT NULL, a_username varchar DEFAULT NULL, a_email varchar DEFAULT NULL, a_role enum_role DEFAULT NULL)
 RETURNS table (
     "total" bigint,
@@ -2793,50 +4171,135 @@ END;
 $$;
         
 
-CREATE OR REPLACE FUNCTION api.fun_admin_approve_user_become_expert(a_user_public_id bigint)
+CREATE OR REPLACE FUNCTION api.fun_admin_approve_user_become_expert(a_admin_user_id bigint, a_user_public_id bigint, a_note varchar DEFAULT NULL)
+RETURNS table (
+    "success" boolean
+)
+LANGUAGE plpgsql
+AS $$
+    
+DECLARE
+    _user_id bigint;
+    _application_id bigint;
+BEGIN
+    SELECT a.fkey_user_id, a.pkey_id INTO _user_id, _application_id
+    FROM tbl.expert_application AS a
+      JOIN tbl.user AS u ON u.pkey_id = a.fkey_user_id
+    WHERE u.public_id = a_user_public_id
+      AND a.status = 'Submitted'
+    FOR UPDATE OF a;
+    IF _application_id IS NULL THEN
+        RAISE SQLSTATE 'R001A'; -- InvalidExpertApplicationState
+    END IF;
+    UPDATE tbl.expert_application
+    SET status = 'Approved',
+        fkey_reviewed_by_user_id = a_admin_user_id,
+        reviewed_at = EXTRACT(EPOCH FROM NOW()),
+        updated_at = EXTRACT(EPOCH FROM NOW())
+    WHERE pkey_id = _application_id;
+    INSERT INTO tbl.expert_application_note(fkey_application_id, fkey_author_user_id, status, note, created_at)
+    VALUES (_application_id, a_admin_user_id, 'Approved', a_note, EXTRACT(EPOCH FROM NOW()));
+    UPDATE tbl.expert_profile 
+    SET pending_expert = FALSE,
+        approved_expert = TRUE,
+        approved_at = EXTRACT(EPOCH FROM NOW()),
+        updated_at = EXTRACT(EPOCH FROM NOW())
+    WHERE fkey_user_id = _user_id;
+    UPDATE tbl.user SET role = 'expert' WHERE role = 'user' AND pkey_id = _user_id;
+    RETURN QUERY SELECT TRUE;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_admin_reject_user_become_expert(a_admin_user_id bigint, a_user_public_id bigint, a_reason varchar)
 RETURNS table (
     "success" boolean
 )
 LANGUAGE plpgsql
 AS $$
     
-
 DECLARE
     _user_id bigint;
+    _application_id bigint;
 BEGIN
-    SELECT pkey_id INTO _user_id FROM tbl.user WHERE public_id = a_user_public_id;
-    UPDATE tbl.expert_profile 
-    SET pending_expert = FALSE,
-        approved_expert = TRUE,
-        approved_at = EXTRACT(EPOCH FROM NOW()),
+    SELECT a.fkey_user_id, a.pkey_id INTO _user_id, _application_id
+    FROM tbl.expert_application AS a
+      JOIN tbl.user AS u ON u.pkey_id = a.fkey_user_id
+    WHERE u.public_id = a_user_public_id
+      AND a.status = 'Submitted'
+    FOR UPDATE OF a;
+    IF _application_id IS NULL THEN
+        RAISE SQLSTATE 'R001A'; -- InvalidExpertApplicationState
+    END IF;
+    UPDATE tbl.expert_application
+    SET status = 'Rejected',
+        fkey_reviewed_by_user_id = a_admin_user_id,
+        reviewed_at = EXTRACT(EPOCH FROM NOW()),
         updated_at = EXTRACT(EPOCH FROM NOW())
-    WHERE fkey_user_id = _user_id;
-    UPDATE tbl.user SET role = 'expert' WHERE role = 'user' AND pkey_id = _user_id;
+    WHERE pkey_id = _application_id;
+    INSERT INTO tbl.expert_application_note(fkey_application_id, fkey_author_user_id, status, note, created_at)
+    VALUES (_application_id, a_admin_user_id, 'Rejected', a_reason, EXTRACT(EPOCH FROM NOW()));
+    UPDATE tbl.expert_profile SET pending_expert = FALSE, approved_expert = FALSE WHERE fkey_user_id = _user_id;
     RETURN QUERY SELECT TRUE;
 END
 
 $$;
         
 
-CREATE OR REPLACE FUNCTION api.fun_admin_reject_user_become_expert(a_user_public_id bigint)
+CREATE OR REPLACE FUNCTION api.fun_admin_request_expert_application_info(a_admin_user_id bigint, a_user_public_id bigint, a_note varchar)
 RETURNS table (
     "success" boolean
 )
 LANGUAGE plpgsql
 AS $$
     
-
 DECLARE
     _user_id bigint;
+    _application_id bigint;
 BEGIN
-    SELECT pkey_id INTO _user_id FROM tbl.user WHERE public_id = a_user_public_id;
-    UPDATE tbl.expert_profile SET pending_expert = FALSE, approved_expert = FALSE WHERE fkey_user_id = _user_id;
+    SELECT a.fkey_user_id, a.pkey_id INTO _user_id, _application_id
+    FROM tbl.expert_application AS a
+      JOIN tbl.user AS u ON u.pkey_id = a.fkey_user_id
+    WHERE u.public_id = a_user_public_id
+      AND a.status = 'Submitted'
+    FOR UPDATE OF a;
+    IF _application_id IS NULL THEN
+        RAISE SQLSTATE 'R001A'; -- InvalidExpertApplicationState
+    END IF;
+    UPDATE tbl.expert_application
+    SET status = 'InfoRequested',
+        fkey_reviewed_by_user_id = a_admin_user_id,
+        reviewed_at = EXTRACT(EPOCH FROM NOW()),
+        updated_at = EXTRACT(EPOCH FROM NOW())
+    WHERE pkey_id = _application_id;
+    INSERT INTO tbl.expert_application_note(fkey_application_id, fkey_author_user_id, status, note, created_at)
+    VALUES (_application_id, a_admin_user_id, 'InfoRequested', a_note, EXTRACT(EPOCH FROM NOW()));
+    UPDATE tbl.expert_profile SET pending_expert = FALSE WHERE fkey_user_id = _user_id;
     RETURN QUERY SELECT TRUE;
 END
 
 $$;
         
 
+CREATE OR REPLACE FUNCTION api.fun_admin_get_expert_application_document(a_document_id bigint)
+RETURNS table (
+    "name" varchar,
+    "content_type" varchar,
+    "blob_key" varchar
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT d.name, d.content_type, d.blob_key
+                 FROM tbl.expert_application_document AS d
+                 WHERE d.pkey_id = a_document_id;
+END
+
+$$;
+        
+
 CREATE OR REPLACE FUNCTION api.fun_admin_list_pending_user_expert_applications(a_limit bigint, a_offset bigint)
 RETURNS table (
     "total" bigint,
@@ -2923,6 +4386,87 @@ BEGIN
         WHERE
             pkey_id = a_config_id;
     END IF;
+    PERFORM pg_notify('mc2fi_system_config', 'system_config');
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_admin_list_system_settings()
+RETURNS table (
+    "key" varchar,
+    "value" jsonb,
+    "version" bigint,
+    "updated_by_user_id" bigint,
+    "updated_at" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT s.key, s.value, s.version, s.fkey_updated_by_user_id, s.updated_at
+    FROM tbl.system_setting s
+    ORDER BY s.key;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_admin_set_system_setting(a_admin_user_id bigint, a_key varchar, a_value jsonb, a_expected_version bigint DEFAULT NULL)
+RETURNS table (
+    "version" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+DECLARE
+    _version bigint;
+BEGIN
+    SELECT s.version INTO _version FROM tbl.system_setting s WHERE s.key = a_key FOR UPDATE;
+    _version := coalesce(_version, 0) + 1;
+    IF a_expected_version NOTNULL AND a_expected_version <> _version - 1 THEN
+        RAISE SQLSTATE 'R0017'; -- SettingVersionConflict
+    END IF;
+    INSERT INTO tbl.system_setting (key, value, version, fkey_updated_by_user_id, updated_at)
+    VALUES (a_key, a_value, _version, a_admin_user_id, EXTRACT(EPOCH FROM NOW())::bigint)
+    ON CONFLICT (key) DO UPDATE SET
+        value = excluded.value,
+        version = excluded.version,
+        fkey_updated_by_user_id = excluded.fkey_updated_by_user_id,
+        updated_at = excluded.updated_at;
+    INSERT INTO tbl.system_setting_history (key, value, version, fkey_updated_by_user_id, created_at)
+    VALUES (a_key, a_value, _version, a_admin_user_id, EXTRACT(EPOCH FROM NOW())::bigint);
+    PERFORM pg_notify('mc2fi_system_config', a_key);
+    RETURN QUERY SELECT _version;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_admin_list_system_setting_history(a_limit bigint, a_offset bigint, a_key varchar DEFAULT NULL)
+RETURNS table (
+    "total" bigint,
+    "key" varchar,
+    "value" jsonb,
+    "version" bigint,
+    "updated_by_user_id" bigint,
+    "created_at" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT count(*) OVER() AS total,
+                        h.key,
+                        h.value,
+                        h.version,
+                        h.fkey_updated_by_user_id,
+                        h.created_at
+                 FROM tbl.system_setting_history h
+                 WHERE a_key ISNULL OR h.key = a_key
+                 ORDER BY h.pkey_id DESC
+                 OFFSET a_offset
+                 LIMIT a_limit;
 END
 
 $$;
@@ -2943,6 +4487,55 @@ RETURNS table (
     "strategy_count" bigint,
     "description" varchar,
     "social_media" varchar,
+    "consistent_score" double precision,
+    "risk_score" double precision,
+    "reputation_score" double precision,
+    "aum" double precision,
+    "joined_at" bigint,
+    "requested_at" bigint,
+    "approved_at" bigint,
+    "pending_expert" boolean,
+    "approved_expert" boolean,
+    "followed" boolean,
+    "linked_wallet" varchar
+)
+LANGUAGE plpgsql
+AS $$
+    
+
+ 
+BEGIN
+IF NOT EXISTS (SELECT * FROM tbl.system_config WHERE pkey_id = a_config_id) THEN
+    INSERT INTO tbl.system_config (pkey_id, platform_fee, allow_domain_urls)
+    VALUES (a_config_id, a_platform_fee, a_allow_domain_urls);
+ELSE
+    UPDATE tbl.system_config SET
+        platform_fee = coalesce(a_platform_fee, platform_fee),
+        allow_domain_urls = coalesce(a_allow_domain_urls, allow_domain_urls)
+    WHERE
+        pkey_id = a_config_id;
+END IF;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_admin_list_whitelists(a_limit bigint, a_offset bigint, a_expert_id bigint DEFAULT NULL, a_user_id bigint DEFAULT NULL, a_user_public_id bigint DEFAULT NULL, a_username varchar DEFAULT NULL, a_family_name varchar DEFAULT NULL, a_given_name varchar DEFAULT NULL, a_description varchar DEFAULT NULL, a_social_media varchar DEFAULT NULL)
+RETURNS table (
+    "total" bigint,
+    "expert_id" bigint,
+    "user_id" bigint,
+    "user_public_id" bigint,
+    "listening_wallet" varchar,
+    "username" varchar,
+    "family_name" varchar,
+    "given_name" varchar,
+    "follower_count" bigint,
+    "backer_count" bigint,
+    "strategy_count" bigint,
+    "description" varchar,
+    "social_media" varchar,
+    "consistent_score" double precision,
     "risk_score" double precision,
     "reputation_score" double precision,
     "aum" double precision,
@@ -2957,6 +4550,8 @@ RETURNS table (
 LANGUAGE plpgsql
 AS $$
     
+                
+
 BEGIN
     RETURN QUERY SELECT count(*) OVER() AS total,
         e.pkey_id                                                 AS expert_id,
@@ -2971,6 +4566,7 @@ BEGIN
         (SELECT COUNT(DISTINCT d.fkey_user_id) FROM tbl.strategy AS d WHERE d.fkey_user_id = u.pkey_id) AS strategy_count,
         e.description                                             AS description,
         e.social_media                                            AS social_media,
+        e.consistent_score                                        AS consistent_score,
         e.risk_score                                              AS risk_score,
         e.reputation_score                                        AS reputation_score,
         e.aum                                                     AS aum,
@@ -2999,6 +4595,7 @@ BEGIN
 END
 
 $$;
+        
 
 CREATE OR REPLACE FUNCTION api.fun_admin_list_backers(a_offset bigint, a_limit bigint, a_user_id bigint DEFAULT NULL, a_user_public_id bigint DEFAULT NULL, a_username varchar DEFAULT NULL, a_family_name varchar DEFAULT NULL, a_given_name varchar DEFAULT NULL)
 RETURNS table (
@@ -3273,43 +4870,301 @@ LANGUAGE plpgsql
 AS $$
     
 BEGIN
-    INSERT INTO tbl.escrow_contract_address (pkey_id, blockchain, address)
-         VALUES (a_pkey_id, a_blockchain, a_address);
+    INSERT INTO tbl.escrow_contract_address (pkey_id, blockchain, address)
+         VALUES (a_pkey_id, a_blockchain, a_address);
+END
+            
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_admin_list_escrow_contract_address(a_limit bigint, a_offset bigint, a_blockchain enum_block_chain DEFAULT NULL)
+RETURNS table (
+    "pkey_id" bigint,
+    "blockchain" enum_block_chain,
+    "address" varchar
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT pkey_id, blockchain, address
+                 FROM tbl.escrow_contract_address
+                WHERE (a_blockchain ISNULL OR blockchain = a_blockchain)
+                 ORDER BY pkey_id
+                 OFFSET a_offset
+                 LIMIT a_limit;
+END
+            
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_admin_update_escrow_contract_address(a_blockchain enum_block_chain, a_address varchar)
+RETURNS void
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    UPDATE tbl.escrow_contract_address
+         SET address = a_address
+         WHERE blockchain = a_blockchain;
+END
+            
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_admin_get_user_state(a_user_id bigint)
+RETURNS table (
+    "state" jsonb
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT jsonb_build_object(
+        'role', u.role,
+        'is_blocked', u.is_blocked
+    )
+    FROM tbl.user u
+    WHERE u.pkey_id = a_user_id;
+END
+            
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_admin_get_expert_state(a_user_public_id bigint)
+RETURNS table (
+    "state" jsonb
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT jsonb_build_object(
+        'role', u.role,
+        'pending_expert', e.pending_expert,
+        'approved_expert', e.approved_expert,
+        'approved_at', e.approved_at,
+        'application_status', a.status
+    )
+    FROM tbl.user u
+    LEFT JOIN tbl.expert_profile e ON e.fkey_user_id = u.pkey_id
+    LEFT JOIN tbl.expert_application a ON a.fkey_user_id = u.pkey_id
+    WHERE u.public_id = a_user_public_id;
+END
+            
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_admin_get_strategy_state(a_strategy_id bigint)
+RETURNS table (
+    "state" jsonb
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT jsonb_build_object(
+        'approved', s.approved,
+        'pending_approval', s.pending_approval,
+        'approved_at', s.approved_at
+    )
+    FROM tbl.strategy s
+    WHERE s.pkey_id = a_strategy_id;
+END
+            
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_admin_get_system_config_state(a_config_id bigint)
+RETURNS table (
+    "state" jsonb
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT jsonb_build_object(
+        'platform_fee', c.platform_fee,
+        'allow_domain_urls', c.allow_domain_urls,
+        'escrow_contract_addresses', (SELECT jsonb_object_agg(e.blockchain, e.address)
+                                      FROM tbl.escrow_contract_address e),
+        'settings', (SELECT jsonb_object_agg(s.key, s.value) FROM tbl.system_setting s)
+    )
+    FROM (SELECT a_config_id AS pkey_id) d
+    LEFT JOIN tbl.system_config c ON c.pkey_id = d.pkey_id;
+END
+            
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_admin_get_escrow_token_contract_address_state(a_pkey_id bigint)
+RETURNS table (
+    "state" jsonb
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT jsonb_build_object(
+        'symbol', t.symbol,
+        'short_name', t.short_name,
+        'description', t.description,
+        'is_stablecoin', t.is_stablecoin,
+        'is_wrapped', t.is_wrapped
+    )
+    FROM tbl.escrow_token_contract_address t
+    WHERE t.pkey_id = a_pkey_id;
+END
+            
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_admin_add_action_log(a_admin_user_id bigint, a_method_id int, a_method_name varchar, a_params jsonb, a_state_before jsonb, a_state_after jsonb, a_pending_action_id bigint DEFAULT NULL)
+RETURNS void
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    INSERT INTO tbl.admin_action_log (fkey_admin_user_id, fkey_pending_action_id, method_id, method_name,
+                                      params, state_before, state_after, created_at)
+    VALUES (a_admin_user_id, a_pending_action_id, a_method_id, a_method_name,
+            a_params, a_state_before, a_state_after, EXTRACT(EPOCH FROM NOW())::bigint);
+END
+            
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_admin_list_action_log(a_limit bigint, a_offset bigint, a_admin_user_id bigint DEFAULT NULL, a_method_name varchar DEFAULT NULL)
+RETURNS table (
+    "total" bigint,
+    "action_log_id" bigint,
+    "admin_user_id" bigint,
+    "pending_action_id" bigint,
+    "requested_by_user_id" bigint,
+    "method_name" varchar,
+    "params" jsonb,
+    "state_before" jsonb,
+    "state_after" jsonb,
+    "created_at" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT count(*) OVER() AS total,
+                        l.pkey_id,
+                        l.fkey_admin_user_id,
+                        l.fkey_pending_action_id,
+                        p.fkey_requested_by_user_id,
+                        l.method_name,
+                        l.params,
+                        l.state_before,
+                        l.state_after,
+                        l.created_at
+                 FROM tbl.admin_action_log l
+                 LEFT JOIN tbl.admin_pending_action p ON p.pkey_id = l.fkey_pending_action_id
+                 WHERE (a_admin_user_id ISNULL OR l.fkey_admin_user_id = a_admin_user_id)
+                   AND (a_method_name ISNULL OR l.method_name = a_method_name)
+                 ORDER BY l.pkey_id DESC
+                 OFFSET a_offset
+                 LIMIT a_limit;
+END
+            
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_admin_add_pending_action(a_admin_user_id bigint, a_method_id int, a_method_name varchar, a_params jsonb)
+RETURNS table (
+    "pending_action_id" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY INSERT INTO tbl.admin_pending_action (fkey_requested_by_user_id, method_id, method_name,
+                                                       params, status, created_at)
+                 VALUES (a_admin_user_id, a_method_id, a_method_name,
+                         a_params, 'Pending', EXTRACT(EPOCH FROM NOW())::bigint)
+                 RETURNING pkey_id;
+END
+            
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_admin_list_pending_actions(a_limit bigint, a_offset bigint, a_status enum_admin_pending_action_status DEFAULT NULL)
+RETURNS table (
+    "total" bigint,
+    "pending_action_id" bigint,
+    "requested_by_user_id" bigint,
+    "method_name" varchar,
+    "params" jsonb,
+    "status" enum_admin_pending_action_status,
+    "approved_by_user_id" bigint,
+    "approved_at" bigint,
+    "created_at" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT count(*) OVER() AS total,
+                        a.pkey_id,
+                        a.fkey_requested_by_user_id,
+                        a.method_name,
+                        a.params,
+                        a.status,
+                        a.fkey_approved_by_user_id,
+                        a.approved_at,
+                        a.created_at
+                 FROM tbl.admin_pending_action a
+                 WHERE (a_status ISNULL OR a.status = a_status)
+                 ORDER BY a.pkey_id DESC
+                 OFFSET a_offset
+                 LIMIT a_limit;
 END
             
 $$;
         
 
-CREATE OR REPLACE FUNCTION api.fun_admin_list_escrow_contract_address(a_limit bigint, a_offset bigint, a_blockchain enum_block_chain DEFAULT NULL)
+CREATE OR REPLACE FUNCTION api.fun_admin_approve_pending_action(a_pending_action_id bigint, a_admin_user_id bigint)
 RETURNS table (
-    "pkey_id" bigint,
-    "blockchain" enum_block_chain,
-    "address" varchar
+    "method_id" int,
+    "params" jsonb
 )
 LANGUAGE plpgsql
 AS $$
     
+DECLARE
+    _requested_by_user_id bigint;
 BEGIN
-    RETURN QUERY SELECT pkey_id, blockchain, address
-                 FROM tbl.escrow_contract_address
-                WHERE (a_blockchain ISNULL OR blockchain = a_blockchain)
-                 ORDER BY pkey_id
-                 OFFSET a_offset
-                 LIMIT a_limit;
+    SELECT fkey_requested_by_user_id INTO _requested_by_user_id
+    FROM tbl.admin_pending_action
+    WHERE pkey_id = a_pending_action_id AND status = 'Pending'
+    FOR UPDATE;
+    IF NOT FOUND THEN
+        RAISE SQLSTATE 'R0015'; -- UnknownPendingAction
+    END IF;
+    IF _requested_by_user_id = a_admin_user_id THEN
+        RAISE SQLSTATE 'R0016'; -- SelfApproval
+    END IF;
+    RETURN QUERY UPDATE tbl.admin_pending_action AS a
+                 SET status = 'Approved',
+                     fkey_approved_by_user_id = a_admin_user_id,
+                     approved_at = EXTRACT(EPOCH FROM NOW())::bigint
+                 WHERE a.pkey_id = a_pending_action_id
+                 RETURNING a.method_id, a.params;
 END
             
 $$;
         
 
-CREATE OR REPLACE FUNCTION api.fun_admin_update_escrow_contract_address(a_blockchain enum_block_chain, a_address varchar)
+CREATE OR REPLACE FUNCTION api.fun_admin_set_pending_action_status(a_pending_action_id bigint, a_status enum_admin_pending_action_status)
 RETURNS void
 LANGUAGE plpgsql
 AS $$
     
 BEGIN
-    UPDATE tbl.escrow_contract_address
-         SET address = a_address
-         WHERE blockchain = a_blockchain;
+    UPDATE tbl.admin_pending_action SET status = a_status WHERE pkey_id = a_pending_action_id;
 END
             
 $$;
@@ -3641,7 +5496,7 @@ DECLARE
 BEGIN
 		SELECT etca.pkey_id INTO _token_id
 		FROM tbl.escrow_token_contract_address AS etca
-		WHERE etca.address = a_token_address AND etca.blockchain = a_blockchain;
+		WHERE lower(etca.address) = lower(a_token_address) AND etca.blockchain = a_blockchain;
 
 		SELECT spcab.pkey_id INTO _strategy_contract_asset_balance_id
 		FROM tbl.strategy_pool_contract_asset_balance AS spcab
@@ -3677,24 +5532,52 @@ RETURNS table (
 LANGUAGE plpgsql
 AS $$
     
-BEGIN
-		RETURN QUERY SELECT
-			tc.pkey_id,
-			tc.short_name,
-			tc.symbol,
-			tc.address,
-			tc.decimals,
-			tc.blockchain,
-			spcab.balance AS balance
-			FROM tbl.strategy_pool_contract_asset_balance AS spcab
-			JOIN tbl.escrow_token_contract_address AS tc ON spcab.fkey_token_id = tc.pkey_id
-			JOIN tbl.strategy_pool_contract AS spc ON spc.pkey_id = spcab.fkey_strategy_pool_contract_id 
-			WHERE (a_strategy_pool_contract_id ISNULL OR spcab.fkey_strategy_pool_contract_id = a_strategy_pool_contract_id)
-			AND (a_strategy_id ISNULL OR spc.fkey_strategy_id = a_strategy_id)
-			AND (a_blockchain ISNULL OR tc.blockchain = a_blockchain)
-			AND (a_token_address ISNULL OR tc.address = a_token_address)
-			AND spcab.balance != 0.0;
-END
+            BEGIN
+            SELECT COUNT(*) INTO spcab_count FROM tbl.strategy_pool_contract_asset_balance AS spcab 
+                JOIN tbl.escrow_token_contract_address AS tc ON spcab.fkey_token_id = tc.pkey_id
+                    JOIN tbl.strategy_pool_contract AS spc ON spc.pkey_id = spcab.fkey_strategy_pool_contract_id 
+                   WHERE (a_strategy_pool_contract_id is NULL OR spcab.fkey_strategy_pool_contract_id = a_strategy_pool_contract_id)
+                    AND (a_strategy_id ISNULL OR spc.fkey_strategy_id = a_strategy_id)
+                    AND (a_blockchain ISNULL OR tc.blockchain = a_blockchain)
+                    AND (a_token_address ISNULL OR tc.address = a_token_address)
+                    AND spcab.balance != 0.0;
+            IF spcab_count > 0 THEN
+                RETURN QUERY SELECT
+                    tc.pkey_id,
+                    tc.short_name,
+                    tc.symbol,
+                    tc.address,
+                    tc.decimals,
+                    tc.blockchain,
+                    spcab.balance AS balance
+                    FROM tbl.strategy_pool_contract_asset_balance AS spcab
+                    JOIN tbl.escrow_token_contract_address AS tc ON spcab.fkey_token_id = tc.pkey_id
+                    JOIN tbl.strategy_pool_contract AS spc ON spc.pkey_id = spcab.fkey_strategy_pool_contract_id 
+                    WHERE (a_strategy_pool_contract_id is NULL OR spcab.fkey_strategy_pool_contract_id = a_strategy_pool_contract_id)
+                    AND (a_strategy_id is NULL OR spc.fkey_strategy_id = a_strategy_id)
+                    AND (a_blockchain is NULL OR tc.blockchain = a_blockchain)
+                    AND (a_token_address is NULL OR tc.address = a_token_address)
+                    AND spcab.balance != 0.0;
+            ELSE
+                RETURN QUERY 
+                SELECT 
+                    tc.pkey_id,
+                    tc.short_name, 
+                    tc.symbol, 
+                    tc.address, 
+                    tc.decimals, 
+                    tc.blockchain, 
+                    str.balance
+                    from tbl.expert_listened_wallet_asset_balance AS str 
+                    JOIN tbl.escrow_token_contract_address AS tc ON str.fkey_token_id = tc.pkey_id
+                    join tbl.expert_watched_wallet as ww on str.fkey_expert_watched_wallet_id = ww.pkey_id
+                    join tbl.strategy_watched_wallet as sww on sww.fkey_expert_watched_wallet_id = ww.pkey_id
+                    WHERE (a_blockchain is NULL OR tc.blockchain = a_blockchain) 
+                    AND (a_strategy_id is NULL OR sww.fkey_strategy_id = a_strategy_id)
+                    AND (a_token_address is NULL OR tc.address = a_token_address)
+                    and str.balance != 0;
+            END IF;
+        END
 
 $$;
         
@@ -4056,6 +5939,445 @@ END
 $$;
         
 
+CREATE OR REPLACE FUNCTION api.fun_watcher_list_strategies_due_for_rebalance()
+RETURNS table (
+    "strategy_id" bigint,
+    "blockchain" enum_block_chain,
+    "strategy_pool_contract_id" bigint,
+    "strategy_pool_address" varchar,
+    "rebalance_drift_threshold" double precision
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT
+        s.pkey_id,
+        spc.blockchain,
+        spc.pkey_id,
+        spc.address,
+        s.rebalance_drift_threshold
+    FROM tbl.strategy AS s
+    JOIN tbl.strategy_pool_contract AS spc ON spc.fkey_strategy_id = s.pkey_id
+    WHERE s.approved = TRUE
+        AND s.copy_trading_paused = FALSE
+        AND s.rebalance_interval_seconds IS NOT NULL
+        AND (s.last_rebalanced_at ISNULL
+            OR s.last_rebalanced_at + s.rebalance_interval_seconds <= EXTRACT(EPOCH FROM NOW())::bigint)
+    ORDER BY s.pkey_id;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_watcher_update_strategy_last_rebalanced_at(a_strategy_id bigint)
+RETURNS void
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    UPDATE tbl.strategy
+    SET last_rebalanced_at = EXTRACT(EPOCH FROM NOW())::bigint
+    WHERE pkey_id = a_strategy_id;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_watcher_list_active_strategy_guard_rules()
+RETURNS table (
+    "rule_id" bigint,
+    "strategy_id" bigint,
+    "blockchain" enum_block_chain,
+    "strategy_pool_contract_id" bigint,
+    "strategy_pool_address" varchar,
+    "rule_type" enum_strategy_guard_rule_type,
+    "token_address" varchar,
+    "threshold" double precision,
+    "reference_value" double precision,
+    "exit_token_address" varchar,
+    "outstanding_sp_tokens" decimal(56, 18)
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT
+        r.pkey_id,
+        s.pkey_id,
+        spc.blockchain,
+        spc.pkey_id,
+        spc.address,
+        r.rule_type,
+        t.address,
+        r.threshold,
+        ref.reference_value,
+        et.address,
+        COALESCE((SELECT SUM(usb.balance)
+                  FROM tbl.user_strategy_balance AS usb
+                  WHERE usb.fkey_strategy_pool_contract_id = spc.pkey_id), 0)::decimal(56, 18)
+    FROM tbl.strategy_guard_rule AS r
+    JOIN tbl.strategy AS s ON s.pkey_id = r.fkey_strategy_id
+    JOIN tbl.strategy_pool_contract AS spc ON spc.fkey_strategy_id = s.pkey_id
+    LEFT JOIN tbl.strategy_guard_rule_reference AS ref
+        ON ref.fkey_strategy_guard_rule_id = r.pkey_id AND ref.fkey_strategy_pool_contract_id = spc.pkey_id
+    LEFT JOIN tbl.escrow_token_contract_address AS t ON t.pkey_id = r.fkey_token_id
+    LEFT JOIN tbl.escrow_token_contract_address AS et ON et.pkey_id = r.fkey_exit_token_id
+    WHERE r.triggered_at ISNULL
+        AND s.copy_trading_paused = FALSE
+        -- an asset stop-loss only guards the pool on the chain of its token
+        AND (t.pkey_id ISNULL OR t.blockchain = spc.blockchain)
+    ORDER BY s.pkey_id, spc.pkey_id, r.pkey_id;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_watcher_update_strategy_guard_rule_reference_value(a_rule_id bigint, a_strategy_pool_contract_id bigint, a_reference_value double precision)
+RETURNS void
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    INSERT INTO tbl.strategy_guard_rule_reference (
+        fkey_strategy_guard_rule_id,
+        fkey_strategy_pool_contract_id,
+        reference_value
+    ) VALUES (
+        a_rule_id,
+        a_strategy_pool_contract_id,
+        a_reference_value
+    ) ON CONFLICT (fkey_strategy_guard_rule_id, fkey_strategy_pool_contract_id)
+    DO UPDATE SET reference_value = EXCLUDED.reference_value;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_watcher_trigger_strategy_guard_rule(a_rule_id bigint)
+RETURNS table (
+    "triggered_at" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+DECLARE
+    _triggered_at bigint := EXTRACT(EPOCH FROM NOW())::bigint;
+BEGIN
+    UPDATE tbl.strategy_guard_rule
+    SET triggered_at = _triggered_at
+    WHERE pkey_id = a_rule_id;
+    UPDATE tbl.strategy
+    SET copy_trading_paused = TRUE
+    WHERE pkey_id = (SELECT fkey_strategy_id FROM tbl.strategy_guard_rule WHERE pkey_id = a_rule_id);
+    RETURN QUERY SELECT _triggered_at;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_watcher_get_strategy_copy_trade_controls(a_strategy_id bigint)
+RETURNS table (
+    "copy_trading_paused" boolean,
+    "copy_trade_min_usd" double precision,
+    "copy_trade_max_aum_ratio" double precision,
+    "copy_trade_cooldown_seconds" bigint,
+    "copy_trade_batch_window_seconds" bigint,
+    "last_copy_traded_at" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT
+        s.copy_trading_paused,
+        s.copy_trade_min_usd,
+        s.copy_trade_max_aum_ratio,
+        s.copy_trade_cooldown_seconds,
+        s.copy_trade_batch_window_seconds,
+        s.last_copy_traded_at
+    FROM tbl.strategy AS s
+    WHERE s.pkey_id = a_strategy_id;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_watcher_mark_strategy_copy_trade_pending(a_strategy_id bigint)
+RETURNS void
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    UPDATE tbl.strategy
+    SET copy_trade_pending_since = COALESCE(copy_trade_pending_since, EXTRACT(EPOCH FROM NOW())::bigint)
+    WHERE pkey_id = a_strategy_id;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_watcher_update_strategy_last_copy_traded_at(a_strategy_id bigint)
+RETURNS void
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    UPDATE tbl.strategy
+    SET last_copy_traded_at = EXTRACT(EPOCH FROM NOW())::bigint,
+        copy_trade_pending_since = NULL
+    WHERE pkey_id = a_strategy_id;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_watcher_list_strategies_due_for_batched_copy_trade()
+RETURNS table (
+    "strategy_id" bigint,
+    "blockchain" enum_block_chain,
+    "strategy_pool_contract_id" bigint,
+    "strategy_pool_address" varchar,
+    "copy_trade_min_usd" double precision,
+    "copy_trade_max_aum_ratio" double precision
+)
+LANGUAGE plpgsql
+AS $$
+    
+DECLARE
+    _now bigint := EXTRACT(EPOCH FROM NOW())::bigint;
+BEGIN
+    RETURN QUERY SELECT
+        s.pkey_id,
+        spc.blockchain,
+        spc.pkey_id,
+        spc.address,
+        s.copy_trade_min_usd,
+        s.copy_trade_max_aum_ratio
+    FROM tbl.strategy AS s
+    JOIN tbl.strategy_pool_contract AS spc ON spc.fkey_strategy_id = s.pkey_id
+    WHERE s.copy_trade_pending_since IS NOT NULL
+        AND s.copy_trading_paused = FALSE
+        AND s.copy_trade_pending_since + COALESCE(s.copy_trade_batch_window_seconds, 0) <= _now
+        AND (s.last_copy_traded_at ISNULL
+            OR s.last_copy_traded_at + COALESCE(s.copy_trade_cooldown_seconds, 0) <= _now)
+    ORDER BY s.pkey_id;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_watcher_list_strategies_for_scoring()
+RETURNS table (
+    "strategy_id" bigint,
+    "blockchain" enum_block_chain,
+    "outstanding_sp_tokens" decimal(56, 18)
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT
+        s.pkey_id,
+        spc.blockchain,
+        COALESCE((SELECT SUM(usb.balance)
+                  FROM tbl.user_strategy_balance AS usb
+                  WHERE usb.fkey_strategy_pool_contract_id = spc.pkey_id), 0)::decimal(56, 18)
+    FROM tbl.strategy AS s
+    JOIN tbl.strategy_pool_contract AS spc ON spc.fkey_strategy_id = s.pkey_id
+    WHERE s.approved = TRUE
+        AND s.fkey_user_id IS NOT NULL
+    ORDER BY s.pkey_id;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_watcher_upsert_strategy_daily_value(a_strategy_id bigint, a_day bigint, a_aum_usd double precision, a_share_price double precision DEFAULT NULL)
+RETURNS void
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    INSERT INTO tbl.strategy_daily_value (fkey_strategy_id, day, aum_usd, share_price, updated_at)
+    VALUES (a_strategy_id, a_day, a_aum_usd, a_share_price, EXTRACT(EPOCH FROM NOW())::bigint)
+    ON CONFLICT (fkey_strategy_id, day) DO UPDATE
+        SET aum_usd = EXCLUDED.aum_usd,
+            share_price = EXCLUDED.share_price,
+            updated_at = EXCLUDED.updated_at;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_watcher_list_expert_strategy_daily_values(a_since bigint)
+RETURNS table (
+    "expert_user_id" bigint,
+    "strategy_id" bigint,
+    "day" bigint,
+    "aum_usd" double precision,
+    "share_price" double precision
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT
+        s.fkey_user_id,
+        v.fkey_strategy_id,
+        v.day,
+        v.aum_usd,
+        v.share_price
+    FROM tbl.strategy_daily_value AS v
+    JOIN tbl.strategy AS s ON s.pkey_id = v.fkey_strategy_id
+    WHERE v.day >= a_since
+        AND s.fkey_user_id IS NOT NULL
+    ORDER BY s.fkey_user_id, v.fkey_strategy_id, v.day;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_watcher_list_expert_scoring_stats(a_since bigint)
+RETURNS table (
+    "user_id" bigint,
+    "first_traded_at" bigint,
+    "active_weeks" bigint,
+    "backers_total" bigint,
+    "backers_current" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT
+        e.fkey_user_id,
+        (SELECT MIN(t.happened_at)
+         FROM tbl.strategy_watching_wallet_trade_ledger AS t
+         JOIN tbl.expert_watched_wallet AS w ON w.pkey_id = t.fkey_expert_watched_wallet_id
+         WHERE w.fkey_user_id = e.fkey_user_id),
+        (SELECT COUNT(DISTINCT (t.happened_at - a_since) / 604800)
+         FROM tbl.strategy_watching_wallet_trade_ledger AS t
+         JOIN tbl.expert_watched_wallet AS w ON w.pkey_id = t.fkey_expert_watched_wallet_id
+         WHERE w.fkey_user_id = e.fkey_user_id
+            AND t.happened_at >= a_since),
+        (SELECT COUNT(DISTINCT l.fkey_user_id)
+         FROM tbl.user_back_exit_strategy_ledger AS l
+         JOIN tbl.strategy AS s ON s.pkey_id = l.fkey_strategy_id
+         WHERE s.fkey_user_id = e.fkey_user_id
+            AND l.is_back = TRUE),
+        (SELECT COUNT(DISTINCT usw.fkey_user_id)
+         FROM tbl.user_strategy_balance AS usb
+         JOIN tbl.user_strategy_wallet AS usw ON usw.pkey_id = usb.fkey_user_strategy_wallet_id
+         JOIN tbl.strategy_pool_contract AS spc ON spc.pkey_id = usb.fkey_strategy_pool_contract_id
+         JOIN tbl.strategy AS s ON s.pkey_id = spc.fkey_strategy_id
+         WHERE s.fkey_user_id = e.fkey_user_id
+            AND usb.balance > 0)
+    FROM tbl.expert_profile AS e
+    WHERE e.approved_expert = TRUE
+    ORDER BY e.fkey_user_id;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_watcher_update_expert_scores(a_user_id bigint, a_consistent_score double precision, a_risk_score double precision, a_reputation_score double precision, a_aum double precision)
+RETURNS void
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    UPDATE tbl.expert_profile
+    SET consistent_score = a_consistent_score,
+        risk_score = a_risk_score,
+        reputation_score = a_reputation_score,
+        aum = a_aum,
+        updated_at = EXTRACT(EPOCH FROM NOW())::bigint
+    WHERE fkey_user_id = a_user_id;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_watcher_list_pending_withdraws()
+RETURNS table (
+    "pending_withdraw_id" bigint,
+    "blockchain" enum_block_chain,
+    "transaction_hash" varchar,
+    "created_at" bigint
+)
+LANGUAGE plpgsql
+AS $$
+    
+BEGIN
+    RETURN QUERY SELECT pw.pkey_id, pw.blockchain, pw.transaction_hash, pw.created_at
+    FROM tbl.user_pending_withdraw AS pw
+    ORDER BY pw.pkey_id;
+END
+
+$$;
+        
+
+CREATE OR REPLACE FUNCTION api.fun_watcher_settle_pending_withdraw(a_pending_withdraw_id bigint, a_is_successful boolean)
+RETURNS table (
+    "user_id" bigint,
+    "ledger_entry_id" bigint,
+    "blockchain" enum_block_chain,
+    "user_address" varchar,
+    "escrow_contract_address" varchar,
+    "receiver_address" varchar,
+    "quantity" decimal(56, 18),
+    "transaction_hash" varchar
+)
+LANGUAGE plpgsql
+AS $$
+    
+DECLARE
+    _pending tbl.user_pending_withdraw;
+    _ledger_entry_id bigint;
+BEGIN
+    -- deleting the row first makes sure a withdraw is settled only once
+    DELETE FROM tbl.user_pending_withdraw
+    WHERE pkey_id = a_pending_withdraw_id
+    RETURNING * INTO _pending;
+    IF _pending.pkey_id IS NULL THEN
+        RETURN;
+    END IF;
+    IF a_is_successful THEN
+        SELECT l.ledger_entry_id INTO _ledger_entry_id FROM api.fun_user_add_user_deposit_withdraw_ledger_entry(
+            a_user_id => _pending.fkey_user_id,
+            a_token_address => _pending.token_address,
+            a_blockchain => _pending.blockchain,
+            a_user_address => _pending.user_address,
+            a_escrow_contract_address => _pending.escrow_contract_address,
+            a_receiver_address => _pending.receiver_address,
+            a_quantity => _pending.quantity,
+            a_transaction_hash => _pending.transaction_hash,
+            a_is_deposit => FALSE,
+            a_is_back => FALSE,
+            a_is_withdraw => TRUE,
+            a_is_fee => _pending.is_fee
+        ) AS l;
+    ELSE
+        -- the transfer never happened, give the debited balance back
+        UPDATE tbl.user_deposit_withdraw_balance
+        SET balance = balance + _pending.quantity
+        WHERE pkey_id = _pending.fkey_deposit_withdraw_balance_id;
+    END IF;
+    RETURN QUERY SELECT
+        _pending.fkey_user_id,
+        _ledger_entry_id,
+        _pending.blockchain,
+        _pending.user_address,
+        _pending.escrow_contract_address,
+        _pending.receiver_address,
+        _pending.quantity,
+        _pending.transaction_hash;
+END
+
+$$;
+        
+
 CREATE OR REPLACE FUNCTION api.fun_asset_price_insert_asset_prices(a_symbols varchar[], a_prices double precision[], a_timestamps bigint[] DEFAULT NULL)
 RETURNS table (
     "success" boolean
@@ -0,0 +1,3 @@
+-- schema of db/model.sql and db/tbl.sql before versioned migrations were introduced.
+-- databases created before this point get the later migrations applied by `mc2fi_migrate up`,
+-- databases created from the current db/tbl.sql are recorded with `mc2fi_migrate baseline`
@@ -0,0 +1,56 @@
+CREATE TYPE enum_activity_report_format AS ENUM ('Json', 'Csv');
+CREATE TYPE enum_user_activity_event_type AS ENUM ('Deposit', 'Refund', 'EscrowSpent', 'BackStrategy', 'ExitStrategy', 'PoolAssetAdd', 'PoolAssetRemove');
+CREATE TYPE enum_strategy_guard_rule_type AS ENUM ('MaxDrawdown', 'AssetStopLoss', 'TakeProfit');
+
+ALTER TABLE tbl.strategy
+    ADD COLUMN rebalance_interval_seconds bigint  NULL,
+    ADD COLUMN rebalance_drift_threshold double precision  NULL,
+    ADD COLUMN last_rebalanced_at bigint  NULL,
+    ADD COLUMN copy_trading_paused boolean  NOT NULL DEFAULT FALSE,
+    ADD COLUMN copy_trade_min_usd double precision  NULL,
+    ADD COLUMN copy_trade_max_aum_ratio double precision  NULL,
+    ADD COLUMN copy_trade_cooldown_seconds bigint  NULL,
+    ADD COLUMN copy_trade_batch_window_seconds bigint  NULL,
+    ADD COLUMN copy_trade_pending_since bigint  NULL,
+    ADD COLUMN last_copy_traded_at bigint  NULL;
+
+CREATE SEQUENCE tbl.seq_strategy_guard_rule_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
+CREATE TABLE tbl.strategy_guard_rule (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_strategy_guard_rule_id'),
+    fkey_strategy_id bigint  NOT NULL,
+    rule_type enum_strategy_guard_rule_type  NOT NULL,
+    fkey_token_id bigint  NULL,
+    threshold double precision  NOT NULL,
+    reference_value double precision  NULL,
+    fkey_exit_token_id bigint  NULL,
+    triggered_at bigint  NULL,
+    created_at bigint  NOT NULL,
+    CONSTRAINT strategy_guard_rule_pk PRIMARY KEY (pkey_id)
+);
+
+ALTER TABLE tbl.strategy_guard_rule ADD CONSTRAINT strategy_guard_rule_exit_token
+    FOREIGN KEY (fkey_exit_token_id)
+    REFERENCES tbl.escrow_token_contract_address (pkey_id)
+    NOT DEFERRABLE
+    INITIALLY IMMEDIATE
+;
+
+ALTER TABLE tbl.strategy_guard_rule ADD CONSTRAINT strategy_guard_rule_strategy
+    FOREIGN KEY (fkey_strategy_id)
+    REFERENCES tbl.strategy (pkey_id)
+    NOT DEFERRABLE
+    INITIALLY IMMEDIATE
+;
+
+ALTER TABLE tbl.strategy_guard_rule ADD CONSTRAINT strategy_guard_rule_token
+    FOREIGN KEY (fkey_token_id)
+    REFERENCES tbl.escrow_token_contract_address (pkey_id)
+    NOT DEFERRABLE
+    INITIALLY IMMEDIATE
+;
@@ -0,0 +1,35 @@
+CREATE TYPE enum_user_notification_kind AS ENUM ('StrategyTrade', 'BackStrategy', 'ExitStrategy', 'Refund', 'ExpertNewStrategy');
+
+CREATE SEQUENCE tbl.seq_user_notification_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
+CREATE TABLE tbl.user_notification (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_user_notification_id'),
+    fkey_user_id bigint  NOT NULL,
+    kind enum_user_notification_kind  NOT NULL,
+    fkey_strategy_id bigint  NULL,
+    message varchar(512)  NOT NULL,
+    read_at bigint  NULL,
+    created_at bigint  NOT NULL,
+    CONSTRAINT user_notification_pk PRIMARY KEY (pkey_id)
+);
+
+CREATE INDEX user_notification_idx_1 on tbl.user_notification (fkey_user_id ASC,pkey_id DESC);
+
+ALTER TABLE tbl.user_notification ADD CONSTRAINT user_notification_strategy
+    FOREIGN KEY (fkey_strategy_id)
+    REFERENCES tbl.strategy (pkey_id)
+    NOT DEFERRABLE
+    INITIALLY IMMEDIATE
+;
+
+ALTER TABLE tbl.user_notification ADD CONSTRAINT user_notification_user
+    FOREIGN KEY (fkey_user_id)
+    REFERENCES tbl."user" (pkey_id)
+    NOT DEFERRABLE
+    INITIALLY IMMEDIATE
+;
@@ -0,0 +1,20 @@
+CREATE SEQUENCE tbl.seq_login_nonce_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
+CREATE TABLE tbl.login_nonce (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_login_nonce_id'),
+    nonce varchar(64)  NOT NULL,
+    address varchar(64)  NOT NULL,
+    ip_address inet  NOT NULL,
+    created_at bigint  NOT NULL,
+    expires_at bigint  NOT NULL,
+    used_at bigint  NULL,
+    CONSTRAINT login_nonce_ak_1 UNIQUE (nonce) NOT DEFERRABLE  INITIALLY IMMEDIATE,
+    CONSTRAINT login_nonce_pk PRIMARY KEY (pkey_id)
+);
+
+CREATE INDEX login_nonce_idx_1 on tbl.login_nonce (expires_at ASC);
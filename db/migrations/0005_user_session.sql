@@ -0,0 +1,48 @@
+CREATE SEQUENCE tbl.seq_user_session_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
+CREATE TABLE tbl.user_session (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_user_session_id'),
+    fkey_user_id bigint  NOT NULL,
+    service enum_service  NOT NULL,
+    device_id varchar(256)  NOT NULL,
+    device_os varchar(64)  NOT NULL,
+    ip_address inet  NOT NULL,
+    access_token uuid  NOT NULL,
+    access_expires_at bigint  NOT NULL,
+    refresh_token uuid  NOT NULL,
+    refresh_expires_at bigint  NOT NULL,
+    created_at bigint  NOT NULL,
+    last_seen_at bigint  NOT NULL,
+    revoked_at bigint  NULL,
+    CONSTRAINT user_session_ak_1 UNIQUE (access_token) NOT DEFERRABLE  INITIALLY IMMEDIATE,
+    CONSTRAINT user_session_ak_2 UNIQUE (refresh_token) NOT DEFERRABLE  INITIALLY IMMEDIATE,
+    CONSTRAINT user_session_pk PRIMARY KEY (pkey_id)
+);
+
+CREATE UNIQUE INDEX user_session_idx_1 on tbl.user_session (fkey_user_id ASC,service ASC,device_id ASC) WHERE revoked_at IS NULL;
+
+CREATE TABLE tbl.user_session_rotated_token (
+    refresh_token uuid  NOT NULL,
+    fkey_session_id bigint  NOT NULL,
+    rotated_at bigint  NOT NULL,
+    CONSTRAINT user_session_rotated_token_pk PRIMARY KEY (refresh_token)
+);
+
+ALTER TABLE tbl.user_session_rotated_token ADD CONSTRAINT user_session_rotated_token_user_session
+    FOREIGN KEY (fkey_session_id)
+    REFERENCES tbl.user_session (pkey_id)
+    NOT DEFERRABLE
+    INITIALLY IMMEDIATE
+;
+
+ALTER TABLE tbl.user_session ADD CONSTRAINT user_session_user
+    FOREIGN KEY (fkey_user_id)
+    REFERENCES tbl."user" (pkey_id)
+    NOT DEFERRABLE
+    INITIALLY IMMEDIATE
+;
@@ -0,0 +1,33 @@
+CREATE TYPE enum_api_key_scope AS ENUM ('ReadOnly', 'Trade', 'Withdraw');
+
+CREATE SEQUENCE tbl.seq_user_api_key_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
+CREATE TABLE tbl.user_api_key (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_user_api_key_id'),
+    fkey_user_id bigint  NOT NULL,
+    name varchar(64)  NOT NULL,
+    api_key varchar(64)  NOT NULL,
+    api_secret varchar(64)  NOT NULL,
+    scopes enum_api_key_scope[]  NOT NULL,
+    ip_allowlist inet[]  NULL,
+    expires_at bigint  NULL,
+    created_at bigint  NOT NULL,
+    last_used_at bigint  NULL,
+    revoked_at bigint  NULL,
+    CONSTRAINT user_api_key_ak_1 UNIQUE (api_key) NOT DEFERRABLE  INITIALLY IMMEDIATE,
+    CONSTRAINT user_api_key_pk PRIMARY KEY (pkey_id)
+);
+
+CREATE INDEX user_api_key_idx_1 on tbl.user_api_key (fkey_user_id ASC);
+
+ALTER TABLE tbl.user_api_key ADD CONSTRAINT user_api_key_user
+    FOREIGN KEY (fkey_user_id)
+    REFERENCES tbl."user" (pkey_id)
+    NOT DEFERRABLE
+    INITIALLY IMMEDIATE
+;
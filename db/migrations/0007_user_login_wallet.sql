@@ -0,0 +1,30 @@
+CREATE SEQUENCE tbl.seq_user_login_wallet_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
+CREATE TABLE tbl.user_login_wallet (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_user_login_wallet_id'),
+    fkey_user_id bigint  NOT NULL,
+    address varchar(64)  NOT NULL,
+    is_primary boolean  NOT NULL,
+    created_at bigint  NOT NULL,
+    CONSTRAINT user_login_wallet_ak_1 UNIQUE (address) NOT DEFERRABLE  INITIALLY IMMEDIATE,
+    CONSTRAINT user_login_wallet_pk PRIMARY KEY (pkey_id)
+);
+
+CREATE UNIQUE INDEX user_login_wallet_idx_1 on tbl.user_login_wallet (fkey_user_id ASC) WHERE is_primary;
+
+ALTER TABLE tbl.user_login_wallet ADD CONSTRAINT user_login_wallet_user
+    FOREIGN KEY (fkey_user_id)
+    REFERENCES tbl."user" (pkey_id)
+    NOT DEFERRABLE
+    INITIALLY IMMEDIATE
+;
+
+-- every existing login address becomes the primary wallet of its account
+INSERT INTO tbl.user_login_wallet (fkey_user_id, address, is_primary, created_at)
+SELECT pkey_id, address, TRUE, created_at
+FROM tbl."user";
@@ -0,0 +1,73 @@
+CREATE TYPE enum_admin_pending_action_status AS ENUM ('Pending', 'Approved', 'Executed', 'Failed');
+
+CREATE SEQUENCE tbl.seq_admin_pending_action_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
+CREATE SEQUENCE tbl.seq_admin_action_log_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
+CREATE TABLE tbl.admin_pending_action (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_admin_pending_action_id'),
+    fkey_requested_by_user_id bigint  NOT NULL,
+    method_id int  NOT NULL,
+    method_name varchar(64)  NOT NULL,
+    params jsonb  NOT NULL,
+    status enum_admin_pending_action_status  NOT NULL,
+    fkey_approved_by_user_id bigint  NULL,
+    approved_at bigint  NULL,
+    created_at bigint  NOT NULL,
+    CONSTRAINT admin_pending_action_pk PRIMARY KEY (pkey_id)
+);
+
+CREATE INDEX admin_pending_action_idx_1 on tbl.admin_pending_action (status ASC);
+
+CREATE TABLE tbl.admin_action_log (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_admin_action_log_id'),
+    fkey_admin_user_id bigint  NOT NULL,
+    fkey_pending_action_id bigint  NULL,
+    method_id int  NOT NULL,
+    method_name varchar(64)  NOT NULL,
+    params jsonb  NOT NULL,
+    state_before jsonb  NULL,
+    state_after jsonb  NULL,
+    created_at bigint  NOT NULL,
+    CONSTRAINT admin_action_log_pk PRIMARY KEY (pkey_id)
+);
+
+CREATE INDEX admin_action_log_idx_1 on tbl.admin_action_log (fkey_admin_user_id ASC);
+
+ALTER TABLE tbl.admin_pending_action ADD CONSTRAINT admin_pending_action_requested_by
+    FOREIGN KEY (fkey_requested_by_user_id)
+    REFERENCES tbl."user" (pkey_id)
+    NOT DEFERRABLE
+    INITIALLY IMMEDIATE
+;
+
+ALTER TABLE tbl.admin_pending_action ADD CONSTRAINT admin_pending_action_approved_by
+    FOREIGN KEY (fkey_approved_by_user_id)
+    REFERENCES tbl."user" (pkey_id)
+    NOT DEFERRABLE
+    INITIALLY IMMEDIATE
+;
+
+ALTER TABLE tbl.admin_action_log ADD CONSTRAINT admin_action_log_user
+    FOREIGN KEY (fkey_admin_user_id)
+    REFERENCES tbl."user" (pkey_id)
+    NOT DEFERRABLE
+    INITIALLY IMMEDIATE
+;
+
+ALTER TABLE tbl.admin_action_log ADD CONSTRAINT admin_action_log_admin_pending_action
+    FOREIGN KEY (fkey_pending_action_id)
+    REFERENCES tbl.admin_pending_action (pkey_id)
+    NOT DEFERRABLE
+    INITIALLY IMMEDIATE
+;
@@ -0,0 +1,40 @@
+CREATE SEQUENCE tbl.seq_system_setting_history_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
+CREATE TABLE tbl.system_setting (
+    key varchar(64)  NOT NULL,
+    value jsonb  NOT NULL,
+    version bigint  NOT NULL,
+    fkey_updated_by_user_id bigint  NULL,
+    updated_at bigint  NOT NULL,
+    CONSTRAINT system_setting_pk PRIMARY KEY (key)
+);
+
+CREATE TABLE tbl.system_setting_history (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_system_setting_history_id'),
+    key varchar(64)  NOT NULL,
+    value jsonb  NOT NULL,
+    version bigint  NOT NULL,
+    fkey_updated_by_user_id bigint  NULL,
+    created_at bigint  NOT NULL,
+    CONSTRAINT system_setting_history_ak_1 UNIQUE (key, version) NOT DEFERRABLE  INITIALLY IMMEDIATE,
+    CONSTRAINT system_setting_history_pk PRIMARY KEY (pkey_id)
+);
+
+ALTER TABLE tbl.system_setting ADD CONSTRAINT system_setting_user
+    FOREIGN KEY (fkey_updated_by_user_id)
+    REFERENCES tbl."user" (pkey_id)
+    NOT DEFERRABLE
+    INITIALLY IMMEDIATE
+;
+
+ALTER TABLE tbl.system_setting_history ADD CONSTRAINT system_setting_history_user
+    FOREIGN KEY (fkey_updated_by_user_id)
+    REFERENCES tbl."user" (pkey_id)
+    NOT DEFERRABLE
+    INITIALLY IMMEDIATE
+;
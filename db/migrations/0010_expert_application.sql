@@ -0,0 +1,108 @@
+CREATE TYPE enum_expert_application_status AS ENUM ('Draft', 'Submitted', 'InfoRequested', 'Approved', 'Rejected');
+
+CREATE SEQUENCE tbl.seq_expert_application_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
+CREATE SEQUENCE tbl.seq_expert_application_document_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
+CREATE SEQUENCE tbl.seq_expert_application_note_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
+CREATE TABLE tbl.expert_application (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_expert_application_id'),
+    fkey_user_id bigint  NOT NULL,
+    status enum_expert_application_status  NOT NULL,
+    questionnaire jsonb  NOT NULL,
+    fkey_reviewed_by_user_id bigint  NULL,
+    submitted_at bigint  NULL,
+    reviewed_at bigint  NULL,
+    updated_at bigint  NOT NULL,
+    created_at bigint  NOT NULL,
+    CONSTRAINT expert_application_ak_1 UNIQUE (fkey_user_id) NOT DEFERRABLE  INITIALLY IMMEDIATE,
+    CONSTRAINT expert_application_pk PRIMARY KEY (pkey_id)
+);
+
+CREATE TABLE tbl.expert_application_document (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_expert_application_document_id'),
+    fkey_application_id bigint  NOT NULL,
+    name varchar(256)  NOT NULL,
+    content_type varchar(128)  NOT NULL,
+    size bigint  NOT NULL,
+    blob_key varchar(256)  NOT NULL,
+    created_at bigint  NOT NULL,
+    CONSTRAINT expert_application_document_pk PRIMARY KEY (pkey_id)
+);
+
+CREATE INDEX expert_application_document_idx_1 on tbl.expert_application_document (fkey_application_id ASC);
+
+CREATE TABLE tbl.expert_application_note (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_expert_application_note_id'),
+    fkey_application_id bigint  NOT NULL,
+    fkey_author_user_id bigint  NOT NULL,
+    status enum_expert_application_status  NOT NULL,
+    note varchar(4096)  NULL,
+    created_at bigint  NOT NULL,
+    CONSTRAINT expert_application_note_pk PRIMARY KEY (pkey_id)
+);
+
+CREATE INDEX expert_application_note_idx_1 on tbl.expert_application_note (fkey_application_id ASC);
+
+ALTER TABLE tbl.expert_application ADD CONSTRAINT expert_application_user
+    FOREIGN KEY (fkey_user_id)
+    REFERENCES tbl."user" (pkey_id)
+    NOT DEFERRABLE
+    INITIALLY IMMEDIATE
+;
+
+ALTER TABLE tbl.expert_application ADD CONSTRAINT expert_application_reviewed_by
+    FOREIGN KEY (fkey_reviewed_by_user_id)
+    REFERENCES tbl."user" (pkey_id)
+    NOT DEFERRABLE
+    INITIALLY IMMEDIATE
+;
+
+ALTER TABLE tbl.expert_application_document ADD CONSTRAINT expert_application_document_application
+    FOREIGN KEY (fkey_application_id)
+    REFERENCES tbl.expert_application (pkey_id)
+    NOT DEFERRABLE
+    INITIALLY IMMEDIATE
+;
+
+ALTER TABLE tbl.expert_application_note ADD CONSTRAINT expert_application_note_application
+    FOREIGN KEY (fkey_application_id)
+    REFERENCES tbl.expert_application (pkey_id)
+    NOT DEFERRABLE
+    INITIALLY IMMEDIATE
+;
+
+ALTER TABLE tbl.expert_application_note ADD CONSTRAINT expert_application_note_author
+    FOREIGN KEY (fkey_author_user_id)
+    REFERENCES tbl."user" (pkey_id)
+    NOT DEFERRABLE
+    INITIALLY IMMEDIATE
+;
+
+-- pending and approved experts get an application in the matching state
+INSERT INTO tbl.expert_application (fkey_user_id, status, questionnaire, submitted_at, reviewed_at, updated_at, created_at)
+SELECT fkey_user_id,
+       CASE WHEN approved_expert THEN 'Approved' ELSE 'Submitted' END::enum_expert_application_status,
+       '{}'::jsonb,
+       requested_at,
+       approved_at,
+       updated_at,
+       created_at
+FROM tbl.expert_profile
+WHERE approved_expert OR pending_expert;
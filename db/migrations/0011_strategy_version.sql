@@ -0,0 +1,44 @@
+ALTER TYPE enum_user_notification_kind ADD VALUE IF NOT EXISTS 'StrategyChange' AFTER 'ExpertNewStrategy';
+
+CREATE TYPE enum_strategy_version_status AS ENUM ('Scheduled', 'Applied', 'Failed');
+
+CREATE SEQUENCE tbl.seq_strategy_version_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
+CREATE TABLE tbl.strategy_version (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_strategy_version_id'),
+    fkey_strategy_id bigint  NOT NULL,
+    version bigint  NOT NULL,
+    fkey_changed_by_user_id bigint  NOT NULL,
+    method_id int  NOT NULL,
+    method_name varchar(64)  NOT NULL,
+    params jsonb  NOT NULL,
+    diff jsonb  NULL,
+    is_material boolean  NOT NULL,
+    status enum_strategy_version_status  NOT NULL,
+    effective_at bigint  NOT NULL,
+    applied_at bigint  NULL,
+    created_at bigint  NOT NULL,
+    CONSTRAINT strategy_version_ak_1 UNIQUE (fkey_strategy_id, version) NOT DEFERRABLE  INITIALLY IMMEDIATE,
+    CONSTRAINT strategy_version_pk PRIMARY KEY (pkey_id)
+);
+
+CREATE INDEX strategy_version_idx_1 on tbl.strategy_version (status, effective_at ASC);
+
+ALTER TABLE tbl.strategy_version ADD CONSTRAINT strategy_version_strategy
+    FOREIGN KEY (fkey_strategy_id)
+    REFERENCES tbl.strategy (pkey_id)
+    NOT DEFERRABLE
+    INITIALLY IMMEDIATE
+;
+
+ALTER TABLE tbl.strategy_version ADD CONSTRAINT strategy_version_user
+    FOREIGN KEY (fkey_changed_by_user_id)
+    REFERENCES tbl."user" (pkey_id)
+    NOT DEFERRABLE
+    INITIALLY IMMEDIATE
+;
@@ -0,0 +1,26 @@
+CREATE TYPE enum_expert_sort_by AS ENUM ('Newest', 'Followers', 'Reputation', 'Consistency');
+
+CREATE SEQUENCE tbl.seq_strategy_daily_value_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
+CREATE TABLE tbl.strategy_daily_value (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_strategy_daily_value_id'),
+    fkey_strategy_id bigint  NOT NULL,
+    day bigint  NOT NULL,
+    aum_usd double precision  NOT NULL,
+    share_price double precision  NULL,
+    updated_at bigint  NOT NULL,
+    CONSTRAINT strategy_daily_value_ak_1 UNIQUE (fkey_strategy_id, day) NOT DEFERRABLE  INITIALLY IMMEDIATE,
+    CONSTRAINT strategy_daily_value_pk PRIMARY KEY (pkey_id)
+);
+
+ALTER TABLE tbl.strategy_daily_value ADD CONSTRAINT strategy_daily_value_strategy
+    FOREIGN KEY (fkey_strategy_id)
+    REFERENCES tbl.strategy (pkey_id)
+    NOT DEFERRABLE
+    INITIALLY IMMEDIATE
+;
@@ -0,0 +1 @@
+ALTER TYPE enum_user_activity_event_type ADD VALUE IF NOT EXISTS 'Withdraw';
@@ -0,0 +1,41 @@
+ALTER TYPE enum_user_activity_event_type ADD VALUE IF NOT EXISTS 'WithdrawFee';
+
+ALTER TABLE tbl.user_deposit_withdraw_ledger ADD COLUMN is_fee boolean  NOT NULL DEFAULT FALSE;
+
+CREATE SEQUENCE tbl.seq_user_pending_withdraw_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
+CREATE TABLE tbl.user_pending_withdraw (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_user_pending_withdraw_id'),
+    fkey_user_id bigint  NOT NULL,
+    fkey_deposit_withdraw_balance_id bigint  NOT NULL,
+    blockchain enum_block_chain  NOT NULL,
+    token_address varchar(64)  NOT NULL,
+    user_address varchar(64)  NOT NULL,
+    escrow_contract_address varchar(64)  NOT NULL,
+    receiver_address varchar(64)  NOT NULL,
+    quantity decimal(56, 18)  NOT NULL,
+    transaction_hash varchar(80)  NOT NULL,
+    is_fee boolean  NOT NULL,
+    created_at bigint  NOT NULL,
+    CONSTRAINT user_pending_withdraw_ak_1 UNIQUE (transaction_hash) NOT DEFERRABLE  INITIALLY IMMEDIATE,
+    CONSTRAINT user_pending_withdraw_pk PRIMARY KEY (pkey_id)
+);
+
+ALTER TABLE tbl.user_pending_withdraw ADD CONSTRAINT user_pending_withdraw_balance
+    FOREIGN KEY (fkey_deposit_withdraw_balance_id)
+    REFERENCES tbl.user_deposit_withdraw_balance (pkey_id)
+    NOT DEFERRABLE
+    INITIALLY IMMEDIATE
+;
+
+ALTER TABLE tbl.user_pending_withdraw ADD CONSTRAINT user_pending_withdraw_user
+    FOREIGN KEY (fkey_user_id)
+    REFERENCES tbl."user" (pkey_id)
+    NOT DEFERRABLE
+    INITIALLY IMMEDIATE
+;
@@ -0,0 +1,35 @@
+ALTER TYPE enum_user_notification_kind ADD VALUE IF NOT EXISTS 'StrategyGuard';
+
+CREATE SEQUENCE tbl.seq_strategy_guard_rule_reference_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
+-- every pool of a strategy is guarded on its own, the reference is the value of one share of the pool
+CREATE TABLE tbl.strategy_guard_rule_reference (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_strategy_guard_rule_reference_id'),
+    fkey_strategy_guard_rule_id bigint  NOT NULL,
+    fkey_strategy_pool_contract_id bigint  NOT NULL,
+    reference_value double precision  NOT NULL,
+    CONSTRAINT strategy_guard_rule_reference_ak_1 UNIQUE (fkey_strategy_guard_rule_id, fkey_strategy_pool_contract_id) NOT DEFERRABLE  INITIALLY IMMEDIATE,
+    CONSTRAINT strategy_guard_rule_reference_pk PRIMARY KEY (pkey_id)
+);
+
+ALTER TABLE tbl.strategy_guard_rule_reference ADD CONSTRAINT strategy_guard_rule_reference_rule
+    FOREIGN KEY (fkey_strategy_guard_rule_id)
+    REFERENCES tbl.strategy_guard_rule (pkey_id)
+    NOT DEFERRABLE
+    INITIALLY IMMEDIATE
+;
+
+ALTER TABLE tbl.strategy_guard_rule_reference ADD CONSTRAINT strategy_guard_rule_reference_strategy_pool_contract
+    FOREIGN KEY (fkey_strategy_pool_contract_id)
+    REFERENCES tbl.strategy_pool_contract (pkey_id)
+    NOT DEFERRABLE
+    INITIALLY IMMEDIATE
+;
+
+-- the old references were pool NAVs, which are not comparable with share values
+ALTER TABLE tbl.strategy_guard_rule DROP COLUMN reference_value;
@@ -0,0 +1,4 @@
+-- every user service consumes the same bus events, the key lets them store one notification per event
+ALTER TABLE tbl.user_notification ADD COLUMN event_key varchar(128)  NULL;
+
+CREATE UNIQUE INDEX user_notification_idx_2 on tbl.user_notification (fkey_user_id ASC,event_key ASC);
@@ -0,0 +1,19 @@
+CREATE SEQUENCE tbl.seq_event_bus_outbox_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
+-- events of lib::event_bus::EventBus, listeners read the events they missed while reconnecting
+CREATE TABLE tbl.event_bus_outbox (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_event_bus_outbox_id'),
+    channel varchar(64)  NOT NULL,
+    payload text  NOT NULL,
+    created_at bigint  NOT NULL,
+    CONSTRAINT event_bus_outbox_pk PRIMARY KEY (pkey_id)
+);
+
+CREATE INDEX event_bus_outbox_idx_1 on tbl.event_bus_outbox (channel ASC,pkey_id ASC);
+
+CREATE INDEX event_bus_outbox_idx_2 on tbl.event_bus_outbox (created_at ASC);
@@ -0,0 +1,2 @@
+-- a scheduled version is claimed by one user service before it is replayed
+ALTER TYPE enum_strategy_version_status ADD VALUE IF NOT EXISTS 'Applying';
@@ -0,0 +1,25 @@
+-- every signed AuthorizeApiKey handshake carries a nonce that can only be used once
+CREATE SEQUENCE tbl.seq_user_api_key_nonce_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
+CREATE TABLE tbl.user_api_key_nonce (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_user_api_key_nonce_id'),
+    fkey_api_key_id bigint  NOT NULL,
+    nonce varchar(64)  NOT NULL,
+    timestamp bigint  NOT NULL,
+    CONSTRAINT user_api_key_nonce_ak_1 UNIQUE (fkey_api_key_id, nonce) NOT DEFERRABLE  INITIALLY IMMEDIATE,
+    CONSTRAINT user_api_key_nonce_pk PRIMARY KEY (pkey_id)
+);
+
+CREATE INDEX user_api_key_nonce_idx_1 on tbl.user_api_key_nonce (timestamp ASC);
+
+ALTER TABLE tbl.user_api_key_nonce ADD CONSTRAINT user_api_key_nonce_user_api_key
+    FOREIGN KEY (fkey_api_key_id)
+    REFERENCES tbl.user_api_key (pkey_id)  
+    NOT DEFERRABLE 
+    INITIALLY IMMEDIATE
+;
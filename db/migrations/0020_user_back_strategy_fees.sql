@@ -0,0 +1,13 @@
+-- the fees charged when a strategy is backed, so reports don't depend on the current fees of the strategy
+ALTER TABLE tbl.user_back_exit_strategy_ledger ADD COLUMN IF NOT EXISTS fees decimal(56, 18) NOT NULL DEFAULT 0;
+
+-- earlier backs didn't record their fees, the fees of the strategy are the closest estimate left
+UPDATE tbl.user_back_exit_strategy_ledger AS a
+SET fees = a.quantity_of_usdc * (
+        COALESCE(s.swap_fee, 0)
+        + COALESCE((SELECT conf.platform_fee FROM tbl.system_config AS conf), 0)
+        + COALESCE(s.expert_fee, 0)
+    )::decimal
+FROM tbl.strategy AS s
+WHERE s.pkey_id = a.fkey_strategy_id
+  AND a.is_back;
@@ -4,4 +4,13 @@ CREATE TYPE enum_blockchain_coin AS ENUM ('USDC', 'USDT', 'BUSD', 'WETH', 'WBNB'
 CREATE TYPE enum_dex AS ENUM ('UniSwap', 'PancakeSwap', 'SushiSwap');
 CREATE TYPE enum_dex_version AS ENUM ('V1', 'V2', 'V3');
 CREATE TYPE enum_dex_path_format AS ENUM ('Json', 'TransactionData', 'TransactionHash');
+CREATE TYPE enum_activity_report_format AS ENUM ('Json', 'Csv');
+CREATE TYPE enum_user_activity_event_type AS ENUM ('Deposit', 'Refund', 'EscrowSpent', 'BackStrategy', 'ExitStrategy', 'PoolAssetAdd', 'PoolAssetRemove', 'Withdraw', 'WithdrawFee');
+CREATE TYPE enum_strategy_guard_rule_type AS ENUM ('MaxDrawdown', 'AssetStopLoss', 'TakeProfit');
+CREATE TYPE enum_user_notification_kind AS ENUM ('StrategyTrade', 'BackStrategy', 'ExitStrategy', 'Refund', 'ExpertNewStrategy', 'StrategyChange', 'StrategyGuard');
+CREATE TYPE enum_api_key_scope AS ENUM ('ReadOnly', 'Trade', 'Withdraw');
+CREATE TYPE enum_admin_pending_action_status AS ENUM ('Pending', 'Approved', 'Executed', 'Failed');
+CREATE TYPE enum_expert_application_status AS ENUM ('Draft', 'Submitted', 'InfoRequested', 'Approved', 'Rejected');
+CREATE TYPE enum_expert_sort_by AS ENUM ('Newest', 'Followers', 'Reputation', 'Consistency');
+CREATE TYPE enum_strategy_version_status AS ENUM ('Scheduled', 'Applied', 'Failed', 'Applying');
 CREATE TYPE enum_service AS ENUM ('auth', 'user', 'admin', 'watcher', 'asset_price');
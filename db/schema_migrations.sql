@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS public.schema_migrations (
+    version bigint  NOT NULL,
+    name varchar  NOT NULL,
+    checksum varchar  NOT NULL,
+    applied_at bigint  NOT NULL,
+    CONSTRAINT schema_migrations_pk PRIMARY KEY (version)
+);
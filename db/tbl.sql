@@ -4,6 +4,38 @@
 CREATE SCHEMA IF NOT EXISTS tbl;;
 
 -- tables
+-- Table: admin_action_log
+CREATE TABLE tbl.admin_action_log (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_admin_action_log_id'),
+    fkey_admin_user_id bigint  NOT NULL,
+    fkey_pending_action_id bigint  NULL,
+    method_id int  NOT NULL,
+    method_name varchar(64)  NOT NULL,
+    params jsonb  NOT NULL,
+    state_before jsonb  NULL,
+    state_after jsonb  NULL,
+    created_at bigint  NOT NULL,
+    CONSTRAINT admin_action_log_pk PRIMARY KEY (pkey_id)
+);
+
+CREATE INDEX admin_action_log_idx_1 on tbl.admin_action_log (fkey_admin_user_id ASC);
+
+-- Table: admin_pending_action
+CREATE TABLE tbl.admin_pending_action (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_admin_pending_action_id'),
+    fkey_requested_by_user_id bigint  NOT NULL,
+    method_id int  NOT NULL,
+    method_name varchar(64)  NOT NULL,
+    params jsonb  NOT NULL,
+    status enum_admin_pending_action_status  NOT NULL,
+    fkey_approved_by_user_id bigint  NULL,
+    approved_at bigint  NULL,
+    created_at bigint  NOT NULL,
+    CONSTRAINT admin_pending_action_pk PRIMARY KEY (pkey_id)
+);
+
+CREATE INDEX admin_pending_action_idx_1 on tbl.admin_pending_action (status ASC);
+
 -- Table: audit_rule
 CREATE TABLE tbl.audit_rule (
     pkey_id bigint  NOT NULL,
@@ -91,6 +123,61 @@ CREATE TABLE tbl.escrow_token_contract_address (
     CONSTRAINT escrow_token_contract_address_pk PRIMARY KEY (pkey_id)
 );
 
+-- Table: event_bus_outbox
+CREATE TABLE tbl.event_bus_outbox (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_event_bus_outbox_id'),
+    channel varchar(64)  NOT NULL,
+    payload text  NOT NULL,
+    created_at bigint  NOT NULL,
+    CONSTRAINT event_bus_outbox_pk PRIMARY KEY (pkey_id)
+);
+
+CREATE INDEX event_bus_outbox_idx_1 on tbl.event_bus_outbox (channel ASC,pkey_id ASC);
+
+CREATE INDEX event_bus_outbox_idx_2 on tbl.event_bus_outbox (created_at ASC);
+
+-- Table: expert_application
+CREATE TABLE tbl.expert_application (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_expert_application_id'),
+    fkey_user_id bigint  NOT NULL,
+    status enum_expert_application_status  NOT NULL,
+    questionnaire jsonb  NOT NULL,
+    fkey_reviewed_by_user_id bigint  NULL,
+    submitted_at bigint  NULL,
+    reviewed_at bigint  NULL,
+    updated_at bigint  NOT NULL,
+    created_at bigint  NOT NULL,
+    CONSTRAINT expert_application_ak_1 UNIQUE (fkey_user_id) NOT DEFERRABLE  INITIALLY IMMEDIATE,
+    CONSTRAINT expert_application_pk PRIMARY KEY (pkey_id)
+);
+
+-- Table: expert_application_document
+CREATE TABLE tbl.expert_application_document (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_expert_application_document_id'),
+    fkey_application_id bigint  NOT NULL,
+    name varchar(256)  NOT NULL,
+    content_type varchar(128)  NOT NULL,
+    size bigint  NOT NULL,
+    blob_key varchar(256)  NOT NULL,
+    created_at bigint  NOT NULL,
+    CONSTRAINT expert_application_document_pk PRIMARY KEY (pkey_id)
+);
+
+CREATE INDEX expert_application_document_idx_1 on tbl.expert_application_document (fkey_application_id ASC);
+
+-- Table: expert_application_note
+CREATE TABLE tbl.expert_application_note (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_expert_application_note_id'),
+    fkey_application_id bigint  NOT NULL,
+    fkey_author_user_id bigint  NOT NULL,
+    status enum_expert_application_status  NOT NULL,
+    note varchar(4096)  NULL,
+    created_at bigint  NOT NULL,
+    CONSTRAINT expert_application_note_pk PRIMARY KEY (pkey_id)
+);
+
+CREATE INDEX expert_application_note_idx_1 on tbl.expert_application_note (fkey_application_id ASC);
+
 -- Table: expert_listened_wallet_asset_balance
 CREATE TABLE tbl.expert_listened_wallet_asset_balance (
     pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_expert_listened_wallet_asset_balance_id'),
@@ -158,6 +245,21 @@ CREATE TABLE tbl.login_attempt (
     CONSTRAINT "tbl.login_attempt_pk" PRIMARY KEY (pkey_id)
 );
 
+-- Table: login_nonce
+CREATE TABLE tbl.login_nonce (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_login_nonce_id'),
+    nonce varchar(64)  NOT NULL,
+    address varchar(64)  NOT NULL,
+    ip_address inet  NOT NULL,
+    created_at bigint  NOT NULL,
+    expires_at bigint  NOT NULL,
+    used_at bigint  NULL,
+    CONSTRAINT login_nonce_ak_1 UNIQUE (nonce) NOT DEFERRABLE  INITIALLY IMMEDIATE,
+    CONSTRAINT login_nonce_pk PRIMARY KEY (pkey_id)
+);
+
+CREATE INDEX login_nonce_idx_1 on tbl.login_nonce (expires_at ASC);
+
 -- Table: strategy
 CREATE TABLE tbl.strategy (
     pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_strategy_id'),
@@ -188,6 +290,16 @@ CREATE TABLE tbl.strategy (
     immutable_audit_rules boolean  NOT NULL DEFAULT FALSE,
     blockchain enum_block_chain  NOT NULL,
     strategy_pool_address varchar(64)  NULL,
+    rebalance_interval_seconds bigint  NULL,
+    rebalance_drift_threshold double precision  NULL,
+    last_rebalanced_at bigint  NULL,
+    copy_trading_paused boolean  NOT NULL DEFAULT FALSE,
+    copy_trade_min_usd double precision  NULL,
+    copy_trade_max_aum_ratio double precision  NULL,
+    copy_trade_cooldown_seconds bigint  NULL,
+    copy_trade_batch_window_seconds bigint  NULL,
+    copy_trade_pending_since bigint  NULL,
+    last_copy_traded_at bigint  NULL,
     CONSTRAINT strategy_pk PRIMARY KEY (pkey_id)
 );
 
@@ -202,6 +314,18 @@ CREATE TABLE tbl.strategy_audit_rule (
     CONSTRAINT strategy_audit_rule_pk PRIMARY KEY (pkey_id)
 );
 
+-- Table: strategy_daily_value
+CREATE TABLE tbl.strategy_daily_value (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_strategy_daily_value_id'),
+    fkey_strategy_id bigint  NOT NULL,
+    day bigint  NOT NULL,
+    aum_usd double precision  NOT NULL,
+    share_price double precision  NULL,
+    updated_at bigint  NOT NULL,
+    CONSTRAINT strategy_daily_value_ak_1 UNIQUE (fkey_strategy_id, day) NOT DEFERRABLE  INITIALLY IMMEDIATE,
+    CONSTRAINT strategy_daily_value_pk PRIMARY KEY (pkey_id)
+);
+
 -- Table: strategy_escrow_pending_wallet_address
 CREATE TABLE tbl.strategy_escrow_pending_wallet_address (
     pkey_id bigint  NOT NULL,
@@ -223,6 +347,29 @@ CREATE TABLE tbl.strategy_escrow_pending_wallet_balance (
     CONSTRAINT strategy_escrow_pending_wallet_balance_pk PRIMARY KEY (pkey_id)
 );
 
+-- Table: strategy_guard_rule
+CREATE TABLE tbl.strategy_guard_rule (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_strategy_guard_rule_id'),
+    fkey_strategy_id bigint  NOT NULL,
+    rule_type enum_strategy_guard_rule_type  NOT NULL,
+    fkey_token_id bigint  NULL,
+    threshold double precision  NOT NULL,
+    fkey_exit_token_id bigint  NULL,
+    triggered_at bigint  NULL,
+    created_at bigint  NOT NULL,
+    CONSTRAINT strategy_guard_rule_pk PRIMARY KEY (pkey_id)
+);
+
+-- Table: strategy_guard_rule_reference
+CREATE TABLE tbl.strategy_guard_rule_reference (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_strategy_guard_rule_reference_id'),
+    fkey_strategy_guard_rule_id bigint  NOT NULL,
+    fkey_strategy_pool_contract_id bigint  NOT NULL,
+    reference_value double precision  NOT NULL,
+    CONSTRAINT strategy_guard_rule_reference_ak_1 UNIQUE (fkey_strategy_guard_rule_id, fkey_strategy_pool_contract_id) NOT DEFERRABLE  INITIALLY IMMEDIATE,
+    CONSTRAINT strategy_guard_rule_reference_pk PRIMARY KEY (pkey_id)
+);
+
 -- Table: strategy_initial_token_ratio
 CREATE TABLE tbl.strategy_initial_token_ratio (
     pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_strategy_initial_token_ratio_id'),
@@ -270,6 +417,27 @@ CREATE TABLE tbl.strategy_pool_contract_asset_ledger (
     CONSTRAINT strategy_pool_contract_asset_ledger_pk PRIMARY KEY (pkey_id)
 );
 
+-- Table: strategy_version
+CREATE TABLE tbl.strategy_version (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_strategy_version_id'),
+    fkey_strategy_id bigint  NOT NULL,
+    version bigint  NOT NULL,
+    fkey_changed_by_user_id bigint  NOT NULL,
+    method_id int  NOT NULL,
+    method_name varchar(64)  NOT NULL,
+    params jsonb  NOT NULL,
+    diff jsonb  NULL,
+    is_material boolean  NOT NULL,
+    status enum_strategy_version_status  NOT NULL,
+    effective_at bigint  NOT NULL,
+    applied_at bigint  NULL,
+    created_at bigint  NOT NULL,
+    CONSTRAINT strategy_version_ak_1 UNIQUE (fkey_strategy_id, version) NOT DEFERRABLE  INITIALLY IMMEDIATE,
+    CONSTRAINT strategy_version_pk PRIMARY KEY (pkey_id)
+);
+
+CREATE INDEX strategy_version_idx_1 on tbl.strategy_version (status, effective_at ASC);
+
 -- Table: strategy_watched_wallet
 CREATE TABLE tbl.strategy_watched_wallet (
     pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_strategy_watching_wallet_id'),
@@ -315,6 +483,28 @@ CREATE TABLE tbl.system_config (
     CONSTRAINT system_config_pk PRIMARY KEY (pkey_id)
 );
 
+-- Table: system_setting
+CREATE TABLE tbl.system_setting (
+    key varchar(64)  NOT NULL,
+    value jsonb  NOT NULL,
+    version bigint  NOT NULL,
+    fkey_updated_by_user_id bigint  NULL,
+    updated_at bigint  NOT NULL,
+    CONSTRAINT system_setting_pk PRIMARY KEY (key)
+);
+
+-- Table: system_setting_history
+CREATE TABLE tbl.system_setting_history (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_system_setting_history_id'),
+    key varchar(64)  NOT NULL,
+    value jsonb  NOT NULL,
+    version bigint  NOT NULL,
+    fkey_updated_by_user_id bigint  NULL,
+    created_at bigint  NOT NULL,
+    CONSTRAINT system_setting_history_ak_1 UNIQUE (key, version) NOT DEFERRABLE  INITIALLY IMMEDIATE,
+    CONSTRAINT system_setting_history_pk PRIMARY KEY (pkey_id)
+);
+
 -- Table: token_price
 CREATE TABLE tbl.token_price (
     pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_token_price_id'),
@@ -370,6 +560,37 @@ CREATE TABLE tbl."user" (
     CONSTRAINT user_pk PRIMARY KEY (pkey_id)
 );
 
+-- Table: user_api_key
+CREATE TABLE tbl.user_api_key (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_user_api_key_id'),
+    fkey_user_id bigint  NOT NULL,
+    name varchar(64)  NOT NULL,
+    api_key varchar(64)  NOT NULL,
+    api_secret varchar(64)  NOT NULL,
+    scopes enum_api_key_scope[]  NOT NULL,
+    ip_allowlist inet[]  NULL,
+    expires_at bigint  NULL,
+    created_at bigint  NOT NULL,
+    last_used_at bigint  NULL,
+    revoked_at bigint  NULL,
+    CONSTRAINT user_api_key_ak_1 UNIQUE (api_key) NOT DEFERRABLE  INITIALLY IMMEDIATE,
+    CONSTRAINT user_api_key_pk PRIMARY KEY (pkey_id)
+);
+
+CREATE INDEX user_api_key_idx_1 on tbl.user_api_key (fkey_user_id ASC);
+
+-- Table: user_api_key_nonce
+CREATE TABLE tbl.user_api_key_nonce (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_user_api_key_nonce_id'),
+    fkey_api_key_id bigint  NOT NULL,
+    nonce varchar(64)  NOT NULL,
+    timestamp bigint  NOT NULL,
+    CONSTRAINT user_api_key_nonce_ak_1 UNIQUE (fkey_api_key_id, nonce) NOT DEFERRABLE  INITIALLY IMMEDIATE,
+    CONSTRAINT user_api_key_nonce_pk PRIMARY KEY (pkey_id)
+);
+
+CREATE INDEX user_api_key_nonce_idx_1 on tbl.user_api_key_nonce (timestamp ASC);
+
 -- Table: user_back_exit_strategy_ledger
 CREATE TABLE tbl.user_back_exit_strategy_ledger (
     pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_user_back_exit_strategy_ledger_id'),
@@ -381,6 +602,7 @@ CREATE TABLE tbl.user_back_exit_strategy_ledger (
     quantity_sp_tokens decimal(56, 18)  NOT NULL,
     is_back boolean  NOT NULL,
     happened_at bigint  NOT NULL,
+    fees decimal(56, 18)  NOT NULL DEFAULT 0,
     CONSTRAINT user_back_exit_strategy_ledger_ak_1 UNIQUE (transaction_hash) NOT DEFERRABLE  INITIALLY IMMEDIATE,
     CONSTRAINT user_back_exit_strategy_ledger_pk PRIMARY KEY (pkey_id)
 );
@@ -434,6 +656,7 @@ CREATE TABLE tbl.user_deposit_withdraw_ledger (
     is_back boolean  NOT NULL,
     is_withdraw boolean  NOT NULL,
     happened_at bigint  NOT NULL,
+    is_fee boolean  NOT NULL DEFAULT FALSE,
     CONSTRAINT user_deposit_withdraw_ledger_ak_1 UNIQUE (transaction_hash) NOT DEFERRABLE  INITIALLY IMMEDIATE,
     CONSTRAINT user_deposit_withdraw_ledger_pk PRIMARY KEY (pkey_id)
 );
@@ -460,6 +683,84 @@ CREATE TABLE tbl.user_follow_strategy (
     CONSTRAINT user_follow_strategy_pk PRIMARY KEY (pkey_id)
 );
 
+-- Table: user_login_wallet
+CREATE TABLE tbl.user_login_wallet (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_user_login_wallet_id'),
+    fkey_user_id bigint  NOT NULL,
+    address varchar(64)  NOT NULL,
+    is_primary boolean  NOT NULL,
+    created_at bigint  NOT NULL,
+    CONSTRAINT user_login_wallet_ak_1 UNIQUE (address) NOT DEFERRABLE  INITIALLY IMMEDIATE,
+    CONSTRAINT user_login_wallet_pk PRIMARY KEY (pkey_id)
+);
+
+CREATE UNIQUE INDEX user_login_wallet_idx_1 on tbl.user_login_wallet (fkey_user_id ASC) WHERE is_primary;
+
+-- Table: user_notification
+CREATE TABLE tbl.user_notification (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_user_notification_id'),
+    fkey_user_id bigint  NOT NULL,
+    kind enum_user_notification_kind  NOT NULL,
+    fkey_strategy_id bigint  NULL,
+    message varchar(512)  NOT NULL,
+    read_at bigint  NULL,
+    created_at bigint  NOT NULL,
+    event_key varchar(128)  NULL,
+    CONSTRAINT user_notification_pk PRIMARY KEY (pkey_id)
+);
+
+CREATE INDEX user_notification_idx_1 on tbl.user_notification (fkey_user_id ASC,pkey_id DESC);
+
+CREATE UNIQUE INDEX user_notification_idx_2 on tbl.user_notification (fkey_user_id ASC,event_key ASC);
+
+-- Table: user_pending_withdraw
+CREATE TABLE tbl.user_pending_withdraw (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_user_pending_withdraw_id'),
+    fkey_user_id bigint  NOT NULL,
+    fkey_deposit_withdraw_balance_id bigint  NOT NULL,
+    blockchain enum_block_chain  NOT NULL,
+    token_address varchar(64)  NOT NULL,
+    user_address varchar(64)  NOT NULL,
+    escrow_contract_address varchar(64)  NOT NULL,
+    receiver_address varchar(64)  NOT NULL,
+    quantity decimal(56, 18)  NOT NULL,
+    transaction_hash varchar(80)  NOT NULL,
+    is_fee boolean  NOT NULL,
+    created_at bigint  NOT NULL,
+    CONSTRAINT user_pending_withdraw_ak_1 UNIQUE (transaction_hash) NOT DEFERRABLE  INITIALLY IMMEDIATE,
+    CONSTRAINT user_pending_withdraw_pk PRIMARY KEY (pkey_id)
+);
+
+-- Table: user_session
+CREATE TABLE tbl.user_session (
+    pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_user_session_id'),
+    fkey_user_id bigint  NOT NULL,
+    service enum_service  NOT NULL,
+    device_id varchar(256)  NOT NULL,
+    device_os varchar(64)  NOT NULL,
+    ip_address inet  NOT NULL,
+    access_token uuid  NOT NULL,
+    access_expires_at bigint  NOT NULL,
+    refresh_token uuid  NOT NULL,
+    refresh_expires_at bigint  NOT NULL,
+    created_at bigint  NOT NULL,
+    last_seen_at bigint  NOT NULL,
+    revoked_at bigint  NULL,
+    CONSTRAINT user_session_ak_1 UNIQUE (access_token) NOT DEFERRABLE  INITIALLY IMMEDIATE,
+    CONSTRAINT user_session_ak_2 UNIQUE (refresh_token) NOT DEFERRABLE  INITIALLY IMMEDIATE,
+    CONSTRAINT user_session_pk PRIMARY KEY (pkey_id)
+);
+
+CREATE UNIQUE INDEX user_session_idx_1 on tbl.user_session (fkey_user_id ASC,service ASC,device_id ASC) WHERE revoked_at IS NULL;
+
+-- Table: user_session_rotated_token
+CREATE TABLE tbl.user_session_rotated_token (
+    refresh_token uuid  NOT NULL,
+    fkey_session_id bigint  NOT NULL,
+    rotated_at bigint  NOT NULL,
+    CONSTRAINT user_session_rotated_token_pk PRIMARY KEY (refresh_token)
+);
+
 -- Table: user_strategy_balance
 CREATE TABLE tbl.user_strategy_balance (
     pkey_id bigint  NOT NULL DEFAULT nextval('tbl.seq_user_strategy_balance_id'),
@@ -517,6 +818,38 @@ CREATE TABLE tbl.user_whitelisted_wallet (
 );
 
 -- foreign keys
+-- Reference: admin_action_log_admin_pending_action (table: admin_action_log)
+ALTER TABLE tbl.admin_action_log ADD CONSTRAINT admin_action_log_admin_pending_action
+    FOREIGN KEY (fkey_pending_action_id)
+    REFERENCES tbl.admin_pending_action (pkey_id)  
+    NOT DEFERRABLE 
+    INITIALLY IMMEDIATE
+;
+
+-- Reference: admin_action_log_user (table: admin_action_log)
+ALTER TABLE tbl.admin_action_log ADD CONSTRAINT admin_action_log_user
+    FOREIGN KEY (fkey_admin_user_id)
+    REFERENCES tbl."user" (pkey_id)  
+    NOT DEFERRABLE 
+    INITIALLY IMMEDIATE
+;
+
+-- Reference: admin_pending_action_approved_by (table: admin_pending_action)
+ALTER TABLE tbl.admin_pending_action ADD CONSTRAINT admin_pending_action_approved_by
+    FOREIGN KEY (fkey_approved_by_user_id)
+    REFERENCES tbl."user" (pkey_id)  
+    NOT DEFERRABLE 
+    INITIALLY IMMEDIATE
+;
+
+-- Reference: admin_pending_action_requested_by (table: admin_pending_action)
+ALTER TABLE tbl.admin_pending_action ADD CONSTRAINT admin_pending_action_requested_by
+    FOREIGN KEY (fkey_requested_by_user_id)
+    REFERENCES tbl."user" (pkey_id)  
+    NOT DEFERRABLE 
+    INITIALLY IMMEDIATE
+;
+
 -- Reference: aum_list_strategy (table: aum_history)
 ALTER TABLE tbl.aum_history ADD CONSTRAINT aum_list_strategy
     FOREIGN KEY (fkey_strategy_id)
@@ -557,6 +890,46 @@ ALTER TABLE tbl.dex_path_for_pair ADD CONSTRAINT dex_path_for_pair_escrow_token_
     INITIALLY IMMEDIATE
 ;
 
+-- Reference: expert_application_document_application (table: expert_application_document)
+ALTER TABLE tbl.expert_application_document ADD CONSTRAINT expert_application_document_application
+    FOREIGN KEY (fkey_application_id)
+    REFERENCES tbl.expert_application (pkey_id)  
+    NOT DEFERRABLE 
+    INITIALLY IMMEDIATE
+;
+
+-- Reference: expert_application_note_application (table: expert_application_note)
+ALTER TABLE tbl.expert_application_note ADD CONSTRAINT expert_application_note_application
+    FOREIGN KEY (fkey_application_id)
+    REFERENCES tbl.expert_application (pkey_id)  
+    NOT DEFERRABLE 
+    INITIALLY IMMEDIATE
+;
+
+-- Reference: expert_application_note_author (table: expert_application_note)
+ALTER TABLE tbl.expert_application_note ADD CONSTRAINT expert_application_note_author
+    FOREIGN KEY (fkey_author_user_id)
+    REFERENCES tbl."user" (pkey_id)  
+    NOT DEFERRABLE 
+    INITIALLY IMMEDIATE
+;
+
+-- Reference: expert_application_reviewed_by (table: expert_application)
+ALTER TABLE tbl.expert_application ADD CONSTRAINT expert_application_reviewed_by
+    FOREIGN KEY (fkey_reviewed_by_user_id)
+    REFERENCES tbl."user" (pkey_id)  
+    NOT DEFERRABLE 
+    INITIALLY IMMEDIATE
+;
+
+-- Reference: expert_application_user (table: expert_application)
+ALTER TABLE tbl.expert_application ADD CONSTRAINT expert_application_user
+    FOREIGN KEY (fkey_user_id)
+    REFERENCES tbl."user" (pkey_id)  
+    NOT DEFERRABLE 
+    INITIALLY IMMEDIATE
+;
+
 -- Reference: expert_listened_wallet_asset_balance_fkey (table: expert_listened_wallet_asset_balance)
 ALTER TABLE tbl.expert_listened_wallet_asset_balance ADD CONSTRAINT expert_listened_wallet_asset_balance_fkey
     FOREIGN KEY (fkey_token_id)
@@ -637,6 +1010,14 @@ ALTER TABLE tbl.strategy_audit_rule ADD CONSTRAINT strategy_audit_rule_strategy
     INITIALLY IMMEDIATE
 ;
 
+-- Reference: strategy_daily_value_strategy (table: strategy_daily_value)
+ALTER TABLE tbl.strategy_daily_value ADD CONSTRAINT strategy_daily_value_strategy
+    FOREIGN KEY (fkey_strategy_id)
+    REFERENCES tbl.strategy (pkey_id)  
+    NOT DEFERRABLE 
+    INITIALLY IMMEDIATE
+;
+
 -- Reference: strategy_escrow_contract_address_strategy (table: strategy_escrow_pending_wallet_address)
 ALTER TABLE tbl.strategy_escrow_pending_wallet_address ADD CONSTRAINT strategy_escrow_contract_address_strategy
     FOREIGN KEY (fkey_strategy_id)
@@ -661,6 +1042,46 @@ ALTER TABLE tbl.strategy_escrow_pending_wallet_balance ADD CONSTRAINT strategy_e
     INITIALLY IMMEDIATE
 ;
 
+-- Reference: strategy_guard_rule_exit_token (table: strategy_guard_rule)
+ALTER TABLE tbl.strategy_guard_rule ADD CONSTRAINT strategy_guard_rule_exit_token
+    FOREIGN KEY (fkey_exit_token_id)
+    REFERENCES tbl.escrow_token_contract_address (pkey_id)  
+    NOT DEFERRABLE 
+    INITIALLY IMMEDIATE
+;
+
+-- Reference: strategy_guard_rule_strategy (table: strategy_guard_rule)
+ALTER TABLE tbl.strategy_guard_rule ADD CONSTRAINT strategy_guard_rule_strategy
+    FOREIGN KEY (fkey_strategy_id)
+    REFERENCES tbl.strategy (pkey_id)  
+    NOT DEFERRABLE 
+    INITIALLY IMMEDIATE
+;
+
+-- Reference: strategy_guard_rule_token (table: strategy_guard_rule)
+ALTER TABLE tbl.strategy_guard_rule ADD CONSTRAINT strategy_guard_rule_token
+    FOREIGN KEY (fkey_token_id)
+    REFERENCES tbl.escrow_token_contract_address (pkey_id)  
+    NOT DEFERRABLE 
+    INITIALLY IMMEDIATE
+;
+
+-- Reference: strategy_guard_rule_reference_rule (table: strategy_guard_rule_reference)
+ALTER TABLE tbl.strategy_guard_rule_reference ADD CONSTRAINT strategy_guard_rule_reference_rule
+    FOREIGN KEY (fkey_strategy_guard_rule_id)
+    REFERENCES tbl.strategy_guard_rule (pkey_id)  
+    NOT DEFERRABLE 
+    INITIALLY IMMEDIATE
+;
+
+-- Reference: strategy_guard_rule_reference_strategy_pool_contract (table: strategy_guard_rule_reference)
+ALTER TABLE tbl.strategy_guard_rule_reference ADD CONSTRAINT strategy_guard_rule_reference_strategy_pool_contract
+    FOREIGN KEY (fkey_strategy_pool_contract_id)
+    REFERENCES tbl.strategy_pool_contract (pkey_id)  
+    NOT DEFERRABLE 
+    INITIALLY IMMEDIATE
+;
+
 -- Reference: strategy_initial_token_ratio_escrow_token_contract_address (table: strategy_initial_token_ratio)
 ALTER TABLE tbl.strategy_initial_token_ratio ADD CONSTRAINT strategy_initial_token_ratio_escrow_token_contract_address
     FOREIGN KEY (token_id)
@@ -725,6 +1146,22 @@ ALTER TABLE tbl.strategy_watched_wallet ADD CONSTRAINT strategy_strategy_watchin
     INITIALLY IMMEDIATE
 ;
 
+-- Reference: strategy_version_strategy (table: strategy_version)
+ALTER TABLE tbl.strategy_version ADD CONSTRAINT strategy_version_strategy
+    FOREIGN KEY (fkey_strategy_id)
+    REFERENCES tbl.strategy (pkey_id)  
+    NOT DEFERRABLE 
+    INITIALLY IMMEDIATE
+;
+
+-- Reference: strategy_version_user (table: strategy_version)
+ALTER TABLE tbl.strategy_version ADD CONSTRAINT strategy_version_user
+    FOREIGN KEY (fkey_changed_by_user_id)
+    REFERENCES tbl."user" (pkey_id)  
+    NOT DEFERRABLE 
+    INITIALLY IMMEDIATE
+;
+
 -- Reference: strategy_wallet_user (table: user_strategy_wallet)
 ALTER TABLE tbl.user_strategy_wallet ADD CONSTRAINT strategy_wallet_user
     FOREIGN KEY (fkey_user_id)
@@ -773,6 +1210,38 @@ ALTER TABLE tbl.strategy_whitelisted_token ADD CONSTRAINT strategy_whitelisted_t
     INITIALLY IMMEDIATE
 ;
 
+-- Reference: system_setting_history_user (table: system_setting_history)
+ALTER TABLE tbl.system_setting_history ADD CONSTRAINT system_setting_history_user
+    FOREIGN KEY (fkey_updated_by_user_id)
+    REFERENCES tbl."user" (pkey_id)  
+    NOT DEFERRABLE 
+    INITIALLY IMMEDIATE
+;
+
+-- Reference: system_setting_user (table: system_setting)
+ALTER TABLE tbl.system_setting ADD CONSTRAINT system_setting_user
+    FOREIGN KEY (fkey_updated_by_user_id)
+    REFERENCES tbl."user" (pkey_id)  
+    NOT DEFERRABLE 
+    INITIALLY IMMEDIATE
+;
+
+-- Reference: user_api_key_user (table: user_api_key)
+ALTER TABLE tbl.user_api_key ADD CONSTRAINT user_api_key_user
+    FOREIGN KEY (fkey_user_id)
+    REFERENCES tbl."user" (pkey_id)  
+    NOT DEFERRABLE 
+    INITIALLY IMMEDIATE
+;
+
+-- Reference: user_api_key_nonce_user_api_key (table: user_api_key_nonce)
+ALTER TABLE tbl.user_api_key_nonce ADD CONSTRAINT user_api_key_nonce_user_api_key
+    FOREIGN KEY (fkey_api_key_id)
+    REFERENCES tbl.user_api_key (pkey_id)  
+    NOT DEFERRABLE 
+    INITIALLY IMMEDIATE
+;
+
 -- Reference: user_back_strategy_history_strategy (table: user_back_exit_strategy_ledger)
 ALTER TABLE tbl.user_back_exit_strategy_ledger ADD CONSTRAINT user_back_strategy_history_strategy
     FOREIGN KEY (fkey_strategy_id)
@@ -885,6 +1354,46 @@ ALTER TABLE tbl.user_follow_strategy ADD CONSTRAINT user_follow_strategy_user
     INITIALLY IMMEDIATE
 ;
 
+-- Reference: user_login_wallet_user (table: user_login_wallet)
+ALTER TABLE tbl.user_login_wallet ADD CONSTRAINT user_login_wallet_user
+    FOREIGN KEY (fkey_user_id)
+    REFERENCES tbl."user" (pkey_id)  
+    NOT DEFERRABLE 
+    INITIALLY IMMEDIATE
+;
+
+-- Reference: user_notification_strategy (table: user_notification)
+ALTER TABLE tbl.user_notification ADD CONSTRAINT user_notification_strategy
+    FOREIGN KEY (fkey_strategy_id)
+    REFERENCES tbl.strategy (pkey_id)  
+    NOT DEFERRABLE 
+    INITIALLY IMMEDIATE
+;
+
+-- Reference: user_notification_user (table: user_notification)
+ALTER TABLE tbl.user_notification ADD CONSTRAINT user_notification_user
+    FOREIGN KEY (fkey_user_id)
+    REFERENCES tbl."user" (pkey_id)  
+    NOT DEFERRABLE 
+    INITIALLY IMMEDIATE
+;
+
+-- Reference: user_pending_withdraw_balance (table: user_pending_withdraw)
+ALTER TABLE tbl.user_pending_withdraw ADD CONSTRAINT user_pending_withdraw_balance
+    FOREIGN KEY (fkey_deposit_withdraw_balance_id)
+    REFERENCES tbl.user_deposit_withdraw_balance (pkey_id)
+    NOT DEFERRABLE
+    INITIALLY IMMEDIATE
+;
+
+-- Reference: user_pending_withdraw_user (table: user_pending_withdraw)
+ALTER TABLE tbl.user_pending_withdraw ADD CONSTRAINT user_pending_withdraw_user
+    FOREIGN KEY (fkey_user_id)
+    REFERENCES tbl."user" (pkey_id)
+    NOT DEFERRABLE
+    INITIALLY IMMEDIATE
+;
+
 -- Reference: user_profile_user (table: expert_profile)
 ALTER TABLE tbl.expert_profile ADD CONSTRAINT user_profile_user
     FOREIGN KEY (fkey_user_id)
@@ -901,6 +1410,22 @@ ALTER TABLE tbl.user_whitelisted_wallet ADD CONSTRAINT user_registered_wallet_us
     INITIALLY IMMEDIATE
 ;
 
+-- Reference: user_session_rotated_token_user_session (table: user_session_rotated_token)
+ALTER TABLE tbl.user_session_rotated_token ADD CONSTRAINT user_session_rotated_token_user_session
+    FOREIGN KEY (fkey_session_id)
+    REFERENCES tbl.user_session (pkey_id)  
+    NOT DEFERRABLE 
+    INITIALLY IMMEDIATE
+;
+
+-- Reference: user_session_user (table: user_session)
+ALTER TABLE tbl.user_session ADD CONSTRAINT user_session_user
+    FOREIGN KEY (fkey_user_id)
+    REFERENCES tbl."user" (pkey_id)  
+    NOT DEFERRABLE 
+    INITIALLY IMMEDIATE
+;
+
 -- Reference: user_strat_pool_cont_asset_bal_strategy_pool_contract (table: user_strategy_pool_contract_asset_balance)
 ALTER TABLE tbl.user_strategy_pool_contract_asset_balance ADD CONSTRAINT user_strat_pool_cont_asset_bal_strategy_pool_contract
     FOREIGN KEY (fkey_strategy_pool_contract_id)
@@ -974,6 +1499,22 @@ ALTER TABLE tbl.user_strategy_pool_contract_asset_ledger ADD CONSTRAINT user_str
 ;
 
 -- sequences
+-- Sequence: seq_admin_action_log_id
+CREATE SEQUENCE tbl.seq_admin_action_log_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
+-- Sequence: seq_admin_pending_action_id
+CREATE SEQUENCE tbl.seq_admin_pending_action_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
 -- Sequence: seq_aum_history_id
 CREATE SEQUENCE tbl.seq_aum_history_id
       NO MINVALUE
@@ -1018,6 +1559,38 @@ CREATE SEQUENCE tbl.seq_escrow_contract_address_id
       NO CYCLE
 ;
 
+-- Sequence: seq_event_bus_outbox_id
+CREATE SEQUENCE tbl.seq_event_bus_outbox_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
+-- Sequence: seq_expert_application_document_id
+CREATE SEQUENCE tbl.seq_expert_application_document_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
+-- Sequence: seq_expert_application_id
+CREATE SEQUENCE tbl.seq_expert_application_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
+-- Sequence: seq_expert_application_note_id
+CREATE SEQUENCE tbl.seq_expert_application_note_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
 -- Sequence: seq_expert_listened_wallet_asset_balance_id
 CREATE SEQUENCE tbl.seq_expert_listened_wallet_asset_balance_id
       NO MINVALUE
@@ -1047,6 +1620,14 @@ CREATE SEQUENCE tbl.seq_login_attempt_id
       AS bigint
 ;
 
+-- Sequence: seq_login_nonce_id
+CREATE SEQUENCE tbl.seq_login_nonce_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
 -- Sequence: seq_strategy_audit_rule
 CREATE SEQUENCE tbl.seq_strategy_audit_rule
       NO MINVALUE
@@ -1054,6 +1635,14 @@ CREATE SEQUENCE tbl.seq_strategy_audit_rule
       NO CYCLE
 ;
 
+-- Sequence: seq_strategy_daily_value_id
+CREATE SEQUENCE tbl.seq_strategy_daily_value_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
 -- Sequence: seq_strategy_escrow_pending_wallet_balance_id
 CREATE SEQUENCE tbl.seq_strategy_escrow_pending_wallet_balance_id
       NO MINVALUE
@@ -1061,6 +1650,22 @@ CREATE SEQUENCE tbl.seq_strategy_escrow_pending_wallet_balance_id
       NO CYCLE
 ;
 
+-- Sequence: seq_strategy_guard_rule_id
+CREATE SEQUENCE tbl.seq_strategy_guard_rule_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
+-- Sequence: seq_strategy_guard_rule_reference_id
+CREATE SEQUENCE tbl.seq_strategy_guard_rule_reference_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
 -- Sequence: seq_strategy_id
 CREATE SEQUENCE tbl.seq_strategy_id
       NO MINVALUE
@@ -1096,6 +1701,14 @@ CREATE SEQUENCE tbl.seq_strategy_pool_contract_id
       NO CYCLE
 ;
 
+-- Sequence: seq_strategy_version_id
+CREATE SEQUENCE tbl.seq_strategy_version_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
 -- Sequence: seq_strategy_wallet_id
 CREATE SEQUENCE tbl.seq_strategy_wallet_id
       NO MINVALUE
@@ -1131,6 +1744,14 @@ CREATE SEQUENCE tbl.seq_strategy_whitelisted_tokens_id
       NO CYCLE
 ;
 
+-- Sequence: seq_system_setting_history_id
+CREATE SEQUENCE tbl.seq_system_setting_history_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
 -- Sequence: seq_token_price_id
 CREATE SEQUENCE tbl.seq_token_price_id
       NO MINVALUE
@@ -1145,6 +1766,22 @@ CREATE SEQUENCE tbl.seq_transaction_cache_id
       NO CYCLE
 ;
 
+-- Sequence: seq_user_api_key_id
+CREATE SEQUENCE tbl.seq_user_api_key_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
+-- Sequence: seq_user_api_key_nonce_id
+CREATE SEQUENCE tbl.seq_user_api_key_nonce_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
 -- Sequence: seq_user_back_exit_strategy_ledger_id
 CREATE SEQUENCE tbl.seq_user_back_exit_strategy_ledger_id
       NO MINVALUE
@@ -1202,6 +1839,30 @@ CREATE SEQUENCE tbl.seq_user_id
       AS bigint
 ;
 
+-- Sequence: seq_user_login_wallet_id
+CREATE SEQUENCE tbl.seq_user_login_wallet_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
+-- Sequence: seq_user_notification_id
+CREATE SEQUENCE tbl.seq_user_notification_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
+-- Sequence: seq_user_pending_withdraw_id
+CREATE SEQUENCE tbl.seq_user_pending_withdraw_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
 -- Sequence: seq_user_request_refund_ledger_id
 CREATE SEQUENCE tbl.seq_user_request_refund_ledger_id
       NO MINVALUE
@@ -1209,6 +1870,14 @@ CREATE SEQUENCE tbl.seq_user_request_refund_ledger_id
       NO CYCLE
 ;
 
+-- Sequence: seq_user_session_id
+CREATE SEQUENCE tbl.seq_user_session_id
+      NO MINVALUE
+      NO MAXVALUE
+      NO CYCLE
+      AS bigint
+;
+
 -- Sequence: seq_user_strategy_balance_id
 CREATE SEQUENCE tbl.seq_user_strategy_balance_id
       NO MINVALUE
@@ -366,6 +366,7 @@ pub async fn populate_user_apply_become_experts() -> Result<()> {
                     .await
                     .request(AdminApproveUserBecomeExpertRequest {
                         user_id: login_info.user_id,
+                        note: None,
                     })
                     .await?;
                 let mut client = connect_user(format!("user-{}", i), &signer.key).await?;
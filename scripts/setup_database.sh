@@ -20,4 +20,11 @@ pg_exec2 -f db/model.sql
 # run twice because of wrong dependencies
 pg_exec2 -f db/tbl.sql
 pg_exec2 -f db/tbl.sql
+# the fresh schema already contains every migration, record them as applied
+pg_exec2 -f db/schema_migrations.sql
+for migration in db/migrations/*.sql; do
+    name=$(basename "$migration" .sql)
+    checksum=$(md5sum "$migration" | cut -d' ' -f1)
+    pg_exec2 -c "INSERT INTO public.schema_migrations (version, name, checksum, applied_at) VALUES ($((10#${name%%_*})), '${name#*_}', '$checksum', $(date +%s)) ON CONFLICT DO NOTHING;"
+done
 pg_exec2 -f db/api.sql
@@ -0,0 +1,216 @@
+// generated by codegen from the endpoint schemas, do not edit
+import type { StreamHandler, Subscription, WsClient } from "./ws_client";
+import { Endpoint } from "./model";
+import type {
+  AdminAddAuditRuleRequest,
+  AdminAddAuditRuleResponse,
+  AdminAddEscrowContractAddressRequest,
+  AdminAddEscrowContractAddressResponse,
+  AdminAddEscrowTokenContractAddressRequest,
+  AdminAddEscrowTokenContractAddressResponse,
+  AdminApprovePendingActionRequest,
+  AdminApprovePendingActionResponse,
+  AdminApproveStrategyRequest,
+  AdminApproveStrategyResponse,
+  AdminApproveUserBecomeExpertRequest,
+  AdminApproveUserBecomeExpertResponse,
+  AdminGetExpertApplicationDocumentRequest,
+  AdminGetExpertApplicationDocumentResponse,
+  AdminGetExpertApplicationRequest,
+  AdminGetExpertApplicationResponse,
+  AdminGetSystemConfigRequest,
+  AdminGetSystemConfigResponse,
+  AdminListActionLogRequest,
+  AdminListActionLogResponse,
+  AdminListBackStrategyLedgerRequest,
+  AdminListBackStrategyLedgerResponse,
+  AdminListBackersRequest,
+  AdminListBackersResponse,
+  AdminListEscrowTokenContractAddressesRequest,
+  AdminListEscrowTokenContractAddressesResponse,
+  AdminListExitStrategyLedgerRequest,
+  AdminListExitStrategyLedgerResponse,
+  AdminListExpertsRequest,
+  AdminListExpertsResponse,
+  AdminListPendingActionsRequest,
+  AdminListPendingActionsResponse,
+  AdminListPendingExpertApplicationsRequest,
+  AdminListPendingExpertApplicationsResponse,
+  AdminListStrategiesRequest,
+  AdminListStrategiesResponse,
+  AdminListSystemSettingHistoryRequest,
+  AdminListSystemSettingHistoryResponse,
+  AdminListUsersRequest,
+  AdminListUsersResponse,
+  AdminNotifyEscrowLedgerChangeRequest,
+  AdminNotifyEscrowLedgerChangeResponse,
+  AdminRefreshExpertWalletBalanceRequest,
+  AdminRefreshExpertWalletBalanceResponse,
+  AdminRejectStrategyRequest,
+  AdminRejectStrategyResponse,
+  AdminRejectUserBecomeExpertRequest,
+  AdminRejectUserBecomeExpertResponse,
+  AdminRequestExpertApplicationInfoRequest,
+  AdminRequestExpertApplicationInfoResponse,
+  AdminSetBlockUserRequest,
+  AdminSetBlockUserResponse,
+  AdminSetBlockchainLoggerRequest,
+  AdminSetBlockchainLoggerResponse,
+  AdminSetUserRoleRequest,
+  AdminSetUserRoleResponse,
+  AdminSubscribeDepositLedgerRequest,
+  AdminSubscribeDepositLedgerResponse,
+  AdminUnsubscribeDepositLedgerRequest,
+  AdminUnsubscribeDepositLedgerResponse,
+  AdminUpdateEscrowTokenContractAddressRequest,
+  AdminUpdateEscrowTokenContractAddressResponse,
+  AdminUpdateSystemConfigRequest,
+  AdminUpdateSystemConfigResponse,
+  UserListDepositLedgerRow,
+} from "./model";
+
+export class AdminClient {
+  constructor(readonly client: WsClient) {}
+
+  adminListUsers(params: AdminListUsersRequest): Promise<AdminListUsersResponse> {
+    return this.client.request(Endpoint.AdminListUsers, params);
+  }
+
+  adminSetUserRole(params: AdminSetUserRoleRequest): Promise<AdminSetUserRoleResponse> {
+    return this.client.request(Endpoint.AdminSetUserRole, params);
+  }
+
+  adminSetBlockUser(params: AdminSetBlockUserRequest): Promise<AdminSetBlockUserResponse> {
+    return this.client.request(Endpoint.AdminSetBlockUser, params);
+  }
+
+  /** Admin approves a user to become an expert */
+  adminListPendingExpertApplications(params: AdminListPendingExpertApplicationsRequest): Promise<AdminListPendingExpertApplicationsResponse> {
+    return this.client.request(Endpoint.AdminListPendingExpertApplications, params);
+  }
+
+  /** Admin approves a user to become an expert */
+  adminApproveUserBecomeExpert(params: AdminApproveUserBecomeExpertRequest): Promise<AdminApproveUserBecomeExpertResponse> {
+    return this.client.request(Endpoint.AdminApproveUserBecomeExpert, params);
+  }
+
+  /** Admin gets the expert application of a user */
+  adminGetExpertApplication(params: AdminGetExpertApplicationRequest): Promise<AdminGetExpertApplicationResponse> {
+    return this.client.request(Endpoint.AdminGetExpertApplication, params);
+  }
+
+  /** Admin downloads a base64 encoded expert application document */
+  adminGetExpertApplicationDocument(params: AdminGetExpertApplicationDocumentRequest): Promise<AdminGetExpertApplicationDocumentResponse> {
+    return this.client.request(Endpoint.AdminGetExpertApplicationDocument, params);
+  }
+
+  /** Admin asks a user for more information on their expert application */
+  adminRequestExpertApplicationInfo(params: AdminRequestExpertApplicationInfoRequest): Promise<AdminRequestExpertApplicationInfoResponse> {
+    return this.client.request(Endpoint.AdminRequestExpertApplicationInfo, params);
+  }
+
+  /** Admin rejects a user's expert application */
+  adminRejectUserBecomeExpert(params: AdminRejectUserBecomeExpertRequest): Promise<AdminRejectUserBecomeExpertResponse> {
+    return this.client.request(Endpoint.AdminRejectUserBecomeExpert, params);
+  }
+
+  /** Admin get system config */
+  adminGetSystemConfig(params: AdminGetSystemConfigRequest): Promise<AdminGetSystemConfigResponse> {
+    return this.client.request(Endpoint.AdminGetSystemConfig, params);
+  }
+
+  /** Admin updates system config */
+  adminUpdateSystemConfig(params: AdminUpdateSystemConfigRequest): Promise<AdminUpdateSystemConfigResponse> {
+    return this.client.request(Endpoint.AdminUpdateSystemConfig, params);
+  }
+
+  /** Admin lists the changes of the system settings */
+  adminListSystemSettingHistory(params: AdminListSystemSettingHistoryRequest): Promise<AdminListSystemSettingHistoryResponse> {
+    return this.client.request(Endpoint.AdminListSystemSettingHistory, params);
+  }
+
+  /** Admin lists experts */
+  adminListExperts(params: AdminListExpertsRequest): Promise<AdminListExpertsResponse> {
+    return this.client.request(Endpoint.AdminListExperts, params);
+  }
+
+  adminListBackers(params: AdminListBackersRequest): Promise<AdminListBackersResponse> {
+    return this.client.request(Endpoint.AdminListBackers, params);
+  }
+
+  adminListStrategies(params: AdminListStrategiesRequest): Promise<AdminListStrategiesResponse> {
+    return this.client.request(Endpoint.AdminListStrategies, params);
+  }
+
+  /** Admin approves strategy */
+  adminApproveStrategy(params: AdminApproveStrategyRequest): Promise<AdminApproveStrategyResponse> {
+    return this.client.request(Endpoint.AdminApproveStrategy, params);
+  }
+
+  adminRefreshExpertWalletBalance(params: AdminRefreshExpertWalletBalanceRequest): Promise<AdminRefreshExpertWalletBalanceResponse> {
+    return this.client.request(Endpoint.AdminRefreshExpertWalletBalance, params);
+  }
+
+  adminRejectStrategy(params: AdminRejectStrategyRequest): Promise<AdminRejectStrategyResponse> {
+    return this.client.request(Endpoint.AdminRejectStrategy, params);
+  }
+
+  adminAddAuditRule(params: AdminAddAuditRuleRequest): Promise<AdminAddAuditRuleResponse> {
+    return this.client.request(Endpoint.AdminAddAuditRule, params);
+  }
+
+  adminNotifyEscrowLedgerChange(params: AdminNotifyEscrowLedgerChangeRequest): Promise<AdminNotifyEscrowLedgerChangeResponse> {
+    return this.client.request(Endpoint.AdminNotifyEscrowLedgerChange, params);
+  }
+
+  adminSubscribeDepositLedger(
+    params: AdminSubscribeDepositLedgerRequest,
+    onData: StreamHandler<UserListDepositLedgerRow>,
+  ): Promise<Subscription<AdminSubscribeDepositLedgerResponse>> {
+    return this.client.subscribe(Endpoint.AdminSubscribeDepositLedger, params, onData);
+  }
+
+  adminUnsubscribeDepositLedger(params: AdminUnsubscribeDepositLedgerRequest): Promise<AdminUnsubscribeDepositLedgerResponse> {
+    return this.client.request(Endpoint.AdminUnsubscribeDepositLedger, params);
+  }
+
+  adminAddEscrowTokenContractAddress(params: AdminAddEscrowTokenContractAddressRequest): Promise<AdminAddEscrowTokenContractAddressResponse> {
+    return this.client.request(Endpoint.AdminAddEscrowTokenContractAddress, params);
+  }
+
+  adminAddEscrowContractAddress(params: AdminAddEscrowContractAddressRequest): Promise<AdminAddEscrowContractAddressResponse> {
+    return this.client.request(Endpoint.AdminAddEscrowContractAddress, params);
+  }
+
+  adminListBackStrategyLedger(params: AdminListBackStrategyLedgerRequest): Promise<AdminListBackStrategyLedgerResponse> {
+    return this.client.request(Endpoint.AdminListBackStrategyLedger, params);
+  }
+
+  adminListExitStrategyLedger(params: AdminListExitStrategyLedgerRequest): Promise<AdminListExitStrategyLedgerResponse> {
+    return this.client.request(Endpoint.AdminListExitStrategyLedger, params);
+  }
+
+  adminSetBlockchainLogger(params: AdminSetBlockchainLoggerRequest): Promise<AdminSetBlockchainLoggerResponse> {
+    return this.client.request(Endpoint.AdminSetBlockchainLogger, params);
+  }
+
+  adminListEscrowTokenContractAddresses(params: AdminListEscrowTokenContractAddressesRequest): Promise<AdminListEscrowTokenContractAddressesResponse> {
+    return this.client.request(Endpoint.AdminListEscrowTokenContractAddresses, params);
+  }
+
+  adminUpdateEscrowTokenContractAddress(params: AdminUpdateEscrowTokenContractAddressRequest): Promise<AdminUpdateEscrowTokenContractAddressResponse> {
+    return this.client.request(Endpoint.AdminUpdateEscrowTokenContractAddress, params);
+  }
+
+  adminListPendingActions(params: AdminListPendingActionsRequest): Promise<AdminListPendingActionsResponse> {
+    return this.client.request(Endpoint.AdminListPendingActions, params);
+  }
+
+  adminApprovePendingAction(params: AdminApprovePendingActionRequest): Promise<AdminApprovePendingActionResponse> {
+    return this.client.request(Endpoint.AdminApprovePendingAction, params);
+  }
+
+  adminListActionLog(params: AdminListActionLogRequest): Promise<AdminListActionLogResponse> {
+    return this.client.request(Endpoint.AdminListActionLog, params);
+  }
+}
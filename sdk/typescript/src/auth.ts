@@ -0,0 +1,93 @@
+// generated by codegen from the endpoint schemas, do not edit
+import { encodeHeader, WsClient } from "./ws_client";
+import type { WsClientOptions, WsConnected } from "./ws_client";
+import type {
+  AuthorizeApiKeyRequest,
+  AuthorizeApiKeyResponse,
+  AuthorizeRequest,
+  AuthorizeResponse,
+  ChangeLoginWalletRequest,
+  ChangeLoginWalletResponse,
+  GetLoginNonceRequest,
+  GetLoginNonceResponse,
+  LoginRequest,
+  LoginResponse,
+  LogoutRequest,
+  LogoutResponse,
+  RefreshTokenRequest,
+  RefreshTokenResponse,
+  SignupRequest,
+  SignupResponse,
+} from "./model";
+
+export function connectLogin(
+  url: string,
+  params: LoginRequest,
+  options?: WsClientOptions,
+): Promise<WsConnected<LoginResponse>> {
+  const header = encodeHeader("Login", ["address", "signatureText", "signature", "service", "deviceId", "deviceOs"], params);
+  return WsClient.connect<LoginResponse>(url, header, options);
+}
+
+export function connectSignup(
+  url: string,
+  params: SignupRequest,
+  options?: WsClientOptions,
+): Promise<WsConnected<SignupResponse>> {
+  const header = encodeHeader("Signup", ["address", "signatureText", "signature", "email", "phone", "agreedTos", "agreedPrivacy", "username"], params);
+  return WsClient.connect<SignupResponse>(url, header, options);
+}
+
+export function connectAuthorize(
+  url: string,
+  params: AuthorizeRequest,
+  options?: WsClientOptions,
+): Promise<WsConnected<AuthorizeResponse>> {
+  const header = encodeHeader("Authorize", ["address", "token", "service", "deviceId", "deviceOs"], params);
+  return WsClient.connect<AuthorizeResponse>(url, header, options);
+}
+
+export function connectLogout(
+  url: string,
+  params: LogoutRequest,
+  options?: WsClientOptions,
+): Promise<WsConnected<LogoutResponse>> {
+  const header = encodeHeader("Logout", ["address", "token", "service", "deviceId"], params);
+  return WsClient.connect<LogoutResponse>(url, header, options);
+}
+
+export function connectChangeLoginWallet(
+  url: string,
+  params: ChangeLoginWalletRequest,
+  options?: WsClientOptions,
+): Promise<WsConnected<ChangeLoginWalletResponse>> {
+  const header = encodeHeader("ChangeLoginWallet", ["oldAddress", "oldSignatureText", "oldSignature", "newAddress", "newSignatureText", "newSignature"], params);
+  return WsClient.connect<ChangeLoginWalletResponse>(url, header, options);
+}
+
+export function connectGetLoginNonce(
+  url: string,
+  params: GetLoginNonceRequest,
+  options?: WsClientOptions,
+): Promise<WsConnected<GetLoginNonceResponse>> {
+  const header = encodeHeader("GetLoginNonce", ["address"], params);
+  return WsClient.connect<GetLoginNonceResponse>(url, header, options);
+}
+
+export function connectRefreshToken(
+  url: string,
+  params: RefreshTokenRequest,
+  options?: WsClientOptions,
+): Promise<WsConnected<RefreshTokenResponse>> {
+  const header = encodeHeader("RefreshToken", ["address", "refreshToken", "service", "deviceId"], params);
+  return WsClient.connect<RefreshTokenResponse>(url, header, options);
+}
+
+export function connectAuthorizeApiKey(
+  url: string,
+  params: AuthorizeApiKeyRequest,
+  options?: WsClientOptions,
+): Promise<WsConnected<AuthorizeApiKeyResponse>> {
+  const header = encodeHeader("AuthorizeApiKey", ["apiKey", "timestamp", "signature", "service", "nonce"], params);
+  return WsClient.connect<AuthorizeApiKeyResponse>(url, header, options);
+}
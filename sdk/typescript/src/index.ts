@@ -0,0 +1,6 @@
+// generated by codegen from the endpoint schemas, do not edit
+export * from "./ws_client";
+export * from "./model";
+export * from "./auth";
+export * from "./user";
+export * from "./admin";
@@ -0,0 +1,2319 @@
+// generated by codegen from the endpoint schemas, do not edit
+
+export type EnumRole = "Guest" | "User" | "Expert" | "Admin" | "Developer" | "Whitelist";
+export const EnumRoleVariants: readonly EnumRole[] = ["Guest", "User", "Expert", "Admin", "Developer", "Whitelist"];
+
+export type EnumBlockChain = "EthereumMainnet" | "EthereumGoerli" | "BscMainnet" | "BscTestnet" | "LocalNet" | "EthereumSepolia";
+export const EnumBlockChainVariants: readonly EnumBlockChain[] = ["EthereumMainnet", "EthereumGoerli", "BscMainnet", "BscTestnet", "LocalNet", "EthereumSepolia"];
+
+export type EnumBlockchainCoin = "USDC" | "USDT" | "BUSD" | "WETH" | "WBNB";
+export const EnumBlockchainCoinVariants: readonly EnumBlockchainCoin[] = ["USDC", "USDT", "BUSD", "WETH", "WBNB"];
+
+export type EnumDex = "UniSwap" | "PancakeSwap" | "SushiSwap";
+export const EnumDexVariants: readonly EnumDex[] = ["UniSwap", "PancakeSwap", "SushiSwap"];
+
+export type EnumDexVersion = "V1" | "V2" | "V3";
+export const EnumDexVersionVariants: readonly EnumDexVersion[] = ["V1", "V2", "V3"];
+
+export type EnumDexPathFormat = "Json" | "TransactionData" | "TransactionHash";
+export const EnumDexPathFormatVariants: readonly EnumDexPathFormat[] = ["Json", "TransactionData", "TransactionHash"];
+
+export type EnumActivityReportFormat = "Json" | "Csv";
+export const EnumActivityReportFormatVariants: readonly EnumActivityReportFormat[] = ["Json", "Csv"];
+
+export type EnumUserActivityEventType = "Deposit" | "Refund" | "EscrowSpent" | "BackStrategy" | "ExitStrategy" | "PoolAssetAdd" | "PoolAssetRemove" | "Withdraw" | "WithdrawFee";
+export const EnumUserActivityEventTypeVariants: readonly EnumUserActivityEventType[] = ["Deposit", "Refund", "EscrowSpent", "BackStrategy", "ExitStrategy", "PoolAssetAdd", "PoolAssetRemove", "Withdraw", "WithdrawFee"];
+
+export type EnumStrategyGuardRuleType = "MaxDrawdown" | "AssetStopLoss" | "TakeProfit";
+export const EnumStrategyGuardRuleTypeVariants: readonly EnumStrategyGuardRuleType[] = ["MaxDrawdown", "AssetStopLoss", "TakeProfit"];
+
+export type EnumUserNotificationKind = "StrategyTrade" | "BackStrategy" | "ExitStrategy" | "Refund" | "ExpertNewStrategy" | "StrategyChange" | "StrategyGuard";
+export const EnumUserNotificationKindVariants: readonly EnumUserNotificationKind[] = ["StrategyTrade", "BackStrategy", "ExitStrategy", "Refund", "ExpertNewStrategy", "StrategyChange", "StrategyGuard"];
+
+export type EnumApiKeyScope = "ReadOnly" | "Trade" | "Withdraw";
+export const EnumApiKeyScopeVariants: readonly EnumApiKeyScope[] = ["ReadOnly", "Trade", "Withdraw"];
+
+export type EnumAdminPendingActionStatus = "Pending" | "Approved" | "Executed" | "Failed";
+export const EnumAdminPendingActionStatusVariants: readonly EnumAdminPendingActionStatus[] = ["Pending", "Approved", "Executed", "Failed"];
+
+export type EnumExpertApplicationStatus = "Draft" | "Submitted" | "InfoRequested" | "Approved" | "Rejected";
+export const EnumExpertApplicationStatusVariants: readonly EnumExpertApplicationStatus[] = ["Draft", "Submitted", "InfoRequested", "Approved", "Rejected"];
+
+export type EnumExpertSortBy = "Newest" | "Followers" | "Reputation" | "Consistency";
+export const EnumExpertSortByVariants: readonly EnumExpertSortBy[] = ["Newest", "Followers", "Reputation", "Consistency"];
+
+export type EnumStrategyVersionStatus = "Scheduled" | "Applied" | "Failed" | "Applying";
+export const EnumStrategyVersionStatusVariants: readonly EnumStrategyVersionStatus[] = ["Scheduled", "Applied", "Failed", "Applying"];
+
+export type EnumService = "Auth" | "User" | "Admin" | "Watcher" | "AssetPrice";
+export const EnumServiceVariants: readonly EnumService[] = ["Auth", "User", "Admin", "Watcher", "AssetPrice"];
+
+/** the `code` of an `Error` response */
+export const EnumErrorCode = {
+  BadRequest: 100400,
+  InternalServerError: 100500,
+  NotImplemented: 100501,
+  NotFound: 100404,
+  TooManyRequests: 100429,
+  DatabaseError: 100601,
+  InvalidService: 100602,
+  UserForbidden: 101403,
+  UserNotFound: 101404,
+  UserMustAgreeTos: 101601,
+  UserMustAgreePrivacyPolicy: 101602,
+  UserNoAuthToken: 101604,
+  UserInvalidAuthToken: 101605,
+  TokenNotTop25: 102602,
+  ImmutableStrategy: 102603,
+  UserWhitelistedWalletNotSameNetworkAsStrategy: 102604,
+  DuplicateRequest: 103001,
+  InvalidEnumLevel: 3484946,
+  Error: 4349632,
+  InvalidArgument: 45349633,
+  InvalidState: 45349634,
+  InvalidSeq: 45349635,
+  InvalidMethod: 45349636,
+  ProtocolViolation: 45349637,
+  MalformedRequest: 45349638,
+  UnknownUser: 45349639,
+  BlockedUser: 45349640,
+  InvalidPassword: 45349641,
+  InvalidToken: 45349642,
+  TemporarilyUnavailable: 45349643,
+  UnexpectedException: 45349644,
+  BackPressureIncreased: 45349645,
+  InvalidPublicId: 45349646,
+  InvalidRange: 45349647,
+  BankAccountAlreadyExists: 45349648,
+  InsufficientFunds: 45349649,
+  UnknownLoginNonce: 45349650,
+  ExpiredLoginNonce: 45349651,
+  UsedLoginNonce: 45349652,
+  TokenExpired: 45349653,
+  LogicalError: 45349654,
+  RestrictedUserPrivileges: 45349655,
+  IdenticalReplacement: 45349656,
+  RefreshTokenReused: 45349657,
+  InsufficientApiKeyScope: 45349658,
+  InvalidRecoveryQuestions: 45349659,
+  InvalidRole: 45349660,
+  WrongRecoveryAnswers: 45349661,
+  MessageNotDelivered: 45349662,
+  NoReply: 45349663,
+  NullAttribute: 45349664,
+  ConsentMissing: 45349665,
+  ActiveSubscriptionRequired: 45349666,
+  UsernameAlreadyRegistered: 45349667,
+  RecoveryQuestionsNotSet: 45349668,
+  MustSubmitAllRecoveryQuestions: 45349669,
+  InvalidRecoveryToken: 45349670,
+  IpNotAllowed: 45349671,
+  ActionPendingApproval: 45349672,
+  UnknownPendingAction: 45349673,
+  SelfApproval: 45349674,
+  SettingVersionConflict: 45349675,
+  RoutingError: 45349676,
+  UnauthorizedMessage: 45349677,
+  InvalidExpertApplicationState: 45349678,
+  AuthError: 45349679,
+  StrategyChangeScheduled: 45349680,
+  InternalError: 45349684,
+} as const;
+export type EnumErrorCode = (typeof EnumErrorCode)[keyof typeof EnumErrorCode];
+
+export const ErrorMessages: Readonly<Record<number, string>> = {
+  100400: "Bad Request",
+  100500: "Internal Server Error",
+  100501: "Method not implemented",
+  100404: "NotFoundResource",
+  100429: "Too many requests",
+  100601: "Database error",
+  100602: "Invalid Service",
+  101403: "Insufficient role for user",
+  101404: "User not found",
+  101601: "Must agree to the terms of service",
+  101602: "Must agree to the privacy policy",
+  101604: "No auth token",
+  101605: "token invalid",
+  102602: "Token is not top 25",
+  102603: "Strategy is immutable",
+  102604: "User whitelisted wallet not same network as strategy",
+  103001: "Duplicate request",
+  3484946: "InvalidEnumLevel",
+  4349632: "Error",
+  45349633: "InvalidArgument",
+  45349634: "InvalidState",
+  45349635: "InvalidSeq",
+  45349636: "InvalidMethod",
+  45349637: "ProtocolViolation",
+  45349638: "MalformedRequest",
+  45349639: "UnknownUser",
+  45349640: "BlockedUser",
+  45349641: "InvalidPassword",
+  45349642: "InvalidToken",
+  45349643: "TemporarilyUnavailable",
+  45349644: "UnexpectedException",
+  45349645: "BackPressureIncreased",
+  45349646: "InvalidPublicId",
+  45349647: "InvalidRange",
+  45349648: "BankAccountAlreadyExists",
+  45349649: "InsufficientFunds",
+  45349650: "UnknownLoginNonce",
+  45349651: "ExpiredLoginNonce",
+  45349652: "UsedLoginNonce",
+  45349653: "TokenExpired",
+  45349654: "LogicalError",
+  45349655: "RestrictedUserPrivileges",
+  45349656: "IdenticalReplacement",
+  45349657: "RefreshTokenReused",
+  45349658: "InsufficientApiKeyScope",
+  45349659: "InvalidRecoveryQuestions",
+  45349660: "InvalidRole",
+  45349661: "WrongRecoveryAnswers",
+  45349662: "MessageNotDelivered",
+  45349663: "NoReply",
+  45349664: "NullAttribute",
+  45349665: "ConsentMissing",
+  45349666: "ActiveSubscriptionRequired",
+  45349667: "UsernameAlreadyRegistered",
+  45349668: "RecoveryQuestionsNotSet",
+  45349669: "MustSubmitAllRecoveryQuestions",
+  45349670: "InvalidRecoveryToken",
+  45349671: "IpNotAllowed",
+  45349672: "ActionPendingApproval",
+  45349673: "UnknownPendingAction",
+  45349674: "SelfApproval",
+  45349675: "SettingVersionConflict",
+  45349676: "RoutingError",
+  45349677: "UnauthorizedMessage",
+  45349678: "InvalidExpertApplicationState",
+  45349679: "AuthError",
+  45349680: "StrategyChangeScheduled",
+  45349684: "InternalError",
+};
+
+/** method codes of the endpoints */
+export const Endpoint = {
+  Login: 10020,
+  Signup: 10010,
+  Authorize: 10030,
+  Logout: 10040,
+  ChangeLoginWallet: 10050,
+  GetLoginNonce: 10060,
+  RefreshToken: 10070,
+  AuthorizeApiKey: 10080,
+  UserFollowStrategy: 20040,
+  UserListFollowedStrategies: 20050,
+  UserUnfollowStrategy: 20060,
+  UserListStrategies: 20061,
+  UserListTopPerformingStrategies: 20063,
+  UserListStrategyBackers: 20064,
+  UserListStrategyFollowers: 20065,
+  UserGetStrategy: 20062,
+  UserListStrategyPoolContractAssetLedger: 20066,
+  UserListUserStrategyPoolContractAssetLedger: 20067,
+  UserGetStrategyStatistics: 20070,
+  UserGetStrategiesStatistics: 20071,
+  UserUpdateUserProfile: 20172,
+  UserBackStrategy: 20080,
+  UserExitStrategy: 20110,
+  UserRequestRefund: 20081,
+  UserRequestWithdraw: 20082,
+  UserListBackedStrategies: 20090,
+  UserListBackStrategyLedger: 20100,
+  ExpertListBackStrategyLedger: 20101,
+  UserListExitStrategyLedger: 20120,
+  ExpertListExitStrategyLedger: 20121,
+  UserFollowExpert: 20130,
+  UserListFollowedExperts: 20140,
+  UserUnfollowExpert: 20150,
+  UserListExperts: 20160,
+  UserListTopPerformingExperts: 20161,
+  UserListFeaturedExperts: 20162,
+  UserListExpertListenedWalletTradeLedger: 20163,
+  UserGetExpertProfile: 20170,
+  UserGetUserProfile: 20180,
+  UserWhitelistWallet: 20190,
+  UserListWhitelistedWallets: 20200,
+  UserUnwhitelistWallet: 20210,
+  UserApplyBecomeExpert: 20220,
+  UserSaveExpertApplication: 20221,
+  UserUploadExpertApplicationDocument: 20222,
+  UserDeleteExpertApplicationDocument: 20223,
+  UserGetExpertApplication: 20224,
+  ExpertCreateStrategy: 20250,
+  ExpertUpdateStrategy: 20260,
+  ExpertFreezeStrategy: 20265,
+  ExpertAddStrategyWatchingWallet: 20270,
+  ExpertRemoveStrategyWatchingWallet: 20280,
+  UserListStrategyWatchingWallets: 20290,
+  UserListWalletActivityLedger: 20300,
+  ExpertAddStrategyInitialTokenRatio: 20310,
+  ExpertRemoveStrategyInitialTokenRatio: 20320,
+  UserListStrategyInitialTokenRatio: 20330,
+  UserListStrategyVersions: 20335,
+  ExpertListFollowers: 20340,
+  ExpertListBackers: 20350,
+  ExpertListPublishedStrategies: 20355,
+  ExpertListUnpublishedStrategies: 20356,
+  UserGetDepositTokens: 20360,
+  UserGetDepositAddresses: 20370,
+  UserListDepositWithdrawLedger: 20380,
+  UserSubscribeDepositLedger: 20381,
+  UserUnsubscribeDepositLedger: 20382,
+  UserListStrategyWallets: 20390,
+  UserCreateStrategyWallet: 20391,
+  UserListStrategyAuditRules: 20400,
+  UserAddStrategyAuditRule: 20410,
+  UserRemoveStrategyAuditRule: 20420,
+  UserGetEscrowAddressForStrategy: 20500,
+  UserListDepositWithdrawBalances: 20510,
+  UserGetDepositWithdrawBalance: 20511,
+  UserListEscrowTokenContractAddresses: 20520,
+  UserListStrategyTokenBalance: 20530,
+  UserGetBackStrategyReviewDetail: 20540,
+  UserListUserBackStrategyAttempt: 20550,
+  UserListUserBackStrategyLog: 20560,
+  UserGetSystemConfig: 20570,
+  UserListUserStrategyBalance: 20580,
+  UserExportActivityReport: 20590,
+  ExpertAddStrategyGuardRule: 20600,
+  ExpertRemoveStrategyGuardRule: 20610,
+  UserListStrategyGuardRules: 20620,
+  ExpertResumeStrategyCopyTrading: 20630,
+  UserSubscribeStrategyGuardEvents: 20640,
+  UserUnsubscribeStrategyGuardEvents: 20641,
+  UserListNotifications: 20650,
+  UserMarkNotificationsRead: 20660,
+  UserSubscribeNotifications: 20670,
+  UserUnsubscribeNotifications: 20671,
+  UserListSessions: 20680,
+  UserRevokeSession: 20690,
+  UserCreateApiKey: 20700,
+  UserListApiKeys: 20710,
+  UserRevokeApiKey: 20720,
+  UserListLoginWallets: 20730,
+  UserLinkLoginWallet: 20740,
+  UserUnlinkLoginWallet: 20750,
+  UserSetPrimaryLoginWallet: 20760,
+  AdminListUsers: 30010,
+  AdminSetUserRole: 30020,
+  AdminSetBlockUser: 30030,
+  AdminListPendingExpertApplications: 30060,
+  AdminApproveUserBecomeExpert: 30040,
+  AdminGetExpertApplication: 30041,
+  AdminGetExpertApplicationDocument: 30042,
+  AdminRequestExpertApplicationInfo: 30045,
+  AdminRejectUserBecomeExpert: 30050,
+  AdminGetSystemConfig: 30070,
+  AdminUpdateSystemConfig: 30080,
+  AdminListSystemSettingHistory: 30085,
+  AdminListExperts: 30090,
+  AdminListBackers: 30100,
+  AdminListStrategies: 30110,
+  AdminApproveStrategy: 30120,
+  AdminRefreshExpertWalletBalance: 30121,
+  AdminRejectStrategy: 30130,
+  AdminAddAuditRule: 31002,
+  AdminNotifyEscrowLedgerChange: 32010,
+  AdminSubscribeDepositLedger: 32011,
+  AdminUnsubscribeDepositLedger: 32012,
+  AdminAddEscrowTokenContractAddress: 32020,
+  AdminAddEscrowContractAddress: 32030,
+  AdminListBackStrategyLedger: 32040,
+  AdminListExitStrategyLedger: 32041,
+  AdminSetBlockchainLogger: 32050,
+  AdminListEscrowTokenContractAddresses: 32060,
+  AdminUpdateEscrowTokenContractAddress: 32080,
+  AdminListPendingActions: 33010,
+  AdminApprovePendingAction: 33020,
+  AdminListActionLog: 33030,
+} as const;
+export type Endpoint = (typeof Endpoint)[keyof typeof Endpoint];
+
+export interface AdminActionLogRow {
+  actionLogId: number;
+  adminUserId: number;
+  pendingActionId?: number | null;
+  requestedByUserId?: number | null;
+  method: string;
+  params: unknown;
+  stateBefore?: unknown | null;
+  stateAfter?: unknown | null;
+  createdAt: number;
+}
+
+export interface AdminAddAuditRuleRequest {
+  ruleId: number;
+  name: string;
+  description: string;
+}
+
+export interface AdminAddAuditRuleResponse {}
+
+export interface AdminAddEscrowContractAddressRequest {
+  pkeyId: number;
+  address: string;
+  blockchain: EnumBlockChain;
+}
+
+export interface AdminAddEscrowContractAddressResponse {}
+
+export interface AdminAddEscrowTokenContractAddressRequest {
+  pkeyId?: number | null;
+  symbol: string;
+  shortName: string;
+  description: string;
+  address: string;
+  blockchain: EnumBlockChain;
+  isStablecoin: boolean;
+  isWrapped: boolean;
+}
+
+export interface AdminAddEscrowTokenContractAddressResponse {}
+
+export interface AdminApprovePendingActionRequest {
+  pendingActionId: number;
+}
+
+export interface AdminApprovePendingActionResponse {
+  success: boolean;
+}
+
+export interface AdminApproveStrategyRequest {
+  strategyId: number;
+}
+
+export interface AdminApproveStrategyResponse {
+  success: boolean;
+}
+
+export interface AdminApproveUserBecomeExpertRequest {
+  userId: number;
+  note?: string | null;
+}
+
+export interface AdminApproveUserBecomeExpertResponse {
+  success: boolean;
+}
+
+export interface AdminBackStrategyLedgerRow {
+  backLedgerId: number;
+  userId: number;
+  strategyId: number;
+  quantity: string;
+  blockchain: EnumBlockChain;
+  transactionHash: string;
+  happenedAt: number;
+}
+
+export interface AdminEscrowTokenContractAddressRow {
+  pkeyId: number;
+  symbol: string;
+  shortName: string;
+  description: string;
+  address: string;
+  blockchain: EnumBlockChain;
+  decimals: number;
+  isStablecoin: boolean;
+  isWrapped: boolean;
+  price: number;
+}
+
+export interface AdminExitStrategyLedgerRow {
+  backLedgerId: number;
+  userId: number;
+  strategyId: number;
+  quantity: string;
+  blockchain: EnumBlockChain;
+  transactionHash: string;
+  happenedAt: number;
+}
+
+export interface AdminGetExpertApplicationDocumentRequest {
+  documentId: number;
+}
+
+export interface AdminGetExpertApplicationDocumentResponse {
+  name: string;
+  contentType: string;
+  content: string;
+}
+
+export interface AdminGetExpertApplicationRequest {
+  userId: number;
+}
+
+export interface AdminGetExpertApplicationResponse {
+  applicationId: number;
+  status: EnumExpertApplicationStatus;
+  questionnaire: unknown;
+  submittedAt?: number | null;
+  reviewedAt?: number | null;
+  documents: ExpertApplicationDocumentRow[];
+  notes: ExpertApplicationNoteRow[];
+}
+
+export interface AdminGetSystemConfigRequest {}
+
+export interface AdminGetSystemConfigResponse {
+  platformFee: number;
+  allowDomainUrls: string;
+  escrowContractAddressEthereum: string;
+  escrowContractAddressGoerli: string;
+  escrowContractAddressBsc: string;
+  escrowContractAddressBscTestnet: string;
+  settings: AdminSystemSettingRow[];
+}
+
+export interface AdminListActionLogRequest {
+  limit?: number | null;
+  offset?: number | null;
+  adminUserId?: number | null;
+  method?: string | null;
+}
+
+export interface AdminListActionLogResponse {
+  actionLogTotal: number;
+  actionLog: AdminActionLogRow[];
+}
+
+export interface AdminListBackStrategyLedgerRequest {
+  limit?: number | null;
+  offset?: number | null;
+  strategyId?: number | null;
+}
+
+export interface AdminListBackStrategyLedgerResponse {
+  backLedgerTotal: number;
+  backLedger: AdminBackStrategyLedgerRow[];
+}
+
+export interface AdminListBackersRequest {
+  offset?: number | null;
+  limit?: number | null;
+  userId?: number | null;
+  userPublicId?: number | null;
+  username?: string | null;
+  familyName?: string | null;
+  givenName?: string | null;
+}
+
+export interface AdminListBackersResponse {
+  backersTotal: number;
+  backers: AdminListBackersRow[];
+}
+
+export interface AdminListBackersRow {
+  username: string;
+  userId: number;
+  loginWalletAddress: string;
+  joinedAt: number;
+  totalPlatformFeePaid: number;
+  totalStrategyFeePaid: number;
+  totalBackingAmount: number;
+}
+
+export interface AdminListEscrowTokenContractAddressesRequest {
+  limit?: number | null;
+  offset?: number | null;
+  symbol?: string | null;
+  address?: string | null;
+  blockchain?: EnumBlockChain | null;
+}
+
+export interface AdminListEscrowTokenContractAddressesResponse {
+  addressesTotal: number;
+  addresses: AdminEscrowTokenContractAddressRow[];
+}
+
+export interface AdminListExitStrategyLedgerRequest {
+  limit?: number | null;
+  offset?: number | null;
+  strategyId?: number | null;
+}
+
+export interface AdminListExitStrategyLedgerResponse {
+  exitLedgerTotal: number;
+  exitLedger: AdminExitStrategyLedgerRow[];
+}
+
+export interface AdminListExpertsRequest {
+  limit?: number | null;
+  offset?: number | null;
+  expertId?: number | null;
+  userId?: number | null;
+  userPublicId?: number | null;
+  username?: string | null;
+  familyName?: string | null;
+  givenName?: string | null;
+  description?: string | null;
+  socialMedia?: string | null;
+}
+
+export interface AdminListExpertsResponse {
+  expertsTotal: number;
+  experts: ListExpertsRow[];
+}
+
+export interface AdminListPendingActionsRequest {
+  limit?: number | null;
+  offset?: number | null;
+  status?: EnumAdminPendingActionStatus | null;
+}
+
+export interface AdminListPendingActionsResponse {
+  pendingActionsTotal: number;
+  pendingActions: AdminPendingActionRow[];
+}
+
+export interface AdminListPendingExpertApplicationsRequest {
+  offset?: number | null;
+  limit?: number | null;
+}
+
+export interface AdminListPendingExpertApplicationsResponse {
+  usersTotal: number;
+  users: ListPendingExpertApplicationsRow[];
+}
+
+export interface AdminListStrategiesRequest {
+  offset?: number | null;
+  limit?: number | null;
+  strategyId?: number | null;
+  strategyName?: string | null;
+  expertId?: number | null;
+  expertName?: string | null;
+  description?: string | null;
+  pendingApproval?: boolean | null;
+  approved?: boolean | null;
+}
+
+export interface AdminListStrategiesResponse {
+  strategiesTotal: number;
+  strategies: ListStrategiesRow[];
+}
+
+export interface AdminListSystemSettingHistoryRequest {
+  limit?: number | null;
+  offset?: number | null;
+  key?: string | null;
+}
+
+export interface AdminListSystemSettingHistoryResponse {
+  historyTotal: number;
+  history: AdminSystemSettingHistoryRow[];
+}
+
+export interface AdminListUsersRequest {
+  limit: number;
+  offset: number;
+  userId?: number | null;
+  address?: string | null;
+  username?: string | null;
+  email?: string | null;
+  role?: EnumRole | null;
+}
+
+export interface AdminListUsersResponse {
+  usersTotal: number;
+  users: ListUserRow[];
+}
+
+export interface AdminNotifyEscrowLedgerChangeRequest {
+  pkeyId: number;
+  userId: number;
+  balance: UserListDepositLedgerRow;
+}
+
+export interface AdminNotifyEscrowLedgerChangeResponse {}
+
+export interface AdminPendingActionRow {
+  pendingActionId: number;
+  requestedByUserId: number;
+  method: string;
+  params: unknown;
+  status: EnumAdminPendingActionStatus;
+  approvedByUserId?: number | null;
+  approvedAt?: number | null;
+  createdAt: number;
+}
+
+export interface AdminRefreshExpertWalletBalanceRequest {
+  strategyId: number;
+}
+
+export interface AdminRefreshExpertWalletBalanceResponse {
+  success: boolean;
+}
+
+export interface AdminRejectStrategyRequest {
+  strategyId: number;
+}
+
+export interface AdminRejectStrategyResponse {
+  success: boolean;
+}
+
+export interface AdminRejectUserBecomeExpertRequest {
+  userId: number;
+  reason: string;
+}
+
+export interface AdminRejectUserBecomeExpertResponse {
+  success: boolean;
+}
+
+export interface AdminRequestExpertApplicationInfoRequest {
+  userId: number;
+  note: string;
+}
+
+export interface AdminRequestExpertApplicationInfoResponse {
+  success: boolean;
+}
+
+export interface AdminSetBlockUserRequest {
+  userId: number;
+  blocked: boolean;
+}
+
+export interface AdminSetBlockUserResponse {}
+
+export interface AdminSetBlockchainLoggerRequest {
+  enabled: boolean;
+}
+
+export interface AdminSetBlockchainLoggerResponse {}
+
+export interface AdminSetUserRoleRequest {
+  userId: number;
+  role: EnumRole;
+}
+
+export interface AdminSetUserRoleResponse {}
+
+export interface AdminSubscribeDepositLedgerRequest {
+  initialData?: number | null;
+  blockchain?: EnumBlockChain | null;
+  mockData?: boolean | null;
+}
+
+export interface AdminSubscribeDepositLedgerResponse {}
+
+export interface AdminSystemSettingHistoryRow {
+  key: string;
+  value: unknown;
+  version: number;
+  updatedByUserId?: number | null;
+  createdAt: number;
+}
+
+export interface AdminSystemSettingRow {
+  key: string;
+  value: unknown;
+  version: number;
+  updatedByUserId?: number | null;
+  updatedAt?: number | null;
+}
+
+export interface AdminSystemSettingUpdate {
+  key: string;
+  value: unknown;
+  version?: number | null;
+}
+
+export interface AdminUnsubscribeDepositLedgerRequest {}
+
+export interface AdminUnsubscribeDepositLedgerResponse {}
+
+export interface AdminUpdateEscrowTokenContractAddressRequest {
+  pkeyId: number;
+  symbol?: string | null;
+  shortName?: string | null;
+  description?: string | null;
+  isStablecoin?: boolean | null;
+  isWrapped?: boolean | null;
+}
+
+export interface AdminUpdateEscrowTokenContractAddressResponse {}
+
+export interface AdminUpdateSystemConfigRequest {
+  platformFee?: number | null;
+  allowDomainUrls?: string | null;
+  escrowContractAddressEthereum?: string | null;
+  escrowContractAddressGoerli?: string | null;
+  escrowContractAddressBsc?: string | null;
+  escrowContractAddressBscTestnet?: string | null;
+  settings?: AdminSystemSettingUpdate[] | null;
+}
+
+export interface AdminUpdateSystemConfigResponse {
+  success: boolean;
+}
+
+export interface AuthorizeApiKeyRequest {
+  apiKey: string;
+  timestamp: number;
+  signature: string;
+  service: EnumService;
+  nonce: string;
+}
+
+export interface AuthorizeApiKeyResponse {
+  success: boolean;
+}
+
+export interface AuthorizeRequest {
+  address: string;
+  token: string;
+  service: EnumService;
+  deviceId: string;
+  deviceOs: string;
+}
+
+export interface AuthorizeResponse {
+  success: boolean;
+}
+
+export interface BackLedgerPoint {
+  time: number;
+  backerCount: number;
+  backerQuantityUsd: number;
+}
+
+export interface BackStrategyLedgerRow {
+  backLedgerId: number;
+  strategyId: number;
+  quantity: string;
+  blockchain: EnumBlockChain;
+  transactionHash: string;
+  happenedAt: number;
+}
+
+export interface ChangeLoginWalletRequest {
+  oldAddress: string;
+  oldSignatureText: string;
+  oldSignature: string;
+  newAddress: string;
+  newSignatureText: string;
+  newSignature: string;
+}
+
+export interface ChangeLoginWalletResponse {}
+
+export interface EstimatedBackedTokenRatios {
+  tokenId: number;
+  tokenName: string;
+  backAmount: string;
+  backValueInUsd: string;
+  backValueRatio: number;
+}
+
+export interface ExitStrategyLedgerRow {
+  exitLedgerId: number;
+  strategyId: number;
+  quantity: string;
+  blockchain: EnumBlockChain;
+  transactionHash: string;
+  happenedAt: number;
+}
+
+export interface ExpertAddStrategyGuardRuleRequest {
+  strategyId: number;
+  ruleType: EnumStrategyGuardRuleType;
+  tokenId?: number | null;
+  threshold: number;
+  exitTokenId?: number | null;
+}
+
+export interface ExpertAddStrategyGuardRuleResponse {
+  ruleId: number;
+}
+
+export interface ExpertAddStrategyInitialTokenRatioRequest {
+  strategyId: number;
+  tokenId: number;
+  quantity: string;
+}
+
+export interface ExpertAddStrategyInitialTokenRatioResponse {
+  success: boolean;
+  tokenId: number;
+}
+
+export interface ExpertAddStrategyWatchingWalletRequest {
+  strategyId: number;
+  blockchain: EnumBlockChain;
+  walletAddress: string;
+  ratio: number;
+}
+
+export interface ExpertAddStrategyWatchingWalletResponse {
+  success: boolean;
+  walletId: number;
+}
+
+export interface ExpertApplicationDocumentRow {
+  documentId: number;
+  name: string;
+  contentType: string;
+  size: number;
+  createdAt: number;
+}
+
+export interface ExpertApplicationNoteRow {
+  status: EnumExpertApplicationStatus;
+  note?: string | null;
+  fromReviewer: boolean;
+  createdAt: number;
+}
+
+export interface ExpertCreateStrategyRequest {
+  name: string;
+  description: string;
+  strategyThesisUrl: string;
+  minimumBackingAmountUsd?: number | null;
+  expertFee: number;
+  agreedTos: boolean;
+  walletAddress: string;
+  walletBlockchain: EnumBlockChain;
+  strategyTokenRelativeToUsdcRatio?: string | null;
+  initialTokens: UserCreateStrategyInitialTokenRow[];
+  auditRules?: number[] | null;
+}
+
+export interface ExpertCreateStrategyResponse {
+  success: boolean;
+  strategyId: number;
+}
+
+export interface ExpertFreezeStrategyRequest {
+  strategyId: number;
+}
+
+export interface ExpertFreezeStrategyResponse {
+  success: boolean;
+}
+
+export interface ExpertListBackStrategyLedgerRequest {
+  limit?: number | null;
+  offset?: number | null;
+  strategyId?: number | null;
+}
+
+export interface ExpertListBackStrategyLedgerResponse {
+  backLedgerTotal: number;
+  backLedger: BackStrategyLedgerRow[];
+}
+
+export interface ExpertListBackersRequest {
+  limit?: number | null;
+  offset?: number | null;
+}
+
+export interface ExpertListBackersResponse {
+  backersTotal: number;
+  backers: ExpertListBackersRow[];
+}
+
+export interface ExpertListBackersRow {
+  publicId: number;
+  username: string;
+  familyName?: string | null;
+  givenName?: string | null;
+  linkedWallet: string;
+  backedAt: number;
+  joinedAt: number;
+}
+
+export interface ExpertListExitStrategyLedgerRequest {
+  strategyId?: number | null;
+  limit?: number | null;
+  offset?: number | null;
+}
+
+export interface ExpertListExitStrategyLedgerResponse {
+  exitLedgerTotal: number;
+  exitLedger: ExitStrategyLedgerRow[];
+}
+
+export interface ExpertListFollowersRequest {
+  limit?: number | null;
+  offset?: number | null;
+}
+
+export interface ExpertListFollowersResponse {
+  followersTotal: number;
+  followers: ExpertListFollowersRow[];
+}
+
+export interface ExpertListFollowersRow {
+  publicId: number;
+  username: string;
+  familyName?: string | null;
+  givenName?: string | null;
+  linkedWallet: string;
+  followedAt: number;
+  joinedAt: number;
+}
+
+export interface ExpertListPublishedStrategiesRequest {
+  limit?: number | null;
+  offset?: number | null;
+}
+
+export interface ExpertListPublishedStrategiesResponse {
+  strategiesTotal: number;
+  strategies: ListStrategiesRow[];
+}
+
+export interface ExpertListUnpublishedStrategiesRequest {
+  limit?: number | null;
+  offset?: number | null;
+}
+
+export interface ExpertListUnpublishedStrategiesResponse {
+  strategiesTotal: number;
+  strategies: ListStrategiesRow[];
+}
+
+export interface ExpertListenedWalletTradeLedgerRow {
+  ledgerId: number;
+  expertListenedWalletId: number;
+  blockchain: EnumBlockChain;
+  transactionHash: string;
+  dex: string;
+  tokenInId: number;
+  tokenInSymbol: string;
+  tokenInAddress: string;
+  amountIn: string;
+  tokenOutId: number;
+  tokenOutSymbol: string;
+  tokenOutAddress: string;
+  amountOut: string;
+  happenedAt: number;
+}
+
+export interface ExpertRemoveStrategyGuardRuleRequest {
+  strategyId: number;
+  ruleId: number;
+}
+
+export interface ExpertRemoveStrategyGuardRuleResponse {
+  success: boolean;
+}
+
+export interface ExpertRemoveStrategyInitialTokenRatioRequest {
+  strategyId: number;
+  tokenId: number;
+}
+
+export interface ExpertRemoveStrategyInitialTokenRatioResponse {
+  success: boolean;
+}
+
+export interface ExpertRemoveStrategyWatchingWalletRequest {
+  strategyId: number;
+  walletId: number;
+}
+
+export interface ExpertRemoveStrategyWatchingWalletResponse {
+  success: boolean;
+}
+
+export interface ExpertResumeStrategyCopyTradingRequest {
+  strategyId: number;
+}
+
+export interface ExpertResumeStrategyCopyTradingResponse {
+  success: boolean;
+}
+
+export interface ExpertUpdateStrategyRequest {
+  strategyId: number;
+  name?: string | null;
+  description?: string | null;
+  socialMedia?: string | null;
+  rebalanceIntervalSeconds?: number | null;
+  rebalanceDriftThreshold?: number | null;
+  copyTradeMinUsd?: number | null;
+  copyTradeMaxAumRatio?: number | null;
+  copyTradeCooldownSeconds?: number | null;
+  copyTradeBatchWindowSeconds?: number | null;
+  expertFee?: number | null;
+}
+
+export interface ExpertUpdateStrategyResponse {
+  success: boolean;
+}
+
+export interface FollowLedgerPoint {
+  time: number;
+  followerCount: number;
+}
+
+export interface GetLoginNonceRequest {
+  address: string;
+}
+
+export interface GetLoginNonceResponse {
+  nonce: string;
+  expiresAt: number;
+}
+
+export interface ListExpertsRow {
+  expertId: number;
+  linkedWallet: string;
+  name: string;
+  familyName?: string | null;
+  givenName?: string | null;
+  followerCount: number;
+  backerCount: number;
+  strategyCount: number;
+  description: string;
+  socialMedia: string;
+  riskScore: number;
+  reputationScore: number;
+  consistentScore: number;
+  aum: number;
+  joinedAt: number;
+  requestedAt: number;
+  approvedAt?: number | null;
+  pendingExpert: boolean;
+  approvedExpert: boolean;
+  followed: boolean;
+}
+
+export interface ListPendingExpertApplicationsRow {
+  userId: number;
+  name: string;
+  linkedWallet: string;
+  joinedAt: number;
+  requestedAt: number;
+  followerCount: number;
+  description: string;
+  socialMedia: string;
+  riskScore: number;
+  reputationScore: number;
+  aum: number;
+}
+
+export interface ListStrategiesRow {
+  strategyId: number;
+  strategyName: string;
+  strategyDescription: string;
+  followers: number;
+  backers: number;
+  aum: number;
+  followed: boolean;
+  swapPrice: number;
+  priceChange: number;
+  strategyPoolAddress?: string | null;
+  approved: boolean;
+  approvedAt?: number | null;
+  blockchain: EnumBlockChain;
+  requestedAt?: number | null;
+  createdAt: number;
+  expertId: number;
+  expertUsername: string;
+  expertFamilyName: string;
+  expertGivenName: string;
+  reputation: number;
+  riskScore: number;
+  strategyPoolToken: string;
+  strategyFee: number;
+  platformFee: number;
+  expertFee: number;
+  swapFee: number;
+  totalFee: number;
+  numberOfTokens: number;
+  backed: boolean;
+}
+
+export interface ListStrategyBackersRow {
+  userId: number;
+  name: string;
+  linkedWallet: string;
+  backedDate: number;
+}
+
+export interface ListStrategyFollowersRow {
+  userId: number;
+  name: string;
+  linkedWallet: string;
+  followedDate: number;
+}
+
+export interface ListStrategyGuardRulesRow {
+  ruleId: number;
+  ruleType: EnumStrategyGuardRuleType;
+  tokenId?: number | null;
+  tokenSymbol?: string | null;
+  threshold: number;
+  referenceValue?: number | null;
+  exitTokenId?: number | null;
+  exitTokenSymbol?: string | null;
+  triggeredAt?: number | null;
+  createdAt: number;
+}
+
+export interface ListStrategyInitialTokenRatioRow {
+  tokenId: number;
+  tokenName: string;
+  tokenAddress: string;
+  quantity: string;
+  updatedAt: number;
+  createdAt: number;
+}
+
+export interface ListStrategyVersionsRow {
+  version: number;
+  change: string;
+  params: unknown;
+  diff?: unknown | null;
+  isMaterial: boolean;
+  status: EnumStrategyVersionStatus;
+  effectiveAt: number;
+  appliedAt?: number | null;
+  createdAt: number;
+}
+
+export interface ListStrategyWatchingWalletsRow {
+  walletId: number;
+  blockchain: EnumBlockChain;
+  walletAddress: string;
+  ratio: number;
+}
+
+export interface ListUserRow {
+  userId: number;
+  publicUserId: number;
+  username?: string | null;
+  address: string;
+  lastIp: string;
+  lastLoginAt?: number | null;
+  loginCount: number;
+  role: EnumRole;
+  email?: string | null;
+  updatedAt: number;
+  createdAt: number;
+}
+
+export interface ListWalletActivityLedgerRow {
+  recordId: number;
+  walletAddress: string;
+  transactionHash: string;
+  dex: string;
+  blockchain: EnumBlockChain;
+  contractAddress: string;
+  tokenInAddress: string;
+  tokenOutAddress: string;
+  callerAddress: string;
+  amountIn: string;
+  amountOut: string;
+  swapCalls: unknown;
+  paths: unknown;
+  dexVersions: unknown;
+  createdAt: number;
+}
+
+export interface ListWalletsRow {
+  walletId: number;
+  blockchain: EnumBlockChain;
+  walletAddress: string;
+  isDefault: boolean;
+  isCompatible: boolean;
+}
+
+export interface LoginRequest {
+  address: string;
+  signatureText: string;
+  signature: string;
+  service: EnumService;
+  deviceId: string;
+  deviceOs: string;
+}
+
+export interface LoginResponse {
+  address: string;
+  displayName: string;
+  avatar?: string | null;
+  role: EnumRole;
+  userId: number;
+  userToken?: string | null;
+  adminToken?: string | null;
+  expiresAt: number;
+  refreshToken: string;
+  refreshExpiresAt: number;
+}
+
+export interface LogoutRequest {
+  address: string;
+  token: string;
+  service: EnumService;
+  deviceId: string;
+}
+
+export interface LogoutResponse {}
+
+export interface NetValuePoint {
+  time: number;
+  netValue: number;
+}
+
+export interface RefreshTokenRequest {
+  address: string;
+  refreshToken: string;
+  service: EnumService;
+  deviceId: string;
+}
+
+export interface RefreshTokenResponse {
+  token: string;
+  expiresAt: number;
+  refreshToken: string;
+  refreshExpiresAt: number;
+}
+
+export interface SignupRequest {
+  address: string;
+  signatureText: string;
+  signature: string;
+  email: string;
+  phone: string;
+  agreedTos: boolean;
+  agreedPrivacy: boolean;
+  username: string;
+}
+
+export interface SignupResponse {
+  address: string;
+  userId: number;
+}
+
+export interface StrategyGuardEvent {
+  strategyId: number;
+  ruleId: number;
+  blockchain: EnumBlockChain;
+  ruleType: EnumStrategyGuardRuleType;
+  threshold: number;
+  referenceValue: number;
+  currentValue: number;
+  exitTokenAddress?: string | null;
+  triggeredAt: number;
+}
+
+export interface StrategyPoolAssetBalancesRow {
+  name: string;
+  symbol: string;
+  address: string;
+  blockchain: EnumBlockChain;
+  balance: string;
+  priceUsd: number;
+  priceUsd7D: number;
+  priceUsd30D: number;
+}
+
+export interface StrategyPoolAssetLedgerRow {
+  aumLedgerId: number;
+  symbol: string;
+  tokenId: number;
+  blockchain: EnumBlockChain;
+  dex: string;
+  transactionHash: string;
+  quantity: string;
+  isAdd: boolean;
+  happenedAt: number;
+}
+
+export interface StrategyPoolContractAssetLedgerRow {
+  ledgerId: number;
+  symbol: string;
+  tokenId: number;
+  blockchain: EnumBlockChain;
+  dex: string;
+  transactionHash: string;
+  quantity: string;
+  isAdd: boolean;
+  happenedAt: number;
+}
+
+export interface UserActivityReportRow {
+  eventType: EnumUserActivityEventType;
+  blockchain: EnumBlockChain;
+  strategyId?: number | null;
+  transactionHash?: string | null;
+  tokenSymbol: string;
+  tokenAddress?: string | null;
+  quantity: string;
+  usdPrice?: number | null;
+  usdValue?: number | null;
+  fees: string;
+  happenedAt: number;
+}
+
+export interface UserAddStrategyAuditRuleRequest {
+  strategyId: number;
+  ruleId: number;
+}
+
+export interface UserAddStrategyAuditRuleResponse {}
+
+export interface UserAllowedEscrowTransferInfo {
+  receiverAddress: string;
+  blockchain: EnumBlockChain;
+  tokenId: number;
+  tokenSymbol: string;
+  tokenName: string;
+  tokenAddress: string;
+}
+
+export interface UserApiKeyRow {
+  apiKeyId: number;
+  name: string;
+  apiKey: string;
+  scopes: EnumApiKeyScope[];
+  ipAllowlist?: string[] | null;
+  expiresAt?: number | null;
+  createdAt: number;
+  lastUsedAt?: number | null;
+}
+
+export interface UserApplyBecomeExpertRequest {}
+
+export interface UserApplyBecomeExpertResponse {
+  success: boolean;
+  expertId: number;
+}
+
+export interface UserBackStrategyAttempt {
+  attemptId: number;
+  strategyId: number;
+  strategyName: string;
+  tokenId: number;
+  tokenSymbol: string;
+  tokenName: string;
+  quantity: string;
+  happenedAt: number;
+}
+
+export interface UserBackStrategyLog {
+  pkeyId: number;
+  message: string;
+  happenedAt: number;
+}
+
+export interface UserBackStrategyRequest {
+  strategyId: number;
+  quantity: string;
+  tokenId: number;
+  strategyWallet?: string | null;
+  nonce: number;
+}
+
+export interface UserBackStrategyResponse {}
+
+export interface UserBackStrategyStreamResponse {
+  end: boolean;
+  msg: string;
+  hash: string;
+}
+
+export interface UserCreateApiKeyRequest {
+  name: string;
+  scopes: EnumApiKeyScope[];
+  ipAllowlist?: string[] | null;
+  expiresAt?: number | null;
+}
+
+export interface UserCreateApiKeyResponse {
+  apiKeyId: number;
+  apiKey: string;
+  apiSecret: string;
+}
+
+export interface UserCreateStrategyInitialTokenRow {
+  tokenId: number;
+  quantity: string;
+}
+
+export interface UserCreateStrategyWalletRequest {
+  blockchain: EnumBlockChain;
+  userManagedWalletAddress?: string | null;
+}
+
+export interface UserCreateStrategyWalletResponse {
+  blockchain: EnumBlockChain;
+  address: string;
+}
+
+export interface UserDeleteExpertApplicationDocumentRequest {
+  documentId: number;
+}
+
+export interface UserDeleteExpertApplicationDocumentResponse {
+  success: boolean;
+}
+
+export interface UserExitStrategyRequest {
+  strategyId: number;
+  quantity: string;
+  blockchain: EnumBlockChain;
+  nonce: number;
+}
+
+export interface UserExitStrategyResponse {
+  success: boolean;
+  transactionHash: string;
+}
+
+export interface UserExportActivityReportRequest {
+  startTime: number;
+  endTime: number;
+  blockchain?: EnumBlockChain | null;
+  format?: EnumActivityReportFormat | null;
+  limit?: number | null;
+  offset?: number | null;
+}
+
+export interface UserExportActivityReportResponse {
+  reportTotal: number;
+  report: UserActivityReportRow[];
+  csv?: string | null;
+  truncated: boolean;
+}
+
+export interface UserFollowExpertRequest {
+  expertId: number;
+}
+
+export interface UserFollowExpertResponse {
+  success: boolean;
+}
+
+export interface UserFollowStrategyRequest {
+  strategyId: number;
+}
+
+export interface UserFollowStrategyResponse {
+  success: boolean;
+}
+
+export interface UserGetBackStrategyReviewDetailRequest {
+  strategyId: number;
+  tokenId: number;
+  quantity: string;
+}
+
+export interface UserGetBackStrategyReviewDetailResponse {
+  strategyFee: string;
+  totalAmountToBack: string;
+  totalAmountToBackAfterFee: string;
+  userStrategyWallets: UserStrategyWallet[];
+  estimatedAmountOfStrategyTokens: string;
+  estimatedBackedTokenRatios: EstimatedBackedTokenRatios[];
+  strategyPoolAssetBalances: StrategyPoolAssetBalancesRow[];
+}
+
+export interface UserGetDepositAddressesRequest {}
+
+export interface UserGetDepositAddressesResponse {
+  addresses: UserGetDepositAddressesRow[];
+}
+
+export interface UserGetDepositAddressesRow {
+  blockchain: EnumBlockChain;
+  address: string;
+  shortName: string;
+}
+
+export interface UserGetDepositTokensRequest {}
+
+export interface UserGetDepositTokensResponse {
+  tokens: UserGetDepositTokensRow[];
+}
+
+export interface UserGetDepositTokensRow {
+  blockchain: EnumBlockChain;
+  token: string;
+  address: string;
+  shortName: string;
+  iconUrl: string;
+  conversion: number;
+}
+
+export interface UserGetDepositWithdrawBalanceRequest {
+  tokenId: number;
+}
+
+export interface UserGetDepositWithdrawBalanceResponse {
+  balance: string;
+}
+
+export interface UserGetEscrowAddressForStrategyRequest {
+  strategyId: number;
+  tokenId?: number | null;
+}
+
+export interface UserGetEscrowAddressForStrategyResponse {
+  tokens: UserAllowedEscrowTransferInfo[];
+}
+
+export interface UserGetExpertApplicationRequest {}
+
+export interface UserGetExpertApplicationResponse {
+  applicationId?: number | null;
+  status: EnumExpertApplicationStatus;
+  questionnaire: unknown;
+  submittedAt?: number | null;
+  reviewedAt?: number | null;
+  documents: ExpertApplicationDocumentRow[];
+  notes: ExpertApplicationNoteRow[];
+}
+
+export interface UserGetExpertProfileRequest {
+  expertId: number;
+}
+
+export interface UserGetExpertProfileResponse {
+  expertId: number;
+  name: string;
+  familyName: string;
+  givenName: string;
+  followerCount: number;
+  backersCount: number;
+  description: string;
+  socialMedia: string;
+  consistentScore: number;
+  riskScore: number;
+  reputationScore: number;
+  aum: number;
+  followed: boolean;
+  strategiesTotal: number;
+  strategies: ListStrategiesRow[];
+}
+
+export interface UserGetStrategiesStatisticsAumListHistory {
+  tokenId: number;
+  tokenName: string;
+  tokenSymbol: string;
+  side: string;
+  quantity: string;
+  quantityUsd: number;
+  happenedAt: number;
+  currentPriceUsd: number;
+  priceChange7D: number;
+  priceChange30D: number;
+}
+
+export interface UserGetStrategiesStatisticsRequest {}
+
+export interface UserGetStrategiesStatisticsResponse {
+  trackingAmountUsd: number;
+  backingAmountUsd: number;
+  differenceAmountUsd: number;
+  aumValueUsd: number;
+  currentValueUsd: number;
+  withdrawableValueUsd: number;
+  strategyPoolTokens: UserGetStrategiesStatisticsStrategyPoolToken[];
+  aumListHistory: UserGetStrategiesStatisticsAumListHistory[];
+}
+
+export interface UserGetStrategiesStatisticsStrategyPoolToken {
+  tokenId: number;
+  tokenName: string;
+  tokenSymbol: string;
+  totalQuantity: string;
+  totalQuantityUsd: number;
+  currentPriceUsd: number;
+  priceChange7D: number;
+  priceChange30D: number;
+}
+
+export interface UserGetStrategyRequest {
+  strategyId: number;
+}
+
+export interface UserGetStrategyResponse {
+  strategy: ListStrategiesRow;
+  watchingWallets: WatchingWalletRow[];
+  strategyPoolAssetUpdatedAt: number;
+  strategyPoolAssetBalances: StrategyPoolAssetBalancesRow[];
+  strategyPoolAssetLedger: StrategyPoolAssetLedgerRow[];
+  auditRules: UserListStrategyAuditRulesRow[];
+  whitelistedTokens: string[];
+}
+
+export interface UserGetStrategyStatisticsRequest {
+  strategyId: number;
+}
+
+export interface UserGetStrategyStatisticsResponse {
+  strategyId: number;
+  netValue: NetValuePoint[];
+  followLedger: FollowLedgerPoint[];
+  backLedger: BackLedgerPoint[];
+}
+
+export interface UserGetSystemConfigRequest {}
+
+export interface UserGetSystemConfigResponse {
+  platformFee: number;
+}
+
+export interface UserGetUserProfileRequest {}
+
+export interface UserGetUserProfileResponse {
+  name: string;
+  loginWallet: string;
+  joinedAt: number;
+  followerCount: number;
+  description: string;
+  socialMedia: string;
+  followedExperts: ListExpertsRow[];
+  followedStrategies: ListStrategiesRow[];
+  backedStrategies: ListStrategiesRow[];
+}
+
+export interface UserLinkLoginWalletRequest {
+  address: string;
+  signatureText: string;
+  signature: string;
+}
+
+export interface UserLinkLoginWalletResponse {}
+
+export interface UserListApiKeysRequest {}
+
+export interface UserListApiKeysResponse {
+  apiKeys: UserApiKeyRow[];
+}
+
+export interface UserListBackStrategyLedgerRequest {
+  limit?: number | null;
+  offset?: number | null;
+  strategyId?: number | null;
+}
+
+export interface UserListBackStrategyLedgerResponse {
+  backLedgerTotal: number;
+  backLedger: BackStrategyLedgerRow[];
+}
+
+export interface UserListBackedStrategiesRequest {
+  limit?: number | null;
+  offset?: number | null;
+}
+
+export interface UserListBackedStrategiesResponse {
+  strategiesTotal: number;
+  strategies: ListStrategiesRow[];
+}
+
+export interface UserListDepositLedgerRow {
+  transactionId: number;
+  blockchain: EnumBlockChain;
+  userAddress: string;
+  contractAddress: string;
+  receiverAddress: string;
+  quantity: string;
+  transactionHash: string;
+  isDeposit: boolean;
+  happenedAt: number;
+}
+
+export interface UserListDepositWithdrawBalance {
+  blockchain: EnumBlockChain;
+  tokenId: number;
+  tokenSymbol: string;
+  tokenName: string;
+  balance: string;
+}
+
+export interface UserListDepositWithdrawBalancesRequest {}
+
+export interface UserListDepositWithdrawBalancesResponse {
+  balances: UserListDepositWithdrawBalance[];
+}
+
+export interface UserListDepositWithdrawLedgerRequest {
+  limit?: number | null;
+  offset?: number | null;
+  blockchain?: EnumBlockChain | null;
+  idDeposit?: boolean | null;
+}
+
+export interface UserListDepositWithdrawLedgerResponse {
+  ledgerTotal: number;
+  ledger: UserListDepositLedgerRow[];
+}
+
+export interface UserListEscrowTokenContractAddressesRequest {
+  limit?: number | null;
+  offset?: number | null;
+  blockchain?: EnumBlockChain | null;
+  isStablecoin?: boolean | null;
+}
+
+export interface UserListEscrowTokenContractAddressesResponse {
+  tokensTotal: number;
+  tokens: UserListEscrowTokenContractAddressesRow[];
+}
+
+export interface UserListEscrowTokenContractAddressesRow {
+  tokenId: number;
+  tokenSymbol: string;
+  tokenName: string;
+  tokenAddress: string;
+  description: string;
+  blockchain: EnumBlockChain;
+  isStablecoin: boolean;
+}
+
+export interface UserListExitStrategyLedgerRequest {
+  strategyId?: number | null;
+  limit?: number | null;
+  offset?: number | null;
+}
+
+export interface UserListExitStrategyLedgerResponse {
+  exitLedgerTotal: number;
+  exitLedger: ExitStrategyLedgerRow[];
+}
+
+export interface UserListExpertListenedWalletTradeLedgerRequest {
+  strategyId: number;
+  limit?: number | null;
+  offset?: number | null;
+}
+
+export interface UserListExpertListenedWalletTradeLedgerResponse {
+  expertListenedWalletTradeLedger: ExpertListenedWalletTradeLedgerRow[];
+}
+
+export interface UserListExpertsRequest {
+  limit?: number | null;
+  offset?: number | null;
+  expertId?: number | null;
+  userId?: number | null;
+  userPublicId?: number | null;
+  username?: string | null;
+  familyName?: string | null;
+  givenName?: string | null;
+  description?: string | null;
+  socialMedia?: string | null;
+  sortByFollowers?: boolean | null;
+}
+
+export interface UserListExpertsResponse {
+  expertsTotal: number;
+  experts: ListExpertsRow[];
+}
+
+export interface UserListFeaturedExpertsRequest {
+  limit?: number | null;
+  offset?: number | null;
+}
+
+export interface UserListFeaturedExpertsResponse {
+  expertsTotal: number;
+  experts: ListExpertsRow[];
+}
+
+export interface UserListFollowedExpertsRequest {
+  limit?: number | null;
+  offset?: number | null;
+}
+
+export interface UserListFollowedExpertsResponse {
+  expertsTotal: number;
+  experts: ListExpertsRow[];
+}
+
+export interface UserListFollowedStrategiesRequest {
+  limit?: number | null;
+  offset?: number | null;
+}
+
+export interface UserListFollowedStrategiesResponse {
+  strategiesTotal: number;
+  strategies: ListStrategiesRow[];
+}
+
+export interface UserListLoginWalletsRequest {}
+
+export interface UserListLoginWalletsResponse {
+  wallets: UserLoginWalletRow[];
+}
+
+export interface UserListNotificationsRequest {
+  unreadOnly?: boolean | null;
+  limit?: number | null;
+  offset?: number | null;
+}
+
+export interface UserListNotificationsResponse {
+  notificationsTotal: number;
+  unreadTotal: number;
+  notifications: UserNotificationRow[];
+}
+
+export interface UserListSessionsRequest {}
+
+export interface UserListSessionsResponse {
+  sessions: UserSessionRow[];
+}
+
+export interface UserListStrategiesRequest {
+  limit?: number | null;
+  offset?: number | null;
+  strategyId?: number | null;
+  strategyName?: string | null;
+  expertId?: number | null;
+  expertName?: string | null;
+  description?: string | null;
+  blockchain?: EnumBlockChain | null;
+  strategyPoolAddress?: string | null;
+}
+
+export interface UserListStrategiesResponse {
+  strategiesTotal: number;
+  strategies: ListStrategiesRow[];
+}
+
+export interface UserListStrategyAuditRulesRequest {
+  strategyId?: number | null;
+}
+
+export interface UserListStrategyAuditRulesResponse {
+  auditRules: UserListStrategyAuditRulesRow[];
+}
+
+export interface UserListStrategyAuditRulesRow {
+  ruleId: number;
+  ruleName: string;
+  ruleDescription: string;
+  createdAt: number;
+  enabled: boolean;
+}
+
+export interface UserListStrategyBackersRequest {
+  strategyId: number;
+  limit?: number | null;
+  offset?: number | null;
+}
+
+export interface UserListStrategyBackersResponse {
+  backersTotal: number;
+  backers: ListStrategyBackersRow[];
+}
+
+export interface UserListStrategyFollowersRequest {
+  strategyId: number;
+  limit?: number | null;
+  offset?: number | null;
+}
+
+export interface UserListStrategyFollowersResponse {
+  followersTotal: number;
+  followers: ListStrategyFollowersRow[];
+}
+
+export interface UserListStrategyGuardRulesRequest {
+  strategyId: number;
+}
+
+export interface UserListStrategyGuardRulesResponse {
+  rulesTotal: number;
+  rules: ListStrategyGuardRulesRow[];
+}
+
+export interface UserListStrategyInitialTokenRatioRequest {
+  strategyId: number;
+}
+
+export interface UserListStrategyInitialTokenRatioResponse {
+  tokenRatiosTotal: number;
+  tokenRatios: ListStrategyInitialTokenRatioRow[];
+}
+
+export interface UserListStrategyPoolContractAssetLedgerRequest {
+  strategyId: number;
+  blockchain: EnumBlockChain;
+  limit?: number | null;
+  offset?: number | null;
+}
+
+export interface UserListStrategyPoolContractAssetLedgerResponse {
+  strategyPoolContractAssetLedger: StrategyPoolContractAssetLedgerRow[];
+}
+
+export interface UserListStrategyTokenBalanceRequest {
+  limit?: number | null;
+  offset?: number | null;
+  strategyId?: number | null;
+}
+
+export interface UserListStrategyTokenBalanceResponse {
+  tokensTotal: number;
+  tokens: UserListStrategyTokenBalanceRow[];
+}
+
+export interface UserListStrategyTokenBalanceRow {
+  strategyId: number;
+  strategyName: string;
+  balance: string;
+  address: string;
+  blockchain: EnumBlockChain;
+}
+
+export interface UserListStrategyVersionsRequest {
+  strategyId: number;
+  limit?: number | null;
+  offset?: number | null;
+}
+
+export interface UserListStrategyVersionsResponse {
+  versionsTotal: number;
+  versions: ListStrategyVersionsRow[];
+}
+
+export interface UserListStrategyWalletsRequest {
+  blockchain?: EnumBlockChain | null;
+}
+
+export interface UserListStrategyWalletsResponse {
+  walletsTotal: number;
+  wallets: UserListStrategyWalletsRow[];
+}
+
+export interface UserListStrategyWalletsRow {
+  blockchain: EnumBlockChain;
+  address: string;
+  isPlatformManaged: boolean;
+  createdAt: number;
+}
+
+export interface UserListStrategyWatchingWalletsRequest {
+  strategyId: number;
+}
+
+export interface UserListStrategyWatchingWalletsResponse {
+  walletsTotal: number;
+  wallets: ListStrategyWatchingWalletsRow[];
+}
+
+export interface UserListTopPerformingExpertsRequest {
+  limit?: number | null;
+  offset?: number | null;
+}
+
+export interface UserListTopPerformingExpertsResponse {
+  expertsTotal: number;
+  experts: ListExpertsRow[];
+}
+
+export interface UserListTopPerformingStrategiesRequest {
+  limit?: number | null;
+  offset?: number | null;
+}
+
+export interface UserListTopPerformingStrategiesResponse {
+  strategiesTotal: number;
+  strategies: ListStrategiesRow[];
+}
+
+export interface UserListUserBackStrategyAttemptRequest {
+  limit?: number | null;
+  offset?: number | null;
+  strategyId?: number | null;
+  tokenId?: number | null;
+}
+
+export interface UserListUserBackStrategyAttemptResponse {
+  total: number;
+  backAttempts: UserBackStrategyAttempt[];
+}
+
+export interface UserListUserBackStrategyLogRequest {
+  attemptId: number;
+  limit?: number | null;
+  offset?: number | null;
+}
+
+export interface UserListUserBackStrategyLogResponse {
+  backLogsTotal: number;
+  backLogs: UserBackStrategyLog[];
+}
+
+export interface UserListUserStrategyBalanceRequest {
+  limit?: number | null;
+  offset?: number | null;
+  strategyId?: number | null;
+}
+
+export interface UserListUserStrategyBalanceResponse {
+  balancesTotal: number;
+  balances: UserStrategyBalance[];
+}
+
+export interface UserListUserStrategyPoolContractAssetLedgerRequest {
+  userId: number;
+  strategyId: number;
+  blockchain: EnumBlockChain;
+  limit?: number | null;
+  offset?: number | null;
+}
+
+export interface UserListUserStrategyPoolContractAssetLedgerResponse {
+  userStrategyPoolContractAssetLedger: UserStrategyPoolContractAssetLedgerRow[];
+}
+
+export interface UserListWalletActivityLedgerRequest {
+  walletAddress: string;
+  blockchain: EnumBlockChain;
+}
+
+export interface UserListWalletActivityLedgerResponse {
+  walletActivitiesTotal: number;
+  walletActivities: ListWalletActivityLedgerRow[];
+}
+
+export interface UserListWhitelistedWalletsRequest {
+  limit?: number | null;
+  offset?: number | null;
+  walletId?: number | null;
+  blockchain?: EnumBlockChain | null;
+  walletAddress?: string | null;
+  strategyId?: number | null;
+}
+
+export interface UserListWhitelistedWalletsResponse {
+  wallets: ListWalletsRow[];
+}
+
+export interface UserLoginWalletRow {
+  address: string;
+  isPrimary: boolean;
+  createdAt: number;
+}
+
+export interface UserMarkNotificationsReadRequest {
+  notificationIds?: number[] | null;
+}
+
+export interface UserMarkNotificationsReadResponse {
+  marked: number;
+}
+
+export interface UserNotification {
+  notificationId: number;
+  kind: EnumUserNotificationKind;
+  strategyId?: number | null;
+  message: string;
+  readAt?: number | null;
+  createdAt: number;
+}
+
+export interface UserNotificationRow {
+  notificationId: number;
+  kind: EnumUserNotificationKind;
+  strategyId?: number | null;
+  message: string;
+  readAt?: number | null;
+  createdAt: number;
+}
+
+export interface UserRemoveStrategyAuditRuleRequest {
+  strategyId: number;
+  ruleId: number;
+}
+
+export interface UserRemoveStrategyAuditRuleResponse {}
+
+export interface UserRequestRefundRequest {
+  quantity: string;
+  walletAddress: string;
+  blockchain: EnumBlockChain;
+  nonce: number;
+}
+
+export interface UserRequestRefundResponse {
+  success: boolean;
+}
+
+export interface UserRequestWithdrawRequest {
+  quantity: string;
+  walletAddress: string;
+  blockchain: EnumBlockChain;
+  nonce: number;
+}
+
+export interface UserRequestWithdrawResponse {
+  success: boolean;
+  quantityReceived: string;
+  fee: string;
+}
+
+export interface UserRevokeApiKeyRequest {
+  apiKeyId: number;
+}
+
+export interface UserRevokeApiKeyResponse {}
+
+export interface UserRevokeSessionRequest {
+  sessionId: number;
+}
+
+export interface UserRevokeSessionResponse {}
+
+export interface UserSaveExpertApplicationRequest {
+  questionnaire: unknown;
+}
+
+export interface UserSaveExpertApplicationResponse {
+  applicationId: number;
+}
+
+export interface UserSessionRow {
+  sessionId: number;
+  service: EnumService;
+  deviceId: string;
+  deviceOs: string;
+  ipAddress: string;
+  createdAt: number;
+  lastSeenAt: number;
+  expiresAt: number;
+}
+
+export interface UserSetPrimaryLoginWalletRequest {
+  address: string;
+}
+
+export interface UserSetPrimaryLoginWalletResponse {}
+
+export interface UserStrategyBalance {
+  strategyId: number;
+  strategyName: string;
+  balance: string;
+  address: string;
+  blockchain: EnumBlockChain;
+}
+
+export interface UserStrategyPoolContractAssetLedgerRow {
+  ledgerId: number;
+  strategyWalletId: number;
+  strategyWalletAddress: string;
+  isStrategyWalletManaged: boolean;
+  symbol: string;
+  tokenId: number;
+  tokenAddress: string;
+  blockchain: EnumBlockChain;
+  dex: string;
+  quantity: string;
+  isAdd: boolean;
+  happenedAt: number;
+}
+
+export interface UserStrategyWallet {
+  walletId: number;
+  address: string;
+  blockchain: EnumBlockChain;
+  isPlatformAddress: boolean;
+}
+
+export interface UserSubscribeDepositLedgerRequest {
+  initialData?: number | null;
+  blockchain?: EnumBlockChain | null;
+  mockData?: boolean | null;
+}
+
+export interface UserSubscribeDepositLedgerResponse {}
+
+export interface UserSubscribeNotificationsRequest {}
+
+export interface UserSubscribeNotificationsResponse {}
+
+export interface UserSubscribeStrategyGuardEventsRequest {}
+
+export interface UserSubscribeStrategyGuardEventsResponse {}
+
+export interface UserUnfollowExpertRequest {
+  expertId: number;
+}
+
+export interface UserUnfollowExpertResponse {
+  success: boolean;
+}
+
+export interface UserUnfollowStrategyRequest {
+  strategyId: number;
+}
+
+export interface UserUnfollowStrategyResponse {
+  success: boolean;
+}
+
+export interface UserUnlinkLoginWalletRequest {
+  address: string;
+}
+
+export interface UserUnlinkLoginWalletResponse {}
+
+export interface UserUnsubscribeDepositLedgerRequest {}
+
+export interface UserUnsubscribeDepositLedgerResponse {}
+
+export interface UserUnsubscribeNotificationsRequest {}
+
+export interface UserUnsubscribeNotificationsResponse {}
+
+export interface UserUnsubscribeStrategyGuardEventsRequest {}
+
+export interface UserUnsubscribeStrategyGuardEventsResponse {}
+
+export interface UserUnwhitelistWalletRequest {
+  walletId: number;
+}
+
+export interface UserUnwhitelistWalletResponse {
+  success: boolean;
+}
+
+export interface UserUpdateUserProfileRequest {
+  username?: string | null;
+  familyName?: string | null;
+  givenName?: string | null;
+  description?: string | null;
+  socialMedia?: string | null;
+}
+
+export interface UserUpdateUserProfileResponse {}
+
+export interface UserUploadExpertApplicationDocumentRequest {
+  name: string;
+  contentType: string;
+  content: string;
+}
+
+export interface UserUploadExpertApplicationDocumentResponse {
+  documentId: number;
+}
+
+export interface UserWhitelistWalletRequest {
+  blockchain: EnumBlockChain;
+  walletAddress: string;
+}
+
+export interface UserWhitelistWalletResponse {
+  success: boolean;
+  walletId: number;
+}
+
+export interface WatchingWalletRow {
+  watchingWalletId: number;
+  walletAddress: string;
+  blockchain: EnumBlockChain;
+  ratioDistribution: number;
+}
@@ -0,0 +1,634 @@
+// generated by codegen from the endpoint schemas, do not edit
+import type { StreamHandler, Subscription, WsClient } from "./ws_client";
+import { Endpoint } from "./model";
+import type {
+  ExpertAddStrategyGuardRuleRequest,
+  ExpertAddStrategyGuardRuleResponse,
+  ExpertAddStrategyInitialTokenRatioRequest,
+  ExpertAddStrategyInitialTokenRatioResponse,
+  ExpertAddStrategyWatchingWalletRequest,
+  ExpertAddStrategyWatchingWalletResponse,
+  ExpertCreateStrategyRequest,
+  ExpertCreateStrategyResponse,
+  ExpertFreezeStrategyRequest,
+  ExpertFreezeStrategyResponse,
+  ExpertListBackStrategyLedgerRequest,
+  ExpertListBackStrategyLedgerResponse,
+  ExpertListBackersRequest,
+  ExpertListBackersResponse,
+  ExpertListExitStrategyLedgerRequest,
+  ExpertListExitStrategyLedgerResponse,
+  ExpertListFollowersRequest,
+  ExpertListFollowersResponse,
+  ExpertListPublishedStrategiesRequest,
+  ExpertListPublishedStrategiesResponse,
+  ExpertListUnpublishedStrategiesRequest,
+  ExpertListUnpublishedStrategiesResponse,
+  ExpertRemoveStrategyGuardRuleRequest,
+  ExpertRemoveStrategyGuardRuleResponse,
+  ExpertRemoveStrategyInitialTokenRatioRequest,
+  ExpertRemoveStrategyInitialTokenRatioResponse,
+  ExpertRemoveStrategyWatchingWalletRequest,
+  ExpertRemoveStrategyWatchingWalletResponse,
+  ExpertResumeStrategyCopyTradingRequest,
+  ExpertResumeStrategyCopyTradingResponse,
+  ExpertUpdateStrategyRequest,
+  ExpertUpdateStrategyResponse,
+  StrategyGuardEvent,
+  UserAddStrategyAuditRuleRequest,
+  UserAddStrategyAuditRuleResponse,
+  UserApplyBecomeExpertRequest,
+  UserApplyBecomeExpertResponse,
+  UserBackStrategyRequest,
+  UserBackStrategyResponse,
+  UserBackStrategyStreamResponse,
+  UserCreateApiKeyRequest,
+  UserCreateApiKeyResponse,
+  UserCreateStrategyWalletRequest,
+  UserCreateStrategyWalletResponse,
+  UserDeleteExpertApplicationDocumentRequest,
+  UserDeleteExpertApplicationDocumentResponse,
+  UserExitStrategyRequest,
+  UserExitStrategyResponse,
+  UserExportActivityReportRequest,
+  UserExportActivityReportResponse,
+  UserFollowExpertRequest,
+  UserFollowExpertResponse,
+  UserFollowStrategyRequest,
+  UserFollowStrategyResponse,
+  UserGetBackStrategyReviewDetailRequest,
+  UserGetBackStrategyReviewDetailResponse,
+  UserGetDepositAddressesRequest,
+  UserGetDepositAddressesResponse,
+  UserGetDepositTokensRequest,
+  UserGetDepositTokensResponse,
+  UserGetDepositWithdrawBalanceRequest,
+  UserGetDepositWithdrawBalanceResponse,
+  UserGetEscrowAddressForStrategyRequest,
+  UserGetEscrowAddressForStrategyResponse,
+  UserGetExpertApplicationRequest,
+  UserGetExpertApplicationResponse,
+  UserGetExpertProfileRequest,
+  UserGetExpertProfileResponse,
+  UserGetStrategiesStatisticsRequest,
+  UserGetStrategiesStatisticsResponse,
+  UserGetStrategyRequest,
+  UserGetStrategyResponse,
+  UserGetStrategyStatisticsRequest,
+  UserGetStrategyStatisticsResponse,
+  UserGetSystemConfigRequest,
+  UserGetSystemConfigResponse,
+  UserGetUserProfileRequest,
+  UserGetUserProfileResponse,
+  UserLinkLoginWalletRequest,
+  UserLinkLoginWalletResponse,
+  UserListApiKeysRequest,
+  UserListApiKeysResponse,
+  UserListBackStrategyLedgerRequest,
+  UserListBackStrategyLedgerResponse,
+  UserListBackedStrategiesRequest,
+  UserListBackedStrategiesResponse,
+  UserListDepositLedgerRow,
+  UserListDepositWithdrawBalancesRequest,
+  UserListDepositWithdrawBalancesResponse,
+  UserListDepositWithdrawLedgerRequest,
+  UserListDepositWithdrawLedgerResponse,
+  UserListEscrowTokenContractAddressesRequest,
+  UserListEscrowTokenContractAddressesResponse,
+  UserListExitStrategyLedgerRequest,
+  UserListExitStrategyLedgerResponse,
+  UserListExpertListenedWalletTradeLedgerRequest,
+  UserListExpertListenedWalletTradeLedgerResponse,
+  UserListExpertsRequest,
+  UserListExpertsResponse,
+  UserListFeaturedExpertsRequest,
+  UserListFeaturedExpertsResponse,
+  UserListFollowedExpertsRequest,
+  UserListFollowedExpertsResponse,
+  UserListFollowedStrategiesRequest,
+  UserListFollowedStrategiesResponse,
+  UserListLoginWalletsRequest,
+  UserListLoginWalletsResponse,
+  UserListNotificationsRequest,
+  UserListNotificationsResponse,
+  UserListSessionsRequest,
+  UserListSessionsResponse,
+  UserListStrategiesRequest,
+  UserListStrategiesResponse,
+  UserListStrategyAuditRulesRequest,
+  UserListStrategyAuditRulesResponse,
+  UserListStrategyBackersRequest,
+  UserListStrategyBackersResponse,
+  UserListStrategyFollowersRequest,
+  UserListStrategyFollowersResponse,
+  UserListStrategyGuardRulesRequest,
+  UserListStrategyGuardRulesResponse,
+  UserListStrategyInitialTokenRatioRequest,
+  UserListStrategyInitialTokenRatioResponse,
+  UserListStrategyPoolContractAssetLedgerRequest,
+  UserListStrategyPoolContractAssetLedgerResponse,
+  UserListStrategyTokenBalanceRequest,
+  UserListStrategyTokenBalanceResponse,
+  UserListStrategyVersionsRequest,
+  UserListStrategyVersionsResponse,
+  UserListStrategyWalletsRequest,
+  UserListStrategyWalletsResponse,
+  UserListStrategyWatchingWalletsRequest,
+  UserListStrategyWatchingWalletsResponse,
+  UserListTopPerformingExpertsRequest,
+  UserListTopPerformingExpertsResponse,
+  UserListTopPerformingStrategiesRequest,
+  UserListTopPerformingStrategiesResponse,
+  UserListUserBackStrategyAttemptRequest,
+  UserListUserBackStrategyAttemptResponse,
+  UserListUserBackStrategyLogRequest,
+  UserListUserBackStrategyLogResponse,
+  UserListUserStrategyBalanceRequest,
+  UserListUserStrategyBalanceResponse,
+  UserListUserStrategyPoolContractAssetLedgerRequest,
+  UserListUserStrategyPoolContractAssetLedgerResponse,
+  UserListWalletActivityLedgerRequest,
+  UserListWalletActivityLedgerResponse,
+  UserListWhitelistedWalletsRequest,
+  UserListWhitelistedWalletsResponse,
+  UserMarkNotificationsReadRequest,
+  UserMarkNotificationsReadResponse,
+  UserNotification,
+  UserRemoveStrategyAuditRuleRequest,
+  UserRemoveStrategyAuditRuleResponse,
+  UserRequestRefundRequest,
+  UserRequestRefundResponse,
+  UserRequestWithdrawRequest,
+  UserRequestWithdrawResponse,
+  UserRevokeApiKeyRequest,
+  UserRevokeApiKeyResponse,
+  UserRevokeSessionRequest,
+  UserRevokeSessionResponse,
+  UserSaveExpertApplicationRequest,
+  UserSaveExpertApplicationResponse,
+  UserSetPrimaryLoginWalletRequest,
+  UserSetPrimaryLoginWalletResponse,
+  UserSubscribeDepositLedgerRequest,
+  UserSubscribeDepositLedgerResponse,
+  UserSubscribeNotificationsRequest,
+  UserSubscribeNotificationsResponse,
+  UserSubscribeStrategyGuardEventsRequest,
+  UserSubscribeStrategyGuardEventsResponse,
+  UserUnfollowExpertRequest,
+  UserUnfollowExpertResponse,
+  UserUnfollowStrategyRequest,
+  UserUnfollowStrategyResponse,
+  UserUnlinkLoginWalletRequest,
+  UserUnlinkLoginWalletResponse,
+  UserUnsubscribeDepositLedgerRequest,
+  UserUnsubscribeDepositLedgerResponse,
+  UserUnsubscribeNotificationsRequest,
+  UserUnsubscribeNotificationsResponse,
+  UserUnsubscribeStrategyGuardEventsRequest,
+  UserUnsubscribeStrategyGuardEventsResponse,
+  UserUnwhitelistWalletRequest,
+  UserUnwhitelistWalletResponse,
+  UserUpdateUserProfileRequest,
+  UserUpdateUserProfileResponse,
+  UserUploadExpertApplicationDocumentRequest,
+  UserUploadExpertApplicationDocumentResponse,
+  UserWhitelistWalletRequest,
+  UserWhitelistWalletResponse,
+} from "./model";
+
+export class UserClient {
+  constructor(readonly client: WsClient) {}
+
+  /** User follows a strategy */
+  userFollowStrategy(params: UserFollowStrategyRequest): Promise<UserFollowStrategyResponse> {
+    return this.client.request(Endpoint.UserFollowStrategy, params);
+  }
+
+  /** User lists followed strategies */
+  userListFollowedStrategies(params: UserListFollowedStrategiesRequest): Promise<UserListFollowedStrategiesResponse> {
+    return this.client.request(Endpoint.UserListFollowedStrategies, params);
+  }
+
+  userUnfollowStrategy(params: UserUnfollowStrategyRequest): Promise<UserUnfollowStrategyResponse> {
+    return this.client.request(Endpoint.UserUnfollowStrategy, params);
+  }
+
+  /** User lists strategies */
+  userListStrategies(params: UserListStrategiesRequest): Promise<UserListStrategiesResponse> {
+    return this.client.request(Endpoint.UserListStrategies, params);
+  }
+
+  /** User lists top performing strategies */
+  userListTopPerformingStrategies(params: UserListTopPerformingStrategiesRequest): Promise<UserListTopPerformingStrategiesResponse> {
+    return this.client.request(Endpoint.UserListTopPerformingStrategies, params);
+  }
+
+  userListStrategyBackers(params: UserListStrategyBackersRequest): Promise<UserListStrategyBackersResponse> {
+    return this.client.request(Endpoint.UserListStrategyBackers, params);
+  }
+
+  userListStrategyFollowers(params: UserListStrategyFollowersRequest): Promise<UserListStrategyFollowersResponse> {
+    return this.client.request(Endpoint.UserListStrategyFollowers, params);
+  }
+
+  /** User gets a strategy */
+  userGetStrategy(params: UserGetStrategyRequest): Promise<UserGetStrategyResponse> {
+    return this.client.request(Endpoint.UserGetStrategy, params);
+  }
+
+  userListStrategyPoolContractAssetLedger(params: UserListStrategyPoolContractAssetLedgerRequest): Promise<UserListStrategyPoolContractAssetLedgerResponse> {
+    return this.client.request(Endpoint.UserListStrategyPoolContractAssetLedger, params);
+  }
+
+  userListUserStrategyPoolContractAssetLedger(params: UserListUserStrategyPoolContractAssetLedgerRequest): Promise<UserListUserStrategyPoolContractAssetLedgerResponse> {
+    return this.client.request(Endpoint.UserListUserStrategyPoolContractAssetLedger, params);
+  }
+
+  /** User gets a strategy statistics */
+  userGetStrategyStatistics(params: UserGetStrategyStatisticsRequest): Promise<UserGetStrategyStatisticsResponse> {
+    return this.client.request(Endpoint.UserGetStrategyStatistics, params);
+  }
+
+  /** User gets statistics of all strategies related to the user */
+  userGetStrategiesStatistics(params: UserGetStrategiesStatisticsRequest): Promise<UserGetStrategiesStatisticsResponse> {
+    return this.client.request(Endpoint.UserGetStrategiesStatistics, params);
+  }
+
+  /** User update its expert profile */
+  userUpdateUserProfile(params: UserUpdateUserProfileRequest): Promise<UserUpdateUserProfileResponse> {
+    return this.client.request(Endpoint.UserUpdateUserProfile, params);
+  }
+
+  userBackStrategy(
+    params: UserBackStrategyRequest,
+    onData: StreamHandler<UserBackStrategyStreamResponse>,
+  ): Promise<Subscription<UserBackStrategyResponse>> {
+    return this.client.subscribe(Endpoint.UserBackStrategy, params, onData);
+  }
+
+  userExitStrategy(params: UserExitStrategyRequest): Promise<UserExitStrategyResponse> {
+    return this.client.request(Endpoint.UserExitStrategy, params);
+  }
+
+  userRequestRefund(params: UserRequestRefundRequest): Promise<UserRequestRefundResponse> {
+    return this.client.request(Endpoint.UserRequestRefund, params);
+  }
+
+  /** User withdraws escrow balance to a whitelisted wallet */
+  userRequestWithdraw(params: UserRequestWithdrawRequest): Promise<UserRequestWithdrawResponse> {
+    return this.client.request(Endpoint.UserRequestWithdraw, params);
+  }
+
+  userListBackedStrategies(params: UserListBackedStrategiesRequest): Promise<UserListBackedStrategiesResponse> {
+    return this.client.request(Endpoint.UserListBackedStrategies, params);
+  }
+
+  userListBackStrategyLedger(params: UserListBackStrategyLedgerRequest): Promise<UserListBackStrategyLedgerResponse> {
+    return this.client.request(Endpoint.UserListBackStrategyLedger, params);
+  }
+
+  expertListBackStrategyLedger(params: ExpertListBackStrategyLedgerRequest): Promise<ExpertListBackStrategyLedgerResponse> {
+    return this.client.request(Endpoint.ExpertListBackStrategyLedger, params);
+  }
+
+  userListExitStrategyLedger(params: UserListExitStrategyLedgerRequest): Promise<UserListExitStrategyLedgerResponse> {
+    return this.client.request(Endpoint.UserListExitStrategyLedger, params);
+  }
+
+  expertListExitStrategyLedger(params: ExpertListExitStrategyLedgerRequest): Promise<ExpertListExitStrategyLedgerResponse> {
+    return this.client.request(Endpoint.ExpertListExitStrategyLedger, params);
+  }
+
+  /** User follows an expert */
+  userFollowExpert(params: UserFollowExpertRequest): Promise<UserFollowExpertResponse> {
+    return this.client.request(Endpoint.UserFollowExpert, params);
+  }
+
+  /** User lists followed experts */
+  userListFollowedExperts(params: UserListFollowedExpertsRequest): Promise<UserListFollowedExpertsResponse> {
+    return this.client.request(Endpoint.UserListFollowedExperts, params);
+  }
+
+  /** User unfollows an expert */
+  userUnfollowExpert(params: UserUnfollowExpertRequest): Promise<UserUnfollowExpertResponse> {
+    return this.client.request(Endpoint.UserUnfollowExpert, params);
+  }
+
+  /** User lists experts */
+  userListExperts(params: UserListExpertsRequest): Promise<UserListExpertsResponse> {
+    return this.client.request(Endpoint.UserListExperts, params);
+  }
+
+  /** User lists experts */
+  userListTopPerformingExperts(params: UserListTopPerformingExpertsRequest): Promise<UserListTopPerformingExpertsResponse> {
+    return this.client.request(Endpoint.UserListTopPerformingExperts, params);
+  }
+
+  /** User lists experts */
+  userListFeaturedExperts(params: UserListFeaturedExpertsRequest): Promise<UserListFeaturedExpertsResponse> {
+    return this.client.request(Endpoint.UserListFeaturedExperts, params);
+  }
+
+  userListExpertListenedWalletTradeLedger(params: UserListExpertListenedWalletTradeLedgerRequest): Promise<UserListExpertListenedWalletTradeLedgerResponse> {
+    return this.client.request(Endpoint.UserListExpertListenedWalletTradeLedger, params);
+  }
+
+  /** User gets an expert profile */
+  userGetExpertProfile(params: UserGetExpertProfileRequest): Promise<UserGetExpertProfileResponse> {
+    return this.client.request(Endpoint.UserGetExpertProfile, params);
+  }
+
+  /** User gets an user profile */
+  userGetUserProfile(params: UserGetUserProfileRequest): Promise<UserGetUserProfileResponse> {
+    return this.client.request(Endpoint.UserGetUserProfile, params);
+  }
+
+  /** User registers a wallet */
+  userWhitelistWallet(params: UserWhitelistWalletRequest): Promise<UserWhitelistWalletResponse> {
+    return this.client.request(Endpoint.UserWhitelistWallet, params);
+  }
+
+  /** User lists wallets */
+  userListWhitelistedWallets(params: UserListWhitelistedWalletsRequest): Promise<UserListWhitelistedWalletsResponse> {
+    return this.client.request(Endpoint.UserListWhitelistedWallets, params);
+  }
+
+  /** User deregisters a wallet */
+  userUnwhitelistWallet(params: UserUnwhitelistWalletRequest): Promise<UserUnwhitelistWalletResponse> {
+    return this.client.request(Endpoint.UserUnwhitelistWallet, params);
+  }
+
+  /** User submits their expert application for review */
+  userApplyBecomeExpert(params: UserApplyBecomeExpertRequest): Promise<UserApplyBecomeExpertResponse> {
+    return this.client.request(Endpoint.UserApplyBecomeExpert, params);
+  }
+
+  /** User saves the questionnaire of their expert application as a draft */
+  userSaveExpertApplication(params: UserSaveExpertApplicationRequest): Promise<UserSaveExpertApplicationResponse> {
+    return this.client.request(Endpoint.UserSaveExpertApplication, params);
+  }
+
+  /** User attaches a base64 encoded document to their expert application */
+  userUploadExpertApplicationDocument(params: UserUploadExpertApplicationDocumentRequest): Promise<UserUploadExpertApplicationDocumentResponse> {
+    return this.client.request(Endpoint.UserUploadExpertApplicationDocument, params);
+  }
+
+  /** User removes a document from their expert application */
+  userDeleteExpertApplicationDocument(params: UserDeleteExpertApplicationDocumentRequest): Promise<UserDeleteExpertApplicationDocumentResponse> {
+    return this.client.request(Endpoint.UserDeleteExpertApplicationDocument, params);
+  }
+
+  /** User gets their expert application with its documents and review notes */
+  userGetExpertApplication(params: UserGetExpertApplicationRequest): Promise<UserGetExpertApplicationResponse> {
+    return this.client.request(Endpoint.UserGetExpertApplication, params);
+  }
+
+  /** User makes a strategy */
+  expertCreateStrategy(params: ExpertCreateStrategyRequest): Promise<ExpertCreateStrategyResponse> {
+    return this.client.request(Endpoint.ExpertCreateStrategy, params);
+  }
+
+  /** Expert updates a strategy, a rebalance interval or copy trade control of 0 disables it. A new expert fee is only applied after backers had time to exit */
+  expertUpdateStrategy(params: ExpertUpdateStrategyRequest): Promise<ExpertUpdateStrategyResponse> {
+    return this.client.request(Endpoint.ExpertUpdateStrategy, params);
+  }
+
+  /** Expert freezes a strategy, by making it immutable */
+  expertFreezeStrategy(params: ExpertFreezeStrategyRequest): Promise<ExpertFreezeStrategyResponse> {
+    return this.client.request(Endpoint.ExpertFreezeStrategy, params);
+  }
+
+  expertAddStrategyWatchingWallet(params: ExpertAddStrategyWatchingWalletRequest): Promise<ExpertAddStrategyWatchingWalletResponse> {
+    return this.client.request(Endpoint.ExpertAddStrategyWatchingWallet, params);
+  }
+
+  expertRemoveStrategyWatchingWallet(params: ExpertRemoveStrategyWatchingWalletRequest): Promise<ExpertRemoveStrategyWatchingWalletResponse> {
+    return this.client.request(Endpoint.ExpertRemoveStrategyWatchingWallet, params);
+  }
+
+  userListStrategyWatchingWallets(params: UserListStrategyWatchingWalletsRequest): Promise<UserListStrategyWatchingWalletsResponse> {
+    return this.client.request(Endpoint.UserListStrategyWatchingWallets, params);
+  }
+
+  userListWalletActivityLedger(params: UserListWalletActivityLedgerRequest): Promise<UserListWalletActivityLedgerResponse> {
+    return this.client.request(Endpoint.UserListWalletActivityLedger, params);
+  }
+
+  expertAddStrategyInitialTokenRatio(params: ExpertAddStrategyInitialTokenRatioRequest): Promise<ExpertAddStrategyInitialTokenRatioResponse> {
+    return this.client.request(Endpoint.ExpertAddStrategyInitialTokenRatio, params);
+  }
+
+  expertRemoveStrategyInitialTokenRatio(params: ExpertRemoveStrategyInitialTokenRatioRequest): Promise<ExpertRemoveStrategyInitialTokenRatioResponse> {
+    return this.client.request(Endpoint.ExpertRemoveStrategyInitialTokenRatio, params);
+  }
+
+  userListStrategyInitialTokenRatio(params: UserListStrategyInitialTokenRatioRequest): Promise<UserListStrategyInitialTokenRatioResponse> {
+    return this.client.request(Endpoint.UserListStrategyInitialTokenRatio, params);
+  }
+
+  /** Lists every change made to a strategy, newest first. Material changes are scheduled and only take effect at effective_at */
+  userListStrategyVersions(params: UserListStrategyVersionsRequest): Promise<UserListStrategyVersionsResponse> {
+    return this.client.request(Endpoint.UserListStrategyVersions, params);
+  }
+
+  expertListFollowers(params: ExpertListFollowersRequest): Promise<ExpertListFollowersResponse> {
+    return this.client.request(Endpoint.ExpertListFollowers, params);
+  }
+
+  expertListBackers(params: ExpertListBackersRequest): Promise<ExpertListBackersResponse> {
+    return this.client.request(Endpoint.ExpertListBackers, params);
+  }
+
+  expertListPublishedStrategies(params: ExpertListPublishedStrategiesRequest): Promise<ExpertListPublishedStrategiesResponse> {
+    return this.client.request(Endpoint.ExpertListPublishedStrategies, params);
+  }
+
+  expertListUnpublishedStrategies(params: ExpertListUnpublishedStrategiesRequest): Promise<ExpertListUnpublishedStrategiesResponse> {
+    return this.client.request(Endpoint.ExpertListUnpublishedStrategies, params);
+  }
+
+  userGetDepositTokens(params: UserGetDepositTokensRequest): Promise<UserGetDepositTokensResponse> {
+    return this.client.request(Endpoint.UserGetDepositTokens, params);
+  }
+
+  userGetDepositAddresses(params: UserGetDepositAddressesRequest): Promise<UserGetDepositAddressesResponse> {
+    return this.client.request(Endpoint.UserGetDepositAddresses, params);
+  }
+
+  userListDepositWithdrawLedger(params: UserListDepositWithdrawLedgerRequest): Promise<UserListDepositWithdrawLedgerResponse> {
+    return this.client.request(Endpoint.UserListDepositWithdrawLedger, params);
+  }
+
+  userSubscribeDepositLedger(
+    params: UserSubscribeDepositLedgerRequest,
+    onData: StreamHandler<UserListDepositLedgerRow>,
+  ): Promise<Subscription<UserSubscribeDepositLedgerResponse>> {
+    return this.client.subscribe(Endpoint.UserSubscribeDepositLedger, params, onData);
+  }
+
+  userUnsubscribeDepositLedger(params: UserUnsubscribeDepositLedgerRequest): Promise<UserUnsubscribeDepositLedgerResponse> {
+    return this.client.request(Endpoint.UserUnsubscribeDepositLedger, params);
+  }
+
+  userListStrategyWallets(params: UserListStrategyWalletsRequest): Promise<UserListStrategyWalletsResponse> {
+    return this.client.request(Endpoint.UserListStrategyWallets, params);
+  }
+
+  userCreateStrategyWallet(params: UserCreateStrategyWalletRequest): Promise<UserCreateStrategyWalletResponse> {
+    return this.client.request(Endpoint.UserCreateStrategyWallet, params);
+  }
+
+  userListStrategyAuditRules(params: UserListStrategyAuditRulesRequest): Promise<UserListStrategyAuditRulesResponse> {
+    return this.client.request(Endpoint.UserListStrategyAuditRules, params);
+  }
+
+  userAddStrategyAuditRule(params: UserAddStrategyAuditRuleRequest): Promise<UserAddStrategyAuditRuleResponse> {
+    return this.client.request(Endpoint.UserAddStrategyAuditRule, params);
+  }
+
+  userRemoveStrategyAuditRule(params: UserRemoveStrategyAuditRuleRequest): Promise<UserRemoveStrategyAuditRuleResponse> {
+    return this.client.request(Endpoint.UserRemoveStrategyAuditRule, params);
+  }
+
+  userGetEscrowAddressForStrategy(params: UserGetEscrowAddressForStrategyRequest): Promise<UserGetEscrowAddressForStrategyResponse> {
+    return this.client.request(Endpoint.UserGetEscrowAddressForStrategy, params);
+  }
+
+  userListDepositWithdrawBalances(params: UserListDepositWithdrawBalancesRequest): Promise<UserListDepositWithdrawBalancesResponse> {
+    return this.client.request(Endpoint.UserListDepositWithdrawBalances, params);
+  }
+
+  userGetDepositWithdrawBalance(params: UserGetDepositWithdrawBalanceRequest): Promise<UserGetDepositWithdrawBalanceResponse> {
+    return this.client.request(Endpoint.UserGetDepositWithdrawBalance, params);
+  }
+
+  userListEscrowTokenContractAddresses(params: UserListEscrowTokenContractAddressesRequest): Promise<UserListEscrowTokenContractAddressesResponse> {
+    return this.client.request(Endpoint.UserListEscrowTokenContractAddresses, params);
+  }
+
+  userListStrategyTokenBalance(params: UserListStrategyTokenBalanceRequest): Promise<UserListStrategyTokenBalanceResponse> {
+    return this.client.request(Endpoint.UserListStrategyTokenBalance, params);
+  }
+
+  userGetBackStrategyReviewDetail(params: UserGetBackStrategyReviewDetailRequest): Promise<UserGetBackStrategyReviewDetailResponse> {
+    return this.client.request(Endpoint.UserGetBackStrategyReviewDetail, params);
+  }
+
+  userListUserBackStrategyAttempt(params: UserListUserBackStrategyAttemptRequest): Promise<UserListUserBackStrategyAttemptResponse> {
+    return this.client.request(Endpoint.UserListUserBackStrategyAttempt, params);
+  }
+
+  userListUserBackStrategyLog(params: UserListUserBackStrategyLogRequest): Promise<UserListUserBackStrategyLogResponse> {
+    return this.client.request(Endpoint.UserListUserBackStrategyLog, params);
+  }
+
+  /** User get system config */
+  userGetSystemConfig(params: UserGetSystemConfigRequest): Promise<UserGetSystemConfigResponse> {
+    return this.client.request(Endpoint.UserGetSystemConfig, params);
+  }
+
+  userListUserStrategyBalance(params: UserListUserStrategyBalanceRequest): Promise<UserListUserStrategyBalanceResponse> {
+    return this.client.request(Endpoint.UserListUserStrategyBalance, params);
+  }
+
+  /** User exports deposits, withdrawals, refunds, backs, exits and pool asset movements in a time range, as rows or as CSV. report_total counts every row in the range, a page holds at most limit of them. A CSV export holds the whole range up to 100000 rows, truncated tells when rows were left out. Back fees are the ones charged when the strategy was backed */
+  userExportActivityReport(params: UserExportActivityReportRequest): Promise<UserExportActivityReportResponse> {
+    return this.client.request(Endpoint.UserExportActivityReport, params);
+  }
+
+  /** Expert adds a max drawdown, asset stop-loss or take-profit guard that pauses copy trading when triggered */
+  expertAddStrategyGuardRule(params: ExpertAddStrategyGuardRuleRequest): Promise<ExpertAddStrategyGuardRuleResponse> {
+    return this.client.request(Endpoint.ExpertAddStrategyGuardRule, params);
+  }
+
+  expertRemoveStrategyGuardRule(params: ExpertRemoveStrategyGuardRuleRequest): Promise<ExpertRemoveStrategyGuardRuleResponse> {
+    return this.client.request(Endpoint.ExpertRemoveStrategyGuardRule, params);
+  }
+
+  userListStrategyGuardRules(params: UserListStrategyGuardRulesRequest): Promise<UserListStrategyGuardRulesResponse> {
+    return this.client.request(Endpoint.UserListStrategyGuardRules, params);
+  }
+
+  /** Expert resumes copy trading paused by a guard and re-arms triggered guards */
+  expertResumeStrategyCopyTrading(params: ExpertResumeStrategyCopyTradingRequest): Promise<ExpertResumeStrategyCopyTradingResponse> {
+    return this.client.request(Endpoint.ExpertResumeStrategyCopyTrading, params);
+  }
+
+  userSubscribeStrategyGuardEvents(
+    params: UserSubscribeStrategyGuardEventsRequest,
+    onData: StreamHandler<StrategyGuardEvent>,
+  ): Promise<Subscription<UserSubscribeStrategyGuardEventsResponse>> {
+    return this.client.subscribe(Endpoint.UserSubscribeStrategyGuardEvents, params, onData);
+  }
+
+  userUnsubscribeStrategyGuardEvents(params: UserUnsubscribeStrategyGuardEventsRequest): Promise<UserUnsubscribeStrategyGuardEventsResponse> {
+    return this.client.request(Endpoint.UserUnsubscribeStrategyGuardEvents, params);
+  }
+
+  /** User lists notifications about strategy trades, backs, exits, refunds and followed experts, newest first */
+  userListNotifications(params: UserListNotificationsRequest): Promise<UserListNotificationsResponse> {
+    return this.client.request(Endpoint.UserListNotifications, params);
+  }
+
+  /** User marks the given notifications as read, or all of them if none are given */
+  userMarkNotificationsRead(params: UserMarkNotificationsReadRequest): Promise<UserMarkNotificationsReadResponse> {
+    return this.client.request(Endpoint.UserMarkNotificationsRead, params);
+  }
+
+  /** User receives new notifications as they are created */
+  userSubscribeNotifications(
+    params: UserSubscribeNotificationsRequest,
+    onData: StreamHandler<UserNotification>,
+  ): Promise<Subscription<UserSubscribeNotificationsResponse>> {
+    return this.client.subscribe(Endpoint.UserSubscribeNotifications, params, onData);
+  }
+
+  userUnsubscribeNotifications(params: UserUnsubscribeNotificationsRequest): Promise<UserUnsubscribeNotificationsResponse> {
+    return this.client.request(Endpoint.UserUnsubscribeNotifications, params);
+  }
+
+  /** User lists the devices it is logged in on, most recently seen first */
+  userListSessions(params: UserListSessionsRequest): Promise<UserListSessionsResponse> {
+    return this.client.request(Endpoint.UserListSessions, params);
+  }
+
+  /** User logs out a device, its tokens stop working and its connections are closed */
+  userRevokeSession(params: UserRevokeSessionRequest): Promise<UserRevokeSessionResponse> {
+    return this.client.request(Endpoint.UserRevokeSession, params);
+  }
+
+  /** User creates an API key for programmatic access, the secret is only returned here */
+  userCreateApiKey(params: UserCreateApiKeyRequest): Promise<UserCreateApiKeyResponse> {
+    return this.client.request(Endpoint.UserCreateApiKey, params);
+  }
+
+  /** User lists its API keys that are not revoked */
+  userListApiKeys(params: UserListApiKeysRequest): Promise<UserListApiKeysResponse> {
+    return this.client.request(Endpoint.UserListApiKeys, params);
+  }
+
+  /** User revokes an API key and closes the connections authorized with it */
+  userRevokeApiKey(params: UserRevokeApiKeyRequest): Promise<UserRevokeApiKeyResponse> {
+    return this.client.request(Endpoint.UserRevokeApiKey, params);
+  }
+
+  /** User lists the wallets it can log in with, the primary one first */
+  userListLoginWallets(params: UserListLoginWalletsRequest): Promise<UserListLoginWalletsResponse> {
+    return this.client.request(Endpoint.UserListLoginWallets, params);
+  }
+
+  /** User links another login wallet, proven by a SIWE message signed with a GetLoginNonce nonce */
+  userLinkLoginWallet(params: UserLinkLoginWalletRequest): Promise<UserLinkLoginWalletResponse> {
+    return this.client.request(Endpoint.UserLinkLoginWallet, params);
+  }
+
+  /** User unlinks a login wallet that is not the primary one */
+  userUnlinkLoginWallet(params: UserUnlinkLoginWalletRequest): Promise<UserUnlinkLoginWalletResponse> {
+    return this.client.request(Endpoint.UserUnlinkLoginWallet, params);
+  }
+
+  /** User makes a linked login wallet the address of the account */
+  userSetPrimaryLoginWallet(params: UserSetPrimaryLoginWalletRequest): Promise<UserSetPrimaryLoginWalletResponse> {
+    return this.client.request(Endpoint.UserSetPrimaryLoginWallet, params);
+  }
+}
@@ -0,0 +1,372 @@
+// generated by codegen from the endpoint schemas, do not edit
+// Websocket framing shared by the generated service clients, mirrors lib/ws/basics.rs and lib/ws/client.rs
+
+export interface WsRequest<Params> {
+  method: number;
+  seq: number;
+  params: Params;
+}
+
+export interface WsSuccessResponse<Params> {
+  type: "Immediate";
+  method: number;
+  seq: number;
+  params: Params;
+}
+
+export interface WsStreamResponse<Data> {
+  type: "Stream";
+  original_seq: number;
+  method: number;
+  stream_seq: number;
+  stream_code: number;
+  data: Data;
+}
+
+export interface WsErrorResponse {
+  type: "Error";
+  method: number;
+  code: number;
+  seq: number;
+  log_id: string;
+  params: unknown;
+}
+
+export type WsLogLevel = "off" | "trace" | "debug" | "info" | "warn" | "error";
+
+export interface WsLogResponse {
+  type: "Log";
+  seq: number;
+  log_id: number;
+  level: WsLogLevel;
+  message: string;
+}
+
+export interface WsForwardedResponse {
+  type: "Forwarded";
+  method: number;
+  seq: number;
+}
+
+/** sent after the handshake response by services that keep sessions, see `WsClient.resume` */
+export interface WsSessionResponse {
+  type: "Session";
+  session_token: string;
+  resumed: boolean;
+  /** how many missed stream messages were dispatched before this response */
+  replayed: number;
+}
+
+export interface WsCloseResponse {
+  type: "Close";
+}
+
+export type WsResponse =
+  | WsSuccessResponse<unknown>
+  | WsStreamResponse<unknown>
+  | WsErrorResponse
+  | WsLogResponse
+  | WsForwardedResponse
+  | WsSessionResponse
+  | WsCloseResponse;
+
+/** an `Error` response of the server, `code` is one of `EnumErrorCode` */
+export class WsError extends Error {
+  constructor(
+    readonly code: number,
+    readonly method: number,
+    readonly seq: number,
+    readonly logId: string,
+    readonly params: unknown,
+  ) {
+    super(`error ${code} of method ${method}: ${JSON.stringify(params)}`);
+  }
+}
+
+export type StreamHandler<Data> = (data: Data, response: WsStreamResponse<Data>) => void;
+
+export interface WsClientOptions {
+  /** defaults to the global `WebSocket`, pass e.g. the `ws` package on older node versions */
+  WebSocket?: WebSocketConstructor;
+  /** rejects a request that got no response in time, 0 disables the timeout */
+  timeoutMs?: number;
+  onLog?: (log: WsLogResponse) => void;
+  onClose?: () => void;
+}
+
+export interface WebSocketLike {
+  readonly readyState: number;
+  onopen: ((ev: unknown) => void) | null;
+  onmessage: ((ev: { data: unknown }) => void) | null;
+  onerror: ((ev: unknown) => void) | null;
+  onclose: ((ev: unknown) => void) | null;
+  send(data: string): void;
+  close(): void;
+}
+
+/** loosely typed so that both the DOM `WebSocket` and the `ws` package are accepted */
+export type WebSocketConstructor = new (url: string, protocols?: string | string[]) => object;
+
+/** the response of the method sent in the handshake header together with the open connection */
+export interface WsConnected<Resp> {
+  client: WsClient;
+  response: Resp;
+}
+
+/** a stream subscription, `close` stops dispatching to the handler but does not unsubscribe on the server */
+export interface Subscription<Resp> {
+  seq: number;
+  response: Resp;
+  close(): void;
+}
+
+interface Pending {
+  resolve(params: unknown): void;
+  reject(err: Error): void;
+  timer?: ReturnType<typeof setTimeout>;
+}
+
+const DEFAULT_TIMEOUT_MS = 30_000;
+/** the handshake response always has seq 0, requests start at 1 */
+const HANDSHAKE_SEQ = 0;
+
+/** percent encodes everything that is not allowed in a websocket subprotocol token */
+function encodeHeaderValue(value: string): string {
+  return encodeURIComponent(value).replace(
+    /[!'()*]/g,
+    (c) => `%${c.charCodeAt(0).toString(16).toUpperCase()}`,
+  );
+}
+
+/**
+ * encodes the `Sec-WebSocket-Protocol` header of a handshake method, the same as `lib::utils::encode_header`:
+ * `0<method name>, 1<first param>, 2<second param>, ...` with absent optional params left out
+ */
+export function encodeHeader(
+  methodName: string,
+  parameters: readonly string[],
+  params: object,
+): string {
+  const values = params as Record<string, unknown>;
+  let header = `0${methodName.toLowerCase()}`;
+  parameters.forEach((name, i) => {
+    const value = values[name];
+    if (value === undefined || value === null) {
+      return;
+    }
+    header += `, ${i + 1}${encodeHeaderValue(String(value).replace(/"/g, ""))}`;
+  });
+  return header;
+}
+
+/** the resume handshake, acknowledging the last stream_seq received of every stream code */
+export function encodeResumeHeader(
+  sessionToken: string,
+  lastStreamSeqs: Map<number, number>,
+): string {
+  let header = `0resume, 1${encodeHeaderValue(sessionToken)}`;
+  const acks = [...lastStreamSeqs].map(([code, seq]) => `${code}:${seq}`).join(";");
+  if (acks.length > 0) {
+    header += `, 2${encodeHeaderValue(acks)}`;
+  }
+  return header;
+}
+
+export class WsClient {
+  private seq = HANDSHAKE_SEQ;
+  private readonly pending = new Map<number, Pending>();
+  private readonly streams = new Map<number, StreamHandler<unknown>>();
+  private readonly lastStreamSeqs = new Map<number, number>();
+  private session?: WsSessionResponse;
+  private socket: WebSocketLike;
+  private closed = false;
+
+  private constructor(
+    private readonly url: string,
+    private readonly WebSocketImpl: WebSocketConstructor,
+    header: string,
+    private readonly options: WsClientOptions,
+  ) {
+    this.socket = this.open(header);
+  }
+
+  /**
+   * opens a connection that is authenticated by the handshake `header`, see `encodeHeader`,
+   * and waits for the response of the handshake method
+   */
+  static connect<Resp>(
+    url: string,
+    header: string,
+    options: WsClientOptions = {},
+  ): Promise<WsConnected<Resp>> {
+    const WebSocketImpl =
+      options.WebSocket ??
+      (globalThis as unknown as { WebSocket?: WebSocketConstructor }).WebSocket;
+    if (!WebSocketImpl) {
+      return Promise.reject(new Error("no WebSocket implementation available"));
+    }
+    const client = new WsClient(url, WebSocketImpl, header, options);
+    return new Promise<WsConnected<Resp>>((resolve, reject) => {
+      client.socket.onerror = () => reject(new Error(`failed to connect to ${url}`));
+      client.wait<Resp>(HANDSHAKE_SEQ).then(
+        (response) => resolve({ client, response }),
+        (err: Error) => {
+          client.close();
+          reject(err);
+        },
+      );
+    });
+  }
+
+  /** sends a request and resolves with the `Immediate` response of the same seq */
+  request<Req, Resp>(method: number, params: Req): Promise<Resp> {
+    const seq = this.send(method, params);
+    return this.wait<Resp>(seq);
+  }
+
+  /** sends a subscribe request and dispatches the `Stream` responses of its seq to `onData` */
+  async subscribe<Req, Resp, Data>(
+    method: number,
+    params: Req,
+    onData: StreamHandler<Data>,
+  ): Promise<Subscription<Resp>> {
+    const seq = this.send(method, params);
+    this.streams.set(seq, onData as StreamHandler<unknown>);
+    try {
+      const response = await this.wait<Resp>(seq);
+      return {
+        seq,
+        response,
+        close: () => {
+          this.streams.delete(seq);
+        },
+      };
+    } catch (err) {
+      this.streams.delete(seq);
+      throw err;
+    }
+  }
+
+  /**
+   * reconnects after the connection dropped, the subscriptions continue on the server session and
+   * the stream messages missed in between are dispatched before live ones
+   */
+  resume(): Promise<WsSessionResponse> {
+    if (!this.session) {
+      return Promise.reject(new Error("the service did not open a session"));
+    }
+    if (!this.closed) {
+      return Promise.reject(new Error("connection still open"));
+    }
+    this.socket = this.open(encodeResumeHeader(this.session.session_token, this.lastStreamSeqs));
+    this.closed = false;
+    return new Promise<WsSessionResponse>((resolve, reject) => {
+      this.socket.onerror = () => reject(new Error(`failed to connect to ${this.url}`));
+      this.wait<WsSessionResponse>(HANDSHAKE_SEQ).then(resolve, (err: Error) => {
+        this.close();
+        reject(err);
+      });
+    });
+  }
+
+  close(): void {
+    if (!this.closed) {
+      this.socket.close();
+      this.onClose();
+    }
+    this.session = undefined;
+    this.streams.clear();
+  }
+
+  private open(header: string): WebSocketLike {
+    // browsers only accept the header as a list of subprotocols, which they join with commas again
+    const protocols = header
+      .split(",")
+      .map((x) => x.trim())
+      .filter((x) => x.length > 0);
+    const socket = new this.WebSocketImpl(this.url, protocols) as WebSocketLike;
+    socket.onmessage = (ev) => this.onMessage(ev.data);
+    socket.onclose = () => this.onClose();
+    return socket;
+  }
+
+  private send<Req>(method: number, params: Req): number {
+    if (this.closed) {
+      throw new Error("connection closed");
+    }
+    this.seq += 1;
+    const req: WsRequest<Req> = { method, seq: this.seq, params };
+    this.socket.send(JSON.stringify(req));
+    return this.seq;
+  }
+
+  private wait<Resp>(seq: number): Promise<Resp> {
+    return new Promise<Resp>((resolve, reject) => {
+      const pending: Pending = { resolve: resolve as (params: unknown) => void, reject };
+      const timeoutMs = this.options.timeoutMs ?? DEFAULT_TIMEOUT_MS;
+      if (timeoutMs > 0) {
+        pending.timer = setTimeout(() => {
+          this.pending.delete(seq);
+          reject(new Error(`request ${seq} timed out after ${timeoutMs}ms`));
+        }, timeoutMs);
+      }
+      this.pending.set(seq, pending);
+    });
+  }
+
+  private settle(seq: number, settle: (pending: Pending) => void): void {
+    const pending = this.pending.get(seq);
+    if (pending) {
+      this.pending.delete(seq);
+      clearTimeout(pending.timer);
+      settle(pending);
+    }
+  }
+
+  private onMessage(data: unknown): void {
+    const resp = JSON.parse(String(data)) as WsResponse;
+    switch (resp.type) {
+      case "Immediate":
+        this.settle(resp.seq, (x) => x.resolve(resp.params));
+        break;
+      case "Stream":
+        this.lastStreamSeqs.set(resp.stream_code, resp.stream_seq);
+        this.streams.get(resp.original_seq)?.(resp.data, resp);
+        break;
+      case "Error":
+        this.settle(resp.seq, (x) =>
+          x.reject(new WsError(resp.code, resp.method, resp.seq, resp.log_id, resp.params)),
+        );
+        break;
+      case "Log":
+        this.options.onLog?.(resp);
+        break;
+      case "Forwarded":
+        // the request was forwarded to another service, its response arrives later with the same seq
+        break;
+      case "Session":
+        // answers the handshake of `resume`, follows the handshake response otherwise
+        this.session = resp;
+        this.settle(HANDSHAKE_SEQ, (x) => x.resolve(resp));
+        break;
+      case "Close":
+        this.close();
+        break;
+    }
+  }
+
+  private onClose(): void {
+    if (this.closed) {
+      return;
+    }
+    this.closed = true;
+    for (const seq of [...this.pending.keys()]) {
+      this.settle(seq, (x) => x.reject(new Error("connection closed")));
+    }
+    if (!this.session) {
+      // kept for `resume` otherwise
+      this.streams.clear();
+    }
+    this.options.onClose?.();
+  }
+}
@@ -34,14 +34,14 @@ pub fn gen_md_docs(root: &str) -> eyre::Result<()> {
 # {} Server
 ID: {}
 ## Endpoints
-|Method Code|Method Name|Parameters|Response|Description|
-|-----------|-----------|----------|--------|-----------|"#,
+|Method Code|Method Name|Parameters|Response|Roles|Description|
+|-----------|-----------|----------|--------|-----|-----------|"#,
             s.name, s.id
         )?;
         for e in s.endpoints {
             writeln!(
                 &mut docs_file,
-                "|{}|{}|{}|{}|{}|",
+                "|{}|{}|{}|{}|{}|{}|",
                 e.code,
                 e.name,
                 e.parameters
@@ -49,6 +49,7 @@ ID: {}
                     .map(|x| format!("{}", x.name))
                     .join(", "),
                 e.returns.iter().map(|x| format!("{}", x.name)).join(", "),
+                e.roles.iter().map(|x| x.name.as_str()).join(", "),
                 e.description
             )?;
         }
@@ -56,6 +57,49 @@ ID: {}
     Ok(())
 }
 
+/// every endpoint must list the roles allowed to call it, as variants of the `role` enum since
+/// they are checked by value
+pub fn check_endpoint_roles() -> eyre::Result<()> {
+    let roles = enums::get_enums()
+        .into_iter()
+        .find_map(|x| match x {
+            Type::Enum { name, variants } if name == "role" => Some(variants),
+            _ => None,
+        })
+        .ok_or_else(|| eyre::eyre!("role enum not found"))?;
+    let scopes = enums::get_enums()
+        .into_iter()
+        .find_map(|x| match x {
+            Type::Enum { name, variants } if name == "api_key_scope" => Some(variants),
+            _ => None,
+        })
+        .ok_or_else(|| eyre::eyre!("api_key_scope enum not found"))?;
+    for e in services::get_services().iter().flat_map(|x| &x.endpoints) {
+        if let Some(scope) = &e.api_key_scope {
+            eyre::ensure!(
+                scopes.contains(scope),
+                "{} has api key scope {:?} which is not in the api_key_scope enum",
+                e.name,
+                scope
+            );
+        }
+        eyre::ensure!(
+            !e.roles.is_empty(),
+            "{} has no roles, nobody would be allowed to call it",
+            e.name
+        );
+        for role in &e.roles {
+            eyre::ensure!(
+                roles.contains(role),
+                "{} has role {:?} which is not in the role enum",
+                e.name,
+                role
+            );
+        }
+    }
+    Ok(())
+}
+
 pub fn gen_systemd_services(root: &str, app_name: &str, user: &str) -> eyre::Result<()> {
     create_dir_all(format!("{}/etc/systemd", root))?;
     let services = services::get_services();
@@ -1,7 +1,9 @@
 pub mod docs;
+pub mod openapi;
 pub mod rust;
 pub mod service;
 pub mod sql;
+pub mod typescript;
 use eyre::*;
 use std::env;
 use std::fs::create_dir_all;
@@ -23,13 +25,17 @@ pub fn main() -> Result<()> {
     let root = root.to_str().unwrap();
     let dir = format!("{}/src/gen", root);
     create_dir_all(&dir)?;
+    docs::check_endpoint_roles()?;
     docs::gen_services_docs(root)?;
     docs::gen_md_docs(root)?;
+    openapi::gen_openapi_json(root)?;
+    openapi::gen_asyncapi_json(root)?;
     rust::gen_model_rs(root, &dir)?;
     sql::gen_model_sql(root)?;
     sql::gen_db_sql(root)?;
     rust::gen_db_rs(&dir)?;
     docs::gen_systemd_services(root, "mc2fi", "mc2fi")?;
     docs::gen_error_message_md(root)?;
+    typescript::gen_typescript_sdk(root)?;
     Ok(())
 }
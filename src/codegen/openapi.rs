@@ -0,0 +1,372 @@
+use crate::rust::{collect_rust_recursive_types, enum_variant_name};
+use crate::{docs, enums, services};
+use convert_case::{Case, Casing};
+use model::endpoint::EndpointSchema;
+use model::service::Service;
+use model::types::*;
+use serde_json::{json, Map, Value};
+use std::collections::BTreeSet;
+use std::fs::File;
+
+/// the auth service only handles the method encoded in the websocket handshake header
+const HANDSHAKE_SERVICE: &str = "auth";
+
+pub trait ToJsonSchema {
+    fn to_json_schema_ref(&self) -> Value;
+    fn to_json_schema_decl(&self) -> Value;
+}
+
+fn component_ref(name: &str) -> Value {
+    json!({ "$ref": format!("#/components/schemas/{}", name) })
+}
+
+impl ToJsonSchema for Type {
+    fn to_json_schema_ref(&self) -> Value {
+        match self {
+            Type::Date | Type::Int => json!({"type": "integer", "format": "int32"}),
+            Type::BigInt => json!({"type": "integer", "format": "int64"}),
+            Type::Numeric => json!({"type": "number"}),
+            Type::Boolean => json!({"type": "boolean"}),
+            Type::String => json!({"type": "string"}),
+            Type::Bytea => {
+                json!({"type": "array", "items": {"type": "integer", "format": "uint8"}})
+            }
+            Type::UUID => json!({"type": "string", "format": "uuid"}),
+            Type::Inet => json!({"type": "string", "format": "ip"}),
+            Type::Struct { name, .. } => component_ref(name),
+            Type::StructRef(name) => component_ref(name),
+            Type::Object => json!({}),
+            Type::DataTable { name, .. } => json!({"type": "array", "items": component_ref(name)}),
+            Type::Vec(ele) => json!({"type": "array", "items": ele.to_json_schema_ref()}),
+            Type::Unit => json!({"type": "null"}),
+            Type::Optional(t) => json!({"anyOf": [t.to_json_schema_ref(), {"type": "null"}]}),
+            Type::Enum { name, .. } => {
+                component_ref(&format!("Enum{}", name.to_case(Case::Pascal)))
+            }
+            Type::EnumRef(name) => component_ref(&format!("Enum{}", name.to_case(Case::Pascal))),
+            Type::BlockchainDecimal => json!({"type": "string", "format": "decimal"}),
+            Type::BlockchainAddress => {
+                json!({"type": "string", "pattern": "^0x[0-9a-fA-F]{40}$"})
+            }
+            Type::BlockchainTransactionHash => {
+                json!({"type": "string", "pattern": "^0x[0-9a-fA-F]{64}$"})
+            }
+        }
+    }
+
+    fn to_json_schema_decl(&self) -> Value {
+        match self {
+            Type::Struct { fields, .. } => {
+                let mut properties = Map::new();
+                let mut required = vec![];
+                for field in fields {
+                    let name = field.name.to_case(Case::Camel);
+                    if !matches!(field.ty, Type::Optional(_)) {
+                        required.push(Value::String(name.clone()));
+                    }
+                    properties.insert(name, field.ty.to_json_schema_ref());
+                }
+                json!({"type": "object", "properties": properties, "required": required})
+            }
+            Type::Enum { variants, .. } => json!({
+                "type": "string",
+                "enum": variants.iter().map(|x| enum_variant_name(&x.name)).collect::<Vec<_>>(),
+            }),
+            x => x.to_json_schema_ref(),
+        }
+    }
+}
+
+fn type_name(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Struct { name, .. } => Some(name.clone()),
+        Type::Enum { name, .. } => Some(format!("Enum{}", name.to_case(Case::Pascal))),
+        _ => None,
+    }
+}
+
+/// every struct and enum referenced by the endpoints, keyed by the name used in `$ref`
+fn gen_component_schemas(root: &str, services: &[Service]) -> eyre::Result<Map<String, Value>> {
+    let mut types = BTreeSet::new();
+    for e in services.iter().flat_map(|x| &x.endpoints) {
+        types.extend(collect_rust_recursive_types(Type::struct_(
+            format!("{}Request", e.name),
+            e.parameters.clone(),
+        )));
+        types.extend(collect_rust_recursive_types(Type::struct_(
+            format!("{}Response", e.name),
+            e.returns.clone(),
+        )));
+        types.extend(
+            e.stream_response
+                .iter()
+                .cloned()
+                .flat_map(collect_rust_recursive_types),
+        );
+    }
+    let mut schemas = Map::new();
+    for ty in enums::get_enums().into_iter().chain(types) {
+        if let Some(name) = type_name(&ty) {
+            schemas.insert(name, ty.to_json_schema_decl());
+        }
+    }
+
+    let errors = docs::get_error_messages(root)?;
+    schemas.insert(
+        "ErrorCode".to_owned(),
+        json!({
+            "type": "integer",
+            "enum": errors.codes.iter().map(|x| x.code).collect::<Vec<_>>(),
+            "description": errors
+                .codes
+                .iter()
+                .map(|x| format!("{}: {} {}", x.code, x.symbol, x.message))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        }),
+    );
+    schemas.insert(
+        "ErrorResponse".to_owned(),
+        json!({
+            "type": "object",
+            "properties": {
+                "method": {"type": "integer"},
+                "code": component_ref("ErrorCode"),
+                "seq": {"type": "integer"},
+                "log_id": {"type": "string"},
+                "params": {},
+            },
+            "required": ["method", "code", "seq", "log_id", "params"],
+        }),
+    );
+    Ok(schemas)
+}
+
+fn gen_openapi_operation(service: &Service, e: &EndpointSchema) -> Value {
+    let success = match &e.stream_response {
+        Some(stream) => json!({
+            "description": "an `immediate` event with the response, then a `stream` event for every update",
+            "content": {
+                "text/event-stream": {
+                    "schema": {"type": "string"},
+                    "x-immediate": component_ref(&format!("{}Response", e.name)),
+                    "x-stream": stream.to_json_schema_ref(),
+                },
+            },
+        }),
+        None => json!({
+            "description": "OK",
+            "content": {
+                "application/json": {"schema": component_ref(&format!("{}Response", e.name))},
+            },
+        }),
+    };
+    let mut operation = json!({
+        "operationId": e.name,
+        "tags": [service.name],
+        "summary": e.description,
+        "x-method-code": e.code,
+        "requestBody": {
+            "required": true,
+            "content": {
+                "application/json": {"schema": component_ref(&format!("{}Request", e.name))},
+            },
+        },
+        "responses": {
+            "200": success,
+            "default": {
+                "description": "the error response, the status is derived from the error code",
+                "content": {"application/json": {"schema": component_ref("ErrorResponse")}},
+            },
+        },
+    });
+    if service.name == HANDSHAKE_SERVICE {
+        operation["security"] = json!([]);
+    }
+    if !e.roles.is_empty() {
+        operation["x-roles"] = json!(e
+            .roles
+            .iter()
+            .map(|x| enum_variant_name(&x.name))
+            .collect::<Vec<_>>());
+    }
+    operation
+}
+
+pub fn gen_openapi_json(root: &str) -> eyre::Result<()> {
+    let services = services::get_services();
+    let mut paths = Map::new();
+    for s in &services {
+        for e in &s.endpoints {
+            paths.insert(
+                format!("/{}", e.name),
+                json!({ "post": gen_openapi_operation(s, e) }),
+            );
+        }
+    }
+    let doc = json!({
+        "openapi": "3.1.0",
+        "info": {
+            "title": "mc2fi",
+            "version": env!("CARGO_PKG_VERSION"),
+            "description": "Every websocket endpoint is also a JSON POST to `/<endpoint name>`. \
+                Endpoints with a stream response reply with server-sent events.",
+        },
+        "paths": paths,
+        "security": [{"handshake": []}],
+        "components": {
+            "schemas": gen_component_schemas(root, &services)?,
+            "securitySchemes": {
+                "handshake": {
+                    "type": "apiKey",
+                    "in": "header",
+                    "name": "Sec-WebSocket-Protocol",
+                    "description": "the same handshake header as the websocket, e.g. `0authorize, 1<address>, 2<token>, 3User, 4<device id>, 5<device os>`",
+                },
+            },
+        },
+    });
+    let filename = format!("{}/docs/openapi.json", root);
+    serde_json::to_writer_pretty(&mut File::create(filename)?, &doc)?;
+    Ok(())
+}
+
+fn asyncapi_message(e: &EndpointSchema, kind: &str, payload: Value) -> Value {
+    json!({
+        "name": format!("{}{}", e.name, kind),
+        "summary": e.description,
+        "x-endpoint": e.name,
+        "payload": payload,
+    })
+}
+
+pub fn gen_asyncapi_json(root: &str) -> eyre::Result<()> {
+    let services = services::get_services();
+    let mut requests = vec![];
+    let mut responses = vec![];
+    for s in services.iter().filter(|x| x.name != HANDSHAKE_SERVICE) {
+        for e in &s.endpoints {
+            requests.push(asyncapi_message(
+                e,
+                "Request",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "method": {"const": e.code},
+                        "seq": {"type": "integer"},
+                        "params": component_ref(&format!("{}Request", e.name)),
+                    },
+                    "required": ["method", "seq", "params"],
+                }),
+            ));
+            responses.push(asyncapi_message(
+                e,
+                "Response",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "type": {"const": "Immediate"},
+                        "method": {"const": e.code},
+                        "seq": {"type": "integer"},
+                        "params": component_ref(&format!("{}Response", e.name)),
+                    },
+                    "required": ["type", "method", "seq", "params"],
+                }),
+            ));
+            if let Some(stream) = &e.stream_response {
+                responses.push(asyncapi_message(
+                    e,
+                    "Stream",
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "type": {"const": "Stream"},
+                            "original_seq": {"type": "integer"},
+                            "method": {"const": e.code},
+                            "stream_seq": {"type": "integer"},
+                            "stream_code": {"type": "integer"},
+                            "data": stream.to_json_schema_ref(),
+                        },
+                        "required": ["type", "original_seq", "method", "stream_seq", "stream_code", "data"],
+                    }),
+                ));
+            }
+        }
+    }
+    responses.push(json!({
+        "name": "Error",
+        "payload": {
+            "allOf": [
+                component_ref("ErrorResponse"),
+                {"type": "object", "properties": {"type": {"const": "Error"}}, "required": ["type"]},
+            ],
+        },
+    }));
+    responses.push(json!({
+        "name": "Log",
+        "payload": {
+            "type": "object",
+            "properties": {
+                "type": {"const": "Log"},
+                "seq": {"type": "integer"},
+                "log_id": {"type": "integer"},
+                "level": {"type": "string", "enum": ["off", "trace", "debug", "info", "warn", "error"]},
+                "message": {"type": "string"},
+            },
+        },
+    }));
+
+    let mut handshakes = Map::new();
+    for e in services
+        .iter()
+        .filter(|x| x.name == HANDSHAKE_SERVICE)
+        .flat_map(|x| &x.endpoints)
+    {
+        handshakes.insert(
+            e.name.clone(),
+            json!({
+                "description": format!(
+                    "`0{}, {}` in the Sec-WebSocket-Protocol header, values are url encoded",
+                    e.name.to_ascii_lowercase(),
+                    e.parameters
+                        .iter()
+                        .enumerate()
+                        .map(|(i, x)| format!("{}<{}>", i + 1, x.name))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                "x-method-code": e.code,
+                "x-request": component_ref(&format!("{}Request", e.name)),
+                "x-response": component_ref(&format!("{}Response", e.name)),
+            }),
+        );
+    }
+
+    let doc = json!({
+        "asyncapi": "2.6.0",
+        "info": {
+            "title": "mc2fi",
+            "version": env!("CARGO_PKG_VERSION"),
+            "description": "Requests are `{method, seq, params}` JSON text frames. \
+                Every request is answered by an `Immediate` or `Error` frame with the same seq, \
+                subscriptions push `Stream` frames whose `original_seq` is the seq of the subscribe request.",
+        },
+        "channels": {
+            "/": {
+                "bindings": {"ws": {"headers": {
+                    "type": "object",
+                    "properties": {"Sec-WebSocket-Protocol": {"type": "string"}},
+                }}},
+                "publish": {"message": {"oneOf": requests}},
+                "subscribe": {"message": {"oneOf": responses}},
+            },
+        },
+        "components": {
+            "schemas": gen_component_schemas(root, &services)?,
+            "x-handshakes": handshakes,
+        },
+    });
+    let filename = format!("{}/docs/asyncapi.json", root);
+    serde_json::to_writer_pretty(&mut File::create(filename)?, &doc)?;
+    Ok(())
+}
@@ -98,11 +98,7 @@ impl ToRust for Type {
 "#,
                         x.comment,
                         x.name,
-                        if x.name.chars().last().unwrap().is_lowercase() {
-                            x.name.to_case(Case::Pascal)
-                        } else {
-                            x.name.clone()
-                        },
+                        enum_variant_name(&x.name),
                         x.value
                     )
                 });
@@ -118,6 +114,15 @@ impl ToRust for Type {
     }
 }
 
+/// name of the generated rust variant, which is also how serde serializes it
+pub fn enum_variant_name(name: &str) -> String {
+    if name.chars().last().unwrap().is_lowercase() {
+        name.to_case(Case::Pascal)
+    } else {
+        name.to_owned()
+    }
+}
+
 pub fn get_parameter_type(this: &ProceduralFunction) -> Type {
     Type::struct_(
         format!("{}Req", this.name.to_case(Case::Pascal)),
@@ -3,6 +3,7 @@ use convert_case::{Case, Casing};
 use itertools::Itertools;
 use model::pg_func::ProceduralFunction;
 use model::types::*;
+use std::collections::BTreeMap;
 use std::fs::File;
 use std::io::Write;
 
@@ -136,3 +137,97 @@ pub fn gen_model_sql(root: &str) -> eyre::Result<()> {
     drop(f);
     Ok(())
 }
+
+/// compares the enums defined in `shared/enums.rs` with the enums of a live database, keyed by
+/// name without the `enum_` prefix, and returns the SQL that brings the database up to date.
+/// postgres can't drop enum values, values that only exist in the database are reported as comments
+pub fn gen_enum_migration_sql(
+    enums: &[Type],
+    live_enums: &BTreeMap<String, Vec<String>>,
+) -> String {
+    let mut sql = vec![];
+    for e in enums {
+        let Type::Enum { name, variants } = e else {
+            unreachable!()
+        };
+        let Some(live_variants) = live_enums.get(name) else {
+            sql.push(format!(
+                "CREATE TYPE enum_{} AS ENUM ({});",
+                name,
+                variants.iter().map(|x| format!("'{}'", x.name)).join(", ")
+            ));
+            continue;
+        };
+        for (i, variant) in variants.iter().enumerate() {
+            if live_variants.contains(&variant.name) {
+                continue;
+            }
+            /* keep the order of shared/enums.rs, the previous variant exists by now */
+            let position = match (i, live_variants.first()) {
+                (0, Some(first)) => format!(" BEFORE '{}'", first),
+                (0, None) => "".to_owned(),
+                (i, _) => format!(" AFTER '{}'", variants[i - 1].name),
+            };
+            sql.push(format!(
+                "ALTER TYPE enum_{} ADD VALUE IF NOT EXISTS '{}'{};",
+                name, variant.name, position
+            ));
+        }
+        for live_variant in live_variants {
+            if !variants.iter().any(|x| &x.name == live_variant) {
+                sql.push(format!(
+                    "-- enum_{} has value '{}' that is no longer defined in shared/enums.rs",
+                    name, live_variant
+                ));
+            }
+        }
+    }
+    sql.join("\n")
+}
+
+/// enums defined in `shared/enums.rs`
+pub fn get_enums() -> Vec<Type> {
+    enums::get_enums()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gen_enum_migration_sql() {
+        let enums = vec![
+            Type::enum_(
+                "dex".to_owned(),
+                vec![
+                    EnumVariant::new("UniSwap", 0),
+                    EnumVariant::new("PancakeSwap", 1),
+                    EnumVariant::new("SushiSwap", 2),
+                ],
+            ),
+            Type::enum_("format".to_owned(), vec![EnumVariant::new("Json", 0)]),
+        ];
+        let mut live_enums = BTreeMap::new();
+        live_enums.insert(
+            "dex".to_owned(),
+            vec!["PancakeSwap".to_owned(), "Curve".to_owned()],
+        );
+        assert_eq!(
+            gen_enum_migration_sql(&enums, &live_enums),
+            "ALTER TYPE enum_dex ADD VALUE IF NOT EXISTS 'UniSwap' BEFORE 'PancakeSwap';
+ALTER TYPE enum_dex ADD VALUE IF NOT EXISTS 'SushiSwap' AFTER 'PancakeSwap';
+-- enum_dex has value 'Curve' that is no longer defined in shared/enums.rs
+CREATE TYPE enum_format AS ENUM ('Json');"
+        );
+        live_enums.insert("format".to_owned(), vec!["Json".to_owned()]);
+        live_enums.insert(
+            "dex".to_owned(),
+            vec![
+                "UniSwap".to_owned(),
+                "PancakeSwap".to_owned(),
+                "SushiSwap".to_owned(),
+            ],
+        );
+        assert_eq!(gen_enum_migration_sql(&enums, &live_enums), "");
+    }
+}
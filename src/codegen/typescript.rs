@@ -0,0 +1,324 @@
+use crate::rust::{collect_rust_recursive_types, enum_variant_name};
+use crate::{docs, enums, services};
+use convert_case::{Case, Casing};
+use itertools::Itertools;
+use model::endpoint::EndpointSchema;
+use model::service::Service;
+use model::types::*;
+use std::collections::BTreeSet;
+use std::fs::{create_dir_all, File};
+use std::io::Write;
+
+const GENERATED_HEADER: &str = "// generated by codegen from the endpoint schemas, do not edit\n";
+const WS_CLIENT_TS: &str = include_str!("ts/ws_client.ts");
+
+/// the auth service only handles the method encoded in the websocket handshake header
+const HANDSHAKE_SERVICE: &str = "auth";
+
+pub trait ToTypescript {
+    fn to_ts_ref(&self) -> String;
+    fn to_ts_decl(&self) -> String;
+}
+
+impl ToTypescript for Type {
+    fn to_ts_ref(&self) -> String {
+        match self {
+            Type::Date | Type::Int | Type::BigInt | Type::Numeric => "number".to_owned(),
+            Type::Boolean => "boolean".to_owned(),
+            Type::String | Type::UUID | Type::Inet => "string".to_owned(),
+            Type::Bytea => "number[]".to_owned(),
+            Type::Struct { name, .. } => name.clone(),
+            Type::StructRef(name) => name.clone(),
+            Type::Object => "unknown".to_owned(),
+            Type::DataTable { name, .. } => format!("{}[]", name),
+            Type::Vec(ele) => match **ele {
+                Type::Optional(_) => format!("({})[]", ele.to_ts_ref()),
+                _ => format!("{}[]", ele.to_ts_ref()),
+            },
+            Type::Unit => "null".to_owned(),
+            Type::Optional(t) => format!("{} | null", t.to_ts_ref()),
+            Type::Enum { name, .. } => format!("Enum{}", name.to_case(Case::Pascal)),
+            Type::EnumRef(name) => format!("Enum{}", name.to_case(Case::Pascal)),
+            // decimals, addresses and hashes are serialized as strings to keep their precision
+            Type::BlockchainDecimal | Type::BlockchainAddress | Type::BlockchainTransactionHash => {
+                "string".to_owned()
+            }
+        }
+    }
+
+    fn to_ts_decl(&self) -> String {
+        match self {
+            Type::Struct { name, fields } if fields.is_empty() => {
+                format!("export interface {} {{}}", name)
+            }
+            Type::Struct { name, fields } => {
+                let mut fields = fields.iter().map(|x| {
+                    let opt = matches!(&x.ty, Type::Optional(_));
+                    format!(
+                        "  {}{}: {};",
+                        x.name.to_case(Case::Camel),
+                        if opt { "?" } else { "" },
+                        x.ty.to_ts_ref()
+                    )
+                });
+                format!("export interface {} {{\n{}\n}}", name, fields.join("\n"))
+            }
+            Type::Enum { name, variants } => {
+                let name = format!("Enum{}", name.to_case(Case::Pascal));
+                let variants = variants
+                    .iter()
+                    .map(|x| format!("\"{}\"", enum_variant_name(&x.name)))
+                    .collect_vec();
+                format!(
+                    "export type {name} = {};\nexport const {name}Variants: readonly {name}[] = [{}];",
+                    variants.join(" | "),
+                    variants.join(", "),
+                    name = name
+                )
+            }
+            x => x.to_ts_ref(),
+        }
+    }
+}
+
+fn ts_doc(description: &str, indent: &str) -> String {
+    if description.is_empty() {
+        "".to_owned()
+    } else {
+        format!("{}/** {} */\n", indent, description)
+    }
+}
+
+fn endpoint_method_name(endpoint: &EndpointSchema) -> String {
+    endpoint.name.to_case(Case::Camel)
+}
+
+fn endpoint_parameter_names(endpoint: &EndpointSchema) -> String {
+    endpoint
+        .parameters
+        .iter()
+        .map(|x| format!("\"{}\"", x.name.to_case(Case::Camel)))
+        .join(", ")
+}
+
+pub fn gen_ts_model(root: &str, dir: &str) -> eyre::Result<()> {
+    let filename = format!("{}/model.ts", dir);
+    let mut f = File::create(&filename)?;
+    write!(&mut f, "{}", GENERATED_HEADER)?;
+
+    for e in enums::get_enums() {
+        writeln!(&mut f, "\n{}", e.to_ts_decl())?;
+    }
+
+    let errors = docs::get_error_messages(root)?;
+    writeln!(
+        &mut f,
+        "\n/** the `code` of an `Error` response */\nexport const EnumErrorCode = {{\n{}\n}} as const;\nexport type EnumErrorCode = (typeof EnumErrorCode)[keyof typeof EnumErrorCode];",
+        errors
+            .codes
+            .iter()
+            .map(|x| format!("  {}: {},", x.symbol.to_case(Case::Pascal), x.code))
+            .join("\n")
+    )?;
+    writeln!(
+        &mut f,
+        "\nexport const ErrorMessages: Readonly<Record<number, string>> = {{\n{}\n}};",
+        errors
+            .codes
+            .iter()
+            .map(|x| format!(
+                "  {}: {},",
+                x.code,
+                serde_json::Value::from(x.message.as_str())
+            ))
+            .join("\n")
+    )?;
+
+    let services = services::get_services();
+    writeln!(
+        &mut f,
+        "\n/** method codes of the endpoints */\nexport const Endpoint = {{\n{}\n}} as const;\nexport type Endpoint = (typeof Endpoint)[keyof typeof Endpoint];",
+        services
+            .iter()
+            .flat_map(|s| &s.endpoints)
+            .map(|e| format!("  {}: {},", e.name, e.code))
+            .join("\n")
+    )?;
+
+    let mut types = BTreeSet::new();
+    for s in services {
+        for e in s.endpoints {
+            let req = Type::struct_(format!("{}Request", e.name), e.parameters);
+            let resp = Type::struct_(format!("{}Response", e.name), e.returns);
+            types.extend(
+                vec![
+                    collect_rust_recursive_types(req),
+                    collect_rust_recursive_types(resp),
+                    e.stream_response
+                        .into_iter()
+                        .flat_map(collect_rust_recursive_types)
+                        .collect(),
+                ]
+                .concat(),
+            );
+        }
+    }
+    for t in types {
+        writeln!(&mut f, "\n{}", t.to_ts_decl())?;
+    }
+    Ok(())
+}
+
+fn gen_ts_handshake_service(f: &mut File, service: &Service) -> eyre::Result<()> {
+    for e in &service.endpoints {
+        writeln!(
+            f,
+            r#"
+{description}export function connect{name}(
+  url: string,
+  params: {name}Request,
+  options?: WsClientOptions,
+): Promise<WsConnected<{name}Response>> {{
+  const header = encodeHeader("{name}", [{parameters}], params);
+  return WsClient.connect<{name}Response>(url, header, options);
+}}"#,
+            description = ts_doc(&e.description, ""),
+            name = e.name,
+            parameters = endpoint_parameter_names(e),
+        )?;
+    }
+    Ok(())
+}
+
+fn gen_ts_service_client(f: &mut File, service: &Service) -> eyre::Result<()> {
+    writeln!(
+        f,
+        "\nexport class {}Client {{\n  constructor(readonly client: WsClient) {{}}",
+        service.name.to_case(Case::Pascal)
+    )?;
+    for e in &service.endpoints {
+        match &e.stream_response {
+            Some(stream) => writeln!(
+                f,
+                r#"
+{description}  {method}(
+    params: {name}Request,
+    onData: StreamHandler<{stream}>,
+  ): Promise<Subscription<{name}Response>> {{
+    return this.client.subscribe(Endpoint.{name}, params, onData);
+  }}"#,
+                description = ts_doc(&e.description, "  "),
+                method = endpoint_method_name(e),
+                name = e.name,
+                stream = stream.to_ts_ref(),
+            )?,
+            None => writeln!(
+                f,
+                r#"
+{description}  {method}(params: {name}Request): Promise<{name}Response> {{
+    return this.client.request(Endpoint.{name}, params);
+  }}"#,
+                description = ts_doc(&e.description, "  "),
+                method = endpoint_method_name(e),
+                name = e.name,
+            )?,
+        }
+    }
+    writeln!(f, "}}")?;
+    Ok(())
+}
+
+fn write_ts_type_imports(f: &mut File, types: BTreeSet<String>) -> eyre::Result<()> {
+    writeln!(
+        f,
+        "import type {{\n{}\n}} from \"./model\";",
+        types.into_iter().map(|x| format!("  {},", x)).join("\n")
+    )?;
+    Ok(())
+}
+
+pub fn gen_ts_services(dir: &str) -> eyre::Result<Vec<String>> {
+    let mut modules = vec![];
+    for s in services::get_services() {
+        if s.endpoints.is_empty() {
+            continue;
+        }
+        let filename = format!("{}/{}.ts", dir, s.name);
+        let mut f = File::create(&filename)?;
+        write!(&mut f, "{}", GENERATED_HEADER)?;
+
+        let types = s
+            .endpoints
+            .iter()
+            .flat_map(|e| {
+                [format!("{}Request", e.name), format!("{}Response", e.name)]
+                    .into_iter()
+                    .chain(e.stream_response.iter().map(|x| x.to_ts_ref()))
+            })
+            .filter(|x| !x.ends_with("[]") && x != "null")
+            .collect::<BTreeSet<_>>();
+        if s.name == HANDSHAKE_SERVICE {
+            writeln!(
+                &mut f,
+                "import {{ encodeHeader, WsClient }} from \"./ws_client\";"
+            )?;
+            writeln!(
+                &mut f,
+                "import type {{ WsClientOptions, WsConnected }} from \"./ws_client\";"
+            )?;
+            write_ts_type_imports(&mut f, types)?;
+            gen_ts_handshake_service(&mut f, &s)?;
+        } else {
+            writeln!(
+                &mut f,
+                "import type {{ StreamHandler, Subscription, WsClient }} from \"./ws_client\";"
+            )?;
+            writeln!(&mut f, "import {{ Endpoint }} from \"./model\";")?;
+            write_ts_type_imports(&mut f, types)?;
+            gen_ts_service_client(&mut f, &s)?;
+        }
+        modules.push(s.name);
+    }
+    Ok(modules)
+}
+
+/// writes the typescript client sdk to `sdk/typescript/src`
+pub fn gen_typescript_sdk(root: &str) -> eyre::Result<()> {
+    let dir = format!("{}/sdk/typescript/src", root);
+    create_dir_all(&dir)?;
+    let mut ws_client = File::create(format!("{}/ws_client.ts", dir))?;
+    write!(&mut ws_client, "{}{}", GENERATED_HEADER, WS_CLIENT_TS)?;
+    gen_ts_model(root, &dir)?;
+    let modules = gen_ts_services(&dir)?;
+
+    let mut index = File::create(format!("{}/index.ts", dir))?;
+    write!(&mut index, "{}", GENERATED_HEADER)?;
+    for module in ["ws_client", "model"]
+        .into_iter()
+        .chain(modules.iter().map(|x| x.as_str()))
+    {
+        writeln!(&mut index, "export * from \"./{}\";", module)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_struct_to_ts_decl() {
+        let ty = Type::struct_(
+            "UserFooRequest",
+            vec![
+                Field::new("strategy_id", Type::BigInt),
+                Field::new("blockchain", Type::optional(Type::enum_ref("block_chain"))),
+                Field::new("amounts", Type::vec(Type::BlockchainDecimal)),
+            ],
+        );
+        assert_eq!(
+            ty.to_ts_decl(),
+            "export interface UserFooRequest {\n  strategyId: number;\n  blockchain?: EnumBlockChain | null;\n  amounts: string[];\n}"
+        );
+    }
+}
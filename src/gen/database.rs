@@ -5,6 +5,9 @@ use postgres_from_row::FromRow;
 use rust_decimal::Decimal;
 use serde::*;
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunAdminAddActionLogRespRow {}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunAdminAddAuditRuleRespRow {}
 
@@ -14,6 +17,17 @@ pub struct FunAdminAddEscrowContractAddressRespRow {}
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunAdminAddEscrowTokenContractAddressRespRow {}
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunAdminAddPendingActionRespRow {
+    pub pending_action_id: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunAdminApprovePendingActionRespRow {
+    pub method_id: i32,
+    pub params: serde_json::Value,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunAdminApproveStrategyRespRow {}
 
@@ -22,6 +36,28 @@ pub struct FunAdminApproveUserBecomeExpertRespRow {
     pub success: bool,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunAdminGetEscrowTokenContractAddressStateRespRow {
+    pub state: serde_json::Value,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunAdminGetExpertApplicationDocumentRespRow {
+    pub name: String,
+    pub content_type: String,
+    pub blob_key: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunAdminGetExpertStateRespRow {
+    pub state: serde_json::Value,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunAdminGetStrategyStateRespRow {
+    pub state: serde_json::Value,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunAdminGetSystemConfigRespRow {
     #[serde(default)]
@@ -30,6 +66,34 @@ pub struct FunAdminGetSystemConfigRespRow {
     pub allow_domain_urls: Option<String>,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunAdminGetSystemConfigStateRespRow {
+    pub state: serde_json::Value,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunAdminGetUserStateRespRow {
+    pub state: serde_json::Value,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunAdminListActionLogRespRow {
+    pub total: i64,
+    pub action_log_id: i64,
+    pub admin_user_id: i64,
+    #[serde(default)]
+    pub pending_action_id: Option<i64>,
+    #[serde(default)]
+    pub requested_by_user_id: Option<i64>,
+    pub method_name: String,
+    pub params: serde_json::Value,
+    #[serde(default)]
+    pub state_before: Option<serde_json::Value>,
+    #[serde(default)]
+    pub state_after: Option<serde_json::Value>,
+    pub created_at: i64,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunAdminListBackersRespRow {
     pub total: i64,
@@ -61,6 +125,21 @@ pub struct FunAdminListEscrowTokenContractAddressRespRow {
     pub is_wrapped: bool,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunAdminListPendingActionsRespRow {
+    pub total: i64,
+    pub pending_action_id: i64,
+    pub requested_by_user_id: i64,
+    pub method_name: String,
+    pub params: serde_json::Value,
+    pub status: EnumAdminPendingActionStatus,
+    #[serde(default)]
+    pub approved_by_user_id: Option<i64>,
+    #[serde(default)]
+    pub approved_at: Option<i64>,
+    pub created_at: i64,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunAdminListPendingUserExpertApplicationsRespRow {
     pub total: i64,
@@ -86,6 +165,27 @@ pub struct FunAdminListPendingUserExpertApplicationsRespRow {
     pub requested_at: Option<i64>,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunAdminListSystemSettingHistoryRespRow {
+    pub total: i64,
+    pub key: String,
+    pub value: serde_json::Value,
+    pub version: i64,
+    #[serde(default)]
+    pub updated_by_user_id: Option<i64>,
+    pub created_at: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunAdminListSystemSettingsRespRow {
+    pub key: String,
+    pub value: serde_json::Value,
+    pub version: i64,
+    #[serde(default)]
+    pub updated_by_user_id: Option<i64>,
+    pub updated_at: i64,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunAdminListUsersRespRow {
     pub total: i64,
@@ -95,7 +195,8 @@ pub struct FunAdminListUsersRespRow {
     pub username: Option<String>,
     pub address: BlockchainAddress,
     pub last_ip: std::net::IpAddr,
-    pub last_login_at: i64,
+    #[serde(default)]
+    pub last_login_at: Option<i64>,
     pub login_count: i32,
     pub role: EnumRole,
     #[serde(default)]
@@ -112,9 +213,22 @@ pub struct FunAdminRejectUserBecomeExpertRespRow {
     pub success: bool,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunAdminRequestExpertApplicationInfoRespRow {
+    pub success: bool,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunAdminSetBlockUserRespRow {}
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunAdminSetPendingActionStatusRespRow {}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunAdminSetSystemSettingRespRow {
+    pub version: i64,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunAdminSetUserRoleRespRow {}
 
@@ -149,6 +263,9 @@ pub struct FunAssetPriceListAssetPricesRespRow {
     pub price_30d: Option<f64>,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunAuthAddFailedLoginAttemptRespRow {}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunAuthAuthenticateRespRow {
     pub user_id: i64,
@@ -160,32 +277,84 @@ pub struct FunAuthAuthenticateRespRow {
     pub ens_avatar: Option<String>,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunAuthAuthorizeApiKeyRespRow {
+    pub user_id: i64,
+    pub role: EnumRole,
+    pub api_key_id: i64,
+    pub api_secret: String,
+    pub scopes: Vec<EnumApiKeyScope>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunAuthAuthorizeRespRow {
     pub user_id: i64,
     pub role: EnumRole,
+    pub session_id: i64,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunAuthChangeLoginWalletAddressRespRow {}
 
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
-pub struct FunAuthRemoveTokenRespRow {}
+pub struct FunAuthConsumeLoginNonceRespRow {}
 
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
-pub struct FunAuthSetRoleRespRow {}
+pub struct FunAuthCreateSessionRespRow {
+    pub session_id: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunAuthIssueLoginNonceRespRow {}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunAuthRefreshSessionRespRow {
+    pub session_id: i64,
+    pub user_id: i64,
+    pub is_reused: bool,
+}
 
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
-pub struct FunAuthSetTokenRespRow {}
+pub struct FunAuthRemoveTokenRespRow {
+    pub user_id: i64,
+    pub session_id: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunAuthSetRoleRespRow {}
 
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunAuthSignupRespRow {
     pub user_id: i64,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunAuthTouchApiKeyRespRow {}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunAuthUpdateUserTableRespRow {}
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunAuthUseApiKeyNonceRespRow {
+    pub accepted: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunAuthValidateApiKeyRespRow {
+    pub role: EnumRole,
+    pub scopes: Vec<EnumApiKeyScope>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunAuthValidateUserSessionRespRow {
+    pub role: EnumRole,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunExpertAddStrategyGuardRuleRespRow {
+    pub rule_id: i64,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunExpertListBackersRespRow {
     pub total: i64,
@@ -214,6 +383,31 @@ pub struct FunExpertListFollowersRespRow {
     pub joined_at: i64,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunExpertRemoveStrategyGuardRuleRespRow {
+    pub success: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunExpertResumeStrategyCopyTradingRespRow {}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunUserAddExpertApplicationDocumentRespRow {
+    pub document_id: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunUserAddNotificationsRespRow {
+    pub notification_id: i64,
+    pub user_id: i64,
+    pub created_at: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunUserAddPendingWithdrawRespRow {
+    pub pending_withdraw_id: i64,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunUserAddStrategyAuditRuleRespRow {}
 
@@ -232,6 +426,12 @@ pub struct FunUserAddStrategyPoolContractRespRow {
     pub strategy_pool_contract_id: i64,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunUserAddStrategyVersionRespRow {
+    pub version_id: i64,
+    pub version: i64,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunUserAddStrategyWalletRespRow {}
 
@@ -275,6 +475,20 @@ pub struct FunUserCheckIfTokenWhitelistedRespRow {
     pub whitelisted: bool,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunUserClaimDueStrategyVersionsRespRow {
+    pub version_id: i64,
+    pub strategy_id: i64,
+    pub user_id: i64,
+    pub method_id: i32,
+    pub params: serde_json::Value,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunUserCreateApiKeyRespRow {
+    pub api_key_id: i64,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunUserCreateExpertProfileRespRow {
     pub expert_id: i64,
@@ -286,9 +500,23 @@ pub struct FunUserCreateStrategyRespRow {
     pub strategy_id: i64,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunUserCreditUserDepositWithdrawBalanceRespRow {}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunUserDebitUserDepositWithdrawBalanceRespRow {
+    #[serde(with = "rust_decimal::serde::str")]
+    pub balance: Decimal,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunUserDelStrategyAuditRuleRespRow {}
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunUserDeleteExpertApplicationDocumentRespRow {
+    pub blob_key: String,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunUserExitStrategyRespRow {
     pub success: bool,
@@ -314,43 +542,7 @@ pub struct FunUserExpertRowType {
     #[serde(default)]
     pub social_media: Option<String>,
     #[serde(default)]
-    pub risk_score: Option<f64>,
-    #[serde(default)]
-    pub reputation_score: Option<f64>,
-    #[serde(default)]
-    pub aum: Option<f64>,
-    pub joined_at: i64,
-    #[serde(default)]
-    pub requested_at: Option<i64>,
-    #[serde(default)]
-    pub approved_at: Option<i64>,
-    pub pending_expert: bool,
-    pub approved_expert: bool,
-    pub followed: bool,
-    pub linked_wallet: BlockchainAddress,
-}
-
-
-
-#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
-pub struct FunUserWhitelistRowType {
-    pub total: i64,
-    pub expert_id: i64,
-    pub user_id: i64,
-    pub user_public_id: i64,
-    pub listening_wallet: String,
-    pub username: String,
-    #[serde(default)]
-    pub family_name: Option<String>,
-    #[serde(default)]
-    pub given_name: Option<String>,
-    pub follower_count: i64,
-    pub backer_count: i64,
-    pub strategy_count: i64,
-    #[serde(default)]
-    pub description: Option<String>,
-    #[serde(default)]
-    pub social_media: Option<String>,
+    pub consistent_score: Option<f64>,
     #[serde(default)]
     pub risk_score: Option<f64>,
     #[serde(default)]
@@ -368,8 +560,6 @@ pub struct FunUserWhitelistRowType {
     pub linked_wallet: BlockchainAddress,
 }
 
-
-
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunUserFollowExpertRespRow {
     pub success: bool,
@@ -380,11 +570,31 @@ pub struct FunUserFollowStrategyRespRow {
     pub success: bool,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunUserGetExpertApplicationRespRow {
+    pub application_id: i64,
+    pub user_public_id: i64,
+    pub status: EnumExpertApplicationStatus,
+    pub questionnaire: serde_json::Value,
+    #[serde(default)]
+    pub submitted_at: Option<i64>,
+    #[serde(default)]
+    pub reviewed_at: Option<i64>,
+    pub updated_at: i64,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunUserGetStrategyIdFromWatchingWalletRespRow {
     pub strategy_id: i64,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunUserGetStrategySnapshotRespRow {
+    #[serde(default)]
+    pub creator_id: Option<i64>,
+    pub snapshot: serde_json::Value,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunUserGetStrategyStatisticsBackLedgerRespRow {
     pub time: i64,
@@ -416,6 +626,11 @@ pub struct FunUserGetUserByAddressRespRow {
     pub joined_at: i64,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunUserGetUserByLoginWalletRespRow {
+    pub user_id: i64,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunUserGetUserProfileRespRow {
     #[serde(default)]
@@ -438,6 +653,24 @@ pub struct FunUserGetUserProfileRespRow {
     pub aum: Option<f64>,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunUserLinkLoginWalletRespRow {}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunUserListApiKeysRespRow {
+    pub api_key_id: i64,
+    pub name: String,
+    pub api_key: String,
+    pub scopes: Vec<EnumApiKeyScope>,
+    #[serde(default)]
+    pub ip_allowlist: Option<Vec<std::net::IpAddr>>,
+    #[serde(default)]
+    pub expires_at: Option<i64>,
+    pub created_at: i64,
+    #[serde(default)]
+    pub last_used_at: Option<i64>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunUserListAuditRulesRespRow {
     pub rule_id: i64,
@@ -506,6 +739,30 @@ pub struct FunUserListExitStrategyLedgerRespRow {
     pub happened_at: i64,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunUserListExpertApplicationDocumentsRespRow {
+    pub document_id: i64,
+    pub name: String,
+    pub content_type: String,
+    pub size: i64,
+    pub blob_key: String,
+    pub created_at: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunUserListExpertApplicationNotesRespRow {
+    pub status: EnumExpertApplicationStatus,
+    #[serde(default)]
+    pub note: Option<String>,
+    pub from_reviewer: bool,
+    pub created_at: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunUserListExpertFollowerIdsRespRow {
+    pub user_id: i64,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunUserListExpertListenedWalletTradeLedgerEntriesRespRow {
     pub expert_listened_wallet_trade_ledger_id: i64,
@@ -526,6 +783,27 @@ pub struct FunUserListExpertListenedWalletTradeLedgerEntriesRespRow {
     pub happened_at: i64,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunUserListLoginWalletsRespRow {
+    pub address: BlockchainAddress,
+    pub is_primary: bool,
+    pub created_at: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunUserListNotificationsRespRow {
+    pub total: i64,
+    pub unread_total: i64,
+    pub notification_id: i64,
+    pub kind: EnumUserNotificationKind,
+    #[serde(default)]
+    pub strategy_id: Option<i64>,
+    pub message: String,
+    #[serde(default)]
+    pub read_at: Option<i64>,
+    pub created_at: i64,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunUserListRequestRefundLedgerRespRow {
     pub request_refund_id: i64,
@@ -536,6 +814,18 @@ pub struct FunUserListRequestRefundLedgerRespRow {
     pub wallet_address: BlockchainAddress,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunUserListSessionsRespRow {
+    pub session_id: i64,
+    pub service: EnumService,
+    pub device_id: String,
+    pub device_os: String,
+    pub ip_address: std::net::IpAddr,
+    pub created_at: i64,
+    pub last_seen_at: i64,
+    pub expires_at: i64,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunUserListStrategyAuditRulesRespRow {
     pub rule_id: i64,
@@ -552,6 +842,11 @@ pub struct FunUserListStrategyBackersRespRow {
     pub backed_at: i64,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunUserListStrategyCurrentBackersRespRow {
+    pub user_id: i64,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunUserListStrategyFollowersRespRow {
     pub total: i64,
@@ -563,18 +858,44 @@ pub struct FunUserListStrategyFollowersRespRow {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
-pub struct FunUserListStrategyInitialTokenRatiosRespRow {
-    pub total: i64,
-    pub blockchain: EnumBlockChain,
-    pub token_id: i64,
-    pub token_name: String,
-    pub token_address: BlockchainAddress,
-    pub token_decimals: i32,
-    #[serde(with = "rust_decimal::serde::str")]
-    pub quantity: Decimal,
-    pub strategy_id: i64,
-    pub created_at: i64,
-    pub updated_at: i64,
+pub struct FunUserListStrategyGuardNotificationRecipientsRespRow {
+    pub user_id: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunUserListStrategyGuardRulesRespRow {
+    pub total: i64,
+    pub rule_id: i64,
+    pub rule_type: EnumStrategyGuardRuleType,
+    #[serde(default)]
+    pub token_id: Option<i64>,
+    #[serde(default)]
+    pub token_symbol: Option<String>,
+    pub threshold: f64,
+    #[serde(default)]
+    pub reference_value: Option<f64>,
+    #[serde(default)]
+    pub exit_token_id: Option<i64>,
+    #[serde(default)]
+    pub exit_token_symbol: Option<String>,
+    #[serde(default)]
+    pub triggered_at: Option<i64>,
+    pub created_at: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunUserListStrategyInitialTokenRatiosRespRow {
+    pub total: i64,
+    pub blockchain: EnumBlockChain,
+    pub token_id: i64,
+    pub token_name: String,
+    pub token_address: BlockchainAddress,
+    pub token_decimals: i32,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub quantity: Decimal,
+    pub strategy_id: i64,
+    pub created_at: i64,
+    pub updated_at: i64,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
@@ -593,6 +914,22 @@ pub struct FunUserListStrategyPoolContractAssetLedgerRespRow {
     pub happened_at: i64,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunUserListStrategyVersionsRespRow {
+    pub total: i64,
+    pub version: i64,
+    pub method_name: String,
+    pub params: serde_json::Value,
+    #[serde(default)]
+    pub diff: Option<serde_json::Value>,
+    pub is_material: bool,
+    pub status: EnumStrategyVersionStatus,
+    pub effective_at: i64,
+    #[serde(default)]
+    pub applied_at: Option<i64>,
+    pub created_at: i64,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunUserListStrategyWalletsRespRow {
     pub total: i64,
@@ -618,6 +955,29 @@ pub struct FunUserListStrategyWhitelistedTokensRespRow {
     pub token_name: String,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunUserListUserActivityReportRespRow {
+    pub total: i64,
+    pub event_type: EnumUserActivityEventType,
+    pub blockchain: EnumBlockChain,
+    #[serde(default)]
+    pub strategy_id: Option<i64>,
+    #[serde(default)]
+    pub transaction_hash: Option<BlockchainTransactionHash>,
+    pub token_symbol: String,
+    #[serde(default)]
+    pub token_address: Option<BlockchainAddress>,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub quantity: Decimal,
+    #[serde(default)]
+    pub usd_price: Option<f64>,
+    #[serde(default)]
+    pub usd_value: Option<f64>,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub fees: Decimal,
+    pub happened_at: i64,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunUserListUserBackStrategyAttemptRespRow {
     pub total: i64,
@@ -707,6 +1067,11 @@ pub struct FunUserListWhitelistedWalletsRespRow {
     pub address: BlockchainAddress,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunUserMarkNotificationsReadRespRow {
+    pub marked: i64,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunUserRemoveStrategyInitialTokenRatioRespRow {}
 
@@ -723,6 +1088,21 @@ pub struct FunUserRequestRefundRespRow {
     pub request_refund_id: i64,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunUserRevokeApiKeyRespRow {
+    pub revoked: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunUserRevokeSessionRespRow {
+    pub revoked: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunUserSaveExpertApplicationRespRow {
+    pub application_id: i64,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunUserSaveUserBackStrategyAttemptRespRow {
     pub user_back_strategy_attempt_id: i64,
@@ -731,6 +1111,14 @@ pub struct FunUserSaveUserBackStrategyAttemptRespRow {
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunUserSaveUserBackStrategyLogRespRow {}
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunUserSetPrimaryLoginWalletRespRow {
+    pub updated: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunUserSetStrategyVersionStatusRespRow {}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunUserStrategyRowType {
     pub total: i64,
@@ -793,6 +1181,11 @@ pub struct FunUserUnfollowStrategyRespRow {
     pub success: bool,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunUserUnlinkLoginWalletRespRow {
+    pub unlinked: bool,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunUserUpdateExpertProfileRespRow {}
 
@@ -825,6 +1218,40 @@ pub struct FunWatcherGetRawTransactionRespRow {
     pub created_at: i64,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunWatcherGetStrategyCopyTradeControlsRespRow {
+    pub copy_trading_paused: bool,
+    #[serde(default)]
+    pub copy_trade_min_usd: Option<f64>,
+    #[serde(default)]
+    pub copy_trade_max_aum_ratio: Option<f64>,
+    #[serde(default)]
+    pub copy_trade_cooldown_seconds: Option<i64>,
+    #[serde(default)]
+    pub copy_trade_batch_window_seconds: Option<i64>,
+    #[serde(default)]
+    pub last_copy_traded_at: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunWatcherListActiveStrategyGuardRulesRespRow {
+    pub rule_id: i64,
+    pub strategy_id: i64,
+    pub blockchain: EnumBlockChain,
+    pub strategy_pool_contract_id: i64,
+    pub strategy_pool_address: BlockchainAddress,
+    pub rule_type: EnumStrategyGuardRuleType,
+    #[serde(default)]
+    pub token_address: Option<BlockchainAddress>,
+    pub threshold: f64,
+    #[serde(default)]
+    pub reference_value: Option<f64>,
+    #[serde(default)]
+    pub exit_token_address: Option<BlockchainAddress>,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub outstanding_sp_tokens: Decimal,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunWatcherListDexPathForPairRespRow {
     pub pkey_id: i64,
@@ -850,6 +1277,26 @@ pub struct FunWatcherListExpertListenedWalletAssetBalanceRespRow {
     pub balance: Decimal,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunWatcherListExpertScoringStatsRespRow {
+    pub user_id: i64,
+    #[serde(default)]
+    pub first_traded_at: Option<i64>,
+    pub active_weeks: i64,
+    pub backers_total: i64,
+    pub backers_current: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunWatcherListExpertStrategyDailyValuesRespRow {
+    pub expert_user_id: i64,
+    pub strategy_id: i64,
+    pub day: i64,
+    pub aum_usd: f64,
+    #[serde(default)]
+    pub share_price: Option<f64>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunWatcherListLastDexTradesForPairRespRow {
     pub transaction_hash: BlockchainTransactionHash,
@@ -864,6 +1311,44 @@ pub struct FunWatcherListLastDexTradesForPairRespRow {
     pub happened_at: i64,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunWatcherListPendingWithdrawsRespRow {
+    pub pending_withdraw_id: i64,
+    pub blockchain: EnumBlockChain,
+    pub transaction_hash: BlockchainTransactionHash,
+    pub created_at: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunWatcherListStrategiesDueForBatchedCopyTradeRespRow {
+    pub strategy_id: i64,
+    pub blockchain: EnumBlockChain,
+    pub strategy_pool_contract_id: i64,
+    pub strategy_pool_address: BlockchainAddress,
+    #[serde(default)]
+    pub copy_trade_min_usd: Option<f64>,
+    #[serde(default)]
+    pub copy_trade_max_aum_ratio: Option<f64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunWatcherListStrategiesDueForRebalanceRespRow {
+    pub strategy_id: i64,
+    pub blockchain: EnumBlockChain,
+    pub strategy_pool_contract_id: i64,
+    pub strategy_pool_address: BlockchainAddress,
+    #[serde(default)]
+    pub rebalance_drift_threshold: Option<f64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunWatcherListStrategiesForScoringRespRow {
+    pub strategy_id: i64,
+    pub blockchain: EnumBlockChain,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub outstanding_sp_tokens: Decimal,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunWatcherListStrategyEscrowPendingWalletBalanceRespRow {
     pub strategy_id: i64,
@@ -909,6 +1394,9 @@ pub struct FunWatcherListUserStrategyBalanceRespRow {
     pub balance: Decimal,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunWatcherMarkStrategyCopyTradePendingRespRow {}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunWatcherSaveRawTransactionRespRow {
     pub transaction_cache_id: i64,
@@ -929,6 +1417,37 @@ pub struct FunWatcherSaveStrategyWatchingWalletTradeLedgerRespRow {
     pub fkey_token_out_name: String,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunWatcherSettlePendingWithdrawRespRow {
+    pub user_id: i64,
+    #[serde(default)]
+    pub ledger_entry_id: Option<i64>,
+    pub blockchain: EnumBlockChain,
+    pub user_address: BlockchainAddress,
+    pub escrow_contract_address: BlockchainAddress,
+    pub receiver_address: BlockchainAddress,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub quantity: Decimal,
+    pub transaction_hash: BlockchainTransactionHash,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunWatcherTriggerStrategyGuardRuleRespRow {
+    pub triggered_at: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunWatcherUpdateExpertScoresRespRow {}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunWatcherUpdateStrategyGuardRuleReferenceValueRespRow {}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunWatcherUpdateStrategyLastCopyTradedAtRespRow {}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunWatcherUpdateStrategyLastRebalancedAtRespRow {}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunWatcherUpdateStrategyWalletPlatformManagementRespRow {
     pub success: bool,
@@ -949,6 +1468,9 @@ pub struct FunWatcherUpsertLastDexTradeForPairRespRow {
     pub last_dex_trade_for_pair_id: i64,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct FunWatcherUpsertStrategyDailyValueRespRow {}
+
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
 pub struct FunWatcherUpsertStrategyPoolContractAssetBalanceRespRow {
     pub strategy_contract_asset_balance_id: i64,
@@ -1035,42 +1557,94 @@ impl DatabaseRequest for FunAuthAuthenticateReq {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct FunAuthSetTokenReq {
+pub struct FunAuthCreateSessionReq {
     pub user_id: i64,
-    pub user_token: uuid::Uuid,
-    pub admin_token: uuid::Uuid,
-    pub service_code: i32,
+    pub service: EnumService,
+    pub device_id: String,
+    pub device_os: String,
+    pub ip_address: std::net::IpAddr,
+    pub access_token: uuid::Uuid,
+    pub access_expires_at: i64,
+    pub refresh_token: uuid::Uuid,
+    pub refresh_expires_at: i64,
 }
 
 #[allow(unused_variables)]
-impl DatabaseRequest for FunAuthSetTokenReq {
-    type ResponseRow = FunAuthSetTokenRespRow;
+impl DatabaseRequest for FunAuthCreateSessionReq {
+    type ResponseRow = FunAuthCreateSessionRespRow;
     fn statement(&self) -> &str {
-        "SELECT * FROM api.fun_auth_set_token(a_user_id => $1::bigint, a_user_token => $2::uuid, a_admin_token => $3::uuid, a_service_code => $4::int);"
+        "SELECT * FROM api.fun_auth_create_session(a_user_id => $1::bigint, a_service => $2::enum_service, a_device_id => $3::varchar, a_device_os => $4::varchar, a_ip_address => $5::inet, a_access_token => $6::uuid, a_access_expires_at => $7::bigint, a_refresh_token => $8::uuid, a_refresh_expires_at => $9::bigint);"
     }
     fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
         vec![
             &self.user_id as &(dyn ToSql + Sync),
-            &self.user_token as &(dyn ToSql + Sync),
-            &self.admin_token as &(dyn ToSql + Sync),
-            &self.service_code as &(dyn ToSql + Sync),
+            &self.service as &(dyn ToSql + Sync),
+            &self.device_id as &(dyn ToSql + Sync),
+            &self.device_os as &(dyn ToSql + Sync),
+            &self.ip_address as &(dyn ToSql + Sync),
+            &self.access_token as &(dyn ToSql + Sync),
+            &self.access_expires_at as &(dyn ToSql + Sync),
+            &self.refresh_token as &(dyn ToSql + Sync),
+            &self.refresh_expires_at as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunAuthRefreshSessionReq {
+    pub address: BlockchainAddress,
+    pub refresh_token: uuid::Uuid,
+    pub service: EnumService,
+    pub device_id: String,
+    pub ip_address: std::net::IpAddr,
+    pub new_access_token: uuid::Uuid,
+    pub new_access_expires_at: i64,
+    pub new_refresh_token: uuid::Uuid,
+    pub new_refresh_expires_at: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunAuthRefreshSessionReq {
+    type ResponseRow = FunAuthRefreshSessionRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_auth_refresh_session(a_address => $1::varchar, a_refresh_token => $2::uuid, a_service => $3::enum_service, a_device_id => $4::varchar, a_ip_address => $5::inet, a_new_access_token => $6::uuid, a_new_access_expires_at => $7::bigint, a_new_refresh_token => $8::uuid, a_new_refresh_expires_at => $9::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.address as &(dyn ToSql + Sync),
+            &self.refresh_token as &(dyn ToSql + Sync),
+            &self.service as &(dyn ToSql + Sync),
+            &self.device_id as &(dyn ToSql + Sync),
+            &self.ip_address as &(dyn ToSql + Sync),
+            &self.new_access_token as &(dyn ToSql + Sync),
+            &self.new_access_expires_at as &(dyn ToSql + Sync),
+            &self.new_refresh_token as &(dyn ToSql + Sync),
+            &self.new_refresh_expires_at as &(dyn ToSql + Sync),
         ]
     }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct FunAuthRemoveTokenReq {
-    pub user_id: i64,
+    pub address: BlockchainAddress,
+    pub token: uuid::Uuid,
+    pub service: EnumService,
+    pub device_id: String,
 }
 
 #[allow(unused_variables)]
 impl DatabaseRequest for FunAuthRemoveTokenReq {
     type ResponseRow = FunAuthRemoveTokenRespRow;
     fn statement(&self) -> &str {
-        "SELECT * FROM api.fun_auth_remove_token(a_user_id => $1::bigint);"
+        "SELECT * FROM api.fun_auth_remove_token(a_address => $1::varchar, a_token => $2::uuid, a_service => $3::enum_service, a_device_id => $4::varchar);"
     }
     fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
-        vec![&self.user_id as &(dyn ToSql + Sync)]
+        vec![
+            &self.address as &(dyn ToSql + Sync),
+            &self.token as &(dyn ToSql + Sync),
+            &self.service as &(dyn ToSql + Sync),
+            &self.device_id as &(dyn ToSql + Sync),
+        ]
     }
 }
 
@@ -1170,69 +1744,243 @@ impl DatabaseRequest for FunAuthUpdateUserTableReq {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct FunUserFollowStrategyReq {
-    pub user_id: i64,
-    pub strategy_id: i64,
+pub struct FunAuthIssueLoginNonceReq {
+    pub address: BlockchainAddress,
+    pub ip_address: std::net::IpAddr,
+    pub nonce: String,
+    pub expires_at: i64,
 }
 
 #[allow(unused_variables)]
-impl DatabaseRequest for FunUserFollowStrategyReq {
-    type ResponseRow = FunUserFollowStrategyRespRow;
+impl DatabaseRequest for FunAuthIssueLoginNonceReq {
+    type ResponseRow = FunAuthIssueLoginNonceRespRow;
     fn statement(&self) -> &str {
-        "SELECT * FROM api.fun_user_follow_strategy(a_user_id => $1::bigint, a_strategy_id => $2::bigint);"
+        "SELECT * FROM api.fun_auth_issue_login_nonce(a_address => $1::varchar, a_ip_address => $2::inet, a_nonce => $3::varchar, a_expires_at => $4::bigint);"
     }
     fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
         vec![
-            &self.user_id as &(dyn ToSql + Sync),
-            &self.strategy_id as &(dyn ToSql + Sync),
+            &self.address as &(dyn ToSql + Sync),
+            &self.ip_address as &(dyn ToSql + Sync),
+            &self.nonce as &(dyn ToSql + Sync),
+            &self.expires_at as &(dyn ToSql + Sync),
         ]
     }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct FunUserUnfollowStrategyReq {
-    pub user_id: i64,
-    pub strategy_id: i64,
+pub struct FunAuthConsumeLoginNonceReq {
+    pub nonce: String,
+    pub address: BlockchainAddress,
+    pub ip_address: std::net::IpAddr,
 }
 
 #[allow(unused_variables)]
-impl DatabaseRequest for FunUserUnfollowStrategyReq {
-    type ResponseRow = FunUserUnfollowStrategyRespRow;
+impl DatabaseRequest for FunAuthConsumeLoginNonceReq {
+    type ResponseRow = FunAuthConsumeLoginNonceRespRow;
     fn statement(&self) -> &str {
-        "SELECT * FROM api.fun_user_unfollow_strategy(a_user_id => $1::bigint, a_strategy_id => $2::bigint);"
+        "SELECT * FROM api.fun_auth_consume_login_nonce(a_nonce => $1::varchar, a_address => $2::varchar, a_ip_address => $3::inet);"
     }
     fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
         vec![
-            &self.user_id as &(dyn ToSql + Sync),
-            &self.strategy_id as &(dyn ToSql + Sync),
+            &self.nonce as &(dyn ToSql + Sync),
+            &self.address as &(dyn ToSql + Sync),
+            &self.ip_address as &(dyn ToSql + Sync),
         ]
     }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct FunUserListFollowedStrategiesReq {
-    pub user_id: i64,
-    pub limit: i64,
-    pub offset: i64,
+pub struct FunAuthAddFailedLoginAttemptReq {
+    pub address: BlockchainAddress,
+    pub ip_address: std::net::IpAddr,
+    #[serde(default)]
+    pub device_id: Option<String>,
+    #[serde(default)]
+    pub device_os: Option<String>,
 }
 
 #[allow(unused_variables)]
-impl DatabaseRequest for FunUserListFollowedStrategiesReq {
-    type ResponseRow = FunUserStrategyRowType;
+impl DatabaseRequest for FunAuthAddFailedLoginAttemptReq {
+    type ResponseRow = FunAuthAddFailedLoginAttemptRespRow;
     fn statement(&self) -> &str {
-        "SELECT * FROM api.fun_user_list_followed_strategies(a_user_id => $1::bigint, a_limit => $2::bigint, a_offset => $3::bigint);"
+        "SELECT * FROM api.fun_auth_add_failed_login_attempt(a_address => $1::varchar, a_ip_address => $2::inet, a_device_id => $3::varchar, a_device_os => $4::varchar);"
     }
     fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
         vec![
-            &self.user_id as &(dyn ToSql + Sync),
-            &self.limit as &(dyn ToSql + Sync),
-            &self.offset as &(dyn ToSql + Sync),
+            &self.address as &(dyn ToSql + Sync),
+            &self.ip_address as &(dyn ToSql + Sync),
+            &self.device_id as &(dyn ToSql + Sync),
+            &self.device_os as &(dyn ToSql + Sync),
         ]
     }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct FunUserListStrategiesReq {
+pub struct FunAuthAuthorizeApiKeyReq {
+    pub api_key: String,
+    pub ip_address: std::net::IpAddr,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunAuthAuthorizeApiKeyReq {
+    type ResponseRow = FunAuthAuthorizeApiKeyRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_auth_authorize_api_key(a_api_key => $1::varchar, a_ip_address => $2::inet);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.api_key as &(dyn ToSql + Sync),
+            &self.ip_address as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunAuthValidateUserSessionReq {
+    pub user_id: i64,
+    pub session_id: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunAuthValidateUserSessionReq {
+    type ResponseRow = FunAuthValidateUserSessionRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_auth_validate_user_session(a_user_id => $1::bigint, a_session_id => $2::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.user_id as &(dyn ToSql + Sync),
+            &self.session_id as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunAuthValidateApiKeyReq {
+    pub user_id: i64,
+    pub api_key_id: i64,
+    pub ip_address: std::net::IpAddr,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunAuthValidateApiKeyReq {
+    type ResponseRow = FunAuthValidateApiKeyRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_auth_validate_api_key(a_user_id => $1::bigint, a_api_key_id => $2::bigint, a_ip_address => $3::inet);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.user_id as &(dyn ToSql + Sync),
+            &self.api_key_id as &(dyn ToSql + Sync),
+            &self.ip_address as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunAuthTouchApiKeyReq {
+    pub api_key_id: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunAuthTouchApiKeyReq {
+    type ResponseRow = FunAuthTouchApiKeyRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_auth_touch_api_key(a_api_key_id => $1::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.api_key_id as &(dyn ToSql + Sync)]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunAuthUseApiKeyNonceReq {
+    pub api_key_id: i64,
+    pub nonce: String,
+    pub timestamp: i64,
+    pub expired_before: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunAuthUseApiKeyNonceReq {
+    type ResponseRow = FunAuthUseApiKeyNonceRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_auth_use_api_key_nonce(a_api_key_id => $1::bigint, a_nonce => $2::varchar, a_timestamp => $3::bigint, a_expired_before => $4::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.api_key_id as &(dyn ToSql + Sync),
+            &self.nonce as &(dyn ToSql + Sync),
+            &self.timestamp as &(dyn ToSql + Sync),
+            &self.expired_before as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserFollowStrategyReq {
+    pub user_id: i64,
+    pub strategy_id: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunUserFollowStrategyReq {
+    type ResponseRow = FunUserFollowStrategyRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_user_follow_strategy(a_user_id => $1::bigint, a_strategy_id => $2::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.user_id as &(dyn ToSql + Sync),
+            &self.strategy_id as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserUnfollowStrategyReq {
+    pub user_id: i64,
+    pub strategy_id: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunUserUnfollowStrategyReq {
+    type ResponseRow = FunUserUnfollowStrategyRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_user_unfollow_strategy(a_user_id => $1::bigint, a_strategy_id => $2::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.user_id as &(dyn ToSql + Sync),
+            &self.strategy_id as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserListFollowedStrategiesReq {
+    pub user_id: i64,
+    pub limit: i64,
+    pub offset: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunUserListFollowedStrategiesReq {
+    type ResponseRow = FunUserStrategyRowType;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_user_list_followed_strategies(a_user_id => $1::bigint, a_limit => $2::bigint, a_offset => $3::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.user_id as &(dyn ToSql + Sync),
+            &self.limit as &(dyn ToSql + Sync),
+            &self.offset as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserListStrategiesReq {
     pub user_id: i64,
     pub limit: i64,
     pub offset: i64,
@@ -1346,13 +2094,15 @@ pub struct FunUserBackStrategyReq {
     pub transaction_hash: BlockchainTransactionHash,
     #[serde(with = "rust_decimal::serde::str")]
     pub earn_sp_tokens: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub fees: Decimal,
 }
 
 #[allow(unused_variables)]
 impl DatabaseRequest for FunUserBackStrategyReq {
     type ResponseRow = FunUserBackStrategyRespRow;
     fn statement(&self) -> &str {
-        "SELECT * FROM api.fun_user_back_strategy(a_user_id => $1::bigint, a_strategy_id => $2::bigint, a_quantity => $3::decimal(56, 18), a_new_total_backed_quantity => $4::decimal(56, 18), a_old_total_backed_quantity => $5::decimal(56, 18), a_new_current_quantity => $6::decimal(56, 18), a_old_current_quantity => $7::decimal(56, 18), a_blockchain => $8::enum_block_chain, a_transaction_hash => $9::varchar, a_earn_sp_tokens => $10::decimal(56, 18));"
+        "SELECT * FROM api.fun_user_back_strategy(a_user_id => $1::bigint, a_strategy_id => $2::bigint, a_quantity => $3::decimal(56, 18), a_new_total_backed_quantity => $4::decimal(56, 18), a_old_total_backed_quantity => $5::decimal(56, 18), a_new_current_quantity => $6::decimal(56, 18), a_old_current_quantity => $7::decimal(56, 18), a_blockchain => $8::enum_block_chain, a_transaction_hash => $9::varchar, a_earn_sp_tokens => $10::decimal(56, 18), a_fees => $11::decimal(56, 18));"
     }
     fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
         vec![
@@ -1366,6 +2116,7 @@ impl DatabaseRequest for FunUserBackStrategyReq {
             &self.blockchain as &(dyn ToSql + Sync),
             &self.transaction_hash as &(dyn ToSql + Sync),
             &self.earn_sp_tokens as &(dyn ToSql + Sync),
+            &self.fees as &(dyn ToSql + Sync),
         ]
     }
 }
@@ -1747,7 +2498,7 @@ pub struct FunUserListExpertsReq {
     pub limit: i64,
     pub offset: i64,
     pub user_id: i64,
-    pub sort_by_followers: bool,
+    pub sort_by: EnumExpertSortBy,
     #[serde(default)]
     pub expert_id: Option<i64>,
     #[serde(default)]
@@ -1770,14 +2521,14 @@ pub struct FunUserListExpertsReq {
 impl DatabaseRequest for FunUserListExpertsReq {
     type ResponseRow = FunUserExpertRowType;
     fn statement(&self) -> &str {
-        "SELECT * FROM api.fun_user_list_experts(a_limit => $1::bigint, a_offset => $2::bigint, a_user_id => $3::bigint, a_sort_by_followers => $4::boolean, a_expert_id => $5::bigint, a_expert_user_id => $6::bigint, a_expert_user_public_id => $7::bigint, a_username => $8::varchar, a_family_name => $9::varchar, a_given_name => $10::varchar, a_description => $11::varchar, a_social_media => $12::varchar);"
+        "SELECT * FROM api.fun_user_list_experts(a_limit => $1::bigint, a_offset => $2::bigint, a_user_id => $3::bigint, a_sort_by => $4::enum_expert_sort_by, a_expert_id => $5::bigint, a_expert_user_id => $6::bigint, a_expert_user_public_id => $7::bigint, a_username => $8::varchar, a_family_name => $9::varchar, a_given_name => $10::varchar, a_description => $11::varchar, a_social_media => $12::varchar);"
     }
     fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
         vec![
             &self.limit as &(dyn ToSql + Sync),
             &self.offset as &(dyn ToSql + Sync),
             &self.user_id as &(dyn ToSql + Sync),
-            &self.sort_by_followers as &(dyn ToSql + Sync),
+            &self.sort_by as &(dyn ToSql + Sync),
             &self.expert_id as &(dyn ToSql + Sync),
             &self.expert_user_id as &(dyn ToSql + Sync),
             &self.expert_user_public_id as &(dyn ToSql + Sync),
@@ -1894,6 +2645,126 @@ impl DatabaseRequest for FunUserApplyBecomeExpertReq {
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserSaveExpertApplicationReq {
+    pub user_id: i64,
+    pub questionnaire: serde_json::Value,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunUserSaveExpertApplicationReq {
+    type ResponseRow = FunUserSaveExpertApplicationRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_user_save_expert_application(a_user_id => $1::bigint, a_questionnaire => $2::jsonb);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.user_id as &(dyn ToSql + Sync),
+            &self.questionnaire as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserAddExpertApplicationDocumentReq {
+    pub user_id: i64,
+    pub name: String,
+    pub content_type: String,
+    pub size: i64,
+    pub blob_key: String,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunUserAddExpertApplicationDocumentReq {
+    type ResponseRow = FunUserAddExpertApplicationDocumentRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_user_add_expert_application_document(a_user_id => $1::bigint, a_name => $2::varchar, a_content_type => $3::varchar, a_size => $4::bigint, a_blob_key => $5::varchar);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.user_id as &(dyn ToSql + Sync),
+            &self.name as &(dyn ToSql + Sync),
+            &self.content_type as &(dyn ToSql + Sync),
+            &self.size as &(dyn ToSql + Sync),
+            &self.blob_key as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserDeleteExpertApplicationDocumentReq {
+    pub user_id: i64,
+    pub document_id: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunUserDeleteExpertApplicationDocumentReq {
+    type ResponseRow = FunUserDeleteExpertApplicationDocumentRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_user_delete_expert_application_document(a_user_id => $1::bigint, a_document_id => $2::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.user_id as &(dyn ToSql + Sync),
+            &self.document_id as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserGetExpertApplicationReq {
+    #[serde(default)]
+    pub user_id: Option<i64>,
+    #[serde(default)]
+    pub user_public_id: Option<i64>,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunUserGetExpertApplicationReq {
+    type ResponseRow = FunUserGetExpertApplicationRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_user_get_expert_application(a_user_id => $1::bigint, a_user_public_id => $2::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.user_id as &(dyn ToSql + Sync),
+            &self.user_public_id as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserListExpertApplicationDocumentsReq {
+    pub application_id: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunUserListExpertApplicationDocumentsReq {
+    type ResponseRow = FunUserListExpertApplicationDocumentsRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_user_list_expert_application_documents(a_application_id => $1::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.application_id as &(dyn ToSql + Sync)]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserListExpertApplicationNotesReq {
+    pub application_id: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunUserListExpertApplicationNotesReq {
+    type ResponseRow = FunUserListExpertApplicationNotesRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_user_list_expert_application_notes(a_application_id => $1::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.application_id as &(dyn ToSql + Sync)]
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct FunUserCreateStrategyReq {
     pub user_id: i64,
@@ -1940,13 +2811,27 @@ pub struct FunUserUpdateStrategyReq {
     pub description: Option<String>,
     #[serde(default)]
     pub social_media: Option<String>,
+    #[serde(default)]
+    pub rebalance_interval_seconds: Option<i64>,
+    #[serde(default)]
+    pub rebalance_drift_threshold: Option<f64>,
+    #[serde(default)]
+    pub copy_trade_min_usd: Option<f64>,
+    #[serde(default)]
+    pub copy_trade_max_aum_ratio: Option<f64>,
+    #[serde(default)]
+    pub copy_trade_cooldown_seconds: Option<i64>,
+    #[serde(default)]
+    pub copy_trade_batch_window_seconds: Option<i64>,
+    #[serde(default)]
+    pub expert_fee: Option<f64>,
 }
 
 #[allow(unused_variables)]
 impl DatabaseRequest for FunUserUpdateStrategyReq {
     type ResponseRow = FunUserUpdateStrategyRespRow;
     fn statement(&self) -> &str {
-        "SELECT * FROM api.fun_user_update_strategy(a_user_id => $1::bigint, a_strategy_id => $2::bigint, a_name => $3::varchar, a_description => $4::varchar, a_social_media => $5::varchar);"
+        "SELECT * FROM api.fun_user_update_strategy(a_user_id => $1::bigint, a_strategy_id => $2::bigint, a_name => $3::varchar, a_description => $4::varchar, a_social_media => $5::varchar, a_rebalance_interval_seconds => $6::bigint, a_rebalance_drift_threshold => $7::double precision, a_copy_trade_min_usd => $8::double precision, a_copy_trade_max_aum_ratio => $9::double precision, a_copy_trade_cooldown_seconds => $10::bigint, a_copy_trade_batch_window_seconds => $11::bigint, a_expert_fee => $12::double precision);"
     }
     fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
         vec![
@@ -1955,6 +2840,13 @@ impl DatabaseRequest for FunUserUpdateStrategyReq {
             &self.name as &(dyn ToSql + Sync),
             &self.description as &(dyn ToSql + Sync),
             &self.social_media as &(dyn ToSql + Sync),
+            &self.rebalance_interval_seconds as &(dyn ToSql + Sync),
+            &self.rebalance_drift_threshold as &(dyn ToSql + Sync),
+            &self.copy_trade_min_usd as &(dyn ToSql + Sync),
+            &self.copy_trade_max_aum_ratio as &(dyn ToSql + Sync),
+            &self.copy_trade_cooldown_seconds as &(dyn ToSql + Sync),
+            &self.copy_trade_batch_window_seconds as &(dyn ToSql + Sync),
+            &self.expert_fee as &(dyn ToSql + Sync),
         ]
     }
 }
@@ -2142,13 +3034,14 @@ pub struct FunUserAddUserDepositWithdrawLedgerEntryReq {
     pub is_deposit: bool,
     pub is_back: bool,
     pub is_withdraw: bool,
+    pub is_fee: bool,
 }
 
 #[allow(unused_variables)]
 impl DatabaseRequest for FunUserAddUserDepositWithdrawLedgerEntryReq {
     type ResponseRow = FunUserAddUserDepositWithdrawLedgerEntryRespRow;
     fn statement(&self) -> &str {
-        "SELECT * FROM api.fun_user_add_user_deposit_withdraw_ledger_entry(a_user_id => $1::bigint, a_token_address => $2::varchar, a_blockchain => $3::enum_block_chain, a_user_address => $4::varchar, a_escrow_contract_address => $5::varchar, a_receiver_address => $6::varchar, a_quantity => $7::decimal(56, 18), a_transaction_hash => $8::varchar, a_is_deposit => $9::boolean, a_is_back => $10::boolean, a_is_withdraw => $11::boolean);"
+        "SELECT * FROM api.fun_user_add_user_deposit_withdraw_ledger_entry(a_user_id => $1::bigint, a_token_address => $2::varchar, a_blockchain => $3::enum_block_chain, a_user_address => $4::varchar, a_escrow_contract_address => $5::varchar, a_receiver_address => $6::varchar, a_quantity => $7::decimal(56, 18), a_transaction_hash => $8::varchar, a_is_deposit => $9::boolean, a_is_back => $10::boolean, a_is_withdraw => $11::boolean, a_is_fee => $12::boolean);"
     }
     fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
         vec![
@@ -2163,6 +3056,7 @@ impl DatabaseRequest for FunUserAddUserDepositWithdrawLedgerEntryReq {
             &self.is_deposit as &(dyn ToSql + Sync),
             &self.is_back as &(dyn ToSql + Sync),
             &self.is_withdraw as &(dyn ToSql + Sync),
+            &self.is_fee as &(dyn ToSql + Sync),
         ]
     }
 }
@@ -2416,6 +3310,22 @@ impl DatabaseRequest for FunUserGetUserByAddressReq {
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserGetUserByLoginWalletReq {
+    pub address: BlockchainAddress,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunUserGetUserByLoginWalletReq {
+    type ResponseRow = FunUserGetUserByLoginWalletRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_user_get_user_by_login_wallet(a_address => $1::varchar);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.address as &(dyn ToSql + Sync)]
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct FunUserAddStrategyWalletReq {
     pub user_id: i64,
@@ -2683,55 +3593,134 @@ impl DatabaseRequest for FunUserUpdateUserDepositWithdrawBalanceReq {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct FunUserAddStrategyPoolContractReq {
-    pub strategy_id: i64,
-    pub blockchain: EnumBlockChain,
-    pub address: BlockchainAddress,
+pub struct FunUserDebitUserDepositWithdrawBalanceReq {
+    pub deposit_withdraw_balance_id: i64,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub quantity: Decimal,
 }
 
 #[allow(unused_variables)]
-impl DatabaseRequest for FunUserAddStrategyPoolContractReq {
-    type ResponseRow = FunUserAddStrategyPoolContractRespRow;
+impl DatabaseRequest for FunUserDebitUserDepositWithdrawBalanceReq {
+    type ResponseRow = FunUserDebitUserDepositWithdrawBalanceRespRow;
     fn statement(&self) -> &str {
-        "SELECT * FROM api.fun_user_add_strategy_pool_contract(a_strategy_id => $1::bigint, a_blockchain => $2::enum_block_chain, a_address => $3::varchar);"
+        "SELECT * FROM api.fun_user_debit_user_deposit_withdraw_balance(a_deposit_withdraw_balance_id => $1::bigint, a_quantity => $2::decimal(56, 18));"
     }
     fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
         vec![
-            &self.strategy_id as &(dyn ToSql + Sync),
-            &self.blockchain as &(dyn ToSql + Sync),
-            &self.address as &(dyn ToSql + Sync),
+            &self.deposit_withdraw_balance_id as &(dyn ToSql + Sync),
+            &self.quantity as &(dyn ToSql + Sync),
         ]
     }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct FunUserListEscrowTokenContractAddressReq {
-    pub limit: i64,
-    pub offset: i64,
-    #[serde(default)]
-    pub token_id: Option<i64>,
-    #[serde(default)]
-    pub blockchain: Option<EnumBlockChain>,
-    #[serde(default)]
-    pub address: Option<BlockchainAddress>,
-    #[serde(default)]
-    pub symbol: Option<String>,
-    #[serde(default)]
-    pub is_stablecoin: Option<bool>,
+pub struct FunUserCreditUserDepositWithdrawBalanceReq {
+    pub deposit_withdraw_balance_id: i64,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub quantity: Decimal,
 }
 
 #[allow(unused_variables)]
-impl DatabaseRequest for FunUserListEscrowTokenContractAddressReq {
-    type ResponseRow = FunUserListEscrowTokenContractAddressRespRow;
+impl DatabaseRequest for FunUserCreditUserDepositWithdrawBalanceReq {
+    type ResponseRow = FunUserCreditUserDepositWithdrawBalanceRespRow;
     fn statement(&self) -> &str {
-        "SELECT * FROM api.fun_user_list_escrow_token_contract_address(a_limit => $1::bigint, a_offset => $2::bigint, a_token_id => $3::bigint, a_blockchain => $4::enum_block_chain, a_address => $5::varchar, a_symbol => $6::varchar, a_is_stablecoin => $7::boolean);"
+        "SELECT * FROM api.fun_user_credit_user_deposit_withdraw_balance(a_deposit_withdraw_balance_id => $1::bigint, a_quantity => $2::decimal(56, 18));"
     }
     fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
         vec![
-            &self.limit as &(dyn ToSql + Sync),
-            &self.offset as &(dyn ToSql + Sync),
-            &self.token_id as &(dyn ToSql + Sync),
-            &self.blockchain as &(dyn ToSql + Sync),
+            &self.deposit_withdraw_balance_id as &(dyn ToSql + Sync),
+            &self.quantity as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserAddPendingWithdrawReq {
+    pub user_id: i64,
+    pub deposit_withdraw_balance_id: i64,
+    pub blockchain: EnumBlockChain,
+    pub token_address: BlockchainAddress,
+    pub user_address: BlockchainAddress,
+    pub escrow_contract_address: BlockchainAddress,
+    pub receiver_address: BlockchainAddress,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub quantity: Decimal,
+    pub transaction_hash: BlockchainTransactionHash,
+    pub is_fee: bool,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunUserAddPendingWithdrawReq {
+    type ResponseRow = FunUserAddPendingWithdrawRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_user_add_pending_withdraw(a_user_id => $1::bigint, a_deposit_withdraw_balance_id => $2::bigint, a_blockchain => $3::enum_block_chain, a_token_address => $4::varchar, a_user_address => $5::varchar, a_escrow_contract_address => $6::varchar, a_receiver_address => $7::varchar, a_quantity => $8::decimal(56, 18), a_transaction_hash => $9::varchar, a_is_fee => $10::boolean);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.user_id as &(dyn ToSql + Sync),
+            &self.deposit_withdraw_balance_id as &(dyn ToSql + Sync),
+            &self.blockchain as &(dyn ToSql + Sync),
+            &self.token_address as &(dyn ToSql + Sync),
+            &self.user_address as &(dyn ToSql + Sync),
+            &self.escrow_contract_address as &(dyn ToSql + Sync),
+            &self.receiver_address as &(dyn ToSql + Sync),
+            &self.quantity as &(dyn ToSql + Sync),
+            &self.transaction_hash as &(dyn ToSql + Sync),
+            &self.is_fee as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserAddStrategyPoolContractReq {
+    pub strategy_id: i64,
+    pub blockchain: EnumBlockChain,
+    pub address: BlockchainAddress,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunUserAddStrategyPoolContractReq {
+    type ResponseRow = FunUserAddStrategyPoolContractRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_user_add_strategy_pool_contract(a_strategy_id => $1::bigint, a_blockchain => $2::enum_block_chain, a_address => $3::varchar);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.strategy_id as &(dyn ToSql + Sync),
+            &self.blockchain as &(dyn ToSql + Sync),
+            &self.address as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserListEscrowTokenContractAddressReq {
+    pub limit: i64,
+    pub offset: i64,
+    #[serde(default)]
+    pub token_id: Option<i64>,
+    #[serde(default)]
+    pub blockchain: Option<EnumBlockChain>,
+    #[serde(default)]
+    pub address: Option<BlockchainAddress>,
+    #[serde(default)]
+    pub symbol: Option<String>,
+    #[serde(default)]
+    pub is_stablecoin: Option<bool>,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunUserListEscrowTokenContractAddressReq {
+    type ResponseRow = FunUserListEscrowTokenContractAddressRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_user_list_escrow_token_contract_address(a_limit => $1::bigint, a_offset => $2::bigint, a_token_id => $3::bigint, a_blockchain => $4::enum_block_chain, a_address => $5::varchar, a_symbol => $6::varchar, a_is_stablecoin => $7::boolean);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.limit as &(dyn ToSql + Sync),
+            &self.offset as &(dyn ToSql + Sync),
+            &self.token_id as &(dyn ToSql + Sync),
+            &self.blockchain as &(dyn ToSql + Sync),
             &self.address as &(dyn ToSql + Sync),
             &self.symbol as &(dyn ToSql + Sync),
             &self.is_stablecoin as &(dyn ToSql + Sync),
@@ -2865,71 +3854,591 @@ impl DatabaseRequest for FunUserListUserBackStrategyLogReq {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct FunAdminListUsersReq {
+pub struct FunUserListUserActivityReportReq {
+    pub user_id: i64,
+    pub start_time: i64,
+    pub end_time: i64,
     pub limit: i64,
     pub offset: i64,
     #[serde(default)]
-    pub user_id: Option<i64>,
+    pub blockchain: Option<EnumBlockChain>,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunUserListUserActivityReportReq {
+    type ResponseRow = FunUserListUserActivityReportRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_user_list_user_activity_report(a_user_id => $1::bigint, a_start_time => $2::bigint, a_end_time => $3::bigint, a_limit => $4::bigint, a_offset => $5::bigint, a_blockchain => $6::enum_block_chain);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.user_id as &(dyn ToSql + Sync),
+            &self.start_time as &(dyn ToSql + Sync),
+            &self.end_time as &(dyn ToSql + Sync),
+            &self.limit as &(dyn ToSql + Sync),
+            &self.offset as &(dyn ToSql + Sync),
+            &self.blockchain as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunExpertAddStrategyGuardRuleReq {
+    pub strategy_id: i64,
+    pub rule_type: EnumStrategyGuardRuleType,
+    pub threshold: f64,
     #[serde(default)]
-    pub address: Option<String>,
+    pub token_id: Option<i64>,
     #[serde(default)]
-    pub username: Option<String>,
+    pub exit_token_id: Option<i64>,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunExpertAddStrategyGuardRuleReq {
+    type ResponseRow = FunExpertAddStrategyGuardRuleRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_expert_add_strategy_guard_rule(a_strategy_id => $1::bigint, a_rule_type => $2::enum_strategy_guard_rule_type, a_threshold => $3::double precision, a_token_id => $4::bigint, a_exit_token_id => $5::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.strategy_id as &(dyn ToSql + Sync),
+            &self.rule_type as &(dyn ToSql + Sync),
+            &self.threshold as &(dyn ToSql + Sync),
+            &self.token_id as &(dyn ToSql + Sync),
+            &self.exit_token_id as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunExpertRemoveStrategyGuardRuleReq {
+    pub strategy_id: i64,
+    pub rule_id: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunExpertRemoveStrategyGuardRuleReq {
+    type ResponseRow = FunExpertRemoveStrategyGuardRuleRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_expert_remove_strategy_guard_rule(a_strategy_id => $1::bigint, a_rule_id => $2::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.strategy_id as &(dyn ToSql + Sync),
+            &self.rule_id as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserListStrategyGuardRulesReq {
+    pub strategy_id: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunUserListStrategyGuardRulesReq {
+    type ResponseRow = FunUserListStrategyGuardRulesRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_user_list_strategy_guard_rules(a_strategy_id => $1::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.strategy_id as &(dyn ToSql + Sync)]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunExpertResumeStrategyCopyTradingReq {
+    pub strategy_id: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunExpertResumeStrategyCopyTradingReq {
+    type ResponseRow = FunExpertResumeStrategyCopyTradingRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_expert_resume_strategy_copy_trading(a_strategy_id => $1::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.strategy_id as &(dyn ToSql + Sync)]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserListStrategyGuardNotificationRecipientsReq {
+    pub strategy_id: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunUserListStrategyGuardNotificationRecipientsReq {
+    type ResponseRow = FunUserListStrategyGuardNotificationRecipientsRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_user_list_strategy_guard_notification_recipients(a_strategy_id => $1::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.strategy_id as &(dyn ToSql + Sync)]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserAddNotificationsReq {
+    pub user_ids: Vec<i64>,
+    pub kind: EnumUserNotificationKind,
+    pub message: String,
     #[serde(default)]
-    pub email: Option<String>,
+    pub strategy_id: Option<i64>,
     #[serde(default)]
-    pub role: Option<EnumRole>,
+    pub event_key: Option<String>,
 }
 
 #[allow(unused_variables)]
-impl DatabaseRequest for FunAdminListUsersReq {
-    type ResponseRow = FunAdminListUsersRespRow;
+impl DatabaseRequest for FunUserAddNotificationsReq {
+    type ResponseRow = FunUserAddNotificationsRespRow;
     fn statement(&self) -> &str {
-        "SELECT * FROM api.fun_admin_list_users(a_limit => $1::bigint, a_offset => $2::bigint, a_user_id => $3::bigint, a_address => $4::varchar, a_username => $5::varchar, a_email => $6::varchar, a_role => $7::enum_role);"
+        "SELECT * FROM api.fun_user_add_notifications(a_user_ids => $1::bigint[], a_kind => $2::enum_user_notification_kind, a_message => $3::varchar, a_strategy_id => $4::bigint, a_event_key => $5::varchar);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.user_ids as &(dyn ToSql + Sync),
+            &self.kind as &(dyn ToSql + Sync),
+            &self.message as &(dyn ToSql + Sync),
+            &self.strategy_id as &(dyn ToSql + Sync),
+            &self.event_key as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserListNotificationsReq {
+    pub user_id: i64,
+    pub unread_only: bool,
+    pub limit: i64,
+    pub offset: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunUserListNotificationsReq {
+    type ResponseRow = FunUserListNotificationsRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_user_list_notifications(a_user_id => $1::bigint, a_unread_only => $2::boolean, a_limit => $3::bigint, a_offset => $4::bigint);"
     }
     fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
         vec![
+            &self.user_id as &(dyn ToSql + Sync),
+            &self.unread_only as &(dyn ToSql + Sync),
             &self.limit as &(dyn ToSql + Sync),
             &self.offset as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserMarkNotificationsReadReq {
+    pub user_id: i64,
+    #[serde(default)]
+    pub notification_ids: Option<Vec<i64>>,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunUserMarkNotificationsReadReq {
+    type ResponseRow = FunUserMarkNotificationsReadRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_user_mark_notifications_read(a_user_id => $1::bigint, a_notification_ids => $2::bigint[]);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
             &self.user_id as &(dyn ToSql + Sync),
-            &self.address as &(dyn ToSql + Sync),
-            &self.username as &(dyn ToSql + Sync),
-            &self.email as &(dyn ToSql + Sync),
-            &self.role as &(dyn ToSql + Sync),
+            &self.notification_ids as &(dyn ToSql + Sync),
         ]
     }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct FunAdminSetUserRoleReq {
+pub struct FunUserListExpertFollowerIdsReq {
+    pub expert_user_id: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunUserListExpertFollowerIdsReq {
+    type ResponseRow = FunUserListExpertFollowerIdsRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_user_list_expert_follower_ids(a_expert_user_id => $1::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.expert_user_id as &(dyn ToSql + Sync)]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserListSessionsReq {
     pub user_id: i64,
-    pub role: EnumRole,
 }
 
 #[allow(unused_variables)]
-impl DatabaseRequest for FunAdminSetUserRoleReq {
-    type ResponseRow = FunAdminSetUserRoleRespRow;
+impl DatabaseRequest for FunUserListSessionsReq {
+    type ResponseRow = FunUserListSessionsRespRow;
     fn statement(&self) -> &str {
-        "SELECT * FROM api.fun_admin_set_user_role(a_user_id => $1::bigint, a_role => $2::enum_role);"
+        "SELECT * FROM api.fun_user_list_sessions(a_user_id => $1::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.user_id as &(dyn ToSql + Sync)]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserRevokeSessionReq {
+    pub user_id: i64,
+    pub session_id: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunUserRevokeSessionReq {
+    type ResponseRow = FunUserRevokeSessionRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_user_revoke_session(a_user_id => $1::bigint, a_session_id => $2::bigint);"
     }
     fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
         vec![
             &self.user_id as &(dyn ToSql + Sync),
-            &self.role as &(dyn ToSql + Sync),
+            &self.session_id as &(dyn ToSql + Sync),
         ]
     }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct FunAdminSetBlockUserReq {
+pub struct FunUserCreateApiKeyReq {
     pub user_id: i64,
-    pub blocked: bool,
+    pub name: String,
+    pub api_key: String,
+    pub api_secret: String,
+    pub scopes: Vec<EnumApiKeyScope>,
+    #[serde(default)]
+    pub ip_allowlist: Option<Vec<std::net::IpAddr>>,
+    #[serde(default)]
+    pub expires_at: Option<i64>,
 }
 
 #[allow(unused_variables)]
-impl DatabaseRequest for FunAdminSetBlockUserReq {
-    type ResponseRow = FunAdminSetBlockUserRespRow;
+impl DatabaseRequest for FunUserCreateApiKeyReq {
+    type ResponseRow = FunUserCreateApiKeyRespRow;
     fn statement(&self) -> &str {
-        "SELECT * FROM api.fun_admin_set_block_user(a_user_id => $1::bigint, a_blocked => $2::boolean);"
+        "SELECT * FROM api.fun_user_create_api_key(a_user_id => $1::bigint, a_name => $2::varchar, a_api_key => $3::varchar, a_api_secret => $4::varchar, a_scopes => $5::enum_api_key_scope[], a_ip_allowlist => $6::inet[], a_expires_at => $7::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.user_id as &(dyn ToSql + Sync),
+            &self.name as &(dyn ToSql + Sync),
+            &self.api_key as &(dyn ToSql + Sync),
+            &self.api_secret as &(dyn ToSql + Sync),
+            &self.scopes as &(dyn ToSql + Sync),
+            &self.ip_allowlist as &(dyn ToSql + Sync),
+            &self.expires_at as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserListApiKeysReq {
+    pub user_id: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunUserListApiKeysReq {
+    type ResponseRow = FunUserListApiKeysRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_user_list_api_keys(a_user_id => $1::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.user_id as &(dyn ToSql + Sync)]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserRevokeApiKeyReq {
+    pub user_id: i64,
+    pub api_key_id: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunUserRevokeApiKeyReq {
+    type ResponseRow = FunUserRevokeApiKeyRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_user_revoke_api_key(a_user_id => $1::bigint, a_api_key_id => $2::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.user_id as &(dyn ToSql + Sync),
+            &self.api_key_id as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserListLoginWalletsReq {
+    pub user_id: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunUserListLoginWalletsReq {
+    type ResponseRow = FunUserListLoginWalletsRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_user_list_login_wallets(a_user_id => $1::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.user_id as &(dyn ToSql + Sync)]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserLinkLoginWalletReq {
+    pub user_id: i64,
+    pub address: BlockchainAddress,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunUserLinkLoginWalletReq {
+    type ResponseRow = FunUserLinkLoginWalletRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_user_link_login_wallet(a_user_id => $1::bigint, a_address => $2::varchar);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.user_id as &(dyn ToSql + Sync),
+            &self.address as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserUnlinkLoginWalletReq {
+    pub user_id: i64,
+    pub address: BlockchainAddress,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunUserUnlinkLoginWalletReq {
+    type ResponseRow = FunUserUnlinkLoginWalletRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_user_unlink_login_wallet(a_user_id => $1::bigint, a_address => $2::varchar);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.user_id as &(dyn ToSql + Sync),
+            &self.address as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserSetPrimaryLoginWalletReq {
+    pub user_id: i64,
+    pub address: BlockchainAddress,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunUserSetPrimaryLoginWalletReq {
+    type ResponseRow = FunUserSetPrimaryLoginWalletRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_user_set_primary_login_wallet(a_user_id => $1::bigint, a_address => $2::varchar);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.user_id as &(dyn ToSql + Sync),
+            &self.address as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserGetStrategySnapshotReq {
+    pub strategy_id: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunUserGetStrategySnapshotReq {
+    type ResponseRow = FunUserGetStrategySnapshotRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_user_get_strategy_snapshot(a_strategy_id => $1::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.strategy_id as &(dyn ToSql + Sync)]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserListStrategyCurrentBackersReq {
+    pub strategy_id: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunUserListStrategyCurrentBackersReq {
+    type ResponseRow = FunUserListStrategyCurrentBackersRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_user_list_strategy_current_backers(a_strategy_id => $1::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.strategy_id as &(dyn ToSql + Sync)]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserAddStrategyVersionReq {
+    pub strategy_id: i64,
+    pub user_id: i64,
+    pub method_id: i32,
+    pub method_name: String,
+    pub params: serde_json::Value,
+    pub is_material: bool,
+    pub status: EnumStrategyVersionStatus,
+    pub effective_at: i64,
+    #[serde(default)]
+    pub diff: Option<serde_json::Value>,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunUserAddStrategyVersionReq {
+    type ResponseRow = FunUserAddStrategyVersionRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_user_add_strategy_version(a_strategy_id => $1::bigint, a_user_id => $2::bigint, a_method_id => $3::int, a_method_name => $4::varchar, a_params => $5::jsonb, a_is_material => $6::boolean, a_status => $7::enum_strategy_version_status, a_effective_at => $8::bigint, a_diff => $9::jsonb);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.strategy_id as &(dyn ToSql + Sync),
+            &self.user_id as &(dyn ToSql + Sync),
+            &self.method_id as &(dyn ToSql + Sync),
+            &self.method_name as &(dyn ToSql + Sync),
+            &self.params as &(dyn ToSql + Sync),
+            &self.is_material as &(dyn ToSql + Sync),
+            &self.status as &(dyn ToSql + Sync),
+            &self.effective_at as &(dyn ToSql + Sync),
+            &self.diff as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserSetStrategyVersionStatusReq {
+    pub version_id: i64,
+    pub status: EnumStrategyVersionStatus,
+    #[serde(default)]
+    pub diff: Option<serde_json::Value>,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunUserSetStrategyVersionStatusReq {
+    type ResponseRow = FunUserSetStrategyVersionStatusRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_user_set_strategy_version_status(a_version_id => $1::bigint, a_status => $2::enum_strategy_version_status, a_diff => $3::jsonb);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.version_id as &(dyn ToSql + Sync),
+            &self.status as &(dyn ToSql + Sync),
+            &self.diff as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserClaimDueStrategyVersionsReq {}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunUserClaimDueStrategyVersionsReq {
+    type ResponseRow = FunUserClaimDueStrategyVersionsRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_user_claim_due_strategy_versions();"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunUserListStrategyVersionsReq {
+    pub strategy_id: i64,
+    pub limit: i64,
+    pub offset: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunUserListStrategyVersionsReq {
+    type ResponseRow = FunUserListStrategyVersionsRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_user_list_strategy_versions(a_strategy_id => $1::bigint, a_limit => $2::bigint, a_offset => $3::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.strategy_id as &(dyn ToSql + Sync),
+            &self.limit as &(dyn ToSql + Sync),
+            &self.offset as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunAdminListUsersReq {
+    pub limit: i64,
+    pub offset: i64,
+    #[serde(default)]
+    pub user_id: Option<i64>,
+    #[serde(default)]
+    pub address: Option<String>,
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub role: Option<EnumRole>,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunAdminListUsersReq {
+    type ResponseRow = FunAdminListUsersRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_admin_list_users(a_limit => $1::bigint, a_offset => $2::bigint, a_user_id => $3::bigint, a_address => $4::varchar, a_username => $5::varchar, a_email => $6::varchar, a_role => $7::enum_role);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.limit as &(dyn ToSql + Sync),
+            &self.offset as &(dyn ToSql + Sync),
+            &self.user_id as &(dyn ToSql + Sync),
+            &self.address as &(dyn ToSql + Sync),
+            &self.username as &(dyn ToSql + Sync),
+            &self.email as &(dyn ToSql + Sync),
+            &self.role as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunAdminSetUserRoleReq {
+    pub user_id: i64,
+    pub role: EnumRole,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunAdminSetUserRoleReq {
+    type ResponseRow = FunAdminSetUserRoleRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_admin_set_user_role(a_user_id => $1::bigint, a_role => $2::enum_role);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.user_id as &(dyn ToSql + Sync),
+            &self.role as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunAdminSetBlockUserReq {
+    pub user_id: i64,
+    pub blocked: bool,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunAdminSetBlockUserReq {
+    type ResponseRow = FunAdminSetBlockUserRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_admin_set_block_user(a_user_id => $1::bigint, a_blocked => $2::boolean);"
     }
     fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
         vec![
@@ -2940,99 +4449,256 @@ impl DatabaseRequest for FunAdminSetBlockUserReq {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct FunAdminApproveUserBecomeExpertReq {
-    pub user_public_id: i64,
-}
+pub struct FunAdminApproveUserBecomeExpertReq {
+    pub admin_user_id: i64,
+    pub user_public_id: i64,
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunAdminApproveUserBecomeExpertReq {
+    type ResponseRow = FunAdminApproveUserBecomeExpertRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_admin_approve_user_become_expert(a_admin_user_id => $1::bigint, a_user_public_id => $2::bigint, a_note => $3::varchar);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.admin_user_id as &(dyn ToSql + Sync),
+            &self.user_public_id as &(dyn ToSql + Sync),
+            &self.note as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunAdminRejectUserBecomeExpertReq {
+    pub admin_user_id: i64,
+    pub user_public_id: i64,
+    pub reason: String,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunAdminRejectUserBecomeExpertReq {
+    type ResponseRow = FunAdminRejectUserBecomeExpertRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_admin_reject_user_become_expert(a_admin_user_id => $1::bigint, a_user_public_id => $2::bigint, a_reason => $3::varchar);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.admin_user_id as &(dyn ToSql + Sync),
+            &self.user_public_id as &(dyn ToSql + Sync),
+            &self.reason as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunAdminRequestExpertApplicationInfoReq {
+    pub admin_user_id: i64,
+    pub user_public_id: i64,
+    pub note: String,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunAdminRequestExpertApplicationInfoReq {
+    type ResponseRow = FunAdminRequestExpertApplicationInfoRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_admin_request_expert_application_info(a_admin_user_id => $1::bigint, a_user_public_id => $2::bigint, a_note => $3::varchar);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.admin_user_id as &(dyn ToSql + Sync),
+            &self.user_public_id as &(dyn ToSql + Sync),
+            &self.note as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunAdminGetExpertApplicationDocumentReq {
+    pub document_id: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunAdminGetExpertApplicationDocumentReq {
+    type ResponseRow = FunAdminGetExpertApplicationDocumentRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_admin_get_expert_application_document(a_document_id => $1::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.document_id as &(dyn ToSql + Sync)]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunAdminListPendingUserExpertApplicationsReq {
+    pub limit: i64,
+    pub offset: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunAdminListPendingUserExpertApplicationsReq {
+    type ResponseRow = FunAdminListPendingUserExpertApplicationsRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_admin_list_pending_user_expert_applications(a_limit => $1::bigint, a_offset => $2::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.limit as &(dyn ToSql + Sync),
+            &self.offset as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunAdminGetSystemConfigReq {
+    pub config_id: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunAdminGetSystemConfigReq {
+    type ResponseRow = FunAdminGetSystemConfigRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_admin_get_system_config(a_config_id => $1::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.config_id as &(dyn ToSql + Sync)]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunAdminUpdateSystemConfigReq {
+    pub config_id: i64,
+    #[serde(default)]
+    pub platform_fee: Option<f64>,
+    #[serde(default)]
+    pub allow_domain_urls: Option<String>,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunAdminUpdateSystemConfigReq {
+    type ResponseRow = FunAdminUpdateSystemConfigRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_admin_update_system_config(a_config_id => $1::bigint, a_platform_fee => $2::double precision, a_allow_domain_urls => $3::varchar);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.config_id as &(dyn ToSql + Sync),
+            &self.platform_fee as &(dyn ToSql + Sync),
+            &self.allow_domain_urls as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunAdminListSystemSettingsReq {}
 
 #[allow(unused_variables)]
-impl DatabaseRequest for FunAdminApproveUserBecomeExpertReq {
-    type ResponseRow = FunAdminApproveUserBecomeExpertRespRow;
+impl DatabaseRequest for FunAdminListSystemSettingsReq {
+    type ResponseRow = FunAdminListSystemSettingsRespRow;
     fn statement(&self) -> &str {
-        "SELECT * FROM api.fun_admin_approve_user_become_expert(a_user_public_id => $1::bigint);"
+        "SELECT * FROM api.fun_admin_list_system_settings();"
     }
     fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
-        vec![&self.user_public_id as &(dyn ToSql + Sync)]
+        vec![]
     }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct FunAdminRejectUserBecomeExpertReq {
-    pub user_public_id: i64,
+pub struct FunAdminSetSystemSettingReq {
+    pub admin_user_id: i64,
+    pub key: String,
+    pub value: serde_json::Value,
+    #[serde(default)]
+    pub expected_version: Option<i64>,
 }
 
 #[allow(unused_variables)]
-impl DatabaseRequest for FunAdminRejectUserBecomeExpertReq {
-    type ResponseRow = FunAdminRejectUserBecomeExpertRespRow;
+impl DatabaseRequest for FunAdminSetSystemSettingReq {
+    type ResponseRow = FunAdminSetSystemSettingRespRow;
     fn statement(&self) -> &str {
-        "SELECT * FROM api.fun_admin_reject_user_become_expert(a_user_public_id => $1::bigint);"
+        "SELECT * FROM api.fun_admin_set_system_setting(a_admin_user_id => $1::bigint, a_key => $2::varchar, a_value => $3::jsonb, a_expected_version => $4::bigint);"
     }
     fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
-        vec![&self.user_public_id as &(dyn ToSql + Sync)]
+        vec![
+            &self.admin_user_id as &(dyn ToSql + Sync),
+            &self.key as &(dyn ToSql + Sync),
+            &self.value as &(dyn ToSql + Sync),
+            &self.expected_version as &(dyn ToSql + Sync),
+        ]
     }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct FunAdminListPendingUserExpertApplicationsReq {
+pub struct FunAdminListSystemSettingHistoryReq {
     pub limit: i64,
     pub offset: i64,
+    #[serde(default)]
+    pub key: Option<String>,
 }
 
 #[allow(unused_variables)]
-impl DatabaseRequest for FunAdminListPendingUserExpertApplicationsReq {
-    type ResponseRow = FunAdminListPendingUserExpertApplicationsRespRow;
+impl DatabaseRequest for FunAdminListSystemSettingHistoryReq {
+    type ResponseRow = FunAdminListSystemSettingHistoryRespRow;
     fn statement(&self) -> &str {
-        "SELECT * FROM api.fun_admin_list_pending_user_expert_applications(a_limit => $1::bigint, a_offset => $2::bigint);"
+        "SELECT * FROM api.fun_admin_list_system_setting_history(a_limit => $1::bigint, a_offset => $2::bigint, a_key => $3::varchar);"
     }
     fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
         vec![
             &self.limit as &(dyn ToSql + Sync),
             &self.offset as &(dyn ToSql + Sync),
+            &self.key as &(dyn ToSql + Sync),
         ]
     }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct FunAdminGetSystemConfigReq {
-    pub config_id: i64,
-}
-
-#[allow(unused_variables)]
-impl DatabaseRequest for FunAdminGetSystemConfigReq {
-    type ResponseRow = FunAdminGetSystemConfigRespRow;
-    fn statement(&self) -> &str {
-        "SELECT * FROM api.fun_admin_get_system_config(a_config_id => $1::bigint);"
-    }
-    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
-        vec![&self.config_id as &(dyn ToSql + Sync)]
-    }
-}
-
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct FunAdminUpdateSystemConfigReq {
-    pub config_id: i64,
+pub struct FunAdminListExpertsReq {
+    pub limit: i64,
+    pub offset: i64,
     #[serde(default)]
-    pub platform_fee: Option<f64>,
+    pub expert_id: Option<i64>,
     #[serde(default)]
-    pub allow_domain_urls: Option<String>,
+    pub user_id: Option<i64>,
+    #[serde(default)]
+    pub user_public_id: Option<i64>,
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub family_name: Option<String>,
+    #[serde(default)]
+    pub given_name: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub social_media: Option<String>,
 }
 
 #[allow(unused_variables)]
-impl DatabaseRequest for FunAdminUpdateSystemConfigReq {
-    type ResponseRow = FunAdminUpdateSystemConfigRespRow;
+impl DatabaseRequest for FunAdminListExpertsReq {
+    type ResponseRow = FunUserExpertRowType;
     fn statement(&self) -> &str {
-        "SELECT * FROM api.fun_admin_update_system_config(a_config_id => $1::bigint, a_platform_fee => $2::double precision, a_allow_domain_urls => $3::varchar);"
+        "SELECT * FROM api.fun_admin_list_experts(a_limit => $1::bigint, a_offset => $2::bigint, a_expert_id => $3::bigint, a_user_id => $4::bigint, a_user_public_id => $5::bigint, a_username => $6::varchar, a_family_name => $7::varchar, a_given_name => $8::varchar, a_description => $9::varchar, a_social_media => $10::varchar);"
     }
     fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
         vec![
-            &self.config_id as &(dyn ToSql + Sync),
-            &self.platform_fee as &(dyn ToSql + Sync),
-            &self.allow_domain_urls as &(dyn ToSql + Sync),
+            &self.limit as &(dyn ToSql + Sync),
+            &self.offset as &(dyn ToSql + Sync),
+            &self.expert_id as &(dyn ToSql + Sync),
+            &self.user_id as &(dyn ToSql + Sync),
+            &self.user_public_id as &(dyn ToSql + Sync),
+            &self.username as &(dyn ToSql + Sync),
+            &self.family_name as &(dyn ToSql + Sync),
+            &self.given_name as &(dyn ToSql + Sync),
+            &self.description as &(dyn ToSql + Sync),
+            &self.social_media as &(dyn ToSql + Sync),
         ]
     }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct FunAdminListExpertsReq {
+pub struct FunAdminListWhitelistsReq {
     pub limit: i64,
     pub offset: i64,
     #[serde(default)]
@@ -3054,10 +4720,10 @@ pub struct FunAdminListExpertsReq {
 }
 
 #[allow(unused_variables)]
-impl DatabaseRequest for FunAdminListExpertsReq {
+impl DatabaseRequest for FunAdminListWhitelistsReq {
     type ResponseRow = FunUserExpertRowType;
     fn statement(&self) -> &str {
-        "SELECT * FROM api.fun_admin_list_experts(a_limit => $1::bigint, a_offset => $2::bigint, a_expert_id => $3::bigint, a_user_id => $4::bigint, a_user_public_id => $5::bigint, a_username => $6::varchar, a_family_name => $7::varchar, a_given_name => $8::varchar, a_description => $9::varchar, a_social_media => $10::varchar);"
+        "SELECT * FROM api.fun_admin_list_whitelists(a_limit => $1::bigint, a_offset => $2::bigint, a_expert_id => $3::bigint, a_user_id => $4::bigint, a_user_public_id => $5::bigint, a_username => $6::varchar, a_family_name => $7::varchar, a_given_name => $8::varchar, a_description => $9::varchar, a_social_media => $10::varchar);"
     }
     fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
         vec![
@@ -3252,122 +4918,346 @@ pub struct FunAdminUpdateEscrowTokenContractAddressReq {
     #[serde(default)]
     pub is_stablecoin: Option<bool>,
     #[serde(default)]
-    pub is_wrapped: Option<bool>,
+    pub is_wrapped: Option<bool>,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunAdminUpdateEscrowTokenContractAddressReq {
+    type ResponseRow = FunAdminUpdateEscrowTokenContractAddressRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_admin_update_escrow_token_contract_address(a_pkey_id => $1::bigint, a_symbol => $2::varchar, a_short_name => $3::varchar, a_description => $4::varchar, a_is_stablecoin => $5::boolean, a_is_wrapped => $6::boolean);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.pkey_id as &(dyn ToSql + Sync),
+            &self.symbol as &(dyn ToSql + Sync),
+            &self.short_name as &(dyn ToSql + Sync),
+            &self.description as &(dyn ToSql + Sync),
+            &self.is_stablecoin as &(dyn ToSql + Sync),
+            &self.is_wrapped as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunAdminListEscrowTokenContractAddressReq {
+    #[serde(default)]
+    pub limit: Option<i64>,
+    #[serde(default)]
+    pub offset: Option<i64>,
+    #[serde(default)]
+    pub blockchain: Option<EnumBlockChain>,
+    #[serde(default)]
+    pub token_address: Option<BlockchainAddress>,
+    #[serde(default)]
+    pub symbol: Option<String>,
+    #[serde(default)]
+    pub token_id: Option<i64>,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunAdminListEscrowTokenContractAddressReq {
+    type ResponseRow = FunAdminListEscrowTokenContractAddressRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_admin_list_escrow_token_contract_address(a_limit => $1::bigint, a_offset => $2::bigint, a_blockchain => $3::enum_block_chain, a_token_address => $4::varchar, a_symbol => $5::varchar, a_token_id => $6::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.limit as &(dyn ToSql + Sync),
+            &self.offset as &(dyn ToSql + Sync),
+            &self.blockchain as &(dyn ToSql + Sync),
+            &self.token_address as &(dyn ToSql + Sync),
+            &self.symbol as &(dyn ToSql + Sync),
+            &self.token_id as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunAdminAddEscrowContractAddressReq {
+    pub pkey_id: i64,
+    pub blockchain: EnumBlockChain,
+    pub address: BlockchainAddress,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunAdminAddEscrowContractAddressReq {
+    type ResponseRow = FunAdminAddEscrowContractAddressRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_admin_add_escrow_contract_address(a_pkey_id => $1::bigint, a_blockchain => $2::enum_block_chain, a_address => $3::varchar);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.pkey_id as &(dyn ToSql + Sync),
+            &self.blockchain as &(dyn ToSql + Sync),
+            &self.address as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunAdminListEscrowContractAddressReq {
+    pub limit: i64,
+    pub offset: i64,
+    #[serde(default)]
+    pub blockchain: Option<EnumBlockChain>,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunAdminListEscrowContractAddressReq {
+    type ResponseRow = FunAdminListEscrowContractAddressRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_admin_list_escrow_contract_address(a_limit => $1::bigint, a_offset => $2::bigint, a_blockchain => $3::enum_block_chain);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.limit as &(dyn ToSql + Sync),
+            &self.offset as &(dyn ToSql + Sync),
+            &self.blockchain as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunAdminUpdateEscrowContractAddressReq {
+    pub blockchain: EnumBlockChain,
+    pub address: BlockchainAddress,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunAdminUpdateEscrowContractAddressReq {
+    type ResponseRow = FunAdminUpdateEscrowContractAddressRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_admin_update_escrow_contract_address(a_blockchain => $1::enum_block_chain, a_address => $2::varchar);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.blockchain as &(dyn ToSql + Sync),
+            &self.address as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunAdminGetUserStateReq {
+    pub user_id: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunAdminGetUserStateReq {
+    type ResponseRow = FunAdminGetUserStateRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_admin_get_user_state(a_user_id => $1::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.user_id as &(dyn ToSql + Sync)]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunAdminGetExpertStateReq {
+    pub user_public_id: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunAdminGetExpertStateReq {
+    type ResponseRow = FunAdminGetExpertStateRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_admin_get_expert_state(a_user_public_id => $1::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.user_public_id as &(dyn ToSql + Sync)]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunAdminGetStrategyStateReq {
+    pub strategy_id: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunAdminGetStrategyStateReq {
+    type ResponseRow = FunAdminGetStrategyStateRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_admin_get_strategy_state(a_strategy_id => $1::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.strategy_id as &(dyn ToSql + Sync)]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunAdminGetSystemConfigStateReq {
+    pub config_id: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunAdminGetSystemConfigStateReq {
+    type ResponseRow = FunAdminGetSystemConfigStateRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_admin_get_system_config_state(a_config_id => $1::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.config_id as &(dyn ToSql + Sync)]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunAdminGetEscrowTokenContractAddressStateReq {
+    pub pkey_id: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunAdminGetEscrowTokenContractAddressStateReq {
+    type ResponseRow = FunAdminGetEscrowTokenContractAddressStateRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_admin_get_escrow_token_contract_address_state(a_pkey_id => $1::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.pkey_id as &(dyn ToSql + Sync)]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunAdminAddActionLogReq {
+    pub admin_user_id: i64,
+    pub method_id: i32,
+    pub method_name: String,
+    pub params: serde_json::Value,
+    pub state_before: serde_json::Value,
+    pub state_after: serde_json::Value,
+    #[serde(default)]
+    pub pending_action_id: Option<i64>,
 }
 
 #[allow(unused_variables)]
-impl DatabaseRequest for FunAdminUpdateEscrowTokenContractAddressReq {
-    type ResponseRow = FunAdminUpdateEscrowTokenContractAddressRespRow;
+impl DatabaseRequest for FunAdminAddActionLogReq {
+    type ResponseRow = FunAdminAddActionLogRespRow;
     fn statement(&self) -> &str {
-        "SELECT * FROM api.fun_admin_update_escrow_token_contract_address(a_pkey_id => $1::bigint, a_symbol => $2::varchar, a_short_name => $3::varchar, a_description => $4::varchar, a_is_stablecoin => $5::boolean, a_is_wrapped => $6::boolean);"
+        "SELECT * FROM api.fun_admin_add_action_log(a_admin_user_id => $1::bigint, a_method_id => $2::int, a_method_name => $3::varchar, a_params => $4::jsonb, a_state_before => $5::jsonb, a_state_after => $6::jsonb, a_pending_action_id => $7::bigint);"
     }
     fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
         vec![
-            &self.pkey_id as &(dyn ToSql + Sync),
-            &self.symbol as &(dyn ToSql + Sync),
-            &self.short_name as &(dyn ToSql + Sync),
-            &self.description as &(dyn ToSql + Sync),
-            &self.is_stablecoin as &(dyn ToSql + Sync),
-            &self.is_wrapped as &(dyn ToSql + Sync),
+            &self.admin_user_id as &(dyn ToSql + Sync),
+            &self.method_id as &(dyn ToSql + Sync),
+            &self.method_name as &(dyn ToSql + Sync),
+            &self.params as &(dyn ToSql + Sync),
+            &self.state_before as &(dyn ToSql + Sync),
+            &self.state_after as &(dyn ToSql + Sync),
+            &self.pending_action_id as &(dyn ToSql + Sync),
         ]
     }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct FunAdminListEscrowTokenContractAddressReq {
-    #[serde(default)]
-    pub limit: Option<i64>,
-    #[serde(default)]
-    pub offset: Option<i64>,
-    #[serde(default)]
-    pub blockchain: Option<EnumBlockChain>,
-    #[serde(default)]
-    pub token_address: Option<BlockchainAddress>,
+pub struct FunAdminListActionLogReq {
+    pub limit: i64,
+    pub offset: i64,
     #[serde(default)]
-    pub symbol: Option<String>,
+    pub admin_user_id: Option<i64>,
     #[serde(default)]
-    pub token_id: Option<i64>,
+    pub method_name: Option<String>,
 }
 
 #[allow(unused_variables)]
-impl DatabaseRequest for FunAdminListEscrowTokenContractAddressReq {
-    type ResponseRow = FunAdminListEscrowTokenContractAddressRespRow;
+impl DatabaseRequest for FunAdminListActionLogReq {
+    type ResponseRow = FunAdminListActionLogRespRow;
     fn statement(&self) -> &str {
-        "SELECT * FROM api.fun_admin_list_escrow_token_contract_address(a_limit => $1::bigint, a_offset => $2::bigint, a_blockchain => $3::enum_block_chain, a_token_address => $4::varchar, a_symbol => $5::varchar, a_token_id => $6::bigint);"
+        "SELECT * FROM api.fun_admin_list_action_log(a_limit => $1::bigint, a_offset => $2::bigint, a_admin_user_id => $3::bigint, a_method_name => $4::varchar);"
     }
     fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
         vec![
             &self.limit as &(dyn ToSql + Sync),
             &self.offset as &(dyn ToSql + Sync),
-            &self.blockchain as &(dyn ToSql + Sync),
-            &self.token_address as &(dyn ToSql + Sync),
-            &self.symbol as &(dyn ToSql + Sync),
-            &self.token_id as &(dyn ToSql + Sync),
+            &self.admin_user_id as &(dyn ToSql + Sync),
+            &self.method_name as &(dyn ToSql + Sync),
         ]
     }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct FunAdminAddEscrowContractAddressReq {
-    pub pkey_id: i64,
-    pub blockchain: EnumBlockChain,
-    pub address: BlockchainAddress,
+pub struct FunAdminAddPendingActionReq {
+    pub admin_user_id: i64,
+    pub method_id: i32,
+    pub method_name: String,
+    pub params: serde_json::Value,
 }
 
 #[allow(unused_variables)]
-impl DatabaseRequest for FunAdminAddEscrowContractAddressReq {
-    type ResponseRow = FunAdminAddEscrowContractAddressRespRow;
+impl DatabaseRequest for FunAdminAddPendingActionReq {
+    type ResponseRow = FunAdminAddPendingActionRespRow;
     fn statement(&self) -> &str {
-        "SELECT * FROM api.fun_admin_add_escrow_contract_address(a_pkey_id => $1::bigint, a_blockchain => $2::enum_block_chain, a_address => $3::varchar);"
+        "SELECT * FROM api.fun_admin_add_pending_action(a_admin_user_id => $1::bigint, a_method_id => $2::int, a_method_name => $3::varchar, a_params => $4::jsonb);"
     }
     fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
         vec![
-            &self.pkey_id as &(dyn ToSql + Sync),
-            &self.blockchain as &(dyn ToSql + Sync),
-            &self.address as &(dyn ToSql + Sync),
+            &self.admin_user_id as &(dyn ToSql + Sync),
+            &self.method_id as &(dyn ToSql + Sync),
+            &self.method_name as &(dyn ToSql + Sync),
+            &self.params as &(dyn ToSql + Sync),
         ]
     }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct FunAdminListEscrowContractAddressReq {
+pub struct FunAdminListPendingActionsReq {
     pub limit: i64,
     pub offset: i64,
     #[serde(default)]
-    pub blockchain: Option<EnumBlockChain>,
+    pub status: Option<EnumAdminPendingActionStatus>,
 }
 
 #[allow(unused_variables)]
-impl DatabaseRequest for FunAdminListEscrowContractAddressReq {
-    type ResponseRow = FunAdminListEscrowContractAddressRespRow;
+impl DatabaseRequest for FunAdminListPendingActionsReq {
+    type ResponseRow = FunAdminListPendingActionsRespRow;
     fn statement(&self) -> &str {
-        "SELECT * FROM api.fun_admin_list_escrow_contract_address(a_limit => $1::bigint, a_offset => $2::bigint, a_blockchain => $3::enum_block_chain);"
+        "SELECT * FROM api.fun_admin_list_pending_actions(a_limit => $1::bigint, a_offset => $2::bigint, a_status => $3::enum_admin_pending_action_status);"
     }
     fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
         vec![
             &self.limit as &(dyn ToSql + Sync),
             &self.offset as &(dyn ToSql + Sync),
-            &self.blockchain as &(dyn ToSql + Sync),
+            &self.status as &(dyn ToSql + Sync),
         ]
     }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct FunAdminUpdateEscrowContractAddressReq {
-    pub blockchain: EnumBlockChain,
-    pub address: BlockchainAddress,
+pub struct FunAdminApprovePendingActionReq {
+    pub pending_action_id: i64,
+    pub admin_user_id: i64,
 }
 
 #[allow(unused_variables)]
-impl DatabaseRequest for FunAdminUpdateEscrowContractAddressReq {
-    type ResponseRow = FunAdminUpdateEscrowContractAddressRespRow;
+impl DatabaseRequest for FunAdminApprovePendingActionReq {
+    type ResponseRow = FunAdminApprovePendingActionRespRow;
     fn statement(&self) -> &str {
-        "SELECT * FROM api.fun_admin_update_escrow_contract_address(a_blockchain => $1::enum_block_chain, a_address => $2::varchar);"
+        "SELECT * FROM api.fun_admin_approve_pending_action(a_pending_action_id => $1::bigint, a_admin_user_id => $2::bigint);"
     }
     fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
         vec![
-            &self.blockchain as &(dyn ToSql + Sync),
-            &self.address as &(dyn ToSql + Sync),
+            &self.pending_action_id as &(dyn ToSql + Sync),
+            &self.admin_user_id as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunAdminSetPendingActionStatusReq {
+    pub pending_action_id: i64,
+    pub status: EnumAdminPendingActionStatus,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunAdminSetPendingActionStatusReq {
+    type ResponseRow = FunAdminSetPendingActionStatusRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_admin_set_pending_action_status(a_pending_action_id => $1::bigint, a_status => $2::enum_admin_pending_action_status);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.pending_action_id as &(dyn ToSql + Sync),
+            &self.status as &(dyn ToSql + Sync),
         ]
     }
 }
@@ -3889,6 +5779,281 @@ impl DatabaseRequest for FunWatcherUpdateStrategyWalletPlatformManagementReq {
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunWatcherListStrategiesDueForRebalanceReq {}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunWatcherListStrategiesDueForRebalanceReq {
+    type ResponseRow = FunWatcherListStrategiesDueForRebalanceRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_watcher_list_strategies_due_for_rebalance();"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunWatcherUpdateStrategyLastRebalancedAtReq {
+    pub strategy_id: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunWatcherUpdateStrategyLastRebalancedAtReq {
+    type ResponseRow = FunWatcherUpdateStrategyLastRebalancedAtRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_watcher_update_strategy_last_rebalanced_at(a_strategy_id => $1::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.strategy_id as &(dyn ToSql + Sync)]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunWatcherListActiveStrategyGuardRulesReq {}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunWatcherListActiveStrategyGuardRulesReq {
+    type ResponseRow = FunWatcherListActiveStrategyGuardRulesRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_watcher_list_active_strategy_guard_rules();"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunWatcherUpdateStrategyGuardRuleReferenceValueReq {
+    pub rule_id: i64,
+    pub strategy_pool_contract_id: i64,
+    pub reference_value: f64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunWatcherUpdateStrategyGuardRuleReferenceValueReq {
+    type ResponseRow = FunWatcherUpdateStrategyGuardRuleReferenceValueRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_watcher_update_strategy_guard_rule_reference_value(a_rule_id => $1::bigint, a_strategy_pool_contract_id => $2::bigint, a_reference_value => $3::double precision);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.rule_id as &(dyn ToSql + Sync),
+            &self.strategy_pool_contract_id as &(dyn ToSql + Sync),
+            &self.reference_value as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunWatcherTriggerStrategyGuardRuleReq {
+    pub rule_id: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunWatcherTriggerStrategyGuardRuleReq {
+    type ResponseRow = FunWatcherTriggerStrategyGuardRuleRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_watcher_trigger_strategy_guard_rule(a_rule_id => $1::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.rule_id as &(dyn ToSql + Sync)]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunWatcherGetStrategyCopyTradeControlsReq {
+    pub strategy_id: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunWatcherGetStrategyCopyTradeControlsReq {
+    type ResponseRow = FunWatcherGetStrategyCopyTradeControlsRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_watcher_get_strategy_copy_trade_controls(a_strategy_id => $1::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.strategy_id as &(dyn ToSql + Sync)]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunWatcherMarkStrategyCopyTradePendingReq {
+    pub strategy_id: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunWatcherMarkStrategyCopyTradePendingReq {
+    type ResponseRow = FunWatcherMarkStrategyCopyTradePendingRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_watcher_mark_strategy_copy_trade_pending(a_strategy_id => $1::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.strategy_id as &(dyn ToSql + Sync)]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunWatcherUpdateStrategyLastCopyTradedAtReq {
+    pub strategy_id: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunWatcherUpdateStrategyLastCopyTradedAtReq {
+    type ResponseRow = FunWatcherUpdateStrategyLastCopyTradedAtRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_watcher_update_strategy_last_copy_traded_at(a_strategy_id => $1::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.strategy_id as &(dyn ToSql + Sync)]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunWatcherListStrategiesDueForBatchedCopyTradeReq {}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunWatcherListStrategiesDueForBatchedCopyTradeReq {
+    type ResponseRow = FunWatcherListStrategiesDueForBatchedCopyTradeRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_watcher_list_strategies_due_for_batched_copy_trade();"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunWatcherListStrategiesForScoringReq {}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunWatcherListStrategiesForScoringReq {
+    type ResponseRow = FunWatcherListStrategiesForScoringRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_watcher_list_strategies_for_scoring();"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunWatcherUpsertStrategyDailyValueReq {
+    pub strategy_id: i64,
+    pub day: i64,
+    pub aum_usd: f64,
+    #[serde(default)]
+    pub share_price: Option<f64>,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunWatcherUpsertStrategyDailyValueReq {
+    type ResponseRow = FunWatcherUpsertStrategyDailyValueRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_watcher_upsert_strategy_daily_value(a_strategy_id => $1::bigint, a_day => $2::bigint, a_aum_usd => $3::double precision, a_share_price => $4::double precision);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.strategy_id as &(dyn ToSql + Sync),
+            &self.day as &(dyn ToSql + Sync),
+            &self.aum_usd as &(dyn ToSql + Sync),
+            &self.share_price as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunWatcherListExpertStrategyDailyValuesReq {
+    pub since: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunWatcherListExpertStrategyDailyValuesReq {
+    type ResponseRow = FunWatcherListExpertStrategyDailyValuesRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_watcher_list_expert_strategy_daily_values(a_since => $1::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.since as &(dyn ToSql + Sync)]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunWatcherListExpertScoringStatsReq {
+    pub since: i64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunWatcherListExpertScoringStatsReq {
+    type ResponseRow = FunWatcherListExpertScoringStatsRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_watcher_list_expert_scoring_stats(a_since => $1::bigint);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.since as &(dyn ToSql + Sync)]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunWatcherUpdateExpertScoresReq {
+    pub user_id: i64,
+    pub consistent_score: f64,
+    pub risk_score: f64,
+    pub reputation_score: f64,
+    pub aum: f64,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunWatcherUpdateExpertScoresReq {
+    type ResponseRow = FunWatcherUpdateExpertScoresRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_watcher_update_expert_scores(a_user_id => $1::bigint, a_consistent_score => $2::double precision, a_risk_score => $3::double precision, a_reputation_score => $4::double precision, a_aum => $5::double precision);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.user_id as &(dyn ToSql + Sync),
+            &self.consistent_score as &(dyn ToSql + Sync),
+            &self.risk_score as &(dyn ToSql + Sync),
+            &self.reputation_score as &(dyn ToSql + Sync),
+            &self.aum as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunWatcherListPendingWithdrawsReq {}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunWatcherListPendingWithdrawsReq {
+    type ResponseRow = FunWatcherListPendingWithdrawsRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_watcher_list_pending_withdraws();"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunWatcherSettlePendingWithdrawReq {
+    pub pending_withdraw_id: i64,
+    pub is_successful: bool,
+}
+
+#[allow(unused_variables)]
+impl DatabaseRequest for FunWatcherSettlePendingWithdrawReq {
+    type ResponseRow = FunWatcherSettlePendingWithdrawRespRow;
+    fn statement(&self) -> &str {
+        "SELECT * FROM api.fun_watcher_settle_pending_withdraw(a_pending_withdraw_id => $1::bigint, a_is_successful => $2::boolean);"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.pending_withdraw_id as &(dyn ToSql + Sync),
+            &self.is_successful as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct FunAssetPriceInsertAssetPricesReq {
     pub symbols: Vec<String>,
@@ -1,2 +1,7 @@
 pub mod database;
 pub mod model;
+
+/// OpenAPI document of every endpoint over HTTP, generated by codegen
+pub const OPENAPI_JSON: &str = include_str!("../../docs/openapi.json");
+/// AsyncAPI document of the websocket protocol, generated by codegen
+pub const ASYNCAPI_JSON: &str = include_str!("../../docs/asyncapi.json");
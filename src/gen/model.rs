@@ -222,6 +222,309 @@ pub enum EnumDexPathFormat {
     Display,
     Hash,
 )]
+#[postgres(name = "enum_activity_report_format")]
+pub enum EnumActivityReportFormat {
+    ///
+    #[postgres(name = "Json")]
+    Json = 0,
+    ///
+    #[postgres(name = "Csv")]
+    Csv = 1,
+}
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    ToSql,
+    FromSql,
+    Serialize,
+    Deserialize,
+    FromPrimitive,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    EnumString,
+    Display,
+    Hash,
+)]
+#[postgres(name = "enum_user_activity_event_type")]
+pub enum EnumUserActivityEventType {
+    ///
+    #[postgres(name = "Deposit")]
+    Deposit = 0,
+    ///
+    #[postgres(name = "Refund")]
+    Refund = 1,
+    ///
+    #[postgres(name = "EscrowSpent")]
+    EscrowSpent = 2,
+    ///
+    #[postgres(name = "BackStrategy")]
+    BackStrategy = 3,
+    ///
+    #[postgres(name = "ExitStrategy")]
+    ExitStrategy = 4,
+    ///
+    #[postgres(name = "PoolAssetAdd")]
+    PoolAssetAdd = 5,
+    ///
+    #[postgres(name = "PoolAssetRemove")]
+    PoolAssetRemove = 6,
+    ///
+    #[postgres(name = "Withdraw")]
+    Withdraw = 7,
+    ///
+    #[postgres(name = "WithdrawFee")]
+    WithdrawFee = 8,
+}
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    ToSql,
+    FromSql,
+    Serialize,
+    Deserialize,
+    FromPrimitive,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    EnumString,
+    Display,
+    Hash,
+)]
+#[postgres(name = "enum_strategy_guard_rule_type")]
+pub enum EnumStrategyGuardRuleType {
+    ///
+    #[postgres(name = "MaxDrawdown")]
+    MaxDrawdown = 0,
+    ///
+    #[postgres(name = "AssetStopLoss")]
+    AssetStopLoss = 1,
+    ///
+    #[postgres(name = "TakeProfit")]
+    TakeProfit = 2,
+}
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    ToSql,
+    FromSql,
+    Serialize,
+    Deserialize,
+    FromPrimitive,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    EnumString,
+    Display,
+    Hash,
+)]
+#[postgres(name = "enum_user_notification_kind")]
+pub enum EnumUserNotificationKind {
+    ///
+    #[postgres(name = "StrategyTrade")]
+    StrategyTrade = 0,
+    ///
+    #[postgres(name = "BackStrategy")]
+    BackStrategy = 1,
+    ///
+    #[postgres(name = "ExitStrategy")]
+    ExitStrategy = 2,
+    ///
+    #[postgres(name = "Refund")]
+    Refund = 3,
+    ///
+    #[postgres(name = "ExpertNewStrategy")]
+    ExpertNewStrategy = 4,
+    ///
+    #[postgres(name = "StrategyChange")]
+    StrategyChange = 5,
+    ///
+    #[postgres(name = "StrategyGuard")]
+    StrategyGuard = 6,
+}
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    ToSql,
+    FromSql,
+    Serialize,
+    Deserialize,
+    FromPrimitive,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    EnumString,
+    Display,
+    Hash,
+)]
+#[postgres(name = "enum_api_key_scope")]
+pub enum EnumApiKeyScope {
+    ///
+    #[postgres(name = "ReadOnly")]
+    ReadOnly = 0,
+    ///
+    #[postgres(name = "Trade")]
+    Trade = 1,
+    ///
+    #[postgres(name = "Withdraw")]
+    Withdraw = 2,
+}
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    ToSql,
+    FromSql,
+    Serialize,
+    Deserialize,
+    FromPrimitive,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    EnumString,
+    Display,
+    Hash,
+)]
+#[postgres(name = "enum_admin_pending_action_status")]
+pub enum EnumAdminPendingActionStatus {
+    ///
+    #[postgres(name = "Pending")]
+    Pending = 0,
+    ///
+    #[postgres(name = "Approved")]
+    Approved = 1,
+    ///
+    #[postgres(name = "Executed")]
+    Executed = 2,
+    ///
+    #[postgres(name = "Failed")]
+    Failed = 3,
+}
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    ToSql,
+    FromSql,
+    Serialize,
+    Deserialize,
+    FromPrimitive,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    EnumString,
+    Display,
+    Hash,
+)]
+#[postgres(name = "enum_expert_application_status")]
+pub enum EnumExpertApplicationStatus {
+    ///
+    #[postgres(name = "Draft")]
+    Draft = 0,
+    ///
+    #[postgres(name = "Submitted")]
+    Submitted = 1,
+    ///
+    #[postgres(name = "InfoRequested")]
+    InfoRequested = 2,
+    ///
+    #[postgres(name = "Approved")]
+    Approved = 3,
+    ///
+    #[postgres(name = "Rejected")]
+    Rejected = 4,
+}
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    ToSql,
+    FromSql,
+    Serialize,
+    Deserialize,
+    FromPrimitive,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    EnumString,
+    Display,
+    Hash,
+)]
+#[postgres(name = "enum_expert_sort_by")]
+pub enum EnumExpertSortBy {
+    ///
+    #[postgres(name = "Newest")]
+    Newest = 0,
+    ///
+    #[postgres(name = "Followers")]
+    Followers = 1,
+    ///
+    #[postgres(name = "Reputation")]
+    Reputation = 2,
+    ///
+    #[postgres(name = "Consistency")]
+    Consistency = 3,
+}
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    ToSql,
+    FromSql,
+    Serialize,
+    Deserialize,
+    FromPrimitive,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    EnumString,
+    Display,
+    Hash,
+)]
+#[postgres(name = "enum_strategy_version_status")]
+pub enum EnumStrategyVersionStatus {
+    ///
+    #[postgres(name = "Scheduled")]
+    Scheduled = 0,
+    ///
+    #[postgres(name = "Applied")]
+    Applied = 1,
+    ///
+    #[postgres(name = "Failed")]
+    Failed = 2,
+    ///
+    #[postgres(name = "Applying")]
+    Applying = 3,
+}
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    ToSql,
+    FromSql,
+    Serialize,
+    Deserialize,
+    FromPrimitive,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    EnumString,
+    Display,
+    Hash,
+)]
 #[postgres(name = "enum_service")]
 pub enum EnumService {
     ///
@@ -275,6 +578,15 @@ pub enum EnumEndpoint {
     #[postgres(name = "ChangeLoginWallet")]
     ChangeLoginWallet = 10050,
     ///
+    #[postgres(name = "GetLoginNonce")]
+    GetLoginNonce = 10060,
+    ///
+    #[postgres(name = "RefreshToken")]
+    RefreshToken = 10070,
+    ///
+    #[postgres(name = "AuthorizeApiKey")]
+    AuthorizeApiKey = 10080,
+    ///
     #[postgres(name = "UserFollowStrategy")]
     UserFollowStrategy = 20040,
     ///
@@ -323,6 +635,9 @@ pub enum EnumEndpoint {
     #[postgres(name = "UserRequestRefund")]
     UserRequestRefund = 20081,
     ///
+    #[postgres(name = "UserRequestWithdraw")]
+    UserRequestWithdraw = 20082,
+    ///
     #[postgres(name = "UserListBackedStrategies")]
     UserListBackedStrategies = 20090,
     ///
@@ -377,6 +692,18 @@ pub enum EnumEndpoint {
     #[postgres(name = "UserApplyBecomeExpert")]
     UserApplyBecomeExpert = 20220,
     ///
+    #[postgres(name = "UserSaveExpertApplication")]
+    UserSaveExpertApplication = 20221,
+    ///
+    #[postgres(name = "UserUploadExpertApplicationDocument")]
+    UserUploadExpertApplicationDocument = 20222,
+    ///
+    #[postgres(name = "UserDeleteExpertApplicationDocument")]
+    UserDeleteExpertApplicationDocument = 20223,
+    ///
+    #[postgres(name = "UserGetExpertApplication")]
+    UserGetExpertApplication = 20224,
+    ///
     #[postgres(name = "ExpertCreateStrategy")]
     ExpertCreateStrategy = 20250,
     ///
@@ -407,6 +734,9 @@ pub enum EnumEndpoint {
     #[postgres(name = "UserListStrategyInitialTokenRatio")]
     UserListStrategyInitialTokenRatio = 20330,
     ///
+    #[postgres(name = "UserListStrategyVersions")]
+    UserListStrategyVersions = 20335,
+    ///
     #[postgres(name = "ExpertListFollowers")]
     ExpertListFollowers = 20340,
     ///
@@ -479,6 +809,66 @@ pub enum EnumEndpoint {
     #[postgres(name = "UserListUserStrategyBalance")]
     UserListUserStrategyBalance = 20580,
     ///
+    #[postgres(name = "UserExportActivityReport")]
+    UserExportActivityReport = 20590,
+    ///
+    #[postgres(name = "ExpertAddStrategyGuardRule")]
+    ExpertAddStrategyGuardRule = 20600,
+    ///
+    #[postgres(name = "ExpertRemoveStrategyGuardRule")]
+    ExpertRemoveStrategyGuardRule = 20610,
+    ///
+    #[postgres(name = "UserListStrategyGuardRules")]
+    UserListStrategyGuardRules = 20620,
+    ///
+    #[postgres(name = "ExpertResumeStrategyCopyTrading")]
+    ExpertResumeStrategyCopyTrading = 20630,
+    ///
+    #[postgres(name = "UserSubscribeStrategyGuardEvents")]
+    UserSubscribeStrategyGuardEvents = 20640,
+    ///
+    #[postgres(name = "UserUnsubscribeStrategyGuardEvents")]
+    UserUnsubscribeStrategyGuardEvents = 20641,
+    ///
+    #[postgres(name = "UserListNotifications")]
+    UserListNotifications = 20650,
+    ///
+    #[postgres(name = "UserMarkNotificationsRead")]
+    UserMarkNotificationsRead = 20660,
+    ///
+    #[postgres(name = "UserSubscribeNotifications")]
+    UserSubscribeNotifications = 20670,
+    ///
+    #[postgres(name = "UserUnsubscribeNotifications")]
+    UserUnsubscribeNotifications = 20671,
+    ///
+    #[postgres(name = "UserListSessions")]
+    UserListSessions = 20680,
+    ///
+    #[postgres(name = "UserRevokeSession")]
+    UserRevokeSession = 20690,
+    ///
+    #[postgres(name = "UserCreateApiKey")]
+    UserCreateApiKey = 20700,
+    ///
+    #[postgres(name = "UserListApiKeys")]
+    UserListApiKeys = 20710,
+    ///
+    #[postgres(name = "UserRevokeApiKey")]
+    UserRevokeApiKey = 20720,
+    ///
+    #[postgres(name = "UserListLoginWallets")]
+    UserListLoginWallets = 20730,
+    ///
+    #[postgres(name = "UserLinkLoginWallet")]
+    UserLinkLoginWallet = 20740,
+    ///
+    #[postgres(name = "UserUnlinkLoginWallet")]
+    UserUnlinkLoginWallet = 20750,
+    ///
+    #[postgres(name = "UserSetPrimaryLoginWallet")]
+    UserSetPrimaryLoginWallet = 20760,
+    ///
     #[postgres(name = "AdminListUsers")]
     AdminListUsers = 30010,
     ///
@@ -494,6 +884,15 @@ pub enum EnumEndpoint {
     #[postgres(name = "AdminApproveUserBecomeExpert")]
     AdminApproveUserBecomeExpert = 30040,
     ///
+    #[postgres(name = "AdminGetExpertApplication")]
+    AdminGetExpertApplication = 30041,
+    ///
+    #[postgres(name = "AdminGetExpertApplicationDocument")]
+    AdminGetExpertApplicationDocument = 30042,
+    ///
+    #[postgres(name = "AdminRequestExpertApplicationInfo")]
+    AdminRequestExpertApplicationInfo = 30045,
+    ///
     #[postgres(name = "AdminRejectUserBecomeExpert")]
     AdminRejectUserBecomeExpert = 30050,
     ///
@@ -503,6 +902,9 @@ pub enum EnumEndpoint {
     #[postgres(name = "AdminUpdateSystemConfig")]
     AdminUpdateSystemConfig = 30080,
     ///
+    #[postgres(name = "AdminListSystemSettingHistory")]
+    AdminListSystemSettingHistory = 30085,
+    ///
     #[postgres(name = "AdminListExperts")]
     AdminListExperts = 30090,
     ///
@@ -553,6 +955,15 @@ pub enum EnumEndpoint {
     ///
     #[postgres(name = "AdminUpdateEscrowTokenContractAddress")]
     AdminUpdateEscrowTokenContractAddress = 32080,
+    ///
+    #[postgres(name = "AdminListPendingActions")]
+    AdminListPendingActions = 33010,
+    ///
+    #[postgres(name = "AdminApprovePendingAction")]
+    AdminApprovePendingAction = 33020,
+    ///
+    #[postgres(name = "AdminListActionLog")]
+    AdminListActionLog = 33030,
 }
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -568,6 +979,9 @@ pub struct ErrorNotImplemented {}
 pub struct ErrorNotFound {}
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
+pub struct ErrorTooManyRequests {}
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct ErrorDatabaseError {}
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -661,6 +1075,18 @@ pub struct ErrorBankAccountAlreadyExists {}
 pub struct ErrorInsufficientFunds {}
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
+pub struct ErrorUnknownLoginNonce {}
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorExpiredLoginNonce {}
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorUsedLoginNonce {}
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorTokenExpired {}
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct ErrorLogicalError {}
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -670,6 +1096,12 @@ pub struct ErrorRestrictedUserPrivileges {}
 pub struct ErrorIdenticalReplacement {}
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
+pub struct ErrorRefreshTokenReused {}
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorInsufficientApiKeyScope {}
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct ErrorInvalidRecoveryQuestions {}
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -706,15 +1138,36 @@ pub struct ErrorMustSubmitAllRecoveryQuestions {}
 pub struct ErrorInvalidRecoveryToken {}
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
-pub struct ErrorRoutingError {}
+pub struct ErrorIpNotAllowed {}
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorActionPendingApproval {}
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorUnknownPendingAction {}
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorSelfApproval {}
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorSettingVersionConflict {}
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorRoutingError {}
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct ErrorUnauthorizedMessage {}
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
+pub struct ErrorInvalidExpertApplicationState {}
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct ErrorAuthError {}
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
+pub struct ErrorStrategyChangeScheduled {}
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct ErrorInternalError {}
 #[derive(
     Debug,
@@ -747,6 +1200,9 @@ pub enum EnumErrorCode {
     /// Custom NotFoundResource
     #[postgres(name = "NotFound")]
     NotFound = 100404,
+    /// Custom Too many requests
+    #[postgres(name = "TooManyRequests")]
+    TooManyRequests = 100429,
     /// Custom Database error
     #[postgres(name = "DatabaseError")]
     DatabaseError = 100601,
@@ -840,6 +1296,18 @@ pub enum EnumErrorCode {
     /// SQL R000H InsufficientFunds
     #[postgres(name = "InsufficientFunds")]
     InsufficientFunds = 45349649,
+    /// SQL R000I UnknownLoginNonce
+    #[postgres(name = "UnknownLoginNonce")]
+    UnknownLoginNonce = 45349650,
+    /// SQL R000J ExpiredLoginNonce
+    #[postgres(name = "ExpiredLoginNonce")]
+    ExpiredLoginNonce = 45349651,
+    /// SQL R000K UsedLoginNonce
+    #[postgres(name = "UsedLoginNonce")]
+    UsedLoginNonce = 45349652,
+    /// SQL R000L TokenExpired
+    #[postgres(name = "TokenExpired")]
+    TokenExpired = 45349653,
     /// SQL R000M LogicalError
     #[postgres(name = "LogicalError")]
     LogicalError = 45349654,
@@ -849,6 +1317,12 @@ pub enum EnumErrorCode {
     /// SQL R000O IdenticalReplacement
     #[postgres(name = "IdenticalReplacement")]
     IdenticalReplacement = 45349656,
+    /// SQL R000P RefreshTokenReused
+    #[postgres(name = "RefreshTokenReused")]
+    RefreshTokenReused = 45349657,
+    /// SQL R000Q InsufficientApiKeyScope
+    #[postgres(name = "InsufficientApiKeyScope")]
+    InsufficientApiKeyScope = 45349658,
     /// SQL R000R InvalidRecoveryQuestions
     #[postgres(name = "InvalidRecoveryQuestions")]
     InvalidRecoveryQuestions = 45349659,
@@ -885,15 +1359,36 @@ pub enum EnumErrorCode {
     /// SQL R0012 InvalidRecoveryToken
     #[postgres(name = "InvalidRecoveryToken")]
     InvalidRecoveryToken = 45349670,
+    /// SQL R0013 IpNotAllowed
+    #[postgres(name = "IpNotAllowed")]
+    IpNotAllowed = 45349671,
+    /// SQL R0014 ActionPendingApproval
+    #[postgres(name = "ActionPendingApproval")]
+    ActionPendingApproval = 45349672,
+    /// SQL R0015 UnknownPendingAction
+    #[postgres(name = "UnknownPendingAction")]
+    UnknownPendingAction = 45349673,
+    /// SQL R0016 SelfApproval
+    #[postgres(name = "SelfApproval")]
+    SelfApproval = 45349674,
+    /// SQL R0017 SettingVersionConflict
+    #[postgres(name = "SettingVersionConflict")]
+    SettingVersionConflict = 45349675,
     /// SQL R0018 RoutingError
     #[postgres(name = "RoutingError")]
     RoutingError = 45349676,
     /// SQL R0019 UnauthorizedMessage
     #[postgres(name = "UnauthorizedMessage")]
     UnauthorizedMessage = 45349677,
+    /// SQL R001A InvalidExpertApplicationState
+    #[postgres(name = "InvalidExpertApplicationState")]
+    InvalidExpertApplicationState = 45349678,
     /// SQL R001B AuthError
     #[postgres(name = "AuthError")]
     AuthError = 45349679,
+    /// SQL R001C StrategyChangeScheduled
+    #[postgres(name = "StrategyChangeScheduled")]
+    StrategyChangeScheduled = 45349680,
     /// SQL R001G InternalError
     #[postgres(name = "InternalError")]
     InternalError = 45349684,
@@ -905,6 +1400,23 @@ impl Into<ErrorCode> for EnumErrorCode {
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AdminActionLogRow {
+    pub action_log_id: i64,
+    pub admin_user_id: i64,
+    #[serde(default)]
+    pub pending_action_id: Option<i64>,
+    #[serde(default)]
+    pub requested_by_user_id: Option<i64>,
+    pub method: String,
+    pub params: serde_json::Value,
+    #[serde(default)]
+    pub state_before: Option<serde_json::Value>,
+    #[serde(default)]
+    pub state_after: Option<serde_json::Value>,
+    pub created_at: i64,
+}
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct AdminAddAuditRuleRequest {
@@ -945,6 +1457,16 @@ pub struct AdminAddEscrowTokenContractAddressRequest {
 pub struct AdminAddEscrowTokenContractAddressResponse {}
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+pub struct AdminApprovePendingActionRequest {
+    pub pending_action_id: i64,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AdminApprovePendingActionResponse {
+    pub success: bool,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct AdminApproveStrategyRequest {
     pub strategy_id: i64,
 }
@@ -957,6 +1479,8 @@ pub struct AdminApproveStrategyResponse {
 #[serde(rename_all = "camelCase")]
 pub struct AdminApproveUserBecomeExpertRequest {
     pub user_id: i64,
+    #[serde(default)]
+    pub note: Option<String>,
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -1006,6 +1530,36 @@ pub struct AdminExitStrategyLedgerRow {
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+pub struct AdminGetExpertApplicationDocumentRequest {
+    pub document_id: i64,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AdminGetExpertApplicationDocumentResponse {
+    pub name: String,
+    pub content_type: String,
+    pub content: String,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AdminGetExpertApplicationRequest {
+    pub user_id: i64,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AdminGetExpertApplicationResponse {
+    pub application_id: i64,
+    pub status: EnumExpertApplicationStatus,
+    pub questionnaire: serde_json::Value,
+    #[serde(default)]
+    pub submitted_at: Option<i64>,
+    #[serde(default)]
+    pub reviewed_at: Option<i64>,
+    pub documents: Vec<ExpertApplicationDocumentRow>,
+    pub notes: Vec<ExpertApplicationNoteRow>,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct AdminGetSystemConfigRequest {}
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -1020,6 +1574,25 @@ pub struct AdminGetSystemConfigResponse {
     pub escrow_contract_address_bsc: Address,
     #[serde(with = "WithBlockchainAddress")]
     pub escrow_contract_address_bsc_testnet: Address,
+    pub settings: Vec<AdminSystemSettingRow>,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AdminListActionLogRequest {
+    #[serde(default)]
+    pub limit: Option<i64>,
+    #[serde(default)]
+    pub offset: Option<i64>,
+    #[serde(default)]
+    pub admin_user_id: Option<i64>,
+    #[serde(default)]
+    pub method: Option<String>,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AdminListActionLogResponse {
+    pub action_log_total: i64,
+    pub action_log: Vec<AdminActionLogRow>,
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -1141,6 +1714,22 @@ pub struct AdminListExpertsResponse {
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+pub struct AdminListPendingActionsRequest {
+    #[serde(default)]
+    pub limit: Option<i64>,
+    #[serde(default)]
+    pub offset: Option<i64>,
+    #[serde(default)]
+    pub status: Option<EnumAdminPendingActionStatus>,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AdminListPendingActionsResponse {
+    pub pending_actions_total: i64,
+    pub pending_actions: Vec<AdminPendingActionRow>,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct AdminListPendingExpertApplicationsRequest {
     #[serde(default)]
     pub offset: Option<i64>,
@@ -1183,6 +1772,22 @@ pub struct AdminListStrategiesResponse {
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+pub struct AdminListSystemSettingHistoryRequest {
+    #[serde(default)]
+    pub limit: Option<i64>,
+    #[serde(default)]
+    pub offset: Option<i64>,
+    #[serde(default)]
+    pub key: Option<String>,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AdminListSystemSettingHistoryResponse {
+    pub history_total: i64,
+    pub history: Vec<AdminSystemSettingHistoryRow>,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct AdminListUsersRequest {
     pub limit: i64,
     pub offset: i64,
@@ -1215,6 +1820,20 @@ pub struct AdminNotifyEscrowLedgerChangeRequest {
 pub struct AdminNotifyEscrowLedgerChangeResponse {}
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+pub struct AdminPendingActionRow {
+    pub pending_action_id: i64,
+    pub requested_by_user_id: i64,
+    pub method: String,
+    pub params: serde_json::Value,
+    pub status: EnumAdminPendingActionStatus,
+    #[serde(default)]
+    pub approved_by_user_id: Option<i64>,
+    #[serde(default)]
+    pub approved_at: Option<i64>,
+    pub created_at: i64,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct AdminRefreshExpertWalletBalanceRequest {
     pub strategy_id: i64,
 }
@@ -1237,6 +1856,7 @@ pub struct AdminRejectStrategyResponse {
 #[serde(rename_all = "camelCase")]
 pub struct AdminRejectUserBecomeExpertRequest {
     pub user_id: i64,
+    pub reason: String,
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -1245,6 +1865,17 @@ pub struct AdminRejectUserBecomeExpertResponse {
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+pub struct AdminRequestExpertApplicationInfoRequest {
+    pub user_id: i64,
+    pub note: String,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AdminRequestExpertApplicationInfoResponse {
+    pub success: bool,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct AdminSetBlockUserRequest {
     pub user_id: i64,
     pub blocked: bool,
@@ -1284,6 +1915,35 @@ pub struct AdminSubscribeDepositLedgerRequest {
 pub struct AdminSubscribeDepositLedgerResponse {}
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+pub struct AdminSystemSettingHistoryRow {
+    pub key: String,
+    pub value: serde_json::Value,
+    pub version: i64,
+    #[serde(default)]
+    pub updated_by_user_id: Option<i64>,
+    pub created_at: i64,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AdminSystemSettingRow {
+    pub key: String,
+    pub value: serde_json::Value,
+    pub version: i64,
+    #[serde(default)]
+    pub updated_by_user_id: Option<i64>,
+    #[serde(default)]
+    pub updated_at: Option<i64>,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AdminSystemSettingUpdate {
+    pub key: String,
+    pub value: serde_json::Value,
+    #[serde(default)]
+    pub version: Option<i64>,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct AdminUnsubscribeDepositLedgerRequest {}
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -1321,6 +1981,8 @@ pub struct AdminUpdateSystemConfigRequest {
     pub escrow_contract_address_bsc: Option<Address>,
     #[serde(default)]
     pub escrow_contract_address_bsc_testnet: Option<Address>,
+    #[serde(default)]
+    pub settings: Option<Vec<AdminSystemSettingUpdate>>,
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -1329,6 +1991,20 @@ pub struct AdminUpdateSystemConfigResponse {
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+pub struct AuthorizeApiKeyRequest {
+    pub api_key: String,
+    pub timestamp: i64,
+    pub signature: String,
+    pub service: EnumService,
+    pub nonce: String,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorizeApiKeyResponse {
+    pub success: bool,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct AuthorizeRequest {
     #[serde(with = "WithBlockchainAddress")]
     pub address: Address,
@@ -1401,6 +2077,22 @@ pub struct ExitStrategyLedgerRow {
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+pub struct ExpertAddStrategyGuardRuleRequest {
+    pub strategy_id: i64,
+    pub rule_type: EnumStrategyGuardRuleType,
+    #[serde(default)]
+    pub token_id: Option<i64>,
+    pub threshold: f64,
+    #[serde(default)]
+    pub exit_token_id: Option<i64>,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpertAddStrategyGuardRuleResponse {
+    pub rule_id: i64,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct ExpertAddStrategyInitialTokenRatioRequest {
     pub strategy_id: i64,
     pub token_id: i64,
@@ -1430,6 +2122,24 @@ pub struct ExpertAddStrategyWatchingWalletResponse {
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+pub struct ExpertApplicationDocumentRow {
+    pub document_id: i64,
+    pub name: String,
+    pub content_type: String,
+    pub size: i64,
+    pub created_at: i64,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpertApplicationNoteRow {
+    pub status: EnumExpertApplicationStatus,
+    #[serde(default)]
+    pub note: Option<String>,
+    pub from_reviewer: bool,
+    pub created_at: i64,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct ExpertCreateStrategyRequest {
     pub name: String,
     pub description: String,
@@ -1604,6 +2314,17 @@ pub struct ExpertListenedWalletTradeLedgerRow {
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+pub struct ExpertRemoveStrategyGuardRuleRequest {
+    pub strategy_id: i64,
+    pub rule_id: i64,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpertRemoveStrategyGuardRuleResponse {
+    pub success: bool,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct ExpertRemoveStrategyInitialTokenRatioRequest {
     pub strategy_id: i64,
     pub token_id: i64,
@@ -1626,6 +2347,16 @@ pub struct ExpertRemoveStrategyWatchingWalletResponse {
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+pub struct ExpertResumeStrategyCopyTradingRequest {
+    pub strategy_id: i64,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpertResumeStrategyCopyTradingResponse {
+    pub success: bool,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct ExpertUpdateStrategyRequest {
     pub strategy_id: i64,
     #[serde(default)]
@@ -1634,6 +2365,20 @@ pub struct ExpertUpdateStrategyRequest {
     pub description: Option<String>,
     #[serde(default)]
     pub social_media: Option<String>,
+    #[serde(default)]
+    pub rebalance_interval_seconds: Option<i64>,
+    #[serde(default)]
+    pub rebalance_drift_threshold: Option<f64>,
+    #[serde(default)]
+    pub copy_trade_min_usd: Option<f64>,
+    #[serde(default)]
+    pub copy_trade_max_aum_ratio: Option<f64>,
+    #[serde(default)]
+    pub copy_trade_cooldown_seconds: Option<i64>,
+    #[serde(default)]
+    pub copy_trade_batch_window_seconds: Option<i64>,
+    #[serde(default)]
+    pub expert_fee: Option<f64>,
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -1648,36 +2393,20 @@ pub struct FollowLedgerPoint {
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct ListExpertsRow {
-    pub expert_id: i64,
+pub struct GetLoginNonceRequest {
     #[serde(with = "WithBlockchainAddress")]
-    pub linked_wallet: Address,
-    pub name: String,
-    #[serde(default)]
-    pub family_name: Option<String>,
-    #[serde(default)]
-    pub given_name: Option<String>,
-    pub follower_count: i64,
-    pub backer_count: i64,
-    pub strategy_count: i64,
-    pub description: String,
-    pub social_media: String,
-    pub risk_score: f64,
-    pub reputation_score: f64,
-    pub consistent_score: f64,
-    pub aum: f64,
-    pub joined_at: i64,
-    pub requested_at: i64,
-    #[serde(default)]
-    pub approved_at: Option<i64>,
-    pub pending_expert: bool,
-    pub approved_expert: bool,
-    pub followed: bool,
+    pub address: Address,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetLoginNonceResponse {
+    pub nonce: String,
+    pub expires_at: i64,
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct ListWhitelistsRow {
-    pub whitelist_id: i64,
+pub struct ListExpertsRow {
+    pub expert_id: i64,
     #[serde(with = "WithBlockchainAddress")]
     pub linked_wallet: Address,
     pub name: String,
@@ -1774,6 +2503,26 @@ pub struct ListStrategyFollowersRow {
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+pub struct ListStrategyGuardRulesRow {
+    pub rule_id: i64,
+    pub rule_type: EnumStrategyGuardRuleType,
+    #[serde(default)]
+    pub token_id: Option<i64>,
+    #[serde(default)]
+    pub token_symbol: Option<String>,
+    pub threshold: f64,
+    #[serde(default)]
+    pub reference_value: Option<f64>,
+    #[serde(default)]
+    pub exit_token_id: Option<i64>,
+    #[serde(default)]
+    pub exit_token_symbol: Option<String>,
+    #[serde(default)]
+    pub triggered_at: Option<i64>,
+    pub created_at: i64,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct ListStrategyInitialTokenRatioRow {
     pub token_id: i64,
     pub token_name: String,
@@ -1786,6 +2535,21 @@ pub struct ListStrategyInitialTokenRatioRow {
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+pub struct ListStrategyVersionsRow {
+    pub version: i64,
+    pub change: String,
+    pub params: serde_json::Value,
+    #[serde(default)]
+    pub diff: Option<serde_json::Value>,
+    pub is_material: bool,
+    pub status: EnumStrategyVersionStatus,
+    pub effective_at: i64,
+    #[serde(default)]
+    pub applied_at: Option<i64>,
+    pub created_at: i64,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct ListStrategyWatchingWalletsRow {
     pub wallet_id: i64,
     pub blockchain: EnumBlockChain,
@@ -1803,7 +2567,8 @@ pub struct ListUserRow {
     #[serde(with = "WithBlockchainAddress")]
     pub address: Address,
     pub last_ip: std::net::IpAddr,
-    pub last_login_at: i64,
+    #[serde(default)]
+    pub last_login_at: Option<i64>,
     pub login_count: i32,
     pub role: EnumRole,
     #[serde(default)]
@@ -1869,12 +2634,23 @@ pub struct LoginResponse {
     pub avatar: Option<String>,
     pub role: EnumRole,
     pub user_id: i64,
-    pub user_token: uuid::Uuid,
-    pub admin_token: uuid::Uuid,
+    #[serde(default)]
+    pub user_token: Option<uuid::Uuid>,
+    #[serde(default)]
+    pub admin_token: Option<uuid::Uuid>,
+    pub expires_at: i64,
+    pub refresh_token: uuid::Uuid,
+    pub refresh_expires_at: i64,
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct LogoutRequest {}
+pub struct LogoutRequest {
+    #[serde(with = "WithBlockchainAddress")]
+    pub address: Address,
+    pub token: uuid::Uuid,
+    pub service: EnumService,
+    pub device_id: String,
+}
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct LogoutResponse {}
@@ -1886,6 +2662,23 @@ pub struct NetValuePoint {
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+pub struct RefreshTokenRequest {
+    #[serde(with = "WithBlockchainAddress")]
+    pub address: Address,
+    pub refresh_token: uuid::Uuid,
+    pub service: EnumService,
+    pub device_id: String,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RefreshTokenResponse {
+    pub token: uuid::Uuid,
+    pub expires_at: i64,
+    pub refresh_token: uuid::Uuid,
+    pub refresh_expires_at: i64,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct SignupRequest {
     #[serde(with = "WithBlockchainAddress")]
     pub address: Address,
@@ -1906,6 +2699,20 @@ pub struct SignupResponse {
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+pub struct StrategyGuardEvent {
+    pub strategy_id: i64,
+    pub rule_id: i64,
+    pub blockchain: EnumBlockChain,
+    pub rule_type: EnumStrategyGuardRuleType,
+    pub threshold: f64,
+    pub reference_value: f64,
+    pub current_value: f64,
+    #[serde(default)]
+    pub exit_token_address: Option<Address>,
+    pub triggered_at: i64,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct StrategyPoolAssetBalancesRow {
     pub name: String,
     pub symbol: String,
@@ -1950,6 +2757,28 @@ pub struct StrategyPoolContractAssetLedgerRow {
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+pub struct UserActivityReportRow {
+    pub event_type: EnumUserActivityEventType,
+    pub blockchain: EnumBlockChain,
+    #[serde(default)]
+    pub strategy_id: Option<i64>,
+    #[serde(default)]
+    pub transaction_hash: Option<H256>,
+    pub token_symbol: String,
+    #[serde(default)]
+    pub token_address: Option<Address>,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub quantity: Decimal,
+    #[serde(default)]
+    pub usd_price: Option<f64>,
+    #[serde(default)]
+    pub usd_value: Option<f64>,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub fees: Decimal,
+    pub happened_at: i64,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct UserAddStrategyAuditRuleRequest {
     pub strategy_id: i64,
     pub rule_id: i64,
@@ -1971,7 +2800,22 @@ pub struct UserAllowedEscrowTransferInfo {
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct UserApplyBecomeExpertRequest {}
+pub struct UserApiKeyRow {
+    pub api_key_id: i64,
+    pub name: String,
+    pub api_key: String,
+    pub scopes: Vec<EnumApiKeyScope>,
+    #[serde(default)]
+    pub ip_allowlist: Option<Vec<std::net::IpAddr>>,
+    #[serde(default)]
+    pub expires_at: Option<i64>,
+    pub created_at: i64,
+    #[serde(default)]
+    pub last_used_at: Option<i64>,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserApplyBecomeExpertRequest {}
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct UserApplyBecomeExpertResponse {
@@ -2022,6 +2866,23 @@ pub struct UserBackStrategyStreamResponse {
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+pub struct UserCreateApiKeyRequest {
+    pub name: String,
+    pub scopes: Vec<EnumApiKeyScope>,
+    #[serde(default)]
+    pub ip_allowlist: Option<Vec<std::net::IpAddr>>,
+    #[serde(default)]
+    pub expires_at: Option<i64>,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserCreateApiKeyResponse {
+    pub api_key_id: i64,
+    pub api_key: String,
+    pub api_secret: String,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct UserCreateStrategyInitialTokenRow {
     pub token_id: i64,
     #[serde(with = "rust_decimal::serde::str")]
@@ -2043,6 +2904,16 @@ pub struct UserCreateStrategyWalletResponse {
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+pub struct UserDeleteExpertApplicationDocumentRequest {
+    pub document_id: i64,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserDeleteExpertApplicationDocumentResponse {
+    pub success: bool,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct UserExitStrategyRequest {
     pub strategy_id: i64,
     #[serde(with = "rust_decimal::serde::str")]
@@ -2059,6 +2930,29 @@ pub struct UserExitStrategyResponse {
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+pub struct UserExportActivityReportRequest {
+    pub start_time: i64,
+    pub end_time: i64,
+    #[serde(default)]
+    pub blockchain: Option<EnumBlockChain>,
+    #[serde(default)]
+    pub format: Option<EnumActivityReportFormat>,
+    #[serde(default)]
+    pub limit: Option<i64>,
+    #[serde(default)]
+    pub offset: Option<i64>,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserExportActivityReportResponse {
+    pub report_total: i64,
+    pub report: Vec<UserActivityReportRow>,
+    #[serde(default)]
+    pub csv: Option<String>,
+    pub truncated: bool,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct UserFollowExpertRequest {
     pub expert_id: i64,
 }
@@ -2160,6 +3054,23 @@ pub struct UserGetEscrowAddressForStrategyResponse {
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+pub struct UserGetExpertApplicationRequest {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserGetExpertApplicationResponse {
+    #[serde(default)]
+    pub application_id: Option<i64>,
+    pub status: EnumExpertApplicationStatus,
+    pub questionnaire: serde_json::Value,
+    #[serde(default)]
+    pub submitted_at: Option<i64>,
+    #[serde(default)]
+    pub reviewed_at: Option<i64>,
+    pub documents: Vec<ExpertApplicationDocumentRow>,
+    pub notes: Vec<ExpertApplicationNoteRow>,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct UserGetExpertProfileRequest {
     pub expert_id: i64,
 }
@@ -2174,6 +3085,7 @@ pub struct UserGetExpertProfileResponse {
     pub backers_count: i32,
     pub description: String,
     pub social_media: String,
+    pub consistent_score: f64,
     pub risk_score: f64,
     pub reputation_score: f64,
     pub aum: f64,
@@ -2279,6 +3191,25 @@ pub struct UserGetUserProfileResponse {
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+pub struct UserLinkLoginWalletRequest {
+    #[serde(with = "WithBlockchainAddress")]
+    pub address: Address,
+    pub signature_text: String,
+    pub signature: String,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserLinkLoginWalletResponse {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserListApiKeysRequest {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserListApiKeysResponse {
+    pub api_keys: Vec<UserApiKeyRow>,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct UserListBackStrategyLedgerRequest {
     #[serde(default)]
     pub limit: Option<i64>,
@@ -2455,12 +3386,6 @@ pub struct UserListExpertsResponse {
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct UserListWhitelistsResponse {
-    pub whiteilsts_total: i64,
-    pub whiteilsts: Vec<ListWhitelistsRow>,
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
 pub struct UserListFeaturedExpertsRequest {
     #[serde(default)]
     pub limit: Option<i64>,
@@ -2503,6 +3428,39 @@ pub struct UserListFollowedStrategiesResponse {
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+pub struct UserListLoginWalletsRequest {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserListLoginWalletsResponse {
+    pub wallets: Vec<UserLoginWalletRow>,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserListNotificationsRequest {
+    #[serde(default)]
+    pub unread_only: Option<bool>,
+    #[serde(default)]
+    pub limit: Option<i64>,
+    #[serde(default)]
+    pub offset: Option<i64>,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserListNotificationsResponse {
+    pub notifications_total: i64,
+    pub unread_total: i64,
+    pub notifications: Vec<UserNotificationRow>,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserListSessionsRequest {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserListSessionsResponse {
+    pub sessions: Vec<UserSessionRow>,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct UserListStrategiesRequest {
     #[serde(default)]
     pub limit: Option<i64>,
@@ -2581,6 +3539,17 @@ pub struct UserListStrategyFollowersResponse {
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+pub struct UserListStrategyGuardRulesRequest {
+    pub strategy_id: i64,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserListStrategyGuardRulesResponse {
+    pub rules_total: i64,
+    pub rules: Vec<ListStrategyGuardRulesRow>,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct UserListStrategyInitialTokenRatioRequest {
     pub strategy_id: i64,
 }
@@ -2634,6 +3603,21 @@ pub struct UserListStrategyTokenBalanceRow {
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+pub struct UserListStrategyVersionsRequest {
+    pub strategy_id: i64,
+    #[serde(default)]
+    pub limit: Option<i64>,
+    #[serde(default)]
+    pub offset: Option<i64>,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserListStrategyVersionsResponse {
+    pub versions_total: i64,
+    pub versions: Vec<ListStrategyVersionsRow>,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct UserListStrategyWalletsRequest {
     #[serde(default)]
     pub blockchain: Option<EnumBlockChain>,
@@ -2793,6 +3777,49 @@ pub struct UserListWhitelistedWalletsResponse {
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+pub struct UserLoginWalletRow {
+    #[serde(with = "WithBlockchainAddress")]
+    pub address: Address,
+    pub is_primary: bool,
+    pub created_at: i64,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserMarkNotificationsReadRequest {
+    #[serde(default)]
+    pub notification_ids: Option<Vec<i64>>,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserMarkNotificationsReadResponse {
+    pub marked: i64,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserNotification {
+    pub notification_id: i64,
+    pub kind: EnumUserNotificationKind,
+    #[serde(default)]
+    pub strategy_id: Option<i64>,
+    pub message: String,
+    #[serde(default)]
+    pub read_at: Option<i64>,
+    pub created_at: i64,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserNotificationRow {
+    pub notification_id: i64,
+    pub kind: EnumUserNotificationKind,
+    #[serde(default)]
+    pub strategy_id: Option<i64>,
+    pub message: String,
+    #[serde(default)]
+    pub read_at: Option<i64>,
+    pub created_at: i64,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct UserRemoveStrategyAuditRuleRequest {
     pub strategy_id: i64,
     pub rule_id: i64,
@@ -2817,6 +3844,72 @@ pub struct UserRequestRefundResponse {
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+pub struct UserRequestWithdrawRequest {
+    #[serde(with = "rust_decimal::serde::str")]
+    pub quantity: Decimal,
+    #[serde(with = "WithBlockchainAddress")]
+    pub wallet_address: Address,
+    pub blockchain: EnumBlockChain,
+    pub nonce: i64,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserRequestWithdrawResponse {
+    pub success: bool,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub quantity_received: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub fee: Decimal,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserRevokeApiKeyRequest {
+    pub api_key_id: i64,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserRevokeApiKeyResponse {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserRevokeSessionRequest {
+    pub session_id: i64,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserRevokeSessionResponse {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSaveExpertApplicationRequest {
+    pub questionnaire: serde_json::Value,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSaveExpertApplicationResponse {
+    pub application_id: i64,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSessionRow {
+    pub session_id: i64,
+    pub service: EnumService,
+    pub device_id: String,
+    pub device_os: String,
+    pub ip_address: std::net::IpAddr,
+    pub created_at: i64,
+    pub last_seen_at: i64,
+    pub expires_at: i64,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSetPrimaryLoginWalletRequest {
+    #[serde(with = "WithBlockchainAddress")]
+    pub address: Address,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSetPrimaryLoginWalletResponse {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct UserStrategyBalance {
     pub strategy_id: i64,
     pub strategy_name: String,
@@ -2869,6 +3962,18 @@ pub struct UserSubscribeDepositLedgerRequest {
 pub struct UserSubscribeDepositLedgerResponse {}
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+pub struct UserSubscribeNotificationsRequest {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSubscribeNotificationsResponse {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSubscribeStrategyGuardEventsRequest {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSubscribeStrategyGuardEventsResponse {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct UserUnfollowExpertRequest {
     pub expert_id: i64,
 }
@@ -2889,12 +3994,33 @@ pub struct UserUnfollowStrategyResponse {
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+pub struct UserUnlinkLoginWalletRequest {
+    #[serde(with = "WithBlockchainAddress")]
+    pub address: Address,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserUnlinkLoginWalletResponse {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct UserUnsubscribeDepositLedgerRequest {}
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct UserUnsubscribeDepositLedgerResponse {}
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+pub struct UserUnsubscribeNotificationsRequest {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserUnsubscribeNotificationsResponse {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserUnsubscribeStrategyGuardEventsRequest {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserUnsubscribeStrategyGuardEventsResponse {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct UserUnwhitelistWalletRequest {
     pub wallet_id: i64,
 }
@@ -2922,6 +4048,18 @@ pub struct UserUpdateUserProfileRequest {
 pub struct UserUpdateUserProfileResponse {}
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+pub struct UserUploadExpertApplicationDocumentRequest {
+    pub name: String,
+    pub content_type: String,
+    pub content: String,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserUploadExpertApplicationDocumentResponse {
+    pub document_id: i64,
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct UserWhitelistWalletRequest {
     pub blockchain: EnumBlockChain,
     #[serde(with = "WithBlockchainAddress")]
@@ -3003,16 +4141,65 @@ impl WsRequest for LoginRequest {
     },
     {
       "name": "user_token",
-      "ty": "UUID"
+      "ty": {
+        "Optional": "UUID"
+      }
     },
     {
       "name": "admin_token",
+      "ty": {
+        "Optional": "UUID"
+      }
+    },
+    {
+      "name": "expires_at",
+      "ty": "BigInt"
+    },
+    {
+      "name": "refresh_token",
       "ty": "UUID"
+    },
+    {
+      "name": "refresh_expires_at",
+      "ty": "BigInt"
     }
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "guest",
+      "value": 0,
+      "comment": ""
+    },
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for LoginResponse {
@@ -3071,7 +4258,40 @@ impl WsRequest for SignupRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "guest",
+      "value": 0,
+      "comment": ""
+    },
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for SignupResponse {
@@ -3116,24 +4336,109 @@ impl WsRequest for AuthorizeRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
-}"#;
-}
-impl WsResponse for AuthorizeResponse {
-    type Request = AuthorizeRequest;
-}
-
-impl WsRequest for LogoutRequest {
-    type Response = LogoutResponse;
-    const METHOD_ID: u32 = 10040;
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "guest",
+      "value": 0,
+      "comment": ""
+    },
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
+}"#;
+}
+impl WsResponse for AuthorizeResponse {
+    type Request = AuthorizeRequest;
+}
+
+impl WsRequest for LogoutRequest {
+    type Response = LogoutResponse;
+    const METHOD_ID: u32 = 10040;
     const SCHEMA: &'static str = r#"{
   "name": "Logout",
   "code": 10040,
-  "parameters": [],
+  "parameters": [
+    {
+      "name": "address",
+      "ty": "BlockchainAddress"
+    },
+    {
+      "name": "token",
+      "ty": "UUID"
+    },
+    {
+      "name": "service",
+      "ty": {
+        "EnumRef": "service"
+      }
+    },
+    {
+      "name": "device_id",
+      "ty": "String"
+    }
+  ],
   "returns": [],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "guest",
+      "value": 0,
+      "comment": ""
+    },
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for LogoutResponse {
@@ -3175,13 +4480,274 @@ impl WsRequest for ChangeLoginWalletRequest {
   "returns": [],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "guest",
+      "value": 0,
+      "comment": ""
+    },
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for ChangeLoginWalletResponse {
     type Request = ChangeLoginWalletRequest;
 }
 
+impl WsRequest for GetLoginNonceRequest {
+    type Response = GetLoginNonceResponse;
+    const METHOD_ID: u32 = 10060;
+    const SCHEMA: &'static str = r#"{
+  "name": "GetLoginNonce",
+  "code": 10060,
+  "parameters": [
+    {
+      "name": "address",
+      "ty": "BlockchainAddress"
+    }
+  ],
+  "returns": [
+    {
+      "name": "nonce",
+      "ty": "String"
+    },
+    {
+      "name": "expires_at",
+      "ty": "BigInt"
+    }
+  ],
+  "stream_response": null,
+  "description": "",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "guest",
+      "value": 0,
+      "comment": ""
+    },
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
+}"#;
+}
+impl WsResponse for GetLoginNonceResponse {
+    type Request = GetLoginNonceRequest;
+}
+
+impl WsRequest for RefreshTokenRequest {
+    type Response = RefreshTokenResponse;
+    const METHOD_ID: u32 = 10070;
+    const SCHEMA: &'static str = r#"{
+  "name": "RefreshToken",
+  "code": 10070,
+  "parameters": [
+    {
+      "name": "address",
+      "ty": "BlockchainAddress"
+    },
+    {
+      "name": "refresh_token",
+      "ty": "UUID"
+    },
+    {
+      "name": "service",
+      "ty": {
+        "EnumRef": "service"
+      }
+    },
+    {
+      "name": "device_id",
+      "ty": "String"
+    }
+  ],
+  "returns": [
+    {
+      "name": "token",
+      "ty": "UUID"
+    },
+    {
+      "name": "expires_at",
+      "ty": "BigInt"
+    },
+    {
+      "name": "refresh_token",
+      "ty": "UUID"
+    },
+    {
+      "name": "refresh_expires_at",
+      "ty": "BigInt"
+    }
+  ],
+  "stream_response": null,
+  "description": "",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "guest",
+      "value": 0,
+      "comment": ""
+    },
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
+}"#;
+}
+impl WsResponse for RefreshTokenResponse {
+    type Request = RefreshTokenRequest;
+}
+
+impl WsRequest for AuthorizeApiKeyRequest {
+    type Response = AuthorizeApiKeyResponse;
+    const METHOD_ID: u32 = 10080;
+    const SCHEMA: &'static str = r#"{
+  "name": "AuthorizeApiKey",
+  "code": 10080,
+  "parameters": [
+    {
+      "name": "api_key",
+      "ty": "String"
+    },
+    {
+      "name": "timestamp",
+      "ty": "BigInt"
+    },
+    {
+      "name": "signature",
+      "ty": "String"
+    },
+    {
+      "name": "service",
+      "ty": {
+        "EnumRef": "service"
+      }
+    },
+    {
+      "name": "nonce",
+      "ty": "String"
+    }
+  ],
+  "returns": [
+    {
+      "name": "success",
+      "ty": "Boolean"
+    }
+  ],
+  "stream_response": null,
+  "description": "",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "guest",
+      "value": 0,
+      "comment": ""
+    },
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
+}"#;
+}
+impl WsResponse for AuthorizeApiKeyResponse {
+    type Request = AuthorizeApiKeyRequest;
+}
+
 impl WsRequest for UserFollowStrategyRequest {
     type Response = UserFollowStrategyResponse;
     const METHOD_ID: u32 = 20040;
@@ -3202,7 +4768,35 @@ impl WsRequest for UserFollowStrategyRequest {
   ],
   "stream_response": null,
   "description": "User follows a strategy",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for UserFollowStrategyResponse {
@@ -3373,7 +4967,39 @@ impl WsRequest for UserListFollowedStrategiesRequest {
   ],
   "stream_response": null,
   "description": "User lists followed strategies",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
 impl WsResponse for UserListFollowedStrategiesResponse {
@@ -3400,7 +5026,35 @@ impl WsRequest for UserUnfollowStrategyRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for UserUnfollowStrategyResponse {
@@ -3615,7 +5269,39 @@ impl WsRequest for UserListStrategiesRequest {
   ],
   "stream_response": null,
   "description": "User lists strategies",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
 impl WsResponse for UserListStrategiesResponse {
@@ -3786,13 +5472,45 @@ impl WsRequest for UserListTopPerformingStrategiesRequest {
   ],
   "stream_response": null,
   "description": "User lists top performing strategies",
-  "json_schema": null
-}"#;
-}
-impl WsResponse for UserListTopPerformingStrategiesResponse {
-    type Request = UserListTopPerformingStrategiesRequest;
-}
-
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
+}"#;
+}
+impl WsResponse for UserListTopPerformingStrategiesResponse {
+    type Request = UserListTopPerformingStrategiesRequest;
+}
+
 impl WsRequest for UserListStrategyBackersRequest {
     type Response = UserListStrategyBackersResponse;
     const METHOD_ID: u32 = 20064;
@@ -3851,7 +5569,39 @@ impl WsRequest for UserListStrategyBackersRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
 impl WsResponse for UserListStrategyBackersResponse {
@@ -3916,7 +5666,39 @@ impl WsRequest for UserListStrategyFollowersRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
 impl WsResponse for UserListStrategyFollowersResponse {
@@ -4233,7 +6015,39 @@ impl WsRequest for UserGetStrategyRequest {
   ],
   "stream_response": null,
   "description": "User gets a strategy",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
 impl WsResponse for UserGetStrategyResponse {
@@ -4322,7 +6136,39 @@ impl WsRequest for UserListStrategyPoolContractAssetLedgerRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
 impl WsResponse for UserListStrategyPoolContractAssetLedgerResponse {
@@ -4427,7 +6273,39 @@ impl WsRequest for UserListUserStrategyPoolContractAssetLedgerRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
 impl WsResponse for UserListUserStrategyPoolContractAssetLedgerResponse {
@@ -4512,7 +6390,39 @@ impl WsRequest for UserGetStrategyStatisticsRequest {
   ],
   "stream_response": null,
   "description": "User gets a strategy statistics",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
 impl WsResponse for UserGetStrategyStatisticsResponse {
@@ -4646,7 +6556,39 @@ impl WsRequest for UserGetStrategiesStatisticsRequest {
   ],
   "stream_response": null,
   "description": "User gets statistics of all strategies related to the user",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
 impl WsResponse for UserGetStrategiesStatisticsResponse {
@@ -4694,7 +6636,35 @@ impl WsRequest for UserUpdateUserProfileRequest {
   "returns": [],
   "stream_response": null,
   "description": "User update its expert profile",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for UserUpdateUserProfileResponse {
@@ -4752,7 +6722,39 @@ impl WsRequest for UserBackStrategyRequest {
     }
   },
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "Trade",
+    "value": 1,
+    "comment": ""
+  }
 }"#;
 }
 impl WsResponse for UserBackStrategyResponse {
@@ -4797,7 +6799,39 @@ impl WsRequest for UserExitStrategyRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "Trade",
+    "value": 1,
+    "comment": ""
+  }
 }"#;
 }
 impl WsResponse for UserExitStrategyResponse {
@@ -4838,13 +6872,126 @@ impl WsRequest for UserRequestRefundRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "Withdraw",
+    "value": 2,
+    "comment": ""
+  }
 }"#;
 }
 impl WsResponse for UserRequestRefundResponse {
     type Request = UserRequestRefundRequest;
 }
 
+impl WsRequest for UserRequestWithdrawRequest {
+    type Response = UserRequestWithdrawResponse;
+    const METHOD_ID: u32 = 20082;
+    const SCHEMA: &'static str = r#"{
+  "name": "UserRequestWithdraw",
+  "code": 20082,
+  "parameters": [
+    {
+      "name": "quantity",
+      "ty": "BlockchainDecimal"
+    },
+    {
+      "name": "wallet_address",
+      "ty": "BlockchainAddress"
+    },
+    {
+      "name": "blockchain",
+      "ty": {
+        "EnumRef": "block_chain"
+      }
+    },
+    {
+      "name": "nonce",
+      "ty": "BigInt"
+    }
+  ],
+  "returns": [
+    {
+      "name": "success",
+      "ty": "Boolean"
+    },
+    {
+      "name": "quantity_received",
+      "ty": "BlockchainDecimal"
+    },
+    {
+      "name": "fee",
+      "ty": "BlockchainDecimal"
+    }
+  ],
+  "stream_response": null,
+  "description": "User withdraws escrow balance to a whitelisted wallet",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "Withdraw",
+    "value": 2,
+    "comment": ""
+  }
+}"#;
+}
+impl WsResponse for UserRequestWithdrawResponse {
+    type Request = UserRequestWithdrawRequest;
+}
+
 impl WsRequest for UserListBackedStrategiesRequest {
     type Response = UserListBackedStrategiesResponse;
     const METHOD_ID: u32 = 20090;
@@ -5009,7 +7156,39 @@ impl WsRequest for UserListBackedStrategiesRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
 impl WsResponse for UserListBackedStrategiesResponse {
@@ -5086,7 +7265,39 @@ impl WsRequest for UserListBackStrategyLedgerRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
 impl WsResponse for UserListBackStrategyLedgerResponse {
@@ -5163,7 +7374,34 @@ impl WsRequest for ExpertListBackStrategyLedgerRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
 impl WsResponse for ExpertListBackStrategyLedgerResponse {
@@ -5240,7 +7478,39 @@ impl WsRequest for UserListExitStrategyLedgerRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
 impl WsResponse for UserListExitStrategyLedgerResponse {
@@ -5317,7 +7587,34 @@ impl WsRequest for ExpertListExitStrategyLedgerRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
 impl WsResponse for ExpertListExitStrategyLedgerResponse {
@@ -5344,7 +7641,35 @@ impl WsRequest for UserFollowExpertRequest {
   ],
   "stream_response": null,
   "description": "User follows an expert",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for UserFollowExpertResponse {
@@ -5477,14 +7802,46 @@ impl WsRequest for UserListFollowedExpertsRequest {
   ],
   "stream_response": null,
   "description": "User lists followed experts",
-  "json_schema": null
-}"#;
-}
-impl WsResponse for UserListFollowedExpertsResponse {
-    type Request = UserListFollowedExpertsRequest;
-}
-
-impl WsRequest for UserUnfollowExpertRequest {
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
+}"#;
+}
+impl WsResponse for UserListFollowedExpertsResponse {
+    type Request = UserListFollowedExpertsRequest;
+}
+
+impl WsRequest for UserUnfollowExpertRequest {
     type Response = UserUnfollowExpertResponse;
     const METHOD_ID: u32 = 20150;
     const SCHEMA: &'static str = r#"{
@@ -5504,7 +7861,35 @@ impl WsRequest for UserUnfollowExpertRequest {
   ],
   "stream_response": null,
   "description": "User unfollows an expert",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for UserUnfollowExpertResponse {
@@ -5691,7 +8076,39 @@ impl WsRequest for UserListExpertsRequest {
   ],
   "stream_response": null,
   "description": "User lists experts",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
 impl WsResponse for UserListExpertsResponse {
@@ -5824,7 +8241,39 @@ impl WsRequest for UserListTopPerformingExpertsRequest {
   ],
   "stream_response": null,
   "description": "User lists experts",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
 impl WsResponse for UserListTopPerformingExpertsResponse {
@@ -5957,7 +8406,39 @@ impl WsRequest for UserListFeaturedExpertsRequest {
   ],
   "stream_response": null,
   "description": "User lists experts",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
 impl WsResponse for UserListFeaturedExpertsResponse {
@@ -6060,7 +8541,39 @@ impl WsRequest for UserListExpertListenedWalletTradeLedgerRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
 impl WsResponse for UserListExpertListenedWalletTradeLedgerResponse {
@@ -6112,6 +8625,10 @@ impl WsRequest for UserGetExpertProfileRequest {
       "name": "social_media",
       "ty": "String"
     },
+    {
+      "name": "consistent_score",
+      "ty": "Numeric"
+    },
     {
       "name": "risk_score",
       "ty": "Numeric"
@@ -6271,7 +8788,39 @@ impl WsRequest for UserGetExpertProfileRequest {
   ],
   "stream_response": null,
   "description": "User gets an expert profile",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
 impl WsResponse for UserGetExpertProfileResponse {
@@ -6683,7 +9232,39 @@ impl WsRequest for UserGetUserProfileRequest {
   ],
   "stream_response": null,
   "description": "User gets an user profile",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
 impl WsResponse for UserGetUserProfileResponse {
@@ -6720,7 +9301,35 @@ impl WsRequest for UserWhitelistWalletRequest {
   ],
   "stream_response": null,
   "description": "User registers a wallet",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for UserWhitelistWalletResponse {
@@ -6809,7 +9418,39 @@ impl WsRequest for UserListWhitelistedWalletsRequest {
   ],
   "stream_response": null,
   "description": "User lists wallets",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
 impl WsResponse for UserListWhitelistedWalletsResponse {
@@ -6836,7 +9477,35 @@ impl WsRequest for UserUnwhitelistWalletRequest {
   ],
   "stream_response": null,
   "description": "User deregisters a wallet",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for UserUnwhitelistWalletResponse {
@@ -6861,205 +9530,427 @@ impl WsRequest for UserApplyBecomeExpertRequest {
     }
   ],
   "stream_response": null,
-  "description": "User applies to become an expert",
-  "json_schema": null
+  "description": "User submits their expert application for review",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for UserApplyBecomeExpertResponse {
     type Request = UserApplyBecomeExpertRequest;
 }
 
-impl WsRequest for ExpertCreateStrategyRequest {
-    type Response = ExpertCreateStrategyResponse;
-    const METHOD_ID: u32 = 20250;
+impl WsRequest for UserSaveExpertApplicationRequest {
+    type Response = UserSaveExpertApplicationResponse;
+    const METHOD_ID: u32 = 20221;
     const SCHEMA: &'static str = r#"{
-  "name": "ExpertCreateStrategy",
-  "code": 20250,
+  "name": "UserSaveExpertApplication",
+  "code": 20221,
   "parameters": [
     {
-      "name": "name",
-      "ty": "String"
-    },
-    {
-      "name": "description",
-      "ty": "String"
-    },
+      "name": "questionnaire",
+      "ty": "Object"
+    }
+  ],
+  "returns": [
     {
-      "name": "strategy_thesis_url",
-      "ty": "String"
-    },
+      "name": "application_id",
+      "ty": "BigInt"
+    }
+  ],
+  "stream_response": null,
+  "description": "User saves the questionnaire of their expert application as a draft",
+  "json_schema": null,
+  "roles": [
     {
-      "name": "minimum_backing_amount_usd",
-      "ty": {
-        "Optional": "Numeric"
-      }
+      "name": "user",
+      "value": 1,
+      "comment": ""
     },
     {
-      "name": "expert_fee",
-      "ty": "Numeric"
+      "name": "expert",
+      "value": 2,
+      "comment": ""
     },
     {
-      "name": "agreed_tos",
-      "ty": "Boolean"
+      "name": "admin",
+      "value": 3,
+      "comment": ""
     },
     {
-      "name": "wallet_address",
-      "ty": "BlockchainAddress"
+      "name": "developer",
+      "value": 4,
+      "comment": ""
     },
     {
-      "name": "wallet_blockchain",
-      "ty": {
-        "EnumRef": "block_chain"
-      }
-    },
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
+}"#;
+}
+impl WsResponse for UserSaveExpertApplicationResponse {
+    type Request = UserSaveExpertApplicationRequest;
+}
+
+impl WsRequest for UserUploadExpertApplicationDocumentRequest {
+    type Response = UserUploadExpertApplicationDocumentResponse;
+    const METHOD_ID: u32 = 20222;
+    const SCHEMA: &'static str = r#"{
+  "name": "UserUploadExpertApplicationDocument",
+  "code": 20222,
+  "parameters": [
     {
-      "name": "strategy_token_relative_to_usdc_ratio",
-      "ty": {
-        "Optional": "BlockchainDecimal"
-      }
+      "name": "name",
+      "ty": "String"
     },
     {
-      "name": "initial_tokens",
-      "ty": {
-        "DataTable": {
-          "name": "UserCreateStrategyInitialTokenRow",
-          "fields": [
-            {
-              "name": "token_id",
-              "ty": "BigInt"
-            },
-            {
-              "name": "quantity",
-              "ty": "BlockchainDecimal"
-            }
-          ]
-        }
-      }
+      "name": "content_type",
+      "ty": "String"
     },
     {
-      "name": "audit_rules",
-      "ty": {
-        "Optional": {
-          "Vec": "BigInt"
-        }
-      }
+      "name": "content",
+      "ty": "String"
     }
   ],
   "returns": [
     {
-      "name": "success",
-      "ty": "Boolean"
-    },
-    {
-      "name": "strategy_id",
+      "name": "document_id",
       "ty": "BigInt"
     }
   ],
   "stream_response": null,
-  "description": "User makes a strategy",
-  "json_schema": null
+  "description": "User attaches a base64 encoded document to their expert application",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
-impl WsResponse for ExpertCreateStrategyResponse {
-    type Request = ExpertCreateStrategyRequest;
+impl WsResponse for UserUploadExpertApplicationDocumentResponse {
+    type Request = UserUploadExpertApplicationDocumentRequest;
 }
 
-impl WsRequest for ExpertUpdateStrategyRequest {
-    type Response = ExpertUpdateStrategyResponse;
-    const METHOD_ID: u32 = 20260;
+impl WsRequest for UserDeleteExpertApplicationDocumentRequest {
+    type Response = UserDeleteExpertApplicationDocumentResponse;
+    const METHOD_ID: u32 = 20223;
     const SCHEMA: &'static str = r#"{
-  "name": "ExpertUpdateStrategy",
-  "code": 20260,
+  "name": "UserDeleteExpertApplicationDocument",
+  "code": 20223,
   "parameters": [
     {
-      "name": "strategy_id",
+      "name": "document_id",
       "ty": "BigInt"
+    }
+  ],
+  "returns": [
+    {
+      "name": "success",
+      "ty": "Boolean"
+    }
+  ],
+  "stream_response": null,
+  "description": "User removes a document from their expert application",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
     },
     {
-      "name": "name",
-      "ty": {
-        "Optional": "String"
-      }
+      "name": "expert",
+      "value": 2,
+      "comment": ""
     },
     {
-      "name": "description",
-      "ty": {
-        "Optional": "String"
-      }
+      "name": "admin",
+      "value": 3,
+      "comment": ""
     },
     {
-      "name": "social_media",
-      "ty": {
-        "Optional": "String"
-      }
-    }
-  ],
-  "returns": [
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
     {
-      "name": "success",
-      "ty": "Boolean"
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
     }
   ],
-  "stream_response": null,
-  "description": "Expert updates a strategy",
-  "json_schema": null
+  "api_key_scope": null
 }"#;
 }
-impl WsResponse for ExpertUpdateStrategyResponse {
-    type Request = ExpertUpdateStrategyRequest;
+impl WsResponse for UserDeleteExpertApplicationDocumentResponse {
+    type Request = UserDeleteExpertApplicationDocumentRequest;
 }
 
-impl WsRequest for ExpertFreezeStrategyRequest {
-    type Response = ExpertFreezeStrategyResponse;
-    const METHOD_ID: u32 = 20265;
+impl WsRequest for UserGetExpertApplicationRequest {
+    type Response = UserGetExpertApplicationResponse;
+    const METHOD_ID: u32 = 20224;
     const SCHEMA: &'static str = r#"{
-  "name": "ExpertFreezeStrategy",
-  "code": 20265,
-  "parameters": [
+  "name": "UserGetExpertApplication",
+  "code": 20224,
+  "parameters": [],
+  "returns": [
     {
-      "name": "strategy_id",
-      "ty": "BigInt"
+      "name": "application_id",
+      "ty": {
+        "Optional": "BigInt"
+      }
+    },
+    {
+      "name": "status",
+      "ty": {
+        "EnumRef": "expert_application_status"
+      }
+    },
+    {
+      "name": "questionnaire",
+      "ty": "Object"
+    },
+    {
+      "name": "submitted_at",
+      "ty": {
+        "Optional": "BigInt"
+      }
+    },
+    {
+      "name": "reviewed_at",
+      "ty": {
+        "Optional": "BigInt"
+      }
+    },
+    {
+      "name": "documents",
+      "ty": {
+        "Vec": {
+          "Struct": {
+            "name": "ExpertApplicationDocumentRow",
+            "fields": [
+              {
+                "name": "document_id",
+                "ty": "BigInt"
+              },
+              {
+                "name": "name",
+                "ty": "String"
+              },
+              {
+                "name": "content_type",
+                "ty": "String"
+              },
+              {
+                "name": "size",
+                "ty": "BigInt"
+              },
+              {
+                "name": "created_at",
+                "ty": "BigInt"
+              }
+            ]
+          }
+        }
+      }
+    },
+    {
+      "name": "notes",
+      "ty": {
+        "Vec": {
+          "Struct": {
+            "name": "ExpertApplicationNoteRow",
+            "fields": [
+              {
+                "name": "status",
+                "ty": {
+                  "EnumRef": "expert_application_status"
+                }
+              },
+              {
+                "name": "note",
+                "ty": {
+                  "Optional": "String"
+                }
+              },
+              {
+                "name": "from_reviewer",
+                "ty": "Boolean"
+              },
+              {
+                "name": "created_at",
+                "ty": "BigInt"
+              }
+            ]
+          }
+        }
+      }
     }
   ],
-  "returns": [
+  "stream_response": null,
+  "description": "User gets their expert application with its documents and review notes",
+  "json_schema": null,
+  "roles": [
     {
-      "name": "success",
-      "ty": "Boolean"
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
     }
   ],
-  "stream_response": null,
-  "description": "Expert freezes a strategy, by making it immutable",
-  "json_schema": null
+  "api_key_scope": null
 }"#;
 }
-impl WsResponse for ExpertFreezeStrategyResponse {
-    type Request = ExpertFreezeStrategyRequest;
+impl WsResponse for UserGetExpertApplicationResponse {
+    type Request = UserGetExpertApplicationRequest;
 }
 
-impl WsRequest for ExpertAddStrategyWatchingWalletRequest {
-    type Response = ExpertAddStrategyWatchingWalletResponse;
-    const METHOD_ID: u32 = 20270;
+impl WsRequest for ExpertCreateStrategyRequest {
+    type Response = ExpertCreateStrategyResponse;
+    const METHOD_ID: u32 = 20250;
     const SCHEMA: &'static str = r#"{
-  "name": "ExpertAddStrategyWatchingWallet",
-  "code": 20270,
+  "name": "ExpertCreateStrategy",
+  "code": 20250,
   "parameters": [
     {
-      "name": "strategy_id",
-      "ty": "BigInt"
+      "name": "name",
+      "ty": "String"
     },
     {
-      "name": "blockchain",
+      "name": "description",
+      "ty": "String"
+    },
+    {
+      "name": "strategy_thesis_url",
+      "ty": "String"
+    },
+    {
+      "name": "minimum_backing_amount_usd",
       "ty": {
-        "EnumRef": "block_chain"
+        "Optional": "Numeric"
       }
     },
+    {
+      "name": "expert_fee",
+      "ty": "Numeric"
+    },
+    {
+      "name": "agreed_tos",
+      "ty": "Boolean"
+    },
     {
       "name": "wallet_address",
       "ty": "BlockchainAddress"
     },
     {
-      "name": "ratio",
-      "ty": "Numeric"
+      "name": "wallet_blockchain",
+      "ty": {
+        "EnumRef": "block_chain"
+      }
+    },
+    {
+      "name": "strategy_token_relative_to_usdc_ratio",
+      "ty": {
+        "Optional": "BlockchainDecimal"
+      }
+    },
+    {
+      "name": "initial_tokens",
+      "ty": {
+        "DataTable": {
+          "name": "UserCreateStrategyInitialTokenRow",
+          "fields": [
+            {
+              "name": "token_id",
+              "ty": "BigInt"
+            },
+            {
+              "name": "quantity",
+              "ty": "BlockchainDecimal"
+            }
+          ]
+        }
+      }
+    },
+    {
+      "name": "audit_rules",
+      "ty": {
+        "Optional": {
+          "Vec": "BigInt"
+        }
+      }
     }
   ],
   "returns": [
@@ -7068,82 +9959,356 @@ impl WsRequest for ExpertAddStrategyWatchingWalletRequest {
       "ty": "Boolean"
     },
     {
-      "name": "wallet_id",
+      "name": "strategy_id",
       "ty": "BigInt"
     }
   ],
   "stream_response": null,
-  "description": "",
-  "json_schema": null
-}"#;
-}
-impl WsResponse for ExpertAddStrategyWatchingWalletResponse {
-    type Request = ExpertAddStrategyWatchingWalletRequest;
-}
-
-impl WsRequest for ExpertRemoveStrategyWatchingWalletRequest {
-    type Response = ExpertRemoveStrategyWatchingWalletResponse;
-    const METHOD_ID: u32 = 20280;
-    const SCHEMA: &'static str = r#"{
-  "name": "ExpertRemoveStrategyWatchingWallet",
-  "code": 20280,
-  "parameters": [
+  "description": "User makes a strategy",
+  "json_schema": null,
+  "roles": [
     {
-      "name": "strategy_id",
-      "ty": "BigInt"
+      "name": "expert",
+      "value": 2,
+      "comment": ""
     },
     {
-      "name": "wallet_id",
-      "ty": "BigInt"
-    }
-  ],
-  "returns": [
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
     {
-      "name": "success",
-      "ty": "Boolean"
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
     }
   ],
-  "stream_response": null,
-  "description": "",
-  "json_schema": null
+  "api_key_scope": null
 }"#;
 }
-impl WsResponse for ExpertRemoveStrategyWatchingWalletResponse {
-    type Request = ExpertRemoveStrategyWatchingWalletRequest;
+impl WsResponse for ExpertCreateStrategyResponse {
+    type Request = ExpertCreateStrategyRequest;
 }
 
-impl WsRequest for UserListStrategyWatchingWalletsRequest {
-    type Response = UserListStrategyWatchingWalletsResponse;
-    const METHOD_ID: u32 = 20290;
+impl WsRequest for ExpertUpdateStrategyRequest {
+    type Response = ExpertUpdateStrategyResponse;
+    const METHOD_ID: u32 = 20260;
     const SCHEMA: &'static str = r#"{
-  "name": "UserListStrategyWatchingWallets",
-  "code": 20290,
+  "name": "ExpertUpdateStrategy",
+  "code": 20260,
   "parameters": [
     {
       "name": "strategy_id",
       "ty": "BigInt"
-    }
-  ],
-  "returns": [
+    },
     {
-      "name": "wallets_total",
-      "ty": "BigInt"
+      "name": "name",
+      "ty": {
+        "Optional": "String"
+      }
     },
     {
-      "name": "wallets",
+      "name": "description",
       "ty": {
-        "DataTable": {
-          "name": "ListStrategyWatchingWalletsRow",
-          "fields": [
-            {
-              "name": "wallet_id",
-              "ty": "BigInt"
-            },
-            {
-              "name": "blockchain",
-              "ty": {
-                "EnumRef": "block_chain"
-              }
+        "Optional": "String"
+      }
+    },
+    {
+      "name": "social_media",
+      "ty": {
+        "Optional": "String"
+      }
+    },
+    {
+      "name": "rebalance_interval_seconds",
+      "ty": {
+        "Optional": "BigInt"
+      }
+    },
+    {
+      "name": "rebalance_drift_threshold",
+      "ty": {
+        "Optional": "Numeric"
+      }
+    },
+    {
+      "name": "copy_trade_min_usd",
+      "ty": {
+        "Optional": "Numeric"
+      }
+    },
+    {
+      "name": "copy_trade_max_aum_ratio",
+      "ty": {
+        "Optional": "Numeric"
+      }
+    },
+    {
+      "name": "copy_trade_cooldown_seconds",
+      "ty": {
+        "Optional": "BigInt"
+      }
+    },
+    {
+      "name": "copy_trade_batch_window_seconds",
+      "ty": {
+        "Optional": "BigInt"
+      }
+    },
+    {
+      "name": "expert_fee",
+      "ty": {
+        "Optional": "Numeric"
+      }
+    }
+  ],
+  "returns": [
+    {
+      "name": "success",
+      "ty": "Boolean"
+    }
+  ],
+  "stream_response": null,
+  "description": "Expert updates a strategy, a rebalance interval or copy trade control of 0 disables it. A new expert fee is only applied after backers had time to exit",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
+}"#;
+}
+impl WsResponse for ExpertUpdateStrategyResponse {
+    type Request = ExpertUpdateStrategyRequest;
+}
+
+impl WsRequest for ExpertFreezeStrategyRequest {
+    type Response = ExpertFreezeStrategyResponse;
+    const METHOD_ID: u32 = 20265;
+    const SCHEMA: &'static str = r#"{
+  "name": "ExpertFreezeStrategy",
+  "code": 20265,
+  "parameters": [
+    {
+      "name": "strategy_id",
+      "ty": "BigInt"
+    }
+  ],
+  "returns": [
+    {
+      "name": "success",
+      "ty": "Boolean"
+    }
+  ],
+  "stream_response": null,
+  "description": "Expert freezes a strategy, by making it immutable",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
+}"#;
+}
+impl WsResponse for ExpertFreezeStrategyResponse {
+    type Request = ExpertFreezeStrategyRequest;
+}
+
+impl WsRequest for ExpertAddStrategyWatchingWalletRequest {
+    type Response = ExpertAddStrategyWatchingWalletResponse;
+    const METHOD_ID: u32 = 20270;
+    const SCHEMA: &'static str = r#"{
+  "name": "ExpertAddStrategyWatchingWallet",
+  "code": 20270,
+  "parameters": [
+    {
+      "name": "strategy_id",
+      "ty": "BigInt"
+    },
+    {
+      "name": "blockchain",
+      "ty": {
+        "EnumRef": "block_chain"
+      }
+    },
+    {
+      "name": "wallet_address",
+      "ty": "BlockchainAddress"
+    },
+    {
+      "name": "ratio",
+      "ty": "Numeric"
+    }
+  ],
+  "returns": [
+    {
+      "name": "success",
+      "ty": "Boolean"
+    },
+    {
+      "name": "wallet_id",
+      "ty": "BigInt"
+    }
+  ],
+  "stream_response": null,
+  "description": "",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
+}"#;
+}
+impl WsResponse for ExpertAddStrategyWatchingWalletResponse {
+    type Request = ExpertAddStrategyWatchingWalletRequest;
+}
+
+impl WsRequest for ExpertRemoveStrategyWatchingWalletRequest {
+    type Response = ExpertRemoveStrategyWatchingWalletResponse;
+    const METHOD_ID: u32 = 20280;
+    const SCHEMA: &'static str = r#"{
+  "name": "ExpertRemoveStrategyWatchingWallet",
+  "code": 20280,
+  "parameters": [
+    {
+      "name": "strategy_id",
+      "ty": "BigInt"
+    },
+    {
+      "name": "wallet_id",
+      "ty": "BigInt"
+    }
+  ],
+  "returns": [
+    {
+      "name": "success",
+      "ty": "Boolean"
+    }
+  ],
+  "stream_response": null,
+  "description": "",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
+}"#;
+}
+impl WsResponse for ExpertRemoveStrategyWatchingWalletResponse {
+    type Request = ExpertRemoveStrategyWatchingWalletRequest;
+}
+
+impl WsRequest for UserListStrategyWatchingWalletsRequest {
+    type Response = UserListStrategyWatchingWalletsResponse;
+    const METHOD_ID: u32 = 20290;
+    const SCHEMA: &'static str = r#"{
+  "name": "UserListStrategyWatchingWallets",
+  "code": 20290,
+  "parameters": [
+    {
+      "name": "strategy_id",
+      "ty": "BigInt"
+    }
+  ],
+  "returns": [
+    {
+      "name": "wallets_total",
+      "ty": "BigInt"
+    },
+    {
+      "name": "wallets",
+      "ty": {
+        "DataTable": {
+          "name": "ListStrategyWatchingWalletsRow",
+          "fields": [
+            {
+              "name": "wallet_id",
+              "ty": "BigInt"
+            },
+            {
+              "name": "blockchain",
+              "ty": {
+                "EnumRef": "block_chain"
+              }
             },
             {
               "name": "wallet_address",
@@ -7160,7 +10325,39 @@ impl WsRequest for UserListStrategyWatchingWalletsRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
 impl WsResponse for UserListStrategyWatchingWalletsResponse {
@@ -7265,7 +10462,39 @@ impl WsRequest for UserListWalletActivityLedgerRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
 impl WsResponse for UserListWalletActivityLedgerResponse {
@@ -7304,14 +10533,37 @@ impl WsRequest for ExpertAddStrategyInitialTokenRatioRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
-}"#;
-}
-impl WsResponse for ExpertAddStrategyInitialTokenRatioResponse {
-    type Request = ExpertAddStrategyInitialTokenRatioRequest;
-}
-
-impl WsRequest for ExpertRemoveStrategyInitialTokenRatioRequest {
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
+}"#;
+}
+impl WsResponse for ExpertAddStrategyInitialTokenRatioResponse {
+    type Request = ExpertAddStrategyInitialTokenRatioRequest;
+}
+
+impl WsRequest for ExpertRemoveStrategyInitialTokenRatioRequest {
     type Response = ExpertRemoveStrategyInitialTokenRatioResponse;
     const METHOD_ID: u32 = 20320;
     const SCHEMA: &'static str = r#"{
@@ -7335,7 +10587,30 @@ impl WsRequest for ExpertRemoveStrategyInitialTokenRatioRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for ExpertRemoveStrategyInitialTokenRatioResponse {
@@ -7396,13 +10671,168 @@ impl WsRequest for UserListStrategyInitialTokenRatioRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
 impl WsResponse for UserListStrategyInitialTokenRatioResponse {
     type Request = UserListStrategyInitialTokenRatioRequest;
 }
 
+impl WsRequest for UserListStrategyVersionsRequest {
+    type Response = UserListStrategyVersionsResponse;
+    const METHOD_ID: u32 = 20335;
+    const SCHEMA: &'static str = r#"{
+  "name": "UserListStrategyVersions",
+  "code": 20335,
+  "parameters": [
+    {
+      "name": "strategy_id",
+      "ty": "BigInt"
+    },
+    {
+      "name": "limit",
+      "ty": {
+        "Optional": "BigInt"
+      }
+    },
+    {
+      "name": "offset",
+      "ty": {
+        "Optional": "BigInt"
+      }
+    }
+  ],
+  "returns": [
+    {
+      "name": "versions_total",
+      "ty": "BigInt"
+    },
+    {
+      "name": "versions",
+      "ty": {
+        "DataTable": {
+          "name": "ListStrategyVersionsRow",
+          "fields": [
+            {
+              "name": "version",
+              "ty": "BigInt"
+            },
+            {
+              "name": "change",
+              "ty": "String"
+            },
+            {
+              "name": "params",
+              "ty": "Object"
+            },
+            {
+              "name": "diff",
+              "ty": {
+                "Optional": "Object"
+              }
+            },
+            {
+              "name": "is_material",
+              "ty": "Boolean"
+            },
+            {
+              "name": "status",
+              "ty": {
+                "EnumRef": "strategy_version_status"
+              }
+            },
+            {
+              "name": "effective_at",
+              "ty": "BigInt"
+            },
+            {
+              "name": "applied_at",
+              "ty": {
+                "Optional": "BigInt"
+              }
+            },
+            {
+              "name": "created_at",
+              "ty": "BigInt"
+            }
+          ]
+        }
+      }
+    }
+  ],
+  "stream_response": null,
+  "description": "Lists every change made to a strategy, newest first. Material changes are scheduled and only take effect at effective_at",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
+}"#;
+}
+impl WsResponse for UserListStrategyVersionsResponse {
+    type Request = UserListStrategyVersionsRequest;
+}
+
 impl WsRequest for ExpertListFollowersRequest {
     type Response = ExpertListFollowersResponse;
     const METHOD_ID: u32 = 20340;
@@ -7473,7 +10903,34 @@ impl WsRequest for ExpertListFollowersRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
 impl WsResponse for ExpertListFollowersResponse {
@@ -7550,7 +11007,34 @@ impl WsRequest for ExpertListBackersRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
 impl WsResponse for ExpertListBackersResponse {
@@ -7721,7 +11205,34 @@ impl WsRequest for ExpertListPublishedStrategiesRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
 impl WsResponse for ExpertListPublishedStrategiesResponse {
@@ -7892,7 +11403,34 @@ impl WsRequest for ExpertListUnpublishedStrategiesRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
 impl WsResponse for ExpertListUnpublishedStrategiesResponse {
@@ -7942,31 +11480,1339 @@ impl WsRequest for UserGetDepositTokensRequest {
           ]
         }
       }
-    }
-  ],
-  "stream_response": null,
-  "description": "",
-  "json_schema": null
-}"#;
-}
-impl WsResponse for UserGetDepositTokensResponse {
-    type Request = UserGetDepositTokensRequest;
-}
-
-impl WsRequest for UserGetDepositAddressesRequest {
-    type Response = UserGetDepositAddressesResponse;
-    const METHOD_ID: u32 = 20370;
-    const SCHEMA: &'static str = r#"{
-  "name": "UserGetDepositAddresses",
-  "code": 20370,
-  "parameters": [],
-  "returns": [
+    }
+  ],
+  "stream_response": null,
+  "description": "",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
+}"#;
+}
+impl WsResponse for UserGetDepositTokensResponse {
+    type Request = UserGetDepositTokensRequest;
+}
+
+impl WsRequest for UserGetDepositAddressesRequest {
+    type Response = UserGetDepositAddressesResponse;
+    const METHOD_ID: u32 = 20370;
+    const SCHEMA: &'static str = r#"{
+  "name": "UserGetDepositAddresses",
+  "code": 20370,
+  "parameters": [],
+  "returns": [
+    {
+      "name": "addresses",
+      "ty": {
+        "DataTable": {
+          "name": "UserGetDepositAddressesRow",
+          "fields": [
+            {
+              "name": "blockchain",
+              "ty": {
+                "EnumRef": "block_chain"
+              }
+            },
+            {
+              "name": "address",
+              "ty": "BlockchainAddress"
+            },
+            {
+              "name": "short_name",
+              "ty": "String"
+            }
+          ]
+        }
+      }
+    }
+  ],
+  "stream_response": null,
+  "description": "",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
+}"#;
+}
+impl WsResponse for UserGetDepositAddressesResponse {
+    type Request = UserGetDepositAddressesRequest;
+}
+
+impl WsRequest for UserListDepositWithdrawLedgerRequest {
+    type Response = UserListDepositWithdrawLedgerResponse;
+    const METHOD_ID: u32 = 20380;
+    const SCHEMA: &'static str = r#"{
+  "name": "UserListDepositWithdrawLedger",
+  "code": 20380,
+  "parameters": [
+    {
+      "name": "limit",
+      "ty": {
+        "Optional": "BigInt"
+      }
+    },
+    {
+      "name": "offset",
+      "ty": {
+        "Optional": "BigInt"
+      }
+    },
+    {
+      "name": "blockchain",
+      "ty": {
+        "Optional": {
+          "EnumRef": "block_chain"
+        }
+      }
+    },
+    {
+      "name": "id_deposit",
+      "ty": {
+        "Optional": "Boolean"
+      }
+    }
+  ],
+  "returns": [
+    {
+      "name": "ledger_total",
+      "ty": "BigInt"
+    },
+    {
+      "name": "ledger",
+      "ty": {
+        "Vec": {
+          "Struct": {
+            "name": "UserListDepositLedgerRow",
+            "fields": [
+              {
+                "name": "transaction_id",
+                "ty": "BigInt"
+              },
+              {
+                "name": "blockchain",
+                "ty": {
+                  "EnumRef": "block_chain"
+                }
+              },
+              {
+                "name": "user_address",
+                "ty": "BlockchainAddress"
+              },
+              {
+                "name": "contract_address",
+                "ty": "BlockchainAddress"
+              },
+              {
+                "name": "receiver_address",
+                "ty": "BlockchainAddress"
+              },
+              {
+                "name": "quantity",
+                "ty": "BlockchainDecimal"
+              },
+              {
+                "name": "transaction_hash",
+                "ty": "BlockchainTransactionHash"
+              },
+              {
+                "name": "is_deposit",
+                "ty": "Boolean"
+              },
+              {
+                "name": "happened_at",
+                "ty": "BigInt"
+              }
+            ]
+          }
+        }
+      }
+    }
+  ],
+  "stream_response": null,
+  "description": "",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
+}"#;
+}
+impl WsResponse for UserListDepositWithdrawLedgerResponse {
+    type Request = UserListDepositWithdrawLedgerRequest;
+}
+
+impl WsRequest for UserSubscribeDepositLedgerRequest {
+    type Response = UserSubscribeDepositLedgerResponse;
+    const METHOD_ID: u32 = 20381;
+    const SCHEMA: &'static str = r#"{
+  "name": "UserSubscribeDepositLedger",
+  "code": 20381,
+  "parameters": [
+    {
+      "name": "initial_data",
+      "ty": {
+        "Optional": "BigInt"
+      }
+    },
+    {
+      "name": "blockchain",
+      "ty": {
+        "Optional": {
+          "EnumRef": "block_chain"
+        }
+      }
+    },
+    {
+      "name": "mock_data",
+      "ty": {
+        "Optional": "Boolean"
+      }
+    }
+  ],
+  "returns": [],
+  "stream_response": {
+    "Struct": {
+      "name": "UserListDepositLedgerRow",
+      "fields": [
+        {
+          "name": "transaction_id",
+          "ty": "BigInt"
+        },
+        {
+          "name": "blockchain",
+          "ty": {
+            "EnumRef": "block_chain"
+          }
+        },
+        {
+          "name": "user_address",
+          "ty": "BlockchainAddress"
+        },
+        {
+          "name": "contract_address",
+          "ty": "BlockchainAddress"
+        },
+        {
+          "name": "receiver_address",
+          "ty": "BlockchainAddress"
+        },
+        {
+          "name": "quantity",
+          "ty": "BlockchainDecimal"
+        },
+        {
+          "name": "transaction_hash",
+          "ty": "BlockchainTransactionHash"
+        },
+        {
+          "name": "is_deposit",
+          "ty": "Boolean"
+        },
+        {
+          "name": "happened_at",
+          "ty": "BigInt"
+        }
+      ]
+    }
+  },
+  "description": "",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
+}"#;
+}
+impl WsResponse for UserSubscribeDepositLedgerResponse {
+    type Request = UserSubscribeDepositLedgerRequest;
+}
+
+impl WsRequest for UserUnsubscribeDepositLedgerRequest {
+    type Response = UserUnsubscribeDepositLedgerResponse;
+    const METHOD_ID: u32 = 20382;
+    const SCHEMA: &'static str = r#"{
+  "name": "UserUnsubscribeDepositLedger",
+  "code": 20382,
+  "parameters": [],
+  "returns": [],
+  "stream_response": null,
+  "description": "",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
+}"#;
+}
+impl WsResponse for UserUnsubscribeDepositLedgerResponse {
+    type Request = UserUnsubscribeDepositLedgerRequest;
+}
+
+impl WsRequest for UserListStrategyWalletsRequest {
+    type Response = UserListStrategyWalletsResponse;
+    const METHOD_ID: u32 = 20390;
+    const SCHEMA: &'static str = r#"{
+  "name": "UserListStrategyWallets",
+  "code": 20390,
+  "parameters": [
+    {
+      "name": "blockchain",
+      "ty": {
+        "Optional": {
+          "EnumRef": "block_chain"
+        }
+      }
+    }
+  ],
+  "returns": [
+    {
+      "name": "wallets_total",
+      "ty": "BigInt"
+    },
+    {
+      "name": "wallets",
+      "ty": {
+        "DataTable": {
+          "name": "UserListStrategyWalletsRow",
+          "fields": [
+            {
+              "name": "blockchain",
+              "ty": {
+                "EnumRef": "block_chain"
+              }
+            },
+            {
+              "name": "address",
+              "ty": "BlockchainAddress"
+            },
+            {
+              "name": "is_platform_managed",
+              "ty": "Boolean"
+            },
+            {
+              "name": "created_at",
+              "ty": "BigInt"
+            }
+          ]
+        }
+      }
+    }
+  ],
+  "stream_response": null,
+  "description": "",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
+}"#;
+}
+impl WsResponse for UserListStrategyWalletsResponse {
+    type Request = UserListStrategyWalletsRequest;
+}
+
+impl WsRequest for UserCreateStrategyWalletRequest {
+    type Response = UserCreateStrategyWalletResponse;
+    const METHOD_ID: u32 = 20391;
+    const SCHEMA: &'static str = r#"{
+  "name": "UserCreateStrategyWallet",
+  "code": 20391,
+  "parameters": [
+    {
+      "name": "blockchain",
+      "ty": {
+        "EnumRef": "block_chain"
+      }
+    },
+    {
+      "name": "user_managed_wallet_address",
+      "ty": {
+        "Optional": "BlockchainAddress"
+      }
+    }
+  ],
+  "returns": [
+    {
+      "name": "blockchain",
+      "ty": {
+        "EnumRef": "block_chain"
+      }
+    },
+    {
+      "name": "address",
+      "ty": "BlockchainAddress"
+    }
+  ],
+  "stream_response": null,
+  "description": "",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
+}"#;
+}
+impl WsResponse for UserCreateStrategyWalletResponse {
+    type Request = UserCreateStrategyWalletRequest;
+}
+
+impl WsRequest for UserListStrategyAuditRulesRequest {
+    type Response = UserListStrategyAuditRulesResponse;
+    const METHOD_ID: u32 = 20400;
+    const SCHEMA: &'static str = r#"{
+  "name": "UserListStrategyAuditRules",
+  "code": 20400,
+  "parameters": [
+    {
+      "name": "strategy_id",
+      "ty": {
+        "Optional": "BigInt"
+      }
+    }
+  ],
+  "returns": [
+    {
+      "name": "audit_rules",
+      "ty": {
+        "DataTable": {
+          "name": "UserListStrategyAuditRulesRow",
+          "fields": [
+            {
+              "name": "rule_id",
+              "ty": "BigInt"
+            },
+            {
+              "name": "rule_name",
+              "ty": "String"
+            },
+            {
+              "name": "rule_description",
+              "ty": "String"
+            },
+            {
+              "name": "created_at",
+              "ty": "BigInt"
+            },
+            {
+              "name": "enabled",
+              "ty": "Boolean"
+            }
+          ]
+        }
+      }
+    }
+  ],
+  "stream_response": null,
+  "description": "",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
+}"#;
+}
+impl WsResponse for UserListStrategyAuditRulesResponse {
+    type Request = UserListStrategyAuditRulesRequest;
+}
+
+impl WsRequest for UserAddStrategyAuditRuleRequest {
+    type Response = UserAddStrategyAuditRuleResponse;
+    const METHOD_ID: u32 = 20410;
+    const SCHEMA: &'static str = r#"{
+  "name": "UserAddStrategyAuditRule",
+  "code": 20410,
+  "parameters": [
+    {
+      "name": "strategy_id",
+      "ty": "BigInt"
+    },
+    {
+      "name": "rule_id",
+      "ty": "BigInt"
+    }
+  ],
+  "returns": [],
+  "stream_response": null,
+  "description": "",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
+}"#;
+}
+impl WsResponse for UserAddStrategyAuditRuleResponse {
+    type Request = UserAddStrategyAuditRuleRequest;
+}
+
+impl WsRequest for UserRemoveStrategyAuditRuleRequest {
+    type Response = UserRemoveStrategyAuditRuleResponse;
+    const METHOD_ID: u32 = 20420;
+    const SCHEMA: &'static str = r#"{
+  "name": "UserRemoveStrategyAuditRule",
+  "code": 20420,
+  "parameters": [
+    {
+      "name": "strategy_id",
+      "ty": "BigInt"
+    },
+    {
+      "name": "rule_id",
+      "ty": "BigInt"
+    }
+  ],
+  "returns": [],
+  "stream_response": null,
+  "description": "",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
+}"#;
+}
+impl WsResponse for UserRemoveStrategyAuditRuleResponse {
+    type Request = UserRemoveStrategyAuditRuleRequest;
+}
+
+impl WsRequest for UserGetEscrowAddressForStrategyRequest {
+    type Response = UserGetEscrowAddressForStrategyResponse;
+    const METHOD_ID: u32 = 20500;
+    const SCHEMA: &'static str = r#"{
+  "name": "UserGetEscrowAddressForStrategy",
+  "code": 20500,
+  "parameters": [
+    {
+      "name": "strategy_id",
+      "ty": "BigInt"
+    },
+    {
+      "name": "token_id",
+      "ty": {
+        "Optional": "BigInt"
+      }
+    }
+  ],
+  "returns": [
+    {
+      "name": "tokens",
+      "ty": {
+        "DataTable": {
+          "name": "UserAllowedEscrowTransferInfo",
+          "fields": [
+            {
+              "name": "receiver_address",
+              "ty": "BlockchainAddress"
+            },
+            {
+              "name": "blockchain",
+              "ty": {
+                "EnumRef": "block_chain"
+              }
+            },
+            {
+              "name": "token_id",
+              "ty": "BigInt"
+            },
+            {
+              "name": "token_symbol",
+              "ty": "String"
+            },
+            {
+              "name": "token_name",
+              "ty": "String"
+            },
+            {
+              "name": "token_address",
+              "ty": "BlockchainAddress"
+            }
+          ]
+        }
+      }
+    }
+  ],
+  "stream_response": null,
+  "description": "",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
+}"#;
+}
+impl WsResponse for UserGetEscrowAddressForStrategyResponse {
+    type Request = UserGetEscrowAddressForStrategyRequest;
+}
+
+impl WsRequest for UserListDepositWithdrawBalancesRequest {
+    type Response = UserListDepositWithdrawBalancesResponse;
+    const METHOD_ID: u32 = 20510;
+    const SCHEMA: &'static str = r#"{
+  "name": "UserListDepositWithdrawBalances",
+  "code": 20510,
+  "parameters": [],
+  "returns": [
+    {
+      "name": "balances",
+      "ty": {
+        "DataTable": {
+          "name": "UserListDepositWithdrawBalance",
+          "fields": [
+            {
+              "name": "blockchain",
+              "ty": {
+                "EnumRef": "block_chain"
+              }
+            },
+            {
+              "name": "token_id",
+              "ty": "BigInt"
+            },
+            {
+              "name": "token_symbol",
+              "ty": "String"
+            },
+            {
+              "name": "token_name",
+              "ty": "String"
+            },
+            {
+              "name": "balance",
+              "ty": "BlockchainDecimal"
+            }
+          ]
+        }
+      }
+    }
+  ],
+  "stream_response": null,
+  "description": "",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
+}"#;
+}
+impl WsResponse for UserListDepositWithdrawBalancesResponse {
+    type Request = UserListDepositWithdrawBalancesRequest;
+}
+
+impl WsRequest for UserGetDepositWithdrawBalanceRequest {
+    type Response = UserGetDepositWithdrawBalanceResponse;
+    const METHOD_ID: u32 = 20511;
+    const SCHEMA: &'static str = r#"{
+  "name": "UserGetDepositWithdrawBalance",
+  "code": 20511,
+  "parameters": [
+    {
+      "name": "token_id",
+      "ty": "BigInt"
+    }
+  ],
+  "returns": [
+    {
+      "name": "balance",
+      "ty": "BlockchainDecimal"
+    }
+  ],
+  "stream_response": null,
+  "description": "",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
+}"#;
+}
+impl WsResponse for UserGetDepositWithdrawBalanceResponse {
+    type Request = UserGetDepositWithdrawBalanceRequest;
+}
+
+impl WsRequest for UserListEscrowTokenContractAddressesRequest {
+    type Response = UserListEscrowTokenContractAddressesResponse;
+    const METHOD_ID: u32 = 20520;
+    const SCHEMA: &'static str = r#"{
+  "name": "UserListEscrowTokenContractAddresses",
+  "code": 20520,
+  "parameters": [
+    {
+      "name": "limit",
+      "ty": {
+        "Optional": "BigInt"
+      }
+    },
+    {
+      "name": "offset",
+      "ty": {
+        "Optional": "BigInt"
+      }
+    },
+    {
+      "name": "blockchain",
+      "ty": {
+        "Optional": {
+          "EnumRef": "block_chain"
+        }
+      }
+    },
+    {
+      "name": "is_stablecoin",
+      "ty": {
+        "Optional": "Boolean"
+      }
+    }
+  ],
+  "returns": [
+    {
+      "name": "tokens_total",
+      "ty": "BigInt"
+    },
+    {
+      "name": "tokens",
+      "ty": {
+        "DataTable": {
+          "name": "UserListEscrowTokenContractAddressesRow",
+          "fields": [
+            {
+              "name": "token_id",
+              "ty": "BigInt"
+            },
+            {
+              "name": "token_symbol",
+              "ty": "String"
+            },
+            {
+              "name": "token_name",
+              "ty": "String"
+            },
+            {
+              "name": "token_address",
+              "ty": "BlockchainAddress"
+            },
+            {
+              "name": "description",
+              "ty": "String"
+            },
+            {
+              "name": "blockchain",
+              "ty": {
+                "EnumRef": "block_chain"
+              }
+            },
+            {
+              "name": "is_stablecoin",
+              "ty": "Boolean"
+            }
+          ]
+        }
+      }
+    }
+  ],
+  "stream_response": null,
+  "description": "",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
+}"#;
+}
+impl WsResponse for UserListEscrowTokenContractAddressesResponse {
+    type Request = UserListEscrowTokenContractAddressesRequest;
+}
+
+impl WsRequest for UserListStrategyTokenBalanceRequest {
+    type Response = UserListStrategyTokenBalanceResponse;
+    const METHOD_ID: u32 = 20530;
+    const SCHEMA: &'static str = r#"{
+  "name": "UserListStrategyTokenBalance",
+  "code": 20530,
+  "parameters": [
+    {
+      "name": "limit",
+      "ty": {
+        "Optional": "BigInt"
+      }
+    },
+    {
+      "name": "offset",
+      "ty": {
+        "Optional": "BigInt"
+      }
+    },
+    {
+      "name": "strategy_id",
+      "ty": {
+        "Optional": "BigInt"
+      }
+    }
+  ],
+  "returns": [
+    {
+      "name": "tokens_total",
+      "ty": "BigInt"
+    },
+    {
+      "name": "tokens",
+      "ty": {
+        "DataTable": {
+          "name": "UserListStrategyTokenBalanceRow",
+          "fields": [
+            {
+              "name": "strategy_id",
+              "ty": "BigInt"
+            },
+            {
+              "name": "strategy_name",
+              "ty": "String"
+            },
+            {
+              "name": "balance",
+              "ty": "BlockchainDecimal"
+            },
+            {
+              "name": "address",
+              "ty": "BlockchainAddress"
+            },
+            {
+              "name": "blockchain",
+              "ty": {
+                "EnumRef": "block_chain"
+              }
+            }
+          ]
+        }
+      }
+    }
+  ],
+  "stream_response": null,
+  "description": "",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
+}"#;
+}
+impl WsResponse for UserListStrategyTokenBalanceResponse {
+    type Request = UserListStrategyTokenBalanceRequest;
+}
+
+impl WsRequest for UserGetBackStrategyReviewDetailRequest {
+    type Response = UserGetBackStrategyReviewDetailResponse;
+    const METHOD_ID: u32 = 20540;
+    const SCHEMA: &'static str = r#"{
+  "name": "UserGetBackStrategyReviewDetail",
+  "code": 20540,
+  "parameters": [
+    {
+      "name": "strategy_id",
+      "ty": "BigInt"
+    },
+    {
+      "name": "token_id",
+      "ty": "BigInt"
+    },
+    {
+      "name": "quantity",
+      "ty": "BlockchainDecimal"
+    }
+  ],
+  "returns": [
+    {
+      "name": "strategy_fee",
+      "ty": "BlockchainDecimal"
+    },
+    {
+      "name": "total_amount_to_back",
+      "ty": "BlockchainDecimal"
+    },
+    {
+      "name": "total_amount_to_back_after_fee",
+      "ty": "BlockchainDecimal"
+    },
+    {
+      "name": "user_strategy_wallets",
+      "ty": {
+        "DataTable": {
+          "name": "UserStrategyWallet",
+          "fields": [
+            {
+              "name": "wallet_id",
+              "ty": "BigInt"
+            },
+            {
+              "name": "address",
+              "ty": "BlockchainAddress"
+            },
+            {
+              "name": "blockchain",
+              "ty": {
+                "EnumRef": "block_chain"
+              }
+            },
+            {
+              "name": "is_platform_address",
+              "ty": "Boolean"
+            }
+          ]
+        }
+      }
+    },
+    {
+      "name": "estimated_amount_of_strategy_tokens",
+      "ty": "BlockchainDecimal"
+    },
+    {
+      "name": "estimated_backed_token_ratios",
+      "ty": {
+        "DataTable": {
+          "name": "EstimatedBackedTokenRatios",
+          "fields": [
+            {
+              "name": "token_id",
+              "ty": "BigInt"
+            },
+            {
+              "name": "token_name",
+              "ty": "String"
+            },
+            {
+              "name": "back_amount",
+              "ty": "BlockchainDecimal"
+            },
+            {
+              "name": "back_value_in_usd",
+              "ty": "BlockchainDecimal"
+            },
+            {
+              "name": "back_value_ratio",
+              "ty": "Numeric"
+            }
+          ]
+        }
+      }
+    },
     {
-      "name": "addresses",
+      "name": "strategy_pool_asset_balances",
       "ty": {
         "DataTable": {
-          "name": "UserGetDepositAddressesRow",
+          "name": "StrategyPoolAssetBalancesRow",
           "fields": [
+            {
+              "name": "name",
+              "ty": "String"
+            },
+            {
+              "name": "symbol",
+              "ty": "String"
+            },
+            {
+              "name": "address",
+              "ty": "BlockchainAddress"
+            },
             {
               "name": "blockchain",
               "ty": {
@@ -7974,12 +12820,20 @@ impl WsRequest for UserGetDepositAddressesRequest {
               }
             },
             {
-              "name": "address",
-              "ty": "BlockchainAddress"
+              "name": "balance",
+              "ty": "BlockchainDecimal"
             },
             {
-              "name": "short_name",
-              "ty": "String"
+              "name": "price_usd",
+              "ty": "Numeric"
+            },
+            {
+              "name": "price_usd_7d",
+              "ty": "Numeric"
+            },
+            {
+              "name": "price_usd_30d",
+              "ty": "Numeric"
             }
           ]
         }
@@ -7988,19 +12842,51 @@ impl WsRequest for UserGetDepositAddressesRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
-impl WsResponse for UserGetDepositAddressesResponse {
-    type Request = UserGetDepositAddressesRequest;
+impl WsResponse for UserGetBackStrategyReviewDetailResponse {
+    type Request = UserGetBackStrategyReviewDetailRequest;
 }
 
-impl WsRequest for UserListDepositWithdrawLedgerRequest {
-    type Response = UserListDepositWithdrawLedgerResponse;
-    const METHOD_ID: u32 = 20380;
+impl WsRequest for UserListUserBackStrategyAttemptRequest {
+    type Response = UserListUserBackStrategyAttemptResponse;
+    const METHOD_ID: u32 = 20550;
     const SCHEMA: &'static str = r#"{
-  "name": "UserListDepositWithdrawLedger",
-  "code": 20380,
+  "name": "UserListUserBackStrategyAttempt",
+  "code": 20550,
   "parameters": [
     {
       "name": "limit",
@@ -8015,228 +12901,312 @@ impl WsRequest for UserListDepositWithdrawLedgerRequest {
       }
     },
     {
-      "name": "blockchain",
+      "name": "strategy_id",
       "ty": {
-        "Optional": {
-          "EnumRef": "block_chain"
-        }
+        "Optional": "BigInt"
       }
     },
     {
-      "name": "id_deposit",
+      "name": "token_id",
       "ty": {
-        "Optional": "Boolean"
+        "Optional": "BigInt"
       }
     }
   ],
   "returns": [
     {
-      "name": "ledger_total",
+      "name": "total",
       "ty": "BigInt"
     },
     {
-      "name": "ledger",
+      "name": "back_attempts",
       "ty": {
-        "Vec": {
-          "Struct": {
-            "name": "UserListDepositLedgerRow",
-            "fields": [
-              {
-                "name": "transaction_id",
-                "ty": "BigInt"
-              },
-              {
-                "name": "blockchain",
-                "ty": {
-                  "EnumRef": "block_chain"
-                }
-              },
-              {
-                "name": "user_address",
-                "ty": "BlockchainAddress"
-              },
-              {
-                "name": "contract_address",
-                "ty": "BlockchainAddress"
-              },
-              {
-                "name": "receiver_address",
-                "ty": "BlockchainAddress"
-              },
-              {
-                "name": "quantity",
-                "ty": "BlockchainDecimal"
-              },
-              {
-                "name": "transaction_hash",
-                "ty": "BlockchainTransactionHash"
-              },
-              {
-                "name": "is_deposit",
-                "ty": "Boolean"
-              },
-              {
-                "name": "happened_at",
-                "ty": "BigInt"
-              }
-            ]
-          }
+        "DataTable": {
+          "name": "UserBackStrategyAttempt",
+          "fields": [
+            {
+              "name": "attempt_id",
+              "ty": "BigInt"
+            },
+            {
+              "name": "strategy_id",
+              "ty": "BigInt"
+            },
+            {
+              "name": "strategy_name",
+              "ty": "String"
+            },
+            {
+              "name": "token_id",
+              "ty": "BigInt"
+            },
+            {
+              "name": "token_symbol",
+              "ty": "String"
+            },
+            {
+              "name": "token_name",
+              "ty": "String"
+            },
+            {
+              "name": "quantity",
+              "ty": "BlockchainDecimal"
+            },
+            {
+              "name": "happened_at",
+              "ty": "BigInt"
+            }
+          ]
         }
       }
     }
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
-impl WsResponse for UserListDepositWithdrawLedgerResponse {
-    type Request = UserListDepositWithdrawLedgerRequest;
+impl WsResponse for UserListUserBackStrategyAttemptResponse {
+    type Request = UserListUserBackStrategyAttemptRequest;
 }
 
-impl WsRequest for UserSubscribeDepositLedgerRequest {
-    type Response = UserSubscribeDepositLedgerResponse;
-    const METHOD_ID: u32 = 20381;
+impl WsRequest for UserListUserBackStrategyLogRequest {
+    type Response = UserListUserBackStrategyLogResponse;
+    const METHOD_ID: u32 = 20560;
     const SCHEMA: &'static str = r#"{
-  "name": "UserSubscribeDepositLedger",
-  "code": 20381,
+  "name": "UserListUserBackStrategyLog",
+  "code": 20560,
   "parameters": [
     {
-      "name": "initial_data",
-      "ty": {
-        "Optional": "BigInt"
-      }
+      "name": "attempt_id",
+      "ty": "BigInt"
     },
     {
-      "name": "blockchain",
+      "name": "limit",
       "ty": {
-        "Optional": {
-          "EnumRef": "block_chain"
-        }
+        "Optional": "BigInt"
       }
     },
     {
-      "name": "mock_data",
+      "name": "offset",
       "ty": {
-        "Optional": "Boolean"
+        "Optional": "BigInt"
       }
-    }
-  ],
-  "returns": [],
-  "stream_response": {
-    "Struct": {
-      "name": "UserListDepositLedgerRow",
-      "fields": [
-        {
-          "name": "transaction_id",
-          "ty": "BigInt"
-        },
-        {
-          "name": "blockchain",
-          "ty": {
-            "EnumRef": "block_chain"
-          }
-        },
-        {
-          "name": "user_address",
-          "ty": "BlockchainAddress"
-        },
-        {
-          "name": "contract_address",
-          "ty": "BlockchainAddress"
-        },
-        {
-          "name": "receiver_address",
-          "ty": "BlockchainAddress"
-        },
-        {
-          "name": "quantity",
-          "ty": "BlockchainDecimal"
-        },
-        {
-          "name": "transaction_hash",
-          "ty": "BlockchainTransactionHash"
-        },
-        {
-          "name": "is_deposit",
-          "ty": "Boolean"
-        },
-        {
-          "name": "happened_at",
-          "ty": "BigInt"
+    }
+  ],
+  "returns": [
+    {
+      "name": "back_logs_total",
+      "ty": "BigInt"
+    },
+    {
+      "name": "back_logs",
+      "ty": {
+        "DataTable": {
+          "name": "UserBackStrategyLog",
+          "fields": [
+            {
+              "name": "pkey_id",
+              "ty": "BigInt"
+            },
+            {
+              "name": "message",
+              "ty": "String"
+            },
+            {
+              "name": "happened_at",
+              "ty": "BigInt"
+            }
+          ]
         }
-      ]
+      }
     }
-  },
+  ],
+  "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
-impl WsResponse for UserSubscribeDepositLedgerResponse {
-    type Request = UserSubscribeDepositLedgerRequest;
+impl WsResponse for UserListUserBackStrategyLogResponse {
+    type Request = UserListUserBackStrategyLogRequest;
 }
 
-impl WsRequest for UserUnsubscribeDepositLedgerRequest {
-    type Response = UserUnsubscribeDepositLedgerResponse;
-    const METHOD_ID: u32 = 20382;
+impl WsRequest for UserGetSystemConfigRequest {
+    type Response = UserGetSystemConfigResponse;
+    const METHOD_ID: u32 = 20570;
     const SCHEMA: &'static str = r#"{
-  "name": "UserUnsubscribeDepositLedger",
-  "code": 20382,
+  "name": "UserGetSystemConfig",
+  "code": 20570,
   "parameters": [],
-  "returns": [],
+  "returns": [
+    {
+      "name": "platform_fee",
+      "ty": "Numeric"
+    }
+  ],
   "stream_response": null,
-  "description": "",
-  "json_schema": null
+  "description": "User get system config",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
-impl WsResponse for UserUnsubscribeDepositLedgerResponse {
-    type Request = UserUnsubscribeDepositLedgerRequest;
+impl WsResponse for UserGetSystemConfigResponse {
+    type Request = UserGetSystemConfigRequest;
 }
 
-impl WsRequest for UserListStrategyWalletsRequest {
-    type Response = UserListStrategyWalletsResponse;
-    const METHOD_ID: u32 = 20390;
+impl WsRequest for UserListUserStrategyBalanceRequest {
+    type Response = UserListUserStrategyBalanceResponse;
+    const METHOD_ID: u32 = 20580;
     const SCHEMA: &'static str = r#"{
-  "name": "UserListStrategyWallets",
-  "code": 20390,
+  "name": "UserListUserStrategyBalance",
+  "code": 20580,
   "parameters": [
     {
-      "name": "blockchain",
+      "name": "limit",
       "ty": {
-        "Optional": {
-          "EnumRef": "block_chain"
-        }
+        "Optional": "BigInt"
+      }
+    },
+    {
+      "name": "offset",
+      "ty": {
+        "Optional": "BigInt"
+      }
+    },
+    {
+      "name": "strategy_id",
+      "ty": {
+        "Optional": "BigInt"
       }
     }
   ],
   "returns": [
     {
-      "name": "wallets_total",
+      "name": "balances_total",
       "ty": "BigInt"
     },
     {
-      "name": "wallets",
+      "name": "balances",
       "ty": {
         "DataTable": {
-          "name": "UserListStrategyWalletsRow",
+          "name": "UserStrategyBalance",
           "fields": [
             {
-              "name": "blockchain",
-              "ty": {
-                "EnumRef": "block_chain"
-              }
+              "name": "strategy_id",
+              "ty": "BigInt"
             },
             {
-              "name": "address",
-              "ty": "BlockchainAddress"
+              "name": "strategy_name",
+              "ty": "String"
             },
             {
-              "name": "is_platform_managed",
-              "ty": "Boolean"
+              "name": "balance",
+              "ty": "BlockchainDecimal"
             },
             {
-              "name": "created_at",
-              "ty": "BigInt"
+              "name": "address",
+              "ty": "BlockchainAddress"
+            },
+            {
+              "name": "blockchain",
+              "ty": {
+                "EnumRef": "block_chain"
+              }
             }
           ]
         }
@@ -8245,63 +13215,84 @@ impl WsRequest for UserListStrategyWalletsRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
-impl WsResponse for UserListStrategyWalletsResponse {
-    type Request = UserListStrategyWalletsRequest;
+impl WsResponse for UserListUserStrategyBalanceResponse {
+    type Request = UserListUserStrategyBalanceRequest;
 }
 
-impl WsRequest for UserCreateStrategyWalletRequest {
-    type Response = UserCreateStrategyWalletResponse;
-    const METHOD_ID: u32 = 20391;
+impl WsRequest for UserExportActivityReportRequest {
+    type Response = UserExportActivityReportResponse;
+    const METHOD_ID: u32 = 20590;
     const SCHEMA: &'static str = r#"{
-  "name": "UserCreateStrategyWallet",
-  "code": 20391,
+  "name": "UserExportActivityReport",
+  "code": 20590,
   "parameters": [
+    {
+      "name": "start_time",
+      "ty": "BigInt"
+    },
+    {
+      "name": "end_time",
+      "ty": "BigInt"
+    },
     {
       "name": "blockchain",
       "ty": {
-        "EnumRef": "block_chain"
+        "Optional": {
+          "EnumRef": "block_chain"
+        }
       }
     },
     {
-      "name": "user_managed_wallet_address",
+      "name": "format",
       "ty": {
-        "Optional": "BlockchainAddress"
+        "Optional": {
+          "EnumRef": "activity_report_format"
+        }
       }
-    }
-  ],
-  "returns": [
+    },
     {
-      "name": "blockchain",
+      "name": "limit",
       "ty": {
-        "EnumRef": "block_chain"
+        "Optional": "BigInt"
       }
     },
     {
-      "name": "address",
-      "ty": "BlockchainAddress"
-    }
-  ],
-  "stream_response": null,
-  "description": "",
-  "json_schema": null
-}"#;
-}
-impl WsResponse for UserCreateStrategyWalletResponse {
-    type Request = UserCreateStrategyWalletRequest;
-}
-
-impl WsRequest for UserListStrategyAuditRulesRequest {
-    type Response = UserListStrategyAuditRulesResponse;
-    const METHOD_ID: u32 = 20400;
-    const SCHEMA: &'static str = r#"{
-  "name": "UserListStrategyAuditRules",
-  "code": 20400,
-  "parameters": [
-    {
-      "name": "strategy_id",
+      "name": "offset",
       "ty": {
         "Optional": "BigInt"
       }
@@ -8309,77 +13300,207 @@ impl WsRequest for UserListStrategyAuditRulesRequest {
   ],
   "returns": [
     {
-      "name": "audit_rules",
+      "name": "report_total",
+      "ty": "BigInt"
+    },
+    {
+      "name": "report",
       "ty": {
         "DataTable": {
-          "name": "UserListStrategyAuditRulesRow",
+          "name": "UserActivityReportRow",
           "fields": [
             {
-              "name": "rule_id",
-              "ty": "BigInt"
+              "name": "event_type",
+              "ty": {
+                "EnumRef": "user_activity_event_type"
+              }
             },
             {
-              "name": "rule_name",
-              "ty": "String"
+              "name": "blockchain",
+              "ty": {
+                "EnumRef": "block_chain"
+              }
             },
             {
-              "name": "rule_description",
+              "name": "strategy_id",
+              "ty": {
+                "Optional": "BigInt"
+              }
+            },
+            {
+              "name": "transaction_hash",
+              "ty": {
+                "Optional": "BlockchainTransactionHash"
+              }
+            },
+            {
+              "name": "token_symbol",
               "ty": "String"
             },
             {
-              "name": "created_at",
-              "ty": "BigInt"
+              "name": "token_address",
+              "ty": {
+                "Optional": "BlockchainAddress"
+              }
             },
             {
-              "name": "enabled",
-              "ty": "Boolean"
+              "name": "quantity",
+              "ty": "BlockchainDecimal"
+            },
+            {
+              "name": "usd_price",
+              "ty": {
+                "Optional": "Numeric"
+              }
+            },
+            {
+              "name": "usd_value",
+              "ty": {
+                "Optional": "Numeric"
+              }
+            },
+            {
+              "name": "fees",
+              "ty": "BlockchainDecimal"
+            },
+            {
+              "name": "happened_at",
+              "ty": "BigInt"
             }
           ]
         }
       }
+    },
+    {
+      "name": "csv",
+      "ty": {
+        "Optional": "String"
+      }
+    },
+    {
+      "name": "truncated",
+      "ty": "Boolean"
     }
   ],
   "stream_response": null,
-  "description": "",
-  "json_schema": null
+  "description": "User exports deposits, withdrawals, refunds, backs, exits and pool asset movements in a time range, as rows or as CSV. report_total counts every row in the range, a page holds at most limit of them. A CSV export holds the whole range up to 100000 rows, truncated tells when rows were left out. Back fees are the ones charged when the strategy was backed",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
-impl WsResponse for UserListStrategyAuditRulesResponse {
-    type Request = UserListStrategyAuditRulesRequest;
+impl WsResponse for UserExportActivityReportResponse {
+    type Request = UserExportActivityReportRequest;
 }
 
-impl WsRequest for UserAddStrategyAuditRuleRequest {
-    type Response = UserAddStrategyAuditRuleResponse;
-    const METHOD_ID: u32 = 20410;
+impl WsRequest for ExpertAddStrategyGuardRuleRequest {
+    type Response = ExpertAddStrategyGuardRuleResponse;
+    const METHOD_ID: u32 = 20600;
     const SCHEMA: &'static str = r#"{
-  "name": "UserAddStrategyAuditRule",
-  "code": 20410,
+  "name": "ExpertAddStrategyGuardRule",
+  "code": 20600,
   "parameters": [
     {
       "name": "strategy_id",
       "ty": "BigInt"
     },
+    {
+      "name": "rule_type",
+      "ty": {
+        "EnumRef": "strategy_guard_rule_type"
+      }
+    },
+    {
+      "name": "token_id",
+      "ty": {
+        "Optional": "BigInt"
+      }
+    },
+    {
+      "name": "threshold",
+      "ty": "Numeric"
+    },
+    {
+      "name": "exit_token_id",
+      "ty": {
+        "Optional": "BigInt"
+      }
+    }
+  ],
+  "returns": [
     {
       "name": "rule_id",
       "ty": "BigInt"
     }
   ],
-  "returns": [],
   "stream_response": null,
-  "description": "",
-  "json_schema": null
+  "description": "Expert adds a max drawdown, asset stop-loss or take-profit guard that pauses copy trading when triggered",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
-impl WsResponse for UserAddStrategyAuditRuleResponse {
-    type Request = UserAddStrategyAuditRuleRequest;
+impl WsResponse for ExpertAddStrategyGuardRuleResponse {
+    type Request = ExpertAddStrategyGuardRuleRequest;
 }
 
-impl WsRequest for UserRemoveStrategyAuditRuleRequest {
-    type Response = UserRemoveStrategyAuditRuleResponse;
-    const METHOD_ID: u32 = 20420;
+impl WsRequest for ExpertRemoveStrategyGuardRuleRequest {
+    type Response = ExpertRemoveStrategyGuardRuleResponse;
+    const METHOD_ID: u32 = 20610;
     const SCHEMA: &'static str = r#"{
-  "name": "UserRemoveStrategyAuditRule",
-  "code": 20420,
+  "name": "ExpertRemoveStrategyGuardRule",
+  "code": 20610,
   "parameters": [
     {
       "name": "strategy_id",
@@ -8390,116 +13511,120 @@ impl WsRequest for UserRemoveStrategyAuditRuleRequest {
       "ty": "BigInt"
     }
   ],
-  "returns": [],
+  "returns": [
+    {
+      "name": "success",
+      "ty": "Boolean"
+    }
+  ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
-impl WsResponse for UserRemoveStrategyAuditRuleResponse {
-    type Request = UserRemoveStrategyAuditRuleRequest;
+impl WsResponse for ExpertRemoveStrategyGuardRuleResponse {
+    type Request = ExpertRemoveStrategyGuardRuleRequest;
 }
 
-impl WsRequest for UserGetEscrowAddressForStrategyRequest {
-    type Response = UserGetEscrowAddressForStrategyResponse;
-    const METHOD_ID: u32 = 20500;
+impl WsRequest for UserListStrategyGuardRulesRequest {
+    type Response = UserListStrategyGuardRulesResponse;
+    const METHOD_ID: u32 = 20620;
     const SCHEMA: &'static str = r#"{
-  "name": "UserGetEscrowAddressForStrategy",
-  "code": 20500,
+  "name": "UserListStrategyGuardRules",
+  "code": 20620,
   "parameters": [
     {
       "name": "strategy_id",
       "ty": "BigInt"
-    },
-    {
-      "name": "token_id",
-      "ty": {
-        "Optional": "BigInt"
-      }
     }
   ],
   "returns": [
     {
-      "name": "tokens",
+      "name": "rules_total",
+      "ty": "BigInt"
+    },
+    {
+      "name": "rules",
       "ty": {
         "DataTable": {
-          "name": "UserAllowedEscrowTransferInfo",
+          "name": "ListStrategyGuardRulesRow",
           "fields": [
             {
-              "name": "receiver_address",
-              "ty": "BlockchainAddress"
+              "name": "rule_id",
+              "ty": "BigInt"
             },
             {
-              "name": "blockchain",
+              "name": "rule_type",
               "ty": {
-                "EnumRef": "block_chain"
+                "EnumRef": "strategy_guard_rule_type"
               }
             },
             {
               "name": "token_id",
-              "ty": "BigInt"
+              "ty": {
+                "Optional": "BigInt"
+              }
             },
             {
               "name": "token_symbol",
-              "ty": "String"
+              "ty": {
+                "Optional": "String"
+              }
             },
             {
-              "name": "token_name",
-              "ty": "String"
+              "name": "threshold",
+              "ty": "Numeric"
             },
             {
-              "name": "token_address",
-              "ty": "BlockchainAddress"
-            }
-          ]
-        }
-      }
-    }
-  ],
-  "stream_response": null,
-  "description": "",
-  "json_schema": null
-}"#;
-}
-impl WsResponse for UserGetEscrowAddressForStrategyResponse {
-    type Request = UserGetEscrowAddressForStrategyRequest;
-}
-
-impl WsRequest for UserListDepositWithdrawBalancesRequest {
-    type Response = UserListDepositWithdrawBalancesResponse;
-    const METHOD_ID: u32 = 20510;
-    const SCHEMA: &'static str = r#"{
-  "name": "UserListDepositWithdrawBalances",
-  "code": 20510,
-  "parameters": [],
-  "returns": [
-    {
-      "name": "balances",
-      "ty": {
-        "DataTable": {
-          "name": "UserListDepositWithdrawBalance",
-          "fields": [
-            {
-              "name": "blockchain",
+              "name": "reference_value",
               "ty": {
-                "EnumRef": "block_chain"
+                "Optional": "Numeric"
               }
             },
             {
-              "name": "token_id",
-              "ty": "BigInt"
+              "name": "exit_token_id",
+              "ty": {
+                "Optional": "BigInt"
+              }
             },
             {
-              "name": "token_symbol",
-              "ty": "String"
+              "name": "exit_token_symbol",
+              "ty": {
+                "Optional": "String"
+              }
             },
             {
-              "name": "token_name",
-              "ty": "String"
+              "name": "triggered_at",
+              "ty": {
+                "Optional": "BigInt"
+              }
             },
             {
-              "name": "balance",
-              "ty": "BlockchainDecimal"
+              "name": "created_at",
+              "ty": "BigInt"
             }
           ]
         }
@@ -8508,114 +13633,311 @@ impl WsRequest for UserListDepositWithdrawBalancesRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
-impl WsResponse for UserListDepositWithdrawBalancesResponse {
-    type Request = UserListDepositWithdrawBalancesRequest;
+impl WsResponse for UserListStrategyGuardRulesResponse {
+    type Request = UserListStrategyGuardRulesRequest;
 }
 
-impl WsRequest for UserGetDepositWithdrawBalanceRequest {
-    type Response = UserGetDepositWithdrawBalanceResponse;
-    const METHOD_ID: u32 = 20511;
+impl WsRequest for ExpertResumeStrategyCopyTradingRequest {
+    type Response = ExpertResumeStrategyCopyTradingResponse;
+    const METHOD_ID: u32 = 20630;
     const SCHEMA: &'static str = r#"{
-  "name": "UserGetDepositWithdrawBalance",
-  "code": 20511,
+  "name": "ExpertResumeStrategyCopyTrading",
+  "code": 20630,
   "parameters": [
     {
-      "name": "token_id",
-      "ty": "BigInt"
+      "name": "strategy_id",
+      "ty": "BigInt"
+    }
+  ],
+  "returns": [
+    {
+      "name": "success",
+      "ty": "Boolean"
+    }
+  ],
+  "stream_response": null,
+  "description": "Expert resumes copy trading paused by a guard and re-arms triggered guards",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
+}"#;
+}
+impl WsResponse for ExpertResumeStrategyCopyTradingResponse {
+    type Request = ExpertResumeStrategyCopyTradingRequest;
+}
+
+impl WsRequest for UserSubscribeStrategyGuardEventsRequest {
+    type Response = UserSubscribeStrategyGuardEventsResponse;
+    const METHOD_ID: u32 = 20640;
+    const SCHEMA: &'static str = r#"{
+  "name": "UserSubscribeStrategyGuardEvents",
+  "code": 20640,
+  "parameters": [],
+  "returns": [],
+  "stream_response": {
+    "Struct": {
+      "name": "StrategyGuardEvent",
+      "fields": [
+        {
+          "name": "strategy_id",
+          "ty": "BigInt"
+        },
+        {
+          "name": "rule_id",
+          "ty": "BigInt"
+        },
+        {
+          "name": "blockchain",
+          "ty": {
+            "EnumRef": "block_chain"
+          }
+        },
+        {
+          "name": "rule_type",
+          "ty": {
+            "EnumRef": "strategy_guard_rule_type"
+          }
+        },
+        {
+          "name": "threshold",
+          "ty": "Numeric"
+        },
+        {
+          "name": "reference_value",
+          "ty": "Numeric"
+        },
+        {
+          "name": "current_value",
+          "ty": "Numeric"
+        },
+        {
+          "name": "exit_token_address",
+          "ty": {
+            "Optional": "BlockchainAddress"
+          }
+        },
+        {
+          "name": "triggered_at",
+          "ty": "BigInt"
+        }
+      ]
+    }
+  },
+  "description": "",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
     }
   ],
-  "returns": [
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
+}"#;
+}
+impl WsResponse for UserSubscribeStrategyGuardEventsResponse {
+    type Request = UserSubscribeStrategyGuardEventsRequest;
+}
+
+impl WsRequest for UserUnsubscribeStrategyGuardEventsRequest {
+    type Response = UserUnsubscribeStrategyGuardEventsResponse;
+    const METHOD_ID: u32 = 20641;
+    const SCHEMA: &'static str = r#"{
+  "name": "UserUnsubscribeStrategyGuardEvents",
+  "code": 20641,
+  "parameters": [],
+  "returns": [],
+  "stream_response": null,
+  "description": "",
+  "json_schema": null,
+  "roles": [
     {
-      "name": "balance",
-      "ty": "BlockchainDecimal"
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
     }
   ],
-  "stream_response": null,
-  "description": "",
-  "json_schema": null
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
-impl WsResponse for UserGetDepositWithdrawBalanceResponse {
-    type Request = UserGetDepositWithdrawBalanceRequest;
+impl WsResponse for UserUnsubscribeStrategyGuardEventsResponse {
+    type Request = UserUnsubscribeStrategyGuardEventsRequest;
 }
 
-impl WsRequest for UserListEscrowTokenContractAddressesRequest {
-    type Response = UserListEscrowTokenContractAddressesResponse;
-    const METHOD_ID: u32 = 20520;
+impl WsRequest for UserListNotificationsRequest {
+    type Response = UserListNotificationsResponse;
+    const METHOD_ID: u32 = 20650;
     const SCHEMA: &'static str = r#"{
-  "name": "UserListEscrowTokenContractAddresses",
-  "code": 20520,
+  "name": "UserListNotifications",
+  "code": 20650,
   "parameters": [
     {
-      "name": "limit",
+      "name": "unread_only",
       "ty": {
-        "Optional": "BigInt"
+        "Optional": "Boolean"
       }
     },
     {
-      "name": "offset",
+      "name": "limit",
       "ty": {
         "Optional": "BigInt"
       }
     },
     {
-      "name": "blockchain",
-      "ty": {
-        "Optional": {
-          "EnumRef": "block_chain"
-        }
-      }
-    },
-    {
-      "name": "is_stablecoin",
+      "name": "offset",
       "ty": {
-        "Optional": "Boolean"
+        "Optional": "BigInt"
       }
     }
   ],
   "returns": [
     {
-      "name": "tokens_total",
+      "name": "notifications_total",
       "ty": "BigInt"
     },
     {
-      "name": "tokens",
+      "name": "unread_total",
+      "ty": "BigInt"
+    },
+    {
+      "name": "notifications",
       "ty": {
         "DataTable": {
-          "name": "UserListEscrowTokenContractAddressesRow",
+          "name": "UserNotificationRow",
           "fields": [
             {
-              "name": "token_id",
+              "name": "notification_id",
               "ty": "BigInt"
             },
             {
-              "name": "token_symbol",
-              "ty": "String"
-            },
-            {
-              "name": "token_name",
-              "ty": "String"
+              "name": "kind",
+              "ty": {
+                "EnumRef": "user_notification_kind"
+              }
             },
             {
-              "name": "token_address",
-              "ty": "BlockchainAddress"
+              "name": "strategy_id",
+              "ty": {
+                "Optional": "BigInt"
+              }
             },
             {
-              "name": "description",
+              "name": "message",
               "ty": "String"
             },
             {
-              "name": "blockchain",
+              "name": "read_at",
               "ty": {
-                "EnumRef": "block_chain"
+                "Optional": "BigInt"
               }
             },
             {
-              "name": "is_stablecoin",
-              "ty": "Boolean"
+              "name": "created_at",
+              "ty": "BigInt"
             }
           ]
         }
@@ -8623,221 +13945,286 @@ impl WsRequest for UserListEscrowTokenContractAddressesRequest {
     }
   ],
   "stream_response": null,
-  "description": "",
-  "json_schema": null
+  "description": "User lists notifications about strategy trades, backs, exits, refunds and followed experts, newest first",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
 }"#;
 }
-impl WsResponse for UserListEscrowTokenContractAddressesResponse {
-    type Request = UserListEscrowTokenContractAddressesRequest;
+impl WsResponse for UserListNotificationsResponse {
+    type Request = UserListNotificationsRequest;
 }
 
-impl WsRequest for UserListStrategyTokenBalanceRequest {
-    type Response = UserListStrategyTokenBalanceResponse;
-    const METHOD_ID: u32 = 20530;
+impl WsRequest for UserMarkNotificationsReadRequest {
+    type Response = UserMarkNotificationsReadResponse;
+    const METHOD_ID: u32 = 20660;
     const SCHEMA: &'static str = r#"{
-  "name": "UserListStrategyTokenBalance",
-  "code": 20530,
+  "name": "UserMarkNotificationsRead",
+  "code": 20660,
   "parameters": [
     {
-      "name": "limit",
-      "ty": {
-        "Optional": "BigInt"
-      }
-    },
-    {
-      "name": "offset",
-      "ty": {
-        "Optional": "BigInt"
-      }
-    },
-    {
-      "name": "strategy_id",
+      "name": "notification_ids",
       "ty": {
-        "Optional": "BigInt"
+        "Optional": {
+          "Vec": "BigInt"
+        }
       }
     }
   ],
   "returns": [
     {
-      "name": "tokens_total",
+      "name": "marked",
       "ty": "BigInt"
+    }
+  ],
+  "stream_response": null,
+  "description": "User marks the given notifications as read, or all of them if none are given",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
     },
     {
-      "name": "tokens",
-      "ty": {
-        "DataTable": {
-          "name": "UserListStrategyTokenBalanceRow",
-          "fields": [
-            {
-              "name": "strategy_id",
-              "ty": "BigInt"
-            },
-            {
-              "name": "strategy_name",
-              "ty": "String"
-            },
-            {
-              "name": "balance",
-              "ty": "BlockchainDecimal"
-            },
-            {
-              "name": "address",
-              "ty": "BlockchainAddress"
-            },
-            {
-              "name": "blockchain",
-              "ty": {
-                "EnumRef": "block_chain"
-              }
-            }
-          ]
-        }
-      }
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
     }
   ],
-  "stream_response": null,
-  "description": "",
-  "json_schema": null
+  "api_key_scope": null
 }"#;
 }
-impl WsResponse for UserListStrategyTokenBalanceResponse {
-    type Request = UserListStrategyTokenBalanceRequest;
+impl WsResponse for UserMarkNotificationsReadResponse {
+    type Request = UserMarkNotificationsReadRequest;
 }
 
-impl WsRequest for UserGetBackStrategyReviewDetailRequest {
-    type Response = UserGetBackStrategyReviewDetailResponse;
-    const METHOD_ID: u32 = 20540;
+impl WsRequest for UserSubscribeNotificationsRequest {
+    type Response = UserSubscribeNotificationsResponse;
+    const METHOD_ID: u32 = 20670;
     const SCHEMA: &'static str = r#"{
-  "name": "UserGetBackStrategyReviewDetail",
-  "code": 20540,
-  "parameters": [
+  "name": "UserSubscribeNotifications",
+  "code": 20670,
+  "parameters": [],
+  "returns": [],
+  "stream_response": {
+    "Struct": {
+      "name": "UserNotification",
+      "fields": [
+        {
+          "name": "notification_id",
+          "ty": "BigInt"
+        },
+        {
+          "name": "kind",
+          "ty": {
+            "EnumRef": "user_notification_kind"
+          }
+        },
+        {
+          "name": "strategy_id",
+          "ty": {
+            "Optional": "BigInt"
+          }
+        },
+        {
+          "name": "message",
+          "ty": "String"
+        },
+        {
+          "name": "read_at",
+          "ty": {
+            "Optional": "BigInt"
+          }
+        },
+        {
+          "name": "created_at",
+          "ty": "BigInt"
+        }
+      ]
+    }
+  },
+  "description": "User receives new notifications as they are created",
+  "json_schema": null,
+  "roles": [
     {
-      "name": "strategy_id",
-      "ty": "BigInt"
+      "name": "user",
+      "value": 1,
+      "comment": ""
     },
     {
-      "name": "token_id",
-      "ty": "BigInt"
+      "name": "expert",
+      "value": 2,
+      "comment": ""
     },
     {
-      "name": "quantity",
-      "ty": "BlockchainDecimal"
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
     }
   ],
-  "returns": [
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
+}"#;
+}
+impl WsResponse for UserSubscribeNotificationsResponse {
+    type Request = UserSubscribeNotificationsRequest;
+}
+
+impl WsRequest for UserUnsubscribeNotificationsRequest {
+    type Response = UserUnsubscribeNotificationsResponse;
+    const METHOD_ID: u32 = 20671;
+    const SCHEMA: &'static str = r#"{
+  "name": "UserUnsubscribeNotifications",
+  "code": 20671,
+  "parameters": [],
+  "returns": [],
+  "stream_response": null,
+  "description": "",
+  "json_schema": null,
+  "roles": [
     {
-      "name": "strategy_fee",
-      "ty": "BlockchainDecimal"
+      "name": "user",
+      "value": 1,
+      "comment": ""
     },
     {
-      "name": "total_amount_to_back",
-      "ty": "BlockchainDecimal"
+      "name": "expert",
+      "value": 2,
+      "comment": ""
     },
     {
-      "name": "total_amount_to_back_after_fee",
-      "ty": "BlockchainDecimal"
+      "name": "admin",
+      "value": 3,
+      "comment": ""
     },
     {
-      "name": "user_strategy_wallets",
-      "ty": {
-        "DataTable": {
-          "name": "UserStrategyWallet",
-          "fields": [
-            {
-              "name": "wallet_id",
-              "ty": "BigInt"
-            },
-            {
-              "name": "address",
-              "ty": "BlockchainAddress"
-            },
-            {
-              "name": "blockchain",
-              "ty": {
-                "EnumRef": "block_chain"
-              }
-            },
-            {
-              "name": "is_platform_address",
-              "ty": "Boolean"
-            }
-          ]
-        }
-      }
+      "name": "developer",
+      "value": 4,
+      "comment": ""
     },
     {
-      "name": "estimated_amount_of_strategy_tokens",
-      "ty": "BlockchainDecimal"
-    },
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": {
+    "name": "ReadOnly",
+    "value": 0,
+    "comment": ""
+  }
+}"#;
+}
+impl WsResponse for UserUnsubscribeNotificationsResponse {
+    type Request = UserUnsubscribeNotificationsRequest;
+}
+
+impl WsRequest for UserListSessionsRequest {
+    type Response = UserListSessionsResponse;
+    const METHOD_ID: u32 = 20680;
+    const SCHEMA: &'static str = r#"{
+  "name": "UserListSessions",
+  "code": 20680,
+  "parameters": [],
+  "returns": [
     {
-      "name": "estimated_backed_token_ratios",
+      "name": "sessions",
       "ty": {
         "DataTable": {
-          "name": "EstimatedBackedTokenRatios",
+          "name": "UserSessionRow",
           "fields": [
             {
-              "name": "token_id",
+              "name": "session_id",
               "ty": "BigInt"
             },
             {
-              "name": "token_name",
-              "ty": "String"
-            },
-            {
-              "name": "back_amount",
-              "ty": "BlockchainDecimal"
-            },
-            {
-              "name": "back_value_in_usd",
-              "ty": "BlockchainDecimal"
+              "name": "service",
+              "ty": {
+                "EnumRef": "service"
+              }
             },
             {
-              "name": "back_value_ratio",
-              "ty": "Numeric"
-            }
-          ]
-        }
-      }
-    },
-    {
-      "name": "strategy_pool_asset_balances",
-      "ty": {
-        "DataTable": {
-          "name": "StrategyPoolAssetBalancesRow",
-          "fields": [
-            {
-              "name": "name",
+              "name": "device_id",
               "ty": "String"
             },
             {
-              "name": "symbol",
+              "name": "device_os",
               "ty": "String"
             },
             {
-              "name": "address",
-              "ty": "BlockchainAddress"
-            },
-            {
-              "name": "blockchain",
-              "ty": {
-                "EnumRef": "block_chain"
-              }
-            },
-            {
-              "name": "balance",
-              "ty": "BlockchainDecimal"
+              "name": "ip_address",
+              "ty": "Inet"
             },
             {
-              "name": "price_usd",
-              "ty": "Numeric"
+              "name": "created_at",
+              "ty": "BigInt"
             },
             {
-              "name": "price_usd_7d",
-              "ty": "Numeric"
+              "name": "last_seen_at",
+              "ty": "BigInt"
             },
             {
-              "name": "price_usd_30d",
-              "ty": "Numeric"
+              "name": "expires_at",
+              "ty": "BigInt"
             }
           ]
         }
@@ -8845,41 +14232,121 @@ impl WsRequest for UserGetBackStrategyReviewDetailRequest {
     }
   ],
   "stream_response": null,
-  "description": "",
-  "json_schema": null
+  "description": "User lists the devices it is logged in on, most recently seen first",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
-impl WsResponse for UserGetBackStrategyReviewDetailResponse {
-    type Request = UserGetBackStrategyReviewDetailRequest;
+impl WsResponse for UserListSessionsResponse {
+    type Request = UserListSessionsRequest;
 }
 
-impl WsRequest for UserListUserBackStrategyAttemptRequest {
-    type Response = UserListUserBackStrategyAttemptResponse;
-    const METHOD_ID: u32 = 20550;
+impl WsRequest for UserRevokeSessionRequest {
+    type Response = UserRevokeSessionResponse;
+    const METHOD_ID: u32 = 20690;
     const SCHEMA: &'static str = r#"{
-  "name": "UserListUserBackStrategyAttempt",
-  "code": 20550,
+  "name": "UserRevokeSession",
+  "code": 20690,
   "parameters": [
     {
-      "name": "limit",
-      "ty": {
-        "Optional": "BigInt"
-      }
+      "name": "session_id",
+      "ty": "BigInt"
+    }
+  ],
+  "returns": [],
+  "stream_response": null,
+  "description": "User logs out a device, its tokens stop working and its connections are closed",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
     },
     {
-      "name": "offset",
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
+}"#;
+}
+impl WsResponse for UserRevokeSessionResponse {
+    type Request = UserRevokeSessionRequest;
+}
+
+impl WsRequest for UserCreateApiKeyRequest {
+    type Response = UserCreateApiKeyResponse;
+    const METHOD_ID: u32 = 20700;
+    const SCHEMA: &'static str = r#"{
+  "name": "UserCreateApiKey",
+  "code": 20700,
+  "parameters": [
+    {
+      "name": "name",
+      "ty": "String"
+    },
+    {
+      "name": "scopes",
       "ty": {
-        "Optional": "BigInt"
+        "Vec": {
+          "EnumRef": "api_key_scope"
+        }
       }
     },
     {
-      "name": "strategy_id",
+      "name": "ip_allowlist",
       "ty": {
-        "Optional": "BigInt"
+        "Optional": {
+          "Vec": "Inet"
+        }
       }
     },
     {
-      "name": "token_id",
+      "name": "expires_at",
       "ty": {
         "Optional": "BigInt"
       }
@@ -8887,46 +14354,112 @@ impl WsRequest for UserListUserBackStrategyAttemptRequest {
   ],
   "returns": [
     {
-      "name": "total",
+      "name": "api_key_id",
       "ty": "BigInt"
     },
     {
-      "name": "back_attempts",
+      "name": "api_key",
+      "ty": "String"
+    },
+    {
+      "name": "api_secret",
+      "ty": "String"
+    }
+  ],
+  "stream_response": null,
+  "description": "User creates an API key for programmatic access, the secret is only returned here",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
+}"#;
+}
+impl WsResponse for UserCreateApiKeyResponse {
+    type Request = UserCreateApiKeyRequest;
+}
+
+impl WsRequest for UserListApiKeysRequest {
+    type Response = UserListApiKeysResponse;
+    const METHOD_ID: u32 = 20710;
+    const SCHEMA: &'static str = r#"{
+  "name": "UserListApiKeys",
+  "code": 20710,
+  "parameters": [],
+  "returns": [
+    {
+      "name": "api_keys",
       "ty": {
         "DataTable": {
-          "name": "UserBackStrategyAttempt",
+          "name": "UserApiKeyRow",
           "fields": [
             {
-              "name": "attempt_id",
+              "name": "api_key_id",
               "ty": "BigInt"
             },
             {
-              "name": "strategy_id",
-              "ty": "BigInt"
+              "name": "name",
+              "ty": "String"
             },
             {
-              "name": "strategy_name",
+              "name": "api_key",
               "ty": "String"
             },
             {
-              "name": "token_id",
-              "ty": "BigInt"
+              "name": "scopes",
+              "ty": {
+                "Vec": {
+                  "EnumRef": "api_key_scope"
+                }
+              }
             },
             {
-              "name": "token_symbol",
-              "ty": "String"
+              "name": "ip_allowlist",
+              "ty": {
+                "Optional": {
+                  "Vec": "Inet"
+                }
+              }
             },
             {
-              "name": "token_name",
-              "ty": "String"
+              "name": "expires_at",
+              "ty": {
+                "Optional": "BigInt"
+              }
             },
             {
-              "name": "quantity",
-              "ty": "BlockchainDecimal"
+              "name": "created_at",
+              "ty": "BigInt"
             },
             {
-              "name": "happened_at",
-              "ty": "BigInt"
+              "name": "last_used_at",
+              "ty": {
+                "Optional": "BigInt"
+              }
             }
           ]
         }
@@ -8934,59 +14467,116 @@ impl WsRequest for UserListUserBackStrategyAttemptRequest {
     }
   ],
   "stream_response": null,
-  "description": "",
-  "json_schema": null
+  "description": "User lists its API keys that are not revoked",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
-impl WsResponse for UserListUserBackStrategyAttemptResponse {
-    type Request = UserListUserBackStrategyAttemptRequest;
+impl WsResponse for UserListApiKeysResponse {
+    type Request = UserListApiKeysRequest;
 }
 
-impl WsRequest for UserListUserBackStrategyLogRequest {
-    type Response = UserListUserBackStrategyLogResponse;
-    const METHOD_ID: u32 = 20560;
+impl WsRequest for UserRevokeApiKeyRequest {
+    type Response = UserRevokeApiKeyResponse;
+    const METHOD_ID: u32 = 20720;
     const SCHEMA: &'static str = r#"{
-  "name": "UserListUserBackStrategyLog",
-  "code": 20560,
+  "name": "UserRevokeApiKey",
+  "code": 20720,
   "parameters": [
     {
-      "name": "attempt_id",
+      "name": "api_key_id",
       "ty": "BigInt"
+    }
+  ],
+  "returns": [],
+  "stream_response": null,
+  "description": "User revokes an API key and closes the connections authorized with it",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
     },
     {
-      "name": "limit",
-      "ty": {
-        "Optional": "BigInt"
-      }
+      "name": "expert",
+      "value": 2,
+      "comment": ""
     },
     {
-      "name": "offset",
-      "ty": {
-        "Optional": "BigInt"
-      }
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
     }
   ],
+  "api_key_scope": null
+}"#;
+}
+impl WsResponse for UserRevokeApiKeyResponse {
+    type Request = UserRevokeApiKeyRequest;
+}
+
+impl WsRequest for UserListLoginWalletsRequest {
+    type Response = UserListLoginWalletsResponse;
+    const METHOD_ID: u32 = 20730;
+    const SCHEMA: &'static str = r#"{
+  "name": "UserListLoginWallets",
+  "code": 20730,
+  "parameters": [],
   "returns": [
     {
-      "name": "back_logs_total",
-      "ty": "BigInt"
-    },
-    {
-      "name": "back_logs",
+      "name": "wallets",
       "ty": {
         "DataTable": {
-          "name": "UserBackStrategyLog",
+          "name": "UserLoginWalletRow",
           "fields": [
             {
-              "name": "pkey_id",
-              "ty": "BigInt"
+              "name": "address",
+              "ty": "BlockchainAddress"
             },
             {
-              "name": "message",
-              "ty": "String"
+              "name": "is_primary",
+              "ty": "Boolean"
             },
             {
-              "name": "happened_at",
+              "name": "created_at",
               "ty": "BigInt"
             }
           ]
@@ -8995,107 +14585,198 @@ impl WsRequest for UserListUserBackStrategyLogRequest {
     }
   ],
   "stream_response": null,
-  "description": "",
-  "json_schema": null
+  "description": "User lists the wallets it can log in with, the primary one first",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
+}"#;
+}
+impl WsResponse for UserListLoginWalletsResponse {
+    type Request = UserListLoginWalletsRequest;
+}
+
+impl WsRequest for UserLinkLoginWalletRequest {
+    type Response = UserLinkLoginWalletResponse;
+    const METHOD_ID: u32 = 20740;
+    const SCHEMA: &'static str = r#"{
+  "name": "UserLinkLoginWallet",
+  "code": 20740,
+  "parameters": [
+    {
+      "name": "address",
+      "ty": "BlockchainAddress"
+    },
+    {
+      "name": "signature_text",
+      "ty": "String"
+    },
+    {
+      "name": "signature",
+      "ty": "String"
+    }
+  ],
+  "returns": [],
+  "stream_response": null,
+  "description": "User links another login wallet, proven by a SIWE message signed with a GetLoginNonce nonce",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
-impl WsResponse for UserListUserBackStrategyLogResponse {
-    type Request = UserListUserBackStrategyLogRequest;
+impl WsResponse for UserLinkLoginWalletResponse {
+    type Request = UserLinkLoginWalletRequest;
 }
 
-impl WsRequest for UserGetSystemConfigRequest {
-    type Response = UserGetSystemConfigResponse;
-    const METHOD_ID: u32 = 20570;
+impl WsRequest for UserUnlinkLoginWalletRequest {
+    type Response = UserUnlinkLoginWalletResponse;
+    const METHOD_ID: u32 = 20750;
     const SCHEMA: &'static str = r#"{
-  "name": "UserGetSystemConfig",
-  "code": 20570,
-  "parameters": [],
-  "returns": [
+  "name": "UserUnlinkLoginWallet",
+  "code": 20750,
+  "parameters": [
     {
-      "name": "platform_fee",
-      "ty": "Numeric"
+      "name": "address",
+      "ty": "BlockchainAddress"
     }
   ],
+  "returns": [],
   "stream_response": null,
-  "description": "User get system config",
-  "json_schema": null
+  "description": "User unlinks a login wallet that is not the primary one",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
+    },
+    {
+      "name": "expert",
+      "value": 2,
+      "comment": ""
+    },
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
-impl WsResponse for UserGetSystemConfigResponse {
-    type Request = UserGetSystemConfigRequest;
+impl WsResponse for UserUnlinkLoginWalletResponse {
+    type Request = UserUnlinkLoginWalletRequest;
 }
 
-impl WsRequest for UserListUserStrategyBalanceRequest {
-    type Response = UserListUserStrategyBalanceResponse;
-    const METHOD_ID: u32 = 20580;
+impl WsRequest for UserSetPrimaryLoginWalletRequest {
+    type Response = UserSetPrimaryLoginWalletResponse;
+    const METHOD_ID: u32 = 20760;
     const SCHEMA: &'static str = r#"{
-  "name": "UserListUserStrategyBalance",
-  "code": 20580,
+  "name": "UserSetPrimaryLoginWallet",
+  "code": 20760,
   "parameters": [
     {
-      "name": "limit",
-      "ty": {
-        "Optional": "BigInt"
-      }
+      "name": "address",
+      "ty": "BlockchainAddress"
+    }
+  ],
+  "returns": [],
+  "stream_response": null,
+  "description": "User makes a linked login wallet the address of the account",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "user",
+      "value": 1,
+      "comment": ""
     },
     {
-      "name": "offset",
-      "ty": {
-        "Optional": "BigInt"
-      }
+      "name": "expert",
+      "value": 2,
+      "comment": ""
     },
     {
-      "name": "strategy_id",
-      "ty": {
-        "Optional": "BigInt"
-      }
-    }
-  ],
-  "returns": [
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
     {
-      "name": "balances_total",
-      "ty": "BigInt"
+      "name": "developer",
+      "value": 4,
+      "comment": ""
     },
     {
-      "name": "balances",
-      "ty": {
-        "DataTable": {
-          "name": "UserStrategyBalance",
-          "fields": [
-            {
-              "name": "strategy_id",
-              "ty": "BigInt"
-            },
-            {
-              "name": "strategy_name",
-              "ty": "String"
-            },
-            {
-              "name": "balance",
-              "ty": "BlockchainDecimal"
-            },
-            {
-              "name": "address",
-              "ty": "BlockchainAddress"
-            },
-            {
-              "name": "blockchain",
-              "ty": {
-                "EnumRef": "block_chain"
-              }
-            }
-          ]
-        }
-      }
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
     }
   ],
-  "stream_response": null,
-  "description": "",
-  "json_schema": null
+  "api_key_scope": null
 }"#;
 }
-impl WsResponse for UserListUserStrategyBalanceResponse {
-    type Request = UserListUserStrategyBalanceRequest;
+impl WsResponse for UserSetPrimaryLoginWalletResponse {
+    type Request = UserSetPrimaryLoginWalletRequest;
 }
 
 impl WsRequest for AdminListUsersRequest {
@@ -9181,7 +14862,9 @@ impl WsRequest for AdminListUsersRequest {
             },
             {
               "name": "last_login_at",
-              "ty": "BigInt"
+              "ty": {
+                "Optional": "BigInt"
+              }
             },
             {
               "name": "login_count",
@@ -9214,7 +14897,25 @@ impl WsRequest for AdminListUsersRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for AdminListUsersResponse {
@@ -9242,7 +14943,25 @@ impl WsRequest for AdminSetUserRoleRequest {
   "returns": [],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for AdminSetUserRoleResponse {
@@ -9268,7 +14987,25 @@ impl WsRequest for AdminSetBlockUserRequest {
   "returns": [],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for AdminSetBlockUserResponse {
@@ -9356,24 +15093,281 @@ impl WsRequest for AdminListPendingExpertApplicationsRequest {
     }
   ],
   "stream_response": null,
-  "description": "Admin approves a user to become an expert",
-  "json_schema": null
+  "description": "Admin approves a user to become an expert",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
+}"#;
+}
+impl WsResponse for AdminListPendingExpertApplicationsResponse {
+    type Request = AdminListPendingExpertApplicationsRequest;
+}
+
+impl WsRequest for AdminApproveUserBecomeExpertRequest {
+    type Response = AdminApproveUserBecomeExpertResponse;
+    const METHOD_ID: u32 = 30040;
+    const SCHEMA: &'static str = r#"{
+  "name": "AdminApproveUserBecomeExpert",
+  "code": 30040,
+  "parameters": [
+    {
+      "name": "user_id",
+      "ty": "BigInt"
+    },
+    {
+      "name": "note",
+      "ty": {
+        "Optional": "String"
+      }
+    }
+  ],
+  "returns": [
+    {
+      "name": "success",
+      "ty": "Boolean"
+    }
+  ],
+  "stream_response": null,
+  "description": "Admin approves a user to become an expert",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
+}"#;
+}
+impl WsResponse for AdminApproveUserBecomeExpertResponse {
+    type Request = AdminApproveUserBecomeExpertRequest;
+}
+
+impl WsRequest for AdminGetExpertApplicationRequest {
+    type Response = AdminGetExpertApplicationResponse;
+    const METHOD_ID: u32 = 30041;
+    const SCHEMA: &'static str = r#"{
+  "name": "AdminGetExpertApplication",
+  "code": 30041,
+  "parameters": [
+    {
+      "name": "user_id",
+      "ty": "BigInt"
+    }
+  ],
+  "returns": [
+    {
+      "name": "application_id",
+      "ty": "BigInt"
+    },
+    {
+      "name": "status",
+      "ty": {
+        "EnumRef": "expert_application_status"
+      }
+    },
+    {
+      "name": "questionnaire",
+      "ty": "Object"
+    },
+    {
+      "name": "submitted_at",
+      "ty": {
+        "Optional": "BigInt"
+      }
+    },
+    {
+      "name": "reviewed_at",
+      "ty": {
+        "Optional": "BigInt"
+      }
+    },
+    {
+      "name": "documents",
+      "ty": {
+        "Vec": {
+          "Struct": {
+            "name": "ExpertApplicationDocumentRow",
+            "fields": [
+              {
+                "name": "document_id",
+                "ty": "BigInt"
+              },
+              {
+                "name": "name",
+                "ty": "String"
+              },
+              {
+                "name": "content_type",
+                "ty": "String"
+              },
+              {
+                "name": "size",
+                "ty": "BigInt"
+              },
+              {
+                "name": "created_at",
+                "ty": "BigInt"
+              }
+            ]
+          }
+        }
+      }
+    },
+    {
+      "name": "notes",
+      "ty": {
+        "Vec": {
+          "Struct": {
+            "name": "ExpertApplicationNoteRow",
+            "fields": [
+              {
+                "name": "status",
+                "ty": {
+                  "EnumRef": "expert_application_status"
+                }
+              },
+              {
+                "name": "note",
+                "ty": {
+                  "Optional": "String"
+                }
+              },
+              {
+                "name": "from_reviewer",
+                "ty": "Boolean"
+              },
+              {
+                "name": "created_at",
+                "ty": "BigInt"
+              }
+            ]
+          }
+        }
+      }
+    }
+  ],
+  "stream_response": null,
+  "description": "Admin gets the expert application of a user",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
-impl WsResponse for AdminListPendingExpertApplicationsResponse {
-    type Request = AdminListPendingExpertApplicationsRequest;
+impl WsResponse for AdminGetExpertApplicationResponse {
+    type Request = AdminGetExpertApplicationRequest;
 }
 
-impl WsRequest for AdminApproveUserBecomeExpertRequest {
-    type Response = AdminApproveUserBecomeExpertResponse;
-    const METHOD_ID: u32 = 30040;
+impl WsRequest for AdminGetExpertApplicationDocumentRequest {
+    type Response = AdminGetExpertApplicationDocumentResponse;
+    const METHOD_ID: u32 = 30042;
     const SCHEMA: &'static str = r#"{
-  "name": "AdminApproveUserBecomeExpert",
-  "code": 30040,
+  "name": "AdminGetExpertApplicationDocument",
+  "code": 30042,
+  "parameters": [
+    {
+      "name": "document_id",
+      "ty": "BigInt"
+    }
+  ],
+  "returns": [
+    {
+      "name": "name",
+      "ty": "String"
+    },
+    {
+      "name": "content_type",
+      "ty": "String"
+    },
+    {
+      "name": "content",
+      "ty": "String"
+    }
+  ],
+  "stream_response": null,
+  "description": "Admin downloads a base64 encoded expert application document",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
+}"#;
+}
+impl WsResponse for AdminGetExpertApplicationDocumentResponse {
+    type Request = AdminGetExpertApplicationDocumentRequest;
+}
+
+impl WsRequest for AdminRequestExpertApplicationInfoRequest {
+    type Response = AdminRequestExpertApplicationInfoResponse;
+    const METHOD_ID: u32 = 30045;
+    const SCHEMA: &'static str = r#"{
+  "name": "AdminRequestExpertApplicationInfo",
+  "code": 30045,
   "parameters": [
     {
       "name": "user_id",
       "ty": "BigInt"
+    },
+    {
+      "name": "note",
+      "ty": "String"
     }
   ],
   "returns": [
@@ -9383,12 +15377,30 @@ impl WsRequest for AdminApproveUserBecomeExpertRequest {
     }
   ],
   "stream_response": null,
-  "description": "Admin approves a user to become an expert",
-  "json_schema": null
+  "description": "Admin asks a user for more information on their expert application",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
-impl WsResponse for AdminApproveUserBecomeExpertResponse {
-    type Request = AdminApproveUserBecomeExpertRequest;
+impl WsResponse for AdminRequestExpertApplicationInfoResponse {
+    type Request = AdminRequestExpertApplicationInfoRequest;
 }
 
 impl WsRequest for AdminRejectUserBecomeExpertRequest {
@@ -9401,6 +15413,10 @@ impl WsRequest for AdminRejectUserBecomeExpertRequest {
     {
       "name": "user_id",
       "ty": "BigInt"
+    },
+    {
+      "name": "reason",
+      "ty": "String"
     }
   ],
   "returns": [
@@ -9410,8 +15426,26 @@ impl WsRequest for AdminRejectUserBecomeExpertRequest {
     }
   ],
   "stream_response": null,
-  "description": "Admin approves a user to become an expert",
-  "json_schema": null
+  "description": "Admin rejects a user's expert application",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for AdminRejectUserBecomeExpertResponse {
@@ -9449,11 +15483,63 @@ impl WsRequest for AdminGetSystemConfigRequest {
     {
       "name": "escrow_contract_address_bsc_testnet",
       "ty": "BlockchainAddress"
+    },
+    {
+      "name": "settings",
+      "ty": {
+        "DataTable": {
+          "name": "AdminSystemSettingRow",
+          "fields": [
+            {
+              "name": "key",
+              "ty": "String"
+            },
+            {
+              "name": "value",
+              "ty": "Object"
+            },
+            {
+              "name": "version",
+              "ty": "BigInt"
+            },
+            {
+              "name": "updated_by_user_id",
+              "ty": {
+                "Optional": "BigInt"
+              }
+            },
+            {
+              "name": "updated_at",
+              "ty": {
+                "Optional": "BigInt"
+              }
+            }
+          ]
+        }
+      }
     }
   ],
   "stream_response": null,
   "description": "Admin get system config",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for AdminGetSystemConfigResponse {
@@ -9502,6 +15588,34 @@ impl WsRequest for AdminUpdateSystemConfigRequest {
       "ty": {
         "Optional": "BlockchainAddress"
       }
+    },
+    {
+      "name": "settings",
+      "ty": {
+        "Optional": {
+          "Vec": {
+            "Struct": {
+              "name": "AdminSystemSettingUpdate",
+              "fields": [
+                {
+                  "name": "key",
+                  "ty": "String"
+                },
+                {
+                  "name": "value",
+                  "ty": "Object"
+                },
+                {
+                  "name": "version",
+                  "ty": {
+                    "Optional": "BigInt"
+                  }
+                }
+              ]
+            }
+          }
+        }
+      }
     }
   ],
   "returns": [
@@ -9512,13 +15626,122 @@ impl WsRequest for AdminUpdateSystemConfigRequest {
   ],
   "stream_response": null,
   "description": "Admin updates system config",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for AdminUpdateSystemConfigResponse {
     type Request = AdminUpdateSystemConfigRequest;
 }
 
+impl WsRequest for AdminListSystemSettingHistoryRequest {
+    type Response = AdminListSystemSettingHistoryResponse;
+    const METHOD_ID: u32 = 30085;
+    const SCHEMA: &'static str = r#"{
+  "name": "AdminListSystemSettingHistory",
+  "code": 30085,
+  "parameters": [
+    {
+      "name": "limit",
+      "ty": {
+        "Optional": "BigInt"
+      }
+    },
+    {
+      "name": "offset",
+      "ty": {
+        "Optional": "BigInt"
+      }
+    },
+    {
+      "name": "key",
+      "ty": {
+        "Optional": "String"
+      }
+    }
+  ],
+  "returns": [
+    {
+      "name": "history_total",
+      "ty": "BigInt"
+    },
+    {
+      "name": "history",
+      "ty": {
+        "DataTable": {
+          "name": "AdminSystemSettingHistoryRow",
+          "fields": [
+            {
+              "name": "key",
+              "ty": "String"
+            },
+            {
+              "name": "value",
+              "ty": "Object"
+            },
+            {
+              "name": "version",
+              "ty": "BigInt"
+            },
+            {
+              "name": "updated_by_user_id",
+              "ty": {
+                "Optional": "BigInt"
+              }
+            },
+            {
+              "name": "created_at",
+              "ty": "BigInt"
+            }
+          ]
+        }
+      }
+    }
+  ],
+  "stream_response": null,
+  "description": "Admin lists the changes of the system settings",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
+}"#;
+}
+impl WsResponse for AdminListSystemSettingHistoryResponse {
+    type Request = AdminListSystemSettingHistoryRequest;
+}
+
 impl WsRequest for AdminListExpertsRequest {
     type Response = AdminListExpertsResponse;
     const METHOD_ID: u32 = 30090;
@@ -9693,7 +15916,25 @@ impl WsRequest for AdminListExpertsRequest {
   ],
   "stream_response": null,
   "description": "Admin lists experts",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for AdminListExpertsResponse {
@@ -9796,7 +16037,25 @@ impl WsRequest for AdminListBackersRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for AdminListBackersResponse {
@@ -10009,7 +16268,25 @@ impl WsRequest for AdminListStrategiesRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for AdminListStrategiesResponse {
@@ -10036,7 +16313,25 @@ impl WsRequest for AdminApproveStrategyRequest {
   ],
   "stream_response": null,
   "description": "Admin approves strategy",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for AdminApproveStrategyResponse {
@@ -10063,7 +16358,25 @@ impl WsRequest for AdminRefreshExpertWalletBalanceRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for AdminRefreshExpertWalletBalanceResponse {
@@ -10090,7 +16403,25 @@ impl WsRequest for AdminRejectStrategyRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for AdminRejectStrategyResponse {
@@ -10120,7 +16451,25 @@ impl WsRequest for AdminAddAuditRuleRequest {
   "returns": [],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for AdminAddAuditRuleResponse {
@@ -10191,10 +16540,28 @@ impl WsRequest for AdminNotifyEscrowLedgerChangeRequest {
       }
     }
   ],
-  "returns": [],
-  "stream_response": null,
-  "description": "",
-  "json_schema": null
+  "returns": [],
+  "stream_response": null,
+  "description": "",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for AdminNotifyEscrowLedgerChangeResponse {
@@ -10276,7 +16643,25 @@ impl WsRequest for AdminSubscribeDepositLedgerRequest {
     }
   },
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for AdminSubscribeDepositLedgerResponse {
@@ -10293,7 +16678,25 @@ impl WsRequest for AdminUnsubscribeDepositLedgerRequest {
   "returns": [],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for AdminUnsubscribeDepositLedgerResponse {
@@ -10347,7 +16750,25 @@ impl WsRequest for AdminAddEscrowTokenContractAddressRequest {
   "returns": [],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for AdminAddEscrowTokenContractAddressResponse {
@@ -10379,7 +16800,25 @@ impl WsRequest for AdminAddEscrowContractAddressRequest {
   "returns": [],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for AdminAddEscrowContractAddressResponse {
@@ -10460,7 +16899,25 @@ impl WsRequest for AdminListBackStrategyLedgerRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for AdminListBackStrategyLedgerResponse {
@@ -10541,7 +16998,25 @@ impl WsRequest for AdminListExitStrategyLedgerRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for AdminListExitStrategyLedgerResponse {
@@ -10563,7 +17038,25 @@ impl WsRequest for AdminSetBlockchainLoggerRequest {
   "returns": [],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for AdminSetBlockchainLoggerResponse {
@@ -10670,7 +17163,25 @@ impl WsRequest for AdminListEscrowTokenContractAddressesRequest {
   ],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for AdminListEscrowTokenContractAddressesResponse {
@@ -10722,9 +17233,300 @@ impl WsRequest for AdminUpdateEscrowTokenContractAddressRequest {
   "returns": [],
   "stream_response": null,
   "description": "",
-  "json_schema": null
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
 }"#;
 }
 impl WsResponse for AdminUpdateEscrowTokenContractAddressResponse {
     type Request = AdminUpdateEscrowTokenContractAddressRequest;
 }
+
+impl WsRequest for AdminListPendingActionsRequest {
+    type Response = AdminListPendingActionsResponse;
+    const METHOD_ID: u32 = 33010;
+    const SCHEMA: &'static str = r#"{
+  "name": "AdminListPendingActions",
+  "code": 33010,
+  "parameters": [
+    {
+      "name": "limit",
+      "ty": {
+        "Optional": "BigInt"
+      }
+    },
+    {
+      "name": "offset",
+      "ty": {
+        "Optional": "BigInt"
+      }
+    },
+    {
+      "name": "status",
+      "ty": {
+        "Optional": {
+          "EnumRef": "admin_pending_action_status"
+        }
+      }
+    }
+  ],
+  "returns": [
+    {
+      "name": "pending_actions_total",
+      "ty": "BigInt"
+    },
+    {
+      "name": "pending_actions",
+      "ty": {
+        "DataTable": {
+          "name": "AdminPendingActionRow",
+          "fields": [
+            {
+              "name": "pending_action_id",
+              "ty": "BigInt"
+            },
+            {
+              "name": "requested_by_user_id",
+              "ty": "BigInt"
+            },
+            {
+              "name": "method",
+              "ty": "String"
+            },
+            {
+              "name": "params",
+              "ty": "Object"
+            },
+            {
+              "name": "status",
+              "ty": {
+                "EnumRef": "admin_pending_action_status"
+              }
+            },
+            {
+              "name": "approved_by_user_id",
+              "ty": {
+                "Optional": "BigInt"
+              }
+            },
+            {
+              "name": "approved_at",
+              "ty": {
+                "Optional": "BigInt"
+              }
+            },
+            {
+              "name": "created_at",
+              "ty": "BigInt"
+            }
+          ]
+        }
+      }
+    }
+  ],
+  "stream_response": null,
+  "description": "",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
+}"#;
+}
+impl WsResponse for AdminListPendingActionsResponse {
+    type Request = AdminListPendingActionsRequest;
+}
+
+impl WsRequest for AdminApprovePendingActionRequest {
+    type Response = AdminApprovePendingActionResponse;
+    const METHOD_ID: u32 = 33020;
+    const SCHEMA: &'static str = r#"{
+  "name": "AdminApprovePendingAction",
+  "code": 33020,
+  "parameters": [
+    {
+      "name": "pending_action_id",
+      "ty": "BigInt"
+    }
+  ],
+  "returns": [
+    {
+      "name": "success",
+      "ty": "Boolean"
+    }
+  ],
+  "stream_response": null,
+  "description": "",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
+}"#;
+}
+impl WsResponse for AdminApprovePendingActionResponse {
+    type Request = AdminApprovePendingActionRequest;
+}
+
+impl WsRequest for AdminListActionLogRequest {
+    type Response = AdminListActionLogResponse;
+    const METHOD_ID: u32 = 33030;
+    const SCHEMA: &'static str = r#"{
+  "name": "AdminListActionLog",
+  "code": 33030,
+  "parameters": [
+    {
+      "name": "limit",
+      "ty": {
+        "Optional": "BigInt"
+      }
+    },
+    {
+      "name": "offset",
+      "ty": {
+        "Optional": "BigInt"
+      }
+    },
+    {
+      "name": "admin_user_id",
+      "ty": {
+        "Optional": "BigInt"
+      }
+    },
+    {
+      "name": "method",
+      "ty": {
+        "Optional": "String"
+      }
+    }
+  ],
+  "returns": [
+    {
+      "name": "action_log_total",
+      "ty": "BigInt"
+    },
+    {
+      "name": "action_log",
+      "ty": {
+        "DataTable": {
+          "name": "AdminActionLogRow",
+          "fields": [
+            {
+              "name": "action_log_id",
+              "ty": "BigInt"
+            },
+            {
+              "name": "admin_user_id",
+              "ty": "BigInt"
+            },
+            {
+              "name": "pending_action_id",
+              "ty": {
+                "Optional": "BigInt"
+              }
+            },
+            {
+              "name": "requested_by_user_id",
+              "ty": {
+                "Optional": "BigInt"
+              }
+            },
+            {
+              "name": "method",
+              "ty": "String"
+            },
+            {
+              "name": "params",
+              "ty": "Object"
+            },
+            {
+              "name": "state_before",
+              "ty": {
+                "Optional": "Object"
+              }
+            },
+            {
+              "name": "state_after",
+              "ty": {
+                "Optional": "Object"
+              }
+            },
+            {
+              "name": "created_at",
+              "ty": "BigInt"
+            }
+          ]
+        }
+      }
+    }
+  ],
+  "stream_response": null,
+  "description": "",
+  "json_schema": null,
+  "roles": [
+    {
+      "name": "admin",
+      "value": 3,
+      "comment": ""
+    },
+    {
+      "name": "developer",
+      "value": 4,
+      "comment": ""
+    },
+    {
+      "name": "whitelist",
+      "value": 5,
+      "comment": ""
+    }
+  ],
+  "api_key_scope": null
+}"#;
+}
+impl WsResponse for AdminListActionLogResponse {
+    type Request = AdminListActionLogRequest;
+}
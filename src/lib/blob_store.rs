@@ -0,0 +1,106 @@
+use eyre::*;
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use serde::Deserialize;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+/// stores opaque blobs, e.g. uploaded documents, under keys like `expert_application/1/<uuid>`
+pub trait BlobStore: Send + Sync {
+    fn put(&self, key: &str, data: Vec<u8>) -> BoxFuture<'static, Result<()>>;
+    fn get(&self, key: &str) -> BoxFuture<'static, Result<Vec<u8>>>;
+    fn delete(&self, key: &str) -> BoxFuture<'static, Result<()>>;
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum BlobStoreConfig {
+    /// one file per blob below `path`
+    Local { path: PathBuf },
+}
+impl Default for BlobStoreConfig {
+    fn default() -> Self {
+        Self::Local {
+            path: PathBuf::from("data/blobs"),
+        }
+    }
+}
+impl BlobStoreConfig {
+    pub fn build(&self) -> Result<Arc<dyn BlobStore>> {
+        match self {
+            Self::Local { path } => Ok(Arc::new(LocalBlobStore::new(path.clone())?)),
+        }
+    }
+}
+
+pub struct LocalBlobStore {
+    root: PathBuf,
+}
+impl LocalBlobStore {
+    pub fn new(root: PathBuf) -> Result<Self> {
+        std::fs::create_dir_all(&root)
+            .with_context(|| format!("Failed to create blob store at {}", root.display()))?;
+        Ok(Self { root })
+    }
+    fn path(&self, key: &str) -> Result<PathBuf> {
+        let key = Path::new(key);
+        ensure!(
+            key.components().all(|x| matches!(x, Component::Normal(_))),
+            "Invalid blob key {}",
+            key.display()
+        );
+        Ok(self.root.join(key))
+    }
+}
+impl BlobStore for LocalBlobStore {
+    fn put(&self, key: &str, data: Vec<u8>) -> BoxFuture<'static, Result<()>> {
+        let path = self.path(key);
+        async move {
+            let path = path?;
+            if let Some(dir) = path.parent() {
+                tokio::fs::create_dir_all(dir).await?;
+            }
+            tokio::fs::write(&path, data).await?;
+            Ok(())
+        }
+        .boxed()
+    }
+    fn get(&self, key: &str) -> BoxFuture<'static, Result<Vec<u8>>> {
+        let path = self.path(key);
+        async move {
+            let path = path?;
+            tokio::fs::read(&path)
+                .await
+                .with_context(|| format!("Failed to read blob {}", path.display()))
+        }
+        .boxed()
+    }
+    fn delete(&self, key: &str) -> BoxFuture<'static, Result<()>> {
+        let path = self.path(key);
+        async move {
+            match tokio::fs::remove_file(path?).await {
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+                x => Ok(x?),
+            }
+        }
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_local_blob_store() -> Result<()> {
+        let root = std::env::temp_dir().join(format!("blob_store_{}", std::process::id()));
+        let store = LocalBlobStore::new(root.clone())?;
+        store.put("a/b", b"hello".to_vec()).await?;
+        assert_eq!(store.get("a/b").await?, b"hello");
+        store.delete("a/b").await?;
+        assert!(store.get("a/b").await.is_err());
+        assert!(store.put("../escape", vec![]).await.is_err());
+        std::fs::remove_dir_all(root)?;
+        Ok(())
+    }
+}
@@ -3,7 +3,7 @@ use eyre::*;
 use serde::de::DeserializeOwned;
 use serde_json::Value;
 use std::fmt::Debug;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 #[derive(Parser)]
 #[clap(author, version, about, long_about = None)]
@@ -25,12 +25,19 @@ struct CliArgument {
 
 pub fn load_config<Config: DeserializeOwned + Debug>(mut service_name: String) -> Result<Config> {
     let args: CliArgument = CliArgument::parse();
-
-    let config = std::fs::read_to_string(&args.config)?;
-    let mut config: Value = serde_json::from_str(&config)?;
     if let Some(entry) = args.config_entry {
         service_name = entry;
     }
+    load_config_from_file(&args.config, service_name)
+}
+
+/// same as `load_config`, for binaries that parse their own command line
+pub fn load_config_from_file<Config: DeserializeOwned + Debug>(
+    path: &Path,
+    service_name: String,
+) -> Result<Config> {
+    let config = std::fs::read_to_string(path)?;
+    let mut config: Value = serde_json::from_str(&config)?;
     let service_config = config
         .get_mut(&service_name)
         .ok_or_else(|| eyre!("Service {} not found in config", service_name))?
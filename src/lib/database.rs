@@ -1,25 +1,36 @@
 use crate::datatable::RDataTable;
-use dashmap::DashMap;
 use deadpool_postgres::Runtime;
 use deadpool_postgres::*;
 use eyre::*;
+use futures::StreamExt;
 use postgres_from_row::FromRow;
 use secrecy::{ExposeSecret, SecretString};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::collections::hash_map::DefaultHasher;
 use std::fmt::Debug;
+use std::future::Future;
 use std::hash::Hash;
 use std::hash::Hasher;
 use std::path::Path;
 use std::process::Command;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio_postgres::error::SqlState;
 pub use tokio_postgres::types::ToSql;
-use tokio_postgres::Statement;
-pub use tokio_postgres::{NoTls, Row, ToStatement};
+use tokio_postgres::{AsyncMessage, Statement};
+pub use tokio_postgres::{NoTls, Notification, Row, ToStatement};
 use tracing::*;
 
+/// how many times a transaction is retried after a serialization failure or a deadlock
+pub const TRANSACTION_MAX_RETRIES: usize = 3;
+/// how many prepared statement lookups happen between two logs of the cache hit rate
+pub const STATEMENT_CACHE_METRICS_LOG_INTERVAL: u64 = 10_000;
+/// how long a listening connection waits before reconnecting after it dropped
+pub const LISTEN_RECONNECT_INTERVAL: Duration = Duration::from_secs(1);
+
 #[derive(Clone, Debug, Default, Deserialize)]
 pub struct DatabaseConfig {
     /// See [`tokio_postgres::Config::user`].
@@ -84,9 +95,57 @@ pub trait DatabaseRequest {
 #[derive(Clone)]
 pub struct DbClient {
     pool: Pool,
-    prepared_stmts: Arc<DashMap<String, Statement>>,
+    pg_config: tokio_postgres::Config,
+    statement_cache_metrics: Arc<StatementCacheMetrics>,
     conn_hash: u64,
 }
+
+/// hit and miss counters of the per-connection prepared statement caches
+#[derive(Debug, Default)]
+pub struct StatementCacheMetrics {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+impl StatementCacheMetrics {
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+    pub fn hit_rate(&self) -> f64 {
+        let hits = self.hits();
+        let total = hits + self.misses();
+        if total == 0 {
+            return 0.0;
+        }
+        hits as f64 / total as f64
+    }
+    /// statements are cached with the connection that prepared them, a pooled connection
+    /// is only used by one task at a time, so a grown cache means the statement was prepared
+    async fn prepare_cached(
+        &self,
+        client: &Object,
+        query: &str,
+    ) -> Result<Statement, tokio_postgres::Error> {
+        let size = client.statement_cache.size();
+        let statement = client.prepare_cached(query).await?;
+        if client.statement_cache.size() > size {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+        }
+        if (self.hits() + self.misses()).is_multiple_of(STATEMENT_CACHE_METRICS_LOG_INTERVAL) {
+            info!(
+                "Prepared statement cache: {} hits, {} misses, hit rate {:.2}%",
+                self.hits(),
+                self.misses(),
+                self.hit_rate() * 100.0
+            );
+        }
+        Ok(statement)
+    }
+}
 impl DbClient {
     #[deprecated]
     pub async fn query<T>(
@@ -118,18 +177,16 @@ impl DbClient {
                 .get()
                 .await
                 .context("Failed to connect to database")?;
-            let statement = client.prepare(req.statement()).await?;
-            // TODO: cache statement along with the client into prepared_stmts. other wise there will be runtime error
+            let statement = self
+                .statement_cache_metrics
+                .prepare_cached(&client, req.statement())
+                .await?;
             let rows = match client.query(&statement, &req.params()).await {
                 Ok(rows) => rows,
                 Err(err) => {
-                    let reason = err.to_string();
-                    if reason.contains("cache lookup failed for type")
-                        || reason.contains("cached plan must not change result type")
-                        || reason.contains("prepared statement")
-                    {
+                    if is_stale_statement_error(&err) {
                         warn!("Database has been updated. Cleaning cache and retrying query");
-                        self.prepared_stmts.clear();
+                        self.clear_statement_caches();
                         error = Some(err);
                         continue;
                     }
@@ -151,9 +208,244 @@ impl DbClient {
         }
         Err(error.unwrap().into())
     }
+    /// runs `f` inside BEGIN/COMMIT on a single connection. every `DatabaseRequest` executed
+    /// through the given `DbTransaction` is applied atomically, and the transaction is rolled back
+    /// if `f` returns an error. the transaction runs at serializable isolation level and `f` is
+    /// called again on serialization failures, deadlocks and stale prepared statements, so it
+    /// must not have side effects outside of the database
+    pub async fn transaction<R, F, Fut>(&self, f: F) -> Result<R>
+    where
+        F: Fn(DbTransaction) -> Fut,
+        Fut: Future<Output = Result<R>>,
+    {
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            let client = Arc::new(
+                self.pool
+                    .get()
+                    .await
+                    .context("Failed to connect to database")?,
+            );
+            let guard = TransactionGuard {
+                client: Some(client.clone()),
+            };
+            client
+                .batch_execute("BEGIN ISOLATION LEVEL SERIALIZABLE")
+                .await?;
+            let result = match f(DbTransaction {
+                client: client.clone(),
+                statement_cache_metrics: self.statement_cache_metrics.clone(),
+            })
+            .await
+            {
+                Ok(ret) => {
+                    let result = client.batch_execute("COMMIT").await;
+                    guard.finish();
+                    result.map(|_| ret).map_err(Error::from)
+                }
+                Err(err) => {
+                    match client.batch_execute("ROLLBACK").await {
+                        Ok(_) => guard.finish(),
+                        /* the guard discards the connection */
+                        Err(rollback_err) => {
+                            warn!("Failed to rollback transaction: {:?}", rollback_err)
+                        }
+                    }
+                    Err(err)
+                }
+            };
+            match result {
+                Err(err) if attempt <= TRANSACTION_MAX_RETRIES && is_retryable_error(&err) => {
+                    if is_stale_statement(&err) {
+                        self.clear_statement_caches();
+                    }
+                    warn!(
+                        "Transaction failed on attempt {}, retrying: {:?}",
+                        attempt, err
+                    );
+                }
+                result => return result,
+            }
+        }
+    }
+    /// a connection of the pool for raw SQL
+    pub(crate) async fn get_client(&self) -> Result<Object> {
+        self.pool
+            .get()
+            .await
+            .context("Failed to connect to database")
+    }
+    /// LISTENs on `channels` with a dedicated connection outside of the pool and forwards the
+    /// notifications until the receiver is dropped. the connection is reopened when it drops,
+    /// notifications sent in between are lost, so `ListenMessage::Listening` tells the receiver
+    /// to look up what it may have missed
+    pub fn listen(&self, channels: Vec<String>) -> mpsc::UnboundedReceiver<ListenMessage> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let pg_config = self.pg_config.clone();
+        tokio::spawn(async move {
+            while !tx.is_closed() {
+                if let Err(err) = listen_until_closed(&pg_config, &channels, &tx).await {
+                    error!("Listening on {:?} failed: {:?}", channels, err);
+                }
+                if !tx.is_closed() {
+                    tokio::time::sleep(LISTEN_RECONNECT_INTERVAL).await;
+                }
+            }
+        });
+        rx
+    }
     pub fn conn_hash(&self) -> u64 {
         self.conn_hash
     }
+    pub fn statement_cache_metrics(&self) -> &StatementCacheMetrics {
+        &self.statement_cache_metrics
+    }
+    /// drops the cached statements of every pooled connection, e.g. after the schema changed
+    pub fn clear_statement_caches(&self) {
+        self.pool.manager().statement_caches.clear();
+    }
+}
+
+/// ends the transaction of [`DbClient::transaction`] when its future is dropped between BEGIN
+/// and COMMIT, so the connection never goes back to the pool with the transaction open
+struct TransactionGuard {
+    client: Option<Arc<Object>>,
+}
+impl TransactionGuard {
+    /// the transaction was committed or rolled back
+    fn finish(mut self) {
+        self.client = None;
+    }
+}
+impl Drop for TransactionGuard {
+    fn drop(&mut self) {
+        let Some(client) = self.client.take() else {
+            return;
+        };
+        let Ok(handle) = tokio::runtime::Handle::try_current() else {
+            discard_connection(client);
+            return;
+        };
+        handle.spawn(async move {
+            if let Err(err) = client.batch_execute("ROLLBACK").await {
+                warn!("Failed to rollback abandoned transaction: {:?}", err);
+                discard_connection(client);
+            }
+        });
+    }
+}
+/// removes the connection from the pool, it is closed once the last transaction handle is dropped
+fn discard_connection(client: Arc<Object>) {
+    match Arc::try_unwrap(client) {
+        Ok(client) => drop(Object::take(client)),
+        Err(_) => error!("Failed to discard a connection still used by a transaction"),
+    }
+}
+
+/// a connection with an open transaction, see [`DbClient::transaction`]
+#[derive(Clone)]
+pub struct DbTransaction {
+    client: Arc<Object>,
+    statement_cache_metrics: Arc<StatementCacheMetrics>,
+}
+impl DbTransaction {
+    /// runs raw SQL that may contain several statements, e.g. a migration file
+    pub async fn batch_execute(&self, sql: &str) -> Result<()> {
+        self.client.batch_execute(sql).await?;
+        Ok(())
+    }
+    pub async fn execute<T: DatabaseRequest + Debug>(
+        &self,
+        req: T,
+    ) -> Result<RDataTable<T::ResponseRow>> {
+        let begin = std::time::Instant::now();
+        let statement = self
+            .statement_cache_metrics
+            .prepare_cached(&self.client, req.statement())
+            .await?;
+        let rows = self.client.query(&statement, &req.params()).await?;
+        let dur = begin.elapsed();
+        debug!(
+            "Database query in transaction took {}.{:03} seconds: {:?}",
+            dur.as_secs(),
+            dur.subsec_millis(),
+            req
+        );
+        let mut response = RDataTable::with_capacity(rows.len());
+        for row in rows {
+            response.push(T::ResponseRow::try_from_row(&row)?);
+        }
+        Ok(response)
+    }
+}
+
+/// what [`DbClient::listen`] forwards
+#[derive(Debug)]
+pub enum ListenMessage {
+    /// the connection listens on the channels, sent every time it was (re)opened
+    Listening,
+    Notification(Notification),
+}
+
+async fn listen_until_closed(
+    pg_config: &tokio_postgres::Config,
+    channels: &[String],
+    tx: &mpsc::UnboundedSender<ListenMessage>,
+) -> Result<()> {
+    let (client, mut connection) = pg_config.connect(NoTls).await?;
+    let notification_tx = tx.clone();
+    /* notifications only arrive while the connection is polled for messages */
+    let messages = tokio::spawn(async move {
+        let mut messages = futures::stream::poll_fn(move |cx| connection.poll_message(cx));
+        while let Some(message) = messages.next().await {
+            if let AsyncMessage::Notification(notification) = message? {
+                if notification_tx
+                    .send(ListenMessage::Notification(notification))
+                    .is_err()
+                {
+                    break;
+                }
+            }
+        }
+        Ok::<_, tokio_postgres::Error>(())
+    });
+    for channel in channels {
+        client
+            .batch_execute(&format!("LISTEN \"{}\"", channel.replace('"', "\"\"")))
+            .await?;
+    }
+    info!("Listening on {:?}", channels);
+    let _ = tx.send(ListenMessage::Listening);
+    messages.await??;
+    Ok(())
+}
+
+fn is_stale_statement_error(err: &tokio_postgres::Error) -> bool {
+    let reason = err.to_string();
+    reason.contains("cache lookup failed for type")
+        || reason.contains("cached plan must not change result type")
+        || reason.contains("prepared statement")
+}
+
+fn is_stale_statement(err: &Error) -> bool {
+    err.chain()
+        .filter_map(|err| err.downcast_ref::<tokio_postgres::Error>())
+        .any(is_stale_statement_error)
+}
+
+/// serialization failures, deadlocks and statements prepared before a schema change
+fn is_retryable_error(err: &Error) -> bool {
+    is_stale_statement(err)
+        || err.chain().any(|err| {
+            err.downcast_ref::<tokio_postgres::Error>()
+                .and_then(|err| err.code())
+                .map(|code| {
+                    *code == SqlState::T_R_SERIALIZATION_FAILURE
+                        || *code == SqlState::T_R_DEADLOCK_DETECTED
+                })
+                .unwrap_or_default()
+        })
 }
 
 pub async fn connect_to_database(config: DatabaseConfig) -> Result<DbClient> {
@@ -192,10 +484,12 @@ pub async fn connect_to_database(config: DatabaseConfig) -> Result<DbClient> {
     config.dbname.hash(&mut hasher);
     let conn_hash = hasher.finish();
 
+    let pg_config = config.get_pg_config()?;
     let pool = config.create_pool(Some(Runtime::Tokio1), NoTls)?;
     Ok(DbClient {
         pool,
-        prepared_stmts: Arc::new(Default::default()),
+        pg_config,
+        statement_cache_metrics: Arc::new(Default::default()),
         conn_hash,
     })
 }
@@ -4,7 +4,7 @@ use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::future::Future;
 use std::hash::{Hash, Hasher};
-use std::io::Read;
+use std::io::{Read, Write};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use tracing::warn;
@@ -383,6 +383,19 @@ impl DataTable {
         }
         Ok(())
     }
+    pub fn write_csv(&self, file: impl Write) -> Result<()> {
+        let mut wtr = csv::Writer::from_writer(file);
+        wtr.write_record(&self.columns)?;
+        for i in 0..self.shape().0 {
+            wtr.write_record(self.column_values.iter().map(|col| match col {
+                Column::Int(x) => x[i].to_string(),
+                Column::String(x) => x[i].clone(),
+                Column::Float(x) => x[i].to_string(),
+            }))?;
+        }
+        wtr.flush()?;
+        Ok(())
+    }
     pub fn sort_by_column(&mut self, column: &str) {
         debug_assert!(self.index.is_none());
         let column = self.get_column(column).expect("Column not found");
@@ -455,3 +468,35 @@ impl<T> RDataTable<T> {
         Ok(futures)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_and_load_csv() -> Result<()> {
+        let mut table = DataTable::new();
+        table.add_column("id", Column::Int(vec![]));
+        table.add_column("symbol", Column::String(vec![]));
+        table.add_column("price", Column::Float(vec![]));
+        table.add_row([Value::Int(1), "USDC".into(), Value::Float(1.0)]);
+        table.add_row([Value::Int(2), "WBNB, wrapped".into(), Value::Float(312.5)]);
+
+        let mut buf = vec![];
+        table.write_csv(&mut buf)?;
+        assert_eq!(
+            String::from_utf8(buf.clone())?,
+            "id,symbol,price\n1,USDC,1\n2,\"WBNB, wrapped\",312.5\n"
+        );
+
+        let mut loaded = DataTable::new();
+        loaded.add_column("id", Column::Int(vec![]));
+        loaded.add_column("symbol", Column::String(vec![]));
+        loaded.add_column("price", Column::Float(vec![]));
+        loaded.load_csv(buf.as_slice())?;
+        assert_eq!(loaded.shape(), (2, 3));
+        assert_eq!(loaded.get_value_str("symbol", 1), Some("WBNB, wrapped"));
+        assert_eq!(loaded.get_value_f64("price", 1), Some(312.5));
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+use crate::handler::{FutureResponse, RequestHandler};
+use crate::toolbox::{RequestContext, Toolbox};
+use crate::ws::WsRequest;
+use eyre::*;
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use model::endpoint::EndpointSchema;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// A wrapped handler along with the name of its endpoint
+pub struct DeferredCall<T> {
+    pub handler: Arc<T>,
+    pub method: String,
+}
+impl<T> Clone for DeferredCall<T> {
+    fn clone(&self) -> Self {
+        Self {
+            handler: self.handler.clone(),
+            method: self.method.clone(),
+        }
+    }
+}
+
+/// Decides which calls to a family of endpoints run right away and which are stored to
+/// be replayed later, e.g. once another admin approved them
+pub trait DeferPolicy<T: RequestHandler>: Send + Sync + 'static {
+    /// Stores `req` to be replayed later and returns the error the call is answered with,
+    /// or `None` to run it right away
+    fn defer(
+        &self,
+        call: &DeferredCall<T>,
+        toolbox: &Toolbox,
+        ctx: RequestContext,
+        req: T::Request,
+    ) -> BoxFuture<'static, Result<Option<Report>>>;
+    /// Runs `req`. `deferred_id` is the id `defer` stored it under when it is replayed
+    fn run(
+        &self,
+        call: &DeferredCall<T>,
+        toolbox: &Toolbox,
+        ctx: RequestContext,
+        req: T::Request,
+        deferred_id: Option<i64>,
+    ) -> FutureResponse<T::Request>;
+}
+
+/// Registered in place of a handler whose calls a `DeferPolicy` may defer
+pub struct DeferredHandler<T, P> {
+    call: DeferredCall<T>,
+    policy: Arc<P>,
+}
+impl<T, P> Clone for DeferredHandler<T, P> {
+    fn clone(&self) -> Self {
+        Self {
+            call: self.call.clone(),
+            policy: self.policy.clone(),
+        }
+    }
+}
+impl<T: RequestHandler + 'static, P: DeferPolicy<T>> RequestHandler for DeferredHandler<T, P> {
+    type Request = T::Request;
+
+    fn handle(
+        &self,
+        toolbox: &Toolbox,
+        ctx: RequestContext,
+        req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let this = self.clone();
+        let toolbox = toolbox.clone();
+        async move {
+            if let Some(err) = this
+                .policy
+                .defer(&this.call, &toolbox, ctx, req.clone())
+                .await?
+            {
+                return Err(err);
+            }
+            this.policy.run(&this.call, &toolbox, ctx, req, None).await
+        }
+        .boxed()
+    }
+}
+
+trait DeferredErased: Send + Sync {
+    fn method(&self) -> &str;
+    fn replay(
+        &self,
+        toolbox: &Toolbox,
+        ctx: RequestContext,
+        params: Value,
+        deferred_id: i64,
+    ) -> BoxFuture<'static, Result<()>>;
+}
+impl<T: RequestHandler + 'static, P: DeferPolicy<T>> DeferredErased for DeferredHandler<T, P> {
+    fn method(&self) -> &str {
+        &self.call.method
+    }
+    fn replay(
+        &self,
+        toolbox: &Toolbox,
+        ctx: RequestContext,
+        params: Value,
+        deferred_id: i64,
+    ) -> BoxFuture<'static, Result<()>> {
+        let req: T::Request = match serde_json::from_value(params) {
+            Ok(req) => req,
+            Err(err) => return async move { Err(err.into()) }.boxed(),
+        };
+        self.policy
+            .run(&self.call, toolbox, ctx, req, Some(deferred_id))
+            .map(|resp| resp.map(|_| ()))
+            .boxed()
+    }
+}
+
+/// The handlers a server wraps with one `DeferPolicy`, kept by method id so that a
+/// deferred call can be replayed from its stored params
+pub struct DeferredHandlers<P> {
+    policy: Arc<P>,
+    handlers: HashMap<u32, Arc<dyn DeferredErased>>,
+}
+impl<P: Send + Sync + 'static> DeferredHandlers<P> {
+    pub fn new(policy: P) -> Self {
+        Self {
+            policy: Arc::new(policy),
+            handlers: HashMap::new(),
+        }
+    }
+    pub fn policy(&self) -> &P {
+        &self.policy
+    }
+    pub fn wrap<T: RequestHandler + 'static>(&mut self, handler: T) -> DeferredHandler<T, P>
+    where
+        P: DeferPolicy<T>,
+    {
+        let schema: EndpointSchema =
+            serde_json::from_str(T::Request::SCHEMA).expect("Invalid schema");
+        let handler = DeferredHandler {
+            call: DeferredCall {
+                handler: Arc::new(handler),
+                method: schema.name,
+            },
+            policy: self.policy.clone(),
+        };
+        self.handlers
+            .insert(T::Request::METHOD_ID, Arc::new(handler.clone()));
+        handler
+    }
+    /// Whether an endpoint of that name was wrapped
+    pub fn contains_method(&self, method: &str) -> bool {
+        self.handlers.values().any(|x| x.method() == method)
+    }
+    /// Runs a deferred call from the method id and params it was stored with
+    pub async fn replay(
+        &self,
+        toolbox: &Toolbox,
+        ctx: RequestContext,
+        method_id: u32,
+        params: Value,
+        deferred_id: i64,
+    ) -> Result<()> {
+        match self.handlers.get(&method_id) {
+            Some(handler) => handler.replay(toolbox, ctx, params, deferred_id).await,
+            None => bail!("no deferred handler registered for method {}", method_id),
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use crate::database::{DbClient, ListenMessage};
+use chrono::Utc;
+use eyre::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::marker::PhantomData;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tracing::*;
+
+/// the postgres channel services publish their domain events to
+pub const EVENT_BUS_CHANNEL: &str = "mc2fi_event_bus";
+/// how long published events are kept for listeners that reconnect
+pub const EVENT_BUS_RETENTION: Duration = Duration::from_secs(24 * 60 * 60);
+/// serializes the publishers, so events become visible in the order of their ids
+const EVENT_BUS_PUBLISH_LOCK: i64 = 0x6d63_3266_6962_7573;
+
+/// typed events carried over postgres, so services only need a `DbClient` to talk to each other.
+/// events are stored in `tbl.event_bus_outbox` and their id is sent with NOTIFY. subscribers read
+/// the events after the last id they saw, also after their listener reconnected, so an event
+/// published while a subscriber reconnects is delivered late instead of lost
+pub struct EventBus<E> {
+    db: DbClient,
+    channel: String,
+    _event: PhantomData<fn() -> E>,
+}
+
+impl<E> Clone for EventBus<E> {
+    fn clone(&self) -> Self {
+        Self {
+            db: self.db.clone(),
+            channel: self.channel.clone(),
+            _event: PhantomData,
+        }
+    }
+}
+
+impl<E: Serialize + DeserializeOwned + Send + 'static> EventBus<E> {
+    pub fn new(db: DbClient) -> Self {
+        Self::with_channel(db, EVENT_BUS_CHANNEL)
+    }
+    pub fn with_channel(db: DbClient, channel: impl Into<String>) -> Self {
+        Self {
+            db,
+            channel: channel.into(),
+            _event: PhantomData,
+        }
+    }
+    pub async fn publish(&self, event: &E) -> Result<()> {
+        let payload = serde_json::to_string(event)?;
+        let now = Utc::now().timestamp();
+        let mut client = self.db.get_client().await?;
+        let tx = client.transaction().await?;
+        tx.execute(
+            "SELECT pg_advisory_xact_lock($1)",
+            &[&EVENT_BUS_PUBLISH_LOCK],
+        )
+        .await?;
+        tx.execute(
+            "DELETE FROM tbl.event_bus_outbox WHERE created_at < $1",
+            &[&(now - EVENT_BUS_RETENTION.as_secs() as i64)],
+        )
+        .await?;
+        let id: i64 = tx
+            .query_one(
+                "INSERT INTO tbl.event_bus_outbox (channel, payload, created_at) VALUES ($1, $2, $3) RETURNING pkey_id",
+                &[&self.channel, &payload, &now],
+            )
+            .await?
+            .get(0);
+        /* delivered on commit */
+        tx.execute(
+            "SELECT pg_notify($1, $2)",
+            &[&self.channel, &id.to_string()],
+        )
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+    /// receives the events published after the subscriber started listening
+    pub fn subscribe(&self) -> mpsc::UnboundedReceiver<E> {
+        let mut messages = self.db.listen(vec![self.channel.clone()]);
+        let db = self.db.clone();
+        let channel = self.channel.clone();
+        let (tx, rx) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let mut last_seen_id = None;
+            while let Some(message) = messages.recv().await {
+                let result = match (&message, last_seen_id.as_mut()) {
+                    /* events published before the first connection listened are not replayed */
+                    (ListenMessage::Listening, None) => last_event_id(&db, &channel)
+                        .await
+                        .map(|id| last_seen_id = Some(id)),
+                    /* a reconnect or a new event, read everything after the last delivered one */
+                    (_, Some(last_seen_id)) => {
+                        deliver_events_after(&db, &channel, last_seen_id, &tx).await
+                    }
+                    (ListenMessage::Notification(_), None) => Ok(()),
+                };
+                if let Err(err) = result {
+                    error!("Failed to read events of channel {}: {:?}", channel, err);
+                }
+                if tx.is_closed() {
+                    break;
+                }
+            }
+        });
+        rx
+    }
+}
+
+async fn last_event_id(db: &DbClient, channel: &str) -> Result<i64> {
+    Ok(db
+        .get_client()
+        .await?
+        .query_one(
+            "SELECT COALESCE(MAX(pkey_id), 0) FROM tbl.event_bus_outbox WHERE channel = $1",
+            &[&channel],
+        )
+        .await?
+        .get(0))
+}
+
+async fn deliver_events_after<E: DeserializeOwned>(
+    db: &DbClient,
+    channel: &str,
+    last_seen_id: &mut i64,
+    tx: &mpsc::UnboundedSender<E>,
+) -> Result<()> {
+    let rows = db
+        .get_client()
+        .await?
+        .query(
+            "SELECT pkey_id, payload FROM tbl.event_bus_outbox WHERE channel = $1 AND pkey_id > $2 ORDER BY pkey_id",
+            &[&channel, &*last_seen_id],
+        )
+        .await?;
+    for row in rows {
+        let id: i64 = row.get(0);
+        let payload: String = row.get(1);
+        *last_seen_id = id;
+        match serde_json::from_str(&payload) {
+            Ok(event) => {
+                if tx.send(event).is_err() {
+                    break;
+                }
+            }
+            Err(err) => warn!(
+                "Dropping malformed event {} on channel {}: {:?} {}",
+                id, channel, err, payload
+            ),
+        }
+    }
+    Ok(())
+}
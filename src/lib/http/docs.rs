@@ -0,0 +1,49 @@
+use eyre::*;
+use serde_json::Value;
+use std::collections::HashSet;
+
+/// the OpenAPI and AsyncAPI documents generated by codegen, served at `/openapi.json` and `/asyncapi.json`
+#[derive(Debug, Clone, Default)]
+pub struct ApiDocuments {
+    pub openapi: Value,
+    pub asyncapi: Value,
+}
+
+impl ApiDocuments {
+    pub fn from_json(openapi: &str, asyncapi: &str) -> Result<Self> {
+        Ok(Self {
+            openapi: serde_json::from_str(openapi).context("invalid OpenAPI document")?,
+            asyncapi: serde_json::from_str(asyncapi).context("invalid AsyncAPI document")?,
+        })
+    }
+
+    /// the documents are generated for every service, only keep the endpoints this server handles
+    pub fn retain_endpoints(&mut self, endpoints: &HashSet<String>) {
+        if let Some(paths) = self
+            .openapi
+            .get_mut("paths")
+            .and_then(|x| x.as_object_mut())
+        {
+            paths.retain(|path, _| endpoints.contains(path.trim_start_matches('/')));
+        }
+        let channel = self
+            .asyncapi
+            .get_mut("channels")
+            .and_then(|x| x.get_mut("/"));
+        if let Some(channel) = channel {
+            for operation in ["publish", "subscribe"] {
+                let messages = channel
+                    .get_mut(operation)
+                    .and_then(|x| x.get_mut("message"))
+                    .and_then(|x| x.get_mut("oneOf"))
+                    .and_then(|x| x.as_array_mut());
+                if let Some(messages) = messages {
+                    messages.retain(|x| match x.get("x-endpoint").and_then(|x| x.as_str()) {
+                        Some(endpoint) => endpoints.contains(endpoint),
+                        None => true,
+                    });
+                }
+            }
+        }
+    }
+}
@@ -1,5 +1,7 @@
 // TODO
 // mod headers;
+mod docs;
 mod server;
 
+pub use docs::*;
 pub use server::*;
@@ -1,48 +1,109 @@
+use convert_case::{Case, Casing};
+use dashmap::DashMap;
 use eyre::*;
 use hyper::body::HttpBody;
+use hyper::header::{HeaderValue, CONTENT_LENGTH, CONTENT_TYPE, ORIGIN};
 use hyper::server::accept::Accept;
 use hyper::service::service_fn;
-use hyper::{Body, Request, Response, StatusCode};
+use hyper::{Body, Method, Request, Response, StatusCode};
+use serde::Serialize;
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::convert::Infallible;
 use std::future::poll_fn;
 use std::net::{SocketAddr, ToSocketAddrs};
 use std::pin::Pin;
 use std::sync::atomic::{AtomicU32, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::task::Poll;
+use std::time::Duration;
 use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::sync::mpsc;
 use tracing::*;
 
 use crate::database::DbClient;
+use crate::error_code::ErrorCode;
 use crate::handler::*;
+use crate::http::ApiDocuments;
 use crate::listener::{ConnectionListener, TcpListener, TlsListener};
 use crate::toolbox::{RequestContext, Toolbox};
-use crate::utils::{get_conn_id, get_log_id};
-use crate::ws::WsConnection;
+use crate::utils::{encode_header, get_conn_id, get_log_id};
 use crate::ws::WsResponseValue;
 use crate::ws::WsServerConfig;
-use crate::ws::{check_handler, WsEndpoint};
+use crate::ws::{
+    check_endpoint_api_key_scope, check_endpoint_role, check_handler, request_error_to_resp,
+    WsEndpoint,
+};
+use crate::ws::{AuthController, ConnectionId, SimpleAuthContoller, WsConnection};
+use crate::ws::{RateLimitKey, RateLimiter, TOO_MANY_REQUESTS};
 use model::endpoint::EndpointSchema;
+use model::types::Type;
+
+/// how long a request waits for the response of its handler
+pub const HTTP_RESPONSE_TIMEOUT: Duration = Duration::from_secs(60);
+/// interval of the comments that keep an idle event stream open and detect closed clients
+pub const SSE_KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(15);
+/// requests with a larger body are answered with 413 before the body is read
+pub const HTTP_MAX_BODY_SIZE: usize = 1024 * 1024;
+/// the same handshake header as the websocket, authenticates every request
+pub const HANDSHAKE_HEADER: &str = "Sec-WebSocket-Protocol";
+
+/// the responses of in-flight requests and event streams, keyed by the connection id of the request
+pub type HttpStreams = Arc<DashMap<ConnectionId, mpsc::UnboundedSender<WsResponseValue>>>;
+/// called with the connection id of an event stream once its client disconnected
+pub type StreamCloseHook = Arc<dyn Fn(ConnectionId) + Send + Sync>;
 
 pub struct HttpServer {
+    pub auth_controller: Arc<dyn AuthController>,
     pub handlers: HashMap<String, WsEndpoint>,
     pub toolbox: Toolbox,
     pub config: WsServerConfig,
+    pub api_documents: ApiDocuments,
+    pub rate_limiter: Option<Arc<RateLimiter>>,
+    pub stream_close_hooks: Vec<StreamCloseHook>,
+    streams: HttpStreams,
 }
 
 impl HttpServer {
     pub fn new(config: WsServerConfig) -> Self {
-        Self {
+        let mut this = Self {
+            auth_controller: Arc::new(SimpleAuthContoller),
             handlers: Default::default(),
             toolbox: Toolbox::new(),
+            rate_limiter: config
+                .rate_limit
+                .clone()
+                .map(|x| Arc::new(RateLimiter::new(x))),
             config,
-        }
+            api_documents: Default::default(),
+            stream_close_hooks: vec![],
+            streams: Default::default(),
+        };
+        this.toolbox = this.route_toolbox(Toolbox::new());
+        this
+    }
+    pub fn add_auth_controller(&mut self, controller: impl AuthController + 'static) {
+        self.auth_controller = Arc::new(controller);
     }
     pub fn add_database(&mut self, db: DbClient) {
         self.toolbox.add_db(db);
     }
+    pub fn set_api_documents(&mut self, api_documents: ApiDocuments) {
+        self.api_documents = api_documents;
+    }
+    /// sends the messages of HTTP requests to their response or event stream, and everything else
+    /// to `toolbox`. used to share one toolbox with a `WebsocketServer`, so that subscriptions made
+    /// over HTTP are published like the websocket ones
+    pub fn route_toolbox(&mut self, mut toolbox: Toolbox) -> Toolbox {
+        let fallback = toolbox.send_msg.clone();
+        let streams = Arc::clone(&self.streams);
+        toolbox.send_msg = Arc::new(move |conn_id, msg| match streams.get(&conn_id) {
+            Some(tx) => tx.send(msg).is_ok(),
+            None => fallback(conn_id, msg),
+        });
+        self.toolbox = toolbox.clone();
+        toolbox
+    }
 
     pub fn add_handler<T: RequestHandler + 'static>(&mut self, schema: EndpointSchema, handler: T) {
         check_handler::<T>(&schema).expect("Invalid handler");
@@ -68,21 +129,13 @@ impl HttpServer {
         addr: SocketAddr,
         stream: S,
     ) {
-        let conn = Arc::new(WsConnection {
-            connection_id: get_conn_id(),
-            user_id: Default::default(),
-            role: AtomicU32::new(0),
-            address: addr,
-            log_id: get_log_id(),
-        });
         let mut seq = 0;
         let handler = move |req| {
             let this = Arc::clone(&self);
             seq += 1;
-            let conn = Arc::clone(&conn);
-            let log_id = conn.log_id;
             async move {
-                match this.handle_request(conn, req, seq).await {
+                let log_id = get_log_id();
+                match this.handle_request(addr, log_id, req, seq).await {
                     Ok(ok) => Ok::<_, Infallible>(ok),
                     Err(err) => {
                         error!("Error handling request: {:?} log_id={}", err, log_id);
@@ -111,20 +164,132 @@ impl HttpServer {
 
     pub async fn handle_request(
         self: Arc<Self>,
-        conn: Arc<WsConnection>,
+        addr: SocketAddr,
+        log_id: u64,
         request: Request<Body>,
         seq: u32,
-    ) -> Result<Response<String>> {
-        let url = request.uri().path().trim_start_matches("/");
+    ) -> Result<Response<Body>> {
+        let origin = request.headers().get(ORIGIN).cloned();
+        let mut response = self.handle_request_impl(addr, log_id, request, seq).await?;
+        if let Some(origin) = origin {
+            self.add_cors_headers(&mut response, origin);
+        }
+        Ok(response)
+    }
+
+    async fn handle_request_impl(
+        &self,
+        addr: SocketAddr,
+        log_id: u64,
+        request: Request<Body>,
+        seq: u32,
+    ) -> Result<Response<Body>> {
+        let url = request.uri().path().trim_start_matches('/').to_owned();
+        match *request.method() {
+            Method::OPTIONS => {
+                return Ok(Response::builder()
+                    .status(StatusCode::NO_CONTENT)
+                    .header("Access-Control-Allow-Methods", "GET, POST, OPTIONS")
+                    .header(
+                        "Access-Control-Allow-Headers",
+                        format!("Content-Type, {}", HANDSHAKE_HEADER),
+                    )
+                    .body(Body::empty())?);
+            }
+            Method::GET => {
+                return match url.as_str() {
+                    "openapi.json" => json_response(StatusCode::OK, &self.api_documents.openapi),
+                    "asyncapi.json" => json_response(StatusCode::OK, &self.api_documents.asyncapi),
+                    _ => text_response(StatusCode::NOT_FOUND, format!("{} not found", url)),
+                };
+            }
+            Method::POST => {}
+            _ => {
+                return text_response(StatusCode::METHOD_NOT_ALLOWED, "Endpoints only accept POST");
+            }
+        }
+        let header = match request.headers().get(HANDSHAKE_HEADER) {
+            Some(header) => match header.to_str() {
+                Ok(header) => Some(header.to_owned()),
+                Err(_) => {
+                    return text_response(
+                        StatusCode::BAD_REQUEST,
+                        format!("{} is not valid utf-8", HANDSHAKE_HEADER),
+                    );
+                }
+            },
+            None => None,
+        };
+        let content_length = request
+            .headers()
+            .get(CONTENT_LENGTH)
+            .and_then(|x| x.to_str().ok())
+            .and_then(|x| x.parse::<usize>().ok());
+        if content_length.is_some_and(|x| x > HTTP_MAX_BODY_SIZE) {
+            return body_too_large_response();
+        }
+        let mut body = vec![];
+        let mut b = request.into_body();
+        while let Some(chunk) = poll_fn(|cx| Pin::new(&mut b).poll_data(cx)).await {
+            let chunk = chunk?;
+            /* chunked bodies have no content length */
+            if body.len() + chunk.len() > HTTP_MAX_BODY_SIZE {
+                return body_too_large_response();
+            }
+            body.extend_from_slice(chunk.as_ref());
+        }
+        let req: Value = if body.is_empty() {
+            Value::Object(Default::default())
+        } else {
+            match serde_json::from_slice(&body) {
+                Ok(req) => req,
+                Err(err) => return text_response(StatusCode::BAD_REQUEST, err.to_string()),
+            }
+        };
 
-        let endpoint = match self.handlers.get(url) {
+        let conn = Arc::new(WsConnection {
+            connection_id: get_conn_id(),
+            user_id: Default::default(),
+            role: AtomicU32::new(0),
+            user_session_id: Default::default(),
+            api_key_id: Default::default(),
+            api_key_scopes: Default::default(),
+            address: addr,
+            log_id,
+        });
+        // handshake methods take their parameters from the body instead of the header
+        if let Some(schema) = self
+            .auth_controller
+            .auth_endpoints()
+            .into_iter()
+            .find(|x| x.name == url)
+        {
+            let header = match encode_handshake_header(&schema, req) {
+                Ok(header) => header,
+                Err(err) => return text_response(StatusCode::BAD_REQUEST, err.to_string()),
+            };
+            return match self.authenticate(conn, header).await {
+                Ok(Some(resp)) => ws_response_to_http(resp),
+                Ok(None) => text_response(StatusCode::GATEWAY_TIMEOUT, "No response"),
+                Err(err) => text_response(StatusCode::BAD_REQUEST, err.to_string()),
+            };
+        }
+
+        let endpoint = match self.handlers.get(&url) {
             Some(endpoint) => endpoint,
             None => {
-                return Ok(Response::builder()
-                    .status(StatusCode::NOT_FOUND)
-                    .body(format!("Endpoint {} not found", url))?);
+                return text_response(StatusCode::NOT_FOUND, format!("Endpoint {} not found", url));
             }
         };
+        if let Some(header) = header {
+            match self.authenticate(Arc::clone(&conn), header).await {
+                Ok(Some(WsResponseValue::Error(err))) => {
+                    return json_response(error_status(err.code), &err);
+                }
+                Ok(_) => {}
+                Err(err) => return text_response(StatusCode::BAD_REQUEST, err.to_string()),
+            }
+        }
         let context = RequestContext {
             connection_id: conn.connection_id,
             user_id: conn.get_user_id(),
@@ -134,45 +299,153 @@ impl HttpServer {
             role: conn.role.load(Ordering::Relaxed),
             ip_addr: conn.address.ip(),
         };
-        let mut body = vec![];
-        let mut b = request.into_body();
-        while let Some(chunk) = poll_fn(|cx| Pin::new(&mut b).poll_data(cx)).await {
-            let chunk = chunk?;
-            body.extend_from_slice(chunk.as_ref());
+        if let Some(resp) = check_endpoint_role(&context, &endpoint.schema)
+            .or_else(|| check_endpoint_api_key_scope(&context, &conn, &endpoint.schema))
+        {
+            return ws_response_to_http(resp);
+        }
+        if let Some(limiter) = &self.rate_limiter {
+            if let Err(err) = limiter.check(&context, RateLimitKey::for_ip(&context)) {
+                return ws_response_to_http(request_error_to_resp(
+                    &context,
+                    ErrorCode::new(TOO_MANY_REQUESTS),
+                    err.to_string(),
+                ));
+            }
         }
 
-        let req: Value = match serde_json::from_slice(&body) {
-            Ok(req) => req,
-            Err(err) => {
-                return Ok(Response::builder()
-                    .status(StatusCode::BAD_REQUEST)
-                    .body(err.to_string())?);
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        self.streams.insert(conn.connection_id, tx);
+        endpoint.handler.handle(&self.toolbox, context, req).await;
+        let resp = tokio::time::timeout(HTTP_RESPONSE_TIMEOUT, async {
+            while let Some(resp) = rx.recv().await {
+                if matches!(
+                    resp,
+                    WsResponseValue::Immediate(_) | WsResponseValue::Error(_)
+                ) {
+                    return Some(resp);
+                }
             }
-        };
-        let (tx, rx) = kanal::unbounded_async();
+            None
+        })
+        .await;
+        debug!("Response: {:?}", resp);
+        match resp {
+            Ok(Some(WsResponseValue::Immediate(x)))
+                if endpoint.schema.stream_response.is_some() =>
+            {
+                Ok(self.event_stream_response(conn.connection_id, x.params, rx))
+            }
+            Ok(Some(resp)) => {
+                self.streams.remove(&conn.connection_id);
+                ws_response_to_http(resp)
+            }
+            _ => {
+                self.streams.remove(&conn.connection_id);
+                text_response(StatusCode::GATEWAY_TIMEOUT, "No response")
+            }
+        }
+    }
+
+    /// runs the handshake method in `header` on `conn` and returns its response
+    async fn authenticate(
+        &self,
+        conn: Arc<WsConnection>,
+        header: String,
+    ) -> Result<Option<WsResponseValue>> {
+        let response = Arc::new(Mutex::new(None));
         let mut toolbox = self.toolbox.clone();
-        toolbox.send_msg = Arc::new(move |_conn, resp| {
-            futures::executor::block_on(tx.send(resp)).unwrap();
+        let response2 = Arc::clone(&response);
+        toolbox.send_msg = Arc::new(move |_conn_id, resp| {
+            *response2.lock().unwrap() = Some(resp);
             true
         });
-        endpoint.handler.handle(&toolbox, context, req).await;
-        let resp = rx.recv().await?;
-        info!("Response: {:?}", resp);
-        match resp {
-            WsResponseValue::Immediate(x) => Ok(Response::builder()
-                .status(StatusCode::OK)
-                .body(serde_json::to_string(&x.params)?)?),
-            WsResponseValue::Error(err) => Ok(Response::builder()
-                .status(StatusCode::BAD_REQUEST)
-                .body(serde_json::to_string(&err)?)?),
+        Arc::clone(&self.auth_controller)
+            .auth(&toolbox, header, conn)
+            .await?;
+        let resp = response.lock().unwrap().take();
+        Ok(resp)
+    }
 
-            _ => {
-                todo!()
+    /// an `immediate` event with the response, then a `stream` event for every published message
+    /// until the client disconnects
+    fn event_stream_response(
+        &self,
+        connection_id: ConnectionId,
+        params: Value,
+        mut rx: mpsc::UnboundedReceiver<WsResponseValue>,
+    ) -> Response<Body> {
+        let (mut sender, body) = Body::channel();
+        let streams = Arc::clone(&self.streams);
+        let close_hooks = self.stream_close_hooks.clone();
+        tokio::spawn(async move {
+            let mut keep_alive = tokio::time::interval(SSE_KEEP_ALIVE_INTERVAL);
+            keep_alive.tick().await;
+            let mut event = format!("event: immediate\ndata: {}\n\n", params);
+            'stream: while sender.send_data(event.into()).await.is_ok() {
+                event = loop {
+                    tokio::select! {
+                        msg = rx.recv() => match msg {
+                            Some(WsResponseValue::Stream(x)) => break format!(
+                                "event: stream\nid: {}\ndata: {}\n\n",
+                                x.stream_seq, x.data
+                            ),
+                            Some(WsResponseValue::Close) | None => break 'stream,
+                            Some(_) => {}
+                        },
+                        _ = keep_alive.tick() => break ": keep-alive\n\n".to_owned(),
+                    }
+                };
+            }
+            streams.remove(&connection_id);
+            for hook in &close_hooks {
+                hook(connection_id);
+            }
+            debug!(?connection_id, "Event stream closed");
+        });
+        Response::builder()
+            .status(StatusCode::OK)
+            .header(CONTENT_TYPE, "text/event-stream")
+            .header("Cache-Control", "no-cache")
+            .body(body)
+            .unwrap()
+    }
+
+    /// credentials are only allowed for the configured origins, any other site may make
+    /// anonymous requests when no origins are configured
+    fn add_cors_headers(&self, response: &mut Response<Body>, origin: HeaderValue) {
+        let headers = response.headers_mut();
+        match self.config.allow_cors_urls.get().as_ref() {
+            Some(urls) => {
+                if urls.iter().any(|x| x.as_bytes() == origin.as_bytes()) {
+                    headers.insert("Access-Control-Allow-Origin", origin);
+                    headers.insert(
+                        "Access-Control-Allow-Credentials",
+                        HeaderValue::from_static("true"),
+                    );
+                    headers.insert("Vary", HeaderValue::from_static("Origin"));
+                }
+            }
+            None => {
+                headers.insert("Access-Control-Allow-Origin", HeaderValue::from_static("*"));
             }
         }
     }
 
-    pub async fn listen(self) -> Result<()> {
+    pub async fn listen(mut self) -> Result<()> {
+        let endpoints: HashSet<String> = self
+            .handlers
+            .keys()
+            .cloned()
+            .chain(
+                self.auth_controller
+                    .auth_endpoints()
+                    .into_iter()
+                    .map(|x| x.name),
+            )
+            .collect();
+        self.api_documents.retain_endpoints(&endpoints);
+
         let addr = (self.config.host.as_ref(), self.config.port)
             .to_socket_addrs()?
             .next()
@@ -180,7 +453,7 @@ impl HttpServer {
         if self.config.pub_certs.is_none() && self.config.priv_cert.is_none() {
             let listener = TcpListener::bind(addr).await?;
             self.listen_impl(Arc::new(listener), addr).await
-        } else if !self.config.pub_certs.is_none() && !self.config.priv_cert.is_none() {
+        } else if self.config.pub_certs.is_some() && self.config.priv_cert.is_some() {
             let listener = TcpListener::bind(addr).await?;
 
             let listener = TlsListener::bind(
@@ -200,7 +473,10 @@ impl HttpServer {
         listener: Arc<T>,
         listen_addr: SocketAddr,
     ) -> Result<()> {
-        info!("{} listening on {}", self.config.name, listen_addr);
+        info!(
+            "{} listening on {} over http",
+            self.config.name, listen_addr
+        );
 
         let this = Arc::new(self);
         loop {
@@ -231,6 +507,57 @@ impl HttpServer {
     }
 }
 
+/// the handshake header of an auth method, with absent optional parameters left out
+fn encode_handshake_header(schema: &EndpointSchema, mut req: Value) -> Result<String> {
+    let params = req
+        .as_object_mut()
+        .context("Request body should be an object")?;
+    for param in &schema.parameters {
+        if matches!(param.ty, Type::Optional(_)) {
+            params
+                .entry(param.name.to_case(Case::Camel))
+                .or_insert(Value::Null);
+        }
+    }
+    encode_header(req, schema.clone())
+}
+
+/// the HTTP status of an error code is its last three digits, e.g. 101403 UserForbidden is 403
+fn error_status(code: u32) -> StatusCode {
+    StatusCode::from_u16((code % 1000) as u16)
+        .ok()
+        .filter(|x| x.is_client_error() || x.is_server_error())
+        .unwrap_or(StatusCode::BAD_REQUEST)
+}
+
+fn ws_response_to_http(resp: WsResponseValue) -> Result<Response<Body>> {
+    match resp {
+        WsResponseValue::Immediate(x) => json_response(StatusCode::OK, &x.params),
+        WsResponseValue::Error(err) => json_response(error_status(err.code), &err),
+        resp => bail!("Unexpected response {:?}", resp),
+    }
+}
+
+fn json_response(status: StatusCode, body: &impl Serialize) -> Result<Response<Body>> {
+    Ok(Response::builder()
+        .status(status)
+        .header(CONTENT_TYPE, "application/json")
+        .body(serde_json::to_string(body)?.into())?)
+}
+
+fn text_response(status: StatusCode, body: impl Into<String>) -> Result<Response<Body>> {
+    Ok(Response::builder()
+        .status(status)
+        .body(body.into().into())?)
+}
+
+fn body_too_large_response() -> Result<Response<Body>> {
+    text_response(
+        StatusCode::PAYLOAD_TOO_LARGE,
+        format!("Request body exceeds {} bytes", HTTP_MAX_BODY_SIZE),
+    )
+}
+
 struct ImmediateAcceptor<T> {
     listener: Option<T>,
 }
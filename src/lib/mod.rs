@@ -1,12 +1,16 @@
+pub mod blob_store;
 pub mod config;
 pub mod database;
 pub mod datatable;
+pub mod deferred;
 pub mod error_code;
+pub mod event_bus;
 pub mod handler;
 pub mod http;
 mod listener;
 pub mod log;
 pub mod scheduler;
+pub mod system_config;
 pub mod toolbox;
 pub mod types;
 pub mod utils;
@@ -0,0 +1,232 @@
+use crate::database::{DbClient, ListenMessage};
+use eyre::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt::{Debug, Formatter};
+use std::future::Future;
+use std::sync::{Arc, OnceLock, RwLock};
+use std::time::Duration;
+use tracing::*;
+
+/// the postgres channel notified whenever `tbl.system_config` or `tbl.system_setting` changes
+pub const SYSTEM_CONFIG_CHANNEL: &str = "mc2fi_system_config";
+
+/// a value that can be replaced while the service runs. clones share the value
+pub struct Reloadable<T>(Arc<RwLock<Arc<T>>>);
+
+impl<T> Reloadable<T> {
+    pub fn new(value: T) -> Self {
+        Self(Arc::new(RwLock::new(Arc::new(value))))
+    }
+    pub fn get(&self) -> Arc<T> {
+        self.0.read().unwrap().clone()
+    }
+    pub fn set(&self, value: T) {
+        *self.0.write().unwrap() = Arc::new(value);
+    }
+}
+impl<T> Clone for Reloadable<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+impl<T: Default> Default for Reloadable<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+impl<T: Debug> Debug for Reloadable<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.get().fmt(f)
+    }
+}
+
+/// typed settings stored as key/value pairs in `tbl.system_setting`, unset keys keep their default
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SystemSettings {
+    /// blocks a transaction waits for before it counts as confirmed
+    pub confirmations: u64,
+    /// times a transaction receipt is polled before giving up
+    pub max_retries: u64,
+    /// time between two polls of a transaction receipt
+    pub poll_interval_ms: u64,
+    /// time between two updates of the asset price cache
+    pub asset_price_interval_secs: u64,
+    /// time backers get to exit a strategy before a material change to it takes effect
+    pub strategy_change_notice_secs: u64,
+}
+impl Default for SystemSettings {
+    fn default() -> Self {
+        Self {
+            // TODO: increase confirmations to 14 when we go to public
+            confirmations: 2,
+            max_retries: 8,
+            poll_interval_ms: 3000,
+            asset_price_interval_secs: 60,
+            strategy_change_notice_secs: 86400,
+        }
+    }
+}
+impl SystemSettings {
+    /// the defaults overridden by the stored `(key, value)` pairs. invalid pairs are skipped
+    pub fn from_entries(entries: impl IntoIterator<Item = (String, Value)>) -> Self {
+        let mut this = Self::default();
+        for (key, value) in entries {
+            if let Err(err) = this.set(&key, &value) {
+                warn!("Ignoring system setting {}: {:?}", key, err);
+            }
+        }
+        this
+    }
+    /// validates `value` and sets the setting `key` to it
+    pub fn set(&mut self, key: &str, value: &Value) -> Result<()> {
+        match key {
+            "confirmations" => self.confirmations = parse_in_range(key, value, 1, 64)?,
+            "max_retries" => self.max_retries = parse_in_range(key, value, 1, 100)?,
+            "poll_interval_ms" => self.poll_interval_ms = parse_in_range(key, value, 100, 60_000)?,
+            "asset_price_interval_secs" => {
+                self.asset_price_interval_secs = parse_in_range(key, value, 10, 3600)?
+            }
+            "strategy_change_notice_secs" => {
+                self.strategy_change_notice_secs = parse_in_range(key, value, 0, 30 * 86400)?
+            }
+            _ => bail!("Unknown system setting {}", key),
+        }
+        Ok(())
+    }
+    /// every setting as a `(key, value)` pair
+    pub fn entries(&self) -> Vec<(String, Value)> {
+        match serde_json::to_value(self) {
+            Ok(Value::Object(map)) => map.into_iter().collect(),
+            _ => unreachable!("SystemSettings serializes to an object"),
+        }
+    }
+    pub fn poll_interval(&self) -> Duration {
+        Duration::from_millis(self.poll_interval_ms)
+    }
+    pub fn asset_price_interval(&self) -> Duration {
+        Duration::from_secs(self.asset_price_interval_secs)
+    }
+    pub fn strategy_change_notice(&self) -> Duration {
+        Duration::from_secs(self.strategy_change_notice_secs)
+    }
+}
+
+fn parse_in_range(key: &str, value: &Value, min: u64, max: u64) -> Result<u64> {
+    let value = value
+        .as_u64()
+        .with_context(|| format!("{} must be an integer, got {}", key, value))?;
+    ensure!(
+        (min..=max).contains(&value),
+        "{} must be between {} and {}, got {}",
+        key,
+        min,
+        max,
+        value
+    );
+    Ok(value)
+}
+
+fn settings_cell() -> &'static Reloadable<SystemSettings> {
+    static SETTINGS: OnceLock<Reloadable<SystemSettings>> = OnceLock::new();
+    SETTINGS.get_or_init(Reloadable::default)
+}
+
+/// the settings this process currently runs with
+pub fn system_settings() -> Arc<SystemSettings> {
+    settings_cell().get()
+}
+pub fn set_system_settings(settings: SystemSettings) {
+    settings_cell().set(settings)
+}
+
+/// runs `load` now and again every time `SYSTEM_CONFIG_CHANNEL` is notified or the listener
+/// reconnected, since changes made while it was down were not notified. a failed reload keeps the
+/// previous config
+pub async fn watch_system_config<F, Fut>(db: &DbClient, load: F) -> Result<()>
+where
+    F: Fn(DbClient) -> Fut + Send + 'static,
+    Fut: Future<Output = Result<()>> + Send + 'static,
+{
+    let mut notifications = db.listen(vec![SYSTEM_CONFIG_CHANNEL.to_owned()]);
+    load(db.clone()).await?;
+    let db = db.clone();
+    tokio::spawn(async move {
+        while let Some(message) = notifications.recv().await {
+            match message {
+                /* also covers changes made before the first connection listened */
+                ListenMessage::Listening => info!("Reloading system config, listening on changes"),
+                ListenMessage::Notification(notification) => info!(
+                    "Reloading system config, {} changed",
+                    notification.payload()
+                ),
+            }
+            if let Err(err) = load(db.clone()).await {
+                error!("Failed to reload system config: {:?}", err);
+            }
+        }
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_system_settings_set() {
+        let mut settings = SystemSettings::default();
+        settings.set("confirmations", &json!(14)).unwrap();
+        settings.set("poll_interval_ms", &json!(100)).unwrap();
+        settings
+            .set("strategy_change_notice_secs", &json!(30 * 86400))
+            .unwrap();
+        assert_eq!(settings.confirmations, 14);
+        assert_eq!(settings.poll_interval(), Duration::from_millis(100));
+        assert_eq!(
+            settings.strategy_change_notice(),
+            Duration::from_secs(30 * 86400)
+        );
+
+        /* out of range */
+        for (key, value) in [
+            ("confirmations", json!(0)),
+            ("confirmations", json!(65)),
+            ("max_retries", json!(101)),
+            ("poll_interval_ms", json!(99)),
+            ("asset_price_interval_secs", json!(3601)),
+            ("strategy_change_notice_secs", json!(30 * 86400 + 1)),
+        ] {
+            assert!(settings.set(key, &value).is_err(), "{} {}", key, value);
+        }
+        /* not a non-negative integer */
+        for value in [json!(-1), json!(2.5), json!("2"), json!(null), json!([2])] {
+            assert!(settings.set("confirmations", &value).is_err(), "{}", value);
+        }
+        assert!(settings.set("unknown", &json!(1)).is_err());
+        /* a rejected value leaves the setting as it was */
+        assert_eq!(settings.confirmations, 14);
+    }
+
+    #[test]
+    fn test_system_settings_from_entries() {
+        let settings = SystemSettings::from_entries([
+            ("confirmations".to_string(), json!(6)),
+            ("max_retries".to_string(), json!(0)),
+            ("poll_interval_ms".to_string(), json!("fast")),
+            ("unknown".to_string(), json!(1)),
+            ("asset_price_interval_secs".to_string(), json!(120)),
+        ]);
+        assert_eq!(
+            settings,
+            SystemSettings {
+                confirmations: 6,
+                asset_price_interval_secs: 120,
+                ..Default::default()
+            }
+        );
+        let entries = SystemSettings::from_entries(settings.entries());
+        assert_eq!(entries, settings);
+    }
+}
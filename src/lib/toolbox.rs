@@ -75,6 +75,7 @@ pub struct RequestContext {
 pub struct Toolbox {
     db: Vec<DbClient>,
     pub send_msg: Arc<dyn Fn(ConnectionId, WsResponseValue) -> bool + Send + Sync>,
+    pub close_credential: Arc<dyn Fn(WsCredential) -> usize + Send + Sync>,
 }
 
 impl Toolbox {
@@ -82,6 +83,7 @@ impl Toolbox {
         Self {
             db: vec![],
             send_msg: Arc::new(|_conn_id, _msg| false),
+            close_credential: Arc::new(|_credential| 0),
         }
     }
 
@@ -90,15 +92,53 @@ impl Toolbox {
         states: Arc<DashMap<ConnectionId, Arc<WsStreamState>>>,
         trigger: mpsc::Sender<ConnectionId>,
         oneshot: bool,
+        sessions: Option<Arc<WsSessions>>,
     ) {
+        self.close_credential = {
+            let states = Arc::clone(&states);
+            let trigger = trigger.clone();
+            let sessions = sessions.clone();
+            Arc::new(move |credential| {
+                if let Some(sessions) = &sessions {
+                    sessions.remove_credential(credential);
+                }
+                let conn_ids: Vec<_> = states
+                    .iter()
+                    .filter(|x| x.conn.has_credential(credential))
+                    .map(|x| *x.key())
+                    .collect();
+                for conn_id in &conn_ids {
+                    if let Some(state) = states.get(conn_id) {
+                        state.message_queue.push(WsResponseGeneric::Close);
+                        trigger
+                            .try_send(*conn_id)
+                            .unwrap_or_else(|_| error!("Failed to trigger flush: sender full"));
+                    }
+                }
+                conn_ids.len()
+            })
+        };
         self.send_msg = Arc::new(move |conn_id, msg| {
-            let state = if let Some(state) = states.get(&conn_id) {
-                state
-            } else {
-                return false;
+            let deliver = |msg| {
+                let state = if let Some(state) = states.get(&conn_id) {
+                    state
+                } else {
+                    return false;
+                };
+                Self::send_ws_msg(&state.message_queue, &trigger, conn_id, msg, oneshot);
+                true
             };
-            Self::send_ws_msg(&state.message_queue, &trigger, conn_id, msg, oneshot);
-            true
+            /* a detached session still counts as alive so that its subscriptions are kept */
+            match sessions.as_ref().and_then(|x| Some((x, x.get(conn_id)?))) {
+                Some((sessions, session)) => {
+                    let alive = session.send(msg, deliver);
+                    if !alive {
+                        sessions.remove(&session);
+                    }
+                    alive
+                }
+                None => deliver(msg),
+            }
         });
     }
 
@@ -130,6 +170,16 @@ impl Toolbox {
     pub fn send(&self, conn_id: ConnectionId, resp: WsResponseValue) -> bool {
         (self.send_msg)(conn_id, resp)
     }
+    /// closes every connection authorized with the login session `user_session_id` and drops
+    /// their resumable sessions, returns how many connections were closed
+    pub fn disconnect_user_session(&self, user_session_id: i64) -> usize {
+        (self.close_credential)(WsCredential::UserSession(user_session_id))
+    }
+    /// closes every connection authorized with the API key `api_key_id`, like
+    /// `disconnect_user_session`
+    pub fn disconnect_api_key(&self, api_key_id: i64) -> usize {
+        (self.close_credential)(WsCredential::ApiKey(api_key_id))
+    }
     pub fn send_response(&self, ctx: &RequestContext, resp: impl Serialize) {
         self.send(
             ctx.connection_id,
@@ -2,6 +2,7 @@ use crate::error_code::ErrorCode;
 use crate::handler::RequestHandlerErased;
 use crate::log::LogLevel;
 use crate::toolbox::RequestContext;
+use crate::ws::WsSessionResponse;
 use eyre::*;
 use model::endpoint::EndpointSchema;
 use serde::*;
@@ -35,13 +36,38 @@ pub struct WsConnection {
     pub connection_id: ConnectionId,
     pub user_id: AtomicI64,
     pub role: AtomicU32,
+    /// the login session (`tbl.user_session`) the connection authorized with, 0 if none
+    pub user_session_id: AtomicI64,
+    /// the API key the connection authorized with, 0 if none
+    pub api_key_id: AtomicI64,
+    /// bit `1 << value` for every variant of the `api_key_scope` enum the API key holds
+    pub api_key_scopes: AtomicU32,
     pub address: SocketAddr,
     pub log_id: u64,
 }
+/// what a connection authorized with, so that the connections of a revoked one can be closed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WsCredential {
+    UserSession(i64),
+    ApiKey(i64),
+}
 impl WsConnection {
     pub fn get_user_id(&self) -> i64 {
         self.user_id.load(std::sync::atomic::Ordering::Relaxed)
     }
+    pub fn get_user_session_id(&self) -> i64 {
+        self.user_session_id
+            .load(std::sync::atomic::Ordering::Relaxed)
+    }
+    pub fn get_api_key_id(&self) -> i64 {
+        self.api_key_id.load(std::sync::atomic::Ordering::Relaxed)
+    }
+    pub fn has_credential(&self, credential: WsCredential) -> bool {
+        match credential {
+            WsCredential::UserSession(id) => id != 0 && self.get_user_session_id() == id,
+            WsCredential::ApiKey(id) => id != 0 && self.get_api_key_id() == id,
+        }
+    }
 }
 
 pub type WsSuccessResponse = WsSuccessResponseGeneric<Value>;
@@ -83,11 +109,13 @@ pub enum WsResponseGeneric<Resp> {
     Error(WsResponseError),
     Log(WsLogResponse),
     Forwarded(WsForwardedResponse),
+    Session(WsSessionResponse),
     Close,
 }
 
 pub type WsResponseValue = WsResponseGeneric<Value>;
 
+#[derive(Clone)]
 pub struct WsEndpoint {
     pub schema: EndpointSchema,
     pub handler: Arc<dyn RequestHandlerErased>,
@@ -127,3 +155,53 @@ pub fn request_error_to_resp(
     warn!("Request error: {:?}", err);
     WsResponseValue::Error(err)
 }
+
+/// the error response if the caller authorized with an API key that lacks the scope of the
+/// endpoint, endpoints without a scope are closed to API keys
+pub fn check_endpoint_api_key_scope(
+    ctx: &RequestContext,
+    conn: &WsConnection,
+    schema: &EndpointSchema,
+) -> Option<WsResponseValue> {
+    if conn.get_api_key_id() == 0 {
+        return None;
+    }
+    let scopes = conn
+        .api_key_scopes
+        .load(std::sync::atomic::Ordering::Relaxed);
+    match &schema.api_key_scope {
+        Some(scope) if scopes & (1 << scope.value) != 0 => None,
+        scope => Some(request_error_to_resp(
+            ctx,
+            ErrorCode::new(45349658), // InsufficientApiKeyScope
+            match scope {
+                Some(scope) => format!("Requires API key scope {}", scope.name),
+                None => format!("{} is not available to API keys", schema.name),
+            },
+        )),
+    }
+}
+
+/// the error response if the role of the caller is not in the roles of the endpoint
+pub fn check_endpoint_role(
+    ctx: &RequestContext,
+    schema: &EndpointSchema,
+) -> Option<WsResponseValue> {
+    if schema.allows_role(ctx.role) {
+        return None;
+    }
+    Some(request_error_to_resp(
+        ctx,
+        ErrorCode::new(45349660), // InvalidRole
+        format!(
+            "Requires {} Actual {}",
+            schema
+                .roles
+                .iter()
+                .map(|x| x.name.as_str())
+                .collect::<Vec<_>>()
+                .join(" or "),
+            ctx.role
+        ),
+    ))
+}
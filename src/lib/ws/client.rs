@@ -84,6 +84,9 @@ impl WsClient {
                         WsResponseGeneric::Forwarded(_) => {
                             debug!("expect immediate response, got forwarded")
                         }
+                        WsResponseGeneric::Session(_) => {
+                            debug!("expect immediate response, got session")
+                        }
                         WsResponseGeneric::Close => {
                             unreachable!()
                         }
@@ -24,6 +24,16 @@ impl<S> WebsocketStates<S> {
         self.connection.remove(&connection_id);
         self.states.remove(&connection_id);
     }
+    /// removes `conn` unless a resumed connection took over its connection id
+    pub fn remove_conn(&self, conn: &WsConnection) {
+        if self
+            .states
+            .remove_if(&conn.connection_id, |_, x| x.conn.log_id == conn.log_id)
+            .is_some()
+        {
+            self.connection.remove(&conn.connection_id);
+        }
+    }
     pub fn get_connection(&self, connection_id: u32) -> Option<Arc<WsStreamSink<S>>> {
         self.connection
             .get(&connection_id)
@@ -95,6 +95,19 @@ pub trait AuthController: Sync + Send {
         header: String,
         conn: Arc<WsConnection>,
     ) -> BoxFuture<'static, Result<()>>;
+    /// the methods that can be sent in the handshake header
+    fn auth_endpoints(&self) -> Vec<EndpointSchema> {
+        vec![]
+    }
+    /// checks that the credential `conn` was authorized with is still valid, before a resumed
+    /// session hands its connection to a new socket
+    fn reauthorize(
+        self: Arc<Self>,
+        _toolbox: &Toolbox,
+        _conn: Arc<WsConnection>,
+    ) -> BoxFuture<'static, Result<()>> {
+        async move { Ok(()) }.boxed()
+    }
 }
 pub struct SimpleAuthContoller;
 impl AuthController for SimpleAuthContoller {
@@ -115,6 +128,14 @@ pub trait SubAuthController: Sync + Send {
         ctx: RequestContext,
         conn: Arc<WsConnection>,
     ) -> BoxFuture<'static, Result<serde_json::Value>>;
+    /// like `AuthController::reauthorize`, for the connections this endpoint authorized
+    fn reauthorize(
+        self: Arc<Self>,
+        _toolbox: &Toolbox,
+        _conn: Arc<WsConnection>,
+    ) -> BoxFuture<'static, Result<()>> {
+        async move { Ok(()) }.boxed()
+    }
 }
 pub struct EndpointAuthController {
     pub auth_endpoints: HashMap<String, WsAuthController>,
@@ -150,7 +171,7 @@ fn parse_ty(ty: &Type, value: &str) -> Result<serde_json::Value> {
             let decoded = urlencoding::decode(value)?;
             serde_json::Value::String(decoded.to_string())
         }
-        Type::Int => serde_json::Value::Number(
+        Type::Int | Type::BigInt => serde_json::Value::Number(
             value
                 .parse::<i64>()
                 .with_context(|| format!("Failed to parse integer: {}", value))?
@@ -172,6 +193,12 @@ fn parse_ty(ty: &Type, value: &str) -> Result<serde_json::Value> {
 }
 
 impl AuthController for EndpointAuthController {
+    fn auth_endpoints(&self) -> Vec<EndpointSchema> {
+        self.auth_endpoints
+            .values()
+            .map(|x| x.schema.clone())
+            .collect()
+    }
     fn auth(
         self: Arc<Self>,
         toolbox: &Toolbox,
@@ -233,4 +260,22 @@ impl AuthController for EndpointAuthController {
         }
         .boxed()
     }
+    fn reauthorize(
+        self: Arc<Self>,
+        toolbox: &Toolbox,
+        conn: Arc<WsConnection>,
+    ) -> BoxFuture<'static, Result<()>> {
+        let checks: Vec<_> = self
+            .auth_endpoints
+            .values()
+            .map(|x| x.handler.clone().reauthorize(toolbox, conn.clone()))
+            .collect();
+        async move {
+            for check in checks {
+                check.await?;
+            }
+            Ok(())
+        }
+        .boxed()
+    }
 }
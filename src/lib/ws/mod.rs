@@ -3,11 +3,15 @@ mod client;
 mod conn;
 mod headers;
 mod push;
+mod rate_limit;
 mod server;
+mod session;
 
 pub use basics::*;
 pub use client::*;
 pub use conn::*;
 pub use headers::*;
 pub use push::*;
+pub use rate_limit::*;
 pub use server::*;
+pub use session::*;
@@ -39,9 +39,12 @@ impl<Key: Hash + Eq + Into<u32>> SubscribeManager<Key> {
     }
     pub fn subscribe(&self, topic: Key, ctx: RequestContext) {
         let mut subscribers = self.topics.entry(topic).or_default();
+        /* keeps counting on a second subscribe, resumed sessions acknowledge by stream_seq */
         subscribers
             .subscribers
-            .insert(ctx.connection_id, SubscriberContext { ctx, stream_seq: 0 });
+            .entry(ctx.connection_id)
+            .and_modify(|x| x.ctx = ctx)
+            .or_insert(SubscriberContext { ctx, stream_seq: 0 });
     }
     pub fn unsubscribe_multi(&self, topics: Vec<Key>, connection_id: ConnectionId) {
         for topic in topics {
@@ -53,6 +56,12 @@ impl<Key: Hash + Eq + Into<u32>> SubscribeManager<Key> {
             subscribers.subscribers.remove(&connection_id);
         }
     }
+    /// drops every subscription of a closed connection
+    pub fn unsubscribe_connection(&self, connection_id: ConnectionId) {
+        for mut subscribers in self.topics.iter_mut() {
+            subscribers.subscribers.remove(&connection_id);
+        }
+    }
     pub fn publish_with_filter(
         &self,
         toolbox: &Toolbox,
@@ -0,0 +1,356 @@
+use crate::toolbox::RequestContext;
+use crate::ws::ConnectionId;
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::*;
+
+/// error code sent back when a request is throttled or the ip is banned
+pub const TOO_MANY_REQUESTS: u32 = 100429;
+/// how often buckets that refilled and violation windows that ended are dropped
+const PRUNE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// a token bucket refilled by `per_second` tokens up to `burst`, each request takes one token
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct RateLimitRule {
+    pub per_second: f64,
+    pub burst: u32,
+}
+
+/// overrides the default rule for an endpoint code, a role, or both
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateLimitEndpointRule {
+    #[serde(default)]
+    pub method: Option<u32>,
+    #[serde(default)]
+    pub role: Option<u32>,
+    pub per_second: f64,
+    pub burst: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateLimitConfig {
+    /// applies to every request without a matching rule, unlimited if unset
+    #[serde(default)]
+    pub default: Option<RateLimitRule>,
+    #[serde(default)]
+    pub rules: Vec<RateLimitEndpointRule>,
+    /// an ip that is throttled this many times within `ban_window_secs` gets banned, never if unset
+    #[serde(default)]
+    pub ban_after: Option<u32>,
+    #[serde(default = "default_ban_window_secs")]
+    pub ban_window_secs: u64,
+    #[serde(default = "default_ban_secs")]
+    pub ban_secs: u64,
+}
+fn default_ban_window_secs() -> u64 {
+    60
+}
+fn default_ban_secs() -> u64 {
+    600
+}
+
+/// authenticated users share their buckets across connections, guests get a bucket per
+/// websocket connection, or per ip over http where every request is a new connection
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RateLimitKey {
+    User(i64),
+    Connection(ConnectionId),
+    Ip(IpAddr),
+}
+impl RateLimitKey {
+    pub fn for_connection(ctx: &RequestContext) -> Self {
+        if ctx.user_id > 0 {
+            Self::User(ctx.user_id)
+        } else {
+            Self::Connection(ctx.connection_id)
+        }
+    }
+    pub fn for_ip(ctx: &RequestContext) -> Self {
+        if ctx.user_id > 0 {
+            Self::User(ctx.user_id)
+        } else {
+            Self::Ip(ctx.ip_addr)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RateLimited {
+    Throttled { retry_after: Duration },
+    Banned { remaining: Duration },
+}
+impl Display for RateLimited {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RateLimited::Throttled { retry_after } => write!(
+                f,
+                "Rate limit exceeded, retry in {}ms",
+                retry_after.as_millis()
+            ),
+            RateLimited::Banned { remaining } => write!(
+                f,
+                "Too many requests, banned for {}s",
+                remaining.as_secs().max(1)
+            ),
+        }
+    }
+}
+
+struct TokenBucket {
+    tokens: f64,
+    updated_at: Instant,
+    rule: RateLimitRule,
+}
+impl TokenBucket {
+    fn refilled(&self, now: Instant) -> f64 {
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        (self.tokens + elapsed * self.rule.per_second).min(self.rule.burst as f64)
+    }
+    /// a full bucket is the same as a new one, so it can be dropped
+    fn is_full(&self, now: Instant) -> bool {
+        self.refilled(now) >= self.rule.burst as f64
+    }
+    fn take(&mut self, rule: RateLimitRule, now: Instant) -> Result<(), Duration> {
+        self.rule = rule;
+        self.tokens = self.refilled(now);
+        self.updated_at = now;
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else if rule.per_second > 0.0 {
+            Err(Duration::from_secs_f64(
+                (1.0 - self.tokens) / rule.per_second,
+            ))
+        } else {
+            Err(Duration::MAX)
+        }
+    }
+}
+
+struct Violations {
+    count: u32,
+    window_start: Instant,
+}
+
+pub struct RateLimiter {
+    config: RateLimitConfig,
+    buckets: DashMap<(RateLimitKey, u32), TokenBucket>,
+    violations: DashMap<IpAddr, Violations>,
+    bans: DashMap<IpAddr, Instant>,
+}
+
+impl RateLimiter {
+    pub fn new(config: RateLimitConfig) -> Self {
+        Self {
+            config,
+            buckets: Default::default(),
+            violations: Default::default(),
+            bans: Default::default(),
+        }
+    }
+
+    /// the most specific rule: method and role, then method, then role, then the default
+    pub fn rule(&self, method: u32, role: u32) -> Option<RateLimitRule> {
+        let rank = |x: &RateLimitEndpointRule| match (x.method, x.role) {
+            (Some(m), Some(r)) if m == method && r == role => Some(0),
+            (Some(m), None) if m == method => Some(1),
+            (None, Some(r)) if r == role => Some(2),
+            _ => None,
+        };
+        self.config
+            .rules
+            .iter()
+            .filter_map(|x| rank(x).map(|rank| (rank, x)))
+            .min_by_key(|(rank, _)| *rank)
+            .map(|(_, x)| RateLimitRule {
+                per_second: x.per_second,
+                burst: x.burst,
+            })
+            .or(self.config.default)
+    }
+
+    pub fn banned(&self, ip: IpAddr) -> Option<Duration> {
+        let now = Instant::now();
+        let until = *self.bans.get(&ip)?;
+        if until > now {
+            Some(until - now)
+        } else {
+            self.bans.remove(&ip);
+            None
+        }
+    }
+
+    /// takes a token for the request, counting rejections of the ip towards a ban
+    pub fn check(&self, ctx: &RequestContext, key: RateLimitKey) -> Result<(), RateLimited> {
+        if let Some(remaining) = self.banned(ctx.ip_addr) {
+            return Err(RateLimited::Banned { remaining });
+        }
+        let rule = match self.rule(ctx.method, ctx.role) {
+            Some(rule) => rule,
+            None => return Ok(()),
+        };
+        let now = Instant::now();
+        let result = self
+            .buckets
+            .entry((key, ctx.method))
+            .or_insert_with(|| TokenBucket {
+                tokens: rule.burst as f64,
+                updated_at: now,
+                rule,
+            })
+            .take(rule, now);
+        match result {
+            Ok(()) => Ok(()),
+            Err(retry_after) => {
+                if let Some(remaining) = self.record_violation(ctx.ip_addr, now) {
+                    return Err(RateLimited::Banned { remaining });
+                }
+                Err(RateLimited::Throttled { retry_after })
+            }
+        }
+    }
+
+    fn record_violation(&self, ip: IpAddr, now: Instant) -> Option<Duration> {
+        let ban_after = self.config.ban_after?;
+        let window = Duration::from_secs(self.config.ban_window_secs);
+        let mut violations = self.violations.entry(ip).or_insert(Violations {
+            count: 0,
+            window_start: now,
+        });
+        if now.saturating_duration_since(violations.window_start) > window {
+            violations.count = 0;
+            violations.window_start = now;
+        }
+        violations.count += 1;
+        if violations.count < ban_after {
+            return None;
+        }
+        drop(violations);
+        self.violations.remove(&ip);
+        let duration = Duration::from_secs(self.config.ban_secs);
+        warn!(
+            ?ip,
+            "Banning ip for {}s after exceeding rate limits",
+            duration.as_secs()
+        );
+        self.bans.insert(ip, now + duration);
+        Some(duration)
+    }
+
+    /// drops the buckets of a closed connection
+    pub fn remove_connection(&self, connection_id: ConnectionId) {
+        self.buckets
+            .retain(|(key, _), _| *key != RateLimitKey::Connection(connection_id));
+    }
+
+    /// drops full buckets, ended violation windows and expired bans, which would otherwise
+    /// pile up for every user and ip that ever sent a request
+    pub fn remove_expired(&self, now: Instant) {
+        let window = Duration::from_secs(self.config.ban_window_secs);
+        self.buckets.retain(|_, bucket| !bucket.is_full(now));
+        self.violations
+            .retain(|_, x| now.saturating_duration_since(x.window_start) <= window);
+        self.bans.retain(|_, until| *until > now);
+    }
+
+    pub async fn expire_buckets(self: Arc<Self>) {
+        loop {
+            tokio::time::sleep(PRUNE_INTERVAL).await;
+            self.remove_expired(Instant::now());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    fn ctx(method: u32, role: u32) -> RequestContext {
+        RequestContext {
+            connection_id: 1,
+            user_id: 0,
+            seq: 1,
+            method,
+            log_id: 0,
+            role,
+            ip_addr: IpAddr::V4(Ipv4Addr::LOCALHOST),
+        }
+    }
+
+    #[test]
+    fn test_rate_limiter() {
+        let limiter = RateLimiter::new(RateLimitConfig {
+            default: None,
+            rules: vec![
+                RateLimitEndpointRule {
+                    method: Some(20080),
+                    role: None,
+                    per_second: 0.001,
+                    burst: 2,
+                },
+                RateLimitEndpointRule {
+                    method: Some(20080),
+                    role: Some(3),
+                    per_second: 100.0,
+                    burst: 100,
+                },
+            ],
+            ban_after: Some(2),
+            ban_window_secs: 60,
+            ban_secs: 600,
+        });
+        assert_eq!(limiter.rule(20080, 3).unwrap().burst, 100);
+        assert!(limiter.rule(20081, 1).is_none());
+
+        let ctx = ctx(20080, 1);
+        let key = RateLimitKey::for_connection(&ctx);
+        assert!(limiter.check(&ctx, key).is_ok());
+        assert!(limiter.check(&ctx, key).is_ok());
+        assert!(matches!(
+            limiter.check(&ctx, key),
+            Err(RateLimited::Throttled { .. })
+        ));
+        assert!(matches!(
+            limiter.check(&ctx, key),
+            Err(RateLimited::Banned { .. })
+        ));
+        assert!(limiter.banned(ctx.ip_addr).is_some());
+    }
+
+    #[test]
+    fn test_rate_limiter_remove_expired() {
+        let limiter = RateLimiter::new(RateLimitConfig {
+            default: Some(RateLimitRule {
+                per_second: 1.0,
+                burst: 1,
+            }),
+            rules: vec![],
+            ban_after: Some(10),
+            ban_window_secs: 60,
+            ban_secs: 600,
+        });
+        let ctx = RequestContext {
+            user_id: 7,
+            ..ctx(20080, 1)
+        };
+        let key = RateLimitKey::for_ip(&ctx);
+        assert!(limiter.check(&ctx, key).is_ok());
+        assert!(limiter.check(&ctx, key).is_err());
+        let now = Instant::now();
+        limiter.remove_expired(now);
+        assert_eq!(limiter.buckets.len(), 1);
+        assert_eq!(limiter.violations.len(), 1);
+
+        limiter.remove_expired(now + Duration::from_secs(2));
+        assert!(limiter.buckets.is_empty());
+        assert_eq!(limiter.violations.len(), 1);
+
+        limiter.remove_expired(now + Duration::from_secs(61));
+        assert!(limiter.violations.is_empty());
+    }
+}
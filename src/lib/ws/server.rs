@@ -1,5 +1,6 @@
 use eyre::*;
-use futures::stream::SplitStream;
+use futures::future::BoxFuture;
+use futures::stream::{SplitSink, SplitStream};
 use futures::SinkExt;
 use futures::StreamExt;
 use itertools::Itertools;
@@ -7,7 +8,7 @@ use serde_json::Value;
 use std::collections::HashMap;
 use std::fs::File;
 use std::net::{SocketAddr, ToSocketAddrs};
-use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::atomic::{AtomicI64, AtomicU32, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::io::{AsyncRead, AsyncWrite};
@@ -21,7 +22,9 @@ use tracing::*;
 use crate::database::DbClient;
 use crate::error_code::ErrorCode;
 use crate::handler::*;
+use crate::http::{ApiDocuments, HttpServer, StreamCloseHook};
 use crate::listener::{ConnectionListener, TcpListener, TlsListener};
+use crate::system_config::Reloadable;
 use crate::toolbox::{RequestContext, Toolbox};
 use crate::utils::{get_conn_id, get_log_id};
 use crate::ws::basics::{WsConnection, WsRequestValue};
@@ -30,18 +33,29 @@ use crate::ws::WebsocketStates;
 use crate::ws::WsEndpoint;
 use crate::ws::WsResponseValue;
 use crate::ws::WsStreamSink;
-use crate::ws::{request_error_to_resp, WsStreamState};
+use crate::ws::{
+    check_endpoint_api_key_scope, check_endpoint_role, request_error_to_resp, WsStreamState,
+};
 use crate::ws::{AuthController, ConnectionId};
+use crate::ws::{RateLimitConfig, RateLimitKey, RateLimited, RateLimiter, TOO_MANY_REQUESTS};
+use crate::ws::{ResumeRequest, SessionConfig, WsSessionResponse, WsSessions};
 use crate::ws::{SimpleAuthContoller, WsRequest};
 use model::endpoint::EndpointSchema;
 use serde::{Deserialize, Serialize};
 
+type BackgroundTask = Box<dyn FnOnce(Toolbox) -> BoxFuture<'static, ()> + Send + Sync>;
+
 pub struct WebsocketServer {
     pub auth_controller: Arc<dyn AuthController>,
     pub handlers: HashMap<u32, WsEndpoint>,
     pub message_receiver: Option<mpsc::Receiver<ConnectionId>>,
     toolbox: Toolbox,
     pub config: WsServerConfig,
+    pub api_documents: ApiDocuments,
+    pub rate_limiter: Option<Arc<RateLimiter>>,
+    pub sessions: Option<Arc<WsSessions>>,
+    background_tasks: Vec<BackgroundTask>,
+    stream_close_hooks: Vec<StreamCloseHook>,
 }
 
 impl WebsocketServer {
@@ -51,7 +65,15 @@ impl WebsocketServer {
             handlers: Default::default(),
             message_receiver: None,
             toolbox: Toolbox::new(),
+            rate_limiter: config
+                .rate_limit
+                .clone()
+                .map(|x| Arc::new(RateLimiter::new(x))),
+            sessions: config.session.clone().map(|x| Arc::new(WsSessions::new(x))),
             config,
+            api_documents: Default::default(),
+            background_tasks: vec![],
+            stream_close_hooks: vec![],
         }
     }
     pub fn add_auth_controller(&mut self, controller: impl AuthController + 'static) {
@@ -60,6 +82,23 @@ impl WebsocketServer {
     pub fn add_database(&mut self, db: DbClient) {
         self.toolbox.add_db(db);
     }
+    pub fn set_api_documents(&mut self, api_documents: ApiDocuments) {
+        self.api_documents = api_documents;
+    }
+    /// spawns `task` once the server listens, with the toolbox that can push to connections
+    pub fn add_background_task<F, Fut>(&mut self, task: F)
+    where
+        F: FnOnce(Toolbox) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = ()> + Send + 'static,
+    {
+        self.background_tasks
+            .push(Box::new(move |toolbox| Box::pin(task(toolbox))));
+    }
+    /// runs `hook` with the connection id of every closed HTTP event stream, e.g. to drop its
+    /// subscriptions. websocket connections keep theirs while their session can be resumed
+    pub fn add_stream_close_hook(&mut self, hook: impl Fn(ConnectionId) + Send + Sync + 'static) {
+        self.stream_close_hooks.push(Arc::new(hook));
+    }
 
     pub fn add_handler<T: RequestHandler + 'static>(&mut self, handler: T) {
         let schema = serde_json::from_str(T::Request::SCHEMA).expect("Invalid schema");
@@ -88,12 +127,13 @@ impl WebsocketServer {
         stream: S,
     ) -> Result<()> {
         let (tx, mut rx) = mpsc::channel(1);
+        let allow_cors_urls = self.config.allow_cors_urls.get();
         let hs = tokio_tungstenite::accept_hdr_async(
             stream,
             VerifyProtocol {
                 addr,
                 tx,
-                allow_cors_domains: &self.config.allow_cors_urls,
+                allow_cors_domains: &allow_cors_urls,
             },
         )
         .await;
@@ -102,6 +142,9 @@ impl WebsocketServer {
             connection_id: get_conn_id(),
             user_id: Default::default(),
             role: AtomicU32::new(0),
+            user_session_id: Default::default(),
+            api_key_id: Default::default(),
+            api_key_scopes: Default::default(),
             address: addr,
             log_id: get_log_id(),
         });
@@ -112,12 +155,31 @@ impl WebsocketServer {
             .ok_or_else(|| eyre!("Failed to receive ws headers"))?;
         let (ws_sink, ws_stream) = stream.split();
 
-        let conn = Arc::clone(&conn);
-        states.insert(conn.connection_id, ws_sink, conn.clone());
-
-        let auth_result = Arc::clone(&self.auth_controller)
-            .auth(&self.toolbox, headers, Arc::clone(&conn))
-            .await;
+        let (conn, auth_result) = match ResumeRequest::parse(&headers) {
+            Some(req) if !self.config.header_only => {
+                self.resume_session(req, conn, &states, ws_sink).await
+            }
+            _ => {
+                states.insert(conn.connection_id, ws_sink, conn.clone());
+                let auth_result = Arc::clone(&self.auth_controller)
+                    .auth(&self.toolbox, headers, Arc::clone(&conn))
+                    .await;
+                if let (Ok(()), Some(sessions)) = (&auth_result, &self.sessions) {
+                    if !self.config.header_only {
+                        let session = sessions.create(&conn);
+                        self.toolbox.send(
+                            conn.connection_id,
+                            WsResponseValue::Session(WsSessionResponse {
+                                session_token: session.session_token.clone(),
+                                resumed: false,
+                                replayed: 0,
+                            }),
+                        );
+                    }
+                }
+                (conn, auth_result)
+            }
+        };
         let raw_ctx = RequestContext {
             connection_id: conn.connection_id,
             user_id: conn.get_user_id(),
@@ -142,6 +204,85 @@ impl WebsocketServer {
         Ok(())
     }
 
+    /// reattaches the connection id of the session to the new socket, queueing the stream
+    /// messages the client has not acknowledged before any live one. the login session or API
+    /// key behind it is checked again first, as it may have expired or been revoked meanwhile
+    async fn resume_session<S: AsyncRead + AsyncWrite + Unpin + Send + 'static>(
+        &self,
+        req: Result<ResumeRequest>,
+        conn: Arc<WsConnection>,
+        states: &WebsocketStates<S>,
+        ws_sink: SplitSink<WebSocketStream<S>, Message>,
+    ) -> (Arc<WsConnection>, Result<()>) {
+        let found = req.and_then(|req| {
+            let session = self
+                .sessions
+                .as_ref()
+                .and_then(|x| x.find(&req.session_token))
+                .context("Session not found or expired")?;
+            Ok((req, session))
+        });
+        let (req, session) = match found {
+            Ok(x) => x,
+            Err(err) => {
+                states.insert(conn.connection_id, ws_sink, conn.clone());
+                return (conn, Err(err));
+            }
+        };
+        let resumed = Arc::new(WsConnection {
+            connection_id: session.connection_id,
+            user_id: AtomicI64::new(session.user_id),
+            role: AtomicU32::new(session.role),
+            user_session_id: AtomicI64::new(session.user_session_id),
+            api_key_id: AtomicI64::new(session.api_key_id),
+            api_key_scopes: AtomicU32::new(session.api_key_scopes),
+            address: conn.address,
+            log_id: conn.log_id,
+        });
+        if let Err(err) = Arc::clone(&self.auth_controller)
+            .reauthorize(&self.toolbox, Arc::clone(&resumed))
+            .await
+        {
+            if let Some(sessions) = &self.sessions {
+                sessions.remove(&session);
+            }
+            states.insert(conn.connection_id, ws_sink, conn.clone());
+            return (conn, Err(err));
+        }
+        let conn = resumed;
+        /* the old socket may not have noticed that it is gone */
+        if let Some(old) = states.get_connection(conn.connection_id) {
+            tokio::spawn(async move {
+                let _ = old.ws_sink.lock().await.close().await;
+            });
+        }
+        let replayed = session.attach(&conn, &req.acks, |replay| {
+            states.insert(conn.connection_id, ws_sink, conn.clone());
+            let state = states
+                .get_state(conn.connection_id)
+                .expect("inserted just now");
+            for msg in replay {
+                state.message_queue.push(WsResponseValue::Stream(msg));
+            }
+        });
+        info!(
+            addr = ?conn.address,
+            "Resumed session of connection {} replaying {} messages",
+            conn.connection_id,
+            replayed
+        );
+        /* also flushes the replayed messages */
+        self.toolbox.send(
+            conn.connection_id,
+            WsResponseValue::Session(WsSessionResponse {
+                session_token: session.session_token.clone(),
+                resumed: true,
+                replayed,
+            }),
+        );
+        (conn, Ok(()))
+    }
+
     pub async fn recv_msg<S: AsyncRead + AsyncWrite + Unpin + Send + 'static>(
         self: Arc<Self>,
         conn: Arc<WsConnection>,
@@ -203,6 +344,26 @@ impl WebsocketServer {
                     context.seq = req.seq;
                     context.method = req.method;
                     context.user_id = conn.get_user_id();
+                    context.role = conn.role.load(Ordering::Relaxed);
+                    if let Some(limiter) = &self.rate_limiter {
+                        let key = RateLimitKey::for_connection(&context);
+                        if let Err(err) = limiter.check(&context, key) {
+                            self.toolbox.send(
+                                context.connection_id,
+                                request_error_to_resp(
+                                    &context,
+                                    ErrorCode::new(TOO_MANY_REQUESTS),
+                                    err.to_string(),
+                                ),
+                            );
+                            if let RateLimited::Banned { .. } = err {
+                                // closes the connection after the error is sent
+                                self.toolbox
+                                    .send(context.connection_id, WsResponseValue::Close);
+                            }
+                            continue;
+                        }
+                    }
 
                     let handler = self.handlers.get(&req.method);
                     let handler = match handler {
@@ -219,6 +380,12 @@ impl WebsocketServer {
                             continue;
                         }
                     };
+                    if let Some(resp) = check_endpoint_role(&context, &handler.schema)
+                        .or_else(|| check_endpoint_api_key_scope(&context, &conn, &handler.schema))
+                    {
+                        self.toolbox.send(context.connection_id, resp);
+                        continue;
+                    }
                     tokio::spawn(handler.handler.handle(&self.toolbox, context, req.params));
                 }
                 Err(WsError::Protocol(ProtocolError::ResetWithoutClosingHandshake)) => {
@@ -231,7 +398,13 @@ impl WebsocketServer {
                 }
             }
         }
-        states.remove(context.connection_id);
+        states.remove_conn(&conn);
+        if let Some(sessions) = &self.sessions {
+            sessions.detach(&conn);
+        }
+        if let Some(limiter) = &self.rate_limiter {
+            limiter.remove_connection(context.connection_id);
+        }
         debug!(?addr, "Connection closed");
     }
     pub async fn send_msg_single<S: AsyncRead + AsyncWrite + Unpin + Send + 'static>(
@@ -249,7 +422,7 @@ impl WebsocketServer {
                         debug!(?addr, "Closing connection");
                         let _ = sink.send(Message::Close(None)).await;
                         let _ = sink.close().await;
-                        states.remove(state.conn.connection_id);
+                        states.remove_conn(&state.conn);
                         debug!(?addr, "Connection closed");
                     }
                     resp => {
@@ -262,7 +435,7 @@ impl WebsocketServer {
                             error!(?addr, "Error while sending {:?}", err);
                             let _ = sink.send(Message::Close(None)).await;
                             let _ = sink.close().await;
-                            states.remove(state.conn.connection_id);
+                            states.remove_conn(&state.conn);
                             debug!(?addr, "Connection closed");
                         }
                     }
@@ -271,7 +444,7 @@ impl WebsocketServer {
             if let Err(err) = tokio::time::timeout(Duration::from_secs(1), timeout_operation).await
             {
                 error!(?addr, "Timeout while sending message: {:?}", err);
-                states.remove(state.conn.connection_id);
+                states.remove_conn(&state.conn);
             }
         }
     }
@@ -328,8 +501,41 @@ impl WebsocketServer {
         let states = Arc::new(WebsocketStates::new());
         let (tx, rx) = mpsc::channel(100);
         let message_receiver = rx;
-        self.toolbox
-            .set_ws_states(states.clone_states(), tx, self.config.header_only);
+        self.toolbox.set_ws_states(
+            states.clone_states(),
+            tx,
+            self.config.header_only,
+            self.sessions.clone(),
+        );
+        if let Some(sessions) = &self.sessions {
+            tokio::spawn(Arc::clone(sessions).expire_sessions());
+        }
+        if let Some(rate_limiter) = &self.rate_limiter {
+            tokio::spawn(Arc::clone(rate_limiter).expire_buckets());
+        }
+        if let Some(http_port) = self.config.http_port {
+            let mut http = HttpServer::new(WsServerConfig {
+                port: http_port,
+                ..self.config.clone()
+            });
+            http.auth_controller = Arc::clone(&self.auth_controller);
+            for endpoint in self.handlers.values() {
+                http.handlers
+                    .insert(endpoint.schema.name.clone(), endpoint.clone());
+            }
+            http.set_api_documents(self.api_documents.clone());
+            http.rate_limiter = self.rate_limiter.clone();
+            http.stream_close_hooks = self.stream_close_hooks.clone();
+            self.toolbox = http.route_toolbox(self.toolbox.clone());
+            tokio::spawn(async move {
+                if let Err(err) = http.listen().await {
+                    error!("Error while serving http: {:?}", err);
+                }
+            });
+        }
+        for task in std::mem::take(&mut self.background_tasks) {
+            tokio::spawn(task(self.toolbox.clone()));
+        }
         let this = Arc::new(self);
         tokio::spawn(Arc::clone(&this).send_msg(Arc::clone(&states), message_receiver));
         loop {
@@ -340,6 +546,10 @@ impl WebsocketServer {
                     continue;
                 }
             };
+            if let Some(remaining) = this.rate_limiter.as_ref().and_then(|x| x.banned(addr.ip())) {
+                debug!(?addr, "Refusing banned ip for {}s", remaining.as_secs());
+                continue;
+            }
             let listener = Arc::clone(&listener);
             let this = Arc::clone(&this);
             let states = Arc::clone(&states);
@@ -399,6 +609,11 @@ pub fn check_handler<T: RequestHandler + 'static>(schema: &EndpointSchema) -> Re
     let request_name = std::any::type_name::<T::Request>();
     let should_req_name = format!("{}Request", schema.name);
     check_name("Request", request_name, &should_req_name)?;
+    ensure!(
+        !schema.roles.is_empty(),
+        "{} has no roles, nobody would be allowed to call it",
+        schema.name
+    );
 
     Ok(())
 }
@@ -417,8 +632,18 @@ pub struct WsServerConfig {
     pub priv_cert: Option<String>,
     #[serde(default)]
     pub debug: bool,
+    /// also serves the handlers as JSON POSTs on this port, see `HttpServer`
+    #[serde(default)]
+    pub http_port: Option<u16>,
+    /// token buckets per endpoint code and role, unlimited if unset
+    #[serde(default)]
+    pub rate_limit: Option<RateLimitConfig>,
+    /// lets clients resume their connection and replay missed stream messages, off if unset
+    #[serde(default)]
+    pub session: Option<SessionConfig>,
     #[serde(skip)]
     pub header_only: bool,
+    /// reloaded from `tbl.system_config`, see `lib::system_config`
     #[serde(skip)]
-    pub allow_cors_urls: Arc<Option<Vec<String>>>,
+    pub allow_cors_urls: Reloadable<Option<Vec<String>>>,
 }
@@ -0,0 +1,341 @@
+use crate::ws::{ConnectionId, WsConnection, WsCredential, WsResponseValue, WsStreamResponse};
+use dashmap::DashMap;
+use eyre::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::*;
+
+/// the handshake method that reattaches a connection to its session instead of authenticating
+pub const RESUME_METHOD: &str = "resume";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionConfig {
+    /// how many stream messages of a session are kept for replay
+    #[serde(default = "default_replay_buffer")]
+    pub replay_buffer: usize,
+    /// how long a session survives after its connection dropped
+    #[serde(default = "default_ttl_secs")]
+    pub ttl_secs: u64,
+}
+fn default_replay_buffer() -> usize {
+    256
+}
+fn default_ttl_secs() -> u64 {
+    300
+}
+
+/// sent after the handshake response, `session_token` resumes the session on another connection
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WsSessionResponse {
+    pub session_token: String,
+    pub resumed: bool,
+    /// how many missed stream messages were sent before this response
+    pub replayed: u32,
+}
+
+/// `0resume, 1<session token>, 2<stream code>:<last stream seq>;...` where the acknowledged
+/// stream_seq of every stream code received so far is optional
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResumeRequest {
+    pub session_token: String,
+    pub acks: HashMap<u32, u32>,
+}
+impl ResumeRequest {
+    /// None if the header is not a resume handshake
+    pub fn parse(header: &str) -> Option<Result<Self>> {
+        let splits = header
+            .split(',')
+            .map(|x| x.trim())
+            .filter(|x| !x.is_empty())
+            .map(|x| (&x[..1], &x[1..]))
+            .collect::<HashMap<&str, &str>>();
+        if splits.get("0") != Some(&RESUME_METHOD) {
+            return None;
+        }
+        Some(Self::parse_params(&splits))
+    }
+    fn parse_params(splits: &HashMap<&str, &str>) -> Result<Self> {
+        let session_token = splits
+            .get("1")
+            .context("Could not find param session_token 1")?
+            .to_string();
+        let mut acks = HashMap::new();
+        if let Some(value) = splits.get("2") {
+            for ack in urlencoding::decode(value)?
+                .split(';')
+                .filter(|x| !x.is_empty())
+            {
+                let (code, seq) = ack
+                    .split_once(':')
+                    .with_context(|| format!("Failed to parse ack: {}", ack))?;
+                acks.insert(code.parse()?, seq.parse()?);
+            }
+        }
+        Ok(Self {
+            session_token,
+            acks,
+        })
+    }
+}
+
+struct WsSessionState {
+    replay: VecDeque<WsStreamResponse>,
+    /// the connection currently attached
+    log_id: u64,
+    detached_at: Option<Instant>,
+}
+
+/// outlives its websocket connection for `ttl_secs`, so that a reconnecting client keeps its
+/// connection id, and with it the subscriptions, and receives the stream messages it missed
+pub struct WsSession {
+    pub session_token: String,
+    pub connection_id: ConnectionId,
+    pub user_id: i64,
+    pub role: u32,
+    pub user_session_id: i64,
+    pub api_key_id: i64,
+    pub api_key_scopes: u32,
+    replay_buffer: usize,
+    ttl: Duration,
+    state: Mutex<WsSessionState>,
+}
+impl WsSession {
+    /// records stream messages for replay before handing `msg` to `deliver`, returns false once
+    /// the session has been detached longer than the ttl
+    pub fn send(
+        &self,
+        msg: WsResponseValue,
+        deliver: impl FnOnce(WsResponseValue) -> bool,
+    ) -> bool {
+        let mut state = self.state.lock().unwrap();
+        if let WsResponseValue::Stream(stream) = &msg {
+            if state.replay.len() >= self.replay_buffer {
+                state.replay.pop_front();
+            }
+            if self.replay_buffer > 0 {
+                state.replay.push_back(stream.clone());
+            }
+        }
+        if state.detached_at.is_none() && deliver(msg) {
+            return true;
+        }
+        state.detached_at.get_or_insert_with(Instant::now).elapsed() < self.ttl
+    }
+    /// attaches `conn` and passes the messages it has not acknowledged to `attach`, which must
+    /// queue them. live messages wait for the session lock and are queued after them
+    pub fn attach(
+        &self,
+        conn: &WsConnection,
+        acks: &HashMap<u32, u32>,
+        attach: impl FnOnce(Vec<WsStreamResponse>),
+    ) -> u32 {
+        let mut state = self.state.lock().unwrap();
+        state.log_id = conn.log_id;
+        state.detached_at = None;
+        let replay: Vec<_> = state
+            .replay
+            .iter()
+            .filter(|x| {
+                acks.get(&x.stream_code)
+                    .is_none_or(|seen| x.stream_seq > *seen)
+            })
+            .cloned()
+            .collect();
+        let replayed = replay.len() as u32;
+        attach(replay);
+        replayed
+    }
+    /// starts the ttl unless another connection took over the session meanwhile
+    pub fn detach(&self, conn: &WsConnection) {
+        let mut state = self.state.lock().unwrap();
+        if state.log_id == conn.log_id && state.detached_at.is_none() {
+            state.detached_at = Some(Instant::now());
+        }
+    }
+    pub fn has_credential(&self, credential: WsCredential) -> bool {
+        match credential {
+            WsCredential::UserSession(id) => id != 0 && self.user_session_id == id,
+            WsCredential::ApiKey(id) => id != 0 && self.api_key_id == id,
+        }
+    }
+    pub fn is_expired(&self) -> bool {
+        let state = self.state.lock().unwrap();
+        state.detached_at.is_some_and(|x| x.elapsed() >= self.ttl)
+    }
+}
+
+pub struct WsSessions {
+    config: SessionConfig,
+    by_connection: DashMap<ConnectionId, Arc<WsSession>>,
+    by_token: DashMap<String, Arc<WsSession>>,
+}
+impl WsSessions {
+    pub fn new(config: SessionConfig) -> Self {
+        Self {
+            config,
+            by_connection: Default::default(),
+            by_token: Default::default(),
+        }
+    }
+    pub fn create(&self, conn: &WsConnection) -> Arc<WsSession> {
+        let session = Arc::new(WsSession {
+            session_token: uuid::Uuid::new_v4().to_string(),
+            connection_id: conn.connection_id,
+            user_id: conn.get_user_id(),
+            role: conn.role.load(std::sync::atomic::Ordering::Relaxed),
+            user_session_id: conn.get_user_session_id(),
+            api_key_id: conn.get_api_key_id(),
+            api_key_scopes: conn
+                .api_key_scopes
+                .load(std::sync::atomic::Ordering::Relaxed),
+            replay_buffer: self.config.replay_buffer,
+            ttl: Duration::from_secs(self.config.ttl_secs),
+            state: Mutex::new(WsSessionState {
+                replay: VecDeque::new(),
+                log_id: conn.log_id,
+                detached_at: None,
+            }),
+        });
+        self.by_connection
+            .insert(session.connection_id, Arc::clone(&session));
+        self.by_token
+            .insert(session.session_token.clone(), Arc::clone(&session));
+        session
+    }
+    pub fn get(&self, connection_id: ConnectionId) -> Option<Arc<WsSession>> {
+        self.by_connection
+            .get(&connection_id)
+            .map(|x| Arc::clone(x.value()))
+    }
+    /// the session of `session_token` unless it expired
+    pub fn find(&self, session_token: &str) -> Option<Arc<WsSession>> {
+        let session = self
+            .by_token
+            .get(session_token)
+            .map(|x| Arc::clone(x.value()))?;
+        if session.is_expired() {
+            self.remove(&session);
+            return None;
+        }
+        Some(session)
+    }
+    pub fn detach(&self, conn: &WsConnection) {
+        if let Some(session) = self.get(conn.connection_id) {
+            session.detach(conn);
+        }
+    }
+    pub fn remove(&self, session: &WsSession) {
+        self.by_connection.remove(&session.connection_id);
+        self.by_token.remove(&session.session_token);
+    }
+    /// forgets the sessions authorized with `credential`, so that they can't be resumed
+    pub fn remove_credential(&self, credential: WsCredential) {
+        let sessions: Vec<_> = self
+            .by_token
+            .iter()
+            .filter(|x| x.has_credential(credential))
+            .map(|x| Arc::clone(x.value()))
+            .collect();
+        for session in sessions {
+            self.remove(&session);
+        }
+    }
+    pub fn remove_expired(&self) {
+        let expired: Vec<_> = self
+            .by_token
+            .iter()
+            .filter(|x| x.is_expired())
+            .map(|x| Arc::clone(x.value()))
+            .collect();
+        for session in expired {
+            debug!("Session of connection {} expired", session.connection_id);
+            self.remove(&session);
+        }
+    }
+    pub async fn expire_sessions(self: Arc<Self>) {
+        loop {
+            tokio::time::sleep(Duration::from_secs(self.config.ttl_secs.max(1))).await;
+            self.remove_expired();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+    use std::sync::atomic::{AtomicI64, AtomicU32};
+
+    fn conn(log_id: u64) -> WsConnection {
+        WsConnection {
+            connection_id: 1,
+            user_id: AtomicI64::new(2),
+            role: AtomicU32::new(3),
+            user_session_id: AtomicI64::new(4),
+            api_key_id: AtomicI64::new(0),
+            api_key_scopes: AtomicU32::new(0),
+            address: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+            log_id,
+        }
+    }
+    fn stream(stream_code: u32, stream_seq: u32) -> WsResponseValue {
+        WsResponseValue::Stream(WsStreamResponse {
+            original_seq: 1,
+            method: 1,
+            stream_seq,
+            stream_code,
+            data: Default::default(),
+        })
+    }
+
+    #[test]
+    fn test_parse_resume_request() {
+        assert_eq!(
+            ResumeRequest::parse("0login, 1address").map(|x| x.is_ok()),
+            None
+        );
+        let req = ResumeRequest::parse("0resume, 1token, 25%3A3%3B1%3A0")
+            .unwrap()
+            .unwrap();
+        assert_eq!(req.session_token, "token");
+        assert_eq!(req.acks, HashMap::from([(5, 3), (1, 0)]));
+        assert!(ResumeRequest::parse("0resume").unwrap().is_err());
+    }
+
+    #[test]
+    fn test_session_replay() {
+        let sessions = WsSessions::new(SessionConfig {
+            replay_buffer: 3,
+            ttl_secs: 60,
+        });
+        let session = sessions.create(&conn(1));
+        assert!(session.send(stream(5, 0), |_| true));
+        /* the connection dropped, the session keeps buffering */
+        assert!(session.send(stream(5, 1), |_| false));
+        sessions.detach(&conn(1));
+        assert!(session.send(stream(5, 2), |_| panic!("delivered while detached")));
+        assert!(session.send(stream(6, 0), |_| unreachable!()));
+
+        let resumed = sessions.find(&session.session_token).unwrap();
+        let mut replayed = vec![];
+        let count = resumed.attach(&conn(2), &HashMap::from([(5, 1)]), |x| replayed = x);
+        assert_eq!(count, 2);
+        assert_eq!(
+            replayed
+                .iter()
+                .map(|x| (x.stream_code, x.stream_seq))
+                .collect::<Vec<_>>(),
+            vec![(5, 2), (6, 0)]
+        );
+        /* the old connection closing late does not detach the new one */
+        sessions.detach(&conn(1));
+        let mut delivered = false;
+        assert!(resumed.send(stream(5, 3), |_| {
+            delivered = true;
+            true
+        }));
+        assert!(delivered);
+    }
+}
@@ -0,0 +1,308 @@
+use chrono::Utc;
+use eyre::*;
+use itertools::Itertools;
+use lib::database::{DatabaseRequest, DbClient, ToSql};
+use md5::{Digest, Md5};
+use postgres_from_row::FromRow;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use tracing::*;
+
+/// DDL of the table that records applied migrations, shared with the database setup scripts
+pub const SCHEMA_MIGRATIONS_SQL: &str = include_str!("../../db/schema_migrations.sql");
+
+/// a file in the migrations directory named `<version>_<name>.sql`, e.g. `0002_strategy_guard.sql`
+#[derive(Debug, Clone)]
+pub struct Migration {
+    pub version: i64,
+    pub name: String,
+    pub path: PathBuf,
+    pub sql: String,
+    /// md5 of the file content, the setup scripts compute the same with `md5sum`
+    pub checksum: String,
+}
+
+pub fn load_migrations(dir: &Path) -> Result<Vec<Migration>> {
+    let mut migrations = vec![];
+    for entry in std::fs::read_dir(dir).with_context(|| format!("cannot read {:?}", dir))? {
+        let path = entry?.path();
+        if path.extension().and_then(|x| x.to_str()) != Some("sql") {
+            continue;
+        }
+        let stem = path
+            .file_stem()
+            .and_then(|x| x.to_str())
+            .with_context(|| format!("invalid migration file name {:?}", path))?;
+        let (version, name) = stem
+            .split_once('_')
+            .with_context(|| format!("migration {:?} is not named <version>_<name>.sql", path))?;
+        let version: i64 = version
+            .parse()
+            .with_context(|| format!("migration {:?} has an invalid version", path))?;
+        let content = std::fs::read(&path)?;
+        migrations.push(Migration {
+            version,
+            name: name.to_owned(),
+            checksum: hex::encode(Md5::digest(&content)),
+            sql: String::from_utf8(content)?,
+            path,
+        });
+    }
+    migrations.sort_by_key(|x| x.version);
+    if let Some((a, b)) = migrations
+        .iter()
+        .tuple_windows()
+        .find(|(a, b)| a.version == b.version)
+    {
+        bail!(
+            "migrations {:?} and {:?} have the same version",
+            a.path,
+            b.path
+        );
+    }
+    Ok(migrations)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct AppliedMigration {
+    pub version: i64,
+    pub name: String,
+    pub checksum: String,
+    pub applied_at: i64,
+}
+
+#[derive(Debug, Clone)]
+struct ListAppliedMigrationsReq;
+impl DatabaseRequest for ListAppliedMigrationsReq {
+    type ResponseRow = AppliedMigration;
+    fn statement(&self) -> &str {
+        "SELECT version, name, checksum, applied_at FROM public.schema_migrations ORDER BY version;"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![]
+    }
+}
+
+#[derive(Debug, Clone)]
+struct AddAppliedMigrationReq {
+    version: i64,
+    name: String,
+    checksum: String,
+    applied_at: i64,
+}
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+struct AddAppliedMigrationRespRow {
+    version: i64,
+}
+impl DatabaseRequest for AddAppliedMigrationReq {
+    type ResponseRow = AddAppliedMigrationRespRow;
+    fn statement(&self) -> &str {
+        "INSERT INTO public.schema_migrations (version, name, checksum, applied_at) VALUES ($1, $2, $3, $4) RETURNING version;"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.version as &(dyn ToSql + Sync),
+            &self.name as &(dyn ToSql + Sync),
+            &self.checksum as &(dyn ToSql + Sync),
+            &self.applied_at as &(dyn ToSql + Sync),
+        ]
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ListLiveEnumsReq;
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+struct ListLiveEnumsRespRow {
+    type_name: String,
+    label: String,
+}
+impl DatabaseRequest for ListLiveEnumsReq {
+    type ResponseRow = ListLiveEnumsRespRow;
+    fn statement(&self) -> &str {
+        "SELECT t.typname::varchar AS type_name, e.enumlabel::varchar AS label
+           FROM pg_type t
+           JOIN pg_enum e ON e.enumtypid = t.oid
+           JOIN pg_namespace n ON n.oid = t.typnamespace
+          WHERE n.nspname = 'public' AND t.typname LIKE 'enum\\_%'
+          ORDER BY t.typname, e.enumsortorder;"
+    }
+    fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![]
+    }
+}
+
+async fn ensure_schema_migrations_table(db: &DbClient) -> Result<()> {
+    db.transaction(|tx| async move { tx.batch_execute(SCHEMA_MIGRATIONS_SQL).await })
+        .await
+}
+
+pub async fn list_applied_migrations(db: &DbClient) -> Result<Vec<AppliedMigration>> {
+    ensure_schema_migrations_table(db).await?;
+    Ok(db.execute(ListAppliedMigrationsReq).await?.into_rows())
+}
+
+/// enum values of the live database keyed by enum name without the `enum_` prefix
+pub async fn list_live_enums(db: &DbClient) -> Result<BTreeMap<String, Vec<String>>> {
+    let rows = db.execute(ListLiveEnumsReq).await?.into_rows();
+    let mut enums: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for row in rows {
+        let name = row
+            .type_name
+            .strip_prefix("enum_")
+            .unwrap_or(&row.type_name)
+            .to_owned();
+        enums.entry(name).or_default().push(row.label);
+    }
+    Ok(enums)
+}
+
+/// SQL that adds the enums and enum values of `shared/enums.rs` missing in the live database
+pub async fn diff_enums(db: &DbClient) -> Result<String> {
+    let live_enums = list_live_enums(db).await?;
+    Ok(codegen::sql::gen_enum_migration_sql(
+        &codegen::sql::get_enums(),
+        &live_enums,
+    ))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrationState {
+    Applied,
+    Pending,
+    /// the file changed after it was applied
+    ChecksumMismatch,
+    /// applied to the database but the file is gone
+    Missing,
+}
+impl Display for MigrationState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            MigrationState::Applied => "applied",
+            MigrationState::Pending => "pending",
+            MigrationState::ChecksumMismatch => "checksum mismatch",
+            MigrationState::Missing => "missing file",
+        };
+        f.write_str(s)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MigrationStatus {
+    pub version: i64,
+    pub name: String,
+    pub state: MigrationState,
+    pub applied_at: Option<i64>,
+}
+
+pub fn migration_status(
+    migrations: &[Migration],
+    applied: &[AppliedMigration],
+) -> Vec<MigrationStatus> {
+    let mut status: Vec<MigrationStatus> = migrations
+        .iter()
+        .map(|migration| {
+            let applied = applied.iter().find(|x| x.version == migration.version);
+            MigrationStatus {
+                version: migration.version,
+                name: migration.name.clone(),
+                state: match applied {
+                    None => MigrationState::Pending,
+                    Some(x) if x.checksum != migration.checksum => MigrationState::ChecksumMismatch,
+                    Some(_) => MigrationState::Applied,
+                },
+                applied_at: applied.map(|x| x.applied_at),
+            }
+        })
+        .collect();
+    for applied in applied {
+        if !migrations.iter().any(|x| x.version == applied.version) {
+            status.push(MigrationStatus {
+                version: applied.version,
+                name: applied.name.clone(),
+                state: MigrationState::Missing,
+                applied_at: Some(applied.applied_at),
+            });
+        }
+    }
+    status.sort_by_key(|x| x.version);
+    status
+}
+
+/// applies the pending migrations in order, each one in its own transaction together with its
+/// `schema_migrations` row. returns the applied versions
+pub async fn migrate_up(db: &DbClient, migrations: &[Migration]) -> Result<Vec<i64>> {
+    let applied = list_applied_migrations(db).await?;
+    let status = migration_status(migrations, &applied);
+    if let Some(x) = status
+        .iter()
+        .find(|x| x.state == MigrationState::ChecksumMismatch)
+    {
+        bail!(
+            "migration {}_{} was changed after it was applied",
+            x.version,
+            x.name
+        );
+    }
+    let latest_applied = applied.iter().map(|x| x.version).max().unwrap_or_default();
+    let mut versions = vec![];
+    for migration in migrations {
+        if applied.iter().any(|x| x.version == migration.version) {
+            continue;
+        }
+        if migration.version < latest_applied {
+            bail!(
+                "migration {}_{} is older than the latest applied migration {}",
+                migration.version,
+                migration.name,
+                latest_applied
+            );
+        }
+        info!(
+            "Applying migration {}_{}",
+            migration.version, migration.name
+        );
+        db.transaction(|tx| async move {
+            tx.batch_execute(&migration.sql).await?;
+            tx.execute(AddAppliedMigrationReq {
+                version: migration.version,
+                name: migration.name.clone(),
+                checksum: migration.checksum.clone(),
+                applied_at: Utc::now().timestamp(),
+            })
+            .await?;
+            Ok(())
+        })
+        .await
+        .with_context(|| {
+            format!(
+                "failed to apply migration {}_{}",
+                migration.version, migration.name
+            )
+        })?;
+        versions.push(migration.version);
+    }
+    Ok(versions)
+}
+
+/// records every migration as applied without running it, for databases created from
+/// `db/tbl.sql` which already contains the latest schema
+pub async fn baseline(db: &DbClient, migrations: &[Migration]) -> Result<Vec<i64>> {
+    let applied = list_applied_migrations(db).await?;
+    let mut versions = vec![];
+    for migration in migrations {
+        if applied.iter().any(|x| x.version == migration.version) {
+            continue;
+        }
+        db.execute(AddAppliedMigrationReq {
+            version: migration.version,
+            name: migration.name.clone(),
+            checksum: migration.checksum.clone(),
+            applied_at: Utc::now().timestamp(),
+        })
+        .await?;
+        versions.push(migration.version);
+    }
+    Ok(versions)
+}
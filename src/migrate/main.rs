@@ -0,0 +1,115 @@
+use clap::{Parser, Subcommand};
+use eyre::*;
+use lib::config::load_config_from_file;
+use lib::database::{connect_to_database, DatabaseConfig};
+use lib::log::{setup_logs, LogLevel};
+use mc2fi_migrate::*;
+use serde::Deserialize;
+use std::path::PathBuf;
+use tracing::*;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Config {
+    pub app_db: DatabaseConfig,
+    pub log_level: LogLevel,
+}
+
+#[derive(Parser)]
+#[clap(author, version, about = "Versioned schema migrations of the app database", long_about = None)]
+struct CliArgument {
+    /// The path to config file
+    #[clap(
+        short,
+        long,
+        value_parser,
+        value_name = "FILE",
+        default_value = "etc/config.json",
+        env = "CONFIG"
+    )]
+    config: PathBuf,
+    /// The directory of the numbered migration files
+    #[clap(long, value_parser, default_value = "db/migrations")]
+    dir: PathBuf,
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Apply the pending migrations
+    Up,
+    /// List the migrations and whether they are applied
+    Status,
+    /// Fail unless every migration is applied unchanged and the enums match shared/enums.rs
+    Verify,
+    /// Record every migration as applied, for databases created from db/tbl.sql
+    Baseline,
+    /// Write a migration that adds the enums and enum values missing in the database
+    NewEnumMigration {
+        /// The name of the migration file, after the version
+        #[clap(default_value = "enums")]
+        name: String,
+    },
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let args = CliArgument::parse();
+    let config: Config = load_config_from_file(&args.config, "migrate".to_owned())?;
+    setup_logs(config.log_level)?;
+    let db = connect_to_database(config.app_db).await?;
+    let migrations = load_migrations(&args.dir)?;
+
+    match args.command {
+        Command::Up => {
+            let versions = migrate_up(&db, &migrations).await?;
+            info!("Applied {} migrations: {:?}", versions.len(), versions);
+        }
+        Command::Status => {
+            let applied = list_applied_migrations(&db).await?;
+            for status in migration_status(&migrations, &applied) {
+                println!("{:04} {:<48} {}", status.version, status.name, status.state);
+            }
+        }
+        Command::Verify => {
+            let applied = list_applied_migrations(&db).await?;
+            let mut ok = true;
+            for status in migration_status(&migrations, &applied) {
+                if status.state != MigrationState::Applied {
+                    error!(
+                        "Migration {}_{} is {}",
+                        status.version, status.name, status.state
+                    );
+                    ok = false;
+                }
+            }
+            let enum_diff = diff_enums(&db).await?;
+            if !enum_diff.is_empty() {
+                error!("Enums differ from shared/enums.rs:\n{}", enum_diff);
+                ok = false;
+            }
+            ensure!(ok, "database schema is not up to date");
+            info!("Database schema is up to date");
+        }
+        Command::Baseline => {
+            let versions = baseline(&db, &migrations).await?;
+            info!(
+                "Recorded {} migrations as applied: {:?}",
+                versions.len(),
+                versions
+            );
+        }
+        Command::NewEnumMigration { name } => {
+            let sql = diff_enums(&db).await?;
+            if sql.is_empty() {
+                info!("Enums are up to date, no migration written");
+                return Ok(());
+            }
+            let version = migrations.last().map(|x| x.version).unwrap_or_default() + 1;
+            let path = args.dir.join(format!("{:04}_{}.sql", version, name));
+            std::fs::write(&path, format!("{}\n", sql))?;
+            info!("Wrote {:?}", path);
+        }
+    }
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use crate::types::*;
 use serde::*;
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EndpointSchema {
     pub name: String,
     pub code: u32,
@@ -9,6 +9,13 @@ pub struct EndpointSchema {
     pub stream_response: Option<Type>,
     pub description: String,
     pub json_schema: serde_json::Value,
+    /// variants of the `role` enum allowed to call the endpoint, nobody if empty
+    #[serde(default)]
+    pub roles: Vec<EnumVariant>,
+    /// variant of the `api_key_scope` enum an API key needs to call the endpoint, API keys can't
+    /// call it if None
+    #[serde(default)]
+    pub api_key_scope: Option<EnumVariant>,
 }
 
 impl EndpointSchema {
@@ -26,6 +33,8 @@ impl EndpointSchema {
             stream_response: None,
             description: "".to_string(),
             json_schema: Default::default(),
+            roles: vec![],
+            api_key_scope: None,
         }
     }
     pub fn with_stream_response_type(mut self, stream_response: Type) -> Self {
@@ -36,4 +45,15 @@ impl EndpointSchema {
         self.description = desc.into();
         self
     }
+    pub fn with_roles(mut self, roles: Vec<EnumVariant>) -> Self {
+        self.roles = roles;
+        self
+    }
+    pub fn with_api_key_scope(mut self, scope: EnumVariant) -> Self {
+        self.api_key_scope = Some(scope);
+        self
+    }
+    pub fn allows_role(&self, role: u32) -> bool {
+        self.roles.iter().any(|x| x.value == role as i64)
+    }
 }
@@ -40,7 +40,8 @@ pub fn get_admin_endpoints() -> Vec<EndpointSchema> {
                     ),
                 ),
             ],
-        ),
+        )
+        .with_roles(roles_at_least("admin")),
         EndpointSchema::new(
             "AdminSetUserRole",
             30020,
@@ -49,7 +50,8 @@ pub fn get_admin_endpoints() -> Vec<EndpointSchema> {
                 Field::new("role", Type::enum_ref("role")),
             ],
             vec![],
-        ),
+        )
+        .with_roles(roles_at_least("admin")),
         EndpointSchema::new(
             "AdminSetBlockUser",
             30030,
@@ -58,7 +60,8 @@ pub fn get_admin_endpoints() -> Vec<EndpointSchema> {
                 Field::new("blocked", Type::Boolean),
             ],
             vec![],
-        ),
+        )
+        .with_roles(roles_at_least("admin")),
         EndpointSchema::new(
             "AdminListPendingExpertApplications",
             30060,
@@ -89,21 +92,69 @@ pub fn get_admin_endpoints() -> Vec<EndpointSchema> {
                 ),
             ],
         )
+        .with_roles(roles_at_least("admin"))
         .with_description("Admin approves a user to become an expert"),
         EndpointSchema::new(
             "AdminApproveUserBecomeExpert",
             30040,
-            vec![Field::new("user_id", Type::BigInt)],
+            vec![
+                Field::new("user_id", Type::BigInt),
+                Field::new("note", Type::optional(Type::String)),
+            ],
             vec![Field::new("success", Type::Boolean)],
         )
+        .with_roles(roles_at_least("admin"))
         .with_description("Admin approves a user to become an expert"),
+        EndpointSchema::new(
+            "AdminGetExpertApplication",
+            30041,
+            vec![Field::new("user_id", Type::BigInt)],
+            vec![
+                Field::new("application_id", Type::BigInt),
+                Field::new("status", Type::enum_ref("expert_application_status")),
+                Field::new("questionnaire", Type::Object),
+                Field::new("submitted_at", Type::optional(Type::BigInt)),
+                Field::new("reviewed_at", Type::optional(Type::BigInt)),
+                Field::new("documents", Type::vec(expert_application_document_row())),
+                Field::new("notes", Type::vec(expert_application_note_row())),
+            ],
+        )
+        .with_roles(roles_at_least("admin"))
+        .with_description("Admin gets the expert application of a user"),
+        EndpointSchema::new(
+            "AdminGetExpertApplicationDocument",
+            30042,
+            vec![Field::new("document_id", Type::BigInt)],
+            vec![
+                Field::new("name", Type::String),
+                Field::new("content_type", Type::String),
+                Field::new("content", Type::String),
+            ],
+        )
+        .with_roles(roles_at_least("admin"))
+        .with_description("Admin downloads a base64 encoded expert application document"),
+        EndpointSchema::new(
+            "AdminRequestExpertApplicationInfo",
+            30045,
+            vec![
+                Field::new("user_id", Type::BigInt),
+                Field::new("note", Type::String),
+            ],
+            vec![Field::new("success", Type::Boolean)],
+        )
+        .with_roles(roles_at_least("admin"))
+        .with_description("Admin asks a user for more information on their expert application"),
         EndpointSchema::new(
             "AdminRejectUserBecomeExpert",
             30050,
-            vec![Field::new("user_id", Type::BigInt)],
+            vec![
+                Field::new("user_id", Type::BigInt),
+                Field::new("reason", Type::String),
+            ],
             vec![Field::new("success", Type::Boolean)],
         )
-        .with_description("Admin approves a user to become an expert"),
+        .with_roles(roles_at_least("admin"))
+        .with_description("Admin rejects a user's expert application"),
         EndpointSchema::new(
             "AdminGetSystemConfig",
             30070,
@@ -118,8 +169,22 @@ pub fn get_admin_endpoints() -> Vec<EndpointSchema> {
                     "escrow_contract_address_bsc_testnet",
                     Type::BlockchainAddress,
                 ),
+                Field::new(
+                    "settings",
+                    Type::datatable(
+                        "AdminSystemSettingRow",
+                        vec![
+                            Field::new("key", Type::String),
+                            Field::new("value", Type::Object),
+                            Field::new("version", Type::BigInt),
+                            Field::new("updated_by_user_id", Type::optional(Type::BigInt)),
+                            Field::new("updated_at", Type::optional(Type::BigInt)),
+                        ],
+                    ),
+                ),
             ],
         )
+        .with_roles(roles_at_least("admin"))
         .with_description("Admin get system config"),
         EndpointSchema::new(
             "AdminUpdateSystemConfig",
@@ -143,10 +208,49 @@ pub fn get_admin_endpoints() -> Vec<EndpointSchema> {
                     "escrow_contract_address_bsc_testnet",
                     Type::optional(Type::BlockchainAddress),
                 ),
+                Field::new(
+                    "settings",
+                    Type::optional(Type::vec(Type::struct_(
+                        "AdminSystemSettingUpdate",
+                        vec![
+                            Field::new("key", Type::String),
+                            Field::new("value", Type::Object),
+                            Field::new("version", Type::optional(Type::BigInt)),
+                        ],
+                    ))),
+                ),
             ],
             vec![Field::new("success", Type::Boolean)],
         )
+        .with_roles(roles_at_least("admin"))
         .with_description("Admin updates system config"),
+        EndpointSchema::new(
+            "AdminListSystemSettingHistory",
+            30085,
+            vec![
+                Field::new("limit", Type::optional(Type::BigInt)),
+                Field::new("offset", Type::optional(Type::BigInt)),
+                Field::new("key", Type::optional(Type::String)),
+            ],
+            vec![
+                Field::new("history_total", Type::BigInt),
+                Field::new(
+                    "history",
+                    Type::datatable(
+                        "AdminSystemSettingHistoryRow",
+                        vec![
+                            Field::new("key", Type::String),
+                            Field::new("value", Type::Object),
+                            Field::new("version", Type::BigInt),
+                            Field::new("updated_by_user_id", Type::optional(Type::BigInt)),
+                            Field::new("created_at", Type::BigInt),
+                        ],
+                    ),
+                ),
+            ],
+        )
+        .with_roles(roles_at_least("admin"))
+        .with_description("Admin lists the changes of the system settings"),
         EndpointSchema::new(
             "AdminListExperts",
             30090,
@@ -167,6 +271,7 @@ pub fn get_admin_endpoints() -> Vec<EndpointSchema> {
                 Field::new("experts", list_experts_datatable()),
             ],
         )
+        .with_roles(roles_at_least("admin"))
         .with_description("Admin lists experts"),
         EndpointSchema::new(
             "AdminListBackers",
@@ -200,7 +305,8 @@ pub fn get_admin_endpoints() -> Vec<EndpointSchema> {
                     ),
                 ),
             ],
-        ),
+        )
+        .with_roles(roles_at_least("admin")),
         EndpointSchema::new(
             "AdminListStrategies",
             30110,
@@ -219,26 +325,30 @@ pub fn get_admin_endpoints() -> Vec<EndpointSchema> {
                 Field::new("strategies_total", Type::BigInt),
                 Field::new("strategies", list_strategies_datatable()),
             ],
-        ),
+        )
+        .with_roles(roles_at_least("admin")),
         EndpointSchema::new(
             "AdminApproveStrategy",
             30120,
             vec![Field::new("strategy_id", Type::BigInt)],
             vec![Field::new("success", Type::Boolean)],
         )
+        .with_roles(roles_at_least("admin"))
         .with_description("Admin approves strategy"),
         EndpointSchema::new(
             "AdminRefreshExpertWalletBalance",
             30121,
             vec![Field::new("strategy_id", Type::BigInt)],
             vec![Field::new("success", Type::Boolean)],
-        ),
+        )
+        .with_roles(roles_at_least("admin")),
         EndpointSchema::new(
             "AdminRejectStrategy",
             30130,
             vec![Field::new("strategy_id", Type::BigInt)],
             vec![Field::new("success", Type::Boolean)],
-        ),
+        )
+        .with_roles(roles_at_least("admin")),
         EndpointSchema::new(
             "AdminAddAuditRule",
             31002,
@@ -248,7 +358,8 @@ pub fn get_admin_endpoints() -> Vec<EndpointSchema> {
                 Field::new("description", Type::String),
             ],
             vec![],
-        ),
+        )
+        .with_roles(roles_at_least("admin")),
         EndpointSchema::new(
             "AdminNotifyEscrowLedgerChange",
             32010,
@@ -258,7 +369,8 @@ pub fn get_admin_endpoints() -> Vec<EndpointSchema> {
                 Field::new("balance", user_deposit_withdraw_ledger_entry()),
             ],
             vec![],
-        ),
+        )
+        .with_roles(roles_at_least("admin")),
         EndpointSchema::new(
             "AdminSubscribeDepositLedger",
             32011,
@@ -269,8 +381,10 @@ pub fn get_admin_endpoints() -> Vec<EndpointSchema> {
             ],
             vec![],
         )
+        .with_roles(roles_at_least("admin"))
         .with_stream_response_type(user_deposit_withdraw_ledger_entry()),
-        EndpointSchema::new("AdminUnsubscribeDepositLedger", 32012, vec![], vec![]),
+        EndpointSchema::new("AdminUnsubscribeDepositLedger", 32012, vec![], vec![])
+            .with_roles(roles_at_least("admin")),
         EndpointSchema::new(
             "AdminAddEscrowTokenContractAddress",
             32020,
@@ -285,7 +399,8 @@ pub fn get_admin_endpoints() -> Vec<EndpointSchema> {
                 Field::new("is_wrapped", Type::Boolean),
             ],
             vec![],
-        ),
+        )
+        .with_roles(roles_at_least("admin")),
         EndpointSchema::new(
             "AdminAddEscrowContractAddress",
             32030,
@@ -295,7 +410,8 @@ pub fn get_admin_endpoints() -> Vec<EndpointSchema> {
                 Field::new("blockchain", Type::enum_ref("block_chain")),
             ],
             vec![],
-        ),
+        )
+        .with_roles(roles_at_least("admin")),
         EndpointSchema::new(
             "AdminListBackStrategyLedger",
             32040,
@@ -322,7 +438,8 @@ pub fn get_admin_endpoints() -> Vec<EndpointSchema> {
                     ),
                 ),
             ],
-        ),
+        )
+        .with_roles(roles_at_least("admin")),
         EndpointSchema::new(
             "AdminListExitStrategyLedger",
             32041,
@@ -349,13 +466,15 @@ pub fn get_admin_endpoints() -> Vec<EndpointSchema> {
                     ),
                 ),
             ],
-        ),
+        )
+        .with_roles(roles_at_least("admin")),
         EndpointSchema::new(
             "AdminSetBlockchainLogger",
             32050,
             vec![Field::new("enabled", Type::Boolean)],
             vec![],
-        ),
+        )
+        .with_roles(roles_at_least("admin")),
         EndpointSchema::new(
             "AdminListEscrowTokenContractAddresses",
             32060,
@@ -387,7 +506,8 @@ pub fn get_admin_endpoints() -> Vec<EndpointSchema> {
                     ),
                 ),
             ],
-        ),
+        )
+        .with_roles(roles_at_least("admin")),
         EndpointSchema::new(
             "AdminUpdateEscrowTokenContractAddress",
             32080,
@@ -400,6 +520,77 @@ pub fn get_admin_endpoints() -> Vec<EndpointSchema> {
                 Field::new("is_wrapped", Type::optional(Type::Boolean)),
             ],
             vec![],
-        ),
+        )
+        .with_roles(roles_at_least("admin")),
+        EndpointSchema::new(
+            "AdminListPendingActions",
+            33010,
+            vec![
+                Field::new("limit", Type::optional(Type::BigInt)),
+                Field::new("offset", Type::optional(Type::BigInt)),
+                Field::new(
+                    "status",
+                    Type::optional(Type::enum_ref("admin_pending_action_status")),
+                ),
+            ],
+            vec![
+                Field::new("pending_actions_total", Type::BigInt),
+                Field::new(
+                    "pending_actions",
+                    Type::datatable(
+                        "AdminPendingActionRow",
+                        vec![
+                            Field::new("pending_action_id", Type::BigInt),
+                            Field::new("requested_by_user_id", Type::BigInt),
+                            Field::new("method", Type::String),
+                            Field::new("params", Type::Object),
+                            Field::new("status", Type::enum_ref("admin_pending_action_status")),
+                            Field::new("approved_by_user_id", Type::optional(Type::BigInt)),
+                            Field::new("approved_at", Type::optional(Type::BigInt)),
+                            Field::new("created_at", Type::BigInt),
+                        ],
+                    ),
+                ),
+            ],
+        )
+        .with_roles(roles_at_least("admin")),
+        EndpointSchema::new(
+            "AdminApprovePendingAction",
+            33020,
+            vec![Field::new("pending_action_id", Type::BigInt)],
+            vec![Field::new("success", Type::Boolean)],
+        )
+        .with_roles(roles_at_least("admin")),
+        EndpointSchema::new(
+            "AdminListActionLog",
+            33030,
+            vec![
+                Field::new("limit", Type::optional(Type::BigInt)),
+                Field::new("offset", Type::optional(Type::BigInt)),
+                Field::new("admin_user_id", Type::optional(Type::BigInt)),
+                Field::new("method", Type::optional(Type::String)),
+            ],
+            vec![
+                Field::new("action_log_total", Type::BigInt),
+                Field::new(
+                    "action_log",
+                    Type::datatable(
+                        "AdminActionLogRow",
+                        vec![
+                            Field::new("action_log_id", Type::BigInt),
+                            Field::new("admin_user_id", Type::BigInt),
+                            Field::new("pending_action_id", Type::optional(Type::BigInt)),
+                            Field::new("requested_by_user_id", Type::optional(Type::BigInt)),
+                            Field::new("method", Type::String),
+                            Field::new("params", Type::Object),
+                            Field::new("state_before", Type::optional(Type::Object)),
+                            Field::new("state_after", Type::optional(Type::Object)),
+                            Field::new("created_at", Type::BigInt),
+                        ],
+                    ),
+                ),
+            ],
+        )
+        .with_roles(roles_at_least("admin")),
     ]
 }
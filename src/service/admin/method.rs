@@ -1,24 +1,35 @@
+use crate::events::{DomainEvent, RedeemProcessedEvent, StrategyTradeEvent};
 use crate::shared_method::{
-    convert_expert_db_to_api, convert_strategy_db_to_api_net_value, ensure_user_role,
+    convert_expert_db_to_api, convert_strategy_db_to_api_net_value, load_expert_application_details,
 };
 use api::AssetInfoClient;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
 use eth_sdk::erc20::Erc20Token;
 use eth_sdk::logger::get_blockchain_logger;
 use eth_sdk::utils::u256_to_decimal;
 use eth_sdk::EthereumRpcConnectionPool;
 use eyre::ContextCompat;
 use eyre::*;
+use futures::future::BoxFuture;
 use futures::FutureExt;
 use gen::database::*;
 use gen::model::*;
+use lib::blob_store::BlobStore;
 use lib::database::DbClient;
+use lib::deferred::{DeferPolicy, DeferredCall, DeferredHandlers};
+use lib::event_bus::EventBus;
 use lib::handler::{FutureResponse, RequestHandler};
-use lib::toolbox::{RequestContext, Toolbox};
-use lib::ws::SubscribeManager;
+use lib::system_config::SystemSettings;
+use lib::toolbox::{CustomError, RequestContext, Toolbox};
+use lib::ws::{SubscribeManager, WsRequest};
 use lib::{DEFAULT_LIMIT, DEFAULT_OFFSET};
+use serde_json::Value;
+use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::time::sleep;
+use tracing::*;
 use web3::types::Address;
 
 pub struct MethodAdminListUsers;
@@ -28,13 +39,11 @@ impl RequestHandler for MethodAdminListUsers {
     fn handle(
         &self,
         toolbox: &Toolbox,
-        ctx: RequestContext,
+        _ctx: RequestContext,
         req: Self::Request,
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::Admin)?;
-
             let ret = db
                 .execute(FunAdminListUsersReq {
                     user_id: req.user_id,
@@ -77,13 +86,11 @@ impl RequestHandler for MethodAdminSetUserRole {
     fn handle(
         &self,
         toolbox: &Toolbox,
-        ctx: RequestContext,
+        _ctx: RequestContext,
         req: Self::Request,
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::Admin)?;
-
             let _ret = db
                 .execute(FunAdminSetUserRoleReq {
                     user_id: req.user_id,
@@ -103,13 +110,11 @@ impl RequestHandler for MethodAdminSetBlockUser {
     fn handle(
         &self,
         toolbox: &Toolbox,
-        ctx: RequestContext,
+        _ctx: RequestContext,
         req: Self::Request,
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::Admin)?;
-
             let _ret = db
                 .execute(FunAdminSetBlockUserReq {
                     user_id: req.user_id,
@@ -134,11 +139,11 @@ impl RequestHandler for MethodAdminApproveUserBecomeExpert {
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::Admin)?;
-
             let ret = db
                 .execute(FunAdminApproveUserBecomeExpertReq {
+                    admin_user_id: ctx.user_id,
                     user_public_id: req.user_id,
+                    note: req.note,
                 })
                 .await?;
 
@@ -164,11 +169,11 @@ impl RequestHandler for MethodAdminRejectUserBecomeExpert {
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::Admin)?;
-
             let ret = db
                 .execute(FunAdminRejectUserBecomeExpertReq {
+                    admin_user_id: ctx.user_id,
                     user_public_id: req.user_id,
+                    reason: req.reason,
                 })
                 .await?;
 
@@ -182,6 +187,107 @@ impl RequestHandler for MethodAdminRejectUserBecomeExpert {
         .boxed()
     }
 }
+pub struct MethodAdminRequestExpertApplicationInfo;
+impl RequestHandler for MethodAdminRequestExpertApplicationInfo {
+    type Request = AdminRequestExpertApplicationInfoRequest;
+
+    fn handle(
+        &self,
+        toolbox: &Toolbox,
+        ctx: RequestContext,
+        req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let db: DbClient = toolbox.get_db();
+        async move {
+            let ret = db
+                .execute(FunAdminRequestExpertApplicationInfoReq {
+                    admin_user_id: ctx.user_id,
+                    user_public_id: req.user_id,
+                    note: req.note,
+                })
+                .await?;
+
+            Ok(AdminRequestExpertApplicationInfoResponse {
+                success: ret
+                    .into_result()
+                    .context("failed to request expert application info")?
+                    .success,
+            })
+        }
+        .boxed()
+    }
+}
+pub struct MethodAdminGetExpertApplication;
+impl RequestHandler for MethodAdminGetExpertApplication {
+    type Request = AdminGetExpertApplicationRequest;
+
+    fn handle(
+        &self,
+        toolbox: &Toolbox,
+        _ctx: RequestContext,
+        req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let db: DbClient = toolbox.get_db();
+        async move {
+            let application = db
+                .execute(FunUserGetExpertApplicationReq {
+                    user_id: None,
+                    user_public_id: Some(req.user_id),
+                })
+                .await?
+                .into_result()
+                .with_context(|| {
+                    CustomError::new(EnumErrorCode::NotFound, "Expert application not found")
+                })?;
+            let (documents, notes) =
+                load_expert_application_details(&db, application.application_id).await?;
+
+            Ok(AdminGetExpertApplicationResponse {
+                application_id: application.application_id,
+                status: application.status,
+                questionnaire: application.questionnaire,
+                submitted_at: application.submitted_at,
+                reviewed_at: application.reviewed_at,
+                documents,
+                notes,
+            })
+        }
+        .boxed()
+    }
+}
+pub struct MethodAdminGetExpertApplicationDocument {
+    pub blob_store: Arc<dyn BlobStore>,
+}
+impl RequestHandler for MethodAdminGetExpertApplicationDocument {
+    type Request = AdminGetExpertApplicationDocumentRequest;
+
+    fn handle(
+        &self,
+        toolbox: &Toolbox,
+        _ctx: RequestContext,
+        req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let db: DbClient = toolbox.get_db();
+        let blob_store = self.blob_store.clone();
+        async move {
+            let document = db
+                .execute(FunAdminGetExpertApplicationDocumentReq {
+                    document_id: req.document_id,
+                })
+                .await?
+                .into_result()
+                .with_context(|| CustomError::new(EnumErrorCode::NotFound, "Document not found"))?;
+            let content = blob_store.get(&document.blob_key).await?;
+
+            Ok(AdminGetExpertApplicationDocumentResponse {
+                name: document.name,
+                content_type: document.content_type,
+                content: STANDARD.encode(content),
+            })
+        }
+        .boxed()
+    }
+}
 pub struct MethodAdminListPendingExpertApplications;
 impl RequestHandler for MethodAdminListPendingExpertApplications {
     type Request = AdminListPendingExpertApplicationsRequest;
@@ -189,13 +295,11 @@ impl RequestHandler for MethodAdminListPendingExpertApplications {
     fn handle(
         &self,
         toolbox: &Toolbox,
-        ctx: RequestContext,
+        _ctx: RequestContext,
         req: Self::Request,
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::Admin)?;
-
             let ret = db
                 .execute(FunAdminListPendingUserExpertApplicationsReq {
                     limit: req.limit.unwrap_or(DEFAULT_LIMIT),
@@ -230,12 +334,11 @@ impl RequestHandler for MethodAdminGetSystemConfig {
     fn handle(
         &self,
         toolbox: &Toolbox,
-        ctx: RequestContext,
+        _ctx: RequestContext,
         _req: Self::Request,
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::Admin)?;
             let ret = db
                 .execute(FunAdminGetSystemConfigReq { config_id: 0 })
                 .await?
@@ -250,6 +353,15 @@ impl RequestHandler for MethodAdminGetSystemConfig {
                     .map(|x| x.address)
                     .unwrap_or_default()
             };
+            let stored_settings = db
+                .execute(FunAdminListSystemSettingsReq {})
+                .await?
+                .into_rows();
+            let settings = SystemSettings::from_entries(
+                stored_settings
+                    .iter()
+                    .map(|x| (x.key.clone(), x.value.clone())),
+            );
             let x = AdminGetSystemConfigResponse {
                 platform_fee: ret
                     .as_ref()
@@ -275,6 +387,20 @@ impl RequestHandler for MethodAdminGetSystemConfig {
                     EnumBlockChain::BscTestnet,
                 )
                 .into(),
+                settings: settings
+                    .entries()
+                    .into_iter()
+                    .map(|(key, value)| {
+                        let stored = stored_settings.iter().find(|x| x.key == key);
+                        AdminSystemSettingRow {
+                            key,
+                            value,
+                            version: stored.map(|x| x.version).unwrap_or_default(),
+                            updated_by_user_id: stored.and_then(|x| x.updated_by_user_id),
+                            updated_at: stored.map(|x| x.updated_at),
+                        }
+                    })
+                    .collect(),
             };
             Ok(x)
         }
@@ -293,7 +419,32 @@ impl RequestHandler for MethodAdminUpdateSystemConfig {
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::Admin)?;
+            let settings = req.settings.unwrap_or_default();
+            // validate every setting before anything is written, a version conflict then stops
+            // the update before the rest of the config is touched
+            let mut current = SystemSettings::from_entries(
+                db.execute(FunAdminListSystemSettingsReq {})
+                    .await?
+                    .into_iter()
+                    .map(|x| (x.key, x.value)),
+            );
+            for setting in &settings {
+                if let Err(err) = current.set(&setting.key, &setting.value) {
+                    bail!(CustomError::new(
+                        EnumErrorCode::InvalidArgument,
+                        err.to_string()
+                    ));
+                }
+            }
+            for setting in settings {
+                db.execute(FunAdminSetSystemSettingReq {
+                    admin_user_id: ctx.user_id,
+                    key: setting.key,
+                    value: setting.value,
+                    expected_version: setting.version,
+                })
+                .await?;
+            }
 
             let _ret = db
                 .execute(FunAdminUpdateSystemConfigReq {
@@ -344,6 +495,40 @@ impl RequestHandler for MethodAdminUpdateSystemConfig {
         .boxed()
     }
 }
+pub struct MethodAdminListSystemSettingHistory;
+impl RequestHandler for MethodAdminListSystemSettingHistory {
+    type Request = AdminListSystemSettingHistoryRequest;
+
+    fn handle(
+        &self,
+        toolbox: &Toolbox,
+        _ctx: RequestContext,
+        req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let db: DbClient = toolbox.get_db();
+        async move {
+            let ret = db
+                .execute(FunAdminListSystemSettingHistoryReq {
+                    limit: req.limit.unwrap_or(DEFAULT_LIMIT),
+                    offset: req.offset.unwrap_or(DEFAULT_OFFSET),
+                    key: req.key,
+                })
+                .await?;
+
+            Ok(AdminListSystemSettingHistoryResponse {
+                history_total: ret.first(|x| x.total).unwrap_or_default(),
+                history: ret.map(|x| AdminSystemSettingHistoryRow {
+                    key: x.key,
+                    value: x.value,
+                    version: x.version,
+                    updated_by_user_id: x.updated_by_user_id,
+                    created_at: x.created_at,
+                }),
+            })
+        }
+        .boxed()
+    }
+}
 pub struct MethodAdminListExperts;
 impl RequestHandler for MethodAdminListExperts {
     type Request = AdminListExpertsRequest;
@@ -351,13 +536,11 @@ impl RequestHandler for MethodAdminListExperts {
     fn handle(
         &self,
         toolbox: &Toolbox,
-        ctx: RequestContext,
+        _ctx: RequestContext,
         req: Self::Request,
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::Admin)?;
-
             let ret = db
                 .execute(FunAdminListExpertsReq {
                     limit: req.limit.unwrap_or(DEFAULT_LIMIT),
@@ -389,13 +572,11 @@ impl RequestHandler for MethodAdminListBackers {
     fn handle(
         &self,
         toolbox: &Toolbox,
-        ctx: RequestContext,
+        _ctx: RequestContext,
         req: Self::Request,
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::Admin)?;
-
             let ret = db
                 .execute(FunAdminListBackersReq {
                     limit: req.limit.unwrap_or(DEFAULT_LIMIT),
@@ -434,14 +615,12 @@ impl RequestHandler for MethodAdminListStrategies {
     fn handle(
         &self,
         toolbox: &Toolbox,
-        ctx: RequestContext,
+        _ctx: RequestContext,
         req: Self::Request,
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
         let asset_client = self.asset_client.clone();
         async move {
-            ensure_user_role(ctx, EnumRole::Admin)?;
-
             let ret = db
                 .execute(FunAdminListStrategiesReq {
                     limit: req.limit.unwrap_or(DEFAULT_LIMIT),
@@ -476,14 +655,12 @@ impl RequestHandler for MethodAdminApproveStrategy {
     fn handle(
         &self,
         toolbox: &Toolbox,
-        ctx: RequestContext,
+        _ctx: RequestContext,
         req: Self::Request,
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
         let pool = self.pool.clone();
         async move {
-            ensure_user_role(ctx, EnumRole::Admin)?;
-
             db.execute(FunAdminApproveStrategyReq {
                 strategy_id: req.strategy_id,
             })
@@ -507,14 +684,12 @@ impl RequestHandler for MethodAdminRefreshExpertWalletBalance {
     fn handle(
         &self,
         toolbox: &Toolbox,
-        ctx: RequestContext,
+        _ctx: RequestContext,
         req: Self::Request,
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
         let pool = self.pool.clone();
         async move {
-            ensure_user_role(ctx, EnumRole::Admin)?;
-
             fetch_and_update_strategy_watched_wallet_asset_balances(&db, &pool, req.strategy_id)
                 .await?;
 
@@ -606,13 +781,11 @@ impl RequestHandler for MethodAdminRejectStrategy {
     fn handle(
         &self,
         toolbox: &Toolbox,
-        ctx: RequestContext,
+        _ctx: RequestContext,
         req: Self::Request,
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::Admin)?;
-
             db.execute(FunAdminRejectStrategyReq {
                 strategy_id: req.strategy_id,
             })
@@ -631,14 +804,12 @@ impl RequestHandler for MethodAdminAddAuditRule {
     fn handle(
         &self,
         toolbox: &Toolbox,
-        ctx: RequestContext,
+        _ctx: RequestContext,
         req: Self::Request,
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
 
         async move {
-            ensure_user_role(ctx, EnumRole::Admin)?;
-
             let _ret = db
                 .execute(FunAdminAddAuditRuleReq {
                     rule_id: req.rule_id,
@@ -657,6 +828,8 @@ pub enum AdminSubscribeTopic {
     AdminNotifyEscrowLedgerChange = 1,
     AdminNotifyEscrowLedgerChangeAll = 2,
     UserBackProgress = 3,
+    StrategyGuardTriggered = 4,
+    UserNotification = 5,
 }
 impl Into<u32> for AdminSubscribeTopic {
     fn into(self) -> u32 {
@@ -678,24 +851,260 @@ impl RequestHandler for MethodAdminNotifyEscrowLedgerChange {
         let manager = self.manager.clone();
         let toolbox = toolbox.clone();
         async move {
-            manager.publish_with_filter(
-                &toolbox,
-                AdminSubscribeTopic::AdminNotifyEscrowLedgerChange,
-                &req.balance,
-                |ctx| ctx.user_id == req.user_id,
-                // TODO: filter by blockchain
-            );
-            manager.publish_to_all(
-                &toolbox,
-                AdminSubscribeTopic::AdminNotifyEscrowLedgerChangeAll,
-                &req.balance,
-            );
+            publish_escrow_ledger_change(&toolbox, &manager, req.user_id, &req.balance);
 
             Ok(AdminNotifyEscrowLedgerChangeResponse {})
         }
         .boxed()
     }
 }
+pub fn publish_escrow_ledger_change(
+    toolbox: &Toolbox,
+    manager: &SubscribeManager<AdminSubscribeTopic>,
+    user_id: i64,
+    entry: &UserListDepositLedgerRow,
+) {
+    manager.publish_with_filter(
+        toolbox,
+        AdminSubscribeTopic::AdminNotifyEscrowLedgerChange,
+        entry,
+        |ctx| ctx.user_id == user_id,
+        // TODO: filter by blockchain
+    );
+    manager.publish_to_all(
+        toolbox,
+        AdminSubscribeTopic::AdminNotifyEscrowLedgerChangeAll,
+        entry,
+    );
+}
+pub async fn publish_strategy_guard_triggered(
+    toolbox: &Toolbox,
+    manager: &SubscribeManager<AdminSubscribeTopic>,
+    event: &StrategyGuardEvent,
+) -> Result<()> {
+    let db: DbClient = toolbox.get_db();
+    /* the expert and everyone currently backing the strategy */
+    let recipients: HashSet<i64> = db
+        .execute(FunUserListStrategyGuardNotificationRecipientsReq {
+            strategy_id: event.strategy_id,
+        })
+        .await?
+        .into_iter()
+        .map(|x| x.user_id)
+        .collect();
+    manager.publish_with_filter(
+        toolbox,
+        AdminSubscribeTopic::StrategyGuardTriggered,
+        event,
+        |ctx| recipients.contains(&ctx.user_id),
+    );
+    /* users who are offline find the trigger in their notifications */
+    notify_users_of_event(
+        toolbox,
+        manager,
+        format!(
+            "StrategyGuardTriggered:{}:{}",
+            event.rule_id, event.triggered_at
+        ),
+        recipients.into_iter().collect(),
+        EnumUserNotificationKind::StrategyGuard,
+        Some(event.strategy_id),
+        format!(
+            "{} guard of strategy {} triggered on {} at {}, copy trading is paused",
+            event.rule_type, event.strategy_id, event.blockchain, event.current_value
+        ),
+    )
+    .await
+}
+/// stores a notification for every recipient and pushes it to their connections that subscribed
+/// with `UserSubscribeNotifications`
+pub async fn notify_users(
+    toolbox: &Toolbox,
+    manager: &SubscribeManager<AdminSubscribeTopic>,
+    user_ids: Vec<i64>,
+    kind: EnumUserNotificationKind,
+    strategy_id: Option<i64>,
+    message: String,
+) -> Result<()> {
+    add_and_push_notifications(toolbox, manager, None, user_ids, kind, strategy_id, message).await
+}
+/// like [`notify_users`] for an event of the bus, which every user service handles: the
+/// notifications are stored once under `event_key`, each service pushes them to its own connections
+pub async fn notify_users_of_event(
+    toolbox: &Toolbox,
+    manager: &SubscribeManager<AdminSubscribeTopic>,
+    event_key: String,
+    user_ids: Vec<i64>,
+    kind: EnumUserNotificationKind,
+    strategy_id: Option<i64>,
+    message: String,
+) -> Result<()> {
+    add_and_push_notifications(
+        toolbox,
+        manager,
+        Some(event_key),
+        user_ids,
+        kind,
+        strategy_id,
+        message,
+    )
+    .await
+}
+async fn add_and_push_notifications(
+    toolbox: &Toolbox,
+    manager: &SubscribeManager<AdminSubscribeTopic>,
+    event_key: Option<String>,
+    user_ids: Vec<i64>,
+    kind: EnumUserNotificationKind,
+    strategy_id: Option<i64>,
+    message: String,
+) -> Result<()> {
+    if user_ids.is_empty() {
+        return Ok(());
+    }
+    let db: DbClient = toolbox.get_db();
+    let rows = db
+        .execute(FunUserAddNotificationsReq {
+            user_ids,
+            kind,
+            strategy_id,
+            message: message.clone(),
+            event_key,
+        })
+        .await?;
+    for row in rows.into_iter() {
+        manager.publish_with_filter(
+            toolbox,
+            AdminSubscribeTopic::UserNotification,
+            &UserNotification {
+                notification_id: row.notification_id,
+                kind,
+                strategy_id,
+                message: message.clone(),
+                read_at: None,
+                created_at: row.created_at,
+            },
+            |ctx| ctx.user_id == row.user_id,
+        );
+    }
+    Ok(())
+}
+
+pub async fn notify_strategy_trade(
+    toolbox: &Toolbox,
+    manager: &SubscribeManager<AdminSubscribeTopic>,
+    event: &StrategyTradeEvent,
+) -> Result<()> {
+    let db: DbClient = toolbox.get_db();
+    let mut symbols = vec![];
+    for address in [event.token_in_address, event.token_out_address] {
+        let symbol = db
+            .execute(FunUserListEscrowTokenContractAddressReq {
+                limit: 1,
+                offset: 0,
+                token_id: None,
+                blockchain: Some(event.blockchain),
+                address: Some(address.into()),
+                symbol: None,
+                is_stablecoin: None,
+            })
+            .await?
+            .into_result()
+            .map(|x| x.symbol)
+            .unwrap_or_else(|| format!("{:?}", address));
+        symbols.push(symbol);
+    }
+    /* the expert and everyone currently backing the strategy */
+    let recipients = db
+        .execute(FunUserListStrategyGuardNotificationRecipientsReq {
+            strategy_id: event.strategy_id,
+        })
+        .await?
+        .into_iter()
+        .map(|x| x.user_id)
+        .collect();
+    notify_users_of_event(
+        toolbox,
+        manager,
+        format!("CopyTradeExecuted:{:?}", event.transaction_hash),
+        recipients,
+        EnumUserNotificationKind::StrategyTrade,
+        Some(event.strategy_id),
+        format!(
+            "Strategy {} traded {} {} for {} {}",
+            event.strategy_id, event.amount_in, symbols[0], event.amount_out, symbols[1]
+        ),
+    )
+    .await
+}
+pub async fn notify_redeem_processed(
+    toolbox: &Toolbox,
+    manager: &SubscribeManager<AdminSubscribeTopic>,
+    event: &RedeemProcessedEvent,
+) -> Result<()> {
+    notify_users_of_event(
+        toolbox,
+        manager,
+        format!("RedeemProcessed:{:?}", event.transaction_hash),
+        vec![event.user_id],
+        EnumUserNotificationKind::ExitStrategy,
+        Some(event.strategy_id),
+        format!(
+            "Redeemed {} from strategy {} in transaction {:?}",
+            event.amount, event.strategy_id, event.transaction_hash
+        ),
+    )
+    .await
+}
+pub async fn handle_domain_event(
+    toolbox: &Toolbox,
+    manager: &SubscribeManager<AdminSubscribeTopic>,
+    event: DomainEvent,
+) -> Result<()> {
+    match event {
+        DomainEvent::EscrowCredited(event) => {
+            publish_escrow_ledger_change(toolbox, manager, event.user_id, &event.entry);
+        }
+        DomainEvent::CopyTradeExecuted(event) => {
+            notify_strategy_trade(toolbox, manager, &event).await?;
+        }
+        DomainEvent::StrategyGuardTriggered(event) => {
+            publish_strategy_guard_triggered(toolbox, manager, &event).await?;
+        }
+        DomainEvent::RedeemProcessed(event) => {
+            notify_redeem_processed(toolbox, manager, &event).await?;
+        }
+        DomainEvent::SessionRevoked(event) => {
+            let closed = toolbox.disconnect_user_session(event.user_session_id);
+            debug!(
+                "Closed {} connections of revoked session {} of user {}",
+                closed, event.user_session_id, event.user_id
+            );
+        }
+        DomainEvent::ApiKeyRevoked(event) => {
+            let closed = toolbox.disconnect_api_key(event.api_key_id);
+            debug!(
+                "Closed {} connections of revoked api key {} of user {}",
+                closed, event.api_key_id, event.user_id
+            );
+        }
+    }
+    Ok(())
+}
+/// fans the events other services publish on the bus out to the subscribed connections
+pub async fn consume_domain_events(
+    toolbox: Toolbox,
+    manager: Arc<SubscribeManager<AdminSubscribeTopic>>,
+    bus: EventBus<DomainEvent>,
+) {
+    let mut events = bus.subscribe();
+    while let Some(event) = events.recv().await {
+        debug!("Received domain event {:?}", event);
+        if let Err(err) = handle_domain_event(&toolbox, &manager, event).await {
+            error!("Failed to handle domain event: {:?}", err);
+        }
+    }
+}
 pub struct MethodAdminSubscribeDepositLedger {
     pub manger: Arc<SubscribeManager<AdminSubscribeTopic>>,
 }
@@ -712,7 +1121,6 @@ impl RequestHandler for MethodAdminSubscribeDepositLedger {
         let toolbox = toolbox.clone();
         let db = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::Admin)?;
             manager.subscribe(AdminSubscribeTopic::AdminNotifyEscrowLedgerChangeAll, ctx);
             if let Some(limit) = req.initial_data {
                 let resp = db
@@ -790,13 +1198,12 @@ impl RequestHandler for MethodAdminAddEscrowTokenContractAddress {
     fn handle(
         &self,
         toolbox: &Toolbox,
-        ctx: RequestContext,
+        _ctx: RequestContext,
         req: Self::Request,
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
         let pool = self.pool.clone();
         async move {
-            ensure_user_role(ctx, EnumRole::Admin)?;
             let rpc = pool.get(req.blockchain).await?;
             let contract = Erc20Token::new(rpc.clone(), req.address.into())?;
             let decimals = contract.decimals().await?;
@@ -827,14 +1234,12 @@ impl RequestHandler for MethodAdminAddEscrowContractAddress {
     fn handle(
         &self,
         toolbox: &Toolbox,
-        ctx: RequestContext,
+        _ctx: RequestContext,
         req: Self::Request,
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
 
         async move {
-            ensure_user_role(ctx, EnumRole::Admin)?;
-
             let _ret = db
                 .execute(FunAdminAddEscrowContractAddressReq {
                     pkey_id: req.pkey_id,
@@ -856,12 +1261,11 @@ impl RequestHandler for MethodAdminListBackStrategyLedger {
     fn handle(
         &self,
         toolbox: &Toolbox,
-        ctx: RequestContext,
+        _ctx: RequestContext,
         req: Self::Request,
     ) -> FutureResponse<Self::Request> {
         let db = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::Admin)?;
             let ledger = db
                 .execute(FunUserListBackStrategyLedgerReq {
                     limit: req.limit.unwrap_or(DEFAULT_LIMIT),
@@ -894,12 +1298,11 @@ impl RequestHandler for MethodAdminListExitStrategyLedger {
     fn handle(
         &self,
         toolbox: &Toolbox,
-        ctx: RequestContext,
+        _ctx: RequestContext,
         req: Self::Request,
     ) -> FutureResponse<Self::Request> {
         let db = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::Admin)?;
             let ledger = db
                 .execute(FunUserListExitStrategyLedgerReq {
                     limit: req.limit.unwrap_or(DEFAULT_LIMIT),
@@ -930,11 +1333,10 @@ impl RequestHandler for MethodAdminSetBlockchainLogger {
     fn handle(
         &self,
         _toolbox: &Toolbox,
-        ctx: RequestContext,
+        _ctx: RequestContext,
         req: Self::Request,
     ) -> FutureResponse<Self::Request> {
         async move {
-            ensure_user_role(ctx, EnumRole::Admin)?;
             get_blockchain_logger().set_enabled(req.enabled);
             Ok(AdminSetBlockchainLoggerResponse {})
         }
@@ -950,13 +1352,12 @@ impl RequestHandler for MethodAdminListEscrowTokenContractAddresses {
     fn handle(
         &self,
         toolbox: &Toolbox,
-        ctx: RequestContext,
+        _ctx: RequestContext,
         req: Self::Request,
     ) -> FutureResponse<Self::Request> {
         let db = toolbox.get_db();
         let asset_client = self.asset_client.clone();
         async move {
-            ensure_user_role(ctx, EnumRole::Admin)?;
             let ret = db
                 .execute(FunAdminListEscrowTokenContractAddressReq {
                     limit: req.limit,
@@ -1004,12 +1405,11 @@ impl RequestHandler for MethodAdminUpdateEscrowTokenContractAddress {
     fn handle(
         &self,
         toolbox: &Toolbox,
-        ctx: RequestContext,
+        _ctx: RequestContext,
         req: Self::Request,
     ) -> FutureResponse<Self::Request> {
         let db = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::Admin)?;
             let _ret = db
                 .execute(FunAdminUpdateEscrowTokenContractAddressReq {
                     pkey_id: req.pkey_id,
@@ -1025,3 +1425,322 @@ impl RequestHandler for MethodAdminUpdateEscrowTokenContractAddress {
         .boxed()
     }
 }
+
+/// An admin endpoint that changes state. Every call is written to the admin
+/// action log along with the state it touched before and after running.
+pub trait AdminAction: RequestHandler {
+    /// Loads the state `req` is about to change, `Value::Null` if there is none to compare
+    fn load_state(&self, _db: DbClient, _req: Self::Request) -> BoxFuture<'static, Result<Value>> {
+        async { Ok(Value::Null) }.boxed()
+    }
+}
+
+async fn load_user_state(db: DbClient, user_id: i64) -> Result<Value> {
+    let ret = db.execute(FunAdminGetUserStateReq { user_id }).await?;
+    Ok(ret.into_result().map(|x| x.state).unwrap_or_default())
+}
+async fn load_expert_state(db: DbClient, user_public_id: i64) -> Result<Value> {
+    let ret = db
+        .execute(FunAdminGetExpertStateReq { user_public_id })
+        .await?;
+    Ok(ret.into_result().map(|x| x.state).unwrap_or_default())
+}
+async fn load_strategy_state(db: DbClient, strategy_id: i64) -> Result<Value> {
+    let ret = db
+        .execute(FunAdminGetStrategyStateReq { strategy_id })
+        .await?;
+    Ok(ret.into_result().map(|x| x.state).unwrap_or_default())
+}
+async fn load_system_config_state(db: DbClient) -> Result<Value> {
+    let ret = db
+        .execute(FunAdminGetSystemConfigStateReq { config_id: 0 })
+        .await?;
+    Ok(ret.into_result().map(|x| x.state).unwrap_or_default())
+}
+
+impl AdminAction for MethodAdminSetUserRole {
+    fn load_state(&self, db: DbClient, req: Self::Request) -> BoxFuture<'static, Result<Value>> {
+        load_user_state(db, req.user_id).boxed()
+    }
+}
+impl AdminAction for MethodAdminSetBlockUser {
+    fn load_state(&self, db: DbClient, req: Self::Request) -> BoxFuture<'static, Result<Value>> {
+        load_user_state(db, req.user_id).boxed()
+    }
+}
+impl AdminAction for MethodAdminApproveUserBecomeExpert {
+    fn load_state(&self, db: DbClient, req: Self::Request) -> BoxFuture<'static, Result<Value>> {
+        load_expert_state(db, req.user_id).boxed()
+    }
+}
+impl AdminAction for MethodAdminRejectUserBecomeExpert {
+    fn load_state(&self, db: DbClient, req: Self::Request) -> BoxFuture<'static, Result<Value>> {
+        load_expert_state(db, req.user_id).boxed()
+    }
+}
+impl AdminAction for MethodAdminRequestExpertApplicationInfo {
+    fn load_state(&self, db: DbClient, req: Self::Request) -> BoxFuture<'static, Result<Value>> {
+        load_expert_state(db, req.user_id).boxed()
+    }
+}
+impl AdminAction for MethodAdminUpdateSystemConfig {
+    fn load_state(&self, db: DbClient, _req: Self::Request) -> BoxFuture<'static, Result<Value>> {
+        load_system_config_state(db).boxed()
+    }
+}
+impl AdminAction for MethodAdminAddEscrowContractAddress {
+    fn load_state(&self, db: DbClient, _req: Self::Request) -> BoxFuture<'static, Result<Value>> {
+        load_system_config_state(db).boxed()
+    }
+}
+impl AdminAction for MethodAdminApproveStrategy {
+    fn load_state(&self, db: DbClient, req: Self::Request) -> BoxFuture<'static, Result<Value>> {
+        load_strategy_state(db, req.strategy_id).boxed()
+    }
+}
+impl AdminAction for MethodAdminRejectStrategy {
+    fn load_state(&self, db: DbClient, req: Self::Request) -> BoxFuture<'static, Result<Value>> {
+        load_strategy_state(db, req.strategy_id).boxed()
+    }
+}
+impl AdminAction for MethodAdminUpdateEscrowTokenContractAddress {
+    fn load_state(&self, db: DbClient, req: Self::Request) -> BoxFuture<'static, Result<Value>> {
+        async move {
+            let ret = db
+                .execute(FunAdminGetEscrowTokenContractAddressStateReq {
+                    pkey_id: req.pkey_id,
+                })
+                .await?;
+            Ok(ret.into_result().map(|x| x.state).unwrap_or_default())
+        }
+        .boxed()
+    }
+}
+impl AdminAction for MethodAdminAddAuditRule {}
+impl AdminAction for MethodAdminSetBlockchainLogger {}
+
+/// Queues calls to admin actions that require a second admin as pending actions, and
+/// writes every admin action that ran to the action log
+pub struct AdminActionPolicy {
+    requires_approval: HashSet<String>,
+}
+impl AdminActionPolicy {
+    /// `requires_approval` lists endpoint names, e.g. `AdminSetUserRole`,
+    /// that only run once a second admin approved them
+    pub fn new(requires_approval: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            requires_approval: requires_approval.into_iter().collect(),
+        }
+    }
+}
+impl<T: AdminAction + 'static> DeferPolicy<T> for AdminActionPolicy {
+    fn defer(
+        &self,
+        call: &DeferredCall<T>,
+        toolbox: &Toolbox,
+        ctx: RequestContext,
+        req: T::Request,
+    ) -> BoxFuture<'static, Result<Option<Report>>> {
+        if !self.requires_approval.contains(&call.method) {
+            return async { Ok(None) }.boxed();
+        }
+        let db: DbClient = toolbox.get_db();
+        let method = call.method.clone();
+        async move {
+            let pending_action_id = db
+                .execute(FunAdminAddPendingActionReq {
+                    admin_user_id: ctx.user_id,
+                    method_id: T::Request::METHOD_ID as _,
+                    method_name: method.clone(),
+                    params: serde_json::to_value(&req)?,
+                })
+                .await?
+                .into_result()
+                .context("failed to queue admin action")?
+                .pending_action_id;
+
+            Ok(Some(eyre!(CustomError::new(
+                EnumErrorCode::ActionPendingApproval,
+                format!(
+                    "{} requires approval by another admin, queued as pending action {}",
+                    method, pending_action_id
+                )
+            ))))
+        }
+        .boxed()
+    }
+    fn run(
+        &self,
+        call: &DeferredCall<T>,
+        toolbox: &Toolbox,
+        ctx: RequestContext,
+        req: T::Request,
+        pending_action_id: Option<i64>,
+    ) -> FutureResponse<T::Request> {
+        let db: DbClient = toolbox.get_db();
+        let toolbox = toolbox.clone();
+        let call = call.clone();
+        async move {
+            let params = serde_json::to_value(&req)?;
+            let state_before = call.handler.load_state(db.clone(), req.clone()).await?;
+            let resp = RequestHandler::handle(&*call.handler, &toolbox, ctx, req.clone()).await?;
+            let state_after = call.handler.load_state(db.clone(), req).await?;
+            db.execute(FunAdminAddActionLogReq {
+                admin_user_id: ctx.user_id,
+                pending_action_id,
+                method_id: T::Request::METHOD_ID as _,
+                method_name: call.method,
+                params,
+                state_before,
+                state_after,
+            })
+            .await?;
+            Ok(resp)
+        }
+        .boxed()
+    }
+}
+
+/// The admin actions a server exposes, an approved pending action is replayed from here
+pub type AdminActions = DeferredHandlers<AdminActionPolicy>;
+
+/// Fails if an endpoint that requires approval is not a wrapped admin action
+pub fn check_requires_approval(actions: &AdminActions) -> Result<()> {
+    for method in &actions.policy().requires_approval {
+        ensure!(
+            actions.contains_method(method),
+            "{} is not an admin action that can require approval",
+            method
+        );
+    }
+    Ok(())
+}
+
+pub struct MethodAdminListPendingActions;
+impl RequestHandler for MethodAdminListPendingActions {
+    type Request = AdminListPendingActionsRequest;
+
+    fn handle(
+        &self,
+        toolbox: &Toolbox,
+        _ctx: RequestContext,
+        req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let db: DbClient = toolbox.get_db();
+        async move {
+            let ret = db
+                .execute(FunAdminListPendingActionsReq {
+                    limit: req.limit.unwrap_or(DEFAULT_LIMIT),
+                    offset: req.offset.unwrap_or(DEFAULT_OFFSET),
+                    status: req.status,
+                })
+                .await?;
+
+            Ok(AdminListPendingActionsResponse {
+                pending_actions_total: ret.first(|x| x.total).unwrap_or_default(),
+                pending_actions: ret.map(|x| AdminPendingActionRow {
+                    pending_action_id: x.pending_action_id,
+                    requested_by_user_id: x.requested_by_user_id,
+                    method: x.method_name,
+                    params: x.params,
+                    status: x.status,
+                    approved_by_user_id: x.approved_by_user_id,
+                    approved_at: x.approved_at,
+                    created_at: x.created_at,
+                }),
+            })
+        }
+        .boxed()
+    }
+}
+
+pub struct MethodAdminApprovePendingAction {
+    pub actions: Arc<AdminActions>,
+}
+impl RequestHandler for MethodAdminApprovePendingAction {
+    type Request = AdminApprovePendingActionRequest;
+
+    fn handle(
+        &self,
+        toolbox: &Toolbox,
+        ctx: RequestContext,
+        req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let db: DbClient = toolbox.get_db();
+        let toolbox = toolbox.clone();
+        let actions = self.actions.clone();
+        async move {
+            let pending = db
+                .execute(FunAdminApprovePendingActionReq {
+                    pending_action_id: req.pending_action_id,
+                    admin_user_id: ctx.user_id,
+                })
+                .await?
+                .into_result()
+                .context("failed to approve pending action")?;
+
+            let result = actions
+                .replay(
+                    &toolbox,
+                    ctx,
+                    pending.method_id as _,
+                    pending.params,
+                    req.pending_action_id,
+                )
+                .await;
+            db.execute(FunAdminSetPendingActionStatusReq {
+                pending_action_id: req.pending_action_id,
+                status: if result.is_ok() {
+                    EnumAdminPendingActionStatus::Executed
+                } else {
+                    EnumAdminPendingActionStatus::Failed
+                },
+            })
+            .await?;
+            result?;
+
+            Ok(AdminApprovePendingActionResponse { success: true })
+        }
+        .boxed()
+    }
+}
+
+pub struct MethodAdminListActionLog;
+impl RequestHandler for MethodAdminListActionLog {
+    type Request = AdminListActionLogRequest;
+
+    fn handle(
+        &self,
+        toolbox: &Toolbox,
+        _ctx: RequestContext,
+        req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let db: DbClient = toolbox.get_db();
+        async move {
+            let ret = db
+                .execute(FunAdminListActionLogReq {
+                    limit: req.limit.unwrap_or(DEFAULT_LIMIT),
+                    offset: req.offset.unwrap_or(DEFAULT_OFFSET),
+                    admin_user_id: req.admin_user_id,
+                    method_name: req.method,
+                })
+                .await?;
+
+            Ok(AdminListActionLogResponse {
+                action_log_total: ret.first(|x| x.total).unwrap_or_default(),
+                action_log: ret.map(|x| AdminActionLogRow {
+                    action_log_id: x.action_log_id,
+                    admin_user_id: x.admin_user_id,
+                    pending_action_id: x.pending_action_id,
+                    requested_by_user_id: x.requested_by_user_id,
+                    method: x.method_name,
+                    params: x.params,
+                    state_before: x.state_before,
+                    state_after: x.state_after,
+                    created_at: x.created_at,
+                }),
+            })
+        }
+        .boxed()
+    }
+}
@@ -91,14 +91,34 @@ END;
         ),
         ProceduralFunction::new(
             "fun_admin_approve_user_become_expert",
-            vec![Field::new("user_public_id", Type::BigInt)],
+            vec![
+                Field::new("admin_user_id", Type::BigInt),
+                Field::new("user_public_id", Type::BigInt),
+                Field::new("note", Type::optional(Type::String)),
+            ],
             vec![Field::new("success", Type::Boolean)],
             r#"
-
 DECLARE
     _user_id bigint;
+    _application_id bigint;
 BEGIN
-    SELECT pkey_id INTO _user_id FROM tbl.user WHERE public_id = a_user_public_id;
+    SELECT a.fkey_user_id, a.pkey_id INTO _user_id, _application_id
+    FROM tbl.expert_application AS a
+      JOIN tbl.user AS u ON u.pkey_id = a.fkey_user_id
+    WHERE u.public_id = a_user_public_id
+      AND a.status = 'Submitted'
+    FOR UPDATE OF a;
+    IF _application_id IS NULL THEN
+        RAISE SQLSTATE 'R001A'; -- InvalidExpertApplicationState
+    END IF;
+    UPDATE tbl.expert_application
+    SET status = 'Approved',
+        fkey_reviewed_by_user_id = a_admin_user_id,
+        reviewed_at = EXTRACT(EPOCH FROM NOW()),
+        updated_at = EXTRACT(EPOCH FROM NOW())
+    WHERE pkey_id = _application_id;
+    INSERT INTO tbl.expert_application_note(fkey_application_id, fkey_author_user_id, status, note, created_at)
+    VALUES (_application_id, a_admin_user_id, 'Approved', a_note, EXTRACT(EPOCH FROM NOW()));
     UPDATE tbl.expert_profile 
     SET pending_expert = FALSE,
         approved_expert = TRUE,
@@ -112,17 +132,88 @@ END
         ),
         ProceduralFunction::new(
             "fun_admin_reject_user_become_expert",
-            vec![Field::new("user_public_id", Type::BigInt)],
+            vec![
+                Field::new("admin_user_id", Type::BigInt),
+                Field::new("user_public_id", Type::BigInt),
+                Field::new("reason", Type::String),
+            ],
             vec![Field::new("success", Type::Boolean)],
             r#"
-
 DECLARE
     _user_id bigint;
+    _application_id bigint;
 BEGIN
-    SELECT pkey_id INTO _user_id FROM tbl.user WHERE public_id = a_user_public_id;
+    SELECT a.fkey_user_id, a.pkey_id INTO _user_id, _application_id
+    FROM tbl.expert_application AS a
+      JOIN tbl.user AS u ON u.pkey_id = a.fkey_user_id
+    WHERE u.public_id = a_user_public_id
+      AND a.status = 'Submitted'
+    FOR UPDATE OF a;
+    IF _application_id IS NULL THEN
+        RAISE SQLSTATE 'R001A'; -- InvalidExpertApplicationState
+    END IF;
+    UPDATE tbl.expert_application
+    SET status = 'Rejected',
+        fkey_reviewed_by_user_id = a_admin_user_id,
+        reviewed_at = EXTRACT(EPOCH FROM NOW()),
+        updated_at = EXTRACT(EPOCH FROM NOW())
+    WHERE pkey_id = _application_id;
+    INSERT INTO tbl.expert_application_note(fkey_application_id, fkey_author_user_id, status, note, created_at)
+    VALUES (_application_id, a_admin_user_id, 'Rejected', a_reason, EXTRACT(EPOCH FROM NOW()));
     UPDATE tbl.expert_profile SET pending_expert = FALSE, approved_expert = FALSE WHERE fkey_user_id = _user_id;
     RETURN QUERY SELECT TRUE;
 END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_admin_request_expert_application_info",
+            vec![
+                Field::new("admin_user_id", Type::BigInt),
+                Field::new("user_public_id", Type::BigInt),
+                Field::new("note", Type::String),
+            ],
+            vec![Field::new("success", Type::Boolean)],
+            r#"
+DECLARE
+    _user_id bigint;
+    _application_id bigint;
+BEGIN
+    SELECT a.fkey_user_id, a.pkey_id INTO _user_id, _application_id
+    FROM tbl.expert_application AS a
+      JOIN tbl.user AS u ON u.pkey_id = a.fkey_user_id
+    WHERE u.public_id = a_user_public_id
+      AND a.status = 'Submitted'
+    FOR UPDATE OF a;
+    IF _application_id IS NULL THEN
+        RAISE SQLSTATE 'R001A'; -- InvalidExpertApplicationState
+    END IF;
+    UPDATE tbl.expert_application
+    SET status = 'InfoRequested',
+        fkey_reviewed_by_user_id = a_admin_user_id,
+        reviewed_at = EXTRACT(EPOCH FROM NOW()),
+        updated_at = EXTRACT(EPOCH FROM NOW())
+    WHERE pkey_id = _application_id;
+    INSERT INTO tbl.expert_application_note(fkey_application_id, fkey_author_user_id, status, note, created_at)
+    VALUES (_application_id, a_admin_user_id, 'InfoRequested', a_note, EXTRACT(EPOCH FROM NOW()));
+    UPDATE tbl.expert_profile SET pending_expert = FALSE WHERE fkey_user_id = _user_id;
+    RETURN QUERY SELECT TRUE;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_admin_get_expert_application_document",
+            vec![Field::new("document_id", Type::BigInt)],
+            vec![
+                Field::new("name", Type::String),
+                Field::new("content_type", Type::String),
+                Field::new("blob_key", Type::String),
+            ],
+            r#"
+BEGIN
+    RETURN QUERY SELECT d.name, d.content_type, d.blob_key
+                 FROM tbl.expert_application_document AS d
+                 WHERE d.pkey_id = a_document_id;
+END
 "#,
         ),
         ProceduralFunction::new(
@@ -214,6 +305,88 @@ BEGIN
         WHERE
             pkey_id = a_config_id;
     END IF;
+    PERFORM pg_notify('mc2fi_system_config', 'system_config');
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_admin_list_system_settings",
+            vec![],
+            vec![
+                Field::new("key", Type::String),
+                Field::new("value", Type::Object),
+                Field::new("version", Type::BigInt),
+                Field::new("updated_by_user_id", Type::optional(Type::BigInt)),
+                Field::new("updated_at", Type::BigInt),
+            ],
+            r#"
+BEGIN
+    RETURN QUERY SELECT s.key, s.value, s.version, s.fkey_updated_by_user_id, s.updated_at
+    FROM tbl.system_setting s
+    ORDER BY s.key;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_admin_set_system_setting",
+            vec![
+                Field::new("admin_user_id", Type::BigInt),
+                Field::new("key", Type::String),
+                Field::new("value", Type::Object),
+                Field::new("expected_version", Type::optional(Type::BigInt)),
+            ],
+            vec![Field::new("version", Type::BigInt)],
+            r#"
+DECLARE
+    _version bigint;
+BEGIN
+    SELECT s.version INTO _version FROM tbl.system_setting s WHERE s.key = a_key FOR UPDATE;
+    _version := coalesce(_version, 0) + 1;
+    IF a_expected_version NOTNULL AND a_expected_version <> _version - 1 THEN
+        RAISE SQLSTATE 'R0017'; -- SettingVersionConflict
+    END IF;
+    INSERT INTO tbl.system_setting (key, value, version, fkey_updated_by_user_id, updated_at)
+    VALUES (a_key, a_value, _version, a_admin_user_id, EXTRACT(EPOCH FROM NOW())::bigint)
+    ON CONFLICT (key) DO UPDATE SET
+        value = excluded.value,
+        version = excluded.version,
+        fkey_updated_by_user_id = excluded.fkey_updated_by_user_id,
+        updated_at = excluded.updated_at;
+    INSERT INTO tbl.system_setting_history (key, value, version, fkey_updated_by_user_id, created_at)
+    VALUES (a_key, a_value, _version, a_admin_user_id, EXTRACT(EPOCH FROM NOW())::bigint);
+    PERFORM pg_notify('mc2fi_system_config', a_key);
+    RETURN QUERY SELECT _version;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_admin_list_system_setting_history",
+            vec![
+                Field::new("limit", Type::BigInt),
+                Field::new("offset", Type::BigInt),
+                Field::new("key", Type::optional(Type::String)),
+            ],
+            vec![
+                Field::new("total", Type::BigInt),
+                Field::new("key", Type::String),
+                Field::new("value", Type::Object),
+                Field::new("version", Type::BigInt),
+                Field::new("updated_by_user_id", Type::optional(Type::BigInt)),
+                Field::new("created_at", Type::BigInt),
+            ],
+            r#"
+BEGIN
+    RETURN QUERY SELECT count(*) OVER() AS total,
+                        h.key,
+                        h.value,
+                        h.version,
+                        h.fkey_updated_by_user_id,
+                        h.created_at
+                 FROM tbl.system_setting_history h
+                 WHERE a_key ISNULL OR h.key = a_key
+                 ORDER BY h.pkey_id DESC
+                 OFFSET a_offset
+                 LIMIT a_limit;
 END
 "#,
         ),
@@ -565,6 +738,261 @@ BEGIN
     UPDATE tbl.escrow_contract_address
          SET address = a_address
          WHERE blockchain = a_blockchain;
+END
+            "#,
+        ),
+        ProceduralFunction::new(
+            "fun_admin_get_user_state",
+            vec![Field::new("user_id", Type::BigInt)],
+            vec![Field::new("state", Type::Object)],
+            r#"
+BEGIN
+    RETURN QUERY SELECT jsonb_build_object(
+        'role', u.role,
+        'is_blocked', u.is_blocked
+    )
+    FROM tbl.user u
+    WHERE u.pkey_id = a_user_id;
+END
+            "#,
+        ),
+        ProceduralFunction::new(
+            "fun_admin_get_expert_state",
+            vec![Field::new("user_public_id", Type::BigInt)],
+            vec![Field::new("state", Type::Object)],
+            r#"
+BEGIN
+    RETURN QUERY SELECT jsonb_build_object(
+        'role', u.role,
+        'pending_expert', e.pending_expert,
+        'approved_expert', e.approved_expert,
+        'approved_at', e.approved_at,
+        'application_status', a.status
+    )
+    FROM tbl.user u
+    LEFT JOIN tbl.expert_profile e ON e.fkey_user_id = u.pkey_id
+    LEFT JOIN tbl.expert_application a ON a.fkey_user_id = u.pkey_id
+    WHERE u.public_id = a_user_public_id;
+END
+            "#,
+        ),
+        ProceduralFunction::new(
+            "fun_admin_get_strategy_state",
+            vec![Field::new("strategy_id", Type::BigInt)],
+            vec![Field::new("state", Type::Object)],
+            r#"
+BEGIN
+    RETURN QUERY SELECT jsonb_build_object(
+        'approved', s.approved,
+        'pending_approval', s.pending_approval,
+        'approved_at', s.approved_at
+    )
+    FROM tbl.strategy s
+    WHERE s.pkey_id = a_strategy_id;
+END
+            "#,
+        ),
+        ProceduralFunction::new(
+            "fun_admin_get_system_config_state",
+            vec![Field::new("config_id", Type::BigInt)],
+            vec![Field::new("state", Type::Object)],
+            r#"
+BEGIN
+    RETURN QUERY SELECT jsonb_build_object(
+        'platform_fee', c.platform_fee,
+        'allow_domain_urls', c.allow_domain_urls,
+        'escrow_contract_addresses', (SELECT jsonb_object_agg(e.blockchain, e.address)
+                                      FROM tbl.escrow_contract_address e),
+        'settings', (SELECT jsonb_object_agg(s.key, s.value) FROM tbl.system_setting s)
+    )
+    FROM (SELECT a_config_id AS pkey_id) d
+    LEFT JOIN tbl.system_config c ON c.pkey_id = d.pkey_id;
+END
+            "#,
+        ),
+        ProceduralFunction::new(
+            "fun_admin_get_escrow_token_contract_address_state",
+            vec![Field::new("pkey_id", Type::BigInt)],
+            vec![Field::new("state", Type::Object)],
+            r#"
+BEGIN
+    RETURN QUERY SELECT jsonb_build_object(
+        'symbol', t.symbol,
+        'short_name', t.short_name,
+        'description', t.description,
+        'is_stablecoin', t.is_stablecoin,
+        'is_wrapped', t.is_wrapped
+    )
+    FROM tbl.escrow_token_contract_address t
+    WHERE t.pkey_id = a_pkey_id;
+END
+            "#,
+        ),
+        ProceduralFunction::new(
+            "fun_admin_add_action_log",
+            vec![
+                Field::new("admin_user_id", Type::BigInt),
+                Field::new("pending_action_id", Type::optional(Type::BigInt)),
+                Field::new("method_id", Type::Int),
+                Field::new("method_name", Type::String),
+                Field::new("params", Type::Object),
+                Field::new("state_before", Type::Object),
+                Field::new("state_after", Type::Object),
+            ],
+            vec![],
+            r#"
+BEGIN
+    INSERT INTO tbl.admin_action_log (fkey_admin_user_id, fkey_pending_action_id, method_id, method_name,
+                                      params, state_before, state_after, created_at)
+    VALUES (a_admin_user_id, a_pending_action_id, a_method_id, a_method_name,
+            a_params, a_state_before, a_state_after, EXTRACT(EPOCH FROM NOW())::bigint);
+END
+            "#,
+        ),
+        ProceduralFunction::new(
+            "fun_admin_list_action_log",
+            vec![
+                Field::new("limit", Type::BigInt),
+                Field::new("offset", Type::BigInt),
+                Field::new("admin_user_id", Type::optional(Type::BigInt)),
+                Field::new("method_name", Type::optional(Type::String)),
+            ],
+            vec![
+                Field::new("total", Type::BigInt),
+                Field::new("action_log_id", Type::BigInt),
+                Field::new("admin_user_id", Type::BigInt),
+                Field::new("pending_action_id", Type::optional(Type::BigInt)),
+                Field::new("requested_by_user_id", Type::optional(Type::BigInt)),
+                Field::new("method_name", Type::String),
+                Field::new("params", Type::Object),
+                Field::new("state_before", Type::optional(Type::Object)),
+                Field::new("state_after", Type::optional(Type::Object)),
+                Field::new("created_at", Type::BigInt),
+            ],
+            r#"
+BEGIN
+    RETURN QUERY SELECT count(*) OVER() AS total,
+                        l.pkey_id,
+                        l.fkey_admin_user_id,
+                        l.fkey_pending_action_id,
+                        p.fkey_requested_by_user_id,
+                        l.method_name,
+                        l.params,
+                        l.state_before,
+                        l.state_after,
+                        l.created_at
+                 FROM tbl.admin_action_log l
+                 LEFT JOIN tbl.admin_pending_action p ON p.pkey_id = l.fkey_pending_action_id
+                 WHERE (a_admin_user_id ISNULL OR l.fkey_admin_user_id = a_admin_user_id)
+                   AND (a_method_name ISNULL OR l.method_name = a_method_name)
+                 ORDER BY l.pkey_id DESC
+                 OFFSET a_offset
+                 LIMIT a_limit;
+END
+            "#,
+        ),
+        ProceduralFunction::new(
+            "fun_admin_add_pending_action",
+            vec![
+                Field::new("admin_user_id", Type::BigInt),
+                Field::new("method_id", Type::Int),
+                Field::new("method_name", Type::String),
+                Field::new("params", Type::Object),
+            ],
+            vec![Field::new("pending_action_id", Type::BigInt)],
+            r#"
+BEGIN
+    RETURN QUERY INSERT INTO tbl.admin_pending_action (fkey_requested_by_user_id, method_id, method_name,
+                                                       params, status, created_at)
+                 VALUES (a_admin_user_id, a_method_id, a_method_name,
+                         a_params, 'Pending', EXTRACT(EPOCH FROM NOW())::bigint)
+                 RETURNING pkey_id;
+END
+            "#,
+        ),
+        ProceduralFunction::new(
+            "fun_admin_list_pending_actions",
+            vec![
+                Field::new("limit", Type::BigInt),
+                Field::new("offset", Type::BigInt),
+                Field::new(
+                    "status",
+                    Type::optional(Type::enum_ref("admin_pending_action_status")),
+                ),
+            ],
+            vec![
+                Field::new("total", Type::BigInt),
+                Field::new("pending_action_id", Type::BigInt),
+                Field::new("requested_by_user_id", Type::BigInt),
+                Field::new("method_name", Type::String),
+                Field::new("params", Type::Object),
+                Field::new("status", Type::enum_ref("admin_pending_action_status")),
+                Field::new("approved_by_user_id", Type::optional(Type::BigInt)),
+                Field::new("approved_at", Type::optional(Type::BigInt)),
+                Field::new("created_at", Type::BigInt),
+            ],
+            r#"
+BEGIN
+    RETURN QUERY SELECT count(*) OVER() AS total,
+                        a.pkey_id,
+                        a.fkey_requested_by_user_id,
+                        a.method_name,
+                        a.params,
+                        a.status,
+                        a.fkey_approved_by_user_id,
+                        a.approved_at,
+                        a.created_at
+                 FROM tbl.admin_pending_action a
+                 WHERE (a_status ISNULL OR a.status = a_status)
+                 ORDER BY a.pkey_id DESC
+                 OFFSET a_offset
+                 LIMIT a_limit;
+END
+            "#,
+        ),
+        ProceduralFunction::new(
+            "fun_admin_approve_pending_action",
+            vec![
+                Field::new("pending_action_id", Type::BigInt),
+                Field::new("admin_user_id", Type::BigInt),
+            ],
+            vec![
+                Field::new("method_id", Type::Int),
+                Field::new("params", Type::Object),
+            ],
+            r#"
+DECLARE
+    _requested_by_user_id bigint;
+BEGIN
+    SELECT fkey_requested_by_user_id INTO _requested_by_user_id
+    FROM tbl.admin_pending_action
+    WHERE pkey_id = a_pending_action_id AND status = 'Pending'
+    FOR UPDATE;
+    IF NOT FOUND THEN
+        RAISE SQLSTATE 'R0015'; -- UnknownPendingAction
+    END IF;
+    IF _requested_by_user_id = a_admin_user_id THEN
+        RAISE SQLSTATE 'R0016'; -- SelfApproval
+    END IF;
+    RETURN QUERY UPDATE tbl.admin_pending_action AS a
+                 SET status = 'Approved',
+                     fkey_approved_by_user_id = a_admin_user_id,
+                     approved_at = EXTRACT(EPOCH FROM NOW())::bigint
+                 WHERE a.pkey_id = a_pending_action_id
+                 RETURNING a.method_id, a.params;
+END
+            "#,
+        ),
+        ProceduralFunction::new(
+            "fun_admin_set_pending_action_status",
+            vec![
+                Field::new("pending_action_id", Type::BigInt),
+                Field::new("status", Type::enum_ref("admin_pending_action_status")),
+            ],
+            vec![],
+            r#"
+BEGIN
+    UPDATE tbl.admin_pending_action SET status = a_status WHERE pkey_id = a_pending_action_id;
 END
             "#,
         ),
@@ -1,5 +1,3 @@
-use std::time::Duration;
-
 use eyre::*;
 use secrecy::{ExposeSecret, SecretString};
 use serde::Deserialize;
@@ -7,9 +5,13 @@ use tokio::time::sleep;
 use tracing::error;
 
 use api::cmc::CoinMarketCap;
+use gen::database::FunAdminListSystemSettingsReq;
 use lib::config::load_config;
 use lib::database::{connect_to_database, DatabaseConfig};
 use lib::log::{setup_logs, LogLevel};
+use lib::system_config::{
+    set_system_settings, system_settings, watch_system_config, SystemSettings,
+};
 use mc2fi_asset_price::update_price::{
     delete_old_price_entries, fill_asset_price_cache, fill_past_prices_on_startup,
 };
@@ -27,6 +29,14 @@ async fn main() -> Result<()> {
     setup_logs(config.log_level)?;
     let db = connect_to_database(config.app_db).await?;
     let cmc_client = CoinMarketCap::new(config.cmc_api_key.expose_secret())?;
+    watch_system_config(&db, |db| async move {
+        let settings = db.execute(FunAdminListSystemSettingsReq {}).await?;
+        set_system_settings(SystemSettings::from_entries(
+            settings.into_iter().map(|x| (x.key, x.value)),
+        ));
+        Ok(())
+    })
+    .await?;
 
     fill_past_prices_on_startup(&db, &cmc_client).await?;
     loop {
@@ -42,6 +52,6 @@ async fn main() -> Result<()> {
                 error!("error deleting old price entries: {}", e);
             }
         }
-        sleep(Duration::from_secs(60)).await;
+        sleep(system_settings().asset_price_interval()).await;
     }
 }
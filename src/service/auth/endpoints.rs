@@ -1,5 +1,9 @@
 use model::endpoint::*;
 use model::types::{Field, Type};
+use roles::role_variants;
+
+#[path = "../shared/roles.rs"]
+mod roles;
 
 pub fn endpoint_auth_signup() -> EndpointSchema {
     EndpointSchema::new(
@@ -20,6 +24,7 @@ pub fn endpoint_auth_signup() -> EndpointSchema {
             Field::new("user_id", Type::BigInt),
         ],
     )
+    .with_roles(role_variants())
 }
 pub fn endpoint_auth_login() -> EndpointSchema {
     EndpointSchema::new(
@@ -39,10 +44,14 @@ pub fn endpoint_auth_login() -> EndpointSchema {
             Field::new("avatar", Type::optional(Type::String)),
             Field::new("role", Type::enum_ref("role")),
             Field::new("user_id", Type::BigInt),
-            Field::new("user_token", Type::UUID),
-            Field::new("admin_token", Type::UUID),
+            Field::new("user_token", Type::optional(Type::UUID)),
+            Field::new("admin_token", Type::optional(Type::UUID)),
+            Field::new("expires_at", Type::BigInt),
+            Field::new("refresh_token", Type::UUID),
+            Field::new("refresh_expires_at", Type::BigInt),
         ],
     )
+    .with_roles(role_variants())
 }
 pub fn endpoint_auth_authorize() -> EndpointSchema {
     EndpointSchema::new(
@@ -57,9 +66,21 @@ pub fn endpoint_auth_authorize() -> EndpointSchema {
         ],
         vec![Field::new("success", Type::Boolean)],
     )
+    .with_roles(role_variants())
 }
 pub fn endpoint_auth_logout() -> EndpointSchema {
-    EndpointSchema::new("Logout", 10040, vec![], vec![])
+    EndpointSchema::new(
+        "Logout",
+        10040,
+        vec![
+            Field::new("address", Type::BlockchainAddress),
+            Field::new("token", Type::UUID),
+            Field::new("service", Type::enum_ref("service")),
+            Field::new("device_id", Type::String),
+        ],
+        vec![],
+    )
+    .with_roles(role_variants())
 }
 pub fn endpoint_auth_change_login_wallet() -> EndpointSchema {
     EndpointSchema::new(
@@ -75,6 +96,56 @@ pub fn endpoint_auth_change_login_wallet() -> EndpointSchema {
         ],
         vec![],
     )
+    .with_roles(role_variants())
+}
+
+pub fn endpoint_auth_get_login_nonce() -> EndpointSchema {
+    EndpointSchema::new(
+        "GetLoginNonce",
+        10060,
+        vec![Field::new("address", Type::BlockchainAddress)],
+        vec![
+            Field::new("nonce", Type::String),
+            Field::new("expires_at", Type::BigInt),
+        ],
+    )
+    .with_roles(role_variants())
+}
+
+pub fn endpoint_auth_refresh_token() -> EndpointSchema {
+    EndpointSchema::new(
+        "RefreshToken",
+        10070,
+        vec![
+            Field::new("address", Type::BlockchainAddress),
+            Field::new("refresh_token", Type::UUID),
+            Field::new("service", Type::enum_ref("service")),
+            Field::new("device_id", Type::String),
+        ],
+        vec![
+            Field::new("token", Type::UUID),
+            Field::new("expires_at", Type::BigInt),
+            Field::new("refresh_token", Type::UUID),
+            Field::new("refresh_expires_at", Type::BigInt),
+        ],
+    )
+    .with_roles(role_variants())
+}
+
+pub fn endpoint_auth_authorize_api_key() -> EndpointSchema {
+    EndpointSchema::new(
+        "AuthorizeApiKey",
+        10080,
+        vec![
+            Field::new("api_key", Type::String),
+            Field::new("timestamp", Type::BigInt),
+            Field::new("signature", Type::String),
+            Field::new("service", Type::enum_ref("service")),
+            Field::new("nonce", Type::String),
+        ],
+        vec![Field::new("success", Type::Boolean)],
+    )
+    .with_roles(role_variants())
 }
 
 pub fn get_auth_endpoints() -> Vec<EndpointSchema> {
@@ -84,5 +155,8 @@ pub fn get_auth_endpoints() -> Vec<EndpointSchema> {
         endpoint_auth_authorize(),
         endpoint_auth_logout(),
         endpoint_auth_change_login_wallet(),
+        endpoint_auth_get_login_nonce(),
+        endpoint_auth_refresh_token(),
+        endpoint_auth_authorize_api_key(),
     ]
 }
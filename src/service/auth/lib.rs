@@ -1,22 +1,30 @@
-use crate::endpoints::{endpoint_auth_authorize, endpoint_auth_login, endpoint_auth_signup};
+use crate::endpoints::{
+    endpoint_auth_authorize, endpoint_auth_authorize_api_key, endpoint_auth_get_login_nonce,
+    endpoint_auth_login, endpoint_auth_signup,
+};
+use crate::method::sign_api_key_request;
 use eth_sdk::utils::get_signed_text;
 use eyre::*;
 use gen::model::{
-    AuthorizeRequest, AuthorizeResponse, EnumService, LoginRequest, LoginResponse, SignupRequest,
-    SignupResponse,
+    AuthorizeApiKeyRequest, AuthorizeApiKeyResponse, AuthorizeRequest, AuthorizeResponse,
+    EnumService, GetLoginNonceRequest, GetLoginNonceResponse, LoginRequest, LoginResponse,
+    SignupRequest, SignupResponse, UserLinkLoginWalletRequest, UserLinkLoginWalletResponse,
 };
-use lib::utils::encode_header;
+use lib::utils::{encode_header, get_time_milliseconds};
 use lib::ws::WsClient;
 use siwe::Version;
 use time::OffsetDateTime;
 use tracing::info;
+use uuid::Uuid;
 use web3::signing::Key;
 use web3::types::Address;
 
 pub mod endpoints;
+#[path = "../shared/events.rs"]
+pub mod events;
 pub mod method;
 
-fn get_signature_message(address: Address) -> String {
+fn get_signature_message(address: Address, nonce: String) -> String {
     let sign_text = siwe::Message {
         domain: "mc2.pathscale.com".parse().unwrap(),
         address: address.into(),
@@ -24,7 +32,7 @@ fn get_signature_message(address: Address) -> String {
         uri: "https://mc2.pathscale.com".parse().unwrap(),
         version: Version::V1,
         chain_id: 1,
-        nonce: rand::random::<u64>().to_string(),
+        nonce,
         issued_at: OffsetDateTime::now_utc().into(),
         expiration_time: None,
         not_before: None,
@@ -33,13 +41,26 @@ fn get_signature_message(address: Address) -> String {
     };
     sign_text.to_string()
 }
+pub async fn get_login_nonce(url: &str, address: Address) -> Result<GetLoginNonceResponse> {
+    let mut client = get_ws_auth_client(
+        url,
+        &encode_header(
+            GetLoginNonceRequest { address },
+            endpoint_auth_get_login_nonce(),
+        )?,
+    )
+    .await?;
+    let res: GetLoginNonceResponse = client.recv_resp().await?;
+    Ok(res)
+}
 pub async fn signup(
     url: &str,
     username: impl Into<String>,
     signer: impl Key + Clone,
 ) -> Result<()> {
     let username = username.into();
-    let sign_text = get_signature_message(signer.address());
+    let nonce = get_login_nonce(url, signer.address()).await?.nonce;
+    let sign_text = get_signature_message(signer.address(), nonce);
     let (txt, sig) = get_signed_text(sign_text.to_string(), signer.clone())?;
 
     let mut client = get_ws_auth_client(
@@ -69,7 +90,8 @@ pub async fn login(
     signer: impl Key + Clone,
 ) -> Result<LoginResponse> {
     let _username = username.into();
-    let sign_text = get_signature_message(signer.address());
+    let nonce = get_login_nonce(url, signer.address()).await?.nonce;
+    let sign_text = get_signature_message(signer.address(), nonce);
     let (txt, sig) = get_signed_text(sign_text.to_string(), signer.clone())?;
     let mut client = get_ws_auth_client(
         url,
@@ -91,6 +113,24 @@ pub async fn login(
     Ok(res)
 }
 
+/// links the wallet of `signer` to the account `client` is authorized with
+pub async fn link_login_wallet(
+    auth_url: &str,
+    client: &mut WsClient,
+    signer: impl Key + Clone,
+) -> Result<UserLinkLoginWalletResponse> {
+    let nonce = get_login_nonce(auth_url, signer.address()).await?.nonce;
+    let sign_text = get_signature_message(signer.address(), nonce);
+    let (txt, sig) = get_signed_text(sign_text, signer.clone())?;
+    client
+        .request(UserLinkLoginWalletRequest {
+            address: signer.address(),
+            signature_text: txt,
+            signature: sig,
+        })
+        .await
+}
+
 pub async fn get_ws_auth_client(url: &str, header: &str) -> Result<WsClient> {
     info!("Connecting to {} with header {}", url, header);
     let ws_stream = WsClient::new(url, header).await?;
@@ -113,6 +153,27 @@ pub async fn get_ws_user_client(url: &str, req: &AuthorizeRequest) -> Result<WsC
     Ok(ws_stream)
 }
 
+/// connects to the user service with an API key created by UserCreateApiKey
+pub async fn get_ws_api_key_client(url: &str, api_key: &str, api_secret: &str) -> Result<WsClient> {
+    let timestamp = get_time_milliseconds();
+    let nonce = Uuid::new_v4().simple().to_string();
+    let header = encode_header(
+        AuthorizeApiKeyRequest {
+            api_key: api_key.to_string(),
+            timestamp,
+            signature: sign_api_key_request(api_key, api_secret, timestamp, &nonce),
+            service: EnumService::User,
+            nonce,
+        },
+        endpoint_auth_authorize_api_key(),
+    )?;
+    info!("Connecting to {} with api key {}", url, api_key);
+    let mut ws_stream = WsClient::new(url, &header).await?;
+    let x: AuthorizeApiKeyResponse = ws_stream.recv_resp().await?;
+    info!("AuthorizeApiKeyResponse {:?}", x);
+    Ok(ws_stream)
+}
+
 pub async fn connect_user(
     auth_url: &str,
     user_url: &str,
@@ -124,7 +185,7 @@ pub async fn connect_user(
         user_url,
         &AuthorizeRequest {
             address: login.address,
-            token: login.user_token,
+            token: login.user_token.context("login returned no user token")?,
             service: EnumService::User as _,
             device_id: "24787297130491616".to_string(),
             device_os: "android".to_string(),
@@ -145,7 +206,7 @@ pub async fn connect_user_ext(
         user_url,
         &AuthorizeRequest {
             address: login.address.clone(),
-            token: login.user_token.clone(),
+            token: login.user_token.context("login returned no user token")?,
             service: EnumService::User as _,
             device_id: "24787297130491616".to_string(),
             device_os: "android".to_string(),
@@ -1,12 +1,22 @@
-use crate::shared_method::load_allow_domain_urls;
+use crate::shared_method::load_system_config;
 use eth_sdk::{EthereumConns, EthereumRpcConnectionPool};
 use eyre::*;
 use lib::config::load_config;
 use lib::database::{connect_to_database, DatabaseConfig};
+use lib::event_bus::EventBus;
+use lib::http::ApiDocuments;
 use lib::log::{setup_logs, LogLevel};
+use lib::system_config::watch_system_config;
 use lib::ws::{EndpointAuthController, WebsocketServer, WsServerConfig};
-use mc2fi_auth::endpoints::{endpoint_auth_login, endpoint_auth_logout, endpoint_auth_signup};
-use mc2fi_auth::method::{MethodAuthLogin, MethodAuthLogout, MethodAuthSignup};
+use mc2fi_auth::endpoints::{
+    endpoint_auth_get_login_nonce, endpoint_auth_login, endpoint_auth_logout,
+    endpoint_auth_refresh_token, endpoint_auth_signup,
+};
+use mc2fi_auth::events::DomainEvent;
+use mc2fi_auth::method::{
+    MethodAuthGetLoginNonce, MethodAuthLogin, MethodAuthLogout, MethodAuthRefreshToken,
+    MethodAuthSignup, TokenConfig,
+};
 use serde::*;
 
 #[path = "../shared/shared_method.rs"]
@@ -21,6 +31,8 @@ pub struct Config {
     #[serde(flatten)]
     pub app: WsServerConfig,
     pub ethereum_urls: EthereumConns,
+    #[serde(default)]
+    pub tokens: TokenConfig,
 }
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -31,17 +43,31 @@ async fn main() -> Result<()> {
 
     let mut server = WebsocketServer::new(config.app.clone());
     let db = connect_to_database(config.app_db).await?;
-    load_allow_domain_urls(&db, &mut server.config).await?;
+    let allow_cors_urls = config.app.allow_cors_urls.clone();
+    watch_system_config(&db, move |db| {
+        load_system_config(db, allow_cors_urls.clone())
+    })
+    .await?;
+    let event_bus = EventBus::<DomainEvent>::new(db.clone());
     server.add_database(db);
     server.add_database(connect_to_database(config.auth_db).await?);
     let mut auth_controller = EndpointAuthController::new();
+    auth_controller.add_auth_endpoint(endpoint_auth_get_login_nonce(), MethodAuthGetLoginNonce);
     auth_controller.add_auth_endpoint(
         endpoint_auth_login(),
         MethodAuthLogin {
             allow_cors_sites: config.app.allow_cors_urls.clone(),
+            tokens: config.tokens,
+        },
+    );
+    auth_controller.add_auth_endpoint(
+        endpoint_auth_refresh_token(),
+        MethodAuthRefreshToken {
+            tokens: config.tokens,
+            event_bus: event_bus.clone(),
         },
     );
-    auth_controller.add_auth_endpoint(endpoint_auth_logout(), MethodAuthLogout);
+    auth_controller.add_auth_endpoint(endpoint_auth_logout(), MethodAuthLogout { event_bus });
     auth_controller.add_auth_endpoint(
         endpoint_auth_signup(),
         MethodAuthSignup {
@@ -50,6 +76,10 @@ async fn main() -> Result<()> {
         },
     );
     server.add_auth_controller(auth_controller);
+    server.set_api_documents(ApiDocuments::from_json(
+        gen::OPENAPI_JSON,
+        gen::ASYNCAPI_JSON,
+    )?);
     server.listen().await?;
     Ok(())
 }
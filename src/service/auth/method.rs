@@ -1,30 +1,64 @@
+use crate::events::{DomainEvent, SessionRevokedEvent};
 use eth_sdk::EthereumRpcConnectionPool;
 use eyre::*;
 use futures::future::BoxFuture;
 use futures::FutureExt;
 use gen::database::*;
 use gen::model::*;
+use hmac::{Hmac, Mac};
 use lib::database::DbClient;
+use lib::event_bus::EventBus;
+use lib::system_config::Reloadable;
 use lib::toolbox::*;
 use lib::utils::*;
 use lib::ws::*;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use sha2::Sha256;
 use siwe::{Message, VerificationOpts};
+use std::net::IpAddr;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use time::OffsetDateTime;
-use tracing::{info, warn};
+use tracing::{error, info, warn};
 use uuid::Uuid;
 use web3::api::Namespace;
 use web3::contract::ens::Ens;
 use web3::types::Address;
 
+/// how long a nonce issued by GetLoginNonce can be signed into a login message
+pub const LOGIN_NONCE_TTL_SECS: i64 = 300;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct TokenConfig {
+    /// how long the token passed to Authorize is valid
+    #[serde(default = "default_access_ttl_secs")]
+    pub access_ttl_secs: i64,
+    /// how long a refresh token can be exchanged by RefreshToken
+    #[serde(default = "default_refresh_ttl_secs")]
+    pub refresh_ttl_secs: i64,
+}
+fn default_access_ttl_secs() -> i64 {
+    15 * 60
+}
+fn default_refresh_ttl_secs() -> i64 {
+    30 * 24 * 3600
+}
+impl Default for TokenConfig {
+    fn default() -> Self {
+        Self {
+            access_ttl_secs: default_access_ttl_secs(),
+            refresh_ttl_secs: default_refresh_ttl_secs(),
+        }
+    }
+}
+
 pub async fn ensure_signature_valid(
     signature_text: &str,
     signature: &[u8],
     address: Address,
     allow_cors_sites: &Option<Vec<String>>,
-) -> Result<()> {
+) -> Result<Message> {
     // info!("verifying signature_text: {:?}", signature_text);
     let message = signature_text.parse::<Message>().map_err(|err| {
         CustomError::new(
@@ -54,9 +88,51 @@ pub async fn ensure_signature_valid(
         );
     }
 
+    Ok(message)
+}
+
+/// checks the signature like `ensure_signature_valid` and consumes the nonce of the message,
+/// which must have been issued by GetLoginNonce to the same address and ip
+pub async fn ensure_login_message_valid(
+    db_auth: &DbClient,
+    signature_text: &str,
+    signature: &[u8],
+    address: Address,
+    allow_cors_sites: &Option<Vec<String>>,
+    ip_address: IpAddr,
+) -> Result<()> {
+    let message =
+        ensure_signature_valid(signature_text, signature, address, allow_cors_sites).await?;
+    db_auth
+        .execute(FunAuthConsumeLoginNonceReq {
+            nonce: message.nonce,
+            address: address.into(),
+            ip_address,
+        })
+        .await?;
     Ok(())
 }
 
+pub async fn record_failed_login(
+    db_auth: &DbClient,
+    address: Address,
+    ip_address: IpAddr,
+    device_id: Option<String>,
+    device_os: Option<String>,
+) {
+    if let Err(err) = db_auth
+        .execute(FunAuthAddFailedLoginAttemptReq {
+            address: address.into(),
+            ip_address,
+            device_id,
+            device_os,
+        })
+        .await
+    {
+        warn!("Failed to record failed login of {:?}: {:?}", address, err);
+    }
+}
+
 #[test]
 fn test_siwe_message() {
     let msg = hex_decode(b"6d63322e706174687363616c652e636f6d2077616e747320796f7520746f207369676e20696e207769746820796f757220457468657265756d206163636f756e743a0a3078313131303133623738363245626331423937323634323061613045383732384465333130456536330a0a5468697320726571756573742077696c6c206e6f74207472696767657220616e79207472616e73616374696f6e206f7220696e63757220616e7920636f7374206f7220666565732e4974206973206f6e6c7920696e74656e64656420746f2061757468656e74696361746520796f752061726520746865206f776e6572206f6620746869732077616c6c65743a0a0a0a5552493a2068747470733a2f2f6d63322e706174687363616c652e636f6d2f0a56657273696f6e3a20310a436861696e2049443a20310a4e6f6e63653a203834303132313139310a4973737565642041743a20323032332d30372d32335430383a35323a32352e3632395a").unwrap();
@@ -66,7 +142,7 @@ fn test_siwe_message() {
 }
 pub struct MethodAuthSignup {
     pub pool: EthereumRpcConnectionPool,
-    pub allow_cors_sites: Arc<Option<Vec<String>>>,
+    pub allow_cors_sites: Reloadable<Option<Vec<String>>>,
 }
 
 impl SubAuthController for MethodAuthSignup {
@@ -81,7 +157,7 @@ impl SubAuthController for MethodAuthSignup {
         let db: DbClient = toolbox.get_db();
         let db_auth: DbClient = toolbox.get_nth_db(1);
         let pool = self.pool.clone();
-        let allow_cors_sites = self.allow_cors_sites.clone();
+        let allow_cors_sites = self.allow_cors_sites.get();
         async move {
             let req: SignupRequest = serde_json::from_value(param).map_err(|x| {
                 CustomError::new(EnumErrorCode::BadRequest, format!("Invalid request: {}", x))
@@ -91,13 +167,19 @@ impl SubAuthController for MethodAuthSignup {
             let signature_text = hex_decode(req.signature_text.as_bytes())?;
             let signature = hex_decode(req.signature.as_bytes())?;
             let signature_text_string = String::from_utf8(signature_text.clone())?;
-            ensure_signature_valid(
+            if let Err(err) = ensure_login_message_valid(
+                &db_auth,
                 &signature_text_string,
                 &signature,
                 address,
                 &allow_cors_sites,
+                ctx.ip_addr,
             )
-            .await?;
+            .await
+            {
+                record_failed_login(&db_auth, address, ctx.ip_addr, None, None).await;
+                return Err(err);
+            }
             let conn = pool.get(EnumBlockChain::EthereumMainnet).await?;
             let ens = Ens::new(conn.transport().clone());
             let ens_name = match ens.canonical_name(address).await {
@@ -189,7 +271,8 @@ impl SubAuthController for MethodAuthSignup {
     }
 }
 pub struct MethodAuthLogin {
-    pub allow_cors_sites: Arc<Option<Vec<String>>>,
+    pub allow_cors_sites: Reloadable<Option<Vec<String>>>,
+    pub tokens: TokenConfig,
 }
 
 impl SubAuthController for MethodAuthLogin {
@@ -202,7 +285,8 @@ impl SubAuthController for MethodAuthLogin {
     ) -> BoxFuture<'static, Result<Value>> {
         info!("Login request: {:?}", param);
         let db_auth: DbClient = toolbox.get_nth_db(1);
-        let allow_cors_sites = self.allow_cors_sites.clone();
+        let allow_cors_sites = self.allow_cors_sites.get();
+        let tokens = self.tokens;
         async move {
             let req: LoginRequest = serde_json::from_value(param).map_err(|x| {
                 CustomError::new(EnumErrorCode::BadRequest, format!("Invalid request: {}", x))
@@ -212,13 +296,26 @@ impl SubAuthController for MethodAuthLogin {
             let signature_text = hex_decode(req.signature_text.as_bytes())?;
             let signature_text_string = String::from_utf8(signature_text.clone())?;
             let signature = hex_decode(req.signature.as_bytes())?;
-            ensure_signature_valid(
+            if let Err(err) = ensure_login_message_valid(
+                &db_auth,
                 &signature_text_string,
                 &signature,
                 address,
                 &allow_cors_sites,
+                ctx.ip_addr,
             )
-            .await?;
+            .await
+            {
+                record_failed_login(
+                    &db_auth,
+                    address,
+                    ctx.ip_addr,
+                    Some(req.device_id),
+                    Some(req.device_os),
+                )
+                .await;
+                return Err(err);
+            }
 
             let service_code = req.service;
 
@@ -226,24 +323,37 @@ impl SubAuthController for MethodAuthLogin {
                 .execute(FunAuthAuthenticateReq {
                     address: address.into(),
                     service_code: service_code as _,
-                    device_id: req.device_id,
-                    device_os: req.device_os,
+                    device_id: req.device_id.clone(),
+                    device_os: req.device_os.clone(),
                     ip_address: ctx.ip_addr,
                 })
                 .await?;
             let row = data
                 .into_result()
                 .with_context(|| CustomError::new(EnumErrorCode::UserNoAuthToken, Value::Null))?;
-            let user_token = Uuid::new_v4();
-            let admin_token = Uuid::new_v4();
+            let access_token = Uuid::new_v4();
+            let refresh_token = Uuid::new_v4();
+            let now = chrono::Utc::now().timestamp();
+            let expires_at = now + tokens.access_ttl_secs;
+            let refresh_expires_at = now + tokens.refresh_ttl_secs;
             db_auth
-                .execute(FunAuthSetTokenReq {
+                .execute(FunAuthCreateSessionReq {
                     user_id: row.user_id,
-                    user_token,
-                    admin_token,
-                    service_code: service_code as _,
+                    service: service_code,
+                    device_id: req.device_id,
+                    device_os: req.device_os,
+                    ip_address: ctx.ip_addr,
+                    access_token,
+                    access_expires_at: expires_at,
+                    refresh_token,
+                    refresh_expires_at,
                 })
                 .await?;
+            // only the token of the requested service is returned
+            let (user_token, admin_token) = match service_code {
+                EnumService::Admin => (None, Some(access_token)),
+                _ => (Some(access_token), None),
+            };
             info!("Role: {:?}", row.role);
             Ok(serde_json::to_value(&LoginResponse {
                 address: address.into(),
@@ -255,6 +365,108 @@ impl SubAuthController for MethodAuthLogin {
                 user_id: row.public_user_id,
                 user_token,
                 admin_token,
+                expires_at,
+                refresh_token,
+                refresh_expires_at,
+            })?)
+        }
+        .boxed()
+    }
+}
+
+pub struct MethodAuthRefreshToken {
+    pub tokens: TokenConfig,
+    pub event_bus: EventBus<DomainEvent>,
+}
+
+impl SubAuthController for MethodAuthRefreshToken {
+    fn auth(
+        self: Arc<Self>,
+        toolbox: &Toolbox,
+        param: Value,
+        ctx: RequestContext,
+        _conn: Arc<WsConnection>,
+    ) -> BoxFuture<'static, Result<Value>> {
+        info!("RefreshToken request: {:?}", param);
+        let db_auth: DbClient = toolbox.get_nth_db(1);
+        let tokens = self.tokens;
+        let event_bus = self.event_bus.clone();
+        async move {
+            let req: RefreshTokenRequest = serde_json::from_value(param).map_err(|x| {
+                CustomError::new(EnumErrorCode::BadRequest, format!("Invalid request: {}", x))
+            })?;
+            let token = Uuid::new_v4();
+            let refresh_token = Uuid::new_v4();
+            let now = chrono::Utc::now().timestamp();
+            let expires_at = now + tokens.access_ttl_secs;
+            let refresh_expires_at = now + tokens.refresh_ttl_secs;
+            let row = db_auth
+                .execute(FunAuthRefreshSessionReq {
+                    address: req.address.into(),
+                    refresh_token: req.refresh_token,
+                    service: req.service,
+                    device_id: req.device_id,
+                    ip_address: ctx.ip_addr,
+                    new_access_token: token,
+                    new_access_expires_at: expires_at,
+                    new_refresh_token: refresh_token,
+                    new_refresh_expires_at: refresh_expires_at,
+                })
+                .await?
+                .into_result()
+                .context("No record")?;
+            if row.is_reused {
+                warn!(
+                    "Refresh token of session {} reused by {:?}, session revoked",
+                    row.session_id, req.address
+                );
+                publish_session_revoked(&event_bus, row.user_id, row.session_id).await;
+                bail!(CustomError::new(
+                    EnumErrorCode::RefreshTokenReused,
+                    "Refresh token was already used, the session is revoked"
+                ));
+            }
+            Ok(serde_json::to_value(&RefreshTokenResponse {
+                token,
+                expires_at,
+                refresh_token,
+                refresh_expires_at,
+            })?)
+        }
+        .boxed()
+    }
+}
+
+pub struct MethodAuthGetLoginNonce;
+
+impl SubAuthController for MethodAuthGetLoginNonce {
+    fn auth(
+        self: Arc<Self>,
+        toolbox: &Toolbox,
+        param: Value,
+        ctx: RequestContext,
+        _conn: Arc<WsConnection>,
+    ) -> BoxFuture<'static, Result<Value>> {
+        info!("GetLoginNonce request: {:?}", param);
+        let db_auth: DbClient = toolbox.get_nth_db(1);
+        async move {
+            let req: GetLoginNonceRequest = serde_json::from_value(param).map_err(|x| {
+                CustomError::new(EnumErrorCode::BadRequest, format!("Invalid request: {}", x))
+            })?;
+            // siwe nonces are at least 8 alphanumeric characters
+            let nonce = Uuid::new_v4().simple().to_string();
+            let expires_at = chrono::Utc::now().timestamp() + LOGIN_NONCE_TTL_SECS;
+            db_auth
+                .execute(FunAuthIssueLoginNonceReq {
+                    address: req.address.into(),
+                    ip_address: ctx.ip_addr,
+                    nonce: nonce.clone(),
+                    expires_at,
+                })
+                .await?;
+            Ok(serde_json::to_value(&GetLoginNonceResponse {
+                nonce,
+                expires_at,
             })?)
         }
         .boxed()
@@ -309,31 +521,275 @@ impl SubAuthController for MethodAuthAuthorize {
             conn.user_id
                 .store(auth_data.user_id as _, Ordering::Relaxed);
             conn.role.store(auth_data.role as _, Ordering::Relaxed);
+            conn.user_session_id
+                .store(auth_data.session_id, Ordering::Relaxed);
             Ok(serde_json::to_value(&AuthorizeResponse { success: true })?)
         }
         .boxed()
     }
+    /// the login session may have expired or been revoked since the connection was authorized
+    fn reauthorize(
+        self: Arc<Self>,
+        toolbox: &Toolbox,
+        conn: Arc<WsConnection>,
+    ) -> BoxFuture<'static, Result<()>> {
+        let db_auth: DbClient = toolbox.get_nth_db(1);
+        async move {
+            let user_session_id = conn.get_user_session_id();
+            if user_session_id == 0 {
+                return Ok(());
+            }
+            let session = db_auth
+                .execute(FunAuthValidateUserSessionReq {
+                    user_id: conn.get_user_id(),
+                    session_id: user_session_id,
+                })
+                .await?
+                .into_result()
+                .context("No record")?;
+            conn.role.store(session.role as _, Ordering::Relaxed);
+            Ok(())
+        }
+        .boxed()
+    }
 }
 
-pub struct MethodAuthLogout;
-impl SubAuthController for MethodAuthLogout {
+/// how far the timestamp signed into an AuthorizeApiKey request may be from the server clock
+pub const API_KEY_SIGNATURE_WINDOW_MS: i64 = 30_000;
+/// bounds of the length of the nonce signed into an AuthorizeApiKey request
+pub const API_KEY_NONCE_MIN_LEN: usize = 8;
+pub const API_KEY_NONCE_MAX_LEN: usize = 64;
+
+fn api_key_request_mac(
+    api_key: &str,
+    api_secret: &str,
+    timestamp: i64,
+    nonce: &str,
+) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(api_secret.as_bytes())
+        .expect("HMAC can take a key of any size");
+    mac.update(format!("{}:{}:{}", api_key, timestamp, nonce).as_bytes());
+    mac
+}
+
+/// the hex HMAC-SHA256 of `"{api_key}:{timestamp}:{nonce}"` keyed with the secret of the API key
+pub fn sign_api_key_request(
+    api_key: &str,
+    api_secret: &str,
+    timestamp: i64,
+    nonce: &str,
+) -> String {
+    hex::encode(
+        api_key_request_mac(api_key, api_secret, timestamp, nonce)
+            .finalize()
+            .into_bytes(),
+    )
+}
+
+/// checks the nonce, the timestamp and the signature of an AuthorizeApiKey request. whether the
+/// nonce was used before is checked against the database by the caller
+pub fn verify_api_key_request(
+    api_key: &str,
+    api_secret: &str,
+    timestamp: i64,
+    nonce: &str,
+    signature: &str,
+    now_ms: i64,
+) -> Result<()> {
+    if !(API_KEY_NONCE_MIN_LEN..=API_KEY_NONCE_MAX_LEN).contains(&nonce.len()) {
+        bail!(CustomError::new(
+            EnumErrorCode::BadRequest,
+            format!(
+                "Nonce must be {} to {} characters",
+                API_KEY_NONCE_MIN_LEN, API_KEY_NONCE_MAX_LEN
+            ),
+        ));
+    }
+    if (now_ms - timestamp).abs() > API_KEY_SIGNATURE_WINDOW_MS {
+        bail!(CustomError::new(
+            EnumErrorCode::InvalidPassword,
+            "Timestamp is too far from the server time"
+        ));
+    }
+    let signature = hex::decode(signature.trim_start_matches("0x"))
+        .map_err(|_| CustomError::new(EnumErrorCode::InvalidPassword, "Signature is not hex"))?;
+    if api_key_request_mac(api_key, api_secret, timestamp, nonce)
+        .verify_slice(&signature)
+        .is_err()
+    {
+        bail!(CustomError::new(
+            EnumErrorCode::InvalidPassword,
+            "Signature is not valid"
+        ));
+    }
+    Ok(())
+}
+
+pub struct MethodAuthAuthorizeApiKey {
+    pub accept_service: EnumService,
+}
+impl SubAuthController for MethodAuthAuthorizeApiKey {
     fn auth(
         self: Arc<Self>,
         toolbox: &Toolbox,
-        _param: Value,
+        param: Value,
         ctx: RequestContext,
         conn: Arc<WsConnection>,
     ) -> BoxFuture<'static, Result<Value>> {
         let db_auth: DbClient = toolbox.get_nth_db(1);
-
+        let accepted_service = self.accept_service;
         async move {
+            let req: AuthorizeApiKeyRequest = serde_json::from_value(param).map_err(|x| {
+                CustomError::new(EnumErrorCode::BadRequest, format!("Invalid request: {}", x))
+            })?;
+            info!("AuthorizeApiKey request: {} {}", req.api_key, req.timestamp);
+            if req.service != accepted_service {
+                bail!(CustomError::new(
+                    EnumErrorCode::InvalidService,
+                    format!(
+                        "Invalid service, only {:?} {} permitted",
+                        accepted_service, accepted_service as u32
+                    ),
+                ));
+            }
+            let key = db_auth
+                .execute(FunAuthAuthorizeApiKeyReq {
+                    api_key: req.api_key.clone(),
+                    ip_address: ctx.ip_addr,
+                })
+                .await?
+                .into_result()
+                .with_context(|| {
+                    CustomError::new(EnumErrorCode::UserInvalidAuthToken, Value::Null)
+                })?;
+
+            let now = get_time_milliseconds();
+            verify_api_key_request(
+                &req.api_key,
+                &key.api_secret,
+                req.timestamp,
+                &req.nonce,
+                &req.signature,
+                now,
+            )?;
+            /* a nonce older than the window can't be signed into an accepted request any more */
+            let nonce = db_auth
+                .execute(FunAuthUseApiKeyNonceReq {
+                    api_key_id: key.api_key_id,
+                    nonce: req.nonce.clone(),
+                    timestamp: req.timestamp,
+                    expired_before: now - 2 * API_KEY_SIGNATURE_WINDOW_MS,
+                })
+                .await?
+                .into_result()
+                .context("No record")?;
+            if !nonce.accepted {
+                bail!(CustomError::new(
+                    EnumErrorCode::InvalidPassword,
+                    "Nonce was already used"
+                ));
+            }
             db_auth
-                .execute(FunAuthRemoveTokenReq {
-                    user_id: ctx.user_id,
+                .execute(FunAuthTouchApiKeyReq {
+                    api_key_id: key.api_key_id,
                 })
                 .await?;
-            conn.user_id.store(0, Ordering::Relaxed);
-            conn.role.store(EnumRole::Guest as _, Ordering::Relaxed);
+
+            conn.user_id.store(key.user_id as _, Ordering::Relaxed);
+            conn.role.store(key.role as _, Ordering::Relaxed);
+            conn.api_key_id.store(key.api_key_id, Ordering::Relaxed);
+            conn.api_key_scopes.store(
+                key.scopes.iter().fold(0, |bits, x| bits | 1 << *x as u32),
+                Ordering::Relaxed,
+            );
+            Ok(serde_json::to_value(&AuthorizeApiKeyResponse {
+                success: true,
+            })?)
+        }
+        .boxed()
+    }
+    /// the API key may have expired or been revoked since the connection was authorized
+    fn reauthorize(
+        self: Arc<Self>,
+        toolbox: &Toolbox,
+        conn: Arc<WsConnection>,
+    ) -> BoxFuture<'static, Result<()>> {
+        let db_auth: DbClient = toolbox.get_nth_db(1);
+        async move {
+            let api_key_id = conn.get_api_key_id();
+            if api_key_id == 0 {
+                return Ok(());
+            }
+            let key = db_auth
+                .execute(FunAuthValidateApiKeyReq {
+                    user_id: conn.get_user_id(),
+                    api_key_id,
+                    ip_address: conn.address.ip(),
+                })
+                .await?
+                .into_result()
+                .context("No record")?;
+            conn.role.store(key.role as _, Ordering::Relaxed);
+            conn.api_key_scopes.store(
+                key.scopes.iter().fold(0, |bits, x| bits | 1 << *x as u32),
+                Ordering::Relaxed,
+            );
+            Ok(())
+        }
+        .boxed()
+    }
+}
+
+/// closes the connections of a revoked session on every user service
+async fn publish_session_revoked(
+    event_bus: &EventBus<DomainEvent>,
+    user_id: i64,
+    user_session_id: i64,
+) {
+    if let Err(err) = event_bus
+        .publish(&DomainEvent::SessionRevoked(SessionRevokedEvent {
+            user_id,
+            user_session_id,
+        }))
+        .await
+    {
+        error!(
+            "Failed to publish revocation of session {}: {:?}",
+            user_session_id, err
+        );
+    }
+}
+
+pub struct MethodAuthLogout {
+    pub event_bus: EventBus<DomainEvent>,
+}
+impl SubAuthController for MethodAuthLogout {
+    fn auth(
+        self: Arc<Self>,
+        toolbox: &Toolbox,
+        param: Value,
+        _ctx: RequestContext,
+        _conn: Arc<WsConnection>,
+    ) -> BoxFuture<'static, Result<Value>> {
+        let db_auth: DbClient = toolbox.get_nth_db(1);
+        let event_bus = self.event_bus.clone();
+
+        async move {
+            let req: LogoutRequest = serde_json::from_value(param).map_err(|x| {
+                CustomError::new(EnumErrorCode::BadRequest, format!("Invalid request: {}", x))
+            })?;
+            /* the session of the access token, the other devices of the user stay logged in */
+            let row = db_auth
+                .execute(FunAuthRemoveTokenReq {
+                    address: req.address.into(),
+                    token: req.token,
+                    service: req.service,
+                    device_id: req.device_id,
+                })
+                .await?
+                .into_result()
+                .context("No record")?;
+            publish_session_revoked(&event_bus, row.user_id, row.session_id).await;
             Ok(serde_json::to_value(&LogoutResponse {})?)
         }
         .boxed()
@@ -341,7 +797,7 @@ impl SubAuthController for MethodAuthLogout {
 }
 
 pub struct MethodAuthChangeLoginWallet {
-    pub allow_cors_sites: Arc<Option<Vec<String>>>,
+    pub allow_cors_sites: Reloadable<Option<Vec<String>>>,
 }
 
 impl SubAuthController for MethodAuthChangeLoginWallet {
@@ -354,7 +810,7 @@ impl SubAuthController for MethodAuthChangeLoginWallet {
     ) -> BoxFuture<'static, Result<Value>> {
         info!("MethodAuthChangeLoginWallet request: {:?}", param);
         let db_auth: DbClient = toolbox.get_nth_db(1);
-        let allow_cors_sites = self.allow_cors_sites.clone();
+        let allow_cors_sites = self.allow_cors_sites.get();
         async move {
             let req: ChangeLoginWalletRequest = serde_json::from_value(param).map_err(|x| {
                 CustomError::new(EnumErrorCode::BadRequest, format!("Invalid request: {}", x))
@@ -401,3 +857,80 @@ impl SubAuthController for MethodAuthChangeLoginWallet {
         .boxed()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lib::error_code::ErrorCode;
+
+    const API_KEY: &str = "key";
+    const API_SECRET: &str = "secret";
+    const NONCE: &str = "abcdefgh";
+    const NOW: i64 = 1_700_000_000_000;
+
+    fn error_code(result: Result<()>) -> ErrorCode {
+        result
+            .unwrap_err()
+            .downcast_ref::<CustomError>()
+            .expect("a CustomError")
+            .code
+    }
+
+    #[test]
+    fn test_sign_api_key_request() {
+        assert_eq!(
+            sign_api_key_request(API_KEY, API_SECRET, NOW, NONCE),
+            "1b023c37fc0caa9685df0bb57a34d9fcb34ceb9830f0ae5f30f8f2af0be6aa5f"
+        );
+        assert_ne!(
+            sign_api_key_request(API_KEY, API_SECRET, NOW, NONCE),
+            sign_api_key_request(API_KEY, API_SECRET, NOW, "abcdefgi")
+        );
+    }
+
+    #[test]
+    fn test_verify_api_key_request() {
+        let signature = sign_api_key_request(API_KEY, API_SECRET, NOW, NONCE);
+        verify_api_key_request(API_KEY, API_SECRET, NOW, NONCE, &signature, NOW).unwrap();
+        verify_api_key_request(
+            API_KEY,
+            API_SECRET,
+            NOW,
+            NONCE,
+            &format!("0x{}", signature),
+            NOW + API_KEY_SIGNATURE_WINDOW_MS,
+        )
+        .unwrap();
+
+        let invalid: ErrorCode = EnumErrorCode::InvalidPassword.into();
+        for result in [
+            verify_api_key_request(API_KEY, "other", NOW, NONCE, &signature, NOW),
+            verify_api_key_request("other", API_SECRET, NOW, NONCE, &signature, NOW),
+            verify_api_key_request(API_KEY, API_SECRET, NOW + 1, NONCE, &signature, NOW),
+            verify_api_key_request(API_KEY, API_SECRET, NOW, "abcdefgi", &signature, NOW),
+            verify_api_key_request(API_KEY, API_SECRET, NOW, NONCE, "not hex", NOW),
+            verify_api_key_request(API_KEY, API_SECRET, NOW, NONCE, &signature[2..], NOW),
+            verify_api_key_request(
+                API_KEY,
+                API_SECRET,
+                NOW,
+                NONCE,
+                &signature,
+                NOW + API_KEY_SIGNATURE_WINDOW_MS + 1,
+            ),
+        ] {
+            assert_eq!(error_code(result), invalid);
+        }
+
+        let bad_request: ErrorCode = EnumErrorCode::BadRequest.into();
+        for nonce in ["short", &"n".repeat(API_KEY_NONCE_MAX_LEN + 1)] {
+            let signature = sign_api_key_request(API_KEY, API_SECRET, NOW, nonce);
+            assert_eq!(
+                error_code(verify_api_key_request(
+                    API_KEY, API_SECRET, NOW, nonce, &signature, NOW
+                )),
+                bad_request
+            );
+        }
+    }
+}
@@ -27,9 +27,9 @@ DECLARE
 BEGIN
     IF (a_agreed_tos = FALSE OR a_agreed_privacy = FALSE) THEN
         RAISE SQLSTATE 'R000X'; -- ConsentMissing
-    ELSEIF ((SELECT pkey_id
-             FROM tbl.user
-             WHERE address = a_address) IS NOT NULL) THEN
+    ELSEIF EXISTS(SELECT 1
+                  FROM tbl.user_login_wallet
+                  WHERE address = a_address) THEN
         RAISE SQLSTATE 'R000Z'; -- UsernameAlreadyRegistered
     END IF;
     INSERT INTO tbl.user (address,
@@ -65,6 +65,8 @@ BEGIN
             a_ens_avatar
         )
     RETURNING pkey_id INTO STRICT id_;
+    INSERT INTO tbl.user_login_wallet(fkey_user_id, address, is_primary, created_at)
+    VALUES (id_, a_address, TRUE, extract(Epoch FROM (NOW()))::bigint);
     INSERT INTO tbl.user_whitelisted_wallet(fkey_user_id,
                                            blockchain,
                                            address,
@@ -109,11 +111,12 @@ BEGIN
     ASSERT (a_ip_address NOTNULL AND a_device_id NOTNULL AND a_device_os NOTNULL AND
             a_address NOTNULL AND a_service_code NOTNULL);
 
-    -- Looking up the user.
-    SELECT pkey_id, is_blocked, u.role, u.public_id
+    -- Looking up the user of any of its login wallets.
+    SELECT u.pkey_id, is_blocked, u.role, u.public_id
     INTO _user_id, is_blocked_, _role, _public_user_id
     FROM tbl.user u
-    WHERE address = a_address;
+    JOIN tbl.user_login_wallet w ON w.fkey_user_id = u.pkey_id
+    WHERE w.address = a_address;
 
     -- Log the login attempt. 
     INSERT INTO tbl.login_attempt(fkey_user, address, ip_address, is_password_ok, moment)
@@ -146,26 +149,31 @@ BEGIN
     END IF;
     RETURN QUERY SELECT u.pkey_id, u.public_id, u.role, u.ens_name, u.ens_avatar
     FROM tbl.user u
-    WHERE address = a_address;
+    WHERE u.pkey_id = _user_id;
 END
         "#,
         ),
         ProceduralFunction::new(
-            "fun_auth_set_token",
+            "fun_auth_create_session",
             vec![
                 Field::new("user_id", Type::BigInt),
-                Field::new("user_token", Type::UUID),
-                Field::new("admin_token", Type::UUID),
-                Field::new("service_code", Type::Int),
+                Field::new("service", Type::enum_ref("service")),
+                Field::new("device_id", Type::String),
+                Field::new("device_os", Type::String),
+                Field::new("ip_address", Type::Inet),
+                Field::new("access_token", Type::UUID),
+                Field::new("access_expires_at", Type::BigInt),
+                Field::new("refresh_token", Type::UUID),
+                Field::new("refresh_expires_at", Type::BigInt),
             ],
-            vec![],
+            vec![Field::new("session_id", Type::BigInt)],
             r#"
 DECLARE
-  rc_         integer;
   is_blocked_ boolean;
+  _session_id bigint;
 BEGIN
-  ASSERT (a_user_id NOTNULL AND a_service_code NOTNULL AND a_user_token NOTNULL AND
-          a_admin_token NOTNULL);
+  ASSERT (a_user_id NOTNULL AND a_service NOTNULL AND a_device_id NOTNULL AND
+          a_access_token NOTNULL AND a_refresh_token NOTNULL);
   -- Looking up the user.
   SELECT is_blocked INTO is_blocked_ FROM tbl.user WHERE pkey_id = a_user_id;
   IF (is_blocked_ ISNULL) THEN
@@ -174,39 +182,136 @@ BEGIN
     RAISE SQLSTATE 'R0008'; -- BlockedUser
   END IF;
 
-  -- Setting up the token.
-  IF a_service_code = (SELECT code FROM api.USER_SERVICE()) THEN
-    UPDATE tbl.user
-    SET user_token = a_user_token
-    WHERE pkey_id = a_user_id;
-  END IF;
-  IF a_service_code = (SELECT code FROM api.ADMIN_SERVICE())  THEN
-    UPDATE tbl.user
-    SET admin_token = a_admin_token
-    WHERE pkey_id = a_user_id;
-  END IF;
+  -- A new login replaces the session of the same device only.
+  UPDATE tbl.user_session
+  SET revoked_at = EXTRACT(EPOCH FROM NOW())::bigint
+  WHERE fkey_user_id = a_user_id
+    AND service = a_service
+    AND device_id = a_device_id
+    AND revoked_at ISNULL;
 
-  GET DIAGNOSTICS rc_ := ROW_COUNT;
-  ASSERT (rc_ = 1);
+  INSERT INTO tbl.user_session (fkey_user_id, service, device_id, device_os, ip_address,
+                                access_token, access_expires_at, refresh_token,
+                                refresh_expires_at, created_at, last_seen_at)
+  VALUES (a_user_id, a_service, a_device_id, a_device_os, a_ip_address,
+          a_access_token, a_access_expires_at, a_refresh_token,
+          a_refresh_expires_at, EXTRACT(EPOCH FROM NOW())::bigint,
+          EXTRACT(EPOCH FROM NOW())::bigint)
+  RETURNING pkey_id INTO STRICT _session_id;
+  RETURN QUERY SELECT _session_id;
 END
             "#,
         ),
         ProceduralFunction::new(
-            "fun_auth_remove_token",
-            vec![Field::new("user_id", Type::BigInt)],
-            vec![],
+            "fun_auth_refresh_session",
+            vec![
+                Field::new("address", Type::BlockchainAddress),
+                Field::new("refresh_token", Type::UUID),
+                Field::new("service", Type::enum_ref("service")),
+                Field::new("device_id", Type::String),
+                Field::new("ip_address", Type::Inet),
+                Field::new("new_access_token", Type::UUID),
+                Field::new("new_access_expires_at", Type::BigInt),
+                Field::new("new_refresh_token", Type::UUID),
+                Field::new("new_refresh_expires_at", Type::BigInt),
+            ],
+            vec![
+                Field::new("session_id", Type::BigInt),
+                Field::new("user_id", Type::BigInt),
+                Field::new("is_reused", Type::Boolean),
+            ],
             r#"
+DECLARE
+  _session_id         bigint;
+  _user_id            bigint;
+  _device_id          varchar;
+  _refresh_expires_at bigint;
+  _revoked_at         bigint;
 BEGIN
-  ASSERT (a_user_id NOTNULL);
+  SELECT s.pkey_id, s.fkey_user_id, s.device_id, s.refresh_expires_at, s.revoked_at
+  INTO _session_id, _user_id, _device_id, _refresh_expires_at, _revoked_at
+  FROM tbl.user_session s
+  JOIN tbl.user_login_wallet w ON w.fkey_user_id = s.fkey_user_id
+  WHERE s.refresh_token = a_refresh_token
+    AND s.service = a_service
+    AND w.address = a_address
+  FOR UPDATE OF s;
 
-  -- Setting up the token.
-  UPDATE tbl.user
-  SET user_token = NULL, admin_token = NULL
-  WHERE pkey_id = a_user_id;
+  IF (_session_id ISNULL) THEN
+    -- A refresh token that was already rotated has leaked, the whole session goes.
+    SELECT r.fkey_session_id, s.fkey_user_id
+    INTO _session_id, _user_id
+    FROM tbl.user_session_rotated_token r
+    JOIN tbl.user_session s ON s.pkey_id = r.fkey_session_id
+    JOIN tbl.user_login_wallet w ON w.fkey_user_id = s.fkey_user_id
+    WHERE r.refresh_token = a_refresh_token
+      AND w.address = a_address;
+    IF (_session_id ISNULL) THEN
+      RAISE SQLSTATE 'R000A'; -- InvalidToken
+    END IF;
+    UPDATE tbl.user_session
+    SET revoked_at = EXTRACT(EPOCH FROM NOW())::bigint
+    WHERE pkey_id = _session_id
+      AND revoked_at ISNULL;
+    RETURN QUERY SELECT _session_id, _user_id, TRUE;
+    RETURN;
+  END IF;
 
+  IF (_revoked_at NOTNULL OR _device_id <> a_device_id) THEN
+    RAISE SQLSTATE 'R000A'; -- InvalidToken
+  ELSIF (_refresh_expires_at < EXTRACT(EPOCH FROM NOW())::bigint) THEN
+    RAISE SQLSTATE 'R000L'; -- TokenExpired
+  END IF;
+
+  INSERT INTO tbl.user_session_rotated_token (refresh_token, fkey_session_id, rotated_at)
+  VALUES (a_refresh_token, _session_id, EXTRACT(EPOCH FROM NOW())::bigint);
+  UPDATE tbl.user_session
+  SET access_token       = a_new_access_token,
+      access_expires_at  = a_new_access_expires_at,
+      refresh_token      = a_new_refresh_token,
+      refresh_expires_at = a_new_refresh_expires_at,
+      ip_address         = a_ip_address,
+      last_seen_at       = EXTRACT(EPOCH FROM NOW())::bigint
+  WHERE pkey_id = _session_id;
+  RETURN QUERY SELECT _session_id, _user_id, FALSE;
 END
             "#,
         ),
+        ProceduralFunction::new(
+            "fun_auth_remove_token",
+            vec![
+                Field::new("address", Type::BlockchainAddress),
+                Field::new("token", Type::UUID),
+                Field::new("service", Type::enum_ref("service")),
+                Field::new("device_id", Type::String),
+            ],
+            vec![
+                Field::new("user_id", Type::BigInt),
+                Field::new("session_id", Type::BigInt),
+            ],
+            r#"
+DECLARE
+  _user_id    bigint;
+  _session_id bigint;
+BEGIN
+  -- Only the session of the device logging out, the other devices stay logged in.
+  UPDATE tbl.user_session AS s
+  SET revoked_at = EXTRACT(EPOCH FROM NOW())::bigint
+  FROM tbl.user_login_wallet AS w
+  WHERE w.fkey_user_id = s.fkey_user_id
+    AND w.address = a_address
+    AND s.access_token = a_token
+    AND s.service = a_service
+    AND s.device_id = a_device_id
+    AND s.revoked_at ISNULL
+  RETURNING s.fkey_user_id, s.pkey_id INTO _user_id, _session_id;
+  IF (_session_id ISNULL) THEN
+    RAISE SQLSTATE 'R000A'; -- InvalidToken
+  END IF;
+  RETURN QUERY SELECT _user_id, _session_id;
+END
+"#,
+        ),
         ProceduralFunction::new(
             "fun_auth_authorize",
             vec![
@@ -220,62 +325,60 @@ END
             vec![
                 Field::new("user_id", Type::BigInt),
                 Field::new("role", Type::enum_ref("role")),
+                Field::new("session_id", Type::BigInt),
             ],
             r#"
 DECLARE
-    rc_          integer;
-    is_token_ok_ boolean;
-    user_id_     bigint;
-    role_        enum_role;
-
+    rc_                integer;
+    user_id_           bigint;
+    role_              enum_role;
+    session_id_        bigint;
+    access_expires_at_ bigint;
 BEGIN
     ASSERT (a_address NOTNULL AND a_token NOTNULL AND a_service NOTNULL AND
             a_device_id NOTNULL AND a_device_os NOTNULL);
 
-    -- Looking up the user
-    CASE a_service
-        WHEN 'user'::enum_service
-            THEN SELECT pkey_id, u.role, (user_token = a_token)
-                 INTO user_id_, role_, is_token_ok_
-                 FROM tbl.user AS u
-                 WHERE address = a_address;
-        WHEN 'admin'::enum_service
-            THEN SELECT pkey_id, u.role, (admin_token = a_token)
-                 INTO user_id_, role_, is_token_ok_
-                 FROM tbl.user AS u
-                 WHERE address = a_address;
-        ELSE RAISE SQLSTATE 'R0001'; -- InvalidArgument
-        END CASE;
+    -- Looking up the user of any of its login wallets
+    SELECT u.pkey_id, u.role
+    INTO user_id_, role_
+    FROM tbl.user AS u
+    JOIN tbl.user_login_wallet AS w ON w.fkey_user_id = u.pkey_id
+    WHERE w.address = a_address;
     GET DIAGNOSTICS rc_ := ROW_COUNT;
     IF (rc_ <> 1) THEN
         RAISE SQLSTATE 'R0007'; -- UnknownUser
     END IF;
 
+    -- Looking up the session of the device
+    SELECT pkey_id, access_expires_at
+    INTO session_id_, access_expires_at_
+    FROM tbl.user_session
+    WHERE fkey_user_id = user_id_
+      AND service = a_service
+      AND device_id = a_device_id
+      AND access_token = a_token
+      AND revoked_at ISNULL;
+
     -- Log the authorization attempt
     INSERT INTO tbl.authorization_attempt(fkey_user, ip_address, is_token_ok, moment)
-    VALUES (user_id_, a_ip_address, is_token_ok_ NOTNULL AND is_token_ok_, extract(Epoch FROM (NOW()))::bigint);
+    VALUES (user_id_, a_ip_address,
+            session_id_ NOTNULL AND access_expires_at_ >= extract(Epoch FROM (NOW()))::bigint,
+            extract(Epoch FROM (NOW()))::bigint);
     -- COMMIT;
     -- Validating the token
-    IF NOT is_token_ok_ OR is_token_ok_ IS NULL THEN
+    IF session_id_ ISNULL THEN
         RAISE SQLSTATE 'R000A'; -- InvalidToken
+    ELSIF access_expires_at_ < extract(Epoch FROM (NOW()))::bigint THEN
+        RAISE SQLSTATE 'R000L'; -- TokenExpired
     END IF;
 
     -- Updating the device info
-    CASE a_service
-        WHEN 'user'::enum_service
-            THEN UPDATE tbl.user
-                 SET user_device_id = a_device_id
-                 WHERE pkey_id = user_id_
-                   AND user_token = a_token;
-        WHEN 'admin'::enum_service
-            THEN UPDATE tbl.user
-                 SET admin_device_id = a_device_id
-                 WHERE pkey_id = user_id_
-                   AND admin_token = a_token;
-        END CASE;
-    GET DIAGNOSTICS rc_ := ROW_COUNT;
-    ASSERT (rc_ = 1);
-    RETURN QUERY SELECT user_id_, role_;
+    UPDATE tbl.user_session
+    SET device_os    = a_device_os,
+        ip_address   = a_ip_address,
+        last_seen_at = extract(Epoch FROM (NOW()))::bigint
+    WHERE pkey_id = session_id_;
+    RETURN QUERY SELECT user_id_, role_, session_id_;
 END
             "#,
         ),
@@ -301,10 +404,11 @@ END
             vec![],
             r#"
 BEGIN
+    UPDATE tbl.user_login_wallet SET address = a_new_wallet_address
+     WHERE address = a_old_wallet_address;
     UPDATE tbl.user SET address = a_new_wallet_address,
                 updated_at = EXTRACT(EPOCH FROM NOW())::bigint
      WHERE address = a_old_wallet_address;
-    
 END
             "#,
         ),
@@ -327,6 +431,223 @@ BEGIN
         updated_at = EXTRACT(EPOCH FROM NOW())::bigint
      WHERE pkey_id = a_user_id;
     
+END
+            "#,
+        ),
+        ProceduralFunction::new(
+            "fun_auth_issue_login_nonce",
+            vec![
+                Field::new("address", Type::BlockchainAddress),
+                Field::new("ip_address", Type::Inet),
+                Field::new("nonce", Type::String),
+                Field::new("expires_at", Type::BigInt),
+            ],
+            vec![],
+            r#"
+BEGIN
+    -- nonces expired for a day are of no use even for auditing
+    DELETE FROM tbl.login_nonce
+    WHERE expires_at < EXTRACT(EPOCH FROM NOW())::bigint - 86400;
+    INSERT INTO tbl.login_nonce (nonce, address, ip_address, created_at, expires_at)
+    VALUES (a_nonce, a_address, a_ip_address, EXTRACT(EPOCH FROM NOW())::bigint, a_expires_at);
+END
+            "#,
+        ),
+        ProceduralFunction::new(
+            "fun_auth_consume_login_nonce",
+            vec![
+                Field::new("nonce", Type::String),
+                Field::new("address", Type::BlockchainAddress),
+                Field::new("ip_address", Type::Inet),
+            ],
+            vec![],
+            r#"
+DECLARE
+    _nonce_id   bigint;
+    _expires_at bigint;
+    _used_at    bigint;
+BEGIN
+    SELECT pkey_id, expires_at, used_at
+    INTO _nonce_id, _expires_at, _used_at
+    FROM tbl.login_nonce
+    WHERE nonce = a_nonce
+      AND address = a_address
+      AND ip_address = a_ip_address
+    FOR UPDATE;
+    IF (_nonce_id ISNULL) THEN
+        RAISE SQLSTATE 'R000I'; -- UnknownLoginNonce
+    ELSEIF (_used_at NOTNULL) THEN
+        RAISE SQLSTATE 'R000K'; -- UsedLoginNonce
+    ELSEIF (_expires_at < EXTRACT(EPOCH FROM NOW())::bigint) THEN
+        RAISE SQLSTATE 'R000J'; -- ExpiredLoginNonce
+    END IF;
+    UPDATE tbl.login_nonce
+    SET used_at = EXTRACT(EPOCH FROM NOW())::bigint
+    WHERE pkey_id = _nonce_id;
+END
+            "#,
+        ),
+        ProceduralFunction::new(
+            "fun_auth_add_failed_login_attempt",
+            vec![
+                Field::new("address", Type::BlockchainAddress),
+                Field::new("ip_address", Type::Inet),
+                Field::new("device_id", Type::optional(Type::String)),
+                Field::new("device_os", Type::optional(Type::String)),
+            ],
+            vec![],
+            r#"
+BEGIN
+    INSERT INTO tbl.login_attempt(fkey_user, address, ip_address, device_id, device_os, is_password_ok, moment)
+    VALUES ((SELECT fkey_user_id FROM tbl.user_login_wallet WHERE address = a_address),
+            a_address,
+            a_ip_address,
+            a_device_id,
+            a_device_os,
+            FALSE,
+            EXTRACT(EPOCH FROM NOW())::bigint);
+END
+            "#,
+        ),
+        ProceduralFunction::new(
+            "fun_auth_authorize_api_key",
+            vec![
+                Field::new("api_key", Type::String),
+                Field::new("ip_address", Type::Inet),
+            ],
+            vec![
+                Field::new("user_id", Type::BigInt),
+                Field::new("role", Type::enum_ref("role")),
+                Field::new("api_key_id", Type::BigInt),
+                Field::new("api_secret", Type::String),
+                Field::new("scopes", Type::vec(Type::enum_ref("api_key_scope"))),
+            ],
+            r#"
+DECLARE
+    _api_key_id   bigint;
+    _user_id      bigint;
+    _api_secret   varchar;
+    _scopes       enum_api_key_scope[];
+    _ip_allowlist inet[];
+    _expires_at   bigint;
+    _role         enum_role;
+    _is_blocked   boolean;
+BEGIN
+    SELECT k.pkey_id, k.fkey_user_id, k.api_secret, k.scopes, k.ip_allowlist, k.expires_at, u.role, u.is_blocked
+    INTO _api_key_id, _user_id, _api_secret, _scopes, _ip_allowlist, _expires_at, _role, _is_blocked
+    FROM tbl.user_api_key AS k
+    JOIN tbl.user AS u ON u.pkey_id = k.fkey_user_id
+    WHERE k.api_key = a_api_key
+      AND k.revoked_at ISNULL;
+    IF (_api_key_id ISNULL) THEN
+        RAISE SQLSTATE 'R000A'; -- InvalidToken
+    ELSEIF (_expires_at < EXTRACT(EPOCH FROM NOW())::bigint) THEN
+        RAISE SQLSTATE 'R000L'; -- TokenExpired
+    ELSEIF (_ip_allowlist NOTNULL AND a_ip_address <> ALL (_ip_allowlist)) THEN
+        RAISE SQLSTATE 'R0013'; -- IpNotAllowed
+    ELSEIF (_is_blocked) THEN
+        RAISE SQLSTATE 'R0008'; -- BlockedUser
+    END IF;
+    RETURN QUERY SELECT _user_id, _role, _api_key_id, _api_secret, _scopes;
+END
+            "#,
+        ),
+        ProceduralFunction::new(
+            "fun_auth_validate_user_session",
+            vec![
+                Field::new("user_id", Type::BigInt),
+                Field::new("session_id", Type::BigInt),
+            ],
+            vec![Field::new("role", Type::enum_ref("role"))],
+            r#"
+DECLARE
+    _access_expires_at bigint;
+    _role              enum_role;
+BEGIN
+    SELECT s.access_expires_at, u.role
+    INTO _access_expires_at, _role
+    FROM tbl.user_session AS s
+    JOIN tbl.user AS u ON u.pkey_id = s.fkey_user_id
+    WHERE s.pkey_id = a_session_id
+      AND s.fkey_user_id = a_user_id
+      AND s.revoked_at ISNULL;
+    IF (_role ISNULL) THEN
+        RAISE SQLSTATE 'R000A'; -- InvalidToken
+    ELSEIF (_access_expires_at < EXTRACT(EPOCH FROM NOW())::bigint) THEN
+        RAISE SQLSTATE 'R000L'; -- TokenExpired
+    END IF;
+    RETURN QUERY SELECT _role;
+END
+            "#,
+        ),
+        ProceduralFunction::new(
+            "fun_auth_validate_api_key",
+            vec![
+                Field::new("user_id", Type::BigInt),
+                Field::new("api_key_id", Type::BigInt),
+                Field::new("ip_address", Type::Inet),
+            ],
+            vec![
+                Field::new("role", Type::enum_ref("role")),
+                Field::new("scopes", Type::vec(Type::enum_ref("api_key_scope"))),
+            ],
+            r#"
+DECLARE
+    _scopes       enum_api_key_scope[];
+    _ip_allowlist inet[];
+    _expires_at   bigint;
+    _role         enum_role;
+    _is_blocked   boolean;
+BEGIN
+    SELECT k.scopes, k.ip_allowlist, k.expires_at, u.role, u.is_blocked
+    INTO _scopes, _ip_allowlist, _expires_at, _role, _is_blocked
+    FROM tbl.user_api_key AS k
+    JOIN tbl.user AS u ON u.pkey_id = k.fkey_user_id
+    WHERE k.pkey_id = a_api_key_id
+      AND k.fkey_user_id = a_user_id
+      AND k.revoked_at ISNULL;
+    IF (_role ISNULL) THEN
+        RAISE SQLSTATE 'R000A'; -- InvalidToken
+    ELSEIF (_expires_at < EXTRACT(EPOCH FROM NOW())::bigint) THEN
+        RAISE SQLSTATE 'R000L'; -- TokenExpired
+    ELSEIF (_ip_allowlist NOTNULL AND a_ip_address <> ALL (_ip_allowlist)) THEN
+        RAISE SQLSTATE 'R0013'; -- IpNotAllowed
+    ELSEIF (_is_blocked) THEN
+        RAISE SQLSTATE 'R0008'; -- BlockedUser
+    END IF;
+    RETURN QUERY SELECT _role, _scopes;
+END
+            "#,
+        ),
+        ProceduralFunction::new(
+            "fun_auth_touch_api_key",
+            vec![Field::new("api_key_id", Type::BigInt)],
+            vec![],
+            r#"
+BEGIN
+    UPDATE tbl.user_api_key
+    SET last_used_at = EXTRACT(EPOCH FROM NOW())::bigint
+    WHERE pkey_id = a_api_key_id;
+END
+            "#,
+        ),
+        ProceduralFunction::new(
+            "fun_auth_use_api_key_nonce",
+            vec![
+                Field::new("api_key_id", Type::BigInt),
+                Field::new("nonce", Type::String),
+                Field::new("timestamp", Type::BigInt),
+                Field::new("expired_before", Type::BigInt),
+            ],
+            vec![Field::new("accepted", Type::Boolean)],
+            r#"
+BEGIN
+    DELETE FROM tbl.user_api_key_nonce
+    WHERE timestamp < a_expired_before;
+    INSERT INTO tbl.user_api_key_nonce (fkey_api_key_id, nonce, timestamp)
+    VALUES (a_api_key_id, a_nonce, a_timestamp)
+    ON CONFLICT (fkey_api_key_id, nonce) DO NOTHING;
+    RETURN QUERY SELECT FOUND;
 END
             "#,
         ),
@@ -1,6 +1,10 @@
 // TODO: rework Type::datatable to be more ergonomic
 
 use model::types::*;
+use roles::role_variants;
+
+#[path = "roles.rs"]
+mod roles;
 
 pub fn strategy_row() -> Type {
     Type::struct_(
@@ -77,6 +81,30 @@ pub fn list_experts_datatable() -> Type {
     Type::vec(expert_row())
 }
 
+pub fn expert_application_document_row() -> Type {
+    Type::struct_(
+        "ExpertApplicationDocumentRow",
+        vec![
+            Field::new("document_id", Type::BigInt),
+            Field::new("name", Type::String),
+            Field::new("content_type", Type::String),
+            Field::new("size", Type::BigInt),
+            Field::new("created_at", Type::BigInt),
+        ],
+    )
+}
+pub fn expert_application_note_row() -> Type {
+    Type::struct_(
+        "ExpertApplicationNoteRow",
+        vec![
+            Field::new("status", Type::enum_ref("expert_application_status")),
+            Field::new("note", Type::optional(Type::String)),
+            Field::new("from_reviewer", Type::Boolean),
+            Field::new("created_at", Type::BigInt),
+        ],
+    )
+}
+
 pub fn user_deposit_withdraw_ledger_entry() -> Type {
     Type::struct_(
         "UserListDepositLedgerRow",
@@ -93,3 +121,14 @@ pub fn user_deposit_withdraw_ledger_entry() -> Type {
         ],
     )
 }
+
+/// `role` and every more privileged role, the same as `ensure_user_role`
+pub fn roles_at_least(role: &str) -> Vec<EnumVariant> {
+    let roles = role_variants();
+    let min = roles
+        .iter()
+        .find(|x| x.name == role)
+        .unwrap_or_else(|| panic!("unknown role {}", role))
+        .value;
+    roles.into_iter().filter(|x| x.value >= min).collect()
+}
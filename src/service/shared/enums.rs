@@ -1,6 +1,9 @@
 use crate::services::get_services;
 use model::types::*;
 
+#[path = "roles.rs"]
+mod roles;
+
 pub fn get_service_enum() -> Type {
     Type::enum_(
         "service".to_owned(),
@@ -12,17 +15,7 @@ pub fn get_service_enum() -> Type {
 }
 pub fn get_enums() -> Vec<Type> {
     vec![
-        Type::enum_(
-            "role".to_owned(),
-            vec![
-                EnumVariant::new("guest", 0),
-                EnumVariant::new("user", 1),
-                EnumVariant::new("expert", 2),
-                EnumVariant::new("admin", 3),
-                EnumVariant::new("developer", 4),
-                EnumVariant::new("whitelist", 5),
-            ],
-        ),
+        Type::enum_("role".to_owned(), roles::role_variants()),
         Type::enum_(
             "block_chain".to_owned(),
             vec![
@@ -68,6 +61,89 @@ pub fn get_enums() -> Vec<Type> {
                 EnumVariant::new("TransactionHash", 2),
             ],
         ),
+        Type::enum_(
+            "activity_report_format".to_owned(),
+            vec![EnumVariant::new("Json", 0), EnumVariant::new("Csv", 1)],
+        ),
+        Type::enum_(
+            "user_activity_event_type".to_owned(),
+            vec![
+                EnumVariant::new("Deposit", 0),
+                EnumVariant::new("Refund", 1),
+                EnumVariant::new("EscrowSpent", 2),
+                EnumVariant::new("BackStrategy", 3),
+                EnumVariant::new("ExitStrategy", 4),
+                EnumVariant::new("PoolAssetAdd", 5),
+                EnumVariant::new("PoolAssetRemove", 6),
+                EnumVariant::new("Withdraw", 7),
+                EnumVariant::new("WithdrawFee", 8),
+            ],
+        ),
+        Type::enum_(
+            "strategy_guard_rule_type".to_owned(),
+            vec![
+                EnumVariant::new("MaxDrawdown", 0),
+                EnumVariant::new("AssetStopLoss", 1),
+                EnumVariant::new("TakeProfit", 2),
+            ],
+        ),
+        Type::enum_(
+            "user_notification_kind".to_owned(),
+            vec![
+                EnumVariant::new("StrategyTrade", 0),
+                EnumVariant::new("BackStrategy", 1),
+                EnumVariant::new("ExitStrategy", 2),
+                EnumVariant::new("Refund", 3),
+                EnumVariant::new("ExpertNewStrategy", 4),
+                EnumVariant::new("StrategyChange", 5),
+                EnumVariant::new("StrategyGuard", 6),
+            ],
+        ),
+        Type::enum_(
+            "api_key_scope".to_owned(),
+            vec![
+                EnumVariant::new("ReadOnly", 0),
+                EnumVariant::new("Trade", 1),
+                EnumVariant::new("Withdraw", 2),
+            ],
+        ),
+        Type::enum_(
+            "admin_pending_action_status".to_owned(),
+            vec![
+                EnumVariant::new("Pending", 0),
+                EnumVariant::new("Approved", 1),
+                EnumVariant::new("Executed", 2),
+                EnumVariant::new("Failed", 3),
+            ],
+        ),
+        Type::enum_(
+            "expert_application_status".to_owned(),
+            vec![
+                EnumVariant::new("Draft", 0),
+                EnumVariant::new("Submitted", 1),
+                EnumVariant::new("InfoRequested", 2),
+                EnumVariant::new("Approved", 3),
+                EnumVariant::new("Rejected", 4),
+            ],
+        ),
+        Type::enum_(
+            "expert_sort_by".to_owned(),
+            vec![
+                EnumVariant::new("Newest", 0),
+                EnumVariant::new("Followers", 1),
+                EnumVariant::new("Reputation", 2),
+                EnumVariant::new("Consistency", 3),
+            ],
+        ),
+        Type::enum_(
+            "strategy_version_status".to_owned(),
+            vec![
+                EnumVariant::new("Scheduled", 0),
+                EnumVariant::new("Applied", 1),
+                EnumVariant::new("Failed", 2),
+                EnumVariant::new("Applying", 3),
+            ],
+        ),
         get_service_enum(),
     ]
 }
@@ -1,7 +1,7 @@
 use crate::logger::get_blockchain_logger;
 use crate::{
-    EitherTransport, EthereumRpcConnectionGuard, EthereumRpcConnectionPool, MultiChainAddressTable,
-    CONFIRMATIONS,
+    confirmations, EitherTransport, EthereumRpcConnectionGuard, EthereumRpcConnectionPool,
+    MultiChainAddressTable,
 };
 use eyre::{bail, ContextCompat};
 use gen::model::EnumBlockChain;
@@ -44,7 +44,7 @@ impl<T: Transport> ContractDeployer<T> {
             abi,
             options: Options::default(),
             max_retries: 1,
-            confirmations: CONFIRMATIONS, // not enough for ethereum. should be 14
+            confirmations: confirmations(), // not enough for ethereum. should be 14
             poll_interval: time::Duration::from_secs(3),
             linker: HashMap::default(),
             code: None,
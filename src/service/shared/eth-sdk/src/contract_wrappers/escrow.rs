@@ -310,6 +310,27 @@ impl<T: Transport> EscrowContract<T> {
         Ok(tx_hash)
     }
 
+    pub async fn estimate_gas_transfer_asset_from(
+        &self,
+        signer: impl Key,
+        proprietor: Address,
+        asset: Address,
+        amount: U256,
+        recipient: Address,
+    ) -> Result<U256, RpcCallError> {
+        let estimated_gas = self
+            .contract
+            .estimate_gas(
+                EscrowFunctions::TransferAssetFrom.as_str(),
+                (proprietor, asset, amount, recipient),
+                signer.address(),
+                Options::default(),
+            )
+            .await?;
+
+        Ok(estimated_gas)
+    }
+
     pub async fn transfer_asset_from(
         &self,
         conn: &EthereumRpcConnection,
@@ -2,7 +2,7 @@ use crate::evm::DexTrade;
 use eyre::*;
 use gen::database::*;
 use gen::model::EnumBlockChain;
-use lib::database::DbClient;
+use lib::database::{DbClient, DbTransaction};
 use rust_decimal::Decimal;
 use web3::types::Address;
 
@@ -139,8 +139,9 @@ pub async fn update_expert_listened_wallet_asset_balance_cache(
     Ok(())
 }
 
+/// runs in the transaction that moves the pool balances, so pool and per-user balances move together
 pub async fn update_user_strategy_pool_asset_balances_on_copy_trade(
-    db: &DbClient,
+    tx: &DbTransaction,
     blockchain: EnumBlockChain,
     strategy_pool_contract_id: i64,
     sp_sold_asset_address: Address,
@@ -150,7 +151,7 @@ pub async fn update_user_strategy_pool_asset_balances_on_copy_trade(
     sp_bought_asset_amount: Decimal,
 ) -> Result<()> {
     /* get strategy wallets that hold sold asset */
-    let strategy_wallet_sold_asset_rows = db
+    let strategy_wallet_sold_asset_rows = tx
         .execute(FunUserListUserStrategyPoolContractAssetBalancesReq {
             strategy_pool_contract_id: Some(strategy_pool_contract_id),
             token_address: Some(sp_sold_asset_address.into()),
@@ -170,7 +171,7 @@ pub async fn update_user_strategy_pool_asset_balances_on_copy_trade(
         let added_bought_amount =
             sp_bought_asset_amount * currently_owned_sold_asset / sp_sold_asset_previous_amount;
         /* update user strategy pool contract asset balances */
-        db.execute(FunUserUpsertUserStrategyPoolContractAssetBalanceReq {
+        tx.execute(FunUserUpsertUserStrategyPoolContractAssetBalanceReq {
             strategy_wallet_id: strategy_wallet_sold_asset_row.strategy_wallet_id,
             strategy_pool_contract_id,
             token_address: sp_sold_asset_address.into(),
@@ -179,7 +180,7 @@ pub async fn update_user_strategy_pool_asset_balances_on_copy_trade(
             new_balance: new_sold_asset_balance.into(),
         })
         .await?;
-        match db
+        match tx
             .execute(FunUserListUserStrategyPoolContractAssetBalancesReq {
                 strategy_pool_contract_id: Some(strategy_pool_contract_id),
                 token_address: Some(sp_bought_asset_address.into()),
@@ -193,7 +194,7 @@ pub async fn update_user_strategy_pool_asset_balances_on_copy_trade(
             Some(bought_asset_old_balance_row) => {
                 let bought_asset_old_balance = bought_asset_old_balance_row.balance;
                 /* if user already held bought asset, add to old balance */
-                db.execute(FunUserUpsertUserStrategyPoolContractAssetBalanceReq {
+                tx.execute(FunUserUpsertUserStrategyPoolContractAssetBalanceReq {
                     strategy_wallet_id: strategy_wallet_sold_asset_row.strategy_wallet_id,
                     strategy_pool_contract_id,
                     token_address: sp_bought_asset_address.into(),
@@ -205,7 +206,7 @@ pub async fn update_user_strategy_pool_asset_balances_on_copy_trade(
             }
             None => {
                 /* if user did not hold bought asset, use new amount */
-                db.execute(FunUserUpsertUserStrategyPoolContractAssetBalanceReq {
+                tx.execute(FunUserUpsertUserStrategyPoolContractAssetBalanceReq {
                     strategy_wallet_id: strategy_wallet_sold_asset_row.strategy_wallet_id,
                     strategy_pool_contract_id,
                     token_address: sp_bought_asset_address.into(),
@@ -218,7 +219,7 @@ pub async fn update_user_strategy_pool_asset_balances_on_copy_trade(
         }
 
         /* add entries to ledger */
-        db.execute(FunUserAddUserStrategyPoolContractAssetLedgerEntryReq {
+        tx.execute(FunUserAddUserStrategyPoolContractAssetLedgerEntryReq {
             strategy_wallet_id: strategy_wallet_sold_asset_row.strategy_wallet_id,
             strategy_pool_contract_id,
             token_address: sp_sold_asset_address.into(),
@@ -227,7 +228,7 @@ pub async fn update_user_strategy_pool_asset_balances_on_copy_trade(
             is_add: false,
         })
         .await?;
-        db.execute(FunUserAddUserStrategyPoolContractAssetLedgerEntryReq {
+        tx.execute(FunUserAddUserStrategyPoolContractAssetLedgerEntryReq {
             strategy_wallet_id: strategy_wallet_sold_asset_row.strategy_wallet_id,
             strategy_pool_contract_id,
             token_address: sp_bought_asset_address.into(),
@@ -237,6 +238,5 @@ pub async fn update_user_strategy_pool_asset_balances_on_copy_trade(
         })
         .await?;
     }
-
     Ok(())
 }
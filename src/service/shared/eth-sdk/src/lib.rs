@@ -1,5 +1,6 @@
 use crate::utils::{wait_for_confirmations_simple, wei_to_eth};
 use eyre::*;
+use lib::system_config::system_settings;
 use std::fmt::{Debug, Formatter};
 use std::time::Duration;
 use tracing::info;
@@ -129,7 +130,15 @@ pub const DEV_ACCOUNT_PRIV_KEY: &str =
     "bc0846d716105203f84e0c841a63faa5d7b20addff1975b1554485b5a13a8061";
 pub const DEV_ACCOUNT_ADDRESS: &str = "0x8A2D8E538E8544B77303E57950d526Da42D54af3";
 
-// TODO: increase confirmations to 14 when we go to public
-pub const CONFIRMATIONS: u64 = 2;
-pub const MAX_RETRIES: u64 = 8;
-pub const POLL_INTERVAL: Duration = Duration::from_secs(3);
+/// blocks to wait for a transaction, the `confirmations` system setting
+pub fn confirmations() -> u64 {
+    system_settings().confirmations
+}
+/// polls of a transaction receipt before giving up, the `max_retries` system setting
+pub fn max_retries() -> u64 {
+    system_settings().max_retries
+}
+/// time between polls of a transaction receipt, the `poll_interval_ms` system setting
+pub fn poll_interval() -> Duration {
+    system_settings().poll_interval()
+}
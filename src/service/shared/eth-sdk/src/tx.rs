@@ -19,6 +19,53 @@ pub async fn execute_transaction_and_ensure_success<Tx, Fut>(
     poll_interval: Duration,
     logger: &DynLogger,
 ) -> Result<H256>
+where
+    Tx: Fn() -> Fut,
+    Fut: std::future::Future<Output = Result<H256, RpcCallError>>,
+{
+    Ok(execute_transaction_and_track(
+        transaction,
+        conn,
+        confirmations,
+        max_retries,
+        poll_interval,
+        logger,
+    )
+    .await?)
+}
+
+/// why `execute_transaction_and_track` gave up on a transaction
+#[derive(Debug)]
+pub enum TransactionFailure {
+    /// nothing was published, or everything published was reverted or dropped
+    NotExecuted(Report),
+    /// `hash` was published but its outcome is unknown, it may still be mined
+    Unconfirmed { hash: H256, error: Report },
+}
+
+impl std::fmt::Display for TransactionFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            TransactionFailure::NotExecuted(error) => write!(f, "{}", error),
+            TransactionFailure::Unconfirmed { hash, error } => {
+                write!(f, "transaction {:?} is unconfirmed: {}", hash, error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for TransactionFailure {}
+
+/// like `execute_transaction_and_ensure_success`, but tells apart failures that moved no funds
+/// from a published transaction that may still be mined. such a transaction is never replayed
+pub async fn execute_transaction_and_track<Tx, Fut>(
+    transaction: Tx,
+    conn: &EthereumRpcConnection,
+    confirmations: u64,
+    max_retries: u64,
+    poll_interval: Duration,
+    logger: &DynLogger,
+) -> Result<H256, TransactionFailure>
 where
     Tx: Fn() -> Fut,
     Fut: std::future::Future<Output = Result<H256, RpcCallError>>,
@@ -44,25 +91,28 @@ where
                 }
                 Err(internal_error) => {
                     /* if the transaction failed to be published because of an internal error, return the error */
-                    return Err(internal_error.into());
+                    return Err(TransactionFailure::NotExecuted(internal_error.into()));
                 }
             }
         }
 
-        if maybe_hash.is_none() {
-            bail!(
-                "transaction failed to be published after {} attempts",
-                max_retries
-            );
-        }
-
-        let hash = maybe_hash.unwrap();
+        let hash = match maybe_hash {
+            Some(hash) => hash,
+            None => {
+                return Err(TransactionFailure::NotExecuted(eyre!(
+                    "transaction failed to be published after {} attempts",
+                    max_retries
+                )))
+            }
+        };
 
         logger.log(format!(
             "transaction {:?} sent, waiting for confirmations",
             hash
         ));
 
+        let mut last_provider_error = None;
+        let mut replay = false;
         for _confirmation_attempt in 0..max_retries {
             let confirmation_result = wait_for_confirmations(
                 &conn.eth(),
@@ -84,6 +134,7 @@ where
                         "provider error {:?} confirming transaction {:?}, retrying confirmation",
                         err, hash
                     ));
+                    last_provider_error = Some(err);
                     continue;
                 }
                 Err(ConfirmationError::TransactionRevertedAfterConfirmations(_))
@@ -93,20 +144,39 @@ where
                         "transaction {:?} failed after confirmations, replaying transaction",
                         hash
                     ));
+                    replay = true;
                     break;
                 }
+                Err(error @ ConfirmationError::TransactionReverted(_)) => {
+                    /* if the transaction was reverted before confirmations, return the error */
+                    return Err(TransactionFailure::NotExecuted(error.into()));
+                }
                 Err(error) => {
-                    /* if the transaction was reverted or not found before confirmations, return the error */
-                    return Err(error.into());
+                    /* if the transaction was not found before confirmations, it may still be mined */
+                    return Err(TransactionFailure::Unconfirmed {
+                        hash,
+                        error: error.into(),
+                    });
                 }
             }
         }
+        if !replay {
+            /* the provider never told us what happened, replaying could execute it twice */
+            return Err(TransactionFailure::Unconfirmed {
+                hash,
+                error: eyre!(
+                    "transaction failed to be confirmed after {} attempts: {:?}",
+                    max_retries,
+                    last_provider_error
+                ),
+            });
+        }
     }
 
-    bail!(
+    Err(TransactionFailure::NotExecuted(eyre!(
         "transaction failed to be confirmed after {} attempts",
         max_retries
-    )
+    )))
 }
 
 #[derive(Debug)]
@@ -0,0 +1,72 @@
+use gen::model::*;
+use lib::types::*;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// domain events the watcher and the user service publish on the `lib::event_bus::EventBus`,
+/// consumed by every user service to push to or close its connections
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type", content = "data")]
+pub enum DomainEvent {
+    /// a deposit to or withdrawal from the escrow was recorded in the user's ledger
+    EscrowCredited(EscrowCreditedEvent),
+    /// the strategy pool mirrored a trade of the expert
+    CopyTradeExecuted(StrategyTradeEvent),
+    StrategyGuardTriggered(StrategyGuardEvent),
+    /// the user's strategy tokens were redeemed back to their wallet
+    RedeemProcessed(RedeemProcessedEvent),
+    /// a login session was revoked, its connections must be closed on every instance
+    SessionRevoked(SessionRevokedEvent),
+    /// an API key was revoked, its connections must be closed on every instance
+    ApiKeyRevoked(ApiKeyRevokedEvent),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EscrowCreditedEvent {
+    pub user_id: i64,
+    pub entry: UserListDepositLedgerRow,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StrategyTradeEvent {
+    pub strategy_id: i64,
+    pub blockchain: EnumBlockChain,
+    #[serde(with = "WithBlockchainAddress")]
+    pub token_in_address: Address,
+    #[serde(with = "WithBlockchainAddress")]
+    pub token_out_address: Address,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub amount_in: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub amount_out: Decimal,
+    #[serde(with = "WithBlockchainTransactionHash")]
+    pub transaction_hash: H256,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RedeemProcessedEvent {
+    pub user_id: i64,
+    pub strategy_id: i64,
+    pub blockchain: EnumBlockChain,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub amount: Decimal,
+    #[serde(with = "WithBlockchainTransactionHash")]
+    pub transaction_hash: H256,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionRevokedEvent {
+    pub user_id: i64,
+    pub user_session_id: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiKeyRevokedEvent {
+    pub user_id: i64,
+    pub api_key_id: i64,
+}
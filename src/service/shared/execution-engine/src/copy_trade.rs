@@ -5,8 +5,8 @@ use eth_sdk::pancake_swap::pair_paths::parse_pancake_swap_dex_path;
 use eth_sdk::pancake_swap::PancakePairPathSet;
 use eth_sdk::utils::decimal_to_u256;
 use eth_sdk::{
-    EitherTransport, EthereumRpcConnection, EthereumRpcConnectionPool, ScaledMath, CONFIRMATIONS,
-    MAX_RETRIES, POLL_INTERVAL,
+    confirmations, max_retries, poll_interval, EitherTransport, EthereumRpcConnection,
+    EthereumRpcConnectionPool, ScaledMath,
 };
 use eyre::*;
 use gen::database::*;
@@ -123,6 +123,87 @@ pub struct CopyTradePlan {
     pub trades: Vec<CopyTradeEntry>,
 }
 
+/// per-strategy limits on the size of a single copy trade
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CopyTradeLimits {
+    /// trades worth less than this in USD are skipped
+    pub min_trade_usd: Option<Decimal>,
+    /// trades are capped to this fraction of the strategy pool value
+    pub max_trade_aum_ratio: Option<Decimal>,
+}
+impl CopyTradeLimits {
+    pub fn is_empty(&self) -> bool {
+        self.min_trade_usd.is_none() && self.max_trade_aum_ratio.is_none()
+    }
+    /// returns the amount of token_in that may be spent, or None when the trade is too small to copy
+    pub fn limit_amount_in(
+        &self,
+        amount_in: Decimal,
+        token_in_price: Decimal,
+        strategy_total_value: Decimal,
+    ) -> Option<Decimal> {
+        let mut amount_in = amount_in;
+        if let Some(ratio) = self.max_trade_aum_ratio {
+            if !token_in_price.is_zero() {
+                amount_in = amount_in.min(strategy_total_value * ratio / token_in_price);
+            }
+        }
+        if let Some(min_trade_usd) = self.min_trade_usd {
+            if amount_in * token_in_price < min_trade_usd {
+                return None;
+            }
+        }
+        Some(amount_in)
+    }
+}
+
+/// drops trades below the minimum size and scales down trades above the maximum size
+pub fn apply_copy_trade_limits(
+    plan: CopyTradePlan,
+    limits: CopyTradeLimits,
+    asset_prices: &HashMap<Address, f64>,
+    asset_decimals: &HashMap<Address, u32>,
+    strategy_total_value: Decimal,
+) -> Result<CopyTradePlan> {
+    let mut trades = vec![];
+    for trade in plan.trades {
+        let token_in_price = asset_prices
+            .get(&trade.token_in)
+            .and_then(|x| Decimal::from_f64(*x))
+            .with_context(|| format!("could not get price of asset {:?}", trade.token_in))?;
+        let Some(amount_in) =
+            limits.limit_amount_in(trade.amount_in, token_in_price, strategy_total_value)
+        else {
+            continue;
+        };
+        if amount_in == trade.amount_in {
+            trades.push(trade);
+            continue;
+        }
+        let scale = amount_in / trade.amount_in;
+        let amount_out = trade.amount_out * scale;
+        trades.push(CopyTradeEntry {
+            amount_in,
+            amount_in_native: decimal_to_u256(
+                amount_in,
+                *asset_decimals
+                    .get(&trade.token_in)
+                    .context("no token_in decimals")?,
+            ),
+            amount_out,
+            amount_out_native: decimal_to_u256(
+                amount_out,
+                *asset_decimals
+                    .get(&trade.token_out)
+                    .context("no token_out decimals")?,
+            ),
+            trade_ratio: trade.trade_ratio * scale,
+            ..trade
+        });
+    }
+    Ok(CopyTradePlan { trades })
+}
+
 /// calculate the value of each asset in USD
 pub fn calculate_asset_values(
     amounts: HashMap<Address, Decimal>,
@@ -152,6 +233,29 @@ pub fn convert_amount_to_ratio(
     Ok((total_value, ratios))
 }
 
+/// largest difference between the strategy ratio and the target ratio of any asset, in terms of USD
+pub fn calculate_max_ratio_drift(
+    target_asset_amounts: HashMap<Address, Decimal>,
+    strategy_asset_amounts: HashMap<Address, Decimal>,
+    asset_prices: HashMap<Address, f64>,
+) -> Result<Decimal> {
+    let (_, target_asset_ratios) =
+        convert_amount_to_ratio(target_asset_amounts, asset_prices.clone())?;
+    let (_, strategy_asset_ratios) = convert_amount_to_ratio(strategy_asset_amounts, asset_prices)?;
+    let drift = target_asset_ratios
+        .keys()
+        .chain(strategy_asset_ratios.keys())
+        .unique()
+        .map(|key| {
+            let target_ratio = target_asset_ratios.get(key).copied().unwrap_or_default();
+            let strategy_ratio = strategy_asset_ratios.get(key).copied().unwrap_or_default();
+            (strategy_ratio - target_ratio).abs()
+        })
+        .max()
+        .unwrap_or_default();
+    Ok(drift)
+}
+
 /// core algorithm of copy trading
 /// also used in backing to calculate which assets to buy
 pub fn calculate_copy_trade_plan(
@@ -291,9 +395,9 @@ pub async fn execute_copy_trade_plan(
     let trade_hash = execute_transaction_and_ensure_success(
         copy_trade_transaction,
         &conn,
-        CONFIRMATIONS,
-        MAX_RETRIES,
-        POLL_INTERVAL,
+        confirmations(),
+        max_retries(),
+        poll_interval(),
         &DynLogger::empty(),
     )
     .await?;
@@ -383,6 +487,86 @@ pub async fn execute_copy_trade(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_max_ratio_drift() -> Result<()> {
+        let token_a = Address::from_low_u64_be(1);
+        let token_b = Address::from_low_u64_be(2);
+        let token_c = Address::from_low_u64_be(3);
+        let mut target_amounts = HashMap::new();
+        target_amounts.insert(token_a, 50.into());
+        target_amounts.insert(token_b, 50.into());
+        let mut strategy_amounts = HashMap::new();
+        strategy_amounts.insert(token_a, 70.into());
+        strategy_amounts.insert(token_b, 20.into());
+        strategy_amounts.insert(token_c, 25.into());
+        let mut prices = HashMap::new();
+        prices.insert(token_a, 1.0);
+        prices.insert(token_b, 1.0);
+        prices.insert(token_c, 0.4);
+        let drift = calculate_max_ratio_drift(target_amounts, strategy_amounts, prices)?;
+        // strategy holds 70% of token_a and 20% of token_b against a 50/50 target
+        assert_eq!(drift, Decimal::new(3, 1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_copy_trade_limits() -> Result<()> {
+        let token_a = Address::from_low_u64_be(1);
+        let token_b = Address::from_low_u64_be(2);
+        let limits = CopyTradeLimits {
+            min_trade_usd: Some(10.into()),
+            max_trade_aum_ratio: Some(Decimal::new(1, 1)),
+        };
+        // a 1000 USD pool can trade at most 100 USD, which is 50 of a token priced at 2 USD
+        assert_eq!(
+            limits.limit_amount_in(80.into(), 2.into(), 1000.into()),
+            Some(50.into())
+        );
+        assert_eq!(
+            limits.limit_amount_in(30.into(), 2.into(), 1000.into()),
+            Some(30.into())
+        );
+        assert_eq!(
+            limits.limit_amount_in(4.into(), 2.into(), 1000.into()),
+            None
+        );
+
+        let plan = CopyTradePlan {
+            trades: vec![
+                CopyTradeEntry {
+                    blockchain: EnumBlockChain::BscMainnet,
+                    dex: EnumDex::PancakeSwap,
+                    token_in: token_a,
+                    token_out: token_b,
+                    amount_in: 80.into(),
+                    amount_in_native: decimal_to_u256(80.into(), 18),
+                    amount_out: 160.into(),
+                    amount_out_native: decimal_to_u256(160.into(), 18),
+                    trade_ratio: Decimal::new(16, 2),
+                },
+                CopyTradeEntry {
+                    blockchain: EnumBlockChain::BscMainnet,
+                    dex: EnumDex::PancakeSwap,
+                    token_in: token_b,
+                    token_out: token_a,
+                    amount_in: 5.into(),
+                    amount_in_native: decimal_to_u256(5.into(), 18),
+                    amount_out: Decimal::new(25, 1),
+                    amount_out_native: decimal_to_u256(Decimal::new(25, 1), 18),
+                    trade_ratio: Decimal::new(5, 3),
+                },
+            ],
+        };
+        let prices = HashMap::from([(token_a, 2.0), (token_b, 1.0)]);
+        let decimals = HashMap::from([(token_a, 18), (token_b, 18)]);
+        let plan = apply_copy_trade_limits(plan, limits, &prices, &decimals, 1000.into())?;
+        assert_eq!(plan.trades.len(), 1);
+        assert_eq!(plan.trades[0].amount_in, Decimal::from(50));
+        assert_eq!(plan.trades[0].amount_out, Decimal::from(100));
+        assert_eq!(plan.trades[0].trade_ratio, Decimal::new(1, 1));
+        Ok(())
+    }
+
     #[test]
     fn test_copy_trading_empty() -> Result<()> {
         let expert_amounts = HashMap::new();
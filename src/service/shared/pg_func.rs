@@ -120,6 +120,7 @@ pub fn expert_row_type() -> Type {
             Field::new("strategy_count", Type::BigInt),
             Field::new("description", Type::optional(Type::String)),
             Field::new("social_media", Type::optional(Type::String)),
+            Field::new("consistent_score", Type::optional(Type::Numeric)),
             Field::new("risk_score", Type::optional(Type::Numeric)),
             Field::new("reputation_score", Type::optional(Type::Numeric)),
             Field::new("aum", Type::optional(Type::Numeric)),
@@ -149,6 +150,7 @@ pub fn get_expert(followed: &str) -> String {
         (SELECT COUNT(DISTINCT d.fkey_user_id) FROM tbl.strategy AS d WHERE d.fkey_user_id = u.pkey_id) AS strategy_count,
         e.description                                             AS description,
         e.social_media                                            AS social_media,
+        e.consistent_score                                        AS consistent_score,
         e.risk_score                                              AS risk_score,
         e.reputation_score                                        AS reputation_score,
         e.aum                                                     AS aum,
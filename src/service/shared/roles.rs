@@ -0,0 +1,14 @@
+use model::types::*;
+
+/// the variants of the `role` enum, from the least to the most privileged. `get_enums` and the
+/// roles of the endpoints are both built from them
+pub fn role_variants() -> Vec<EnumVariant> {
+    vec![
+        EnumVariant::new("guest", 0),
+        EnumVariant::new("user", 1),
+        EnumVariant::new("expert", 2),
+        EnumVariant::new("admin", 3),
+        EnumVariant::new("developer", 4),
+        EnumVariant::new("whitelist", 5),
+    ]
+}
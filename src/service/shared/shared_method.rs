@@ -1,17 +1,22 @@
+use api::cmc::CoinMarketCap;
 use api::AssetInfoClient;
-use eth_sdk::{BlockchainCoinAddresses, EscrowAddresses};
+use eth_sdk::erc20::Erc20Token;
+use eth_sdk::{BlockchainCoinAddresses, EscrowAddresses, EthereumRpcConnection, ScaledMath};
 use eyre::*;
 use eyre::{anyhow, ensure, ContextCompat};
 use gen::database::*;
-use gen::model::{EnumBlockChain, EnumErrorCode, EnumRole, ListExpertsRow, ListStrategiesRow};
+use gen::model::{
+    EnumBlockChain, EnumErrorCode, EnumRole, ExpertApplicationDocumentRow,
+    ExpertApplicationNoteRow, ListExpertsRow, ListStrategiesRow,
+};
 use lib::database::DbClient;
+use lib::system_config::{set_system_settings, Reloadable, SystemSettings};
 use lib::toolbox::{CustomError, RequestContext};
-use lib::ws::WsServerConfig;
 use num_traits::{FromPrimitive, ToPrimitive, Zero};
 use rust_decimal::Decimal;
 use std::sync::Arc;
 use tracing::info;
-use web3::types::{Address, H256};
+use web3::types::{Address, H256, U256};
 
 pub fn ensure_user_role(ctx: RequestContext, role: EnumRole) -> Result<()> {
     let ctx_role = EnumRole::from_u32(ctx.role).context("Invalid role")?;
@@ -106,7 +111,7 @@ pub fn convert_expert_db_to_api(x: FunUserExpertRowType) -> ListExpertsRow {
         social_media: x.social_media.unwrap_or_default(),
         risk_score: x.risk_score.unwrap_or_default(),
         reputation_score: x.reputation_score.unwrap_or_default(),
-        consistent_score: 0.5,
+        consistent_score: x.consistent_score.unwrap_or_default(),
         aum: x.aum.unwrap_or_default(),
         joined_at: x.joined_at,
         requested_at: x.requested_at.unwrap_or_default(),
@@ -118,6 +123,40 @@ pub fn convert_expert_db_to_api(x: FunUserExpertRowType) -> ListExpertsRow {
     }
 }
 
+/// the documents and review notes of an expert application
+pub async fn load_expert_application_details(
+    db: &DbClient,
+    application_id: i64,
+) -> Result<(
+    Vec<ExpertApplicationDocumentRow>,
+    Vec<ExpertApplicationNoteRow>,
+)> {
+    let documents = db
+        .execute(FunUserListExpertApplicationDocumentsReq { application_id })
+        .await?
+        .into_iter()
+        .map(|x| ExpertApplicationDocumentRow {
+            document_id: x.document_id,
+            name: x.name,
+            content_type: x.content_type,
+            size: x.size,
+            created_at: x.created_at,
+        })
+        .collect();
+    let notes = db
+        .execute(FunUserListExpertApplicationNotesReq { application_id })
+        .await?
+        .into_iter()
+        .map(|x| ExpertApplicationNoteRow {
+            status: x.status,
+            note: x.note,
+            from_reviewer: x.from_reviewer,
+            created_at: x.created_at,
+        })
+        .collect();
+    Ok((documents, notes))
+}
+
 pub async fn load_coin_addresses(db: &DbClient) -> Result<Arc<BlockchainCoinAddresses>> {
     let mut coin_addresses = BlockchainCoinAddresses::empty();
     let coins_from_db = db
@@ -155,7 +194,22 @@ pub async fn load_escrow_address(db: &DbClient) -> Result<Arc<EscrowAddresses>>
     Ok(Arc::new(this))
 }
 
-pub async fn load_allow_domain_urls(db: &DbClient, config: &mut WsServerConfig) -> Result<()> {
+/// loads the typed settings of `tbl.system_setting` into `system_settings()`
+pub async fn load_system_settings(db: DbClient) -> Result<()> {
+    let rows = db.execute(FunAdminListSystemSettingsReq {}).await?;
+    set_system_settings(SystemSettings::from_entries(
+        rows.into_iter().map(|x| (x.key, x.value)),
+    ));
+    Ok(())
+}
+
+/// loads the system settings and the domains allowed by `tbl.system_config`, pass it to
+/// `watch_system_config` to keep both current
+pub async fn load_system_config(
+    db: DbClient,
+    allow_cors_urls: Reloadable<Option<Vec<String>>>,
+) -> Result<()> {
+    load_system_settings(db.clone()).await?;
     let system_config = db
         .execute(FunAdminGetSystemConfigReq { config_id: 0 })
         .await?
@@ -164,7 +218,7 @@ pub async fn load_allow_domain_urls(db: &DbClient, config: &mut WsServerConfig)
             platform_fee: None,
             allow_domain_urls: None,
         });
-    config.allow_cors_urls = Arc::new(
+    allow_cors_urls.set(
         system_config
             .allow_domain_urls
             .map(|x| x.split(";").map(|x| x.to_string()).collect()),
@@ -180,40 +234,43 @@ pub async fn update_strategy_token_balances_and_ledger_exit_strategy(
     redeem_transaction_hash: H256,
     redeemed_amount: Decimal,
 ) -> Result<()> {
-    /* update user strategy token ledger */
-    db.execute(FunUserExitStrategyReq {
-        user_id,
-        strategy_id,
-        // TODO: calculate value of sp tokens exit in usdc
-        quantity: Decimal::zero(),
-        blockchain,
-        transaction_hash: redeem_transaction_hash.into(),
-        redeem_sp_tokens: redeemed_amount,
-    })
-    .await?;
+    /* ledger and balance are updated together, or not at all */
+    db.transaction(|tx| async move {
+        /* update user strategy token ledger */
+        tx.execute(FunUserExitStrategyReq {
+            user_id,
+            strategy_id,
+            // TODO: calculate value of sp tokens exit in usdc
+            quantity: Decimal::zero(),
+            blockchain,
+            transaction_hash: redeem_transaction_hash.into(),
+            redeem_sp_tokens: redeemed_amount,
+        })
+        .await?;
 
-    /* update user strategy token balance */
-    let user_strategy_balance = db
-        .execute(FunWatcherListUserStrategyBalanceReq {
-            limit: 1,
-            offset: 0,
-            strategy_id: Some(strategy_id),
-            user_id: Some(user_id),
-            blockchain: Some(blockchain),
+        /* update user strategy token balance */
+        let user_strategy_balance = tx
+            .execute(FunWatcherListUserStrategyBalanceReq {
+                limit: 1,
+                offset: 0,
+                strategy_id: Some(strategy_id),
+                user_id: Some(user_id),
+                blockchain: Some(blockchain),
+            })
+            .await?
+            .first(|x| x.balance)
+            .context("could not get user strategy token balance from database on exit strategy")?;
+        tx.execute(FunWatcherUpsertUserStrategyBalanceReq {
+            user_id,
+            strategy_id,
+            blockchain,
+            old_balance: user_strategy_balance,
+            new_balance: user_strategy_balance - redeemed_amount,
         })
-        .await?
-        .first(|x| x.balance)
-        .context("could not get user strategy token balance from database on exit strategy")?;
-    db.execute(FunWatcherUpsertUserStrategyBalanceReq {
-        user_id,
-        strategy_id,
-        blockchain,
-        old_balance: user_strategy_balance,
-        new_balance: user_strategy_balance - redeemed_amount,
+        .await?;
+        Ok(())
     })
-    .await?;
-
-    Ok(())
+    .await
 }
 
 pub async fn update_asset_balances_and_ledger_exit_strategy(
@@ -227,73 +284,112 @@ pub async fn update_asset_balances_and_ledger_exit_strategy(
     assets_withdrawn: Vec<Address>,
     amounts_withdrawn: Vec<Decimal>,
 ) -> Result<()> {
-    for idx in 0..assets_withdrawn.len() {
-        /* update per-user strategy pool asset balance & ledger */
-        let asset = assets_withdrawn[idx];
-        let amount = amounts_withdrawn[idx];
-        let asset_old_balance = db
-            .execute(FunUserListUserStrategyPoolContractAssetBalancesReq {
-                strategy_pool_contract_id: Some(strategy_pool_contract_id),
-                user_id: Some(user_id),
-                strategy_wallet_id: Some(strategy_wallet_id),
-                token_address: Some(asset.into()),
-                blockchain: Some(blockchain),
+    let assets_withdrawn = &assets_withdrawn;
+    let amounts_withdrawn = &amounts_withdrawn;
+    /* balances and ledgers of all withdrawn assets are updated together, or not at all */
+    db.transaction(|tx| async move {
+        for idx in 0..assets_withdrawn.len() {
+            /* update per-user strategy pool asset balance & ledger */
+            let asset = assets_withdrawn[idx];
+            let amount = amounts_withdrawn[idx];
+            let asset_old_balance = tx
+                .execute(FunUserListUserStrategyPoolContractAssetBalancesReq {
+                    strategy_pool_contract_id: Some(strategy_pool_contract_id),
+                    user_id: Some(user_id),
+                    strategy_wallet_id: Some(strategy_wallet_id),
+                    token_address: Some(asset.into()),
+                    blockchain: Some(blockchain),
+                })
+                .await?
+                .into_result()
+                .context("user strategy pool asset balance not found")?
+                .balance;
+
+            tx.execute(FunUserUpsertUserStrategyPoolContractAssetBalanceReq {
+                strategy_pool_contract_id,
+                strategy_wallet_id,
+                token_address: asset.into(),
+                blockchain,
+                old_balance: asset_old_balance,
+                new_balance: asset_old_balance - amount,
             })
-            .await?
-            .into_result()
-            .context("user strategy pool asset balance not found")?
-            .balance;
+            .await?;
 
-        db.execute(FunUserUpsertUserStrategyPoolContractAssetBalanceReq {
-            strategy_pool_contract_id,
-            strategy_wallet_id,
-            token_address: asset.into(),
-            blockchain,
-            old_balance: asset_old_balance,
-            new_balance: asset_old_balance - amount,
-        })
-        .await?;
+            tx.execute(FunUserAddUserStrategyPoolContractAssetLedgerEntryReq {
+                strategy_pool_contract_id,
+                strategy_wallet_id,
+                token_address: asset.into(),
+                blockchain,
+                amount: amount.into(),
+                is_add: false,
+            })
+            .await?;
 
-        db.execute(FunUserAddUserStrategyPoolContractAssetLedgerEntryReq {
-            strategy_pool_contract_id,
-            strategy_wallet_id,
-            token_address: asset.into(),
-            blockchain,
-            amount: amount.into(),
-            is_add: false,
-        })
-        .await?;
+            /* update strategy pool asset balances & ledger */
+            let old_asset_balance_row = tx
+                .execute(FunWatcherListStrategyPoolContractAssetBalancesReq {
+                    strategy_pool_contract_id: Some(strategy_pool_contract_id),
+                    strategy_id: Some(strategy_id),
+                    blockchain: Some(blockchain),
+                    token_address: Some(asset.into()),
+                })
+                .await?
+                .into_result()
+                .context("strategy pool balance of redeemed asset not found")?;
 
-        /* update strategy pool asset balances & ledger */
-        let old_asset_balance_row = db
-            .execute(FunWatcherListStrategyPoolContractAssetBalancesReq {
-                strategy_pool_contract_id: Some(strategy_pool_contract_id),
-                strategy_id: Some(strategy_id),
-                blockchain: Some(blockchain),
-                token_address: Some(asset.into()),
+            tx.execute(FunWatcherUpsertStrategyPoolContractAssetBalanceReq {
+                strategy_pool_contract_id,
+                token_address: asset.into(),
+                blockchain,
+                new_balance: old_asset_balance_row.balance - amount,
             })
-            .await?
-            .into_result()
-            .context("strategy pool balance of redeemed asset not found")?;
+            .await?;
 
-        db.execute(FunWatcherUpsertStrategyPoolContractAssetBalanceReq {
-            strategy_pool_contract_id,
-            token_address: asset.into(),
-            blockchain,
-            new_balance: old_asset_balance_row.balance - amount,
-        })
-        .await?;
+            tx.execute(FunUserAddStrategyPoolContractAssetLedgerEntryReq {
+                strategy_pool_contract_id,
+                token_address: asset.into(),
+                blockchain,
+                amount: amount.into(),
+                is_add: false,
+                transaction_hash: withdraw_transaction_hash.into(),
+            })
+            .await?;
+        }
+        Ok(())
+    })
+    .await
+}
 
-        db.execute(FunUserAddStrategyPoolContractAssetLedgerEntryReq {
-            strategy_pool_contract_id,
-            token_address: asset.into(),
-            blockchain,
-            amount: amount.into(),
-            is_add: false,
-            transaction_hash: withdraw_transaction_hash.into(),
-        })
+pub async fn calculate_gas_fee_in_tokens(
+    cmc: &CoinMarketCap,
+    conn: &EthereumRpcConnection,
+    blockchain: &EnumBlockChain,
+    token_address: Address,
+    total_gas_fee_in_wei: U256,
+) -> Result<U256> {
+    let token_contract = Erc20Token::new(conn.clone(), token_address)?;
+    let token_decimals = token_contract.decimals().await?;
+    let token_symbol = token_contract.symbol().await?;
+
+    let native_symbol = match blockchain {
+        EnumBlockChain::EthereumMainnet => "ETH",
+        EnumBlockChain::EthereumGoerli => "ETH",
+        EnumBlockChain::EthereumSepolia => "ETH",
+        EnumBlockChain::BscMainnet => "BNB",
+        EnumBlockChain::BscTestnet => "BNB",
+        _ => bail!("unsupported blockchain"),
+    };
+
+    /* get token value of 1 native token */
+    let native_price = cmc
+        .get_quote_price_by_symbol(native_symbol.to_string(), token_symbol)
         .await?;
-    }
 
-    Ok(())
+    let gas_fee_in_tokens = total_gas_fee_in_wei
+        /* native price doesn't consider decimals */
+        .mul_f64(native_price)?
+        /* so multiply native price without decimals by proportion the token decimals take in native decimals */
+        .mul_div(U256::exp10(token_decimals.as_usize()), U256::exp10(18))?;
+
+    Ok(gas_fee_in_tokens)
 }
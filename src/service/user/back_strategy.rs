@@ -9,8 +9,8 @@ use eth_sdk::strategy_pool::StrategyPoolContract;
 use eth_sdk::strategy_wallet::StrategyWalletContract;
 use eth_sdk::utils::{decimal_to_u256, u256_to_decimal};
 use eth_sdk::{
-    DexAddresses, EitherTransport, EthereumRpcConnection, TransactionFetcher, CONFIRMATIONS,
-    MAX_RETRIES, POLL_INTERVAL,
+    confirmations, max_retries, poll_interval, DexAddresses, EitherTransport,
+    EthereumRpcConnection, TransactionFetcher,
 };
 use eth_sdk::{StrategyPoolHeraldAddresses, StrategyWalletHeraldAddresses};
 use execution_engine::copy_trade::{
@@ -687,9 +687,9 @@ pub async fn user_back_strategy(
         execute_transaction_and_ensure_success(
             transfer_asset_from_transaction,
             &conn,
-            CONFIRMATIONS,
-            MAX_RETRIES,
-            POLL_INTERVAL,
+            confirmations(),
+            max_retries(),
+            poll_interval(),
             &logger,
         )
         .await?;
@@ -707,6 +707,7 @@ pub async fn user_back_strategy(
             token_address: token_address.into(),
             escrow_contract_address: escrow_contract.address().into(),
             is_withdraw: false,
+            is_fee: false,
         })
         .await?;
 
@@ -753,9 +754,9 @@ pub async fn user_back_strategy(
     execute_transaction_and_ensure_success(
         approve_transaction,
         &conn,
-        CONFIRMATIONS,
-        MAX_RETRIES,
-        POLL_INTERVAL,
+        confirmations(),
+        max_retries(),
+        poll_interval(),
         &logger,
     )
     .await?;
@@ -793,9 +794,9 @@ pub async fn user_back_strategy(
                 execute_transaction_and_ensure_success(
                     approve_trasanction,
                     &conn,
-                    CONFIRMATIONS,
-                    MAX_RETRIES,
-                    POLL_INTERVAL,
+                    confirmations(),
+                    max_retries(),
+                    poll_interval(),
                     &logger,
                 )
                 .await?;
@@ -826,9 +827,9 @@ pub async fn user_back_strategy(
                 let trade_hash = execute_transaction_and_ensure_success(
                     copy_trade_transaction,
                     &conn,
-                    CONFIRMATIONS,
-                    MAX_RETRIES,
-                    POLL_INTERVAL,
+                    confirmations(),
+                    max_retries(),
+                    poll_interval(),
                     &logger,
                 )
                 .await?;
@@ -875,9 +876,9 @@ pub async fn user_back_strategy(
                 execute_transaction_and_ensure_success(
                     approve_transaction,
                     &conn,
-                    CONFIRMATIONS,
-                    MAX_RETRIES,
-                    POLL_INTERVAL,
+                    confirmations(),
+                    max_retries(),
+                    poll_interval(),
                     &logger,
                 )
                 .await?;
@@ -888,10 +889,10 @@ pub async fn user_back_strategy(
 
     let CalculateUserBackStrategyCalculateAmountToMintResult {
         // back_amount_minus_fees,
-        /* TODO: fees are now in the pending wallet, we should add it to the database */
-        /* TODO: add table to register treasury fees and strategy fees */
-        // fees,
         // we discard this value because it's not really exactly the value
+        /* TODO: fees are now in the pending wallet, they are only recorded on the back ledger */
+        /* TODO: add table to register treasury fees and strategy fees */
+        fees,
         strategy_token_to_mint,
         strategy_pool_assets_bought_for_this_backer,
         token_decimals,
@@ -960,9 +961,9 @@ pub async fn user_back_strategy(
     let deposit_transaction_hash = execute_transaction_and_ensure_success(
         deposit_transaction,
         &conn,
-        CONFIRMATIONS,
-        MAX_RETRIES,
-        POLL_INTERVAL,
+        confirmations(),
+        max_retries(),
+        poll_interval(),
         &logger,
     )
     .await?;
@@ -1078,6 +1079,7 @@ pub async fn user_back_strategy(
             blockchain,
             transaction_hash: deposit_transaction_hash.into(),
             earn_sp_tokens: strategy_token_to_mint.into(),
+            fees,
         })
         .await?
         .into_result()
@@ -1696,6 +1698,7 @@ mod tests {
             blockchain: EnumBlockChain::LocalNet,
             transaction_hash: deposit_hash.into(),
             earn_sp_tokens: strategy_tokens_minted.into(),
+            fees: U256::zero().into(),
         })
         .await?;
 
@@ -1,8 +1,8 @@
 use model::endpoint::*;
-use model::types::{Field, Type};
+use model::types::{EnumVariant, Field, Type};
 use shared_endpoints::{
-    list_experts_datatable, list_strategies_datatable, strategy_row,
-    user_deposit_withdraw_ledger_entry,
+    expert_application_document_row, expert_application_note_row, list_experts_datatable,
+    list_strategies_datatable, roles_at_least, strategy_row, user_deposit_withdraw_ledger_entry,
 };
 
 #[path = "../shared/endpoints.rs"]
@@ -15,6 +15,7 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
             vec![Field::new("strategy_id", Type::BigInt)],
             vec![Field::new("success", Type::Boolean)],
         )
+        .with_roles(roles_at_least("user"))
         .with_description("User follows a strategy"),
         EndpointSchema::new(
             "UserListFollowedStrategies",
@@ -28,13 +29,16 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                 Field::new("strategies", list_strategies_datatable()),
             ],
         )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("ReadOnly"))
         .with_description("User lists followed strategies"),
         EndpointSchema::new(
             "UserUnfollowStrategy",
             20060,
             vec![Field::new("strategy_id", Type::BigInt)],
             vec![Field::new("success", Type::Boolean)],
-        ),
+        )
+        .with_roles(roles_at_least("user")),
         EndpointSchema::new(
             "UserListStrategies",
             20061,
@@ -57,6 +61,8 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                 Field::new("strategies", list_strategies_datatable()),
             ],
         )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("ReadOnly"))
         .with_description("User lists strategies"),
         EndpointSchema::new(
             "UserListTopPerformingStrategies",
@@ -70,6 +76,8 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                 Field::new("strategies", list_strategies_datatable()),
             ],
         )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("ReadOnly"))
         .with_description("User lists top performing strategies"),
         EndpointSchema::new(
             "UserListStrategyBackers",
@@ -94,7 +102,9 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                     ),
                 ),
             ],
-        ),
+        )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("ReadOnly")),
         EndpointSchema::new(
             "UserListStrategyFollowers",
             20065,
@@ -118,7 +128,9 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                     ),
                 ),
             ],
-        ),
+        )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("ReadOnly")),
         EndpointSchema::new(
             "UserGetStrategy",
             20062,
@@ -187,6 +199,8 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                 Field::new("whitelisted_tokens", Type::vec(Type::String)),
             ],
         )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("ReadOnly"))
         .with_description("User gets a strategy"),
         EndpointSchema::new(
             "UserListStrategyPoolContractAssetLedger",
@@ -214,7 +228,9 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                     ],
                 ),
             )],
-        ),
+        )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("ReadOnly")),
         EndpointSchema::new(
             "UserListUserStrategyPoolContractAssetLedger",
             20067,
@@ -245,7 +261,9 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                     ],
                 ),
             )],
-        ),
+        )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("ReadOnly")),
         EndpointSchema::new(
             "UserGetStrategyStatistics",
             20070,
@@ -285,6 +303,8 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                 ),
             ],
         )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("ReadOnly"))
         .with_description("User gets a strategy statistics"),
         EndpointSchema::new(
             "UserGetStrategiesStatistics",
@@ -333,6 +353,8 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                 ),
             ],
         )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("ReadOnly"))
         .with_description("User gets statistics of all strategies related to the user"),
         EndpointSchema::new(
             "UserUpdateUserProfile",
@@ -346,6 +368,7 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
             ],
             vec![],
         )
+        .with_roles(roles_at_least("user"))
         .with_description("User update its expert profile"),
         EndpointSchema::new(
             "UserBackStrategy",
@@ -359,6 +382,8 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
             ],
             vec![],
         )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("Trade"))
         .with_stream_response_type(Type::struct_(
             "UserBackStrategyStreamResponse",
             vec![
@@ -380,7 +405,9 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                 Field::new("success", Type::Boolean),
                 Field::new("transaction_hash", Type::BlockchainTransactionHash),
             ],
-        ),
+        )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("Trade")),
         EndpointSchema::new(
             "UserRequestRefund",
             20081,
@@ -391,7 +418,27 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                 Field::new("nonce", Type::BigInt),
             ],
             vec![Field::new("success", Type::Boolean)],
-        ),
+        )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("Withdraw")),
+        EndpointSchema::new(
+            "UserRequestWithdraw",
+            20082,
+            vec![
+                Field::new("quantity", Type::BlockchainDecimal),
+                Field::new("wallet_address", Type::BlockchainAddress),
+                Field::new("blockchain", Type::enum_ref("block_chain")),
+                Field::new("nonce", Type::BigInt),
+            ],
+            vec![
+                Field::new("success", Type::Boolean),
+                Field::new("quantity_received", Type::BlockchainDecimal),
+                Field::new("fee", Type::BlockchainDecimal),
+            ],
+        )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("Withdraw"))
+        .with_description("User withdraws escrow balance to a whitelisted wallet"),
         EndpointSchema::new(
             "UserListBackedStrategies",
             20090,
@@ -403,7 +450,9 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                 Field::new("strategies_total", Type::BigInt),
                 Field::new("strategies", list_strategies_datatable()),
             ],
-        ),
+        )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("ReadOnly")),
         EndpointSchema::new(
             "UserListBackStrategyLedger",
             20100,
@@ -429,7 +478,9 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                     ),
                 ),
             ],
-        ),
+        )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("ReadOnly")),
         EndpointSchema::new(
             "ExpertListBackStrategyLedger",
             20101,
@@ -455,7 +506,9 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                     ),
                 ),
             ],
-        ),
+        )
+        .with_roles(roles_at_least("expert"))
+        .with_api_key_scope(api_key_scope("ReadOnly")),
         EndpointSchema::new(
             "UserListExitStrategyLedger",
             20120,
@@ -481,7 +534,9 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                     ),
                 ),
             ],
-        ),
+        )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("ReadOnly")),
         EndpointSchema::new(
             "ExpertListExitStrategyLedger",
             20121,
@@ -507,13 +562,16 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                     ),
                 ),
             ],
-        ),
+        )
+        .with_roles(roles_at_least("expert"))
+        .with_api_key_scope(api_key_scope("ReadOnly")),
         EndpointSchema::new(
             "UserFollowExpert",
             20130,
             vec![Field::new("expert_id", Type::BigInt)],
             vec![Field::new("success", Type::Boolean)],
         )
+        .with_roles(roles_at_least("user"))
         .with_description("User follows an expert"),
         EndpointSchema::new(
             "UserListFollowedExperts",
@@ -527,6 +585,8 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                 Field::new("experts", list_experts_datatable()),
             ],
         )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("ReadOnly"))
         .with_description("User lists followed experts"),
         EndpointSchema::new(
             "UserUnfollowExpert",
@@ -534,6 +594,7 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
             vec![Field::new("expert_id", Type::BigInt)],
             vec![Field::new("success", Type::Boolean)],
         )
+        .with_roles(roles_at_least("user"))
         .with_description("User unfollows an expert"),
         EndpointSchema::new(
             "UserListExperts",
@@ -556,6 +617,8 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                 Field::new("experts", list_experts_datatable()),
             ],
         )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("ReadOnly"))
         .with_description("User lists experts"),
         EndpointSchema::new(
             "UserListTopPerformingExperts",
@@ -569,6 +632,8 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                 Field::new("experts", list_experts_datatable()),
             ],
         )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("ReadOnly"))
         .with_description("User lists experts"),
         EndpointSchema::new(
             "UserListFeaturedExperts",
@@ -582,6 +647,8 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                 Field::new("experts", list_experts_datatable()),
             ],
         )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("ReadOnly"))
         .with_description("User lists experts"),
         EndpointSchema::new(
             "UserListExpertListenedWalletTradeLedger",
@@ -613,7 +680,9 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                     ],
                 ),
             )],
-        ),
+        )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("ReadOnly")),
         EndpointSchema::new(
             "UserGetExpertProfile",
             20170,
@@ -627,6 +696,7 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                 Field::new("backers_count", Type::Int),
                 Field::new("description", Type::String),
                 Field::new("social_media", Type::String),
+                Field::new("consistent_score", Type::Numeric),
                 Field::new("risk_score", Type::Numeric),
                 Field::new("reputation_score", Type::Numeric),
                 Field::new("aum", Type::Numeric),
@@ -635,6 +705,8 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                 Field::new("strategies", list_strategies_datatable()),
             ],
         )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("ReadOnly"))
         .with_description("User gets an expert profile"),
         EndpointSchema::new(
             "UserGetUserProfile",
@@ -652,6 +724,8 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                 Field::new("backed_strategies", list_strategies_datatable()),
             ],
         )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("ReadOnly"))
         .with_description("User gets an user profile"),
         EndpointSchema::new(
             "UserWhitelistWallet",
@@ -665,6 +739,7 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                 Field::new("wallet_id", Type::BigInt),
             ],
         )
+        .with_roles(roles_at_least("user"))
         .with_description("User registers a wallet"),
         EndpointSchema::new(
             "UserListWhitelistedWallets",
@@ -691,6 +766,8 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                 ),
             )],
         )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("ReadOnly"))
         .with_description("User lists wallets"),
         EndpointSchema::new(
             "UserUnwhitelistWallet",
@@ -698,6 +775,7 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
             vec![Field::new("wallet_id", Type::BigInt)],
             vec![Field::new("success", Type::Boolean)],
         )
+        .with_roles(roles_at_least("user"))
         .with_description("User deregisters a wallet"),
         EndpointSchema::new(
             "UserApplyBecomeExpert",
@@ -708,7 +786,52 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                 Field::new("expert_id", Type::BigInt),
             ],
         )
-        .with_description("User applies to become an expert"),
+        .with_roles(roles_at_least("user"))
+        .with_description("User submits their expert application for review"),
+        EndpointSchema::new(
+            "UserSaveExpertApplication",
+            20221,
+            vec![Field::new("questionnaire", Type::Object)],
+            vec![Field::new("application_id", Type::BigInt)],
+        )
+        .with_roles(roles_at_least("user"))
+        .with_description("User saves the questionnaire of their expert application as a draft"),
+        EndpointSchema::new(
+            "UserUploadExpertApplicationDocument",
+            20222,
+            vec![
+                Field::new("name", Type::String),
+                Field::new("content_type", Type::String),
+                Field::new("content", Type::String),
+            ],
+            vec![Field::new("document_id", Type::BigInt)],
+        )
+        .with_roles(roles_at_least("user"))
+        .with_description("User attaches a base64 encoded document to their expert application"),
+        EndpointSchema::new(
+            "UserDeleteExpertApplicationDocument",
+            20223,
+            vec![Field::new("document_id", Type::BigInt)],
+            vec![Field::new("success", Type::Boolean)],
+        )
+        .with_roles(roles_at_least("user"))
+        .with_description("User removes a document from their expert application"),
+        EndpointSchema::new(
+            "UserGetExpertApplication",
+            20224,
+            vec![],
+            vec![
+                Field::new("application_id", Type::optional(Type::BigInt)),
+                Field::new("status", Type::enum_ref("expert_application_status")),
+                Field::new("questionnaire", Type::Object),
+                Field::new("submitted_at", Type::optional(Type::BigInt)),
+                Field::new("reviewed_at", Type::optional(Type::BigInt)),
+                Field::new("documents", Type::vec(expert_application_document_row())),
+                Field::new("notes", Type::vec(expert_application_note_row())),
+            ],
+        )
+        .with_roles(roles_at_least("user"))
+        .with_description("User gets their expert application with its documents and review notes"),
         EndpointSchema::new(
             "ExpertCreateStrategy",
             20250,
@@ -742,6 +865,7 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                 Field::new("strategy_id", Type::BigInt),
             ],
         )
+        .with_roles(roles_at_least("expert"))
         .with_description("User makes a strategy"),
         EndpointSchema::new(
             "ExpertUpdateStrategy",
@@ -751,17 +875,26 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                 Field::new("name", Type::optional(Type::String)),
                 Field::new("description", Type::optional(Type::String)),
                 Field::new("social_media", Type::optional(Type::String)),
+                Field::new("rebalance_interval_seconds", Type::optional(Type::BigInt)),
+                Field::new("rebalance_drift_threshold", Type::optional(Type::Numeric)),
+                Field::new("copy_trade_min_usd", Type::optional(Type::Numeric)),
+                Field::new("copy_trade_max_aum_ratio", Type::optional(Type::Numeric)),
+                Field::new("copy_trade_cooldown_seconds", Type::optional(Type::BigInt)),
+                Field::new("copy_trade_batch_window_seconds", Type::optional(Type::BigInt)),
+                Field::new("expert_fee", Type::optional(Type::Numeric)),
             ],
             vec![Field::new("success", Type::Boolean)],
         )
-        .with_description("Expert updates a strategy"),
+        .with_roles(roles_at_least("expert"))
+        .with_description("Expert updates a strategy, a rebalance interval or copy trade control of 0 disables it. A new expert fee is only applied after backers had time to exit"),
         EndpointSchema::new(
             "ExpertFreezeStrategy",
             20265,
             vec![Field::new("strategy_id", Type::BigInt)],
             vec![Field::new("success", Type::Boolean)],
         )
-        .with_description("Expert freezes a strategy, by making it immutable"),
+        .with_description("Expert freezes a strategy, by making it immutable")
+        .with_roles(roles_at_least("expert")),
         EndpointSchema::new(
             "ExpertAddStrategyWatchingWallet",
             20270,
@@ -775,7 +908,8 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                 Field::new("success", Type::Boolean),
                 Field::new("wallet_id", Type::BigInt),
             ],
-        ),
+        )
+        .with_roles(roles_at_least("expert")),
         EndpointSchema::new(
             "ExpertRemoveStrategyWatchingWallet",
             20280,
@@ -784,7 +918,8 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                 Field::new("wallet_id", Type::BigInt),
             ],
             vec![Field::new("success", Type::Boolean)],
-        ),
+        )
+        .with_roles(roles_at_least("expert")),
         EndpointSchema::new(
             "UserListStrategyWatchingWallets",
             20290,
@@ -804,7 +939,9 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                     ),
                 ),
             ],
-        ),
+        )
+        .with_api_key_scope(api_key_scope("ReadOnly"))
+        .with_roles(roles_at_least("user")),
         EndpointSchema::new(
             "UserListWalletActivityLedger",
             20300,
@@ -838,7 +975,9 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                     ),
                 ),
             ],
-        ),
+        )
+        .with_api_key_scope(api_key_scope("ReadOnly"))
+        .with_roles(roles_at_least("user")),
         EndpointSchema::new(
             "ExpertAddStrategyInitialTokenRatio",
             20310,
@@ -851,7 +990,8 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                 Field::new("success", Type::Boolean),
                 Field::new("token_id", Type::BigInt),
             ],
-        ),
+        )
+        .with_roles(roles_at_least("expert")),
         EndpointSchema::new(
             "ExpertRemoveStrategyInitialTokenRatio",
             20320,
@@ -860,7 +1000,8 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                 Field::new("token_id", Type::BigInt),
             ],
             vec![Field::new("success", Type::Boolean)],
-        ),
+        )
+        .with_roles(roles_at_least("expert")),
         EndpointSchema::new(
             "UserListStrategyInitialTokenRatio",
             20330,
@@ -882,7 +1023,41 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                     ),
                 ),
             ],
-        ),
+        )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("ReadOnly")),
+        EndpointSchema::new(
+            "UserListStrategyVersions",
+            20335,
+            vec![
+                Field::new("strategy_id", Type::BigInt),
+                Field::new("limit", Type::optional(Type::BigInt)),
+                Field::new("offset", Type::optional(Type::BigInt)),
+            ],
+            vec![
+                Field::new("versions_total", Type::BigInt),
+                Field::new(
+                    "versions",
+                    Type::datatable(
+                        "ListStrategyVersionsRow",
+                        vec![
+                            Field::new("version", Type::BigInt),
+                            Field::new("change", Type::String),
+                            Field::new("params", Type::Object),
+                            Field::new("diff", Type::optional(Type::Object)),
+                            Field::new("is_material", Type::Boolean),
+                            Field::new("status", Type::enum_ref("strategy_version_status")),
+                            Field::new("effective_at", Type::BigInt),
+                            Field::new("applied_at", Type::optional(Type::BigInt)),
+                            Field::new("created_at", Type::BigInt),
+                        ],
+                    ),
+                ),
+            ],
+        )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("ReadOnly"))
+        .with_description("Lists every change made to a strategy, newest first. Material changes are scheduled and only take effect at effective_at"),
         EndpointSchema::new(
             "ExpertListFollowers",
             20340,
@@ -908,7 +1083,9 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                     ),
                 ),
             ],
-        ),
+        )
+        .with_roles(roles_at_least("expert"))
+        .with_api_key_scope(api_key_scope("ReadOnly")),
         EndpointSchema::new(
             "ExpertListBackers",
             20350,
@@ -934,7 +1111,9 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                     ),
                 ),
             ],
-        ),
+        )
+        .with_roles(roles_at_least("expert"))
+        .with_api_key_scope(api_key_scope("ReadOnly")),
         EndpointSchema::new(
             "ExpertListPublishedStrategies",
             20355,
@@ -946,7 +1125,9 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                 Field::new("strategies_total", Type::BigInt),
                 Field::new("strategies", list_strategies_datatable()),
             ],
-        ),
+        )
+        .with_roles(roles_at_least("expert"))
+        .with_api_key_scope(api_key_scope("ReadOnly")),
         EndpointSchema::new(
             "ExpertListUnpublishedStrategies",
             20356,
@@ -958,7 +1139,9 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                 Field::new("strategies_total", Type::BigInt),
                 Field::new("strategies", list_strategies_datatable()),
             ],
-        ),
+        )
+        .with_roles(roles_at_least("expert"))
+        .with_api_key_scope(api_key_scope("ReadOnly")),
         EndpointSchema::new(
             "UserGetDepositTokens",
             20360,
@@ -977,7 +1160,9 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                     ],
                 ),
             )],
-        ),
+        )
+        .with_api_key_scope(api_key_scope("ReadOnly"))
+        .with_roles(roles_at_least("user")),
         EndpointSchema::new(
             "UserGetDepositAddresses",
             20370,
@@ -993,7 +1178,9 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                     ],
                 ),
             )],
-        ),
+        )
+        .with_api_key_scope(api_key_scope("ReadOnly"))
+        .with_roles(roles_at_least("user")),
         EndpointSchema::new(
             "UserListDepositWithdrawLedger",
             20380,
@@ -1007,7 +1194,9 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                 Field::new("ledger_total", Type::BigInt),
                 Field::new("ledger", Type::vec(user_deposit_withdraw_ledger_entry())),
             ],
-        ),
+        )
+        .with_api_key_scope(api_key_scope("ReadOnly"))
+        .with_roles(roles_at_least("user")),
         EndpointSchema::new(
             "UserSubscribeDepositLedger",
             20381,
@@ -1018,8 +1207,12 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
             ],
             vec![],
         )
-        .with_stream_response_type(user_deposit_withdraw_ledger_entry()),
-        EndpointSchema::new("UserUnsubscribeDepositLedger", 20382, vec![], vec![]),
+        .with_api_key_scope(api_key_scope("ReadOnly"))
+        .with_stream_response_type(user_deposit_withdraw_ledger_entry())
+        .with_roles(roles_at_least("user")),
+        EndpointSchema::new("UserUnsubscribeDepositLedger", 20382, vec![], vec![])
+            .with_api_key_scope(api_key_scope("ReadOnly"))
+        .with_roles(roles_at_least("user")),
         EndpointSchema::new(
             "UserListStrategyWallets",
             20390,
@@ -1042,7 +1235,9 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                     ),
                 ),
             ],
-        ),
+        )
+        .with_api_key_scope(api_key_scope("ReadOnly"))
+        .with_roles(roles_at_least("user")),
         EndpointSchema::new(
             "UserCreateStrategyWallet",
             20391,
@@ -1057,7 +1252,8 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                 Field::new("blockchain", Type::enum_ref("block_chain")),
                 Field::new("address", Type::BlockchainAddress),
             ],
-        ),
+        )
+        .with_roles(roles_at_least("user")),
         EndpointSchema::new(
             "UserListStrategyAuditRules",
             20400,
@@ -1075,7 +1271,9 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                     ],
                 ),
             )],
-        ),
+        )
+        .with_api_key_scope(api_key_scope("ReadOnly"))
+        .with_roles(roles_at_least("user")),
         EndpointSchema::new(
             "UserAddStrategyAuditRule",
             20410,
@@ -1084,7 +1282,8 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                 Field::new("rule_id", Type::BigInt),
             ],
             vec![],
-        ),
+        )
+        .with_roles(roles_at_least("expert")),
         EndpointSchema::new(
             "UserRemoveStrategyAuditRule",
             20420,
@@ -1093,7 +1292,8 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                 Field::new("rule_id", Type::BigInt),
             ],
             vec![],
-        ),
+        )
+        .with_roles(roles_at_least("expert")),
         EndpointSchema::new(
             "UserGetEscrowAddressForStrategy",
             20500,
@@ -1116,7 +1316,9 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                     ],
                 ),
             )],
-        ),
+        )
+        .with_api_key_scope(api_key_scope("ReadOnly"))
+        .with_roles(roles_at_least("user")),
         EndpointSchema::new(
             "UserListDepositWithdrawBalances",
             20510,
@@ -1134,13 +1336,17 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                     ],
                 ),
             )],
-        ),
+        )
+        .with_api_key_scope(api_key_scope("ReadOnly"))
+        .with_roles(roles_at_least("user")),
         EndpointSchema::new(
             "UserGetDepositWithdrawBalance",
             20511,
             vec![Field::new("token_id", Type::BigInt)],
             vec![Field::new("balance", Type::BlockchainDecimal)],
-        ),
+        )
+        .with_api_key_scope(api_key_scope("ReadOnly"))
+        .with_roles(roles_at_least("user")),
         EndpointSchema::new(
             "UserListEscrowTokenContractAddresses",
             20520,
@@ -1168,7 +1374,9 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                     ),
                 ),
             ],
-        ),
+        )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("ReadOnly")),
         EndpointSchema::new(
             "UserListStrategyTokenBalance",
             20530,
@@ -1193,7 +1401,9 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                     ),
                 ),
             ],
-        ),
+        )
+        .with_api_key_scope(api_key_scope("ReadOnly"))
+        .with_roles(roles_at_least("user")),
         EndpointSchema::new(
             "UserGetBackStrategyReviewDetail",
             20540,
@@ -1252,7 +1462,9 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                     ),
                 ),
             ],
-        ),
+        )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("ReadOnly")),
         EndpointSchema::new(
             "UserListUserBackStrategyAttempt",
             20550,
@@ -1281,7 +1493,9 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                     ),
                 ),
             ],
-        ),
+        )
+        .with_api_key_scope(api_key_scope("ReadOnly"))
+        .with_roles(roles_at_least("user")),
         EndpointSchema::new(
             "UserListUserBackStrategyLog",
             20560,
@@ -1304,13 +1518,17 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                     ),
                 ),
             ],
-        ),
+        )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("ReadOnly")),
         EndpointSchema::new(
             "UserGetSystemConfig",
             20570,
             vec![],
             vec![Field::new("platform_fee", Type::Numeric)],
         )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("ReadOnly"))
         .with_description("User get system config"),
         EndpointSchema::new(
             "UserListUserStrategyBalance",
@@ -1336,6 +1554,355 @@ pub fn get_user_endpoints() -> Vec<EndpointSchema> {
                     ),
                 ),
             ],
+        )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("ReadOnly")),
+        EndpointSchema::new(
+            "UserExportActivityReport",
+            20590,
+            vec![
+                Field::new("start_time", Type::BigInt),
+                Field::new("end_time", Type::BigInt),
+                Field::new("blockchain", Type::optional(Type::enum_ref("block_chain"))),
+                Field::new(
+                    "format",
+                    Type::optional(Type::enum_ref("activity_report_format")),
+                ),
+                Field::new("limit", Type::optional(Type::BigInt)),
+                Field::new("offset", Type::optional(Type::BigInt)),
+            ],
+            vec![
+                Field::new("report_total", Type::BigInt),
+                Field::new(
+                    "report",
+                    Type::datatable(
+                        "UserActivityReportRow",
+                        vec![
+                            Field::new("event_type", Type::enum_ref("user_activity_event_type")),
+                            Field::new("blockchain", Type::enum_ref("block_chain")),
+                            Field::new("strategy_id", Type::optional(Type::BigInt)),
+                            Field::new(
+                                "transaction_hash",
+                                Type::optional(Type::BlockchainTransactionHash),
+                            ),
+                            Field::new("token_symbol", Type::String),
+                            Field::new("token_address", Type::optional(Type::BlockchainAddress)),
+                            Field::new("quantity", Type::BlockchainDecimal),
+                            Field::new("usd_price", Type::optional(Type::Numeric)),
+                            Field::new("usd_value", Type::optional(Type::Numeric)),
+                            Field::new("fees", Type::BlockchainDecimal),
+                            Field::new("happened_at", Type::BigInt),
+                        ],
+                    ),
+                ),
+                Field::new("csv", Type::optional(Type::String)),
+                Field::new("truncated", Type::Boolean),
+            ],
+        )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("ReadOnly"))
+        .with_description(
+            "User exports deposits, withdrawals, refunds, backs, exits and pool asset movements in a time range, as rows or as CSV. report_total counts every row in the range, a page holds at most limit of them. A CSV export holds the whole range up to 100000 rows, truncated tells when rows were left out. Back fees are the ones charged when the strategy was backed",
+        ),
+        EndpointSchema::new(
+            "ExpertAddStrategyGuardRule",
+            20600,
+            vec![
+                Field::new("strategy_id", Type::BigInt),
+                Field::new("rule_type", Type::enum_ref("strategy_guard_rule_type")),
+                Field::new("token_id", Type::optional(Type::BigInt)),
+                Field::new("threshold", Type::Numeric),
+                Field::new("exit_token_id", Type::optional(Type::BigInt)),
+            ],
+            vec![Field::new("rule_id", Type::BigInt)],
+        )
+        .with_roles(roles_at_least("expert"))
+        .with_description(
+            "Expert adds a max drawdown, asset stop-loss or take-profit guard that pauses copy trading when triggered",
+        ),
+        EndpointSchema::new(
+            "ExpertRemoveStrategyGuardRule",
+            20610,
+            vec![
+                Field::new("strategy_id", Type::BigInt),
+                Field::new("rule_id", Type::BigInt),
+            ],
+            vec![Field::new("success", Type::Boolean)],
+        )
+        .with_roles(roles_at_least("expert")),
+        EndpointSchema::new(
+            "UserListStrategyGuardRules",
+            20620,
+            vec![Field::new("strategy_id", Type::BigInt)],
+            vec![
+                Field::new("rules_total", Type::BigInt),
+                Field::new(
+                    "rules",
+                    Type::datatable(
+                        "ListStrategyGuardRulesRow",
+                        vec![
+                            Field::new("rule_id", Type::BigInt),
+                            Field::new("rule_type", Type::enum_ref("strategy_guard_rule_type")),
+                            Field::new("token_id", Type::optional(Type::BigInt)),
+                            Field::new("token_symbol", Type::optional(Type::String)),
+                            Field::new("threshold", Type::Numeric),
+                            Field::new("reference_value", Type::optional(Type::Numeric)),
+                            Field::new("exit_token_id", Type::optional(Type::BigInt)),
+                            Field::new("exit_token_symbol", Type::optional(Type::String)),
+                            Field::new("triggered_at", Type::optional(Type::BigInt)),
+                            Field::new("created_at", Type::BigInt),
+                        ],
+                    ),
+                ),
+            ],
+        )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("ReadOnly")),
+        EndpointSchema::new(
+            "ExpertResumeStrategyCopyTrading",
+            20630,
+            vec![Field::new("strategy_id", Type::BigInt)],
+            vec![Field::new("success", Type::Boolean)],
+        )
+        .with_roles(roles_at_least("expert"))
+        .with_description("Expert resumes copy trading paused by a guard and re-arms triggered guards"),
+        EndpointSchema::new("UserSubscribeStrategyGuardEvents", 20640, vec![], vec![])
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("ReadOnly"))
+            .with_stream_response_type(strategy_guard_event()),
+        EndpointSchema::new("UserUnsubscribeStrategyGuardEvents", 20641, vec![], vec![])
+            .with_api_key_scope(api_key_scope("ReadOnly"))
+        .with_roles(roles_at_least("user")),
+        EndpointSchema::new(
+            "UserListNotifications",
+            20650,
+            vec![
+                Field::new("unread_only", Type::optional(Type::Boolean)),
+                Field::new("limit", Type::optional(Type::BigInt)),
+                Field::new("offset", Type::optional(Type::BigInt)),
+            ],
+            vec![
+                Field::new("notifications_total", Type::BigInt),
+                Field::new("unread_total", Type::BigInt),
+                Field::new(
+                    "notifications",
+                    Type::datatable(
+                        "UserNotificationRow",
+                        vec![
+                            Field::new("notification_id", Type::BigInt),
+                            Field::new("kind", Type::enum_ref("user_notification_kind")),
+                            Field::new("strategy_id", Type::optional(Type::BigInt)),
+                            Field::new("message", Type::String),
+                            Field::new("read_at", Type::optional(Type::BigInt)),
+                            Field::new("created_at", Type::BigInt),
+                        ],
+                    ),
+                ),
+            ],
+        )
+        .with_roles(roles_at_least("user"))
+        .with_api_key_scope(api_key_scope("ReadOnly"))
+        .with_description(
+            "User lists notifications about strategy trades, backs, exits, refunds and followed experts, newest first",
+        ),
+        EndpointSchema::new(
+            "UserMarkNotificationsRead",
+            20660,
+            vec![Field::new(
+                "notification_ids",
+                Type::optional(Type::vec(Type::BigInt)),
+            )],
+            vec![Field::new("marked", Type::BigInt)],
+        )
+        .with_roles(roles_at_least("user"))
+        .with_description(
+            "User marks the given notifications as read, or all of them if none are given",
         ),
+        EndpointSchema::new("UserSubscribeNotifications", 20670, vec![], vec![])
+            .with_roles(roles_at_least("user"))
+            .with_api_key_scope(api_key_scope("ReadOnly"))
+            .with_stream_response_type(Type::struct_(
+                "UserNotification",
+                vec![
+                    Field::new("notification_id", Type::BigInt),
+                    Field::new("kind", Type::enum_ref("user_notification_kind")),
+                    Field::new("strategy_id", Type::optional(Type::BigInt)),
+                    Field::new("message", Type::String),
+                    Field::new("read_at", Type::optional(Type::BigInt)),
+                    Field::new("created_at", Type::BigInt),
+                ],
+            ))
+            .with_description("User receives new notifications as they are created"),
+        EndpointSchema::new("UserUnsubscribeNotifications", 20671, vec![], vec![])
+            .with_api_key_scope(api_key_scope("ReadOnly"))
+        .with_roles(roles_at_least("user")),
+        EndpointSchema::new(
+            "UserListSessions",
+            20680,
+            vec![],
+            vec![Field::new(
+                "sessions",
+                Type::datatable(
+                    "UserSessionRow",
+                    vec![
+                        Field::new("session_id", Type::BigInt),
+                        Field::new("service", Type::enum_ref("service")),
+                        Field::new("device_id", Type::String),
+                        Field::new("device_os", Type::String),
+                        Field::new("ip_address", Type::Inet),
+                        Field::new("created_at", Type::BigInt),
+                        Field::new("last_seen_at", Type::BigInt),
+                        Field::new("expires_at", Type::BigInt),
+                    ],
+                ),
+            )],
+        )
+        .with_roles(roles_at_least("user"))
+        .with_description("User lists the devices it is logged in on, most recently seen first"),
+        EndpointSchema::new(
+            "UserRevokeSession",
+            20690,
+            vec![Field::new("session_id", Type::BigInt)],
+            vec![],
+        )
+        .with_roles(roles_at_least("user"))
+        .with_description(
+            "User logs out a device, its tokens stop working and its connections are closed",
+        ),
+        EndpointSchema::new(
+            "UserCreateApiKey",
+            20700,
+            vec![
+                Field::new("name", Type::String),
+                Field::new("scopes", Type::vec(Type::enum_ref("api_key_scope"))),
+                Field::new("ip_allowlist", Type::optional(Type::vec(Type::Inet))),
+                Field::new("expires_at", Type::optional(Type::BigInt)),
+            ],
+            vec![
+                Field::new("api_key_id", Type::BigInt),
+                Field::new("api_key", Type::String),
+                Field::new("api_secret", Type::String),
+            ],
+        )
+        .with_roles(roles_at_least("user"))
+        .with_description(
+            "User creates an API key for programmatic access, the secret is only returned here",
+        ),
+        EndpointSchema::new(
+            "UserListApiKeys",
+            20710,
+            vec![],
+            vec![Field::new(
+                "api_keys",
+                Type::datatable(
+                    "UserApiKeyRow",
+                    vec![
+                        Field::new("api_key_id", Type::BigInt),
+                        Field::new("name", Type::String),
+                        Field::new("api_key", Type::String),
+                        Field::new("scopes", Type::vec(Type::enum_ref("api_key_scope"))),
+                        Field::new("ip_allowlist", Type::optional(Type::vec(Type::Inet))),
+                        Field::new("expires_at", Type::optional(Type::BigInt)),
+                        Field::new("created_at", Type::BigInt),
+                        Field::new("last_used_at", Type::optional(Type::BigInt)),
+                    ],
+                ),
+            )],
+        )
+        .with_roles(roles_at_least("user"))
+        .with_description("User lists its API keys that are not revoked"),
+        EndpointSchema::new(
+            "UserRevokeApiKey",
+            20720,
+            vec![Field::new("api_key_id", Type::BigInt)],
+            vec![],
+        )
+        .with_roles(roles_at_least("user"))
+        .with_description("User revokes an API key and closes the connections authorized with it"),
+        EndpointSchema::new(
+            "UserListLoginWallets",
+            20730,
+            vec![],
+            vec![Field::new(
+                "wallets",
+                Type::datatable(
+                    "UserLoginWalletRow",
+                    vec![
+                        Field::new("address", Type::BlockchainAddress),
+                        Field::new("is_primary", Type::Boolean),
+                        Field::new("created_at", Type::BigInt),
+                    ],
+                ),
+            )],
+        )
+        .with_roles(roles_at_least("user"))
+        .with_description("User lists the wallets it can log in with, the primary one first"),
+        EndpointSchema::new(
+            "UserLinkLoginWallet",
+            20740,
+            vec![
+                Field::new("address", Type::BlockchainAddress),
+                Field::new("signature_text", Type::String),
+                Field::new("signature", Type::String),
+            ],
+            vec![],
+        )
+        .with_roles(roles_at_least("user"))
+        .with_description(
+            "User links another login wallet, proven by a SIWE message signed with a GetLoginNonce nonce",
+        ),
+        EndpointSchema::new(
+            "UserUnlinkLoginWallet",
+            20750,
+            vec![Field::new("address", Type::BlockchainAddress)],
+            vec![],
+        )
+        .with_roles(roles_at_least("user"))
+        .with_description("User unlinks a login wallet that is not the primary one"),
+        EndpointSchema::new(
+            "UserSetPrimaryLoginWallet",
+            20760,
+            vec![Field::new("address", Type::BlockchainAddress)],
+            vec![],
+        )
+        .with_roles(roles_at_least("user"))
+        .with_description("User makes a linked login wallet the address of the account"),
     ]
 }
+
+/// the variants of the `api_key_scope` enum in enums.rs
+fn api_key_scope_variants() -> Vec<EnumVariant> {
+    vec![
+        EnumVariant::new("ReadOnly", 0),
+        EnumVariant::new("Trade", 1),
+        EnumVariant::new("Withdraw", 2),
+    ]
+}
+
+/// the `api_key_scope` variant an API key needs to call an endpoint
+fn api_key_scope(scope: &str) -> EnumVariant {
+    api_key_scope_variants()
+        .into_iter()
+        .find(|x| x.name == scope)
+        .unwrap_or_else(|| panic!("unknown api key scope {}", scope))
+}
+
+fn strategy_guard_event() -> Type {
+    Type::struct_(
+        "StrategyGuardEvent",
+        vec![
+            Field::new("strategy_id", Type::BigInt),
+            Field::new("rule_id", Type::BigInt),
+            Field::new("blockchain", Type::enum_ref("block_chain")),
+            Field::new("rule_type", Type::enum_ref("strategy_guard_rule_type")),
+            Field::new("threshold", Type::Numeric),
+            Field::new("reference_value", Type::Numeric),
+            Field::new("current_value", Type::Numeric),
+            Field::new(
+                "exit_token_address",
+                Type::optional(Type::BlockchainAddress),
+            ),
+            Field::new("triggered_at", Type::BigInt),
+        ],
+    )
+}
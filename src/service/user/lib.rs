@@ -6,7 +6,10 @@ pub mod admin_method;
 pub mod audit;
 pub mod back_strategy;
 pub mod endpoints;
+#[path = "../shared/events.rs"]
+pub mod events;
 pub mod method;
 
 #[path = "../shared/shared_method.rs"]
 pub mod shared_method;
+pub mod strategy_version;
@@ -5,18 +5,27 @@ use eth_sdk::signer::Secp256k1SecretKey;
 use eth_sdk::{DexAddresses, EthereumConns, EthereumRpcConnectionPool};
 use eyre::*;
 use gen::model::EnumService;
+use lib::blob_store::BlobStoreConfig;
 use lib::config::load_config;
 use lib::database::{connect_to_database, DatabaseConfig};
+use lib::event_bus::EventBus;
+use lib::http::ApiDocuments;
 use lib::log::{setup_logs, LogLevel};
+use lib::system_config::watch_system_config;
 use lib::ws::{EndpointAuthController, SubscribeManager, WebsocketServer, WsServerConfig};
 use lru::LruCache;
 use mc2fi_asset_price::AssetPriceClient;
-use mc2fi_auth::endpoints::endpoint_auth_authorize;
-use mc2fi_auth::method::MethodAuthAuthorize;
+use mc2fi_auth::endpoints::{endpoint_auth_authorize, endpoint_auth_authorize_api_key};
+use mc2fi_auth::method::{MethodAuthAuthorize, MethodAuthAuthorizeApiKey};
 use mc2fi_user::admin_method::*;
 use mc2fi_user::audit::AuditLogger;
+use mc2fi_user::events::DomainEvent;
 use mc2fi_user::method::*;
-use mc2fi_user::shared_method::{load_allow_domain_urls, load_coin_addresses, load_escrow_address};
+use mc2fi_user::shared_method::{load_coin_addresses, load_escrow_address, load_system_config};
+use mc2fi_user::strategy_version::{
+    apply_scheduled_strategy_changes, MethodUserListStrategyVersions, StrategyChangePolicy,
+    StrategyChanges,
+};
 use secrecy::{ExposeSecret, SecretString};
 use serde::Deserialize;
 use std::fmt::Debug;
@@ -37,17 +46,29 @@ pub struct Config {
     pub setup_ethereum_localnet: bool,
     pub god_key: SecretString,
     pub cmc_api_key: SecretString,
+    /// admin endpoints, e.g. `AdminSetUserRole`, that wait for a second admin's approval
+    #[serde(default)]
+    pub admin_actions_requiring_approval: Vec<String>,
+    /// where uploaded documents, e.g. of expert applications, are kept
+    #[serde(default)]
+    pub blob_store: BlobStoreConfig,
 }
 #[tokio::main]
 async fn main() -> Result<()> {
-    let mut config: Config = load_config("user".to_owned())?;
+    let config: Config = load_config("user".to_owned())?;
     setup_logs(config.log_level)?;
     let audit_logger = AuditLogger::new()?;
     let mut server = WebsocketServer::new(config.app.clone());
     let db = connect_to_database(config.app_db).await?;
     let asset_client = Arc::new(AssetPriceClient::new(db.clone()));
-    load_allow_domain_urls(&db, &mut config.app).await?;
+    let blob_store = config.blob_store.build()?;
+    let allow_cors_urls = config.app.allow_cors_urls.clone();
+    watch_system_config(&db, move |db| {
+        load_system_config(db, allow_cors_urls.clone())
+    })
+    .await?;
     server.add_database(db.clone());
+    let event_bus = EventBus::<DomainEvent>::new(db.clone());
     server.add_database(connect_to_database(config.auth_db).await?);
 
     let mut auth_controller = EndpointAuthController::new();
@@ -57,6 +78,12 @@ async fn main() -> Result<()> {
             accept_service: EnumService::User,
         },
     );
+    auth_controller.add_auth_endpoint(
+        endpoint_auth_authorize_api_key(),
+        MethodAuthAuthorizeApiKey {
+            accept_service: EnumService::User,
+        },
+    );
     server.add_auth_controller(auth_controller);
 
     let coin_addresses = load_coin_addresses(&db).await?;
@@ -120,34 +147,63 @@ async fn main() -> Result<()> {
     });
     server.add_handler(MethodUserUpdateUserProfile);
     server.add_handler(MethodUserApplyBecomeExpert);
+    server.add_handler(MethodUserSaveExpertApplication);
+    server.add_handler(MethodUserUploadExpertApplicationDocument {
+        blob_store: blob_store.clone(),
+    });
+    server.add_handler(MethodUserDeleteExpertApplicationDocument {
+        blob_store: blob_store.clone(),
+    });
+    server.add_handler(MethodUserGetExpertApplication);
 
     server.add_handler(MethodUserListUserBackStrategyAttempt);
     server.add_handler(MethodUserListUserBackStrategyLog);
 
+    let sub_manager = SubscribeManager::new();
+    sub_manager.add_topic(AdminSubscribeTopic::AdminNotifyEscrowLedgerChange);
+    sub_manager.add_topic(AdminSubscribeTopic::UserNotification);
+    let sub_manager = Arc::new(sub_manager);
+    {
+        let sub_manager = Arc::clone(&sub_manager);
+        server.add_stream_close_hook(move |connection_id| {
+            sub_manager.unsubscribe_connection(connection_id)
+        });
+    }
+    let mut strategy_changes =
+        StrategyChanges::new(StrategyChangePolicy::new(Arc::clone(&sub_manager)));
     server.add_handler(MethodExpertCreateStrategy {
         cmc_client: Arc::new(CoinMarketCap::new(config.cmc_api_key.expose_secret())?),
+        manager: Arc::clone(&sub_manager),
     });
-    server.add_handler(MethodExpertUpdateStrategy {
+    server.add_handler(strategy_changes.wrap(MethodExpertUpdateStrategy {
         logger: audit_logger.clone(),
-    });
-    // TODO: move them to MethodExpertUpdateStrategy
-    // server.add_handler(MethodExpertAddStrategyInitialTokenRatio {
-    //     logger: audit_logger.clone(),
-    // });
-    // server.add_handler(MethodExpertRemoveStrategyInitialTokenRatio {
-    //     logger: audit_logger.clone(),
-    // });
+    }));
+    server.add_handler(
+        strategy_changes.wrap(MethodExpertAddStrategyInitialTokenRatio {
+            logger: audit_logger.clone(),
+        }),
+    );
+    server.add_handler(
+        strategy_changes.wrap(MethodExpertRemoveStrategyInitialTokenRatio {
+            logger: audit_logger.clone(),
+        }),
+    );
 
-    server.add_handler(MethodExpertRemoveStrategyWatchingWallet {
-        logger: audit_logger.clone(),
-    });
+    server.add_handler(
+        strategy_changes.wrap(MethodExpertRemoveStrategyWatchingWallet {
+            logger: audit_logger.clone(),
+        }),
+    );
     server.add_handler(MethodExpertListBackStrategyLedger);
     server.add_handler(MethodExpertListExitStrategyLedger);
     server.add_handler(MethodUserListStrategyInitialTokenRatio);
+    server.add_handler(MethodUserListStrategyVersions);
     server.add_handler(MethodUserGetDepositTokens {
         coin_addresses: coin_addresses.clone(),
     });
     server.add_handler(MethodUserListStrategyAuditRules);
+    server.add_handler(strategy_changes.wrap(MethodUserAddStrategyAuditRule));
+    server.add_handler(strategy_changes.wrap(MethodUserRemoveStrategyAuditRule));
     server.add_handler(MethodUserListDepositWithdrawBalances);
     server.add_handler(MethodUserGetDepositWithdrawBalance {
         escrow_addresses: escrow_contract_address.clone(),
@@ -165,15 +221,25 @@ async fn main() -> Result<()> {
     });
     server.add_handler(MethodUserGetSystemConfig);
     server.add_handler(MethodUserListUserStrategyBalance);
+    server.add_handler(MethodUserExportActivityReport);
 
+    let mut admin_actions = AdminActions::new(AdminActionPolicy::new(
+        config.admin_actions_requiring_approval.clone(),
+    ));
     server.add_handler(MethodAdminListUsers);
-    server.add_handler(MethodAdminSetUserRole);
-    server.add_handler(MethodAdminSetBlockUser);
-    server.add_handler(MethodAdminApproveUserBecomeExpert);
-    server.add_handler(MethodAdminRejectUserBecomeExpert);
+    server.add_handler(admin_actions.wrap(MethodAdminSetUserRole));
+    server.add_handler(admin_actions.wrap(MethodAdminSetBlockUser));
+    server.add_handler(admin_actions.wrap(MethodAdminApproveUserBecomeExpert));
+    server.add_handler(admin_actions.wrap(MethodAdminRejectUserBecomeExpert));
+    server.add_handler(admin_actions.wrap(MethodAdminRequestExpertApplicationInfo));
+    server.add_handler(MethodAdminGetExpertApplication);
+    server.add_handler(MethodAdminGetExpertApplicationDocument {
+        blob_store: blob_store.clone(),
+    });
     server.add_handler(MethodAdminListPendingExpertApplications);
     server.add_handler(MethodAdminGetSystemConfig);
-    server.add_handler(MethodAdminUpdateSystemConfig);
+    server.add_handler(admin_actions.wrap(MethodAdminUpdateSystemConfig));
+    server.add_handler(MethodAdminListSystemSettingHistory);
     server.add_handler(MethodAdminListBackers);
     server.add_handler(MethodAdminListExperts);
     server.add_handler(MethodAdminListStrategies {
@@ -181,11 +247,8 @@ async fn main() -> Result<()> {
     });
     server.add_handler(MethodAdminListBackStrategyLedger);
     server.add_handler(MethodAdminListExitStrategyLedger);
-    server.add_handler(MethodAdminRejectStrategy);
-    server.add_handler(MethodAdminSetBlockchainLogger);
-    let sub_manager = SubscribeManager::new();
-    sub_manager.add_topic(AdminSubscribeTopic::AdminNotifyEscrowLedgerChange);
-    let sub_manager = Arc::new(sub_manager);
+    server.add_handler(admin_actions.wrap(MethodAdminRejectStrategy));
+    server.add_handler(admin_actions.wrap(MethodAdminSetBlockchainLogger));
     server.add_handler(MethodAdminNotifyEscrowLedgerChange {
         manager: Arc::clone(&sub_manager),
     });
@@ -203,15 +266,48 @@ async fn main() -> Result<()> {
     server.add_handler(MethodAdminUnsubscribeDepositLedger {
         manger: Arc::clone(&sub_manager),
     });
+    server.add_handler(MethodUserSubscribeStrategyGuardEvents {
+        manger: Arc::clone(&sub_manager),
+    });
+    server.add_handler(MethodUserUnsubscribeStrategyGuardEvents {
+        manger: Arc::clone(&sub_manager),
+    });
+    server.add_handler(MethodUserListNotifications);
+    server.add_handler(MethodUserMarkNotificationsRead);
+    server.add_handler(MethodUserSubscribeNotifications {
+        manger: Arc::clone(&sub_manager),
+    });
+    server.add_handler(MethodUserUnsubscribeNotifications {
+        manger: Arc::clone(&sub_manager),
+    });
+    server.add_handler(MethodUserListSessions);
+    server.add_handler(MethodUserRevokeSession {
+        event_bus: event_bus.clone(),
+    });
+    server.add_handler(MethodUserCreateApiKey);
+    server.add_handler(MethodUserListApiKeys);
+    server.add_handler(MethodUserRevokeApiKey {
+        event_bus: event_bus.clone(),
+    });
+    server.add_handler(MethodUserListLoginWallets);
+    server.add_handler(MethodUserLinkLoginWallet {
+        allow_cors_sites: config.app.allow_cors_urls.clone(),
+    });
+    server.add_handler(MethodUserUnlinkLoginWallet);
+    server.add_handler(MethodUserSetPrimaryLoginWallet);
+    server.add_handler(strategy_changes.wrap(MethodExpertAddStrategyGuardRule));
+    server.add_handler(strategy_changes.wrap(MethodExpertRemoveStrategyGuardRule));
+    server.add_handler(MethodUserListStrategyGuardRules);
+    server.add_handler(MethodExpertResumeStrategyCopyTrading);
 
-    server.add_handler(MethodAdminAddAuditRule);
-    server.add_handler(MethodAdminAddEscrowContractAddress);
+    server.add_handler(admin_actions.wrap(MethodAdminAddAuditRule));
+    server.add_handler(admin_actions.wrap(MethodAdminAddEscrowContractAddress));
 
     let eth_pool = EthereumRpcConnectionPool::from_conns(config.ethereum_urls);
     server.add_handler(MethodAdminListEscrowTokenContractAddresses {
         asset_client: Arc::new(CoinMarketCap::new(config.cmc_api_key.expose_secret())?),
     });
-    server.add_handler(MethodAdminUpdateEscrowTokenContractAddress);
+    server.add_handler(admin_actions.wrap(MethodAdminUpdateEscrowTokenContractAddress));
     let escrow_contract = Arc::new(AbstractEscrowContract::new2(
         escrow_contract_address.clone(),
     ));
@@ -219,13 +315,21 @@ async fn main() -> Result<()> {
 
     let pancake_paths = WorkingPancakePairPaths::new(coin_addresses.clone(), eth_pool.clone())?;
     let pancake_paths = Arc::new(pancake_paths);
-    server.add_handler(MethodExpertAddStrategyWatchingWallet {
-        logger: audit_logger.clone(),
-        pool: eth_pool.clone(),
-    });
-    server.add_handler(MethodAdminApproveStrategy {
+    server.add_handler(
+        strategy_changes.wrap(MethodExpertAddStrategyWatchingWallet {
+            logger: audit_logger.clone(),
+            pool: eth_pool.clone(),
+        }),
+    );
+    server.add_handler(admin_actions.wrap(MethodAdminApproveStrategy {
         pool: eth_pool.clone(),
+    }));
+    check_requires_approval(&admin_actions)?;
+    server.add_handler(MethodAdminListPendingActions);
+    server.add_handler(MethodAdminApprovePendingAction {
+        actions: Arc::new(admin_actions),
     });
+    server.add_handler(MethodAdminListActionLog);
     server.add_handler(MethodAdminRefreshExpertWalletBalance {
         pool: eth_pool.clone(),
     });
@@ -257,15 +361,36 @@ async fn main() -> Result<()> {
         pool: eth_pool.clone(),
         master_key: master_key.clone(),
         lru: lru.clone(),
+        manager: Arc::clone(&sub_manager),
     });
     server.add_handler(MethodUserRequestRefund {
+        pool: eth_pool.clone(),
+        stablecoin_addresses: coin_addresses.clone(),
+        escrow_contract: escrow_contract.clone(),
+        master_key: master_key.clone(),
+        lru: lru.clone(),
+        manager: Arc::clone(&sub_manager),
+    });
+    server.add_handler(MethodUserRequestWithdraw {
         pool: eth_pool,
         stablecoin_addresses: coin_addresses,
         escrow_contract: escrow_contract.clone(),
         master_key: master_key.clone(),
+        cmc_client: Arc::new(CoinMarketCap::new(config.cmc_api_key.expose_secret())?),
         lru,
+        manager: Arc::clone(&sub_manager),
+    });
+    let strategy_changes = Arc::new(strategy_changes);
+    server.add_background_task(move |toolbox| {
+        apply_scheduled_strategy_changes(strategy_changes, toolbox)
     });
+    server
+        .add_background_task(move |toolbox| consume_domain_events(toolbox, sub_manager, event_bus));
     server.dump_schemas()?;
+    server.set_api_documents(ApiDocuments::from_json(
+        gen::OPENAPI_JSON,
+        gen::ASYNCAPI_JSON,
+    )?);
     server.listen().await?;
     Ok(())
 }
@@ -1,4 +1,4 @@
-use crate::admin_method::AdminSubscribeTopic;
+use crate::admin_method::{notify_users, AdminSubscribeTopic};
 use crate::audit::{
     get_audit_rules, validate_audit_rule_immutable_tokens, AuditLogger, AUDIT_TOP25_TOKENS,
 };
@@ -7,13 +7,16 @@ use crate::back_strategy::{
     calculate_user_back_strategy_calculate_amount_to_mint,
     CalculateUserBackStrategyCalculateAmountToMintResult,
 };
+use crate::events::{ApiKeyRevokedEvent, DomainEvent, SessionRevokedEvent};
 use crate::shared_method::{
-    convert_expert_db_to_api, convert_strategy_db_to_api_net_value, ensure_user_role,
-    update_asset_balances_and_ledger_exit_strategy,
+    calculate_gas_fee_in_tokens, convert_expert_db_to_api, convert_strategy_db_to_api_net_value,
+    load_expert_application_details, update_asset_balances_and_ledger_exit_strategy,
     update_strategy_token_balances_and_ledger_exit_strategy,
 };
 use api::cmc::CoinMarketCap;
 use api::AssetInfoClient;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
 use chrono::Utc;
 use eth_sdk::erc20::Erc20Token;
 use eth_sdk::escrow::{AbstractEscrowContract, EscrowContract};
@@ -29,13 +32,19 @@ use futures::FutureExt;
 use gen::database::*;
 use gen::model::*;
 use itertools::Itertools;
+use lib::blob_store::BlobStore;
 use lib::database::DbClient;
+use lib::datatable::{Column, DataTable};
+use lib::event_bus::EventBus;
 use lib::handler::{FutureResponse, RequestHandler};
 use lib::log::DynLogger;
+use lib::system_config::Reloadable;
 use lib::toolbox::*;
+use lib::utils::hex_decode;
 use lib::ws::SubscribeManager;
 use lib::{DEFAULT_LIMIT, DEFAULT_OFFSET};
 use lru::LruCache;
+use mc2fi_auth::method::{ensure_login_message_valid, record_failed_login};
 use num_traits::{FromPrimitive, ToPrimitive, Zero};
 use rust_decimal::Decimal;
 use std::sync::Arc;
@@ -43,8 +52,9 @@ use std::time::Duration;
 use tokio::sync::Mutex;
 use tokio::time::sleep;
 use tracing::*;
+use uuid::Uuid;
 use web3::signing::Key;
-use web3::types::{Address, H256};
+use web3::types::{Address, H256, U256};
 
 pub struct MethodUserFollowStrategy;
 
@@ -59,8 +69,6 @@ impl RequestHandler for MethodUserFollowStrategy {
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
-
             let ret = db
                 .execute(FunUserFollowStrategyReq {
                     user_id: ctx.user_id,
@@ -94,8 +102,6 @@ impl RequestHandler for MethodUserListFollowedStrategies {
         let db: DbClient = toolbox.get_db();
         let asset_client = self.asset_client.clone();
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
-
             let ret = db
                 .execute(FunUserListFollowedStrategiesReq {
                     user_id: ctx.user_id,
@@ -129,8 +135,6 @@ impl RequestHandler for MethodUserListStrategies {
         let db: DbClient = toolbox.get_db();
         let asset_client = self.asset_client.clone();
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
-
             let ret = db
                 .execute(FunUserListStrategiesReq {
                     user_id: ctx.user_id,
@@ -175,7 +179,6 @@ impl RequestHandler for MethodUserListTopPerformingStrategies {
         let db: DbClient = toolbox.get_db();
         let asset_client = self.asset_client.clone();
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
             // TODO: use FunUserListTopPerformingStrategiesReq
 
             let ret = db
@@ -211,13 +214,11 @@ impl RequestHandler for MethodUserListStrategyFollowers {
     fn handle(
         &self,
         toolbox: &Toolbox,
-        ctx: RequestContext,
+        _ctx: RequestContext,
         req: Self::Request,
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
-
             let ret = db
                 .execute(FunUserListStrategyFollowersReq {
                     strategy_id: req.strategy_id,
@@ -246,13 +247,11 @@ impl RequestHandler for MethodUserListStrategyBackers {
     fn handle(
         &self,
         toolbox: &Toolbox,
-        ctx: RequestContext,
+        _ctx: RequestContext,
         req: Self::Request,
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
-
             let ret = db
                 .execute(FunUserListStrategyBackersReq {
                     strategy_id: req.strategy_id,
@@ -289,7 +288,6 @@ impl RequestHandler for MethodUserGetStrategy {
         let db: DbClient = toolbox.get_db();
         let asset_client = self.asset_client.clone();
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
             let ret = db
                 .execute(FunUserListStrategiesReq {
                     user_id: ctx.user_id,
@@ -397,13 +395,11 @@ impl RequestHandler for MethodUserListStrategyPoolContractAssetLedger {
     fn handle(
         &self,
         toolbox: &Toolbox,
-        ctx: RequestContext,
+        _ctx: RequestContext,
         req: Self::Request,
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
-
             let ledger = db
                 .execute(FunUserListStrategyPoolContractAssetLedgerReq {
                     limit: req.limit,
@@ -448,8 +444,6 @@ impl RequestHandler for MethodUserListUserStrategyPoolContractAssetLedger {
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
-
             let ledger = db
                 .execute(FunUserListUserStrategyPoolContractAssetLedgerEntriesReq {
                     limit: req.limit,
@@ -491,12 +485,11 @@ impl RequestHandler for MethodUserGetStrategyStatistics {
     fn handle(
         &self,
         toolbox: &Toolbox,
-        ctx: RequestContext,
+        _ctx: RequestContext,
         req: Self::Request,
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
             let net_value = db
                 .execute(FunUserGetStrategyStatisticsNetValueReq {
                     strategy_id: req.strategy_id,
@@ -557,7 +550,6 @@ impl RequestHandler for MethodUserGetStrategiesStatistics {
         let db: DbClient = toolbox.get_db();
         let asset_client = self.asset_client.clone();
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
             let strategies = db
                 .execute(FunUserListStrategiesReq {
                     user_id: ctx.user_id,
@@ -629,7 +621,6 @@ impl RequestHandler for MethodUserListBackedStrategies {
         let db: DbClient = toolbox.get_db();
         let asset_client = self.asset_client.clone();
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
             let ret = db
                 .execute(FunUserListBackedStrategiesReq {
                     user_id: ctx.user_id,
@@ -796,8 +787,9 @@ impl RequestHandler for MethodUserBackStrategy {
                 .with_context(|| CustomError::new(EnumErrorCode::NotFound, "Token not found"))?;
             let escrow_contract = escrow_contract.get(&pool, token.blockchain).await?;
             let eth_conn = pool.get(token.blockchain).await?;
-            ensure_user_role(ctx, EnumRole::User)?;
             subscribe_manager.subscribe(AdminSubscribeTopic::UserBackProgress, ctx);
+            let notify_toolbox = toolbox.clone();
+            let notify_manager = subscribe_manager.clone();
             let seq = ctx.seq;
             let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
             let attempt = db
@@ -849,7 +841,7 @@ impl RequestHandler for MethodUserBackStrategy {
                 report_progress(false, msg, H256::zero());
             }));
             tokio::spawn(async move {
-                if let Err(err) = back_strategy::user_back_strategy(
+                let result = back_strategy::user_back_strategy(
                     &eth_conn,
                     &ctx,
                     &db,
@@ -867,11 +859,30 @@ impl RequestHandler for MethodUserBackStrategy {
                     &pancake_swap,
                     &asset_client,
                 )
-                .await
-                {
+                .await;
+                if let Err(err) = &result {
                     error!("user back strategy error: {:?}", err);
                     logger.log(format!("user back strategy error {}", err));
                 }
+                let message = match &result {
+                    Ok(()) => format!(
+                        "Backed strategy {} with {} {}",
+                        req.strategy_id, req.quantity, token.symbol
+                    ),
+                    Err(err) => format!("Backing strategy {} failed: {}", req.strategy_id, err),
+                };
+                if let Err(err) = notify_users(
+                    &notify_toolbox,
+                    &notify_manager,
+                    vec![ctx.user_id],
+                    EnumUserNotificationKind::BackStrategy,
+                    Some(req.strategy_id),
+                    message,
+                )
+                .await
+                {
+                    error!("Failed to notify user of back strategy: {:?}", err);
+                }
             });
             Ok::<_, Error>(UserBackStrategyResponse {})
         }
@@ -1000,9 +1011,9 @@ pub async fn user_exit_strategy(
             redeem_tx_hash = execute_transaction_and_ensure_success(
                 redeem_from_strategy_transaction,
                 &conn,
-                CONFIRMATIONS,
-                MAX_RETRIES,
-                POLL_INTERVAL,
+                confirmations(),
+                max_retries(),
+                poll_interval(),
                 &DynLogger::empty(),
             )
             .await?;
@@ -1031,9 +1042,9 @@ pub async fn user_exit_strategy(
             redeem_tx_hash = execute_transaction_and_ensure_success(
                 full_redeem_from_strategy_transaction,
                 &conn,
-                CONFIRMATIONS,
-                MAX_RETRIES,
-                POLL_INTERVAL,
+                confirmations(),
+                max_retries(),
+                poll_interval(),
                 &DynLogger::empty(),
             )
             .await?;
@@ -1078,9 +1089,9 @@ pub async fn user_exit_strategy(
     let withdraw_tx_hash = execute_transaction_and_ensure_success(
         withdraw_transaction,
         &conn,
-        CONFIRMATIONS,
-        MAX_RETRIES,
-        POLL_INTERVAL,
+        confirmations(),
+        max_retries(),
+        poll_interval(),
         &logger,
     )
     .await?;
@@ -1118,6 +1129,7 @@ pub struct MethodUserExitStrategy {
     pub pool: EthereumRpcConnectionPool,
     pub master_key: Secp256k1SecretKey,
     pub lru: Arc<Mutex<LruCache<i64, ()>>>,
+    pub manager: Arc<SubscribeManager<AdminSubscribeTopic>>,
 }
 
 impl RequestHandler for MethodUserExitStrategy {
@@ -1130,9 +1142,11 @@ impl RequestHandler for MethodUserExitStrategy {
         req: Self::Request,
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
+        let toolbox = toolbox.clone();
         let pool = self.pool.clone();
         let master_key = self.master_key.clone();
         let lru = self.lru.clone();
+        let manager = self.manager.clone();
         async move {
             {
                 let mut lru = lru.lock().await;
@@ -1145,7 +1159,6 @@ impl RequestHandler for MethodUserExitStrategy {
             }
             let eth_conn = pool.get(req.blockchain).await?;
             // TODO: decide if we should ensure user role
-            ensure_user_role(ctx, EnumRole::User)?;
             let tx_hash = match user_exit_strategy(
                 &eth_conn,
                 &ctx,
@@ -1164,6 +1177,21 @@ impl RequestHandler for MethodUserExitStrategy {
                     return Err(CustomError::new(EnumErrorCode::InvalidArgument, err).into());
                 }
             };
+            if let Err(err) = notify_users(
+                &toolbox,
+                &manager,
+                vec![ctx.user_id],
+                EnumUserNotificationKind::ExitStrategy,
+                Some(req.strategy_id),
+                format!(
+                    "Exited strategy {} with {} strategy tokens",
+                    req.strategy_id, req.quantity
+                ),
+            )
+            .await
+            {
+                error!("Failed to notify users of exit strategy: {:?}", err);
+            }
 
             Ok(UserExitStrategyResponse {
                 success: true,
@@ -1180,6 +1208,7 @@ pub struct MethodUserRequestRefund {
     pub escrow_contract: Arc<AbstractEscrowContract>,
     pub master_key: Secp256k1SecretKey,
     pub lru: Arc<Mutex<LruCache<i64, ()>>>,
+    pub manager: Arc<SubscribeManager<AdminSubscribeTopic>>,
 }
 
 impl RequestHandler for MethodUserRequestRefund {
@@ -1194,9 +1223,11 @@ impl RequestHandler for MethodUserRequestRefund {
         let db: DbClient = toolbox.get_db();
         let pool = self.pool.clone();
         let stablecoin_addresses = self.stablecoin_addresses.clone();
+        let toolbox = toolbox.clone();
         let escrow_contract = self.escrow_contract.clone();
         let master_key = self.master_key.clone();
         let lru = self.lru.clone();
+        let manager = self.manager.clone();
         async move {
             {
                 let mut lru = lru.lock().await;
@@ -1210,8 +1241,6 @@ impl RequestHandler for MethodUserRequestRefund {
             let escrow_contract = escrow_contract.get(&pool, req.blockchain).await?;
             let eth_conn = pool.get(req.blockchain).await?;
 
-            ensure_user_role(ctx, EnumRole::User)?;
-
             on_user_request_refund(
                 &eth_conn,
                 &ctx,
@@ -1226,6 +1255,23 @@ impl RequestHandler for MethodUserRequestRefund {
                 DynLogger::empty(),
             )
             .await?;
+            if let Err(err) = notify_users(
+                &toolbox,
+                &manager,
+                vec![ctx.user_id],
+                EnumUserNotificationKind::Refund,
+                None,
+                format!(
+                    "Refunded {} {} to {:?}",
+                    req.quantity,
+                    EnumBlockchainCoin::USDC,
+                    req.wallet_address
+                ),
+            )
+            .await
+            {
+                error!("Failed to notify users of refund: {:?}", err);
+            }
             Ok(UserRequestRefundResponse { success: true })
         }
         .boxed()
@@ -1330,9 +1376,9 @@ pub async fn on_user_request_refund(
     let hash = execute_transaction_and_ensure_success(
         refund_transaction,
         &_conn,
-        CONFIRMATIONS,
-        MAX_RETRIES,
-        POLL_INTERVAL,
+        confirmations(),
+        max_retries(),
+        poll_interval(),
         &logger,
     )
     .await?;
@@ -1374,6 +1420,390 @@ pub async fn on_user_request_refund(
 
     Ok(hash)
 }
+pub struct MethodUserRequestWithdraw {
+    pub pool: EthereumRpcConnectionPool,
+    pub stablecoin_addresses: Arc<BlockchainCoinAddresses>,
+    pub escrow_contract: Arc<AbstractEscrowContract>,
+    pub master_key: Secp256k1SecretKey,
+    pub cmc_client: Arc<CoinMarketCap>,
+    pub lru: Arc<Mutex<LruCache<i64, ()>>>,
+    pub manager: Arc<SubscribeManager<AdminSubscribeTopic>>,
+}
+
+impl RequestHandler for MethodUserRequestWithdraw {
+    type Request = UserRequestWithdrawRequest;
+
+    fn handle(
+        &self,
+        toolbox: &Toolbox,
+        ctx: RequestContext,
+        req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let db: DbClient = toolbox.get_db();
+        let toolbox = toolbox.clone();
+        let pool = self.pool.clone();
+        let stablecoin_addresses = self.stablecoin_addresses.clone();
+        let escrow_contract = self.escrow_contract.clone();
+        let master_key = self.master_key.clone();
+        let cmc_client = self.cmc_client.clone();
+        let lru = self.lru.clone();
+        let manager = self.manager.clone();
+        async move {
+            {
+                let mut lru = lru.lock().await;
+                if lru.put(req.nonce, ()).is_some() {
+                    bail!(CustomError::new(
+                        EnumErrorCode::DuplicateRequest,
+                        "duplicate request"
+                    ))
+                }
+            }
+            let escrow_contract = escrow_contract.get(&pool, req.blockchain).await?;
+            let eth_conn = pool.get(req.blockchain).await?;
+
+            let (quantity_received, fee) = on_user_request_withdraw(
+                &eth_conn,
+                &ctx,
+                &db,
+                req.blockchain,
+                &stablecoin_addresses,
+                escrow_contract,
+                &cmc_client,
+                req.quantity.into(),
+                req.wallet_address.into(),
+                master_key,
+                EnumBlockchainCoin::USDC,
+                |row| {
+                    manager.publish_with_filter(
+                        &toolbox,
+                        AdminSubscribeTopic::AdminNotifyEscrowLedgerChange,
+                        row,
+                        |x| x.user_id == ctx.user_id,
+                    )
+                },
+                DynLogger::empty(),
+            )
+            .await?;
+            Ok(UserRequestWithdrawResponse {
+                success: true,
+                quantity_received: quantity_received.into(),
+                fee: fee.into(),
+            })
+        }
+        .boxed()
+    }
+}
+
+/// withdraws `quantity` of the user's escrow balance to one of the user's whitelisted wallets.
+/// the quantity is taken from the wallets with the largest balances first, and the gas fee of all
+/// transfers is deducted from it and paid to the escrow signer.
+/// returns the quantity received by the wallet and the fee
+pub async fn on_user_request_withdraw(
+    conn: &EthereumRpcConnection,
+    ctx: &RequestContext,
+    db: &DbClient,
+    chain: EnumBlockChain,
+    stablecoin_addresses: &BlockchainCoinAddresses,
+    escrow_contract: EscrowContract<EitherTransport>,
+    cmc: &CoinMarketCap,
+    quantity: Decimal,
+    wallet_address: Address,
+    escrow_signer: impl Key + Clone,
+    token: EnumBlockchainCoin,
+    notify_ledger_change: impl Fn(&UserListDepositLedgerRow),
+    logger: DynLogger,
+) -> Result<(Decimal, Decimal)> {
+    info!(
+        "on_user_request_withdraw {:?} from {:?} transfer {} {:?} to {:?}",
+        chain,
+        escrow_contract.address(),
+        quantity,
+        token,
+        wallet_address
+    );
+    if quantity <= Decimal::zero() {
+        bail!(CustomError::new(
+            EnumErrorCode::InvalidArgument,
+            "withdraw quantity must be positive"
+        ));
+    }
+
+    db.execute(FunUserListWhitelistedWalletsReq {
+        limit: 1,
+        offset: 0,
+        user_id: Some(ctx.user_id),
+        blockchain: Some(chain),
+        address: Some(wallet_address.into()),
+    })
+    .await?
+    .into_result()
+    .ok_or_else(|| {
+        CustomError::new(
+            EnumErrorCode::UserForbidden,
+            "withdraw wallet is not whitelisted",
+        )
+    })?;
+
+    let token_address = stablecoin_addresses
+        .get(chain, token)
+        .context("no stablecoin address")?;
+
+    let withdrawn_token_row = db
+        .execute(FunUserListEscrowTokenContractAddressReq {
+            limit: 1,
+            offset: 0,
+            token_id: None,
+            blockchain: Some(chain),
+            address: Some(token_address.into()),
+            symbol: None,
+            is_stablecoin: None,
+        })
+        .await?
+        .into_result()
+        .context("could not get withdrawn token contract from database")?;
+    let token_decimals = withdrawn_token_row.decimals as u32;
+
+    let balance_rows = db
+        .execute(FunUserListUserDepositWithdrawBalanceReq {
+            limit: None,
+            offset: None,
+            user_id: ctx.user_id,
+            user_address: None,
+            blockchain: Some(chain),
+            token_id: Some(withdrawn_token_row.token_id),
+            token_address: Some(token_address.into()),
+            escrow_contract_address: Some(escrow_contract.address().into()),
+        })
+        .await?
+        .into_rows()
+        .into_iter()
+        .filter(|row| row.balance > Decimal::zero())
+        .sorted_by(|a, b| b.balance.cmp(&a.balance))
+        .collect::<Vec<_>>();
+
+    let total_balance: Decimal = balance_rows.iter().map(|row| row.balance).sum();
+    if total_balance < quantity {
+        bail!(CustomError::new(
+            EnumErrorCode::InsufficientFunds,
+            "insufficient balance for withdraw"
+        ));
+    }
+
+    /* take the quantity from the wallets with the largest balances first */
+    let mut allocations = vec![];
+    let mut remaining = quantity;
+    for row in balance_rows {
+        if remaining.is_zero() {
+            break;
+        }
+        let amount = remaining.min(row.balance);
+        remaining -= amount;
+        allocations.push((row, amount));
+    }
+
+    /* every allocation is one transfer to the wallet, plus one transfer for the fee */
+    let (first_row, first_amount) = allocations.first().context("no balance to withdraw")?;
+    let estimated_transfer_gas = escrow_contract
+        .estimate_gas_transfer_asset_from(
+            escrow_signer.clone(),
+            first_row.user_address.clone().into(),
+            token_address,
+            decimal_to_u256(*first_amount, token_decimals),
+            wallet_address,
+        )
+        .await?;
+    let estimated_gas_price = conn.eth().gas_price().await?;
+    let estimated_fee = estimated_transfer_gas
+        .try_checked_mul(U256::from(allocations.len() + 1))?
+        .try_checked_mul(estimated_gas_price)?;
+    let fee = u256_to_decimal(
+        calculate_gas_fee_in_tokens(cmc, conn, &chain, token_address, estimated_fee).await?,
+        token_decimals,
+    );
+    /* the fee is paid from the first allocation, which is the largest one */
+    if fee >= *first_amount {
+        bail!(CustomError::new(
+            EnumErrorCode::InvalidArgument,
+            "withdraw quantity does not cover the gas fee"
+        ));
+    }
+
+    /* debit the whole quantity before anything goes on chain, so concurrent withdrawals can not
+    spend the same balance twice */
+    let reserved = &allocations;
+    db.transaction(|tx| async move {
+        for (balance_row, amount) in reserved.iter() {
+            tx.execute(FunUserDebitUserDepositWithdrawBalanceReq {
+                deposit_withdraw_balance_id: balance_row.deposit_withdraw_balance_id,
+                quantity: *amount,
+            })
+            .await?
+            .into_result()
+            .ok_or_else(|| {
+                CustomError::new(
+                    EnumErrorCode::InsufficientFunds,
+                    "insufficient balance for withdraw",
+                )
+            })?;
+        }
+        Ok(())
+    })
+    .await?;
+
+    let escrow_contract_address = escrow_contract.address();
+    for (i, (balance_row, amount)) in allocations.iter().enumerate() {
+        let proprietor: Address = balance_row.user_address.clone().into();
+        let mut transfers = vec![];
+        if i == 0 {
+            transfers.push((escrow_signer.address(), fee, true));
+            transfers.push((wallet_address, *amount - fee, false));
+        } else {
+            transfers.push((wallet_address, *amount, false));
+        }
+        let mut executed_transfers = vec![];
+        let mut transfer_result = Ok(());
+        for (recipient, transfer_amount, is_fee) in transfers {
+            let withdraw_transaction = || {
+                escrow_contract.transfer_asset_from(
+                    conn,
+                    escrow_signer.clone(),
+                    proprietor,
+                    token_address,
+                    decimal_to_u256(transfer_amount, token_decimals),
+                    recipient,
+                    logger.clone(),
+                )
+            };
+            match execute_transaction_and_track(
+                withdraw_transaction,
+                conn,
+                confirmations(),
+                max_retries(),
+                poll_interval(),
+                &logger,
+            )
+            .await
+            {
+                Ok(hash) => executed_transfers.push((recipient, transfer_amount, is_fee, hash)),
+                Err(err) => {
+                    transfer_result = Err((recipient, transfer_amount, is_fee, err));
+                    break;
+                }
+            }
+        }
+
+        /* the balance is already debited, record the confirmed transfers in the ledger */
+        if !executed_transfers.is_empty() {
+            let executed_transfers = &executed_transfers;
+            let ledger_entry_ids = db
+                .transaction(|tx| async move {
+                    let mut ledger_entry_ids = vec![];
+                    for (recipient, transfer_amount, is_fee, hash) in executed_transfers.iter() {
+                        let ledger_entry = tx
+                            .execute(FunUserAddUserDepositWithdrawLedgerEntryReq {
+                                user_id: ctx.user_id,
+                                token_address: token_address.into(),
+                                blockchain: chain,
+                                user_address: proprietor.into(),
+                                escrow_contract_address: escrow_contract_address.into(),
+                                receiver_address: (*recipient).into(),
+                                quantity: *transfer_amount,
+                                transaction_hash: (*hash).into(),
+                                is_deposit: false,
+                                is_back: false,
+                                is_withdraw: true,
+                                is_fee: *is_fee,
+                            })
+                            .await?
+                            .into_result()
+                            .context(
+                                "could not add entry to deposit withdraw ledger on request withdraw",
+                            )?;
+                        ledger_entry_ids.push(ledger_entry.ledger_entry_id);
+                    }
+                    Ok(ledger_entry_ids)
+                })
+                .await?;
+
+            for ((recipient, transfer_amount, _, hash), transaction_id) in
+                executed_transfers.iter().zip(ledger_entry_ids)
+            {
+                notify_ledger_change(&UserListDepositLedgerRow {
+                    transaction_id,
+                    blockchain: chain,
+                    user_address: proprietor.into(),
+                    contract_address: escrow_contract_address.into(),
+                    receiver_address: (*recipient).into(),
+                    quantity: (*transfer_amount).into(),
+                    transaction_hash: (*hash).into(),
+                    is_deposit: false,
+                    happened_at: Utc::now().timestamp(),
+                });
+            }
+        }
+
+        if let Err((recipient, transfer_amount, is_fee, failure)) = transfer_result {
+            let mut transferred: Decimal = executed_transfers.iter().map(|x| x.1).sum();
+            /* a transfer that may still be mined stays debited, the watcher settles it once its
+            outcome is known */
+            if let TransactionFailure::Unconfirmed { hash, .. } = &failure {
+                transferred += transfer_amount;
+                if let Err(pending_err) = db
+                    .execute(FunUserAddPendingWithdrawReq {
+                        user_id: ctx.user_id,
+                        deposit_withdraw_balance_id: balance_row.deposit_withdraw_balance_id,
+                        blockchain: chain,
+                        token_address: token_address.into(),
+                        user_address: proprietor.into(),
+                        escrow_contract_address: escrow_contract_address.into(),
+                        receiver_address: recipient.into(),
+                        quantity: transfer_amount,
+                        transaction_hash: (*hash).into(),
+                        is_fee,
+                    })
+                    .await
+                {
+                    error!(
+                        "failed to record pending withdraw transaction {:?} of {} from deposit withdraw balance {}: {:?}",
+                        hash, transfer_amount, balance_row.deposit_withdraw_balance_id, pending_err
+                    );
+                }
+            }
+            /* give back what never left the escrow: the rest of this allocation and the ones after it */
+            let refunds = std::iter::once((balance_row, *amount - transferred)).chain(
+                allocations[i + 1..]
+                    .iter()
+                    .map(|(row, amount)| (row, *amount)),
+            );
+            for (balance_row, refund) in refunds {
+                if refund.is_zero() {
+                    continue;
+                }
+                if let Err(refund_err) = db
+                    .execute(FunUserCreditUserDepositWithdrawBalanceReq {
+                        deposit_withdraw_balance_id: balance_row.deposit_withdraw_balance_id,
+                        quantity: refund,
+                    })
+                    .await
+                {
+                    error!(
+                        "failed to refund {} to deposit withdraw balance {} after a failed withdraw: {:?}",
+                        refund, balance_row.deposit_withdraw_balance_id, refund_err
+                    );
+                }
+            }
+            return Err(match failure {
+                TransactionFailure::NotExecuted(err) => err,
+                TransactionFailure::Unconfirmed { hash, error } => error.wrap_err(format!(
+                    "withdraw transaction {:?} is pending and will be settled once it is confirmed",
+                    hash
+                )),
+            });
+        }
+    }
+
+    Ok((quantity - fee, fee))
+}
 pub struct MethodUserUnfollowStrategy;
 impl RequestHandler for MethodUserUnfollowStrategy {
     type Request = UserUnfollowStrategyRequest;
@@ -1387,7 +1817,6 @@ impl RequestHandler for MethodUserUnfollowStrategy {
         let db: DbClient = toolbox.get_db();
 
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
             let ret = db
                 .execute(FunUserUnfollowStrategyReq {
                     user_id: ctx.user_id,
@@ -1418,7 +1847,6 @@ impl RequestHandler for MethodUserFollowExpert {
         let db: DbClient = toolbox.get_db();
 
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
             let ret = db
                 .execute(FunUserFollowExpertReq {
                     user_id: ctx.user_id,
@@ -1449,7 +1877,6 @@ impl RequestHandler for MethodUserListFollowedExperts {
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
             let ret = db
                 .execute(FunUserListFollowedExpertsReq {
                     user_id: ctx.user_id,
@@ -1478,7 +1905,6 @@ impl RequestHandler for MethodUserUnfollowExpert {
         let db: DbClient = toolbox.get_db();
 
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
             let ret = db
                 .execute(FunUserUnfollowExpertReq {
                     user_id: ctx.user_id,
@@ -1507,7 +1933,6 @@ impl RequestHandler for MethodUserListExperts {
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
             let ret = db
                 .execute(FunUserListExpertsReq {
                     offset: req.offset.unwrap_or(DEFAULT_OFFSET),
@@ -1521,7 +1946,11 @@ impl RequestHandler for MethodUserListExperts {
                     given_name: req.given_name,
                     description: req.description,
                     social_media: req.social_media,
-                    sort_by_followers: req.sort_by_followers.unwrap_or_default(),
+                    sort_by: if req.sort_by_followers.unwrap_or_default() {
+                        EnumExpertSortBy::Followers
+                    } else {
+                        EnumExpertSortBy::Newest
+                    },
                 })
                 .await?;
             Ok(UserListExpertsResponse {
@@ -1544,7 +1973,6 @@ impl RequestHandler for MethodUserListTopPerformingExperts {
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
             let ret = db
                 .execute(FunUserListExpertsReq {
                     user_id: ctx.user_id,
@@ -1558,7 +1986,7 @@ impl RequestHandler for MethodUserListTopPerformingExperts {
                     social_media: None,
                     offset: req.offset.unwrap_or(DEFAULT_OFFSET),
                     limit: req.limit.unwrap_or(DEFAULT_LIMIT),
-                    sort_by_followers: false,
+                    sort_by: EnumExpertSortBy::Reputation,
                 })
                 .await?;
             Ok(UserListTopPerformingExpertsResponse {
@@ -1581,7 +2009,6 @@ impl RequestHandler for MethodUserListFeaturedExperts {
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
             let ret = db
                 .execute(FunUserListExpertsReq {
                     offset: req.offset.unwrap_or(DEFAULT_OFFSET),
@@ -1595,7 +2022,7 @@ impl RequestHandler for MethodUserListFeaturedExperts {
                     given_name: None,
                     description: None,
                     social_media: None,
-                    sort_by_followers: false,
+                    sort_by: EnumExpertSortBy::Consistency,
                 })
                 .await?;
             Ok(UserListFeaturedExpertsResponse {
@@ -1613,12 +2040,11 @@ impl RequestHandler for MethodUserListExpertListenedWalletTradeLedger {
     fn handle(
         &self,
         toolbox: &Toolbox,
-        ctx: RequestContext,
+        _ctx: RequestContext,
         req: Self::Request,
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
             let ledger = db
                 .execute(FunUserListExpertListenedWalletTradeLedgerEntriesReq {
                     strategy_id: req.strategy_id,
@@ -1667,7 +2093,6 @@ impl RequestHandler for MethodUserGetExpertProfile {
         let db: DbClient = toolbox.get_db();
         let asset_client = self.asset_client.clone();
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
             let ret = db
                 .execute(FunUserGetExpertProfileReq {
                     expert_id: None,
@@ -1702,6 +2127,7 @@ impl RequestHandler for MethodUserGetExpertProfile {
                 backers_count: ret.backer_count as _,
                 description: ret.description.unwrap_or_default(),
                 social_media: ret.social_media.unwrap_or_default(),
+                consistent_score: ret.consistent_score.unwrap_or_default(),
                 risk_score: ret.risk_score.unwrap_or_default(),
                 aum: ret.aum.unwrap_or_default(),
                 reputation_score: ret.reputation_score.unwrap_or_default(),
@@ -1729,7 +2155,6 @@ impl RequestHandler for MethodUserUpdateUserProfile {
         let db: DbClient = toolbox.get_db();
         // TODO: handle 2nd db for auth
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
             db.execute(FunAuthUpdateUserTableReq {
                 user_id: ctx.user_id,
                 username: req.username,
@@ -1787,7 +2212,6 @@ impl RequestHandler for MethodUserGetUserProfile {
         let db: DbClient = toolbox.get_db();
         let asset_client = self.asset_client.clone();
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
             let ret = db
                 .execute(FunUserGetUserProfileReq {
                     user_id: ctx.user_id,
@@ -1848,8 +2272,6 @@ impl RequestHandler for MethodUserWhitelistWallet {
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
-
             // let signature_text = hex_decode(req.message_to_sign.as_bytes())?;
             // let signature = hex_decode(req.message_signature.as_bytes())?;
             //
@@ -1891,7 +2313,6 @@ impl RequestHandler for MethodUserListWhitelistedWallets {
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
             let blockchain_filter = if let Some(strategy_id) = req.strategy_id {
                 let strategy = db
                     .execute(FunUserListStrategiesReq {
@@ -1959,8 +2380,6 @@ impl RequestHandler for MethodUserUnwhitelistWallet {
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
-
             let _ret = db
                 .execute(FunUserRemoveWhitelistedWalletReq {
                     whitelisted_wallet_id: req.wallet_id,
@@ -1985,8 +2404,6 @@ impl RequestHandler for MethodUserApplyBecomeExpert {
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
-
             let ret = db
                 .execute(FunUserApplyBecomeExpertReq {
                     user_id: ctx.user_id,
@@ -2003,71 +2420,273 @@ impl RequestHandler for MethodUserApplyBecomeExpert {
         .boxed()
     }
 }
-pub struct MethodExpertCreateStrategy {
-    pub cmc_client: Arc<CoinMarketCap>,
-}
+/// largest document, in bytes, that can be attached to an expert application
+pub const MAX_EXPERT_APPLICATION_DOCUMENT_SIZE: usize = 10 << 20;
+pub const MAX_EXPERT_APPLICATION_DOCUMENTS: usize = 10;
 
-impl RequestHandler for MethodExpertCreateStrategy {
-    type Request = ExpertCreateStrategyRequest;
+pub struct MethodUserSaveExpertApplication;
+impl RequestHandler for MethodUserSaveExpertApplication {
+    type Request = UserSaveExpertApplicationRequest;
 
     fn handle(
         &self,
         toolbox: &Toolbox,
         ctx: RequestContext,
-        mut req: Self::Request,
+        req: Self::Request,
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
-        let cmc_client = self.cmc_client.clone();
         async move {
-            ensure_user_role(ctx, EnumRole::Expert)?;
-
             ensure!(
-                0.0 <= req.expert_fee && req.expert_fee <= 1.0,
+                req.questionnaire.is_object(),
                 CustomError::new(
                     EnumErrorCode::InvalidArgument,
-                    "Expert fee must be less than 1.0 and greater than 0.0"
+                    "questionnaire must be an object"
                 )
             );
-
             let ret = db
-                .execute(FunUserCreateStrategyReq {
+                .execute(FunUserSaveExpertApplicationReq {
                     user_id: ctx.user_id,
-                    name: req.name,
-                    description: req.description,
-                    strategy_thesis_url: req.strategy_thesis_url,
-                    minimum_backing_amount_usd: req.minimum_backing_amount_usd.unwrap_or_default(),
-                    swap_fee: 0.0,
-                    expert_fee: req.expert_fee,
-                    agreed_tos: req.agreed_tos,
-                    wallet_address: req.wallet_address.into(),
-                    blockchain: req.wallet_blockchain,
+                    questionnaire: req.questionnaire,
                 })
                 .await?
                 .into_result()
-                .context("failed to create strategy")?;
-            let audit_rules: Vec<_> = req
-                .audit_rules
-                .unwrap_or_default()
-                .into_iter()
-                .filter(|x| get_audit_rules().iter().map(|y| y.id).contains(x))
-                .collect();
+                .context("failed to save expert application")?;
 
-            for &s in &audit_rules {
-                db.execute(FunUserAddStrategyAuditRuleReq {
-                    strategy_id: ret.strategy_id,
-                    audit_rule_id: s,
-                })
-                .await?;
-            }
-            if audit_rules.iter().contains(&AUDIT_TOP25_TOKENS.id) {
-                let token_list = cmc_client.get_top_25_coins().await?;
-                for token in token_list {
-                    db.execute(FunUserAddStrategyWhitelistedTokenReq {
-                        strategy_id: ret.strategy_id,
-                        token_name: token.symbol,
-                    })
-                    .await?;
-                }
+            Ok(UserSaveExpertApplicationResponse {
+                application_id: ret.application_id,
+            })
+        }
+        .boxed()
+    }
+}
+pub struct MethodUserUploadExpertApplicationDocument {
+    pub blob_store: Arc<dyn BlobStore>,
+}
+impl RequestHandler for MethodUserUploadExpertApplicationDocument {
+    type Request = UserUploadExpertApplicationDocumentRequest;
+
+    fn handle(
+        &self,
+        toolbox: &Toolbox,
+        ctx: RequestContext,
+        req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let db: DbClient = toolbox.get_db();
+        let blob_store = self.blob_store.clone();
+        async move {
+            let content = STANDARD.decode(&req.content).map_err(|_| {
+                CustomError::new(EnumErrorCode::InvalidArgument, "content must be base64")
+            })?;
+            ensure!(
+                content.len() <= MAX_EXPERT_APPLICATION_DOCUMENT_SIZE,
+                CustomError::new(
+                    EnumErrorCode::InvalidArgument,
+                    format!(
+                        "document must be at most {} bytes",
+                        MAX_EXPERT_APPLICATION_DOCUMENT_SIZE
+                    )
+                )
+            );
+            if let Some(application) = db
+                .execute(FunUserGetExpertApplicationReq {
+                    user_id: Some(ctx.user_id),
+                    user_public_id: None,
+                })
+                .await?
+                .into_result()
+            {
+                let documents = db
+                    .execute(FunUserListExpertApplicationDocumentsReq {
+                        application_id: application.application_id,
+                    })
+                    .await?;
+                ensure!(
+                    documents.len() < MAX_EXPERT_APPLICATION_DOCUMENTS,
+                    CustomError::new(
+                        EnumErrorCode::InvalidArgument,
+                        format!(
+                            "an application takes at most {} documents",
+                            MAX_EXPERT_APPLICATION_DOCUMENTS
+                        )
+                    )
+                );
+            }
+
+            let blob_key = format!("expert_application/{}/{}", ctx.user_id, Uuid::new_v4());
+            let size = content.len() as i64;
+            blob_store.put(&blob_key, content).await?;
+            let ret = db
+                .execute(FunUserAddExpertApplicationDocumentReq {
+                    user_id: ctx.user_id,
+                    name: req.name,
+                    content_type: req.content_type,
+                    size,
+                    blob_key: blob_key.clone(),
+                })
+                .await;
+            let ret = match ret {
+                Ok(ret) => ret,
+                Err(err) => {
+                    if let Err(err) = blob_store.delete(&blob_key).await {
+                        warn!("Failed to delete blob {}: {:?}", blob_key, err);
+                    }
+                    return Err(err);
+                }
+            };
+
+            Ok(UserUploadExpertApplicationDocumentResponse {
+                document_id: ret
+                    .into_result()
+                    .context("failed to add expert application document")?
+                    .document_id,
+            })
+        }
+        .boxed()
+    }
+}
+pub struct MethodUserDeleteExpertApplicationDocument {
+    pub blob_store: Arc<dyn BlobStore>,
+}
+impl RequestHandler for MethodUserDeleteExpertApplicationDocument {
+    type Request = UserDeleteExpertApplicationDocumentRequest;
+
+    fn handle(
+        &self,
+        toolbox: &Toolbox,
+        ctx: RequestContext,
+        req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let db: DbClient = toolbox.get_db();
+        let blob_store = self.blob_store.clone();
+        async move {
+            let document = db
+                .execute(FunUserDeleteExpertApplicationDocumentReq {
+                    user_id: ctx.user_id,
+                    document_id: req.document_id,
+                })
+                .await?
+                .into_result()
+                .with_context(|| CustomError::new(EnumErrorCode::NotFound, "Document not found"))?;
+            blob_store.delete(&document.blob_key).await?;
+
+            Ok(UserDeleteExpertApplicationDocumentResponse { success: true })
+        }
+        .boxed()
+    }
+}
+pub struct MethodUserGetExpertApplication;
+impl RequestHandler for MethodUserGetExpertApplication {
+    type Request = UserGetExpertApplicationRequest;
+
+    fn handle(
+        &self,
+        toolbox: &Toolbox,
+        ctx: RequestContext,
+        _req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let db: DbClient = toolbox.get_db();
+        async move {
+            let Some(application) = db
+                .execute(FunUserGetExpertApplicationReq {
+                    user_id: Some(ctx.user_id),
+                    user_public_id: None,
+                })
+                .await?
+                .into_result()
+            else {
+                // nothing saved yet, the application is an empty draft
+                return Ok(UserGetExpertApplicationResponse {
+                    application_id: None,
+                    status: EnumExpertApplicationStatus::Draft,
+                    questionnaire: serde_json::json!({}),
+                    submitted_at: None,
+                    reviewed_at: None,
+                    documents: vec![],
+                    notes: vec![],
+                });
+            };
+            let (documents, notes) =
+                load_expert_application_details(&db, application.application_id).await?;
+
+            Ok(UserGetExpertApplicationResponse {
+                application_id: Some(application.application_id),
+                status: application.status,
+                questionnaire: application.questionnaire,
+                submitted_at: application.submitted_at,
+                reviewed_at: application.reviewed_at,
+                documents,
+                notes,
+            })
+        }
+        .boxed()
+    }
+}
+pub struct MethodExpertCreateStrategy {
+    pub cmc_client: Arc<CoinMarketCap>,
+    pub manager: Arc<SubscribeManager<AdminSubscribeTopic>>,
+}
+
+impl RequestHandler for MethodExpertCreateStrategy {
+    type Request = ExpertCreateStrategyRequest;
+
+    fn handle(
+        &self,
+        toolbox: &Toolbox,
+        ctx: RequestContext,
+        mut req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let db: DbClient = toolbox.get_db();
+        let toolbox = toolbox.clone();
+        let cmc_client = self.cmc_client.clone();
+        let manager = self.manager.clone();
+        async move {
+            ensure!(
+                0.0 <= req.expert_fee && req.expert_fee <= 1.0,
+                CustomError::new(
+                    EnumErrorCode::InvalidArgument,
+                    "Expert fee must be less than 1.0 and greater than 0.0"
+                )
+            );
+
+            let ret = db
+                .execute(FunUserCreateStrategyReq {
+                    user_id: ctx.user_id,
+                    name: req.name.clone(),
+                    description: req.description,
+                    strategy_thesis_url: req.strategy_thesis_url,
+                    minimum_backing_amount_usd: req.minimum_backing_amount_usd.unwrap_or_default(),
+                    swap_fee: 0.0,
+                    expert_fee: req.expert_fee,
+                    agreed_tos: req.agreed_tos,
+                    wallet_address: req.wallet_address.into(),
+                    blockchain: req.wallet_blockchain,
+                })
+                .await?
+                .into_result()
+                .context("failed to create strategy")?;
+            let audit_rules: Vec<_> = req
+                .audit_rules
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|x| get_audit_rules().iter().map(|y| y.id).contains(x))
+                .collect();
+
+            for &s in &audit_rules {
+                db.execute(FunUserAddStrategyAuditRuleReq {
+                    strategy_id: ret.strategy_id,
+                    audit_rule_id: s,
+                })
+                .await?;
+            }
+            if audit_rules.iter().contains(&AUDIT_TOP25_TOKENS.id) {
+                let token_list = cmc_client.get_top_25_coins().await?;
+                for token in token_list {
+                    db.execute(FunUserAddStrategyWhitelistedTokenReq {
+                        strategy_id: ret.strategy_id,
+                        token_name: token.symbol,
+                    })
+                    .await?;
+                }
             }
             let usdc = db
                 .execute(FunUserListEscrowTokenContractAddressReq {
@@ -2144,6 +2763,26 @@ impl RequestHandler for MethodExpertCreateStrategy {
                 })
                 .await?;
             }
+            let followers = db
+                .execute(FunUserListExpertFollowerIdsReq {
+                    expert_user_id: ctx.user_id,
+                })
+                .await?
+                .into_iter()
+                .map(|x| x.user_id)
+                .collect();
+            if let Err(err) = notify_users(
+                &toolbox,
+                &manager,
+                followers,
+                EnumUserNotificationKind::ExpertNewStrategy,
+                Some(ret.strategy_id),
+                format!("An expert you follow created strategy {}", req.name),
+            )
+            .await
+            {
+                error!("Failed to notify users of new strategy: {:?}", err);
+            }
 
             Ok(ExpertCreateStrategyResponse {
                 success: ret.success,
@@ -2168,7 +2807,6 @@ impl RequestHandler for MethodExpertUpdateStrategy {
         let db: DbClient = toolbox.get_db();
         let logger = self.logger.clone();
         async move {
-            ensure_user_role(ctx, EnumRole::Expert)?;
             validate_audit_rule_immutable_tokens(&logger, &db, req.strategy_id).await?;
             let strategy = db
                 .execute(FunUserListStrategiesReq {
@@ -2194,6 +2832,66 @@ impl RequestHandler for MethodExpertUpdateStrategy {
                 strategy.creator_id == ctx.user_id,
                 CustomError::new(EnumErrorCode::UserForbidden, "Not your strategy")
             );
+            if let Some(interval) = req.rebalance_interval_seconds {
+                ensure!(
+                    interval >= 0,
+                    CustomError::new(
+                        EnumErrorCode::InvalidArgument,
+                        "rebalance interval must not be negative"
+                    )
+                );
+            }
+            if let Some(threshold) = req.rebalance_drift_threshold {
+                ensure!(
+                    threshold > 0.0 && threshold < 1.0,
+                    CustomError::new(
+                        EnumErrorCode::InvalidArgument,
+                        "rebalance drift threshold must be between 0 and 1"
+                    )
+                );
+            }
+            if let Some(min_usd) = req.copy_trade_min_usd {
+                ensure!(
+                    min_usd >= 0.0 && min_usd.is_finite(),
+                    CustomError::new(
+                        EnumErrorCode::InvalidArgument,
+                        "copy trade minimum must not be negative"
+                    )
+                );
+            }
+            if let Some(ratio) = req.copy_trade_max_aum_ratio {
+                ensure!(
+                    (0.0..=1.0).contains(&ratio),
+                    CustomError::new(
+                        EnumErrorCode::InvalidArgument,
+                        "copy trade maximum AUM ratio must be between 0 and 1"
+                    )
+                );
+            }
+            if let Some(expert_fee) = req.expert_fee {
+                ensure!(
+                    (0.0..=1.0).contains(&expert_fee),
+                    CustomError::new(
+                        EnumErrorCode::InvalidArgument,
+                        "expert fee must be between 0 and 1"
+                    )
+                );
+            }
+            for seconds in [
+                req.copy_trade_cooldown_seconds,
+                req.copy_trade_batch_window_seconds,
+            ]
+            .into_iter()
+            .flatten()
+            {
+                ensure!(
+                    seconds >= 0,
+                    CustomError::new(
+                        EnumErrorCode::InvalidArgument,
+                        "copy trade cooldown and batching window must not be negative"
+                    )
+                );
+            }
             let ret = db
                 .execute(FunUserUpdateStrategyReq {
                     user_id: ctx.user_id,
@@ -2201,6 +2899,13 @@ impl RequestHandler for MethodExpertUpdateStrategy {
                     name: req.name,
                     description: req.description,
                     social_media: req.social_media,
+                    rebalance_interval_seconds: req.rebalance_interval_seconds,
+                    rebalance_drift_threshold: req.rebalance_drift_threshold,
+                    copy_trade_min_usd: req.copy_trade_min_usd,
+                    copy_trade_max_aum_ratio: req.copy_trade_max_aum_ratio,
+                    copy_trade_cooldown_seconds: req.copy_trade_cooldown_seconds,
+                    copy_trade_batch_window_seconds: req.copy_trade_batch_window_seconds,
+                    expert_fee: req.expert_fee,
                 })
                 .await?
                 .into_result()
@@ -2232,8 +2937,6 @@ impl RequestHandler for MethodExpertAddStrategyWatchingWallet {
         let logger = self.logger.clone();
         let pool = self.pool.clone();
         async move {
-            ensure_user_role(ctx, EnumRole::Expert)?;
-
             validate_audit_rule_immutable_tokens(&logger, &db, req.strategy_id).await?;
             let strategy = db
                 .execute(FunUserListStrategiesReq {
@@ -2358,7 +3061,6 @@ impl RequestHandler for MethodExpertRemoveStrategyWatchingWallet {
         let db: DbClient = toolbox.get_db();
         let logger = self.logger.clone();
         async move {
-            ensure_user_role(ctx, EnumRole::Expert)?;
             validate_audit_rule_immutable_tokens(&logger, &db, req.strategy_id).await?;
             let ret = db
                 .execute(FunUserRemoveStrategyWatchWalletReq {
@@ -2393,8 +3095,6 @@ impl RequestHandler for MethodExpertAddStrategyInitialTokenRatio {
         let db: DbClient = toolbox.get_db();
         let logger = self.logger.clone();
         async move {
-            ensure_user_role(ctx, EnumRole::Expert)?;
-
             validate_audit_rule_immutable_tokens(&logger, &db, req.strategy_id).await?;
             let strategy = db
                 .execute(FunUserListStrategiesReq {
@@ -2454,8 +3154,8 @@ impl RequestHandler for MethodExpertRemoveStrategyInitialTokenRatio {
         let logger = self.logger.clone();
 
         async move {
-            ensure_user_role(ctx, EnumRole::Expert)?;
             validate_audit_rule_immutable_tokens(&logger, &db, req.strategy_id).await?;
+            ensure_expert_owns_strategy(&db, ctx, req.strategy_id).await?;
             let _ret = db
                 .execute(FunUserRemoveStrategyInitialTokenRatioReq {
                     token_id: req.token_id,
@@ -2477,14 +3177,12 @@ impl RequestHandler for MethodUserListStrategyInitialTokenRatio {
     fn handle(
         &self,
         toolbox: &Toolbox,
-        ctx: RequestContext,
+        _ctx: RequestContext,
         req: Self::Request,
     ) -> FutureResponse<Self::Request> {
         let db: DbClient = toolbox.get_db();
 
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
-
             let ret = db
                 .execute(FunUserListStrategyInitialTokenRatiosReq {
                     strategy_id: req.strategy_id,
@@ -2523,8 +3221,6 @@ impl RequestHandler for MethodExpertListFollowers {
         let db: DbClient = toolbox.get_db();
 
         async move {
-            ensure_user_role(ctx, EnumRole::Expert)?;
-
             let ret = db
                 .execute(FunExpertListFollowersReq {
                     user_id: ctx.user_id,
@@ -2566,8 +3262,6 @@ impl RequestHandler for MethodExpertListBackers {
         let db: DbClient = toolbox.get_db();
 
         async move {
-            ensure_user_role(ctx, EnumRole::Expert)?;
-
             let ret = db
                 .execute(FunExpertListBackersReq {
                     user_id: ctx.user_id,
@@ -2999,7 +3693,7 @@ impl RequestHandler for MethodUserAddStrategyAuditRule {
                 CustomError::new(EnumErrorCode::UserForbidden, "Not your strategy")
             );
             ensure!(
-                strategy.immutable_audit_rules,
+                !strategy.immutable_audit_rules,
                 CustomError::new(EnumErrorCode::UserForbidden, "Strategy rules immutable")
             );
             db.execute(FunUserAddStrategyAuditRuleReq {
@@ -3049,7 +3743,7 @@ impl RequestHandler for MethodUserRemoveStrategyAuditRule {
                 CustomError::new(EnumErrorCode::UserForbidden, "Not your strategy")
             );
             ensure!(
-                strategy.immutable_audit_rules,
+                !strategy.immutable_audit_rules,
                 CustomError::new(EnumErrorCode::UserForbidden, "Strategy rules immutable")
             );
             db.execute(FunUserDelStrategyAuditRuleReq {
@@ -3136,12 +3830,11 @@ impl RequestHandler for MethodUserListEscrowTokenContractAddresses {
     fn handle(
         &self,
         toolbox: &Toolbox,
-        ctx: RequestContext,
+        _ctx: RequestContext,
         req: Self::Request,
     ) -> FutureResponse<Self::Request> {
         let db = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
             let balances = db
                 .execute(FunUserListEscrowTokenContractAddressReq {
                     limit: req.limit.unwrap_or(DEFAULT_LIMIT),
@@ -3181,7 +3874,6 @@ impl RequestHandler for MethodUserListBackStrategyLedger {
     ) -> FutureResponse<Self::Request> {
         let db = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
             let ledger = db
                 .execute(FunUserListBackStrategyLedgerReq {
                     limit: req.limit.unwrap_or(DEFAULT_LIMIT),
@@ -3213,12 +3905,11 @@ impl RequestHandler for MethodExpertListBackStrategyLedger {
     fn handle(
         &self,
         toolbox: &Toolbox,
-        ctx: RequestContext,
+        _ctx: RequestContext,
         req: Self::Request,
     ) -> FutureResponse<Self::Request> {
         let db = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::Expert)?;
             let ledger = db
                 .execute(FunUserListBackStrategyLedgerReq {
                     limit: req.limit.unwrap_or(DEFAULT_LIMIT),
@@ -3255,7 +3946,6 @@ impl RequestHandler for MethodUserListExitStrategyLedger {
     ) -> FutureResponse<Self::Request> {
         let db = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
             let ledger = db
                 .execute(FunUserListExitStrategyLedgerReq {
                     limit: req.limit.unwrap_or(DEFAULT_LIMIT),
@@ -3287,12 +3977,11 @@ impl RequestHandler for MethodExpertListExitStrategyLedger {
     fn handle(
         &self,
         toolbox: &Toolbox,
-        ctx: RequestContext,
+        _ctx: RequestContext,
         req: Self::Request,
     ) -> FutureResponse<Self::Request> {
         let db = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::Expert)?;
             let ledger = db
                 .execute(FunUserListExitStrategyLedgerReq {
                     limit: req.limit.unwrap_or(DEFAULT_LIMIT),
@@ -3414,7 +4103,6 @@ impl RequestHandler for MethodUserGetBackStrategyReviewDetail {
         let asset_client = self.asset_client.clone();
         let escrow_contract = self.escrow_contract.clone();
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
             let token = db
                 .execute(FunUserListEscrowTokenContractAddressReq {
                     limit: 1,
@@ -3610,12 +4298,11 @@ impl RequestHandler for MethodUserListUserBackStrategyLog {
     fn handle(
         &self,
         toolbox: &Toolbox,
-        ctx: RequestContext,
+        _ctx: RequestContext,
         req: Self::Request,
     ) -> FutureResponse<Self::Request> {
         let db = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
             let logs = db
                 .execute(FunUserListUserBackStrategyLogReq {
                     limit: req.limit.unwrap_or(DEFAULT_LIMIT),
@@ -3643,12 +4330,11 @@ impl RequestHandler for MethodUserGetSystemConfig {
     fn handle(
         &self,
         toolbox: &Toolbox,
-        ctx: RequestContext,
+        _ctx: RequestContext,
         _req: Self::Request,
     ) -> FutureResponse<Self::Request> {
         let db = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
             let config = db
                 .execute(FunAdminGetSystemConfigReq { config_id: 0 })
                 .await?
@@ -3676,7 +4362,6 @@ impl RequestHandler for MethodExpertListPublishedStrategies {
         let db = toolbox.get_db();
         let asset_client = self.asset_client.clone();
         async move {
-            ensure_user_role(ctx, EnumRole::Expert)?;
             let strategies = db
                 .execute(FunUserListStrategiesReq {
                     user_id: ctx.user_id,
@@ -3719,7 +4404,6 @@ impl RequestHandler for MethodExpertListUnpublishedStrategies {
         let db = toolbox.get_db();
         let asset_client = self.asset_client.clone();
         async move {
-            ensure_user_role(ctx, EnumRole::Expert)?;
             let strategies = db
                 .execute(FunUserListStrategiesReq {
                     user_id: ctx.user_id,
@@ -3758,7 +4442,6 @@ impl RequestHandler for MethodUserListUserStrategyBalance {
     ) -> FutureResponse<Self::Request> {
         let db = toolbox.get_db();
         async move {
-            ensure_user_role(ctx, EnumRole::User)?;
             let balances = db
                 .execute(FunUserListUserStrategyBalanceReq {
                     user_id: ctx.user_id,
@@ -3781,3 +4464,904 @@ impl RequestHandler for MethodUserListUserStrategyBalance {
         .boxed()
     }
 }
+/// most rows a CSV activity report holds, the response says when the range had more
+pub const ACTIVITY_REPORT_CSV_MAX_ROWS: i64 = 100_000;
+
+pub struct MethodUserExportActivityReport;
+impl RequestHandler for MethodUserExportActivityReport {
+    type Request = UserExportActivityReportRequest;
+
+    fn handle(
+        &self,
+        toolbox: &Toolbox,
+        ctx: RequestContext,
+        req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let db = toolbox.get_db();
+        async move {
+            if req.start_time >= req.end_time {
+                bail!(CustomError::new(
+                    EnumErrorCode::InvalidRange,
+                    "start_time must be before end_time"
+                ));
+            }
+            let format = req.format.unwrap_or(EnumActivityReportFormat::Json);
+            /* a CSV export is a file of the whole range, not a page */
+            let limit = match format {
+                EnumActivityReportFormat::Json => req.limit.unwrap_or(DEFAULT_LIMIT),
+                EnumActivityReportFormat::Csv => req
+                    .limit
+                    .unwrap_or(ACTIVITY_REPORT_CSV_MAX_ROWS)
+                    .min(ACTIVITY_REPORT_CSV_MAX_ROWS),
+            };
+            let offset = req.offset.unwrap_or(DEFAULT_OFFSET);
+            let ret = db
+                .execute(FunUserListUserActivityReportReq {
+                    user_id: ctx.user_id,
+                    start_time: req.start_time,
+                    end_time: req.end_time,
+                    blockchain: req.blockchain,
+                    limit,
+                    offset,
+                })
+                .await?;
+            let report_total = ret.first(|x| x.total).unwrap_or_default();
+            let truncated = offset + (ret.len() as i64) < report_total;
+            let report = ret.map(|x| UserActivityReportRow {
+                event_type: x.event_type,
+                blockchain: x.blockchain,
+                strategy_id: x.strategy_id,
+                transaction_hash: x.transaction_hash.map(|x| x.into()),
+                token_symbol: x.token_symbol,
+                token_address: x.token_address.map(|x| x.into()),
+                quantity: x.quantity,
+                usd_price: x.usd_price,
+                usd_value: x.usd_value,
+                fees: x.fees,
+                happened_at: x.happened_at,
+            });
+            match format {
+                EnumActivityReportFormat::Json => Ok(UserExportActivityReportResponse {
+                    report_total,
+                    report,
+                    csv: None,
+                    truncated,
+                }),
+                EnumActivityReportFormat::Csv => Ok(UserExportActivityReportResponse {
+                    report_total,
+                    report: vec![],
+                    csv: Some(activity_report_to_csv(&report)?),
+                    truncated,
+                }),
+            }
+        }
+        .boxed()
+    }
+}
+
+fn activity_report_to_csv(rows: &[UserActivityReportRow]) -> Result<String> {
+    let mut table = DataTable::new();
+    for column in [
+        "event_type",
+        "blockchain",
+        "strategy_id",
+        "transaction_hash",
+        "token_symbol",
+        "token_address",
+        "quantity",
+        "usd_price",
+        "usd_value",
+        "fees",
+    ] {
+        table.add_column(column, Column::String(vec![]));
+    }
+    table.add_column("happened_at", Column::Int(vec![]));
+    for row in rows {
+        table.add_row([
+            row.event_type.to_string().into(),
+            row.blockchain.to_string().into(),
+            row.strategy_id
+                .map(|x| x.to_string())
+                .unwrap_or_default()
+                .into(),
+            row.transaction_hash
+                .map(|x| format!("{:?}", x))
+                .unwrap_or_default()
+                .into(),
+            row.token_symbol.clone().into(),
+            row.token_address
+                .map(|x| format!("{:?}", x))
+                .unwrap_or_default()
+                .into(),
+            row.quantity.to_string().into(),
+            row.usd_price
+                .map(|x| x.to_string())
+                .unwrap_or_default()
+                .into(),
+            row.usd_value
+                .map(|x| x.to_string())
+                .unwrap_or_default()
+                .into(),
+            row.fees.to_string().into(),
+            row.happened_at.into(),
+        ]);
+    }
+    let mut buf = vec![];
+    table.write_csv(&mut buf)?;
+    Ok(String::from_utf8(buf)?)
+}
+
+async fn ensure_expert_owns_strategy(
+    db: &DbClient,
+    ctx: RequestContext,
+    strategy_id: i64,
+) -> Result<FunUserStrategyRowType> {
+    let strategy = db
+        .execute(FunUserListStrategiesReq {
+            strategy_id: Some(strategy_id),
+            strategy_name: None,
+            expert_id: None,
+            expert_public_id: None,
+            expert_name: None,
+            description: None,
+            blockchain: None,
+            user_id: ctx.user_id,
+            limit: 1,
+            offset: 0,
+            strategy_pool_address: None,
+            approved: None,
+        })
+        .await?
+        .into_result()
+        .with_context(|| CustomError::new(EnumErrorCode::NotFound, "failed to find strategy"))?;
+    ensure!(
+        strategy.creator_id == ctx.user_id,
+        CustomError::new(EnumErrorCode::UserForbidden, "Not your strategy")
+    );
+    Ok(strategy)
+}
+
+pub struct MethodExpertAddStrategyGuardRule;
+impl RequestHandler for MethodExpertAddStrategyGuardRule {
+    type Request = ExpertAddStrategyGuardRuleRequest;
+
+    fn handle(
+        &self,
+        toolbox: &Toolbox,
+        ctx: RequestContext,
+        req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let db: DbClient = toolbox.get_db();
+        async move {
+            let strategy = ensure_expert_owns_strategy(&db, ctx, req.strategy_id).await?;
+
+            /* drawdown and stop-loss thresholds are fractions of the reference value that may be lost */
+            let threshold_ok = match req.rule_type {
+                EnumStrategyGuardRuleType::MaxDrawdown
+                | EnumStrategyGuardRuleType::AssetStopLoss => {
+                    req.threshold > 0.0 && req.threshold < 1.0
+                }
+                EnumStrategyGuardRuleType::TakeProfit => {
+                    req.threshold > 0.0 && req.threshold.is_finite()
+                }
+            };
+            ensure!(
+                threshold_ok,
+                CustomError::new(
+                    EnumErrorCode::InvalidArgument,
+                    format!("invalid threshold {} for {}", req.threshold, req.rule_type)
+                )
+            );
+            match (req.rule_type, req.token_id) {
+                (EnumStrategyGuardRuleType::AssetStopLoss, None) => bail!(CustomError::new(
+                    EnumErrorCode::InvalidArgument,
+                    "asset stop-loss requires token_id"
+                )),
+                (EnumStrategyGuardRuleType::AssetStopLoss, Some(token_id)) => {
+                    db.execute(FunUserListEscrowTokenContractAddressReq {
+                        limit: 1,
+                        offset: 0,
+                        token_id: Some(token_id),
+                        blockchain: Some(strategy.blockchain),
+                        address: None,
+                        symbol: None,
+                        is_stablecoin: None,
+                    })
+                    .await?
+                    .into_result()
+                    .with_context(|| {
+                        CustomError::new(EnumErrorCode::NotFound, "token not found on this chain")
+                    })?;
+                }
+                (_, Some(_)) => bail!(CustomError::new(
+                    EnumErrorCode::InvalidArgument,
+                    "token_id is only used by asset stop-loss"
+                )),
+                (_, None) => {}
+            }
+            if let Some(exit_token_id) = req.exit_token_id {
+                let exit_token = db
+                    .execute(FunUserListEscrowTokenContractAddressReq {
+                        limit: 1,
+                        offset: 0,
+                        token_id: Some(exit_token_id),
+                        blockchain: Some(strategy.blockchain),
+                        address: None,
+                        symbol: None,
+                        is_stablecoin: None,
+                    })
+                    .await?
+                    .into_result()
+                    .with_context(|| {
+                        CustomError::new(
+                            EnumErrorCode::NotFound,
+                            "exit token not found on this chain",
+                        )
+                    })?;
+                ensure!(
+                    exit_token.is_stablecoin,
+                    CustomError::new(
+                        EnumErrorCode::InvalidArgument,
+                        "exit token must be a stablecoin"
+                    )
+                );
+            }
+
+            let ret = db
+                .execute(FunExpertAddStrategyGuardRuleReq {
+                    strategy_id: req.strategy_id,
+                    rule_type: req.rule_type,
+                    token_id: req.token_id,
+                    threshold: req.threshold,
+                    exit_token_id: req.exit_token_id,
+                })
+                .await?
+                .into_result()
+                .context("failed to add strategy guard rule")?;
+
+            Ok(ExpertAddStrategyGuardRuleResponse {
+                rule_id: ret.rule_id,
+            })
+        }
+        .boxed()
+    }
+}
+
+pub struct MethodExpertRemoveStrategyGuardRule;
+impl RequestHandler for MethodExpertRemoveStrategyGuardRule {
+    type Request = ExpertRemoveStrategyGuardRuleRequest;
+
+    fn handle(
+        &self,
+        toolbox: &Toolbox,
+        ctx: RequestContext,
+        req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let db: DbClient = toolbox.get_db();
+        async move {
+            ensure_expert_owns_strategy(&db, ctx, req.strategy_id).await?;
+
+            let ret = db
+                .execute(FunExpertRemoveStrategyGuardRuleReq {
+                    strategy_id: req.strategy_id,
+                    rule_id: req.rule_id,
+                })
+                .await?
+                .into_result()
+                .context("failed to remove strategy guard rule")?;
+            ensure!(
+                ret.success,
+                CustomError::new(EnumErrorCode::NotFound, "guard rule not found")
+            );
+
+            Ok(ExpertRemoveStrategyGuardRuleResponse { success: true })
+        }
+        .boxed()
+    }
+}
+
+pub struct MethodUserListStrategyGuardRules;
+impl RequestHandler for MethodUserListStrategyGuardRules {
+    type Request = UserListStrategyGuardRulesRequest;
+
+    fn handle(
+        &self,
+        toolbox: &Toolbox,
+        _ctx: RequestContext,
+        req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let db: DbClient = toolbox.get_db();
+        async move {
+            let ret = db
+                .execute(FunUserListStrategyGuardRulesReq {
+                    strategy_id: req.strategy_id,
+                })
+                .await?;
+
+            Ok(UserListStrategyGuardRulesResponse {
+                rules_total: ret.first(|x| x.total).unwrap_or_default(),
+                rules: ret.map(|x| ListStrategyGuardRulesRow {
+                    rule_id: x.rule_id,
+                    rule_type: x.rule_type,
+                    token_id: x.token_id,
+                    token_symbol: x.token_symbol,
+                    threshold: x.threshold,
+                    reference_value: x.reference_value,
+                    exit_token_id: x.exit_token_id,
+                    exit_token_symbol: x.exit_token_symbol,
+                    triggered_at: x.triggered_at,
+                    created_at: x.created_at,
+                }),
+            })
+        }
+        .boxed()
+    }
+}
+
+pub struct MethodExpertResumeStrategyCopyTrading;
+impl RequestHandler for MethodExpertResumeStrategyCopyTrading {
+    type Request = ExpertResumeStrategyCopyTradingRequest;
+
+    fn handle(
+        &self,
+        toolbox: &Toolbox,
+        ctx: RequestContext,
+        req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let db: DbClient = toolbox.get_db();
+        async move {
+            ensure_expert_owns_strategy(&db, ctx, req.strategy_id).await?;
+
+            db.execute(FunExpertResumeStrategyCopyTradingReq {
+                strategy_id: req.strategy_id,
+            })
+            .await?;
+
+            Ok(ExpertResumeStrategyCopyTradingResponse { success: true })
+        }
+        .boxed()
+    }
+}
+
+pub struct MethodUserSubscribeStrategyGuardEvents {
+    pub manger: Arc<SubscribeManager<AdminSubscribeTopic>>,
+}
+impl RequestHandler for MethodUserSubscribeStrategyGuardEvents {
+    type Request = UserSubscribeStrategyGuardEventsRequest;
+
+    fn handle(
+        &self,
+        _toolbox: &Toolbox,
+        ctx: RequestContext,
+        _req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let manager = self.manger.clone();
+        async move {
+            manager.subscribe(AdminSubscribeTopic::StrategyGuardTriggered, ctx);
+
+            Ok(UserSubscribeStrategyGuardEventsResponse {})
+        }
+        .boxed()
+    }
+}
+
+pub struct MethodUserUnsubscribeStrategyGuardEvents {
+    pub manger: Arc<SubscribeManager<AdminSubscribeTopic>>,
+}
+impl RequestHandler for MethodUserUnsubscribeStrategyGuardEvents {
+    type Request = UserUnsubscribeStrategyGuardEventsRequest;
+
+    fn handle(
+        &self,
+        _toolbox: &Toolbox,
+        ctx: RequestContext,
+        _req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let manager = self.manger.clone();
+        async move {
+            manager.unsubscribe(
+                AdminSubscribeTopic::StrategyGuardTriggered,
+                ctx.connection_id,
+            );
+
+            Ok(UserUnsubscribeStrategyGuardEventsResponse {})
+        }
+        .boxed()
+    }
+}
+
+pub struct MethodUserListNotifications;
+impl RequestHandler for MethodUserListNotifications {
+    type Request = UserListNotificationsRequest;
+
+    fn handle(
+        &self,
+        toolbox: &Toolbox,
+        ctx: RequestContext,
+        req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let db: DbClient = toolbox.get_db();
+        async move {
+            let ret = db
+                .execute(FunUserListNotificationsReq {
+                    user_id: ctx.user_id,
+                    unread_only: req.unread_only.unwrap_or_default(),
+                    limit: req.limit.unwrap_or(DEFAULT_LIMIT),
+                    offset: req.offset.unwrap_or(DEFAULT_OFFSET),
+                })
+                .await?;
+
+            Ok(UserListNotificationsResponse {
+                notifications_total: ret.first(|x| x.total).unwrap_or_default(),
+                unread_total: ret.first(|x| x.unread_total).unwrap_or_default(),
+                notifications: ret.map(|x| UserNotificationRow {
+                    notification_id: x.notification_id,
+                    kind: x.kind,
+                    strategy_id: x.strategy_id,
+                    message: x.message,
+                    read_at: x.read_at,
+                    created_at: x.created_at,
+                }),
+            })
+        }
+        .boxed()
+    }
+}
+
+pub struct MethodUserMarkNotificationsRead;
+impl RequestHandler for MethodUserMarkNotificationsRead {
+    type Request = UserMarkNotificationsReadRequest;
+
+    fn handle(
+        &self,
+        toolbox: &Toolbox,
+        ctx: RequestContext,
+        req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let db: DbClient = toolbox.get_db();
+        async move {
+            let ret = db
+                .execute(FunUserMarkNotificationsReadReq {
+                    user_id: ctx.user_id,
+                    notification_ids: req.notification_ids,
+                })
+                .await?
+                .into_result()
+                .context("failed to mark notifications as read")?;
+
+            Ok(UserMarkNotificationsReadResponse { marked: ret.marked })
+        }
+        .boxed()
+    }
+}
+
+pub struct MethodUserSubscribeNotifications {
+    pub manger: Arc<SubscribeManager<AdminSubscribeTopic>>,
+}
+impl RequestHandler for MethodUserSubscribeNotifications {
+    type Request = UserSubscribeNotificationsRequest;
+
+    fn handle(
+        &self,
+        _toolbox: &Toolbox,
+        ctx: RequestContext,
+        _req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let manager = self.manger.clone();
+        async move {
+            manager.subscribe(AdminSubscribeTopic::UserNotification, ctx);
+
+            Ok(UserSubscribeNotificationsResponse {})
+        }
+        .boxed()
+    }
+}
+
+pub struct MethodUserUnsubscribeNotifications {
+    pub manger: Arc<SubscribeManager<AdminSubscribeTopic>>,
+}
+impl RequestHandler for MethodUserUnsubscribeNotifications {
+    type Request = UserUnsubscribeNotificationsRequest;
+
+    fn handle(
+        &self,
+        _toolbox: &Toolbox,
+        ctx: RequestContext,
+        _req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let manager = self.manger.clone();
+        async move {
+            manager.unsubscribe(AdminSubscribeTopic::UserNotification, ctx.connection_id);
+
+            Ok(UserUnsubscribeNotificationsResponse {})
+        }
+        .boxed()
+    }
+}
+
+pub struct MethodUserListSessions;
+impl RequestHandler for MethodUserListSessions {
+    type Request = UserListSessionsRequest;
+
+    fn handle(
+        &self,
+        toolbox: &Toolbox,
+        ctx: RequestContext,
+        _req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let db_auth: DbClient = toolbox.get_nth_db(1);
+        async move {
+            let ret = db_auth
+                .execute(FunUserListSessionsReq {
+                    user_id: ctx.user_id,
+                })
+                .await?;
+
+            Ok(UserListSessionsResponse {
+                sessions: ret.map(|x| UserSessionRow {
+                    session_id: x.session_id,
+                    service: x.service,
+                    device_id: x.device_id,
+                    device_os: x.device_os,
+                    ip_address: x.ip_address,
+                    created_at: x.created_at,
+                    last_seen_at: x.last_seen_at,
+                    expires_at: x.expires_at,
+                }),
+            })
+        }
+        .boxed()
+    }
+}
+
+pub struct MethodUserRevokeSession {
+    pub event_bus: EventBus<DomainEvent>,
+}
+impl RequestHandler for MethodUserRevokeSession {
+    type Request = UserRevokeSessionRequest;
+
+    fn handle(
+        &self,
+        toolbox: &Toolbox,
+        ctx: RequestContext,
+        req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let db_auth: DbClient = toolbox.get_nth_db(1);
+        let toolbox = toolbox.clone();
+        let event_bus = self.event_bus.clone();
+        async move {
+            let ret = db_auth
+                .execute(FunUserRevokeSessionReq {
+                    user_id: ctx.user_id,
+                    session_id: req.session_id,
+                })
+                .await?
+                .into_result()
+                .context("failed to revoke session")?;
+            if !ret.revoked {
+                bail!(CustomError::new(
+                    EnumErrorCode::NotFound,
+                    "Session not found or already revoked"
+                ));
+            }
+            /* answer first, the caller may be revoking its own session */
+            toolbox.send_response(&ctx, UserRevokeSessionResponse {});
+            let closed = toolbox.disconnect_user_session(req.session_id);
+            info!(
+                "User {} revoked session {}, closed {} connections",
+                ctx.user_id, req.session_id, closed
+            );
+            /* the session may also be connected to other instances of the service */
+            if let Err(err) = event_bus
+                .publish(&DomainEvent::SessionRevoked(SessionRevokedEvent {
+                    user_id: ctx.user_id,
+                    user_session_id: req.session_id,
+                }))
+                .await
+            {
+                error!(
+                    "Failed to publish revocation of session {}: {:?}",
+                    req.session_id, err
+                );
+            }
+            bail!(NoResponseError)
+        }
+        .boxed()
+    }
+}
+
+pub struct MethodUserCreateApiKey;
+impl RequestHandler for MethodUserCreateApiKey {
+    type Request = UserCreateApiKeyRequest;
+
+    fn handle(
+        &self,
+        toolbox: &Toolbox,
+        ctx: RequestContext,
+        req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let db_auth: DbClient = toolbox.get_nth_db(1);
+        async move {
+            ensure!(
+                !req.name.is_empty() && req.name.len() <= 64,
+                CustomError::new(
+                    EnumErrorCode::InvalidArgument,
+                    "name must be between 1 and 64 characters"
+                )
+            );
+            ensure!(
+                !req.scopes.is_empty(),
+                CustomError::new(
+                    EnumErrorCode::InvalidArgument,
+                    "at least one scope is required"
+                )
+            );
+            if let Some(expires_at) = req.expires_at {
+                ensure!(
+                    expires_at > Utc::now().timestamp(),
+                    CustomError::new(EnumErrorCode::InvalidArgument, "expires_at is in the past")
+                );
+            }
+            let api_key = uuid::Uuid::new_v4().simple().to_string();
+            let api_secret = hex::encode(rand::random::<[u8; 32]>());
+            let ret = db_auth
+                .execute(FunUserCreateApiKeyReq {
+                    user_id: ctx.user_id,
+                    name: req.name,
+                    api_key: api_key.clone(),
+                    api_secret: api_secret.clone(),
+                    scopes: req.scopes.into_iter().unique().collect(),
+                    ip_allowlist: req.ip_allowlist,
+                    expires_at: req.expires_at,
+                })
+                .await?
+                .into_result()
+                .context("failed to create api key")?;
+
+            Ok(UserCreateApiKeyResponse {
+                api_key_id: ret.api_key_id,
+                api_key,
+                api_secret,
+            })
+        }
+        .boxed()
+    }
+}
+
+pub struct MethodUserListApiKeys;
+impl RequestHandler for MethodUserListApiKeys {
+    type Request = UserListApiKeysRequest;
+
+    fn handle(
+        &self,
+        toolbox: &Toolbox,
+        ctx: RequestContext,
+        _req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let db_auth: DbClient = toolbox.get_nth_db(1);
+        async move {
+            let ret = db_auth
+                .execute(FunUserListApiKeysReq {
+                    user_id: ctx.user_id,
+                })
+                .await?;
+
+            Ok(UserListApiKeysResponse {
+                api_keys: ret.map(|x| UserApiKeyRow {
+                    api_key_id: x.api_key_id,
+                    name: x.name,
+                    api_key: x.api_key,
+                    scopes: x.scopes,
+                    ip_allowlist: x.ip_allowlist,
+                    expires_at: x.expires_at,
+                    created_at: x.created_at,
+                    last_used_at: x.last_used_at,
+                }),
+            })
+        }
+        .boxed()
+    }
+}
+
+pub struct MethodUserRevokeApiKey {
+    pub event_bus: EventBus<DomainEvent>,
+}
+impl RequestHandler for MethodUserRevokeApiKey {
+    type Request = UserRevokeApiKeyRequest;
+
+    fn handle(
+        &self,
+        toolbox: &Toolbox,
+        ctx: RequestContext,
+        req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let db_auth: DbClient = toolbox.get_nth_db(1);
+        let toolbox = toolbox.clone();
+        let event_bus = self.event_bus.clone();
+        async move {
+            let ret = db_auth
+                .execute(FunUserRevokeApiKeyReq {
+                    user_id: ctx.user_id,
+                    api_key_id: req.api_key_id,
+                })
+                .await?
+                .into_result()
+                .context("failed to revoke api key")?;
+            if !ret.revoked {
+                bail!(CustomError::new(
+                    EnumErrorCode::NotFound,
+                    "API key not found or already revoked"
+                ));
+            }
+            toolbox.send_response(&ctx, UserRevokeApiKeyResponse {});
+            let closed = toolbox.disconnect_api_key(req.api_key_id);
+            info!(
+                "User {} revoked api key {}, closed {} connections",
+                ctx.user_id, req.api_key_id, closed
+            );
+            if let Err(err) = event_bus
+                .publish(&DomainEvent::ApiKeyRevoked(ApiKeyRevokedEvent {
+                    user_id: ctx.user_id,
+                    api_key_id: req.api_key_id,
+                }))
+                .await
+            {
+                error!(
+                    "Failed to publish revocation of api key {}: {:?}",
+                    req.api_key_id, err
+                );
+            }
+            bail!(NoResponseError)
+        }
+        .boxed()
+    }
+}
+
+pub struct MethodUserListLoginWallets;
+impl RequestHandler for MethodUserListLoginWallets {
+    type Request = UserListLoginWalletsRequest;
+
+    fn handle(
+        &self,
+        toolbox: &Toolbox,
+        ctx: RequestContext,
+        _req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let db_auth: DbClient = toolbox.get_nth_db(1);
+        async move {
+            let ret = db_auth
+                .execute(FunUserListLoginWalletsReq {
+                    user_id: ctx.user_id,
+                })
+                .await?;
+
+            Ok(UserListLoginWalletsResponse {
+                wallets: ret.map(|x| UserLoginWalletRow {
+                    address: x.address.into(),
+                    is_primary: x.is_primary,
+                    created_at: x.created_at,
+                }),
+            })
+        }
+        .boxed()
+    }
+}
+
+pub struct MethodUserLinkLoginWallet {
+    pub allow_cors_sites: Reloadable<Option<Vec<String>>>,
+}
+impl RequestHandler for MethodUserLinkLoginWallet {
+    type Request = UserLinkLoginWalletRequest;
+
+    fn handle(
+        &self,
+        toolbox: &Toolbox,
+        ctx: RequestContext,
+        req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let db_auth: DbClient = toolbox.get_nth_db(1);
+        let allow_cors_sites = self.allow_cors_sites.get();
+        async move {
+            let signature_text = hex_decode(req.signature_text.as_bytes())?;
+            let signature_text = String::from_utf8(signature_text)?;
+            let signature = hex_decode(req.signature.as_bytes())?;
+            if let Err(err) = ensure_login_message_valid(
+                &db_auth,
+                &signature_text,
+                &signature,
+                req.address,
+                &allow_cors_sites,
+                ctx.ip_addr,
+            )
+            .await
+            {
+                record_failed_login(&db_auth, req.address, ctx.ip_addr, None, None).await;
+                return Err(err);
+            }
+            db_auth
+                .execute(FunUserLinkLoginWalletReq {
+                    user_id: ctx.user_id,
+                    address: req.address.into(),
+                })
+                .await?;
+
+            Ok(UserLinkLoginWalletResponse {})
+        }
+        .boxed()
+    }
+}
+
+pub struct MethodUserUnlinkLoginWallet;
+impl RequestHandler for MethodUserUnlinkLoginWallet {
+    type Request = UserUnlinkLoginWalletRequest;
+
+    fn handle(
+        &self,
+        toolbox: &Toolbox,
+        ctx: RequestContext,
+        req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let db_auth: DbClient = toolbox.get_nth_db(1);
+        async move {
+            let wallet = db_auth
+                .execute(FunUserListLoginWalletsReq {
+                    user_id: ctx.user_id,
+                })
+                .await?
+                .into_iter()
+                .find(|x| x.address == req.address.into())
+                .with_context(|| {
+                    CustomError::new(EnumErrorCode::NotFound, "Wallet is not linked")
+                })?;
+            ensure!(
+                !wallet.is_primary,
+                CustomError::new(
+                    EnumErrorCode::InvalidArgument,
+                    "Make another wallet primary before unlinking this one"
+                )
+            );
+            db_auth
+                .execute(FunUserUnlinkLoginWalletReq {
+                    user_id: ctx.user_id,
+                    address: req.address.into(),
+                })
+                .await?;
+
+            Ok(UserUnlinkLoginWalletResponse {})
+        }
+        .boxed()
+    }
+}
+
+pub struct MethodUserSetPrimaryLoginWallet;
+impl RequestHandler for MethodUserSetPrimaryLoginWallet {
+    type Request = UserSetPrimaryLoginWalletRequest;
+
+    fn handle(
+        &self,
+        toolbox: &Toolbox,
+        ctx: RequestContext,
+        req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let db_auth: DbClient = toolbox.get_nth_db(1);
+        async move {
+            let ret = db_auth
+                .execute(FunUserSetPrimaryLoginWalletReq {
+                    user_id: ctx.user_id,
+                    address: req.address.into(),
+                })
+                .await?
+                .into_result()
+                .context("failed to set primary wallet")?;
+            if !ret.updated {
+                bail!(CustomError::new(
+                    EnumErrorCode::NotFound,
+                    "Wallet is not linked"
+                ));
+            }
+
+            Ok(UserSetPrimaryLoginWalletResponse {})
+        }
+        .boxed()
+    }
+}
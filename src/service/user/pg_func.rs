@@ -179,6 +179,7 @@ END
                 Field::new("blockchain", Type::enum_ref("block_chain")),
                 Field::new("transaction_hash", Type::BlockchainTransactionHash),
                 Field::new("earn_sp_tokens", Type::BlockchainDecimal),
+                Field::new("fees", Type::BlockchainDecimal),
             ],
             vec![Field::new("success", Type::Boolean)],
             r#"
@@ -206,7 +207,8 @@ BEGIN
 			quantity_sp_tokens,
 			transaction_hash,
 			happened_at,
-			is_back
+			is_back,
+			fees
 		) VALUES (
 			a_user_id,
 			a_strategy_id,
@@ -215,7 +217,8 @@ BEGIN
 			a_earn_sp_tokens,
 			a_transaction_hash,
 			extract(epoch from now())::bigint,
-			TRUE);
+			TRUE,
+			a_fees);
     RETURN QUERY SELECT TRUE;
 END
             "#,
@@ -818,7 +821,7 @@ END
                 Field::new("limit", Type::BigInt),
                 Field::new("offset", Type::BigInt),
                 Field::new("user_id", Type::BigInt),
-                Field::new("sort_by_followers", Type::Boolean),
+                Field::new("sort_by", Type::enum_ref("expert_sort_by")),
                 Field::new("expert_id", Type::optional(Type::BigInt)),
                 Field::new("expert_user_id", Type::optional(Type::BigInt)),
                 Field::new("expert_user_public_id", Type::optional(Type::BigInt)),
@@ -843,10 +846,13 @@ BEGIN
                         AND (a_given_name ISNULL OR u.given_name ILIKE a_given_name || '%')
                         AND (a_description ISNULL OR e.description ILIKE a_description || '%')
                         AND (a_social_media ISNULL OR e.social_media ILIKE a_social_media || '%')
-                 ORDER BY CASE 
-                  WHEN a_sort_by_followers = TRUE THEN follower_count 
-                  ELSE e.pkey_id
-                 END DESC
+                 ORDER BY
+                    (CASE WHEN a_sort_by = 'Followers' THEN
+                        (SELECT COUNT(DISTINCT d.fkey_user_id) FROM tbl.user_follow_expert AS d WHERE d.fkey_expert_id = e.pkey_id AND d.unfollowed = FALSE)
+                    END) DESC NULLS LAST,
+                    (CASE WHEN a_sort_by = 'Reputation' THEN e.reputation_score END) DESC NULLS LAST,
+                    (CASE WHEN a_sort_by = 'Consistency' THEN e.consistent_score END) DESC NULLS LAST,
+                    e.pkey_id DESC
                  OFFSET a_offset
                  LIMIT a_limit
                  ;
@@ -960,7 +966,28 @@ END
             r#"
 DECLARE
     _expert_id bigint;
-BEGIN
+    _application_id bigint;
+    _status enum_expert_application_status;
+BEGIN
+    SELECT pkey_id, status INTO _application_id, _status
+    FROM tbl.expert_application
+    WHERE fkey_user_id = a_user_id
+    FOR UPDATE;
+    IF _application_id IS NULL THEN
+        INSERT INTO tbl.expert_application(fkey_user_id, status, questionnaire, updated_at, created_at)
+        VALUES (a_user_id, 'Draft', '{}', extract(epoch from now())::bigint, extract(epoch from now())::bigint)
+        RETURNING pkey_id INTO _application_id;
+    ELSIF _status NOT IN ('Draft', 'InfoRequested', 'Rejected') THEN
+        RAISE SQLSTATE 'R001A'; -- InvalidExpertApplicationState
+    END IF;
+    UPDATE tbl.expert_application
+    SET status = 'Submitted',
+        submitted_at = extract(epoch from now())::bigint,
+        updated_at = extract(epoch from now())::bigint
+    WHERE pkey_id = _application_id;
+    INSERT INTO tbl.expert_application_note(fkey_application_id, fkey_author_user_id, status, created_at)
+    VALUES (_application_id, a_user_id, 'Submitted', extract(epoch from now())::bigint);
+
     IF NOT EXISTS(SELECT * FROM tbl.expert_profile WHERE fkey_user_id = a_user_id) THEN
         INSERT INTO tbl.expert_profile(fkey_user_id, pending_expert, requested_at, updated_at, created_at)
         VALUES(a_user_id, TRUE, extract(epoch from now())::bigint, extract(epoch from now())::bigint, extract(epoch from now())::bigint)
@@ -975,6 +1002,169 @@ BEGIN
     END IF;
     RETURN QUERY SELECT TRUE, _expert_id;
 END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_user_save_expert_application",
+            vec![
+                Field::new("user_id", Type::BigInt),
+                Field::new("questionnaire", Type::Object),
+            ],
+            vec![Field::new("application_id", Type::BigInt)],
+            r#"
+DECLARE
+    _application_id bigint;
+    _status enum_expert_application_status;
+BEGIN
+    SELECT pkey_id, status INTO _application_id, _status
+    FROM tbl.expert_application
+    WHERE fkey_user_id = a_user_id
+    FOR UPDATE;
+    IF _application_id IS NULL THEN
+        INSERT INTO tbl.expert_application(fkey_user_id, status, questionnaire, updated_at, created_at)
+        VALUES (a_user_id, 'Draft', a_questionnaire, extract(epoch from now())::bigint, extract(epoch from now())::bigint)
+        RETURNING pkey_id INTO _application_id;
+    ELSIF _status NOT IN ('Draft', 'InfoRequested', 'Rejected') THEN
+        RAISE SQLSTATE 'R001A'; -- InvalidExpertApplicationState
+    ELSE
+        -- editing a rejected application starts a new draft for resubmission
+        UPDATE tbl.expert_application
+        SET questionnaire = a_questionnaire,
+            status = CASE WHEN _status = 'Rejected' THEN 'Draft' ELSE _status END,
+            updated_at = extract(epoch from now())::bigint
+        WHERE pkey_id = _application_id;
+    END IF;
+    RETURN QUERY SELECT _application_id;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_user_add_expert_application_document",
+            vec![
+                Field::new("user_id", Type::BigInt),
+                Field::new("name", Type::String),
+                Field::new("content_type", Type::String),
+                Field::new("size", Type::BigInt),
+                Field::new("blob_key", Type::String),
+            ],
+            vec![Field::new("document_id", Type::BigInt)],
+            r#"
+DECLARE
+    _application_id bigint;
+BEGIN
+    SELECT pkey_id INTO _application_id
+    FROM tbl.expert_application
+    WHERE fkey_user_id = a_user_id
+      AND status IN ('Draft', 'InfoRequested', 'Rejected')
+    FOR UPDATE;
+    IF _application_id IS NULL THEN
+        RAISE SQLSTATE 'R001A'; -- InvalidExpertApplicationState
+    END IF;
+    RETURN QUERY INSERT INTO tbl.expert_application_document(fkey_application_id, name, content_type, size, blob_key, created_at)
+    VALUES (_application_id, a_name, a_content_type, a_size, a_blob_key, extract(epoch from now())::bigint)
+    RETURNING pkey_id;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_user_delete_expert_application_document",
+            vec![
+                Field::new("user_id", Type::BigInt),
+                Field::new("document_id", Type::BigInt),
+            ],
+            vec![Field::new("blob_key", Type::String)],
+            r#"
+BEGIN
+    IF NOT EXISTS(SELECT * FROM tbl.expert_application
+                  WHERE fkey_user_id = a_user_id
+                    AND status IN ('Draft', 'InfoRequested', 'Rejected')) THEN
+        RAISE SQLSTATE 'R001A'; -- InvalidExpertApplicationState
+    END IF;
+    RETURN QUERY DELETE FROM tbl.expert_application_document d
+    USING tbl.expert_application a
+    WHERE d.pkey_id = a_document_id
+      AND d.fkey_application_id = a.pkey_id
+      AND a.fkey_user_id = a_user_id
+    RETURNING d.blob_key;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_user_get_expert_application",
+            vec![
+                Field::new("user_id", Type::optional(Type::BigInt)),
+                Field::new("user_public_id", Type::optional(Type::BigInt)),
+            ],
+            vec![
+                Field::new("application_id", Type::BigInt),
+                Field::new("user_public_id", Type::BigInt),
+                Field::new("status", Type::enum_ref("expert_application_status")),
+                Field::new("questionnaire", Type::Object),
+                Field::new("submitted_at", Type::optional(Type::BigInt)),
+                Field::new("reviewed_at", Type::optional(Type::BigInt)),
+                Field::new("updated_at", Type::BigInt),
+            ],
+            r#"
+BEGIN
+    RETURN QUERY SELECT a.pkey_id,
+                        u.public_id,
+                        a.status,
+                        a.questionnaire,
+                        a.submitted_at,
+                        a.reviewed_at,
+                        a.updated_at
+                 FROM tbl.expert_application AS a
+                   JOIN tbl.user AS u ON u.pkey_id = a.fkey_user_id
+                 WHERE (a_user_id ISNULL OR u.pkey_id = a_user_id)
+                   AND (a_user_public_id ISNULL OR u.public_id = a_user_public_id);
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_user_list_expert_application_documents",
+            vec![Field::new("application_id", Type::BigInt)],
+            vec![
+                Field::new("document_id", Type::BigInt),
+                Field::new("name", Type::String),
+                Field::new("content_type", Type::String),
+                Field::new("size", Type::BigInt),
+                Field::new("blob_key", Type::String),
+                Field::new("created_at", Type::BigInt),
+            ],
+            r#"
+BEGIN
+    RETURN QUERY SELECT d.pkey_id,
+                        d.name,
+                        d.content_type,
+                        d.size,
+                        d.blob_key,
+                        d.created_at
+                 FROM tbl.expert_application_document AS d
+                 WHERE d.fkey_application_id = a_application_id
+                 ORDER BY d.pkey_id;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_user_list_expert_application_notes",
+            vec![Field::new("application_id", Type::BigInt)],
+            vec![
+                Field::new("status", Type::enum_ref("expert_application_status")),
+                Field::new("note", Type::optional(Type::String)),
+                Field::new("from_reviewer", Type::Boolean),
+                Field::new("created_at", Type::BigInt),
+            ],
+            r#"
+BEGIN
+    RETURN QUERY SELECT n.status,
+                        n.note,
+                        n.fkey_author_user_id <> a.fkey_user_id,
+                        n.created_at
+                 FROM tbl.expert_application_note AS n
+                   JOIN tbl.expert_application AS a ON a.pkey_id = n.fkey_application_id
+                 WHERE n.fkey_application_id = a_application_id
+                 ORDER BY n.pkey_id;
+END
 "#,
         ),
         ProceduralFunction::new(
@@ -1087,6 +1277,16 @@ END
                 Field::new("name", Type::optional(Type::String)),
                 Field::new("description", Type::optional(Type::String)),
                 Field::new("social_media", Type::optional(Type::String)),
+                Field::new("rebalance_interval_seconds", Type::optional(Type::BigInt)),
+                Field::new("rebalance_drift_threshold", Type::optional(Type::Numeric)),
+                Field::new("copy_trade_min_usd", Type::optional(Type::Numeric)),
+                Field::new("copy_trade_max_aum_ratio", Type::optional(Type::Numeric)),
+                Field::new("copy_trade_cooldown_seconds", Type::optional(Type::BigInt)),
+                Field::new(
+                    "copy_trade_batch_window_seconds",
+                    Type::optional(Type::BigInt),
+                ),
+                Field::new("expert_fee", Type::optional(Type::Numeric)),
             ],
             vec![Field::new("success", Type::Boolean)],
             r#"
@@ -1095,7 +1295,14 @@ BEGIN
     UPDATE tbl.strategy
     SET name = COALESCE(a_name, name),
         description = COALESCE(a_description, description),
-        social_media = COALESCE(a_social_media, social_media)
+        social_media = COALESCE(a_social_media, social_media),
+        rebalance_interval_seconds = NULLIF(COALESCE(a_rebalance_interval_seconds, rebalance_interval_seconds), 0),
+        rebalance_drift_threshold = COALESCE(a_rebalance_drift_threshold, rebalance_drift_threshold),
+        copy_trade_min_usd = NULLIF(COALESCE(a_copy_trade_min_usd, copy_trade_min_usd), 0),
+        copy_trade_max_aum_ratio = NULLIF(COALESCE(a_copy_trade_max_aum_ratio, copy_trade_max_aum_ratio), 0),
+        copy_trade_cooldown_seconds = NULLIF(COALESCE(a_copy_trade_cooldown_seconds, copy_trade_cooldown_seconds), 0),
+        copy_trade_batch_window_seconds = NULLIF(COALESCE(a_copy_trade_batch_window_seconds, copy_trade_batch_window_seconds), 0),
+        expert_fee = COALESCE(a_expert_fee, expert_fee)
     WHERE pkey_id = a_strategy_id
       AND fkey_user_id = a_user_id;
     RETURN QUERY SELECT TRUE;
@@ -1335,6 +1542,7 @@ END
                 Field::new("is_deposit", Type::Boolean),
                 Field::new("is_back", Type::Boolean),
                 Field::new("is_withdraw", Type::Boolean),
+                Field::new("is_fee", Type::Boolean),
             ],
             vec![Field::new("ledger_entry_id", Type::BigInt)],
             r#"
@@ -1373,6 +1581,7 @@ BEGIN
 		is_deposit,
 		is_back,
 		is_withdraw,
+		is_fee,
 		happened_at
 		) VALUES (a_user_id,
 							_token_id,
@@ -1386,6 +1595,7 @@ BEGIN
 							a_is_deposit,
 							a_is_back,
 							a_is_withdraw,
+							a_is_fee,
 							EXTRACT(EPOCH FROM NOW())::bigint
 		) RETURNING pkey_id;
 
@@ -1709,8 +1919,8 @@ END
 DECLARE
 		_user_id BIGINT;
 BEGIN
-		-- search for user id in user table by address
-		SELECT pkey_id INTO _user_id FROM tbl.user AS a WHERE a.address = a_address;
+		-- search for user id by any of its login wallets
+		SELECT fkey_user_id INTO _user_id FROM tbl.user_login_wallet AS a WHERE a.address = a_address;
 
 		-- if address is not registered in user table, search for a whitelisted wallet
 		IF _user_id IS NULL THEN
@@ -1734,6 +1944,18 @@ BEGIN
             a.given_name, 
             a.created_at 
             FROM tbl.user AS a WHERE a.pkey_id = _user_id;
+END
+            "#,
+        ),
+        ProceduralFunction::new(
+            "fun_user_get_user_by_login_wallet",
+            vec![Field::new("address", Type::BlockchainAddress)],
+            vec![Field::new("user_id", Type::BigInt)],
+            r#"
+BEGIN
+    RETURN QUERY SELECT a.fkey_user_id
+    FROM tbl.user_login_wallet AS a
+    WHERE a.address = a_address;
 END
             "#,
         ),
@@ -2000,6 +2222,83 @@ BEGIN
     UPDATE tbl.user_deposit_withdraw_balance SET balance = a_new_balance WHERE pkey_id = a_deposit_withdraw_balance_id;
     RETURN QUERY SELECT TRUE;
 END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_user_debit_user_deposit_withdraw_balance",
+            vec![
+                Field::new("deposit_withdraw_balance_id", Type::BigInt),
+                Field::new("quantity", Type::BlockchainDecimal),
+            ],
+            vec![Field::new("balance", Type::BlockchainDecimal)],
+            r#"
+BEGIN
+    RETURN QUERY UPDATE tbl.user_deposit_withdraw_balance AS b
+                 SET balance = b.balance - a_quantity
+                 WHERE b.pkey_id = a_deposit_withdraw_balance_id
+                    AND b.balance >= a_quantity
+                 RETURNING b.balance;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_user_credit_user_deposit_withdraw_balance",
+            vec![
+                Field::new("deposit_withdraw_balance_id", Type::BigInt),
+                Field::new("quantity", Type::BlockchainDecimal),
+            ],
+            vec![],
+            r#"
+BEGIN
+    UPDATE tbl.user_deposit_withdraw_balance
+    SET balance = balance + a_quantity
+    WHERE pkey_id = a_deposit_withdraw_balance_id;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_user_add_pending_withdraw",
+            vec![
+                Field::new("user_id", Type::BigInt),
+                Field::new("deposit_withdraw_balance_id", Type::BigInt),
+                Field::new("blockchain", Type::enum_ref("block_chain")),
+                Field::new("token_address", Type::BlockchainAddress),
+                Field::new("user_address", Type::BlockchainAddress),
+                Field::new("escrow_contract_address", Type::BlockchainAddress),
+                Field::new("receiver_address", Type::BlockchainAddress),
+                Field::new("quantity", Type::BlockchainDecimal),
+                Field::new("transaction_hash", Type::BlockchainTransactionHash),
+                Field::new("is_fee", Type::Boolean),
+            ],
+            vec![Field::new("pending_withdraw_id", Type::BigInt)],
+            r#"
+BEGIN
+    RETURN QUERY INSERT INTO tbl.user_pending_withdraw (
+        fkey_user_id,
+        fkey_deposit_withdraw_balance_id,
+        blockchain,
+        token_address,
+        user_address,
+        escrow_contract_address,
+        receiver_address,
+        quantity,
+        transaction_hash,
+        is_fee,
+        created_at
+    ) VALUES (
+        a_user_id,
+        a_deposit_withdraw_balance_id,
+        a_blockchain,
+        a_token_address,
+        a_user_address,
+        a_escrow_contract_address,
+        a_receiver_address,
+        a_quantity,
+        a_transaction_hash,
+        a_is_fee,
+        EXTRACT(EPOCH FROM NOW())::bigint
+    ) RETURNING pkey_id;
+END
 "#,
         ),
         ProceduralFunction::new(
@@ -2213,5 +2512,796 @@ BEGIN
 END
             "#,
         ),
+        ProceduralFunction::new(
+            "fun_user_list_user_activity_report",
+            vec![
+                Field::new("user_id", Type::BigInt),
+                Field::new("start_time", Type::BigInt),
+                Field::new("end_time", Type::BigInt),
+                Field::new("blockchain", Type::optional(Type::enum_ref("block_chain"))),
+                Field::new("limit", Type::BigInt),
+                Field::new("offset", Type::BigInt),
+            ],
+            vec![
+                Field::new("total", Type::BigInt),
+                Field::new("event_type", Type::enum_ref("user_activity_event_type")),
+                Field::new("blockchain", Type::enum_ref("block_chain")),
+                Field::new("strategy_id", Type::optional(Type::BigInt)),
+                Field::new(
+                    "transaction_hash",
+                    Type::optional(Type::BlockchainTransactionHash),
+                ),
+                Field::new("token_symbol", Type::String),
+                Field::new("token_address", Type::optional(Type::BlockchainAddress)),
+                Field::new("quantity", Type::BlockchainDecimal),
+                Field::new("usd_price", Type::optional(Type::Numeric)),
+                Field::new("usd_value", Type::optional(Type::Numeric)),
+                Field::new("fees", Type::BlockchainDecimal),
+                Field::new("happened_at", Type::BigInt),
+            ],
+            r#"
+BEGIN
+    RETURN QUERY SELECT
+        count(*) OVER() AS total,
+        e.event_type,
+        e.blockchain,
+        e.strategy_id,
+        e.transaction_hash,
+        e.token_symbol,
+        e.token_address,
+        e.quantity,
+        p.price,
+        (e.quantity::double precision * p.price)::double precision,
+        e.fees,
+        e.happened_at
+    FROM (
+        -- deposits, withdrawals and their gas fees, refunds and escrow spent on backing
+        SELECT
+            (CASE WHEN a.is_deposit THEN 'Deposit'
+                  WHEN a.is_back THEN 'EscrowSpent'
+                  WHEN a.is_withdraw AND a.is_fee THEN 'WithdrawFee'
+                  WHEN a.is_withdraw THEN 'Withdraw'
+                  ELSE 'Refund' END)::enum_user_activity_event_type AS event_type,
+            a.blockchain,
+            NULL::bigint AS strategy_id,
+            NULLIF(a.transaction_hash, '')::varchar AS transaction_hash,
+            t.symbol::varchar AS token_symbol,
+            t.address::varchar AS token_address,
+            t.is_stablecoin,
+            a.quantity,
+            0::decimal(56, 18) AS fees,
+            a.happened_at,
+            a.pkey_id AS event_id
+        FROM tbl.user_deposit_withdraw_ledger AS a
+        JOIN tbl.escrow_token_contract_address AS t ON t.pkey_id = a.fkey_token_id
+        WHERE a.fkey_user_id = a_user_id
+        UNION ALL
+        -- backing and exiting strategies, always denominated in USDC
+        SELECT
+            (CASE WHEN a.is_back THEN 'BackStrategy'
+                  ELSE 'ExitStrategy' END)::enum_user_activity_event_type,
+            a.blockchain,
+            a.fkey_strategy_id,
+            a.transaction_hash::varchar,
+            'USDC'::varchar,
+            t.address::varchar,
+            TRUE,
+            a.quantity_of_usdc,
+            -- the fees charged when the strategy was backed
+            a.fees,
+            a.happened_at,
+            a.pkey_id
+        FROM tbl.user_back_exit_strategy_ledger AS a
+        LEFT JOIN tbl.escrow_token_contract_address AS t ON t.blockchain = a.blockchain AND t.symbol = 'USDC'
+        WHERE a.fkey_user_id = a_user_id
+        UNION ALL
+        -- assets moved in and out of strategy pools on behalf of the user's strategy wallet
+        SELECT
+            (CASE WHEN a.is_add THEN 'PoolAssetAdd'
+                  ELSE 'PoolAssetRemove' END)::enum_user_activity_event_type,
+            w.blockchain,
+            spc.fkey_strategy_id,
+            NULL::varchar,
+            t.symbol::varchar,
+            t.address::varchar,
+            t.is_stablecoin,
+            a.amount,
+            0::decimal(56, 18),
+            a.happened_at,
+            a.pkey_id
+        FROM tbl.user_strategy_pool_contract_asset_ledger AS a
+        JOIN tbl.user_strategy_wallet AS w ON w.pkey_id = a.fkey_strategy_wallet_id
+        JOIN tbl.strategy_pool_contract AS spc ON spc.pkey_id = a.fkey_strategy_pool_contract_id
+        JOIN tbl.escrow_token_contract_address AS t ON t.pkey_id = a.fkey_token_id
+        WHERE w.fkey_user_id = a_user_id
+    ) AS e
+    -- price of the token at the time of the event, stablecoins fall back to 1 USD
+    LEFT JOIN LATERAL (
+        SELECT COALESCE(
+            (SELECT tp.price FROM tbl.token_price AS tp
+                WHERE tp.symbol = e.token_symbol AND tp.created_at <= e.happened_at
+                ORDER BY tp.created_at DESC
+                LIMIT 1),
+            CASE WHEN e.is_stablecoin THEN 1.0::double precision END
+        ) AS price
+    ) AS p ON TRUE
+    WHERE e.happened_at >= a_start_time
+        AND e.happened_at < a_end_time
+        AND (a_blockchain ISNULL OR e.blockchain = a_blockchain)
+    -- every event type comes from a single ledger, so its id makes the order total
+    ORDER BY e.happened_at, e.event_type, e.event_id
+    OFFSET a_offset
+    LIMIT a_limit;
+END
+            "#,
+        ),
+        ProceduralFunction::new(
+            "fun_expert_add_strategy_guard_rule",
+            vec![
+                Field::new("strategy_id", Type::BigInt),
+                Field::new("rule_type", Type::enum_ref("strategy_guard_rule_type")),
+                Field::new("token_id", Type::optional(Type::BigInt)),
+                Field::new("threshold", Type::Numeric),
+                Field::new("exit_token_id", Type::optional(Type::BigInt)),
+            ],
+            vec![Field::new("rule_id", Type::BigInt)],
+            r#"
+BEGIN
+    RETURN QUERY INSERT INTO tbl.strategy_guard_rule (
+        fkey_strategy_id,
+        rule_type,
+        fkey_token_id,
+        threshold,
+        fkey_exit_token_id,
+        created_at
+    ) VALUES (
+        a_strategy_id,
+        a_rule_type,
+        a_token_id,
+        a_threshold,
+        a_exit_token_id,
+        EXTRACT(EPOCH FROM NOW())::bigint
+    ) RETURNING pkey_id;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_expert_remove_strategy_guard_rule",
+            vec![
+                Field::new("strategy_id", Type::BigInt),
+                Field::new("rule_id", Type::BigInt),
+            ],
+            vec![Field::new("success", Type::Boolean)],
+            r#"
+BEGIN
+    DELETE FROM tbl.strategy_guard_rule_reference AS r
+    USING tbl.strategy_guard_rule AS g
+    WHERE r.fkey_strategy_guard_rule_id = g.pkey_id
+        AND g.pkey_id = a_rule_id AND g.fkey_strategy_id = a_strategy_id;
+    DELETE FROM tbl.strategy_guard_rule
+    WHERE pkey_id = a_rule_id AND fkey_strategy_id = a_strategy_id;
+    RETURN QUERY SELECT FOUND;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_user_list_strategy_guard_rules",
+            vec![Field::new("strategy_id", Type::BigInt)],
+            vec![
+                Field::new("total", Type::BigInt),
+                Field::new("rule_id", Type::BigInt),
+                Field::new("rule_type", Type::enum_ref("strategy_guard_rule_type")),
+                Field::new("token_id", Type::optional(Type::BigInt)),
+                Field::new("token_symbol", Type::optional(Type::String)),
+                Field::new("threshold", Type::Numeric),
+                Field::new("reference_value", Type::optional(Type::Numeric)),
+                Field::new("exit_token_id", Type::optional(Type::BigInt)),
+                Field::new("exit_token_symbol", Type::optional(Type::String)),
+                Field::new("triggered_at", Type::optional(Type::BigInt)),
+                Field::new("created_at", Type::BigInt),
+            ],
+            r#"
+BEGIN
+    RETURN QUERY SELECT
+        COUNT(*) OVER() AS total,
+        a.pkey_id,
+        a.rule_type,
+        a.fkey_token_id,
+        t.symbol,
+        a.threshold,
+        -- share value the rule is measured from, on the strategy's first pool
+        (SELECT r.reference_value
+         FROM tbl.strategy_guard_rule_reference AS r
+         WHERE r.fkey_strategy_guard_rule_id = a.pkey_id
+         ORDER BY r.fkey_strategy_pool_contract_id
+         LIMIT 1),
+        a.fkey_exit_token_id,
+        et.symbol,
+        a.triggered_at,
+        a.created_at
+    FROM tbl.strategy_guard_rule AS a
+    LEFT JOIN tbl.escrow_token_contract_address AS t ON t.pkey_id = a.fkey_token_id
+    LEFT JOIN tbl.escrow_token_contract_address AS et ON et.pkey_id = a.fkey_exit_token_id
+    WHERE a.fkey_strategy_id = a_strategy_id
+    ORDER BY a.pkey_id;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_expert_resume_strategy_copy_trading",
+            vec![Field::new("strategy_id", Type::BigInt)],
+            vec![],
+            r#"
+BEGIN
+    UPDATE tbl.strategy
+    SET copy_trading_paused = FALSE
+    WHERE pkey_id = a_strategy_id;
+    -- triggered rules are re-armed against fresh reference values
+    DELETE FROM tbl.strategy_guard_rule_reference AS r
+    USING tbl.strategy_guard_rule AS g
+    WHERE r.fkey_strategy_guard_rule_id = g.pkey_id
+        AND g.fkey_strategy_id = a_strategy_id AND g.triggered_at IS NOT NULL;
+    UPDATE tbl.strategy_guard_rule
+    SET triggered_at = NULL
+    WHERE fkey_strategy_id = a_strategy_id AND triggered_at IS NOT NULL;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_user_list_strategy_guard_notification_recipients",
+            vec![Field::new("strategy_id", Type::BigInt)],
+            vec![Field::new("user_id", Type::BigInt)],
+            r#"
+BEGIN
+    RETURN QUERY SELECT s.fkey_user_id
+    FROM tbl.strategy AS s
+    WHERE s.pkey_id = a_strategy_id AND s.fkey_user_id IS NOT NULL
+    UNION
+    SELECT usw.fkey_user_id
+    FROM tbl.user_strategy_balance AS usb
+    JOIN tbl.strategy_pool_contract AS spc ON spc.pkey_id = usb.fkey_strategy_pool_contract_id
+    JOIN tbl.user_strategy_wallet AS usw ON usw.pkey_id = usb.fkey_user_strategy_wallet_id
+    WHERE spc.fkey_strategy_id = a_strategy_id AND usb.balance > 0;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_user_add_notifications",
+            vec![
+                Field::new("user_ids", Type::vec(Type::BigInt)),
+                Field::new("kind", Type::enum_ref("user_notification_kind")),
+                Field::new("strategy_id", Type::optional(Type::BigInt)),
+                Field::new("message", Type::String),
+                Field::new("event_key", Type::optional(Type::String)),
+            ],
+            vec![
+                Field::new("notification_id", Type::BigInt),
+                Field::new("user_id", Type::BigInt),
+                Field::new("created_at", Type::BigInt),
+            ],
+            r#"
+BEGIN
+    IF a_event_key ISNULL THEN
+        RETURN QUERY INSERT INTO tbl.user_notification AS n (
+            fkey_user_id,
+            kind,
+            fkey_strategy_id,
+            message,
+            created_at
+        ) SELECT
+            u.user_id,
+            a_kind,
+            a_strategy_id,
+            a_message,
+            EXTRACT(EPOCH FROM NOW())::bigint
+        FROM UNNEST(a_user_ids) AS u(user_id)
+        RETURNING n.pkey_id, n.fkey_user_id, n.created_at;
+        RETURN;
+    END IF;
+    -- the first caller stores the notifications of the event, the others get the stored rows
+    INSERT INTO tbl.user_notification (
+        fkey_user_id,
+        kind,
+        fkey_strategy_id,
+        message,
+        created_at,
+        event_key
+    ) SELECT
+        u.user_id,
+        a_kind,
+        a_strategy_id,
+        a_message,
+        EXTRACT(EPOCH FROM NOW())::bigint,
+        a_event_key
+    FROM UNNEST(a_user_ids) AS u(user_id)
+    ON CONFLICT (fkey_user_id, event_key) DO NOTHING;
+    RETURN QUERY SELECT n.pkey_id, n.fkey_user_id, n.created_at
+    FROM tbl.user_notification AS n
+    WHERE n.event_key = a_event_key AND n.fkey_user_id = ANY(a_user_ids);
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_user_list_notifications",
+            vec![
+                Field::new("user_id", Type::BigInt),
+                Field::new("unread_only", Type::Boolean),
+                Field::new("limit", Type::BigInt),
+                Field::new("offset", Type::BigInt),
+            ],
+            vec![
+                Field::new("total", Type::BigInt),
+                Field::new("unread_total", Type::BigInt),
+                Field::new("notification_id", Type::BigInt),
+                Field::new("kind", Type::enum_ref("user_notification_kind")),
+                Field::new("strategy_id", Type::optional(Type::BigInt)),
+                Field::new("message", Type::String),
+                Field::new("read_at", Type::optional(Type::BigInt)),
+                Field::new("created_at", Type::BigInt),
+            ],
+            r#"
+BEGIN
+    RETURN QUERY SELECT
+        COUNT(*) OVER() AS total,
+        COUNT(*) FILTER (WHERE a.read_at ISNULL) OVER() AS unread_total,
+        a.pkey_id,
+        a.kind,
+        a.fkey_strategy_id,
+        a.message,
+        a.read_at,
+        a.created_at
+    FROM tbl.user_notification AS a
+    WHERE a.fkey_user_id = a_user_id
+        AND (NOT a_unread_only OR a.read_at ISNULL)
+    ORDER BY a.pkey_id DESC
+    LIMIT a_limit
+    OFFSET a_offset;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_user_mark_notifications_read",
+            vec![
+                Field::new("user_id", Type::BigInt),
+                Field::new("notification_ids", Type::optional(Type::vec(Type::BigInt))),
+            ],
+            vec![Field::new("marked", Type::BigInt)],
+            r#"
+DECLARE
+    _marked bigint;
+BEGIN
+    UPDATE tbl.user_notification
+    SET read_at = EXTRACT(EPOCH FROM NOW())::bigint
+    WHERE fkey_user_id = a_user_id
+        AND read_at ISNULL
+        AND (a_notification_ids ISNULL OR pkey_id = ANY(a_notification_ids));
+    GET DIAGNOSTICS _marked = ROW_COUNT;
+    RETURN QUERY SELECT _marked;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_user_list_expert_follower_ids",
+            vec![Field::new("expert_user_id", Type::BigInt)],
+            vec![Field::new("user_id", Type::BigInt)],
+            r#"
+BEGIN
+    RETURN QUERY SELECT DISTINCT ufe.fkey_user_id
+    FROM tbl.user_follow_expert AS ufe
+    JOIN tbl.expert_profile AS ep ON ep.pkey_id = ufe.fkey_expert_id
+    WHERE ep.fkey_user_id = a_expert_user_id AND NOT ufe.unfollowed;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_user_list_sessions",
+            vec![Field::new("user_id", Type::BigInt)],
+            vec![
+                Field::new("session_id", Type::BigInt),
+                Field::new("service", Type::enum_ref("service")),
+                Field::new("device_id", Type::String),
+                Field::new("device_os", Type::String),
+                Field::new("ip_address", Type::Inet),
+                Field::new("created_at", Type::BigInt),
+                Field::new("last_seen_at", Type::BigInt),
+                Field::new("expires_at", Type::BigInt),
+            ],
+            r#"
+BEGIN
+    RETURN QUERY SELECT
+        a.pkey_id,
+        a.service,
+        a.device_id,
+        a.device_os,
+        a.ip_address,
+        a.created_at,
+        a.last_seen_at,
+        a.refresh_expires_at
+    FROM tbl.user_session AS a
+    WHERE a.fkey_user_id = a_user_id
+        AND a.revoked_at ISNULL
+        AND a.refresh_expires_at >= EXTRACT(EPOCH FROM NOW())::bigint
+    ORDER BY a.last_seen_at DESC;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_user_revoke_session",
+            vec![
+                Field::new("user_id", Type::BigInt),
+                Field::new("session_id", Type::BigInt),
+            ],
+            vec![Field::new("revoked", Type::Boolean)],
+            r#"
+BEGIN
+    UPDATE tbl.user_session
+    SET revoked_at = EXTRACT(EPOCH FROM NOW())::bigint
+    WHERE pkey_id = a_session_id
+        AND fkey_user_id = a_user_id
+        AND revoked_at ISNULL;
+    RETURN QUERY SELECT FOUND;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_user_create_api_key",
+            vec![
+                Field::new("user_id", Type::BigInt),
+                Field::new("name", Type::String),
+                Field::new("api_key", Type::String),
+                Field::new("api_secret", Type::String),
+                Field::new("scopes", Type::vec(Type::enum_ref("api_key_scope"))),
+                Field::new("ip_allowlist", Type::optional(Type::vec(Type::Inet))),
+                Field::new("expires_at", Type::optional(Type::BigInt)),
+            ],
+            vec![Field::new("api_key_id", Type::BigInt)],
+            r#"
+BEGIN
+    RETURN QUERY INSERT INTO tbl.user_api_key (
+        fkey_user_id,
+        name,
+        api_key,
+        api_secret,
+        scopes,
+        ip_allowlist,
+        expires_at,
+        created_at
+    ) VALUES (
+        a_user_id,
+        a_name,
+        a_api_key,
+        a_api_secret,
+        a_scopes,
+        a_ip_allowlist,
+        a_expires_at,
+        EXTRACT(EPOCH FROM NOW())::bigint
+    ) RETURNING pkey_id;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_user_list_api_keys",
+            vec![Field::new("user_id", Type::BigInt)],
+            vec![
+                Field::new("api_key_id", Type::BigInt),
+                Field::new("name", Type::String),
+                Field::new("api_key", Type::String),
+                Field::new("scopes", Type::vec(Type::enum_ref("api_key_scope"))),
+                Field::new("ip_allowlist", Type::optional(Type::vec(Type::Inet))),
+                Field::new("expires_at", Type::optional(Type::BigInt)),
+                Field::new("created_at", Type::BigInt),
+                Field::new("last_used_at", Type::optional(Type::BigInt)),
+            ],
+            r#"
+BEGIN
+    RETURN QUERY SELECT
+        a.pkey_id,
+        a.name,
+        a.api_key,
+        a.scopes,
+        a.ip_allowlist,
+        a.expires_at,
+        a.created_at,
+        a.last_used_at
+    FROM tbl.user_api_key AS a
+    WHERE a.fkey_user_id = a_user_id
+        AND a.revoked_at ISNULL
+    ORDER BY a.pkey_id DESC;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_user_revoke_api_key",
+            vec![
+                Field::new("user_id", Type::BigInt),
+                Field::new("api_key_id", Type::BigInt),
+            ],
+            vec![Field::new("revoked", Type::Boolean)],
+            r#"
+BEGIN
+    UPDATE tbl.user_api_key
+    SET revoked_at = EXTRACT(EPOCH FROM NOW())::bigint
+    WHERE pkey_id = a_api_key_id
+        AND fkey_user_id = a_user_id
+        AND revoked_at ISNULL;
+    RETURN QUERY SELECT FOUND;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_user_list_login_wallets",
+            vec![Field::new("user_id", Type::BigInt)],
+            vec![
+                Field::new("address", Type::BlockchainAddress),
+                Field::new("is_primary", Type::Boolean),
+                Field::new("created_at", Type::BigInt),
+            ],
+            r#"
+BEGIN
+    RETURN QUERY SELECT a.address, a.is_primary, a.created_at
+    FROM tbl.user_login_wallet AS a
+    WHERE a.fkey_user_id = a_user_id
+    ORDER BY a.is_primary DESC, a.pkey_id;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_user_link_login_wallet",
+            vec![
+                Field::new("user_id", Type::BigInt),
+                Field::new("address", Type::BlockchainAddress),
+            ],
+            vec![],
+            r#"
+BEGIN
+    IF EXISTS(SELECT 1 FROM tbl.user_login_wallet WHERE address = a_address) THEN
+        RAISE SQLSTATE 'R000Z'; -- UsernameAlreadyRegistered
+    END IF;
+    INSERT INTO tbl.user_login_wallet (fkey_user_id, address, is_primary, created_at)
+    VALUES (a_user_id, a_address, FALSE, EXTRACT(EPOCH FROM NOW())::bigint);
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_user_unlink_login_wallet",
+            vec![
+                Field::new("user_id", Type::BigInt),
+                Field::new("address", Type::BlockchainAddress),
+            ],
+            vec![Field::new("unlinked", Type::Boolean)],
+            r#"
+BEGIN
+    DELETE FROM tbl.user_login_wallet
+    WHERE fkey_user_id = a_user_id
+        AND address = a_address
+        AND NOT is_primary;
+    RETURN QUERY SELECT FOUND;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_user_set_primary_login_wallet",
+            vec![
+                Field::new("user_id", Type::BigInt),
+                Field::new("address", Type::BlockchainAddress),
+            ],
+            vec![Field::new("updated", Type::Boolean)],
+            r#"
+BEGIN
+    IF NOT EXISTS(SELECT 1
+                  FROM tbl.user_login_wallet
+                  WHERE fkey_user_id = a_user_id
+                    AND address = a_address) THEN
+        RETURN QUERY SELECT FALSE;
+        RETURN;
+    END IF;
+    UPDATE tbl.user_login_wallet
+    SET is_primary = FALSE
+    WHERE fkey_user_id = a_user_id
+        AND is_primary;
+    UPDATE tbl.user_login_wallet
+    SET is_primary = TRUE
+    WHERE fkey_user_id = a_user_id
+        AND address = a_address;
+    UPDATE tbl.user
+    SET address = a_address,
+        updated_at = EXTRACT(EPOCH FROM NOW())::bigint
+    WHERE pkey_id = a_user_id;
+    RETURN QUERY SELECT TRUE;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_user_get_strategy_snapshot",
+            vec![Field::new("strategy_id", Type::BigInt)],
+            vec![
+                Field::new("creator_id", Type::optional(Type::BigInt)),
+                Field::new("snapshot", Type::Object),
+            ],
+            r#"
+BEGIN
+    RETURN QUERY SELECT s.fkey_user_id, jsonb_build_object(
+        'name', s.name,
+        'description', s.description,
+        'social_media', s.social_media,
+        'expert_fee', s.expert_fee,
+        'swap_fee', s.swap_fee,
+        'minimum_backing_amount_usd', s.minimum_backing_amount_usd,
+        'rebalance_interval_seconds', s.rebalance_interval_seconds,
+        'rebalance_drift_threshold', s.rebalance_drift_threshold,
+        'copy_trade_min_usd', s.copy_trade_min_usd,
+        'copy_trade_max_aum_ratio', s.copy_trade_max_aum_ratio,
+        'copy_trade_cooldown_seconds', s.copy_trade_cooldown_seconds,
+        'copy_trade_batch_window_seconds', s.copy_trade_batch_window_seconds,
+        'watching_wallets', COALESCE((SELECT jsonb_agg(jsonb_build_object(
+                                          'wallet_id', sw.pkey_id,
+                                          'blockchain', ew.blockchain,
+                                          'address', ew.address,
+                                          'ratio', sw.ratio_distribution
+                                      ) ORDER BY sw.pkey_id)
+                                      FROM tbl.strategy_watched_wallet AS sw
+                                        JOIN tbl.expert_watched_wallet AS ew
+                                          ON ew.pkey_id = sw.fkey_expert_watched_wallet_id
+                                      WHERE sw.fkey_strategy_id = s.pkey_id), '[]'::jsonb),
+        'initial_token_ratios', COALESCE((SELECT jsonb_agg(jsonb_build_object(
+                                              'token_id', r.token_id,
+                                              'quantity', r.quantity
+                                          ) ORDER BY r.token_id)
+                                          FROM tbl.strategy_initial_token_ratio AS r
+                                          WHERE r.fkey_strategy_id = s.pkey_id), '[]'::jsonb),
+        'audit_rules', COALESCE((SELECT jsonb_agg(ar.fkey_audit_rule_id ORDER BY ar.fkey_audit_rule_id)
+                                 FROM tbl.strategy_audit_rule AS ar
+                                 WHERE ar.fkey_strategy_id = s.pkey_id), '[]'::jsonb),
+        'guard_rules', COALESCE((SELECT jsonb_agg(jsonb_build_object(
+                                     'rule_id', g.pkey_id,
+                                     'rule_type', g.rule_type,
+                                     'token_id', g.fkey_token_id,
+                                     'threshold', g.threshold,
+                                     'exit_token_id', g.fkey_exit_token_id
+                                 ) ORDER BY g.pkey_id)
+                                 FROM tbl.strategy_guard_rule AS g
+                                 WHERE g.fkey_strategy_id = s.pkey_id), '[]'::jsonb)
+    )
+    FROM tbl.strategy AS s
+    WHERE s.pkey_id = a_strategy_id;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_user_list_strategy_current_backers",
+            vec![Field::new("strategy_id", Type::BigInt)],
+            vec![Field::new("user_id", Type::BigInt)],
+            r#"
+BEGIN
+    RETURN QUERY SELECT DISTINCT usw.fkey_user_id
+    FROM tbl.user_strategy_balance AS usb
+    JOIN tbl.strategy_pool_contract AS spc ON spc.pkey_id = usb.fkey_strategy_pool_contract_id
+    JOIN tbl.user_strategy_wallet AS usw ON usw.pkey_id = usb.fkey_user_strategy_wallet_id
+    WHERE spc.fkey_strategy_id = a_strategy_id AND usb.balance > 0;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_user_add_strategy_version",
+            vec![
+                Field::new("strategy_id", Type::BigInt),
+                Field::new("user_id", Type::BigInt),
+                Field::new("method_id", Type::Int),
+                Field::new("method_name", Type::String),
+                Field::new("params", Type::Object),
+                Field::new("diff", Type::optional(Type::Object)),
+                Field::new("is_material", Type::Boolean),
+                Field::new("status", Type::enum_ref("strategy_version_status")),
+                Field::new("effective_at", Type::BigInt),
+            ],
+            vec![
+                Field::new("version_id", Type::BigInt),
+                Field::new("version", Type::BigInt),
+            ],
+            r#"
+DECLARE
+    _version bigint;
+    _now bigint := EXTRACT(EPOCH FROM NOW())::bigint;
+BEGIN
+    -- serializes version numbers of the same strategy
+    PERFORM 1 FROM tbl.strategy WHERE pkey_id = a_strategy_id FOR UPDATE;
+    SELECT COALESCE(MAX(v.version), 0) + 1 INTO _version
+    FROM tbl.strategy_version AS v
+    WHERE v.fkey_strategy_id = a_strategy_id;
+    RETURN QUERY INSERT INTO tbl.strategy_version AS v (fkey_strategy_id, version, fkey_changed_by_user_id,
+                                                        method_id, method_name, params, diff, is_material,
+                                                        status, effective_at, applied_at, created_at)
+    VALUES (a_strategy_id, _version, a_user_id, a_method_id, a_method_name, a_params, a_diff, a_is_material,
+            a_status, a_effective_at, CASE WHEN a_status = 'Applied' THEN _now END, _now)
+    RETURNING v.pkey_id, v.version;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_user_set_strategy_version_status",
+            vec![
+                Field::new("version_id", Type::BigInt),
+                Field::new("status", Type::enum_ref("strategy_version_status")),
+                Field::new("diff", Type::optional(Type::Object)),
+            ],
+            vec![],
+            r#"
+BEGIN
+    UPDATE tbl.strategy_version
+    SET status = a_status,
+        diff = COALESCE(a_diff, diff),
+        applied_at = CASE WHEN a_status = 'Applied' THEN EXTRACT(EPOCH FROM NOW())::bigint END
+    WHERE pkey_id = a_version_id;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_user_claim_due_strategy_versions",
+            vec![],
+            vec![
+                Field::new("version_id", Type::BigInt),
+                Field::new("strategy_id", Type::BigInt),
+                Field::new("user_id", Type::BigInt),
+                Field::new("method_id", Type::Int),
+                Field::new("params", Type::Object),
+            ],
+            r#"
+BEGIN
+    -- every user service looks for due versions, each one is replayed by the service that claims it
+    RETURN QUERY WITH claimed AS (
+        UPDATE tbl.strategy_version AS v
+        SET status = 'Applying'
+        WHERE v.pkey_id IN (SELECT d.pkey_id
+                            FROM tbl.strategy_version AS d
+                            WHERE d.status = 'Scheduled'
+                              AND d.effective_at <= EXTRACT(EPOCH FROM NOW())::bigint
+                            FOR UPDATE SKIP LOCKED)
+        RETURNING v.pkey_id, v.fkey_strategy_id, v.fkey_changed_by_user_id, v.method_id, v.params
+    )
+    SELECT c.pkey_id, c.fkey_strategy_id, c.fkey_changed_by_user_id, c.method_id, c.params
+    FROM claimed AS c
+    ORDER BY c.pkey_id;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_user_list_strategy_versions",
+            vec![
+                Field::new("strategy_id", Type::BigInt),
+                Field::new("limit", Type::BigInt),
+                Field::new("offset", Type::BigInt),
+            ],
+            vec![
+                Field::new("total", Type::BigInt),
+                Field::new("version", Type::BigInt),
+                Field::new("method_name", Type::String),
+                Field::new("params", Type::Object),
+                Field::new("diff", Type::optional(Type::Object)),
+                Field::new("is_material", Type::Boolean),
+                Field::new("status", Type::enum_ref("strategy_version_status")),
+                Field::new("effective_at", Type::BigInt),
+                Field::new("applied_at", Type::optional(Type::BigInt)),
+                Field::new("created_at", Type::BigInt),
+            ],
+            r#"
+BEGIN
+    RETURN QUERY SELECT count(*) OVER() AS total,
+                        v.version,
+                        v.method_name,
+                        v.params,
+                        v.diff,
+                        v.is_material,
+                        v.status,
+                        v.effective_at,
+                        v.applied_at,
+                        v.created_at
+                 FROM tbl.strategy_version AS v
+                 WHERE v.fkey_strategy_id = a_strategy_id
+                 ORDER BY v.version DESC
+                 OFFSET a_offset
+                 LIMIT a_limit;
+END
+"#,
+        ),
     ]
 }
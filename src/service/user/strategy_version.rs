@@ -0,0 +1,385 @@
+use crate::admin_method::{notify_users, AdminSubscribeTopic};
+use crate::method::*;
+use chrono::Utc;
+use eyre::*;
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use gen::database::*;
+use gen::model::*;
+use lib::database::DbClient;
+use lib::deferred::{DeferPolicy, DeferredCall, DeferredHandlers};
+use lib::handler::{FutureResponse, RequestHandler};
+use lib::system_config::system_settings;
+use lib::toolbox::{CustomError, RequestContext, Toolbox};
+use lib::ws::{SubscribeManager, WsRequest};
+use lib::{DEFAULT_LIMIT, DEFAULT_OFFSET};
+use serde_json::{json, Map, Value};
+use std::net::Ipv4Addr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::sleep;
+use tracing::*;
+
+/// Keys of a strategy snapshot backers agreed to when they backed it. A change to any of
+/// them is material
+pub const MATERIAL_STRATEGY_KEYS: &[&str] =
+    &["expert_fee", "swap_fee", "watching_wallets", "audit_rules"];
+/// Time between two checks for scheduled strategy changes that became due
+const SCHEDULED_CHANGES_INTERVAL: Duration = Duration::from_secs(60);
+
+/// An expert endpoint that changes a strategy. Every call is recorded as a new
+/// strategy version along with the fields it changed.
+pub trait StrategyChange: RequestHandler {
+    fn strategy_id(&self, req: &Self::Request) -> i64;
+    /// Whether `req` changes something backers agreed to. Material changes to a
+    /// backed strategy only take effect once backers had time to exit
+    fn is_material(&self, _req: &Self::Request) -> bool {
+        false
+    }
+}
+
+impl StrategyChange for MethodExpertUpdateStrategy {
+    fn strategy_id(&self, req: &Self::Request) -> i64 {
+        req.strategy_id
+    }
+    fn is_material(&self, req: &Self::Request) -> bool {
+        req.expert_fee.is_some()
+    }
+}
+impl StrategyChange for MethodExpertAddStrategyWatchingWallet {
+    fn strategy_id(&self, req: &Self::Request) -> i64 {
+        req.strategy_id
+    }
+    fn is_material(&self, _req: &Self::Request) -> bool {
+        true
+    }
+}
+impl StrategyChange for MethodExpertRemoveStrategyWatchingWallet {
+    fn strategy_id(&self, req: &Self::Request) -> i64 {
+        req.strategy_id
+    }
+    fn is_material(&self, _req: &Self::Request) -> bool {
+        true
+    }
+}
+impl StrategyChange for MethodUserAddStrategyAuditRule {
+    fn strategy_id(&self, req: &Self::Request) -> i64 {
+        req.strategy_id
+    }
+    fn is_material(&self, _req: &Self::Request) -> bool {
+        true
+    }
+}
+impl StrategyChange for MethodUserRemoveStrategyAuditRule {
+    fn strategy_id(&self, req: &Self::Request) -> i64 {
+        req.strategy_id
+    }
+    fn is_material(&self, _req: &Self::Request) -> bool {
+        true
+    }
+}
+impl StrategyChange for MethodExpertAddStrategyInitialTokenRatio {
+    fn strategy_id(&self, req: &Self::Request) -> i64 {
+        req.strategy_id
+    }
+}
+impl StrategyChange for MethodExpertRemoveStrategyInitialTokenRatio {
+    fn strategy_id(&self, req: &Self::Request) -> i64 {
+        req.strategy_id
+    }
+}
+impl StrategyChange for MethodExpertAddStrategyGuardRule {
+    fn strategy_id(&self, req: &Self::Request) -> i64 {
+        req.strategy_id
+    }
+}
+impl StrategyChange for MethodExpertRemoveStrategyGuardRule {
+    fn strategy_id(&self, req: &Self::Request) -> i64 {
+        req.strategy_id
+    }
+}
+
+async fn load_strategy_snapshot(db: &DbClient, strategy_id: i64) -> Result<(Option<i64>, Value)> {
+    let ret = db
+        .execute(FunUserGetStrategySnapshotReq { strategy_id })
+        .await?
+        .into_result()
+        .with_context(|| CustomError::new(EnumErrorCode::NotFound, "failed to find strategy"))?;
+    Ok((ret.creator_id, ret.snapshot))
+}
+
+/// `{key: {before, after}}` for every top level key whose value differs between the snapshots
+pub fn diff_snapshots(before: &Value, after: &Value) -> Value {
+    let empty = Map::new();
+    let before = before.as_object().unwrap_or(&empty);
+    let after = after.as_object().unwrap_or(&empty);
+    let mut diff = Map::new();
+    for key in before.keys().chain(after.keys()) {
+        let old = before.get(key).unwrap_or(&Value::Null);
+        let new = after.get(key).unwrap_or(&Value::Null);
+        if old != new {
+            diff.insert(key.clone(), json!({ "before": old, "after": new }));
+        }
+    }
+    Value::Object(diff)
+}
+
+fn is_material_diff(diff: &Value) -> bool {
+    MATERIAL_STRATEGY_KEYS
+        .iter()
+        .any(|key| diff.get(key).is_some())
+}
+
+async fn notify_strategy_backers(
+    toolbox: &Toolbox,
+    manager: &SubscribeManager<AdminSubscribeTopic>,
+    strategy_id: i64,
+    message: String,
+) -> Result<()> {
+    let db: DbClient = toolbox.get_db();
+    let backers = db
+        .execute(FunUserListStrategyCurrentBackersReq { strategy_id })
+        .await?
+        .map(|x| x.user_id);
+    notify_users(
+        toolbox,
+        manager,
+        backers,
+        EnumUserNotificationKind::StrategyChange,
+        Some(strategy_id),
+        message,
+    )
+    .await
+}
+
+/// Schedules material changes to a strategy that has backers as a new version instead of
+/// running them right away, and records a version for every change that ran
+pub struct StrategyChangePolicy {
+    manager: Arc<SubscribeManager<AdminSubscribeTopic>>,
+}
+impl StrategyChangePolicy {
+    pub fn new(manager: Arc<SubscribeManager<AdminSubscribeTopic>>) -> Self {
+        Self { manager }
+    }
+}
+impl<T: StrategyChange + 'static> DeferPolicy<T> for StrategyChangePolicy {
+    fn defer(
+        &self,
+        call: &DeferredCall<T>,
+        toolbox: &Toolbox,
+        ctx: RequestContext,
+        req: T::Request,
+    ) -> BoxFuture<'static, Result<Option<Report>>> {
+        let call = call.clone();
+        let manager = self.manager.clone();
+        let toolbox = toolbox.clone();
+        async move {
+            let db: DbClient = toolbox.get_db();
+            let strategy_id = call.handler.strategy_id(&req);
+            let notice = system_settings().strategy_change_notice();
+            let schedule = call.handler.is_material(&req)
+                && !notice.is_zero()
+                && !db
+                    .execute(FunUserListStrategyCurrentBackersReq { strategy_id })
+                    .await?
+                    .into_rows()
+                    .is_empty();
+            if !schedule {
+                return Ok(None);
+            }
+            let (creator_id, _) = load_strategy_snapshot(&db, strategy_id).await?;
+            ensure!(
+                creator_id == Some(ctx.user_id),
+                CustomError::new(EnumErrorCode::UserForbidden, "Not your strategy")
+            );
+            let effective_at = Utc::now().timestamp() + notice.as_secs() as i64;
+            let version = db
+                .execute(FunUserAddStrategyVersionReq {
+                    strategy_id,
+                    user_id: ctx.user_id,
+                    method_id: T::Request::METHOD_ID as _,
+                    method_name: call.method.clone(),
+                    params: serde_json::to_value(&req)?,
+                    diff: None,
+                    is_material: true,
+                    status: EnumStrategyVersionStatus::Scheduled,
+                    effective_at,
+                })
+                .await?
+                .into_result()
+                .context("failed to schedule strategy change")?
+                .version;
+            notify_strategy_backers(
+                &toolbox,
+                &manager,
+                strategy_id,
+                format!(
+                    "Strategy {} will be changed by {} at {}, you can exit it before then",
+                    strategy_id, call.method, effective_at
+                ),
+            )
+            .await?;
+
+            Ok(Some(eyre!(CustomError::new(
+                EnumErrorCode::StrategyChangeScheduled,
+                format!(
+                    "{} changes what backers agreed to, scheduled as version {} taking effect at {}",
+                    call.method, version, effective_at
+                )
+            ))))
+        }
+        .boxed()
+    }
+    fn run(
+        &self,
+        call: &DeferredCall<T>,
+        toolbox: &Toolbox,
+        ctx: RequestContext,
+        req: T::Request,
+        scheduled_version_id: Option<i64>,
+    ) -> FutureResponse<T::Request> {
+        let db: DbClient = toolbox.get_db();
+        let toolbox = toolbox.clone();
+        let call = call.clone();
+        let manager = self.manager.clone();
+        async move {
+            let strategy_id = call.handler.strategy_id(&req);
+            let params = serde_json::to_value(&req)?;
+            let (_, before) = load_strategy_snapshot(&db, strategy_id).await?;
+            let resp = RequestHandler::handle(&*call.handler, &toolbox, ctx, req).await?;
+            let (_, after) = load_strategy_snapshot(&db, strategy_id).await?;
+            let diff = diff_snapshots(&before, &after);
+            let is_material = is_material_diff(&diff);
+            match scheduled_version_id {
+                Some(version_id) => {
+                    db.execute(FunUserSetStrategyVersionStatusReq {
+                        version_id,
+                        status: EnumStrategyVersionStatus::Applied,
+                        diff: Some(diff),
+                    })
+                    .await?;
+                }
+                None => {
+                    db.execute(FunUserAddStrategyVersionReq {
+                        strategy_id,
+                        user_id: ctx.user_id,
+                        method_id: T::Request::METHOD_ID as _,
+                        method_name: call.method.clone(),
+                        params,
+                        diff: Some(diff),
+                        is_material,
+                        status: EnumStrategyVersionStatus::Applied,
+                        effective_at: Utc::now().timestamp(),
+                    })
+                    .await?;
+                }
+            }
+            if is_material {
+                notify_strategy_backers(
+                    &toolbox,
+                    &manager,
+                    strategy_id,
+                    format!("Strategy {} was changed by {}", strategy_id, call.method),
+                )
+                .await?;
+            }
+            Ok(resp)
+        }
+        .boxed()
+    }
+}
+
+/// The strategy changes a server exposes, a scheduled change is replayed from here once
+/// it is due
+pub type StrategyChanges = DeferredHandlers<StrategyChangePolicy>;
+
+async fn apply_due_strategy_changes(changes: &StrategyChanges, toolbox: &Toolbox) -> Result<()> {
+    let db: DbClient = toolbox.get_db();
+    /* claimed versions are no longer scheduled, a crash while replaying leaves them `Applying` */
+    let due = db
+        .execute(FunUserClaimDueStrategyVersionsReq {})
+        .await?
+        .into_rows();
+    for version in due {
+        let ctx = RequestContext {
+            connection_id: 0,
+            user_id: version.user_id,
+            seq: 0,
+            method: version.method_id as _,
+            log_id: 0,
+            ip_addr: Ipv4Addr::new(127, 0, 0, 1).into(),
+            role: EnumRole::Expert as u32,
+        };
+        let result = changes
+            .replay(
+                toolbox,
+                ctx,
+                version.method_id as _,
+                version.params,
+                version.version_id,
+            )
+            .await;
+        if let Err(err) = result {
+            error!(
+                "Failed to apply version {} of strategy {}: {:?}",
+                version.version_id, version.strategy_id, err
+            );
+            db.execute(FunUserSetStrategyVersionStatusReq {
+                version_id: version.version_id,
+                status: EnumStrategyVersionStatus::Failed,
+                diff: None,
+            })
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+/// Applies scheduled strategy changes once their notice period is over
+pub async fn apply_scheduled_strategy_changes(changes: Arc<StrategyChanges>, toolbox: Toolbox) {
+    loop {
+        if let Err(err) = apply_due_strategy_changes(&changes, &toolbox).await {
+            error!("Failed to apply scheduled strategy changes: {:?}", err);
+        }
+        sleep(SCHEDULED_CHANGES_INTERVAL).await;
+    }
+}
+
+pub struct MethodUserListStrategyVersions;
+impl RequestHandler for MethodUserListStrategyVersions {
+    type Request = UserListStrategyVersionsRequest;
+
+    fn handle(
+        &self,
+        toolbox: &Toolbox,
+        _ctx: RequestContext,
+        req: Self::Request,
+    ) -> FutureResponse<Self::Request> {
+        let db: DbClient = toolbox.get_db();
+        async move {
+            let ret = db
+                .execute(FunUserListStrategyVersionsReq {
+                    strategy_id: req.strategy_id,
+                    limit: req.limit.unwrap_or(DEFAULT_LIMIT),
+                    offset: req.offset.unwrap_or(DEFAULT_OFFSET),
+                })
+                .await?;
+
+            Ok(UserListStrategyVersionsResponse {
+                versions_total: ret.first(|x| x.total).unwrap_or_default(),
+                versions: ret.map(|x| ListStrategyVersionsRow {
+                    version: x.version,
+                    change: x.method_name,
+                    params: x.params,
+                    diff: x.diff,
+                    is_material: x.is_material,
+                    status: x.status,
+                    effective_at: x.effective_at,
+                    applied_at: x.applied_at,
+                    created_at: x.created_at,
+                }),
+            })
+        }
+        .boxed()
+    }
+}
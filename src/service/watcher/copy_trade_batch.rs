@@ -0,0 +1,59 @@
+use crate::rebalance::rebalance_strategy;
+use crate::strategy_pool_trade::StrategyPool;
+use crate::AppState;
+use execution_engine::copy_trade::CopyTradeLimits;
+use eyre::*;
+use gen::database::*;
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::Decimal;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::*;
+
+/// how often the watcher looks for batched or cooled down copy trades that are ready to execute
+pub const BATCHED_COPY_TRADE_CHECK_INTERVAL: Duration = Duration::from_secs(15);
+
+/// expert swaps that arrive during a batching window or a cooldown only mark the strategy as pending.
+/// once the window and cooldown have passed, the strategy pool is traded once towards the expert's
+/// current holdings, which nets out all the swaps in between
+pub async fn flush_batched_copy_trades(state: Arc<AppState>) -> Result<()> {
+    let strategies = state
+        .db
+        .execute(FunWatcherListStrategiesDueForBatchedCopyTradeReq {})
+        .await?;
+    for strategy in strategies.into_iter() {
+        let limits = CopyTradeLimits {
+            min_trade_usd: strategy.copy_trade_min_usd.and_then(Decimal::from_f64),
+            max_trade_aum_ratio: strategy
+                .copy_trade_max_aum_ratio
+                .and_then(Decimal::from_f64),
+        };
+        /* clear the pending flag first, expert swaps arriving while we trade start a new batch */
+        state
+            .db
+            .execute(FunWatcherUpdateStrategyLastCopyTradedAtReq {
+                strategy_id: strategy.strategy_id,
+            })
+            .await?;
+        if let Err(err) = rebalance_strategy(
+            &state,
+            &state.asset_client,
+            StrategyPool {
+                strategy_id: strategy.strategy_id,
+                blockchain: strategy.blockchain,
+                contract_id: strategy.strategy_pool_contract_id,
+                address: strategy.strategy_pool_address.into(),
+            },
+            Decimal::ZERO,
+            limits,
+        )
+        .await
+        {
+            error!(
+                "failed to execute batched copy trade for strategy {}: {:?}",
+                strategy.strategy_id, err
+            );
+        }
+    }
+    Ok(())
+}
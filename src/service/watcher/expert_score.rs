@@ -0,0 +1,339 @@
+use crate::AppState;
+use api::AssetInfoClient;
+use chrono::Utc;
+use execution_engine::copy_trade::{
+    calculate_asset_values, fetch_strategy_pool_contract_asset_balances_and_decimals,
+    get_token_prices,
+};
+use eyre::*;
+use gen::database::*;
+use itertools::Itertools;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::*;
+use web3::types::Address;
+
+/// how often the watcher records strategy values and recomputes expert scores
+pub const EXPERT_SCORE_INTERVAL: Duration = Duration::from_secs(3600);
+/// how far back trading activity and strategy values are considered
+const SCORE_WINDOW_DAYS: i64 = 90;
+const DAY: i64 = 86400;
+const WEEK: i64 = 7 * DAY;
+
+/// one `tbl.strategy_daily_value` row
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DailyValue {
+    pub day: i64,
+    pub aum_usd: f64,
+    pub share_price: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StrategyPerformance {
+    /// share price change over the window, 0.1 means +10%
+    pub total_return: f64,
+    /// largest drop of the share price from a previous peak, between 0 and 1
+    pub max_drawdown: f64,
+    /// standard deviation of the daily share price returns, annualized
+    pub volatility: f64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ExpertScoreInput {
+    /// weeks between the start of the window, or the first trade if later, and now. 0 if the
+    /// expert never traded
+    pub observed_weeks: i64,
+    /// weeks of the window in which the expert's watched wallets traded
+    pub active_weeks: i64,
+    /// users who ever backed one of the expert's strategies
+    pub backers_total: i64,
+    /// users currently holding shares of one of the expert's strategies
+    pub backers_current: i64,
+    /// the daily values of each strategy, ordered by day
+    pub strategies: Vec<Vec<DailyValue>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExpertScores {
+    pub consistent_score: f64,
+    pub risk_score: f64,
+    pub reputation_score: f64,
+    pub aum: f64,
+}
+
+/// performance of a strategy from its share price history, `None` with fewer than two prices.
+/// share prices only move with the value of the pool assets, not with backers entering or
+/// exiting, so they measure the expert's trading alone
+pub fn strategy_performance(values: &[DailyValue]) -> Option<StrategyPerformance> {
+    let prices: Vec<f64> = values
+        .iter()
+        .filter_map(|x| x.share_price)
+        .filter(|x| *x > 0.0)
+        .collect();
+    if prices.len() < 2 {
+        return None;
+    }
+    let returns: Vec<f64> = prices.windows(2).map(|x| x[1] / x[0] - 1.0).collect();
+    let mean = returns.iter().sum::<f64>() / returns.len() as f64;
+    let variance = returns.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / returns.len() as f64;
+    let mut peak = prices[0];
+    let mut max_drawdown: f64 = 0.0;
+    for price in prices.iter() {
+        peak = peak.max(*price);
+        max_drawdown = max_drawdown.max(1.0 - price / peak);
+    }
+    Some(StrategyPerformance {
+        total_return: prices[prices.len() - 1] / prices[0] - 1.0,
+        max_drawdown,
+        volatility: variance.sqrt() * 365f64.sqrt(),
+    })
+}
+
+/// every score is between 0 and 1. strategy figures are averaged weighted by the latest AUM of
+/// each strategy, or equally when no strategy holds anything.
+///
+/// - activity: share of the observed weeks in which the expert traded
+/// - consistency: half activity, half stability of returns (1 - annualized volatility). only
+///   activity when there is no strategy history
+/// - risk: 0.6 * max drawdown + 0.4 * annualized volatility, both capped at 1. 0.5 when there is
+///   no strategy history
+/// - reputation: 0.35 * performance + 0.25 * consistency + 0.2 * (1 - risk) + 0.2 * retention,
+///   where performance maps the return onto 0..1 with 0% at 0.5 (0.5 + 0.5 * tanh(return / 0.5))
+///   and retention is the share of all backers who still back the expert, 0.5 without backers
+/// - aum: sum of the latest AUM in USD of the expert's strategies
+pub fn compute_expert_scores(input: &ExpertScoreInput) -> ExpertScores {
+    let activity = if input.observed_weeks > 0 {
+        (input.active_weeks as f64 / input.observed_weeks as f64).min(1.0)
+    } else {
+        0.0
+    };
+    let latest_aums: Vec<f64> = input
+        .strategies
+        .iter()
+        .map(|x| x.last().map(|x| x.aum_usd.max(0.0)).unwrap_or_default())
+        .collect();
+    let aum = latest_aums.iter().sum::<f64>();
+    let performances: Vec<(f64, StrategyPerformance)> = input
+        .strategies
+        .iter()
+        .zip(latest_aums.iter())
+        .filter_map(|(values, aum)| strategy_performance(values).map(|x| (*aum, x)))
+        .collect();
+    let total_weight = performances.iter().map(|x| x.0).sum::<f64>();
+    let weighted = |f: fn(&StrategyPerformance) -> f64| -> f64 {
+        if total_weight > 0.0 {
+            performances.iter().map(|(w, x)| w * f(x)).sum::<f64>() / total_weight
+        } else {
+            performances.iter().map(|(_, x)| f(x)).sum::<f64>() / performances.len() as f64
+        }
+    };
+
+    let (consistent_score, risk_score, performance) = if performances.is_empty() {
+        (activity, 0.5, 0.5)
+    } else {
+        let volatility = weighted(|x| x.volatility.min(1.0));
+        let max_drawdown = weighted(|x| x.max_drawdown.min(1.0));
+        let total_return = weighted(|x| x.total_return);
+        (
+            0.5 * activity + 0.5 * (1.0 - volatility),
+            0.6 * max_drawdown + 0.4 * volatility,
+            0.5 + 0.5 * (total_return / 0.5).tanh(),
+        )
+    };
+    let retention = if input.backers_total > 0 {
+        (input.backers_current as f64 / input.backers_total as f64).min(1.0)
+    } else {
+        0.5
+    };
+    ExpertScores {
+        consistent_score,
+        risk_score,
+        reputation_score: 0.35 * performance
+            + 0.25 * consistent_score
+            + 0.2 * (1.0 - risk_score)
+            + 0.2 * retention,
+        aum,
+    }
+}
+
+/// records today's value of every strategy, then recomputes the scores of every approved expert
+pub async fn score_experts(
+    state: Arc<AppState>,
+    asset_client: Arc<dyn AssetInfoClient>,
+) -> Result<()> {
+    let now = Utc::now().timestamp();
+    let strategies = state
+        .db
+        .execute(FunWatcherListStrategiesForScoringReq {})
+        .await?
+        .into_rows();
+    for strategy in strategies {
+        if let Err(err) = record_strategy_daily_value(&state, &asset_client, &strategy, now).await {
+            error!(
+                "failed to record daily value of strategy {}: {:?}",
+                strategy.strategy_id, err
+            );
+        }
+    }
+
+    let since = now - SCORE_WINDOW_DAYS * DAY;
+    let mut values_by_expert: HashMap<i64, Vec<Vec<DailyValue>>> = HashMap::new();
+    let values = state
+        .db
+        .execute(FunWatcherListExpertStrategyDailyValuesReq { since })
+        .await?
+        .into_rows();
+    /* rows are ordered by expert and strategy */
+    for ((expert_user_id, _), values) in &values
+        .into_iter()
+        .group_by(|x| (x.expert_user_id, x.strategy_id))
+    {
+        values_by_expert.entry(expert_user_id).or_default().push(
+            values
+                .map(|x| DailyValue {
+                    day: x.day,
+                    aum_usd: x.aum_usd,
+                    share_price: x.share_price,
+                })
+                .collect(),
+        );
+    }
+    let experts = state
+        .db
+        .execute(FunWatcherListExpertScoringStatsReq { since })
+        .await?
+        .into_rows();
+    for expert in experts {
+        let observed_weeks = match expert.first_traded_at {
+            Some(first_traded_at) => (now - first_traded_at.max(since) + WEEK - 1) / WEEK,
+            None => 0,
+        };
+        let scores = compute_expert_scores(&ExpertScoreInput {
+            observed_weeks,
+            active_weeks: expert.active_weeks,
+            backers_total: expert.backers_total,
+            backers_current: expert.backers_current,
+            strategies: values_by_expert.remove(&expert.user_id).unwrap_or_default(),
+        });
+        if let Err(err) = state
+            .db
+            .execute(FunWatcherUpdateExpertScoresReq {
+                user_id: expert.user_id,
+                consistent_score: scores.consistent_score,
+                risk_score: scores.risk_score,
+                reputation_score: scores.reputation_score,
+                aum: scores.aum,
+            })
+            .await
+        {
+            error!(
+                "failed to update scores of expert {}: {:?}",
+                expert.user_id, err
+            );
+        }
+    }
+    Ok(())
+}
+
+async fn record_strategy_daily_value(
+    state: &AppState,
+    asset_client: &Arc<dyn AssetInfoClient>,
+    strategy: &FunWatcherListStrategiesForScoringRespRow,
+    now: i64,
+) -> Result<()> {
+    let (pool_amounts, _) = fetch_strategy_pool_contract_asset_balances_and_decimals(
+        &state.db,
+        strategy.blockchain,
+        strategy.strategy_id,
+    )
+    .await?;
+    let tokens: Vec<Address> = pool_amounts.keys().cloned().collect();
+    let prices = get_token_prices(&state.db, asset_client, tokens).await?;
+    let aum = calculate_asset_values(pool_amounts, prices)?
+        .values()
+        .sum::<Decimal>();
+    /* the share price is only comparable across days, the unit of the share tokens does not matter */
+    let share_price = if strategy.outstanding_sp_tokens.is_zero() {
+        None
+    } else {
+        (aum / strategy.outstanding_sp_tokens).to_f64()
+    };
+    state
+        .db
+        .execute(FunWatcherUpsertStrategyDailyValueReq {
+            strategy_id: strategy.strategy_id,
+            day: now - now % DAY,
+            aum_usd: aum.to_f64().context("strategy AUM out of range")?,
+            share_price,
+        })
+        .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(prices: &[f64]) -> Vec<DailyValue> {
+        prices
+            .iter()
+            .enumerate()
+            .map(|(i, x)| DailyValue {
+                day: i as i64 * DAY,
+                aum_usd: 100.0 * x,
+                share_price: Some(*x),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_strategy_performance() {
+        assert_eq!(strategy_performance(&values(&[1.0])), None);
+        let performance = strategy_performance(&values(&[1.0, 1.2, 0.9, 1.1])).unwrap();
+        assert!((performance.total_return - 0.1).abs() < 1e-9);
+        assert!((performance.max_drawdown - 0.25).abs() < 1e-9);
+        assert!(performance.volatility > 0.0);
+        let flat = strategy_performance(&values(&[1.0, 1.0, 1.0])).unwrap();
+        assert_eq!(flat.volatility, 0.0);
+        assert_eq!(flat.max_drawdown, 0.0);
+    }
+
+    #[test]
+    fn test_compute_expert_scores() {
+        let idle = compute_expert_scores(&ExpertScoreInput::default());
+        assert_eq!(idle.consistent_score, 0.0);
+        assert_eq!(idle.risk_score, 0.5);
+        assert_eq!(idle.aum, 0.0);
+
+        let steady = compute_expert_scores(&ExpertScoreInput {
+            observed_weeks: 10,
+            active_weeks: 10,
+            backers_total: 4,
+            backers_current: 4,
+            strategies: vec![values(&[1.0, 1.01, 1.02, 1.03])],
+        });
+        let erratic = compute_expert_scores(&ExpertScoreInput {
+            observed_weeks: 10,
+            active_weeks: 3,
+            backers_total: 4,
+            backers_current: 1,
+            strategies: vec![values(&[1.0, 1.5, 0.6, 1.03])],
+        });
+        assert!((steady.aum - 103.0).abs() < 1e-9);
+        assert!(steady.consistent_score > erratic.consistent_score);
+        assert!(steady.risk_score < erratic.risk_score);
+        assert!(steady.reputation_score > erratic.reputation_score);
+        for scores in [idle, steady, erratic] {
+            for score in [
+                scores.consistent_score,
+                scores.risk_score,
+                scores.reputation_score,
+            ] {
+                assert!((0.0..=1.0).contains(&score));
+            }
+        }
+    }
+}
@@ -0,0 +1,357 @@
+use crate::rebalance::execute_rebalance_trade;
+use crate::strategy_pool_trade::StrategyPool;
+use crate::AppState;
+use api::AssetInfoClient;
+use chrono::Utc;
+use execution_engine::copy_trade::{
+    calculate_asset_values, calculate_copy_trade_plan,
+    fetch_strategy_pool_contract_asset_balances_and_decimals, get_token_prices,
+};
+use eyre::*;
+use gen::database::*;
+use gen::model::*;
+use itertools::Itertools;
+use mc2fi_user::events::DomainEvent;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::*;
+use web3::types::Address;
+
+/// how often the watcher evaluates stop-loss and take-profit guards
+pub const GUARD_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GuardOutcome {
+    /// nothing happened
+    Hold,
+    /// the reference value should be moved, e.g. a new share value peak for drawdown rules
+    UpdateReference(f64),
+    Triggered,
+}
+
+/// drawdown and take-profit rules compare the USD value of one share of the pool against the
+/// reference value, so deposits and exits do not move them, asset stop-loss rules compare the
+/// USD price of a single asset.
+/// the reference value is the share value or price seen when the rule was first evaluated, and
+/// for drawdown rules it follows the share value up so the threshold is measured from the peak
+pub fn evaluate_guard_rule(
+    rule_type: EnumStrategyGuardRuleType,
+    threshold: f64,
+    reference_value: Option<f64>,
+    current_value: f64,
+) -> GuardOutcome {
+    let Some(reference_value) = reference_value else {
+        return if current_value > 0.0 {
+            GuardOutcome::UpdateReference(current_value)
+        } else {
+            GuardOutcome::Hold
+        };
+    };
+    match rule_type {
+        EnumStrategyGuardRuleType::MaxDrawdown if current_value > reference_value => {
+            GuardOutcome::UpdateReference(current_value)
+        }
+        EnumStrategyGuardRuleType::MaxDrawdown | EnumStrategyGuardRuleType::AssetStopLoss
+            if current_value <= reference_value * (1.0 - threshold) =>
+        {
+            GuardOutcome::Triggered
+        }
+        EnumStrategyGuardRuleType::TakeProfit
+            if current_value >= reference_value * (1.0 + threshold) =>
+        {
+            GuardOutcome::Triggered
+        }
+        _ => GuardOutcome::Hold,
+    }
+}
+
+pub async fn evaluate_strategy_guards(
+    state: Arc<AppState>,
+    asset_client: Arc<dyn AssetInfoClient>,
+) -> Result<()> {
+    let rules = state
+        .db
+        .execute(FunWatcherListActiveStrategyGuardRulesReq {})
+        .await?
+        .into_rows();
+    /* rules are ordered by strategy and pool, every pool is guarded against its own prices */
+    let rules_by_pool: Vec<((i64, i64), Vec<_>)> = rules
+        .into_iter()
+        .group_by(|x| (x.strategy_id, x.strategy_pool_contract_id))
+        .into_iter()
+        .map(|(key, rules)| (key, rules.collect()))
+        .collect();
+    let mut paused_strategies = HashSet::new();
+    for ((strategy_id, strategy_pool_contract_id), rules) in rules_by_pool {
+        /* copy trading of the strategy was paused by a guard of another pool in this round */
+        if paused_strategies.contains(&strategy_id) {
+            continue;
+        }
+        match evaluate_strategy_pool_guard_rules(&state, &asset_client, rules).await {
+            Ok(true) => {
+                paused_strategies.insert(strategy_id);
+            }
+            Ok(false) => {}
+            Err(err) => {
+                error!(
+                    "failed to evaluate guards of strategy {} pool {}: {:?}",
+                    strategy_id, strategy_pool_contract_id, err
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+/// returns whether a guard triggered
+async fn evaluate_strategy_pool_guard_rules(
+    state: &AppState,
+    asset_client: &Arc<dyn AssetInfoClient>,
+    rules: Vec<FunWatcherListActiveStrategyGuardRulesRespRow>,
+) -> Result<bool> {
+    let first = rules.first().context("no guard rules")?;
+    let strategy_id = first.strategy_id;
+    let blockchain = first.blockchain;
+    let outstanding_sp_tokens = first.outstanding_sp_tokens;
+    let (pool_amounts, pool_decimals) = fetch_strategy_pool_contract_asset_balances_and_decimals(
+        &state.db,
+        blockchain,
+        strategy_id,
+    )
+    .await?;
+    let tokens: Vec<Address> = pool_amounts
+        .keys()
+        .cloned()
+        .chain(rules.iter().filter_map(|x| x.token_address.map(Into::into)))
+        .unique()
+        .collect();
+    let prices = get_token_prices(&state.db, asset_client, tokens).await?;
+    let nav = calculate_asset_values(pool_amounts.clone(), prices.clone())?
+        .values()
+        .sum::<Decimal>();
+    /* nobody holds shares of the pool, there is no share value to guard */
+    let share_value = if outstanding_sp_tokens.is_zero() {
+        None
+    } else {
+        Some(
+            (nav / outstanding_sp_tokens)
+                .to_f64()
+                .context("share value out of range")?,
+        )
+    };
+
+    for rule in rules.iter() {
+        let current_value = match rule.rule_type {
+            EnumStrategyGuardRuleType::MaxDrawdown | EnumStrategyGuardRuleType::TakeProfit => {
+                let Some(share_value) = share_value else {
+                    continue;
+                };
+                share_value
+            }
+            EnumStrategyGuardRuleType::AssetStopLoss => {
+                let token: Address = rule
+                    .token_address
+                    .context("asset stop-loss without token")?
+                    .into();
+                *prices
+                    .get(&token)
+                    .with_context(|| format!("no price for {:?}", token))?
+            }
+        };
+        match evaluate_guard_rule(
+            rule.rule_type,
+            rule.threshold,
+            rule.reference_value,
+            current_value,
+        ) {
+            GuardOutcome::Hold => {}
+            GuardOutcome::UpdateReference(reference_value) => {
+                state
+                    .db
+                    .execute(FunWatcherUpdateStrategyGuardRuleReferenceValueReq {
+                        rule_id: rule.rule_id,
+                        strategy_pool_contract_id: rule.strategy_pool_contract_id,
+                        reference_value,
+                    })
+                    .await?;
+            }
+            GuardOutcome::Triggered => {
+                trigger_strategy_guard(
+                    state,
+                    rule,
+                    current_value,
+                    pool_amounts,
+                    pool_decimals,
+                    prices,
+                )
+                .await?;
+                /* copy trading is paused now, the remaining rules stay armed until the expert resumes */
+                return Ok(true);
+            }
+        }
+    }
+    Ok(false)
+}
+
+async fn trigger_strategy_guard(
+    state: &AppState,
+    rule: &FunWatcherListActiveStrategyGuardRulesRespRow,
+    current_value: f64,
+    pool_amounts: HashMap<Address, Decimal>,
+    mut pool_decimals: HashMap<Address, u32>,
+    mut prices: HashMap<Address, f64>,
+) -> Result<()> {
+    warn!(
+        "strategy {} guard {} ({}) triggered on {} at {}, pausing copy trading",
+        rule.strategy_id, rule.rule_id, rule.rule_type, rule.blockchain, current_value
+    );
+    let triggered_at = state
+        .db
+        .execute(FunWatcherTriggerStrategyGuardRuleReq {
+            rule_id: rule.rule_id,
+        })
+        .await?
+        .into_result()
+        .map(|x| x.triggered_at)
+        .unwrap_or_else(|| Utc::now().timestamp());
+
+    if let Some(exit_token) = rule.exit_token_address {
+        let exit_token: Address = exit_token.into();
+        let exit_token_row = state
+            .db
+            .execute(FunUserListEscrowTokenContractAddressReq {
+                limit: 1,
+                offset: 0,
+                token_id: None,
+                blockchain: Some(rule.blockchain),
+                address: Some(exit_token.into()),
+                symbol: None,
+                is_stablecoin: None,
+            })
+            .await?
+            .into_result()
+            .context("exit token not found")?;
+        pool_decimals.insert(exit_token, exit_token_row.decimals as _);
+        /* the exit token is a stablecoin, fall back to 1 USD when there is no price for it */
+        prices.entry(exit_token).or_insert(1.0);
+        if let Err(err) = exit_strategy_pool_to_token(
+            state,
+            rule,
+            exit_token,
+            pool_amounts,
+            pool_decimals,
+            prices,
+        )
+        .await
+        {
+            error!(
+                "failed to exit strategy {} into {:?}: {:?}",
+                rule.strategy_id, exit_token, err
+            );
+        }
+    }
+
+    state
+        .publish_event(DomainEvent::StrategyGuardTriggered(StrategyGuardEvent {
+            strategy_id: rule.strategy_id,
+            rule_id: rule.rule_id,
+            blockchain: rule.blockchain,
+            rule_type: rule.rule_type,
+            threshold: rule.threshold,
+            reference_value: rule.reference_value.unwrap_or_default(),
+            current_value,
+            exit_token_address: rule.exit_token_address.map(Into::into),
+            triggered_at,
+        }))
+        .await;
+    Ok(())
+}
+
+/// sells every asset of the strategy pool into the exit token
+async fn exit_strategy_pool_to_token(
+    state: &AppState,
+    rule: &FunWatcherListActiveStrategyGuardRulesRespRow,
+    exit_token: Address,
+    pool_amounts: HashMap<Address, Decimal>,
+    pool_decimals: HashMap<Address, u32>,
+    prices: HashMap<Address, f64>,
+) -> Result<()> {
+    if pool_amounts
+        .iter()
+        .all(|(token, amount)| *token == exit_token || amount.is_zero())
+    {
+        return Ok(());
+    }
+    let plan = calculate_copy_trade_plan(
+        rule.blockchain,
+        HashMap::from([(exit_token, Decimal::ONE)]),
+        pool_amounts,
+        prices,
+        pool_decimals.clone(),
+    )?;
+    info!(
+        "exiting strategy {} into {:?} in {} trades",
+        rule.strategy_id,
+        exit_token,
+        plan.trades.len()
+    );
+    let pool = StrategyPool {
+        strategy_id: rule.strategy_id,
+        blockchain: rule.blockchain,
+        contract_id: rule.strategy_pool_contract_id,
+        address: rule.strategy_pool_address.into(),
+    };
+    for trade in plan.trades {
+        execute_rebalance_trade(state, pool, &pool_decimals, trade).await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evaluate_guard_rule() {
+        use EnumStrategyGuardRuleType::*;
+        assert_eq!(
+            evaluate_guard_rule(MaxDrawdown, 0.2, None, 100.0),
+            GuardOutcome::UpdateReference(100.0)
+        );
+        assert_eq!(
+            evaluate_guard_rule(MaxDrawdown, 0.2, None, 0.0),
+            GuardOutcome::Hold
+        );
+        assert_eq!(
+            evaluate_guard_rule(MaxDrawdown, 0.2, Some(100.0), 120.0),
+            GuardOutcome::UpdateReference(120.0)
+        );
+        assert_eq!(
+            evaluate_guard_rule(MaxDrawdown, 0.2, Some(100.0), 85.0),
+            GuardOutcome::Hold
+        );
+        assert_eq!(
+            evaluate_guard_rule(MaxDrawdown, 0.2, Some(100.0), 80.0),
+            GuardOutcome::Triggered
+        );
+        assert_eq!(
+            evaluate_guard_rule(AssetStopLoss, 0.1, Some(10.0), 10.5),
+            GuardOutcome::Hold
+        );
+        assert_eq!(
+            evaluate_guard_rule(AssetStopLoss, 0.1, Some(10.0), 8.9),
+            GuardOutcome::Triggered
+        );
+        assert_eq!(
+            evaluate_guard_rule(TakeProfit, 0.5, Some(100.0), 140.0),
+            GuardOutcome::Hold
+        );
+        assert_eq!(
+            evaluate_guard_rule(TakeProfit, 0.5, Some(100.0), 150.0),
+            GuardOutcome::Triggered
+        );
+    }
+}
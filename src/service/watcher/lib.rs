@@ -1,6 +1,13 @@
+pub mod copy_trade_batch;
+pub mod expert_score;
+pub mod guard;
 pub mod method;
+pub mod pending_withdraw;
+pub mod rebalance;
+pub mod strategy_pool_trade;
 
 use api::cmc::CoinMarketCap;
+use api::AssetInfoClient;
 use eth_sdk::erc20::build_erc_20;
 use eth_sdk::pancake_swap::parse::{get_pancake_swap_parser, PancakeSwapParser};
 use eth_sdk::signer::Secp256k1SecretKey;
@@ -10,10 +17,12 @@ use eth_sdk::{
 };
 use eyre::*;
 use lib::database::DbClient;
-use lib::ws::WsClient;
+use lib::event_bus::EventBus;
+use mc2fi_asset_price::AssetPriceClient;
+use mc2fi_user::events::DomainEvent;
 use mc2fi_user::shared_method::load_escrow_address;
 use std::sync::Arc;
-use tokio::sync::Mutex;
+use tracing::*;
 use web3::ethabi::Contract;
 
 pub struct AppState {
@@ -27,15 +36,15 @@ pub struct AppState {
     pub wallet_herald_addresses: Arc<StrategyWalletHeraldAddresses>,
     pub erc_20: Contract,
     pub master_key: Secp256k1SecretKey,
-    pub admin_client: Option<Mutex<WsClient>>,
+    pub event_bus: Option<EventBus<DomainEvent>>,
     pub cmc_client: CoinMarketCap,
+    pub asset_client: Arc<dyn AssetInfoClient>,
 }
 impl AppState {
     pub async fn new(
         db: DbClient,
         eth_pool: EthereumRpcConnectionPool,
         master_key: Secp256k1SecretKey,
-        admin_client: WsClient,
         cmc_client: CoinMarketCap,
         token_addresses: Arc<BlockchainCoinAddresses>,
     ) -> Result<Self> {
@@ -44,14 +53,23 @@ impl AppState {
             eth_pool,
             pancake_swap_parser: get_pancake_swap_parser(),
             escrow_addresses: load_escrow_address(&db).await?,
+            asset_client: Arc::new(AssetPriceClient::new(db.clone())),
+            event_bus: Some(EventBus::new(db.clone())),
             db,
             token_addresses,
             erc_20: build_erc_20()?,
             master_key,
-            admin_client: Some(Mutex::new(admin_client)),
             cmc_client,
             pool_herald_addresses: Arc::new(StrategyPoolHeraldAddresses::new()),
             wallet_herald_addresses: Arc::new(StrategyWalletHeraldAddresses::new()),
         })
     }
+    /// lets the user service push the event to its connections, failures are only logged
+    pub async fn publish_event(&self, event: DomainEvent) {
+        if let Some(event_bus) = self.event_bus.as_ref() {
+            if let Err(err) = event_bus.publish(&event).await {
+                error!("error publishing {:?}: {:?}", event, err);
+            }
+        }
+    }
 }
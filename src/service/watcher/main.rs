@@ -15,8 +15,16 @@ use gen::model::*;
 use lib::config::load_config;
 use lib::database::{connect_to_database, DatabaseConfig};
 use lib::log::{setup_logs, LogLevel};
-use mc2fi_auth::{connect_user, signup};
-use mc2fi_user::shared_method::load_coin_addresses;
+use lib::scheduler::Scheduler;
+use lib::system_config::watch_system_config;
+use mc2fi_user::shared_method::{load_coin_addresses, load_system_settings};
+use mc2fi_watcher::copy_trade_batch::{
+    flush_batched_copy_trades, BATCHED_COPY_TRADE_CHECK_INTERVAL,
+};
+use mc2fi_watcher::expert_score::{score_experts, EXPERT_SCORE_INTERVAL};
+use mc2fi_watcher::guard::{evaluate_strategy_guards, GUARD_CHECK_INTERVAL};
+use mc2fi_watcher::pending_withdraw::{settle_pending_withdraws, PENDING_WITHDRAW_CHECK_INTERVAL};
+use mc2fi_watcher::rebalance::{rebalance_strategies, REBALANCE_CHECK_INTERVAL};
 use mc2fi_watcher::{method, AppState};
 use secrecy::{ExposeSecret, SecretString};
 use serde::Deserialize;
@@ -39,8 +47,6 @@ pub struct Config {
     pub priv_cert: Option<String>,
     pub ethereum_urls: EthereumConns,
     pub god_key: SecretString,
-    pub user_url: String,
-    pub auth_url: String,
     pub cmc_api_key: SecretString,
 }
 
@@ -50,21 +56,78 @@ async fn main() -> Result<()> {
     setup_logs(config.log_level)?;
     let cmc_client = CoinMarketCap::new(config.cmc_api_key.expose_secret())?;
     let master_key = Secp256k1SecretKey::from_str(config.god_key.expose_secret())?;
-    if let Err(err) = signup(&config.auth_url, "dev-watcher", &master_key.key).await {
-        error!("failed to signup: {}", err);
-    }
-
-    let client = connect_user(
-        &config.auth_url,
-        &config.user_url,
-        "dev-watcher",
-        &master_key.key,
-    )
-    .await?;
     let db = connect_to_database(config.app_db).await?;
+    watch_system_config(&db, load_system_settings).await?;
 
     let eth_pool = EthereumRpcConnectionPool::from_conns(config.ethereum_urls);
     let coin_addresses = load_coin_addresses(&db).await?;
+    let state =
+        Arc::new(AppState::new(db, eth_pool, master_key, cmc_client, coin_addresses).await?);
+    let asset_client = state.asset_client.clone();
+
+    let mut scheduler = Scheduler::new().await;
+    {
+        let state = state.clone();
+        let asset_client = asset_client.clone();
+        scheduler.add_adaptive_job(GUARD_CHECK_INTERVAL, move || {
+            let state = state.clone();
+            let asset_client = asset_client.clone();
+            async move {
+                if let Err(err) = evaluate_strategy_guards(state, asset_client).await {
+                    error!("failed to evaluate strategy guards: {:?}", err);
+                }
+            }
+        })?;
+    }
+    {
+        let state = state.clone();
+        scheduler.add_adaptive_job(BATCHED_COPY_TRADE_CHECK_INTERVAL, move || {
+            let state = state.clone();
+            async move {
+                if let Err(err) = flush_batched_copy_trades(state).await {
+                    error!("failed to flush batched copy trades: {:?}", err);
+                }
+            }
+        })?;
+    }
+    {
+        let state = state.clone();
+        let asset_client = asset_client.clone();
+        scheduler.add_adaptive_job(EXPERT_SCORE_INTERVAL, move || {
+            let state = state.clone();
+            let asset_client = asset_client.clone();
+            async move {
+                if let Err(err) = score_experts(state, asset_client).await {
+                    error!("failed to score experts: {:?}", err);
+                }
+            }
+        })?;
+    }
+    {
+        let state = state.clone();
+        scheduler.add_adaptive_job(PENDING_WITHDRAW_CHECK_INTERVAL, move || {
+            let state = state.clone();
+            async move {
+                if let Err(err) = settle_pending_withdraws(state).await {
+                    error!("failed to settle pending withdraws: {:?}", err);
+                }
+            }
+        })?;
+    }
+    {
+        let state = state.clone();
+        scheduler.add_adaptive_job(REBALANCE_CHECK_INTERVAL, move || {
+            let state = state.clone();
+            let asset_client = asset_client.clone();
+            async move {
+                if let Err(err) = rebalance_strategies(state, asset_client).await {
+                    error!("failed to rebalance strategies: {:?}", err);
+                }
+            }
+        })?;
+    }
+    scheduler.spawn().await;
+
     let app: Router<(), Body> = Router::new()
         .route("/eth-mainnet-swaps", post(handle_swaps_eth_mainnet))
         .route("/eth-mainnet-escrows", post(handle_escrows_eth_mainnet))
@@ -98,9 +161,7 @@ async fn main() -> Result<()> {
             "/bsc-testnet-revoke-adminships",
             post(handle_revoke_adminships_bsc_testnet),
         )
-        .with_state(Arc::new(
-            AppState::new(db, eth_pool, master_key, client, cmc_client, coin_addresses).await?,
-        ));
+        .with_state(state);
 
     let addr = tokio::net::lookup_host((config.host.as_ref(), config.port))
         .await?
@@ -1,36 +1,39 @@
+use crate::strategy_pool_trade::{execute_strategy_pool_trade, StrategyPool, StrategyPoolTrade};
 use crate::AppState;
-use api::cmc::CoinMarketCap;
 use axum::http::StatusCode;
 use bytes::Bytes;
 use chrono::Utc;
 use eth_sdk::dex_tracker::{
     get_strategy_id_from_watching_wallet, parse_dex_trade,
     update_expert_listened_wallet_asset_balance_cache,
-    update_user_strategy_pool_asset_balances_on_copy_trade,
 };
-use eth_sdk::erc20::Erc20Token;
 use eth_sdk::escrow::{parse_escrow_withdraw_event, EscrowContract};
 use eth_sdk::escrow_tracker::escrow::parse_escrow_transfer;
 use eth_sdk::evm::{parse_quickalert_payload, DexTrade};
 use eth_sdk::execute_transaction_and_ensure_success;
-use eth_sdk::pancake_swap::execute::PancakeSmartRouterContract;
 use eth_sdk::strategy_pool::StrategyPoolContract;
 use eth_sdk::strategy_pool_herald::parse_strategy_pool_herald_redeem_event;
 use eth_sdk::utils::{
     decimal_to_u256, u256_to_decimal, wait_for_confirmations, wait_for_confirmations_simple,
 };
 use eth_sdk::{
-    evm, EthereumRpcConnection, ScaledMath, TransactionFetcher, TransactionReady, CONFIRMATIONS,
-    MAX_RETRIES, POLL_INTERVAL,
+    confirmations, evm, max_retries, poll_interval, ScaledMath, TransactionFetcher,
+    TransactionReady,
+};
+use execution_engine::copy_trade::{
+    calculate_asset_values, fetch_strategy_pool_contract_asset_balances_and_decimals,
+    get_token_prices, CopyTradeLimits,
 };
 use eyre::*;
 use gen::database::*;
 use gen::model::*;
 use lib::log::DynLogger;
+use mc2fi_user::events::{DomainEvent, EscrowCreditedEvent, RedeemProcessedEvent};
 use mc2fi_user::shared_method::{
-    update_asset_balances_and_ledger_exit_strategy,
+    calculate_gas_fee_in_tokens, update_asset_balances_and_ledger_exit_strategy,
     update_strategy_token_balances_and_ledger_exit_strategy,
 };
+use rust_decimal::prelude::FromPrimitive;
 use rust_decimal::Decimal;
 use std::sync::Arc;
 use std::time::Duration;
@@ -218,9 +221,41 @@ pub async fn copy_trade_for_strategy(
     blockchain: EnumBlockChain,
     expert_trade: DexTrade,
 ) -> Result<()> {
+    let controls = state
+        .db
+        .execute(FunWatcherGetStrategyCopyTradeControlsReq { strategy_id })
+        .await?
+        .into_result()
+        .context("strategy not found")?;
+    if controls.copy_trading_paused {
+        info!(
+            "copy trading for strategy {} is paused by a guard",
+            strategy_id
+        );
+        return Ok(());
+    }
+    /* defer to the batched copy trade job during a batching window or a cooldown */
+    let now = Utc::now().timestamp();
+    let in_cooldown = matches!(
+        (controls.last_copy_traded_at, controls.copy_trade_cooldown_seconds),
+        (Some(last), Some(cooldown)) if last + cooldown > now
+    );
+    if controls.copy_trade_batch_window_seconds.is_some() || in_cooldown {
+        info!("batching copy trade for strategy {}", strategy_id);
+        state
+            .db
+            .execute(FunWatcherMarkStrategyCopyTradePendingReq { strategy_id })
+            .await?;
+        return Ok(());
+    }
+    let limits = CopyTradeLimits {
+        min_trade_usd: controls.copy_trade_min_usd.and_then(Decimal::from_f64),
+        max_trade_aum_ratio: controls
+            .copy_trade_max_aum_ratio
+            .and_then(Decimal::from_f64),
+    };
     info!("start copy trading for strategy {}", strategy_id);
 
-    let conn = state.eth_pool.get(blockchain).await?;
     let token_in_row = state
         .db
         .execute(FunUserListEscrowTokenContractAddressReq {
@@ -296,9 +331,7 @@ pub async fn copy_trade_for_strategy(
         .into_result()
         .context("could not fetch strategy pool contract row on this chain")?;
 
-    let address: Address = strategy_pool_contract_row.address.into();
     /* check if SP contract holds token_in */
-    let strategy_pool_contract = StrategyPoolContract::new(conn.clone(), address)?;
     let strategy_pool_asset_token_in_row = state
         .db
         .execute(FunWatcherListStrategyPoolContractAssetBalancesReq {
@@ -330,262 +363,71 @@ pub async fn copy_trade_for_strategy(
     } else {
         u256_to_decimal(expert_trade.amount_in, token_in_decimals as _)
     };
-    let amount_to_spend = corrected_amount_in * sp_asset_token_in_previous_amount
+    let mut amount_to_spend = corrected_amount_in * sp_asset_token_in_previous_amount
         / expert_wallet_asset_token_in_previous_amount;
     if amount_to_spend.is_zero() {
         bail!("spent ratio is too small to be represented in amount of token_in owned by strategy pool contract");
     }
-    info!(
-        "amount_to_spend: {} {:?}",
-        amount_to_spend, expert_trade.token_in
-    );
-    /* instantiate pancake swap contract */
-    let pancake_contract = PancakeSmartRouterContract::new(
-        conn.clone(),
-        state
-            .dex_addresses
-            .get(blockchain, EnumDex::PancakeSwap)
-            .ok_or_else(|| eyre!("pancake swap not available on this chain"))?,
-    )?;
-
-    /* acquire asset before trade */
-    let acquire_asset_before_trade_transaction = || {
-        let amount_to_spend = decimal_to_u256(amount_to_spend, 18);
-        strategy_pool_contract.acquire_asset_before_trade(
-            &conn,
-            state.master_key.clone(),
-            expert_trade.token_in,
-            amount_to_spend,
-        )
-    };
-
-    execute_transaction_and_ensure_success(
-        acquire_asset_before_trade_transaction,
-        &conn,
-        CONFIRMATIONS,
-        MAX_RETRIES,
-        POLL_INTERVAL,
-        &DynLogger::empty(),
-    )
-    .await?;
-    /* instantiate token_in and token_out contracts from expert's trade */
-    let token_in_contract = Erc20Token::new(conn.clone(), expert_trade.token_in)?;
-    let token_out_contract = Erc20Token::new(conn.clone(), expert_trade.token_out)?;
-
-    /* approve pancakeswap to trade token_in */
-    let approve_transaction = || {
-        let amount_to_spend = decimal_to_u256(amount_to_spend, token_in_decimals as _);
-        token_in_contract.approve(
-            &conn,
-            state.master_key.clone(),
-            pancake_contract.address(),
-            amount_to_spend,
-            DynLogger::empty(),
-        )
-    };
-
-    execute_transaction_and_ensure_success(
-        approve_transaction,
-        &conn,
-        CONFIRMATIONS,
-        MAX_RETRIES,
-        POLL_INTERVAL,
-        &DynLogger::empty(),
-    )
-    .await?;
-
-    /* trade token_in for token_out */
-    info!(
-        "copy_trade_and_ensure_success: amount_in: {}, amount_out_minimum: {}",
-        amount_to_spend, 1
-    );
-
-    let copy_trade_pair_paths = expert_trade.get_pancake_pair_paths()?;
-    let copy_trade_transaction = || {
-        let amount_to_spend = decimal_to_u256(amount_to_spend, token_in_decimals as _);
-        pancake_contract.copy_trade(
-            &conn,
-            state.master_key.clone(),
-            copy_trade_pair_paths.clone(),
-            amount_to_spend,
-            U256::one(),
-        )
-    };
-
-    let copy_trade_hash = execute_transaction_and_ensure_success(
-        copy_trade_transaction,
-        &conn,
-        CONFIRMATIONS,
-        MAX_RETRIES,
-        POLL_INTERVAL,
-        &DynLogger::empty(),
-    )
-    .await?;
-
-    info!(
-        "copy_trade_and_ensure_success: tx_hash: {:?}",
-        copy_trade_hash
-    );
-
-    let pending_wallet_trade_receipt = conn
-        .eth()
-        .transaction_receipt(copy_trade_hash)
-        .await?
-        .context("could not find transaction receipt for copy trade")?;
-
-    /* parse trade to find amount_out */
-    let strategy_pool_pending_wallet_trade = parse_dex_trade(
-        blockchain,
-        &TransactionFetcher::new_and_assume_ready(
-            pending_wallet_trade_receipt.transaction_hash,
-            &conn,
+    if !limits.is_empty() {
+        let (strategy_asset_amounts, _) = fetch_strategy_pool_contract_asset_balances_and_decimals(
+            &state.db,
+            blockchain,
+            strategy_id,
         )
-        .await?,
-        &state.dex_addresses,
-        &state.pancake_swap_parser,
-    )
-    .await?;
-
-    /* approve strategy pool for amount_out */
-    info!(
-        "approve strategy pool for amount_out: {:?} {:?} {:?}",
-        state.master_key.address(),
-        strategy_pool_contract.address(),
-        strategy_pool_pending_wallet_trade.amount_out,
-    );
-
-    let approve_transaction = || {
-        token_out_contract.approve(
-            &conn,
-            state.master_key.clone(),
-            strategy_pool_contract.address(),
-            strategy_pool_pending_wallet_trade.amount_out,
-            DynLogger::empty(),
+        .await?;
+        let prices = get_token_prices(
+            &state.db,
+            &state.asset_client,
+            strategy_asset_amounts.keys().cloned().collect(),
         )
-    };
-
-    execute_transaction_and_ensure_success(
-        approve_transaction,
-        &conn,
-        CONFIRMATIONS,
-        MAX_RETRIES,
-        POLL_INTERVAL,
-        &DynLogger::empty(),
-    )
-    .await?;
-
-    /* give back traded assets */
+        .await?;
+        let token_in_price = prices
+            .get(&expert_trade.token_in)
+            .and_then(|x| Decimal::from_f64(*x))
+            .context("could not get price of token_in")?;
+        let strategy_total_value = calculate_asset_values(strategy_asset_amounts, prices)?
+            .values()
+            .sum::<Decimal>();
+        match limits.limit_amount_in(amount_to_spend, token_in_price, strategy_total_value) {
+            Some(limited) => amount_to_spend = limited,
+            None => {
+                info!(
+                    "copy trade of {} {:?} for strategy {} is below the minimum trade size",
+                    amount_to_spend, expert_trade.token_in, strategy_id
+                );
+                return Ok(());
+            }
+        }
+    }
     info!(
-        "give_back_assets_after_trade: {:?} {:?} {:?}",
-        state.master_key.address(),
-        strategy_pool_contract.address(),
-        strategy_pool_pending_wallet_trade.amount_out,
+        "amount_to_spend: {} {:?}",
+        amount_to_spend, expert_trade.token_in
     );
-    let give_back_assets_after_trade_transaction = || {
-        strategy_pool_contract.give_back_assets_after_trade(
-            &conn,
-            state.master_key.clone(),
-            vec![expert_trade.token_out],
-            vec![strategy_pool_pending_wallet_trade.amount_out],
-        )
-    };
-
-    execute_transaction_and_ensure_success(
-        give_back_assets_after_trade_transaction,
-        &conn,
-        CONFIRMATIONS,
-        MAX_RETRIES,
-        POLL_INTERVAL,
-        &DynLogger::empty(),
+    execute_strategy_pool_trade(
+        &state,
+        StrategyPool {
+            strategy_id,
+            blockchain,
+            contract_id: strategy_pool_contract_row.pkey_id,
+            address: strategy_pool_contract_row.address.into(),
+        },
+        StrategyPoolTrade {
+            token_in: expert_trade.token_in,
+            token_out: expert_trade.token_out,
+            token_in_decimals: token_in_decimals as _,
+            token_out_decimals: token_out_decimals as _,
+            amount_in: decimal_to_u256(amount_to_spend, token_in_decimals as _),
+            amount_out_minimum: U256::one(),
+            paths: expert_trade.get_pancake_pair_paths()?,
+        },
     )
     .await?;
-
-    /* update strategy pool contract asset balances & ledger */
-
-    state
-        .db
-        .execute(FunWatcherUpsertStrategyPoolContractAssetBalanceReq {
-            strategy_pool_contract_id: strategy_pool_contract_row.pkey_id,
-            token_address: expert_trade.token_in.into(),
-            blockchain,
-            new_balance: sp_asset_token_in_previous_amount - amount_to_spend,
-        })
-        .await?;
-
-    let maybe_strategy_pool_asset_token_out_row = state
-        .db
-        .execute(FunWatcherListStrategyPoolContractAssetBalancesReq {
-            strategy_pool_contract_id: Some(strategy_pool_contract_row.pkey_id),
-            token_address: Some(expert_trade.token_out.into()),
-            blockchain: Some(blockchain),
-            strategy_id: None,
-        })
-        .await?
-        .into_result();
-    let token_in_amount = u256_to_decimal(
-        strategy_pool_pending_wallet_trade.amount_in,
-        token_in_decimals as _,
-    );
-    let token_out_amount = u256_to_decimal(
-        strategy_pool_pending_wallet_trade.amount_out,
-        token_out_decimals as _,
-    );
-    let strategy_pool_asset_token_out_new_balance = match maybe_strategy_pool_asset_token_out_row {
-        Some(token_out) => token_out.balance + token_out_amount,
-        None => token_out_amount,
-    };
-
-    state
-        .db
-        .execute(FunWatcherUpsertStrategyPoolContractAssetBalanceReq {
-            strategy_pool_contract_id: strategy_pool_contract_row.pkey_id,
-            token_address: expert_trade.token_out.into(),
-            blockchain,
-            new_balance: strategy_pool_asset_token_out_new_balance,
-        })
-        .await?;
-
-    state
-        .db
-        .execute(FunUserAddStrategyPoolContractAssetLedgerEntryReq {
-            strategy_pool_contract_id: strategy_pool_contract_row.pkey_id,
-            token_address: strategy_pool_pending_wallet_trade.token_in.into(),
-            blockchain,
-            amount: token_in_amount,
-            transaction_hash: pending_wallet_trade_receipt.transaction_hash.into(),
-            is_add: false,
-        })
-        .await?;
-
+    /* start the cooldown */
     state
         .db
-        .execute(FunUserAddStrategyPoolContractAssetLedgerEntryReq {
-            strategy_pool_contract_id: strategy_pool_contract_row.pkey_id,
-            token_address: strategy_pool_pending_wallet_trade.token_out.into(),
-            blockchain,
-            amount: token_out_amount,
-            transaction_hash: pending_wallet_trade_receipt.transaction_hash.into(),
-            is_add: true,
-        })
+        .execute(FunWatcherUpdateStrategyLastCopyTradedAtReq { strategy_id })
         .await?;
 
-    /* update per-user strategy pool contract asset balances & ledger */
-    info!(
-        "update_user_strategy_pool_asset_balances_on_copy_trade: strategy_pool_contract_id: {}, token_in: {}, token_out: {}",
-        strategy_pool_contract_row.pkey_id, strategy_pool_pending_wallet_trade.token_in, strategy_pool_pending_wallet_trade.token_out
-    );
-    update_user_strategy_pool_asset_balances_on_copy_trade(
-        &state.db,
-        blockchain,
-        strategy_pool_contract_row.pkey_id,
-        strategy_pool_pending_wallet_trade.token_in,
-        token_in_amount,
-        sp_asset_token_in_previous_amount,
-        strategy_pool_pending_wallet_trade.token_out,
-        token_out_amount,
-    )
-    .await?;
-
     // TODO: multi-chain for loop ends here
 
     Ok(())
@@ -612,9 +454,9 @@ pub async fn handle_escrows(
             match wait_for_confirmations(
                 &conn.eth(),
                 hash,
-                POLL_INTERVAL,
-                MAX_RETRIES,
-                CONFIRMATIONS,
+                poll_interval(),
+                max_retries(),
+                confirmations(),
             )
             .await
             {
@@ -714,7 +556,7 @@ pub async fn handle_escrow_transaction(
             .get(blockchain, ())
             .context("could not find escrow contract address on this chain")?,
     )?;
-    /* check if transaction was made by a whitelisted wallet */
+    /* check if transaction was made by a whitelisted wallet or a linked login wallet */
     let whitelisted_wallet = state
         .db
         .execute(FunUserListWhitelistedWalletsReq {
@@ -726,9 +568,16 @@ pub async fn handle_escrow_transaction(
         })
         .await?
         .into_result();
+    let login_wallet = state
+        .db
+        .execute(FunUserGetUserByLoginWalletReq {
+            address: caller.into(),
+        })
+        .await?
+        .into_result();
 
-    if whitelisted_wallet.is_none() {
-        /* escrow was not done by a whitelisted wallet, return it minus fees */
+    if whitelisted_wallet.is_none() && login_wallet.is_none() {
+        /* escrow was not done by a known wallet, return it minus fees */
         /* estimate gas of deposit rejection using dummy values */
         let estimated_refund_gas = escrow_contract
             .estimate_gas_reject_deposit(
@@ -783,22 +632,22 @@ pub async fn handle_escrow_transaction(
         execute_transaction_and_ensure_success(
             reject_deposit_transaction,
             &conn,
-            CONFIRMATIONS,
-            MAX_RETRIES,
-            POLL_INTERVAL,
+            confirmations(),
+            max_retries(),
+            poll_interval(),
             &DynLogger::empty(),
         )
         .await?;
 
         info!(
-            "escrow was not done by a whitelisted wallet, refunded {:?} tokens to {:?}",
+            "escrow was not done by a known wallet, refunded {:?} tokens to {:?}",
             refund_amount, caller
         );
 
         return Ok(());
     }
 
-    /* deposit was done by a whitelisted wallet, write it to contract and database */
+    /* deposit was done by a known wallet, write it to contract and database */
     let user = match state
         .db
         .execute(FunUserGetUserByAddressReq {
@@ -830,124 +679,85 @@ pub async fn handle_escrow_transaction(
     execute_transaction_and_ensure_success(
         accept_deposit_transaction,
         &conn,
-        CONFIRMATIONS,
-        MAX_RETRIES,
-        POLL_INTERVAL,
+        confirmations(),
+        max_retries(),
+        poll_interval(),
         &DynLogger::empty(),
     )
     .await?;
 
-    /* insert escrow in ledger */
-
-    state
-        .db
-        .execute(FunUserAddUserDepositWithdrawLedgerEntryReq {
-            user_id: user.user_id,
-            token_address: called_address.into(),
-            blockchain,
-            user_address: escrow.owner.into(),
-            escrow_contract_address: escrow.recipient.into(),
-            receiver_address: escrow.recipient.into(),
-            quantity: u256_to_decimal(escrow.amount, escrow_transfer_token.decimals as _),
-            transaction_hash: tx.get_hash().into(),
-            is_deposit: true,
-            is_back: false,
-            is_withdraw: false,
-        })
-        .await?;
-    let old_balance = state
-        .db
-        .execute(FunUserListUserDepositWithdrawBalanceReq {
-            limit: Some(1),
-            offset: None,
-            user_id: user.user_id,
-            user_address: Some(escrow.owner.into()),
-            blockchain: Some(blockchain),
-            token_address: Some(called_address.into()),
-            token_id: None,
-            escrow_contract_address: Some(escrow.recipient.into()),
-        })
-        .await?
-        .into_result()
-        .map(|x| x.balance)
-        .unwrap_or_default();
-    let new_balance =
-        old_balance + u256_to_decimal(escrow.amount, escrow_transfer_token.decimals as _);
+    /* insert escrow in ledger and update the balance in one transaction */
+    let transaction_hash = tx.get_hash();
     let resp = state
         .db
-        .execute(FunWatcherUpsertUserDepositWithdrawBalanceReq {
-            user_id: user.user_id,
-            user_address: escrow.owner.into(),
-            blockchain,
-            old_balance,
-            new_balance,
-            token_address: called_address.into(),
-            escrow_contract_address: escrow.recipient.into(),
-        })
-        .await?;
-
-    if let Some(admin_client) = state.admin_client.as_ref() {
-        if let Err(err) = admin_client
-            .lock()
-            .await
-            .request(AdminNotifyEscrowLedgerChangeRequest {
-                pkey_id: 0,
-                user_id: user.user_id,
-                balance: UserListDepositLedgerRow {
-                    transaction_id: resp
-                        .first(|x| x.ret_pkey_id)
-                        .unwrap_or_else(|| Utc::now().timestamp()),
-                    quantity: u256_to_decimal(escrow.amount, escrow_transfer_token.decimals as _),
+        .transaction(|db_tx| async move {
+            db_tx
+                .execute(FunUserAddUserDepositWithdrawLedgerEntryReq {
+                    user_id: user.user_id,
+                    token_address: called_address.into(),
                     blockchain,
                     user_address: escrow.owner.into(),
-                    contract_address: called_address.into(),
-                    transaction_hash: tx.get_hash().into(),
-                    is_deposit: true,
+                    escrow_contract_address: escrow.recipient.into(),
                     receiver_address: escrow.recipient.into(),
-
-                    happened_at: Utc::now().timestamp(),
-                },
-            })
-            .await
-        {
-            error!("error notifying admin of escrow ledger change: {:?}", err);
-        }
-    }
-    Ok(())
-}
-
-pub async fn calculate_gas_fee_in_tokens(
-    cmc: &CoinMarketCap,
-    conn: &EthereumRpcConnection,
-    blockchain: &EnumBlockChain,
-    token_address: Address,
-    total_gas_fee_in_wei: U256,
-) -> Result<U256> {
-    let token_contract = Erc20Token::new(conn.clone(), token_address)?;
-    let token_decimals = token_contract.decimals().await?;
-    let token_symbol = token_contract.symbol().await?;
-
-    let native_symbol = match blockchain {
-        EnumBlockChain::EthereumMainnet => "ETH",
-        EnumBlockChain::EthereumGoerli => "ETH",
-        EnumBlockChain::EthereumSepolia => "ETH",
-        EnumBlockChain::BscMainnet => "BNB",
-        EnumBlockChain::BscTestnet => "BNB",
-        _ => bail!("unsupported blockchain"),
-    };
-
-    /* get token value of 1 native token */
-    let native_price = cmc
-        .get_quote_price_by_symbol(native_symbol.to_string(), token_symbol)
+                    quantity: u256_to_decimal(escrow.amount, escrow_transfer_token.decimals as _),
+                    transaction_hash: transaction_hash.into(),
+                    is_deposit: true,
+                    is_back: false,
+                    is_withdraw: false,
+                    is_fee: false,
+                })
+                .await?;
+            let old_balance = db_tx
+                .execute(FunUserListUserDepositWithdrawBalanceReq {
+                    limit: Some(1),
+                    offset: None,
+                    user_id: user.user_id,
+                    user_address: Some(escrow.owner.into()),
+                    blockchain: Some(blockchain),
+                    token_address: Some(called_address.into()),
+                    token_id: None,
+                    escrow_contract_address: Some(escrow.recipient.into()),
+                })
+                .await?
+                .into_result()
+                .map(|x| x.balance)
+                .unwrap_or_default();
+            let new_balance =
+                old_balance + u256_to_decimal(escrow.amount, escrow_transfer_token.decimals as _);
+            db_tx
+                .execute(FunWatcherUpsertUserDepositWithdrawBalanceReq {
+                    user_id: user.user_id,
+                    user_address: escrow.owner.into(),
+                    blockchain,
+                    old_balance,
+                    new_balance,
+                    token_address: called_address.into(),
+                    escrow_contract_address: escrow.recipient.into(),
+                })
+                .await
+        })
         .await?;
 
-    let gas_fee_in_tokens = total_gas_fee_in_wei
-        /* native price doesn't consider decimals */
-        .mul_f64(native_price)?
-        /* so multiply native price without decimals by proportion the token decimals take in native decimals */
-        .mul_div(U256::exp10(token_decimals.as_usize()), U256::exp10(18))?;
-
-    Ok(gas_fee_in_tokens)
+    state
+        .publish_event(DomainEvent::EscrowCredited(EscrowCreditedEvent {
+            user_id: user.user_id,
+            entry: UserListDepositLedgerRow {
+                transaction_id: resp
+                    .first(|x| x.ret_pkey_id)
+                    .unwrap_or_else(|| Utc::now().timestamp()),
+                quantity: u256_to_decimal(escrow.amount, escrow_transfer_token.decimals as _),
+                blockchain,
+                user_address: escrow.owner,
+                contract_address: called_address,
+                transaction_hash,
+                is_deposit: true,
+                receiver_address: escrow.recipient,
+
+                happened_at: Utc::now().timestamp(),
+            },
+        }))
+        .await;
+    Ok(())
 }
 
 pub async fn handle_withdraws(
@@ -1050,51 +860,56 @@ pub async fn handle_withdraw_transaction(
         })?;
     let withdraw_amount = u256_to_decimal(withdraw_event.amount, withdraw_token.decimals as _);
     /* update user deposit withdraw balance & ledger */
+    let transaction_hash = tx.get_hash();
     state
         .db
-        .execute(FunUserAddUserDepositWithdrawLedgerEntryReq {
-            user_id: user.user_id,
-            quantity: withdraw_amount,
-            blockchain,
-            user_address: withdraw_event.proprietor.into(),
-            token_address: withdraw_event.asset.into(),
-            escrow_contract_address: escrow_contract_address.into(),
-            transaction_hash: tx.get_hash().into(),
-            receiver_address: withdraw_event.proprietor.into(),
-            is_deposit: false,
-            is_back: false,
-            is_withdraw: true,
-        })
-        .await
-        .context("error inserting withdraw in ledger")?;
-
-    let old_balance = state
-        .db
-        .execute(FunUserListUserDepositWithdrawBalanceReq {
-            limit: Some(1),
-            offset: None,
-            user_id: user.user_id,
-            user_address: Some(withdraw_event.proprietor.into()),
-            blockchain: Some(blockchain),
-            token_address: Some(withdraw_event.asset.into()),
-            token_id: None,
-            escrow_contract_address: Some(escrow_contract_address.into()),
-        })
-        .await?
-        .into_result()
-        .map(|x| x.balance)
-        .unwrap_or_default();
-    let new_balance = old_balance - withdraw_amount;
-    state
-        .db
-        .execute(FunWatcherUpsertUserDepositWithdrawBalanceReq {
-            user_id: user.user_id,
-            user_address: withdraw_event.proprietor.into(),
-            blockchain,
-            old_balance,
-            new_balance,
-            token_address: withdraw_event.asset.into(),
-            escrow_contract_address: escrow_contract_address.into(),
+        .transaction(|db_tx| async move {
+            db_tx
+                .execute(FunUserAddUserDepositWithdrawLedgerEntryReq {
+                    user_id: user.user_id,
+                    quantity: withdraw_amount,
+                    blockchain,
+                    user_address: withdraw_event.proprietor.into(),
+                    token_address: withdraw_event.asset.into(),
+                    escrow_contract_address: escrow_contract_address.into(),
+                    transaction_hash: transaction_hash.into(),
+                    receiver_address: withdraw_event.proprietor.into(),
+                    is_deposit: false,
+                    is_back: false,
+                    is_withdraw: true,
+                    is_fee: false,
+                })
+                .await
+                .context("error inserting withdraw in ledger")?;
+
+            let old_balance = db_tx
+                .execute(FunUserListUserDepositWithdrawBalanceReq {
+                    limit: Some(1),
+                    offset: None,
+                    user_id: user.user_id,
+                    user_address: Some(withdraw_event.proprietor.into()),
+                    blockchain: Some(blockchain),
+                    token_address: Some(withdraw_event.asset.into()),
+                    token_id: None,
+                    escrow_contract_address: Some(escrow_contract_address.into()),
+                })
+                .await?
+                .into_result()
+                .map(|x| x.balance)
+                .unwrap_or_default();
+            let new_balance = old_balance - withdraw_amount;
+            db_tx
+                .execute(FunWatcherUpsertUserDepositWithdrawBalanceReq {
+                    user_id: user.user_id,
+                    user_address: withdraw_event.proprietor.into(),
+                    blockchain,
+                    old_balance,
+                    new_balance,
+                    token_address: withdraw_event.asset.into(),
+                    escrow_contract_address: escrow_contract_address.into(),
+                })
+                .await?;
+            Ok(())
         })
         .await?;
 
@@ -1281,9 +1096,9 @@ pub async fn handle_redeem_transaction(
     let withdraw_transaction_hash = execute_transaction_and_ensure_success(
         withdraw_transaction,
         &conn,
-        CONFIRMATIONS,
-        MAX_RETRIES,
-        POLL_INTERVAL,
+        confirmations(),
+        max_retries(),
+        poll_interval(),
         &logger,
     )
     .await?;
@@ -1314,6 +1129,16 @@ pub async fn handle_redeem_transaction(
     )
     .await?;
 
+    state
+        .publish_event(DomainEvent::RedeemProcessed(RedeemProcessedEvent {
+            user_id: strategy_wallet_contract_row.user_id,
+            strategy_id: strategy_pool_contract_row.strategy_id,
+            blockchain,
+            amount: redeem_amount,
+            transaction_hash: withdraw_transaction_hash,
+        }))
+        .await;
+
     Ok(())
 }
 
@@ -1338,9 +1163,9 @@ pub async fn handle_revoke_adminships(
             match wait_for_confirmations(
                 &conn.eth(),
                 hash,
-                POLL_INTERVAL,
-                MAX_RETRIES,
-                CONFIRMATIONS,
+                poll_interval(),
+                max_retries(),
+                confirmations(),
             )
             .await
             {
@@ -1423,6 +1248,7 @@ pub async fn handle_revoke_adminship_transaction(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use api::cmc::CoinMarketCap;
     use eth_sdk::EthereumRpcConnectionPool;
     use std::println;
     use std::str::FromStr;
@@ -0,0 +1,98 @@
+use crate::AppState;
+use chrono::Utc;
+use eth_sdk::{confirmations, TransactionFetcher, TxStatus};
+use eyre::*;
+use gen::database::*;
+use gen::model::*;
+use mc2fi_user::events::{DomainEvent, EscrowCreditedEvent};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::*;
+
+/// how often the watcher looks at withdraw transactions that were sent but never confirmed
+pub const PENDING_WITHDRAW_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+/// a withdraw transaction no node knows of after this long was dropped and gets refunded
+pub const PENDING_WITHDRAW_DROP_AFTER: Duration = Duration::from_secs(60 * 60);
+
+/// records confirmed withdraw transfers in the ledger and refunds the reverted or dropped ones
+pub async fn settle_pending_withdraws(state: Arc<AppState>) -> Result<()> {
+    let pending_withdraws = state
+        .db
+        .execute(FunWatcherListPendingWithdrawsReq {})
+        .await?;
+    for withdraw in pending_withdraws.into_iter() {
+        if let Err(err) = settle_pending_withdraw(&state, &withdraw).await {
+            error!(
+                "failed to settle pending withdraw {}: {:?}",
+                withdraw.pending_withdraw_id, err
+            );
+        }
+    }
+    Ok(())
+}
+
+async fn settle_pending_withdraw(
+    state: &AppState,
+    withdraw: &FunWatcherListPendingWithdrawsRespRow,
+) -> Result<()> {
+    let conn = state.eth_pool.get(withdraw.blockchain).await?;
+    let mut tx = TransactionFetcher::new(withdraw.transaction_hash.into());
+    tx.update(&conn).await?;
+    let is_successful = match tx.get_status() {
+        TxStatus::Successful => {
+            let block_number = tx
+                .get_receipt()
+                .and_then(|x| x.block_number)
+                .context("no block number in receipt")?;
+            /* wait for the confirmations a transfer normally waits for */
+            if conn.eth().block_number().await? < block_number + confirmations() {
+                return Ok(());
+            }
+            true
+        }
+        TxStatus::Reverted => false,
+        TxStatus::NotFound
+            if Utc::now().timestamp() - withdraw.created_at
+                > PENDING_WITHDRAW_DROP_AFTER.as_secs() as i64 =>
+        {
+            false
+        }
+        _ => return Ok(()),
+    };
+    let settled = match state
+        .db
+        .execute(FunWatcherSettlePendingWithdrawReq {
+            pending_withdraw_id: withdraw.pending_withdraw_id,
+            is_successful,
+        })
+        .await?
+        .into_result()
+    {
+        Some(settled) => settled,
+        /* settled concurrently */
+        None => return Ok(()),
+    };
+    info!(
+        "settled pending withdraw {} of {} in transaction {:?}, successful: {}",
+        withdraw.pending_withdraw_id, settled.quantity, settled.transaction_hash, is_successful
+    );
+    if let Some(ledger_entry_id) = settled.ledger_entry_id {
+        state
+            .publish_event(DomainEvent::EscrowCredited(EscrowCreditedEvent {
+                user_id: settled.user_id,
+                entry: UserListDepositLedgerRow {
+                    transaction_id: ledger_entry_id,
+                    blockchain: settled.blockchain,
+                    user_address: settled.user_address.into(),
+                    contract_address: settled.escrow_contract_address.into(),
+                    receiver_address: settled.receiver_address.into(),
+                    quantity: settled.quantity,
+                    transaction_hash: settled.transaction_hash.into(),
+                    is_deposit: false,
+                    happened_at: Utc::now().timestamp(),
+                },
+            }))
+            .await;
+    }
+    Ok(())
+}
@@ -829,6 +829,435 @@ BEGIN
 
 		RETURN QUERY SELECT TRUE;
 END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_watcher_list_strategies_due_for_rebalance",
+            vec![],
+            vec![
+                Field::new("strategy_id", Type::BigInt),
+                Field::new("blockchain", Type::enum_ref("block_chain")),
+                Field::new("strategy_pool_contract_id", Type::BigInt),
+                Field::new("strategy_pool_address", Type::BlockchainAddress),
+                Field::new("rebalance_drift_threshold", Type::optional(Type::Numeric)),
+            ],
+            r#"
+BEGIN
+    RETURN QUERY SELECT
+        s.pkey_id,
+        spc.blockchain,
+        spc.pkey_id,
+        spc.address,
+        s.rebalance_drift_threshold
+    FROM tbl.strategy AS s
+    JOIN tbl.strategy_pool_contract AS spc ON spc.fkey_strategy_id = s.pkey_id
+    WHERE s.approved = TRUE
+        AND s.copy_trading_paused = FALSE
+        AND s.rebalance_interval_seconds IS NOT NULL
+        AND (s.last_rebalanced_at ISNULL
+            OR s.last_rebalanced_at + s.rebalance_interval_seconds <= EXTRACT(EPOCH FROM NOW())::bigint)
+    ORDER BY s.pkey_id;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_watcher_update_strategy_last_rebalanced_at",
+            vec![Field::new("strategy_id", Type::BigInt)],
+            vec![],
+            r#"
+BEGIN
+    UPDATE tbl.strategy
+    SET last_rebalanced_at = EXTRACT(EPOCH FROM NOW())::bigint
+    WHERE pkey_id = a_strategy_id;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_watcher_list_active_strategy_guard_rules",
+            vec![],
+            vec![
+                Field::new("rule_id", Type::BigInt),
+                Field::new("strategy_id", Type::BigInt),
+                Field::new("blockchain", Type::enum_ref("block_chain")),
+                Field::new("strategy_pool_contract_id", Type::BigInt),
+                Field::new("strategy_pool_address", Type::BlockchainAddress),
+                Field::new("rule_type", Type::enum_ref("strategy_guard_rule_type")),
+                Field::new("token_address", Type::optional(Type::BlockchainAddress)),
+                Field::new("threshold", Type::Numeric),
+                Field::new("reference_value", Type::optional(Type::Numeric)),
+                Field::new(
+                    "exit_token_address",
+                    Type::optional(Type::BlockchainAddress),
+                ),
+                Field::new("outstanding_sp_tokens", Type::BlockchainDecimal),
+            ],
+            r#"
+BEGIN
+    RETURN QUERY SELECT
+        r.pkey_id,
+        s.pkey_id,
+        spc.blockchain,
+        spc.pkey_id,
+        spc.address,
+        r.rule_type,
+        t.address,
+        r.threshold,
+        ref.reference_value,
+        et.address,
+        COALESCE((SELECT SUM(usb.balance)
+                  FROM tbl.user_strategy_balance AS usb
+                  WHERE usb.fkey_strategy_pool_contract_id = spc.pkey_id), 0)::decimal(56, 18)
+    FROM tbl.strategy_guard_rule AS r
+    JOIN tbl.strategy AS s ON s.pkey_id = r.fkey_strategy_id
+    JOIN tbl.strategy_pool_contract AS spc ON spc.fkey_strategy_id = s.pkey_id
+    LEFT JOIN tbl.strategy_guard_rule_reference AS ref
+        ON ref.fkey_strategy_guard_rule_id = r.pkey_id AND ref.fkey_strategy_pool_contract_id = spc.pkey_id
+    LEFT JOIN tbl.escrow_token_contract_address AS t ON t.pkey_id = r.fkey_token_id
+    LEFT JOIN tbl.escrow_token_contract_address AS et ON et.pkey_id = r.fkey_exit_token_id
+    WHERE r.triggered_at ISNULL
+        AND s.copy_trading_paused = FALSE
+        -- an asset stop-loss only guards the pool on the chain of its token
+        AND (t.pkey_id ISNULL OR t.blockchain = spc.blockchain)
+    ORDER BY s.pkey_id, spc.pkey_id, r.pkey_id;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_watcher_update_strategy_guard_rule_reference_value",
+            vec![
+                Field::new("rule_id", Type::BigInt),
+                Field::new("strategy_pool_contract_id", Type::BigInt),
+                Field::new("reference_value", Type::Numeric),
+            ],
+            vec![],
+            r#"
+BEGIN
+    INSERT INTO tbl.strategy_guard_rule_reference (
+        fkey_strategy_guard_rule_id,
+        fkey_strategy_pool_contract_id,
+        reference_value
+    ) VALUES (
+        a_rule_id,
+        a_strategy_pool_contract_id,
+        a_reference_value
+    ) ON CONFLICT (fkey_strategy_guard_rule_id, fkey_strategy_pool_contract_id)
+    DO UPDATE SET reference_value = EXCLUDED.reference_value;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_watcher_trigger_strategy_guard_rule",
+            vec![Field::new("rule_id", Type::BigInt)],
+            vec![Field::new("triggered_at", Type::BigInt)],
+            r#"
+DECLARE
+    _triggered_at bigint := EXTRACT(EPOCH FROM NOW())::bigint;
+BEGIN
+    UPDATE tbl.strategy_guard_rule
+    SET triggered_at = _triggered_at
+    WHERE pkey_id = a_rule_id;
+    UPDATE tbl.strategy
+    SET copy_trading_paused = TRUE
+    WHERE pkey_id = (SELECT fkey_strategy_id FROM tbl.strategy_guard_rule WHERE pkey_id = a_rule_id);
+    RETURN QUERY SELECT _triggered_at;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_watcher_get_strategy_copy_trade_controls",
+            vec![Field::new("strategy_id", Type::BigInt)],
+            vec![
+                Field::new("copy_trading_paused", Type::Boolean),
+                Field::new("copy_trade_min_usd", Type::optional(Type::Numeric)),
+                Field::new("copy_trade_max_aum_ratio", Type::optional(Type::Numeric)),
+                Field::new("copy_trade_cooldown_seconds", Type::optional(Type::BigInt)),
+                Field::new(
+                    "copy_trade_batch_window_seconds",
+                    Type::optional(Type::BigInt),
+                ),
+                Field::new("last_copy_traded_at", Type::optional(Type::BigInt)),
+            ],
+            r#"
+BEGIN
+    RETURN QUERY SELECT
+        s.copy_trading_paused,
+        s.copy_trade_min_usd,
+        s.copy_trade_max_aum_ratio,
+        s.copy_trade_cooldown_seconds,
+        s.copy_trade_batch_window_seconds,
+        s.last_copy_traded_at
+    FROM tbl.strategy AS s
+    WHERE s.pkey_id = a_strategy_id;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_watcher_mark_strategy_copy_trade_pending",
+            vec![Field::new("strategy_id", Type::BigInt)],
+            vec![],
+            r#"
+BEGIN
+    UPDATE tbl.strategy
+    SET copy_trade_pending_since = COALESCE(copy_trade_pending_since, EXTRACT(EPOCH FROM NOW())::bigint)
+    WHERE pkey_id = a_strategy_id;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_watcher_update_strategy_last_copy_traded_at",
+            vec![Field::new("strategy_id", Type::BigInt)],
+            vec![],
+            r#"
+BEGIN
+    UPDATE tbl.strategy
+    SET last_copy_traded_at = EXTRACT(EPOCH FROM NOW())::bigint,
+        copy_trade_pending_since = NULL
+    WHERE pkey_id = a_strategy_id;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_watcher_list_strategies_due_for_batched_copy_trade",
+            vec![],
+            vec![
+                Field::new("strategy_id", Type::BigInt),
+                Field::new("blockchain", Type::enum_ref("block_chain")),
+                Field::new("strategy_pool_contract_id", Type::BigInt),
+                Field::new("strategy_pool_address", Type::BlockchainAddress),
+                Field::new("copy_trade_min_usd", Type::optional(Type::Numeric)),
+                Field::new("copy_trade_max_aum_ratio", Type::optional(Type::Numeric)),
+            ],
+            r#"
+DECLARE
+    _now bigint := EXTRACT(EPOCH FROM NOW())::bigint;
+BEGIN
+    RETURN QUERY SELECT
+        s.pkey_id,
+        spc.blockchain,
+        spc.pkey_id,
+        spc.address,
+        s.copy_trade_min_usd,
+        s.copy_trade_max_aum_ratio
+    FROM tbl.strategy AS s
+    JOIN tbl.strategy_pool_contract AS spc ON spc.fkey_strategy_id = s.pkey_id
+    WHERE s.copy_trade_pending_since IS NOT NULL
+        AND s.copy_trading_paused = FALSE
+        AND s.copy_trade_pending_since + COALESCE(s.copy_trade_batch_window_seconds, 0) <= _now
+        AND (s.last_copy_traded_at ISNULL
+            OR s.last_copy_traded_at + COALESCE(s.copy_trade_cooldown_seconds, 0) <= _now)
+    ORDER BY s.pkey_id;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_watcher_list_strategies_for_scoring",
+            vec![],
+            vec![
+                Field::new("strategy_id", Type::BigInt),
+                Field::new("blockchain", Type::enum_ref("block_chain")),
+                Field::new("outstanding_sp_tokens", Type::BlockchainDecimal),
+            ],
+            r#"
+BEGIN
+    RETURN QUERY SELECT
+        s.pkey_id,
+        spc.blockchain,
+        COALESCE((SELECT SUM(usb.balance)
+                  FROM tbl.user_strategy_balance AS usb
+                  WHERE usb.fkey_strategy_pool_contract_id = spc.pkey_id), 0)::decimal(56, 18)
+    FROM tbl.strategy AS s
+    JOIN tbl.strategy_pool_contract AS spc ON spc.fkey_strategy_id = s.pkey_id
+    WHERE s.approved = TRUE
+        AND s.fkey_user_id IS NOT NULL
+    ORDER BY s.pkey_id;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_watcher_upsert_strategy_daily_value",
+            vec![
+                Field::new("strategy_id", Type::BigInt),
+                Field::new("day", Type::BigInt),
+                Field::new("aum_usd", Type::Numeric),
+                Field::new("share_price", Type::optional(Type::Numeric)),
+            ],
+            vec![],
+            r#"
+BEGIN
+    INSERT INTO tbl.strategy_daily_value (fkey_strategy_id, day, aum_usd, share_price, updated_at)
+    VALUES (a_strategy_id, a_day, a_aum_usd, a_share_price, EXTRACT(EPOCH FROM NOW())::bigint)
+    ON CONFLICT (fkey_strategy_id, day) DO UPDATE
+        SET aum_usd = EXCLUDED.aum_usd,
+            share_price = EXCLUDED.share_price,
+            updated_at = EXCLUDED.updated_at;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_watcher_list_expert_strategy_daily_values",
+            vec![Field::new("since", Type::BigInt)],
+            vec![
+                Field::new("expert_user_id", Type::BigInt),
+                Field::new("strategy_id", Type::BigInt),
+                Field::new("day", Type::BigInt),
+                Field::new("aum_usd", Type::Numeric),
+                Field::new("share_price", Type::optional(Type::Numeric)),
+            ],
+            r#"
+BEGIN
+    RETURN QUERY SELECT
+        s.fkey_user_id,
+        v.fkey_strategy_id,
+        v.day,
+        v.aum_usd,
+        v.share_price
+    FROM tbl.strategy_daily_value AS v
+    JOIN tbl.strategy AS s ON s.pkey_id = v.fkey_strategy_id
+    WHERE v.day >= a_since
+        AND s.fkey_user_id IS NOT NULL
+    ORDER BY s.fkey_user_id, v.fkey_strategy_id, v.day;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_watcher_list_expert_scoring_stats",
+            vec![Field::new("since", Type::BigInt)],
+            vec![
+                Field::new("user_id", Type::BigInt),
+                Field::new("first_traded_at", Type::optional(Type::BigInt)),
+                Field::new("active_weeks", Type::BigInt),
+                Field::new("backers_total", Type::BigInt),
+                Field::new("backers_current", Type::BigInt),
+            ],
+            r#"
+BEGIN
+    RETURN QUERY SELECT
+        e.fkey_user_id,
+        (SELECT MIN(t.happened_at)
+         FROM tbl.strategy_watching_wallet_trade_ledger AS t
+         JOIN tbl.expert_watched_wallet AS w ON w.pkey_id = t.fkey_expert_watched_wallet_id
+         WHERE w.fkey_user_id = e.fkey_user_id),
+        (SELECT COUNT(DISTINCT (t.happened_at - a_since) / 604800)
+         FROM tbl.strategy_watching_wallet_trade_ledger AS t
+         JOIN tbl.expert_watched_wallet AS w ON w.pkey_id = t.fkey_expert_watched_wallet_id
+         WHERE w.fkey_user_id = e.fkey_user_id
+            AND t.happened_at >= a_since),
+        (SELECT COUNT(DISTINCT l.fkey_user_id)
+         FROM tbl.user_back_exit_strategy_ledger AS l
+         JOIN tbl.strategy AS s ON s.pkey_id = l.fkey_strategy_id
+         WHERE s.fkey_user_id = e.fkey_user_id
+            AND l.is_back = TRUE),
+        (SELECT COUNT(DISTINCT usw.fkey_user_id)
+         FROM tbl.user_strategy_balance AS usb
+         JOIN tbl.user_strategy_wallet AS usw ON usw.pkey_id = usb.fkey_user_strategy_wallet_id
+         JOIN tbl.strategy_pool_contract AS spc ON spc.pkey_id = usb.fkey_strategy_pool_contract_id
+         JOIN tbl.strategy AS s ON s.pkey_id = spc.fkey_strategy_id
+         WHERE s.fkey_user_id = e.fkey_user_id
+            AND usb.balance > 0)
+    FROM tbl.expert_profile AS e
+    WHERE e.approved_expert = TRUE
+    ORDER BY e.fkey_user_id;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_watcher_update_expert_scores",
+            vec![
+                Field::new("user_id", Type::BigInt),
+                Field::new("consistent_score", Type::Numeric),
+                Field::new("risk_score", Type::Numeric),
+                Field::new("reputation_score", Type::Numeric),
+                Field::new("aum", Type::Numeric),
+            ],
+            vec![],
+            r#"
+BEGIN
+    UPDATE tbl.expert_profile
+    SET consistent_score = a_consistent_score,
+        risk_score = a_risk_score,
+        reputation_score = a_reputation_score,
+        aum = a_aum,
+        updated_at = EXTRACT(EPOCH FROM NOW())::bigint
+    WHERE fkey_user_id = a_user_id;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_watcher_list_pending_withdraws",
+            vec![],
+            vec![
+                Field::new("pending_withdraw_id", Type::BigInt),
+                Field::new("blockchain", Type::enum_ref("block_chain")),
+                Field::new("transaction_hash", Type::BlockchainTransactionHash),
+                Field::new("created_at", Type::BigInt),
+            ],
+            r#"
+BEGIN
+    RETURN QUERY SELECT pw.pkey_id, pw.blockchain, pw.transaction_hash, pw.created_at
+    FROM tbl.user_pending_withdraw AS pw
+    ORDER BY pw.pkey_id;
+END
+"#,
+        ),
+        ProceduralFunction::new(
+            "fun_watcher_settle_pending_withdraw",
+            vec![
+                Field::new("pending_withdraw_id", Type::BigInt),
+                Field::new("is_successful", Type::Boolean),
+            ],
+            vec![
+                Field::new("user_id", Type::BigInt),
+                Field::new("ledger_entry_id", Type::optional(Type::BigInt)),
+                Field::new("blockchain", Type::enum_ref("block_chain")),
+                Field::new("user_address", Type::BlockchainAddress),
+                Field::new("escrow_contract_address", Type::BlockchainAddress),
+                Field::new("receiver_address", Type::BlockchainAddress),
+                Field::new("quantity", Type::BlockchainDecimal),
+                Field::new("transaction_hash", Type::BlockchainTransactionHash),
+            ],
+            r#"
+DECLARE
+    _pending tbl.user_pending_withdraw;
+    _ledger_entry_id bigint;
+BEGIN
+    -- deleting the row first makes sure a withdraw is settled only once
+    DELETE FROM tbl.user_pending_withdraw
+    WHERE pkey_id = a_pending_withdraw_id
+    RETURNING * INTO _pending;
+    IF _pending.pkey_id IS NULL THEN
+        RETURN;
+    END IF;
+    IF a_is_successful THEN
+        SELECT l.ledger_entry_id INTO _ledger_entry_id FROM api.fun_user_add_user_deposit_withdraw_ledger_entry(
+            a_user_id => _pending.fkey_user_id,
+            a_token_address => _pending.token_address,
+            a_blockchain => _pending.blockchain,
+            a_user_address => _pending.user_address,
+            a_escrow_contract_address => _pending.escrow_contract_address,
+            a_receiver_address => _pending.receiver_address,
+            a_quantity => _pending.quantity,
+            a_transaction_hash => _pending.transaction_hash,
+            a_is_deposit => FALSE,
+            a_is_back => FALSE,
+            a_is_withdraw => TRUE,
+            a_is_fee => _pending.is_fee
+        ) AS l;
+    ELSE
+        -- the transfer never happened, give the debited balance back
+        UPDATE tbl.user_deposit_withdraw_balance
+        SET balance = balance + _pending.quantity
+        WHERE pkey_id = _pending.fkey_deposit_withdraw_balance_id;
+    END IF;
+    RETURN QUERY SELECT
+        _pending.fkey_user_id,
+        _ledger_entry_id,
+        _pending.blockchain,
+        _pending.user_address,
+        _pending.escrow_contract_address,
+        _pending.receiver_address,
+        _pending.quantity,
+        _pending.transaction_hash;
+END
 "#,
         ),
     ]
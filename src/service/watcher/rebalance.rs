@@ -0,0 +1,214 @@
+use crate::strategy_pool_trade::{execute_strategy_pool_trade, StrategyPool, StrategyPoolTrade};
+use crate::AppState;
+use api::AssetInfoClient;
+use eth_sdk::ScaledMath;
+use execution_engine::copy_trade::{
+    apply_copy_trade_limits, calculate_asset_values, calculate_copy_trade_plan,
+    calculate_max_ratio_drift, fetch_listened_wallet_asset_balances_and_decimals,
+    fetch_strategy_pool_contract_asset_balances_and_decimals, get_token_prices, load_dex_path,
+    CopyTradeEntry, CopyTradeLimits,
+};
+use eyre::*;
+use gen::database::*;
+use gen::model::*;
+use itertools::Itertools;
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::*;
+use web3::types::Address;
+
+/// how often the watcher looks for strategies whose rebalance interval has elapsed
+pub const REBALANCE_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+/// drift used when a strategy has a rebalance schedule but no threshold
+pub const DEFAULT_REBALANCE_DRIFT_THRESHOLD: f64 = 0.05;
+
+pub async fn rebalance_strategies(
+    state: Arc<AppState>,
+    asset_client: Arc<dyn AssetInfoClient>,
+) -> Result<()> {
+    let strategies = state
+        .db
+        .execute(FunWatcherListStrategiesDueForRebalanceReq {})
+        .await?;
+    for strategy in strategies.into_iter() {
+        let threshold = strategy
+            .rebalance_drift_threshold
+            .unwrap_or(DEFAULT_REBALANCE_DRIFT_THRESHOLD);
+        if let Err(err) = rebalance_strategy(
+            &state,
+            &asset_client,
+            StrategyPool {
+                strategy_id: strategy.strategy_id,
+                blockchain: strategy.blockchain,
+                contract_id: strategy.strategy_pool_contract_id,
+                address: strategy.strategy_pool_address.into(),
+            },
+            Decimal::from_f64(threshold).context("invalid rebalance drift threshold")?,
+            CopyTradeLimits::default(),
+        )
+        .await
+        {
+            error!(
+                "failed to rebalance strategy {}: {:?}",
+                strategy.strategy_id, err
+            );
+        }
+        /* wait for the next interval even if rebalancing failed, so a broken strategy doesn't get retried every tick */
+        if let Err(err) = state
+            .db
+            .execute(FunWatcherUpdateStrategyLastRebalancedAtReq {
+                strategy_id: strategy.strategy_id,
+            })
+            .await
+        {
+            error!(
+                "failed to update last rebalance time of strategy {}: {:?}",
+                strategy.strategy_id, err
+            );
+        }
+    }
+    Ok(())
+}
+
+/// target amounts are the expert's current wallet balances, or the initial token ratios
+/// of the strategy when the expert's wallets hold nothing we know of
+async fn fetch_rebalance_target_amounts(
+    state: &AppState,
+    blockchain: EnumBlockChain,
+    strategy_id: i64,
+) -> Result<(HashMap<Address, Decimal>, HashMap<Address, u32>)> {
+    let (expert_asset_amounts, expert_asset_decimals) =
+        fetch_listened_wallet_asset_balances_and_decimals(&state.db, blockchain, strategy_id)
+            .await?;
+    if expert_asset_amounts.values().any(|x| !x.is_zero()) {
+        return Ok((expert_asset_amounts, expert_asset_decimals));
+    }
+
+    let mut target_amounts = HashMap::new();
+    let mut target_decimals = HashMap::new();
+    for row in state
+        .db
+        .execute(FunUserListStrategyInitialTokenRatiosReq {
+            strategy_id,
+            token_id: None,
+            token_address: None,
+            blockchain: Some(blockchain),
+        })
+        .await?
+        .into_iter()
+    {
+        let token_address: Address = row.token_address.into();
+        *target_amounts.entry(token_address).or_default() += row.quantity;
+        target_decimals.insert(token_address, row.token_decimals as _);
+    }
+    Ok((target_amounts, target_decimals))
+}
+
+pub async fn rebalance_strategy(
+    state: &AppState,
+    asset_client: &Arc<dyn AssetInfoClient>,
+    pool: StrategyPool,
+    drift_threshold: Decimal,
+    limits: CopyTradeLimits,
+) -> Result<()> {
+    let StrategyPool {
+        strategy_id,
+        blockchain,
+        ..
+    } = pool;
+    let (strategy_asset_amounts, strategy_asset_decimals) =
+        fetch_strategy_pool_contract_asset_balances_and_decimals(
+            &state.db,
+            blockchain,
+            strategy_id,
+        )
+        .await?;
+    let (target_asset_amounts, target_asset_decimals) =
+        fetch_rebalance_target_amounts(state, blockchain, strategy_id).await?;
+    if strategy_asset_amounts.values().all(|x| x.is_zero())
+        || target_asset_amounts.values().all(|x| x.is_zero())
+    {
+        info!("strategy {} has nothing to rebalance", strategy_id);
+        return Ok(());
+    }
+
+    let tokens: Vec<Address> = strategy_asset_amounts
+        .keys()
+        .chain(target_asset_amounts.keys())
+        .unique()
+        .cloned()
+        .collect();
+    let prices = get_token_prices(&state.db, asset_client, tokens).await?;
+    let drift = calculate_max_ratio_drift(
+        target_asset_amounts.clone(),
+        strategy_asset_amounts.clone(),
+        prices.clone(),
+    )?;
+    if drift < drift_threshold {
+        info!(
+            "strategy {} drift {} is below threshold {}, not rebalancing",
+            strategy_id, drift, drift_threshold
+        );
+        return Ok(());
+    }
+
+    let decimals: HashMap<Address, u32> = strategy_asset_decimals
+        .into_iter()
+        .merge(target_asset_decimals)
+        .collect();
+    let mut plan = calculate_copy_trade_plan(
+        blockchain,
+        target_asset_amounts,
+        strategy_asset_amounts.clone(),
+        prices.clone(),
+        decimals.clone(),
+    )?;
+    if !limits.is_empty() {
+        let strategy_total_value = calculate_asset_values(strategy_asset_amounts, prices.clone())?
+            .values()
+            .sum::<Decimal>();
+        plan = apply_copy_trade_limits(plan, limits, &prices, &decimals, strategy_total_value)?;
+    }
+    info!(
+        "rebalancing strategy {} with drift {} in {} trades",
+        strategy_id,
+        drift,
+        plan.trades.len()
+    );
+    for trade in plan.trades {
+        execute_rebalance_trade(state, pool, &decimals, trade).await?;
+    }
+    Ok(())
+}
+
+/// trades one leg of a rebalance plan the same way expert trades are copied
+pub(crate) async fn execute_rebalance_trade(
+    state: &AppState,
+    pool: StrategyPool,
+    decimals: &HashMap<Address, u32>,
+    trade: CopyTradeEntry,
+) -> Result<()> {
+    let conn = state.eth_pool.get(pool.blockchain).await?;
+    let paths = load_dex_path(&state.db, &conn, trade.token_in, trade.token_out).await?;
+    execute_strategy_pool_trade(
+        state,
+        pool,
+        StrategyPoolTrade {
+            token_in: trade.token_in,
+            token_out: trade.token_out,
+            token_in_decimals: *decimals
+                .get(&trade.token_in)
+                .context("no token_in decimals")?,
+            token_out_decimals: *decimals
+                .get(&trade.token_out)
+                .context("no token_out decimals")?,
+            amount_in: trade.amount_in_native,
+            amount_out_minimum: trade.amount_out_native.mul_f64(0.98)?,
+            paths,
+        },
+    )
+    .await
+}
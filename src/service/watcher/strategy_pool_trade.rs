@@ -0,0 +1,258 @@
+use crate::AppState;
+use eth_sdk::dex_tracker::{
+    parse_dex_trade, update_user_strategy_pool_asset_balances_on_copy_trade,
+};
+use eth_sdk::erc20::Erc20Token;
+use eth_sdk::execute_transaction_and_ensure_success;
+use eth_sdk::pancake_swap::execute::PancakeSmartRouterContract;
+use eth_sdk::pancake_swap::PancakePairPathSet;
+use eth_sdk::strategy_pool::StrategyPoolContract;
+use eth_sdk::utils::u256_to_decimal;
+use eth_sdk::{confirmations, max_retries, poll_interval, TransactionFetcher};
+use eyre::*;
+use gen::database::*;
+use gen::model::*;
+use lib::log::DynLogger;
+use mc2fi_user::events::{DomainEvent, StrategyTradeEvent};
+use tracing::*;
+use web3::types::{Address, U256};
+
+/// the strategy pool contract of a strategy on one chain
+#[derive(Debug, Clone, Copy)]
+pub struct StrategyPool {
+    pub strategy_id: i64,
+    pub blockchain: EnumBlockChain,
+    pub contract_id: i64,
+    pub address: Address,
+}
+
+/// a swap of strategy pool assets on pancake swap
+#[derive(Debug, Clone)]
+pub struct StrategyPoolTrade {
+    pub token_in: Address,
+    pub token_out: Address,
+    pub token_in_decimals: u32,
+    pub token_out_decimals: u32,
+    pub amount_in: U256,
+    pub amount_out_minimum: U256,
+    pub paths: PancakePairPathSet,
+}
+
+/// acquires token_in from the strategy pool, swaps it for token_out and gives back what was
+/// bought, then updates the pool and user balances and ledgers and publishes the trade
+pub async fn execute_strategy_pool_trade(
+    state: &AppState,
+    pool: StrategyPool,
+    trade: StrategyPoolTrade,
+) -> Result<()> {
+    let blockchain = pool.blockchain;
+    let conn = state.eth_pool.get(blockchain).await?;
+    let strategy_pool_contract = StrategyPoolContract::new(conn.clone(), pool.address)?;
+    let pancake_contract = PancakeSmartRouterContract::new(
+        conn.clone(),
+        state
+            .dex_addresses
+            .get(blockchain, EnumDex::PancakeSwap)
+            .ok_or_else(|| eyre!("pancake swap not available on this chain"))?,
+    )?;
+
+    /* acquire asset before trade */
+    execute_transaction_and_ensure_success(
+        || {
+            strategy_pool_contract.acquire_asset_before_trade(
+                &conn,
+                state.master_key.clone(),
+                trade.token_in,
+                trade.amount_in,
+            )
+        },
+        &conn,
+        confirmations(),
+        max_retries(),
+        poll_interval(),
+        &DynLogger::empty(),
+    )
+    .await?;
+
+    /* approve pancakeswap to trade token_in */
+    let token_in_contract = Erc20Token::new(conn.clone(), trade.token_in)?;
+    execute_transaction_and_ensure_success(
+        || {
+            token_in_contract.approve(
+                &conn,
+                state.master_key.clone(),
+                pancake_contract.address(),
+                trade.amount_in,
+                DynLogger::empty(),
+            )
+        },
+        &conn,
+        confirmations(),
+        max_retries(),
+        poll_interval(),
+        &DynLogger::empty(),
+    )
+    .await?;
+
+    /* trade token_in for token_out */
+    info!(
+        "copy_trade_and_ensure_success: amount_in: {}, amount_out_minimum: {}",
+        trade.amount_in, trade.amount_out_minimum
+    );
+    let trade_hash = execute_transaction_and_ensure_success(
+        || {
+            pancake_contract.copy_trade(
+                &conn,
+                state.master_key.clone(),
+                trade.paths.clone(),
+                trade.amount_in,
+                trade.amount_out_minimum,
+            )
+        },
+        &conn,
+        confirmations(),
+        max_retries(),
+        poll_interval(),
+        &DynLogger::empty(),
+    )
+    .await?;
+    info!("copy_trade_and_ensure_success: tx_hash: {:?}", trade_hash);
+
+    /* parse trade to find amount_out */
+    let executed_trade = parse_dex_trade(
+        blockchain,
+        &TransactionFetcher::new_and_assume_ready(trade_hash, &conn).await?,
+        &state.dex_addresses,
+        state.pancake_swap_parser,
+    )
+    .await?;
+
+    /* approve strategy pool for amount_out and give back traded assets */
+    let token_out_contract = Erc20Token::new(conn.clone(), trade.token_out)?;
+    execute_transaction_and_ensure_success(
+        || {
+            token_out_contract.approve(
+                &conn,
+                state.master_key.clone(),
+                strategy_pool_contract.address(),
+                executed_trade.amount_out,
+                DynLogger::empty(),
+            )
+        },
+        &conn,
+        confirmations(),
+        max_retries(),
+        poll_interval(),
+        &DynLogger::empty(),
+    )
+    .await?;
+    execute_transaction_and_ensure_success(
+        || {
+            strategy_pool_contract.give_back_assets_after_trade(
+                &conn,
+                state.master_key.clone(),
+                vec![trade.token_out],
+                vec![executed_trade.amount_out],
+            )
+        },
+        &conn,
+        confirmations(),
+        max_retries(),
+        poll_interval(),
+        &DynLogger::empty(),
+    )
+    .await?;
+
+    /* update strategy pool contract asset balances & ledger */
+    let token_in_amount = u256_to_decimal(executed_trade.amount_in, trade.token_in_decimals);
+    let token_out_amount = u256_to_decimal(executed_trade.amount_out, trade.token_out_decimals);
+    /* read the balances inside the transaction, other trades of the pool may have changed them */
+    state
+        .db
+        .transaction(|db_tx| async move {
+            let sp_asset_token_in_previous_amount = db_tx
+                .execute(FunWatcherListStrategyPoolContractAssetBalancesReq {
+                    strategy_pool_contract_id: Some(pool.contract_id),
+                    token_address: Some(trade.token_in.into()),
+                    blockchain: Some(blockchain),
+                    strategy_id: None,
+                })
+                .await?
+                .into_result()
+                .context("strategy pool contract does not hold asset to sell")?
+                .balance;
+            db_tx
+                .execute(FunWatcherUpsertStrategyPoolContractAssetBalanceReq {
+                    strategy_pool_contract_id: pool.contract_id,
+                    token_address: trade.token_in.into(),
+                    blockchain,
+                    new_balance: sp_asset_token_in_previous_amount - token_in_amount,
+                })
+                .await?;
+            let sp_asset_token_out_previous_amount = db_tx
+                .execute(FunWatcherListStrategyPoolContractAssetBalancesReq {
+                    strategy_pool_contract_id: Some(pool.contract_id),
+                    token_address: Some(trade.token_out.into()),
+                    blockchain: Some(blockchain),
+                    strategy_id: None,
+                })
+                .await?
+                .into_result()
+                .map(|x| x.balance)
+                .unwrap_or_default();
+            db_tx
+                .execute(FunWatcherUpsertStrategyPoolContractAssetBalanceReq {
+                    strategy_pool_contract_id: pool.contract_id,
+                    token_address: trade.token_out.into(),
+                    blockchain,
+                    new_balance: sp_asset_token_out_previous_amount + token_out_amount,
+                })
+                .await?;
+            db_tx
+                .execute(FunUserAddStrategyPoolContractAssetLedgerEntryReq {
+                    strategy_pool_contract_id: pool.contract_id,
+                    token_address: trade.token_in.into(),
+                    blockchain,
+                    amount: token_in_amount,
+                    transaction_hash: trade_hash.into(),
+                    is_add: false,
+                })
+                .await?;
+            db_tx
+                .execute(FunUserAddStrategyPoolContractAssetLedgerEntryReq {
+                    strategy_pool_contract_id: pool.contract_id,
+                    token_address: trade.token_out.into(),
+                    blockchain,
+                    amount: token_out_amount,
+                    transaction_hash: trade_hash.into(),
+                    is_add: true,
+                })
+                .await?;
+            /* update per-user strategy pool contract asset balances & ledger */
+            update_user_strategy_pool_asset_balances_on_copy_trade(
+                &db_tx,
+                blockchain,
+                pool.contract_id,
+                trade.token_in,
+                token_in_amount,
+                sp_asset_token_in_previous_amount,
+                trade.token_out,
+                token_out_amount,
+            )
+            .await
+        })
+        .await?;
+    state
+        .publish_event(DomainEvent::CopyTradeExecuted(StrategyTradeEvent {
+            strategy_id: pool.strategy_id,
+            blockchain,
+            token_in_address: trade.token_in,
+            token_out_address: trade.token_out,
+            amount_in: token_in_amount,
+            amount_out: token_out_amount,
+            transaction_hash: trade_hash,
+        }))
+        .await;
+
+    Ok(())
+}
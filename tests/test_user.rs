@@ -59,6 +59,13 @@ async fn test_create_update_strategy() -> Result<()> {
             name: None,
             description: None,
             social_media: None,
+            rebalance_interval_seconds: None,
+            rebalance_drift_threshold: None,
+            copy_trade_min_usd: None,
+            copy_trade_max_aum_ratio: None,
+            copy_trade_cooldown_seconds: None,
+            copy_trade_batch_window_seconds: None,
+            expert_fee: None,
         })
         .await?;
     let wallet = client
@@ -295,6 +302,7 @@ async fn test_user_follow_expert() -> Result<()> {
     let resp = admin_client
         .request(AdminApproveUserBecomeExpertRequest {
             user_id: resp.users[0].user_id,
+            note: None,
         })
         .await?;
     info!("Approve {:?}", resp);
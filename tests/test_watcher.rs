@@ -130,7 +130,7 @@ async fn test_handle_eth_escrows_anvil() -> Result<()> {
         escrow_addresses: fake_escrow_addresses,
         db: db.clone(),
         master_key: secure_eoa_key,
-        admin_client: None,
+        event_bus: None,
     };
 
     /* fake QuickAlert payload body */
@@ -365,7 +365,7 @@ async fn test_handle_eth_swap_goerli_testnet() -> Result<()> {
         escrow_addresses: EscrowAddresses::new(),
         db: db.clone(),
         master_key: master_key.clone(),
-        admin_client: None,
+        event_bus: None,
     };
 
     let expert_trade_tx =
@@ -656,7 +656,7 @@ async fn test_handle_eth_swap_bsc_mainnet() -> Result<()> {
         escrow_addresses: EscrowAddresses::new(),
         db: db.clone(),
         master_key: master_key.clone(),
-        admin_client: None,
+        event_bus: None,
     };
 
     let expert_trade_tx =
@@ -61,6 +61,7 @@ pub async fn prepare_expert() -> Result<(Secp256k1SecretKey, WsClient, Secp256k1
     let resp = admin_client
         .request(AdminApproveUserBecomeExpertRequest {
             user_id: resp.users[0].user_id,
+            note: None,
         })
         .await?;
     info!("Approve {:?}", resp);